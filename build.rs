@@ -1,5 +1,15 @@
 extern crate embed_resource;
 
 fn main() {
-    embed_resource::compile("./icon.rc");
-}
\ No newline at end of file
+    // 图标资源只在 Windows 目标上嵌入
+    if std::env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("windows") {
+        embed_resource::compile("./icon.rc");
+    }
+
+    // 内置字体不随源码提交，缺失时给出生成方法，而不是 include_bytes! 的读取错误
+    if std::env::var_os("CARGO_FEATURE_EMBEDDED_FONT").is_some()
+        && !std::path::Path::new("src/assets/fonts/fallback-cjk.otf").is_file()
+    {
+        panic!("启用 embedded-font 特性需要先生成 src/assets/fonts/fallback-cjk.otf，见 src/assets/fonts/README.md");
+    }
+}
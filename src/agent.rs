@@ -0,0 +1,503 @@
+//! 远程上报模式
+//!
+//! 以 `--agent` 启动时不显示界面：检测一次，将完整检测结果连同机器标识以 JSON 形式 POST 到
+//! 配置的 HTTPS 地址后退出。请求头带有 `Authorization: Bearer <令牌>`，服务端据此校验来源。
+//! 配合 Windows 任务计划程序定期运行，小型 IT 团队只需一个收集服务即可清点数十台电脑的硬件。
+//!
+//! 上报地址可写在配置文件的 `agent_endpoint` 中，也可用 `--endpoint` 参数临时指定。
+//! 令牌写在配置文件的 `agent_token` 或环境变量 `HARDWARE_MASTER_AGENT_TOKEN` 中，
+//! 不接受命令行参数，避免令牌出现在进程列表里。除回环地址外只允许 HTTPS，避免令牌与序列号被明文传输。
+//! 配置中记录了本机资产信息（购买日期、保修等）时一并上报。开启隐私模式时检测结果中的序列号会被遮盖，
+//! 机器标识不受影响，不希望上报序列号时请在 `agent_machine_id` 中指定标识。
+//! 上报内容依赖检测结果的序列化，需要启用 `serialize` 特性。
+
+#[cfg(feature = "serialize")]
+use crate::asset::AssetRecord;
+#[cfg(feature = "serialize")]
+use crate::cache::CachedDetection;
+#[cfg(all(windows, feature = "serialize"))]
+use crate::config::AppConfig;
+#[cfg(windows)]
+use crate::constants::AGENT_TIMEOUT_SECS;
+use crate::constants::{AGENT_ARG, AGENT_TOKEN_ENV};
+use crate::HardwareDetector;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+/// 被视为未填写的序列号
+const PLACEHOLDER_SERIALS: &[&str] = &[
+    "未知",
+    "to be filled by o.e.m.",
+    "default string",
+    "system serial number",
+    "not specified",
+    "none",
+    "0",
+];
+
+/// 远程上报参数
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AgentOptions {
+    /// 上报地址，未指定时使用配置中的地址
+    pub endpoint: Option<String>,
+}
+
+impl AgentOptions {
+    /// 解析命令行参数，未指定 `--agent` 时返回 `Ok(None)`
+    ///
+    /// 令牌会出现在进程列表中，因此不接受 `--token` 参数
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::agent::AgentOptions;
+    /// let args = ["硬大师.exe", "--agent", "--endpoint", "https://it.example.com/inventory"];
+    /// let options = AgentOptions::from_args(args.map(String::from)).unwrap().unwrap();
+    /// assert_eq!(options.endpoint.as_deref(), Some("https://it.example.com/inventory"));
+    /// let args = ["硬大师.exe", "--agent", "--token", "secret"];
+    /// assert!(AgentOptions::from_args(args.map(String::from)).is_err());
+    /// assert_eq!(AgentOptions::from_args(["硬大师.exe".to_string()]), Ok(None));
+    /// ```
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut agent = false;
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                AGENT_ARG => agent = true,
+                "--endpoint" => options.endpoint = Some(required(args.next(), "--endpoint")?),
+                "--token" => {
+                    return Err(format!(
+                        "不支持 --token 参数，请在环境变量 {} 或配置文件的 agent_token 中设置令牌",
+                        AGENT_TOKEN_ENV
+                    ))
+                }
+                _ => {}
+            }
+        }
+        Ok(agent.then_some(options))
+    }
+}
+
+/// 读取必填的参数值
+fn required(value: Option<String>, name: &str) -> Result<String, String> {
+    value
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| format!("{} 需要一个值", name))
+}
+
+/// 选择认证令牌：环境变量非空时优先，其次为配置中的令牌
+///
+/// 令牌作为请求头发送，含回车或换行时会注入额外的请求头，此时返回错误
+///
+/// * `env` - 环境变量 `HARDWARE_MASTER_AGENT_TOKEN` 的值
+/// * `configured` - 配置中的令牌
+///
+/// 示例
+/// ```
+/// use hardware_master::agent::resolve_token;
+/// assert_eq!(resolve_token(Some("from-env".to_string()), "from-config").unwrap(), "from-env");
+/// assert_eq!(resolve_token(Some(String::new()), "from-config").unwrap(), "from-config");
+/// assert_eq!(resolve_token(None, "").unwrap(), "");
+/// assert!(resolve_token(None, "secret\r\nX-Admin: 1").is_err());
+/// ```
+pub fn resolve_token(env: Option<String>, configured: &str) -> Result<String, String> {
+    let token = env
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+        .unwrap_or_else(|| configured.trim().to_string());
+    validate_token(&token)?;
+    Ok(token)
+}
+
+/// 检查令牌中是否含有回车、换行等控制字符
+///
+/// 示例
+/// ```
+/// use hardware_master::agent::validate_token;
+/// assert!(validate_token("eyJhbGciOi.J9-_~+/=").is_ok());
+/// assert!(validate_token("secret\nHost: evil").is_err());
+/// assert!(validate_token("secret\r").is_err());
+/// ```
+pub fn validate_token(token: &str) -> Result<(), String> {
+    if token.chars().any(char::is_control) {
+        return Err("认证令牌不能包含回车、换行等控制字符".to_string());
+    }
+    Ok(())
+}
+
+/// 解析后的上报地址，也用于下载在线驱动规则
+#[derive(Debug, Clone, PartialEq)]
+pub struct Endpoint {
+    /// 是否使用 HTTPS
+    pub secure: bool,
+    /// 主机名
+    pub host: String,
+    /// 端口
+    pub port: u16,
+    /// 路径（含查询参数），如 "/inventory"
+    pub path: String,
+}
+
+impl Endpoint {
+    /// 解析地址，除 localhost 与回环地址外只接受 https
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::agent::Endpoint;
+    /// let endpoint = Endpoint::parse("https://it.example.com:8443/api/inventory?site=sh").unwrap();
+    /// assert!(endpoint.secure);
+    /// assert_eq!(endpoint.host, "it.example.com");
+    /// assert_eq!(endpoint.port, 8443);
+    /// assert_eq!(endpoint.path, "/api/inventory?site=sh");
+    ///
+    /// let endpoint = Endpoint::parse("http://127.0.0.1/collect").unwrap();
+    /// assert_eq!((endpoint.secure, endpoint.port), (false, 80));
+    /// assert!(Endpoint::parse("http://it.example.com/collect").is_err());
+    /// assert!(Endpoint::parse("ftp://it.example.com").is_err());
+    /// ```
+    pub fn parse(url: &str) -> Result<Self, String> {
+        let url = url.trim();
+        let (secure, rest) = if let Some(rest) = url.strip_prefix("https://") {
+            (true, rest)
+        } else if let Some(rest) = url.strip_prefix("http://") {
+            (false, rest)
+        } else {
+            return Err(format!("地址必须以 https:// 开头: {}", url));
+        };
+
+        let (authority, path) = match rest.find(['/', '?']) {
+            Some(index) if rest[index..].starts_with('?') => {
+                (&rest[..index], format!("/{}", &rest[index..]))
+            }
+            Some(index) => (&rest[..index], rest[index..].to_string()),
+            None => (rest, "/".to_string()),
+        };
+        // IPv6 地址形如 [::1]:8080
+        let (host, port) = match authority.rfind(':') {
+            Some(index) if !authority[index..].contains(']') => {
+                let port = authority[index + 1..]
+                    .parse::<u16>()
+                    .map_err(|_| format!("无效的端口: {}", authority))?;
+                (&authority[..index], port)
+            }
+            _ => (authority, if secure { 443 } else { 80 }),
+        };
+        if host.is_empty() {
+            return Err(format!("地址缺少主机名: {}", url));
+        }
+
+        let loopback = host.eq_ignore_ascii_case("localhost")
+            || host
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<std::net::IpAddr>()
+                .is_ok_and(|ip| ip.is_loopback());
+        if !secure && !loopback {
+            return Err(format!("地址必须使用 https: {}", url));
+        }
+
+        Ok(Self {
+            secure,
+            host: host.to_string(),
+            port,
+            path,
+        })
+    }
+}
+
+/// 机器标识
+///
+/// 优先使用配置中指定的标识，其次为厂商与整机序列号，序列号未填写时使用计算机名
+///
+/// * `configured` - 配置中的标识，为空时自动生成
+/// * `detector` - 检测结果
+/// * `hostname` - 计算机名
+///
+/// 示例
+/// ```
+/// use hardware_master::agent::machine_id;
+/// use hardware_master::HardwareDetector;
+/// let mut detector = HardwareDetector::new();
+/// assert_eq!(machine_id("", &detector, "PC-01"), "PC-01");
+/// detector.system_info.system_manufacturer = "LENOVO".to_string();
+/// detector.system_info.serial_number = "PF2ABCDE".to_string();
+/// assert_eq!(machine_id("", &detector, "PC-01"), "LENOVO-PF2ABCDE");
+/// assert_eq!(machine_id("财务-03", &detector, "PC-01"), "财务-03");
+/// ```
+pub fn machine_id(configured: &str, detector: &HardwareDetector, hostname: &str) -> String {
+    let configured = configured.trim();
+    if !configured.is_empty() {
+        return configured.to_string();
+    }
+    let system = &detector.system_info;
+    let serial = system.serial_number.trim();
+    if serial.is_empty() || PLACEHOLDER_SERIALS.contains(&serial.to_lowercase().as_str()) {
+        return hostname.to_string();
+    }
+    format!("{}-{}", system.system_manufacturer.trim(), serial)
+}
+
+/// 上报内容
+#[cfg(feature = "serialize")]
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentReport {
+    /// 机器标识
+    pub machine_id: String,
+    /// 计算机名
+    pub hostname: String,
+    /// 完整检测结果
+    pub detection: CachedDetection,
+    /// 资产信息，未记录时不输出
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<AssetRecord>,
+}
+
+#[cfg(feature = "serialize")]
+impl AgentReport {
+    /// 由检测结果生成上报内容
+    ///
+    /// * `machine_id` - 机器标识
+    /// * `hostname` - 计算机名
+    /// * `detector` - 检测结果
+    /// * `timestamp` - 检测时间（Unix 时间戳，秒）
+    pub fn new(
+        machine_id: String,
+        hostname: String,
+        detector: &HardwareDetector,
+        timestamp: i64,
+    ) -> Self {
+        Self {
+            machine_id,
+            hostname,
+            detection: CachedDetection::from_detector(detector, timestamp),
+            asset: None,
+        }
+    }
+
+    /// 附带资产信息
+    ///
+    /// * `asset` - 配置中记录的资产信息
+    pub fn with_asset(mut self, asset: Option<AssetRecord>) -> Self {
+        self.asset = asset;
+        self
+    }
+
+    /// 序列化为带缩进的 JSON，用于导出资产记录
+    pub fn to_json_pretty(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("序列化上报内容失败: {}", e))
+    }
+
+    /// 序列化为 JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("序列化上报内容失败: {}", e))
+    }
+}
+
+/// 通过 WinHTTP 发送 JSON，返回 HTTP 状态码
+///
+/// * `endpoint` - 上报地址
+/// * `token` - 认证令牌，为空时不发送 Authorization 头，含控制字符时返回错误
+/// * `body` - JSON 正文
+#[cfg(windows)]
+pub fn post_json(endpoint: &Endpoint, token: &str, body: &str) -> Result<u32, String> {
+    validate_token(token)?;
+    let mut headers = "Content-Type: application/json; charset=utf-8\r\n".to_string();
+    if !token.is_empty() {
+        headers.push_str(&format!("Authorization: Bearer {}\r\n", token));
+    }
+    let (status, _) = send(endpoint, "POST", &headers, body.as_bytes(), 0)?;
+    Ok(status)
+}
+
+/// 通过 WinHTTP 下载文本，返回 HTTP 状态码与响应正文
+///
+/// * `endpoint` - 下载地址
+/// * `max_bytes` - 响应正文的最大长度，超出时返回错误
+#[cfg(windows)]
+pub fn get_text(endpoint: &Endpoint, max_bytes: usize) -> Result<(u32, String), String> {
+    let (status, body) = send(endpoint, "GET", "", &[], max_bytes)?;
+    let body = String::from_utf8(body).map_err(|_| "响应内容不是 UTF-8 文本".to_string())?;
+    Ok((status, body))
+}
+
+/// 发送 HTTP 请求，返回状态码与最多 `max_bytes` 字节的响应正文
+///
+/// * `headers` - 附加请求头，每行以 `\r\n` 结尾，为空时不附加
+/// * `max_bytes` - 为 0 时不读取响应正文
+#[cfg(windows)]
+fn send(
+    endpoint: &Endpoint,
+    verb: &str,
+    headers: &str,
+    body: &[u8],
+    max_bytes: usize,
+) -> Result<(u32, Vec<u8>), String> {
+    use crate::utils::string::wide_str;
+    use windows::core::PCWSTR;
+    use windows::Win32::Networking::WinHttp::{
+        WinHttpCloseHandle, WinHttpConnect, WinHttpOpen, WinHttpOpenRequest,
+        WinHttpQueryDataAvailable, WinHttpQueryHeaders, WinHttpReadData, WinHttpReceiveResponse,
+        WinHttpSendRequest, WinHttpSetTimeouts, WINHTTP_ACCESS_TYPE_AUTOMATIC_PROXY,
+        WINHTTP_FLAG_SECURE, WINHTTP_OPEN_REQUEST_FLAGS, WINHTTP_QUERY_FLAG_NUMBER,
+        WINHTTP_QUERY_STATUS_CODE,
+    };
+
+    let close = |handle: *mut core::ffi::c_void| {
+        let _ = unsafe { WinHttpCloseHandle(handle) };
+    };
+    let agent = wide_str(&format!("{}/{}", crate::NAME, crate::VERSION));
+    let host = wide_str(&endpoint.host);
+    let verb = wide_str(verb);
+    let path = wide_str(&endpoint.path);
+    let headers: Vec<u16> = headers.encode_utf16().collect();
+    let timeout_ms = (AGENT_TIMEOUT_SECS * 1000) as i32;
+
+    unsafe {
+        let session = WinHttpOpen(
+            PCWSTR(agent.as_ptr()),
+            WINHTTP_ACCESS_TYPE_AUTOMATIC_PROXY,
+            PCWSTR::null(),
+            PCWSTR::null(),
+            0,
+        );
+        if session.is_null() {
+            return Err(format!(
+                "初始化 WinHTTP 失败: {}",
+                windows::core::Error::from_thread()
+            ));
+        }
+        let _session = scopeguard::guard(session, close);
+        let _ = WinHttpSetTimeouts(session, timeout_ms, timeout_ms, timeout_ms, timeout_ms);
+
+        let connection = WinHttpConnect(session, PCWSTR(host.as_ptr()), endpoint.port, 0);
+        if connection.is_null() {
+            return Err(format!(
+                "连接 {} 失败: {}",
+                endpoint.host,
+                windows::core::Error::from_thread()
+            ));
+        }
+        let _connection = scopeguard::guard(connection, close);
+
+        let flags = if endpoint.secure {
+            WINHTTP_FLAG_SECURE
+        } else {
+            WINHTTP_OPEN_REQUEST_FLAGS(0)
+        };
+        let request = WinHttpOpenRequest(
+            connection,
+            PCWSTR(verb.as_ptr()),
+            PCWSTR(path.as_ptr()),
+            PCWSTR::null(),
+            PCWSTR::null(),
+            std::ptr::null(),
+            flags,
+        );
+        if request.is_null() {
+            return Err(format!(
+                "创建请求失败: {}",
+                windows::core::Error::from_thread()
+            ));
+        }
+        let _request = scopeguard::guard(request, close);
+
+        WinHttpSendRequest(
+            request,
+            (!headers.is_empty()).then_some(headers.as_slice()),
+            (!body.is_empty()).then(|| body.as_ptr().cast()),
+            body.len() as u32,
+            body.len() as u32,
+            0,
+        )
+        .map_err(|e| format!("发送请求失败: {}", e))?;
+        WinHttpReceiveResponse(request, std::ptr::null_mut())
+            .map_err(|e| format!("接收响应失败: {}", e))?;
+
+        let mut status = 0u32;
+        let mut size = std::mem::size_of::<u32>() as u32;
+        WinHttpQueryHeaders(
+            request,
+            WINHTTP_QUERY_STATUS_CODE | WINHTTP_QUERY_FLAG_NUMBER,
+            PCWSTR::null(),
+            Some(&mut status as *mut u32 as *mut core::ffi::c_void),
+            &mut size,
+            std::ptr::null_mut(),
+        )
+        .map_err(|e| format!("读取响应状态失败: {}", e))?;
+
+        let mut content = Vec::new();
+        while max_bytes > 0 {
+            let mut available = 0u32;
+            WinHttpQueryDataAvailable(request, &mut available)
+                .map_err(|e| format!("读取响应内容失败: {}", e))?;
+            if available == 0 {
+                break;
+            }
+            if content.len() + available as usize > max_bytes {
+                return Err(format!("响应内容超过 {} 字节", max_bytes));
+            }
+            let start = content.len();
+            content.resize(start + available as usize, 0);
+            let mut read = 0u32;
+            WinHttpReadData(
+                request,
+                content[start..].as_mut_ptr().cast(),
+                available,
+                &mut read,
+            )
+            .map_err(|e| format!("读取响应内容失败: {}", e))?;
+            content.truncate(start + read as usize);
+        }
+        Ok((status, content))
+    }
+}
+
+/// 检测一次并上报，服务端返回非 2xx 状态码时视为失败
+///
+/// * `options` - 命令行参数，优先于配置
+/// * `config` - 用户配置（上报地址、令牌、机器标识与启用的检测类别），令牌可由环境变量覆盖
+#[cfg(all(windows, feature = "serialize"))]
+pub fn run(options: &AgentOptions, config: &AppConfig) -> Result<(), String> {
+    let url = options
+        .endpoint
+        .as_deref()
+        .unwrap_or(&config.agent_endpoint);
+    if url.is_empty() {
+        return Err(
+            "未配置上报地址，请使用 --endpoint 或在配置文件中设置 agent_endpoint".to_string(),
+        );
+    }
+    let endpoint = Endpoint::parse(url)?;
+    let token = resolve_token(std::env::var(AGENT_TOKEN_ENV).ok(), &config.agent_token)?;
+
+    let detector = crate::daemon::detect_once(config);
+    let hostname = std::env::var("COMPUTERNAME").unwrap_or_default();
+    let machine_id = machine_id(&config.agent_machine_id, &detector, &hostname);
+    let asset = config
+        .asset(&crate::asset::asset_key(
+            &config.agent_machine_id,
+            &hostname,
+        ))
+        .cloned();
+    let mut report = AgentReport::new(
+        machine_id,
+        hostname,
+        &detector,
+        chrono::Local::now().timestamp(),
+    )
+    .with_asset(asset);
+    if config.privacy_mode {
+        report.detection.mask_sensitive();
+    }
+
+    let status = post_json(&endpoint, &token, &report.to_json()?)?;
+    if !(200..300).contains(&status) {
+        return Err(format!("上报失败，服务端返回 HTTP {}", status));
+    }
+    log::info!(
+        "检测结果已上报到 {}（{}）",
+        endpoint.host,
+        report.machine_id
+    );
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! 购买建议模块
+//!
+//! 根据检测结果给出升级或更换部件的建议，规则见 `advice_rules.toml`
+
+use super::rules::{self, Condition};
+use super::Facts;
+use serde::Deserialize;
+
+/// 内置建议规则
+const BUILTIN_RULES: &str = include_str!("advice_rules.toml");
+
+/// 用户自定义规则文件名（放在程序所在目录）
+pub const USER_RULES_FILE: &str = "advice_rules.toml";
+
+/// 建议级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AdviceLevel {
+    /// 提示
+    #[default]
+    Info,
+    /// 警告
+    Warning,
+}
+
+/// 建议规则
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdviceRule {
+    /// 规则标识
+    pub id: String,
+    /// 建议级别
+    #[serde(default)]
+    pub level: AdviceLevel,
+    /// 建议内容模板
+    pub message: String,
+    /// 购买链接模板
+    #[serde(default)]
+    pub link: Option<String>,
+    /// 触发条件
+    #[serde(default)]
+    pub when: Vec<Condition>,
+}
+
+/// 建议规则集
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AdviceRuleSet {
+    /// 规则列表
+    #[serde(default)]
+    pub rules: Vec<AdviceRule>,
+}
+
+/// 单条建议
+#[derive(Debug, Clone)]
+pub struct Advice {
+    /// 命中的规则标识
+    pub rule_id: String,
+    /// 建议级别
+    pub level: AdviceLevel,
+    /// 建议内容
+    pub message: String,
+    /// 购买链接
+    pub link: Option<String>,
+}
+
+impl AdviceRuleSet {
+    /// 从 TOML 文本解析规则集
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        rules::parse(content)
+    }
+
+    /// 内置规则集
+    pub fn builtin() -> Self {
+        Self::from_toml(BUILTIN_RULES).unwrap_or_else(|e| {
+            log::warn!("{}", e);
+            Self::default()
+        })
+    }
+
+    /// 加载内置规则，并合并程序目录下的用户规则
+    pub fn load() -> Self {
+        let mut rule_set = Self::builtin();
+        if let Some(content) = rules::read_beside_exe(USER_RULES_FILE) {
+            match Self::from_toml(&content) {
+                Ok(user_rules) => rule_set.merge(user_rules),
+                Err(e) => log::warn!("{}: {}", USER_RULES_FILE, e),
+            }
+        }
+        rule_set
+    }
+
+    /// 合并规则集，标识相同的规则以后者为准
+    pub fn merge(&mut self, other: AdviceRuleSet) {
+        for rule in other.rules {
+            match self.rules.iter_mut().find(|r| r.id == rule.id) {
+                Some(existing) => *existing = rule,
+                None => self.rules.push(rule),
+            }
+        }
+    }
+
+    /// 根据事实评估规则，返回命中的建议
+    pub fn evaluate(&self, facts: &Facts) -> Vec<Advice> {
+        self.rules
+            .iter()
+            .filter(|rule| !rule.when.is_empty() && rules::all_match(&rule.when, facts))
+            .map(|rule| Advice {
+                rule_id: rule.id.clone(),
+                level: rule.level,
+                message: facts.render(&rule.message),
+                link: rule.link.as_ref().map(|link| facts.render(link)),
+            })
+            .collect()
+    }
+}
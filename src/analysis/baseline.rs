@@ -0,0 +1,178 @@
+//! 部署基线模块
+//!
+//! IT 管理员可在程序所在目录放置 `expected_config.toml`，描述期望的硬件配置
+//! （处理器型号、最小内存、TPM 版本、硬盘类型）。启动检测完成后与检测结果比较，
+//! 列出所有不符合项，便于批量部署时核验机器。
+
+use super::rules;
+use super::Facts;
+use regex::RegexBuilder;
+use serde::Deserialize;
+
+/// 基线配置文件名（放在程序所在目录）
+pub const BASELINE_FILE: &str = "expected_config.toml";
+
+/// 期望的硬盘类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExpectedDiskType {
+    /// 固态硬盘
+    Ssd,
+    /// 机械硬盘
+    Hdd,
+}
+
+impl ExpectedDiskType {
+    /// 获取硬盘类型名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExpectedDiskType::Ssd => "固态硬盘",
+            ExpectedDiskType::Hdd => "机械硬盘",
+        }
+    }
+}
+
+/// 期望配置，未填写的项不检查
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Baseline {
+    /// 处理器型号正则表达式（不区分大小写），如 "i5-1[23]\\d{2}"
+    #[serde(default)]
+    pub cpu_model: Option<String>,
+    /// 最小内存容量 (GB)
+    #[serde(default)]
+    pub min_memory_gb: Option<f64>,
+    /// 最低 TPM 主版本，如 2
+    #[serde(default)]
+    pub min_tpm_version: Option<u32>,
+    /// 主硬盘类型
+    #[serde(default)]
+    pub disk_type: Option<ExpectedDiskType>,
+}
+
+/// 不符合基线的项
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaselineMismatch {
+    /// 检查项名称
+    pub item: String,
+    /// 期望值
+    pub expected: String,
+    /// 实际值
+    pub actual: String,
+}
+
+impl BaselineMismatch {
+    fn new(item: &str, expected: impl ToString, actual: impl ToString) -> Self {
+        Self {
+            item: item.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }
+    }
+
+    /// 描述，如 "内存: 期望至少 16 GB，实际 8 GB"
+    pub fn description(&self) -> String {
+        format!("{}: 期望{}，实际 {}", self.item, self.expected, self.actual)
+    }
+}
+
+impl Baseline {
+    /// 从 TOML 文本解析基线，同时校验处理器型号正则表达式
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        let baseline: Self = rules::parse(content)?;
+        if let Some(pattern) = &baseline.cpu_model {
+            RegexBuilder::new(pattern)
+                .case_insensitive(true)
+                .build()
+                .map_err(|e| format!("处理器型号正则表达式无效: {}", e))?;
+        }
+        Ok(baseline)
+    }
+
+    /// 加载程序目录下的基线文件，文件不存在时返回 None
+    pub fn load() -> Option<Result<Self, String>> {
+        rules::read_beside_exe(BASELINE_FILE).map(|content| {
+            Self::from_toml(&content).map_err(|e| format!("{}: {}", BASELINE_FILE, e))
+        })
+    }
+
+    /// 与检测结果比较，返回全部不符合项；无法检测的项同样视为不符合
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::analysis::{baseline::Baseline, Facts};
+    /// let baseline = Baseline::from_toml("min_memory_gb = 16").unwrap();
+    /// let mut facts = Facts::default();
+    /// facts.set_number("memory.total_gb", 8.0);
+    /// let mismatches = baseline.check(&facts);
+    /// assert_eq!(mismatches[0].description(), "内存: 期望至少 16 GB，实际 8 GB");
+    /// ```
+    pub fn check(&self, facts: &Facts) -> Vec<BaselineMismatch> {
+        let mut mismatches = Vec::new();
+
+        if let Some(pattern) = &self.cpu_model {
+            let expected = format!("型号匹配 {}", pattern);
+            match facts.texts.get("cpu.name").filter(|name| *name != "未知") {
+                Some(name) => {
+                    let matched = RegexBuilder::new(pattern)
+                        .case_insensitive(true)
+                        .build()
+                        .map(|regex| regex.is_match(name))
+                        .unwrap_or(false);
+                    if !matched {
+                        mismatches.push(BaselineMismatch::new("处理器", expected, name));
+                    }
+                }
+                None => mismatches.push(BaselineMismatch::new("处理器", expected, "未能检测")),
+            }
+        }
+
+        if let Some(min_gb) = self.min_memory_gb {
+            let expected = format!("至少 {} GB", min_gb);
+            match facts.number("memory.total_gb") {
+                Some(total) if total >= min_gb => {}
+                Some(total) => mismatches.push(BaselineMismatch::new(
+                    "内存",
+                    expected,
+                    format!("{} GB", total),
+                )),
+                None => mismatches.push(BaselineMismatch::new("内存", expected, "未能检测")),
+            }
+        }
+
+        if let Some(min_version) = self.min_tpm_version {
+            let expected = format!("不低于 TPM {}.0", min_version);
+            match facts.number("security.tpm_version") {
+                Some(version) if version >= min_version as f64 => {}
+                Some(version) => mismatches.push(BaselineMismatch::new(
+                    "TPM",
+                    expected,
+                    format!("TPM {}.0", version),
+                )),
+                None => mismatches.push(BaselineMismatch::new("TPM", expected, "未检测到 TPM")),
+            }
+        }
+
+        if let Some(disk_type) = self.disk_type {
+            let actual = facts.number("disk.is_hdd").map(|is_hdd| {
+                if is_hdd > 0.5 {
+                    ExpectedDiskType::Hdd
+                } else {
+                    ExpectedDiskType::Ssd
+                }
+            });
+            match actual {
+                Some(actual) if actual == disk_type => {}
+                Some(actual) => mismatches.push(BaselineMismatch::new(
+                    "硬盘",
+                    disk_type.name(),
+                    actual.name(),
+                )),
+                None => {
+                    mismatches.push(BaselineMismatch::new("硬盘", disk_type.name(), "未能检测"))
+                }
+            }
+        }
+
+        mismatches
+    }
+}
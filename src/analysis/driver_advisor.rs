@@ -0,0 +1,334 @@
+//! 驱动更新检查模块
+//!
+//! 按设备的硬件 ID（VEN/DEV/SUBSYS）与当前驱动版本查询驱动规则，找出低于推荐版本的驱动。
+//! 规则由可替换的提供者给出：程序所在目录下的离线规则文件 `driver_rules.toml`，
+//! 或配置中 `driver_rules_url` 指定的在线规则文件（需要启用 `driver-update-check` 特性，经 WinHTTP 下载，
+//! 除回环地址外只接受 https）。两者同时存在时合并，id 相同的规则以离线文件为准；未提供规则时不给出任何建议。
+//!
+//! 规则文件格式：
+//! ```toml
+//! [[rules]]
+//! id = "nvidia-rtx-4060"
+//! vendor = "10de"
+//! device = "2882"          # 可选，省略时匹配该厂商的所有设备
+//! subsys = "51fa1462"      # 可选，SUBSYS_ 后的 8 位十六进制
+//! min_version = "32.0.15.6094"
+//! message = "{device} 驱动 {current} 较旧，建议更新至 {min_version} 及以上"  # 可选
+//! link = "https://www.nvidia.cn/drivers/"                                      # 可选
+//! ```
+
+use super::advice::{Advice, AdviceLevel};
+use super::rules;
+use crate::detector::gpu::Gpu;
+use crate::detector::HardwareDetector;
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+/// 离线规则文件名（放在程序所在目录）
+pub const USER_RULES_FILE: &str = "driver_rules.toml";
+
+/// 未填写 message 时使用的建议模板
+const DEFAULT_MESSAGE: &str = "{device} 的驱动版本 {current} 低于推荐版本 {min_version}，建议更新";
+
+/// 待检查的设备驱动
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceDriver {
+    /// 设备名称
+    pub name: String,
+    /// 厂商 ID，小写十六进制，如 "10de"
+    pub vendor_id: String,
+    /// 设备 ID，小写十六进制
+    pub device_id: String,
+    /// 子系统 ID（SUBSYS_ 后的 8 位十六进制，小写），未知时为 None
+    pub subsys: Option<String>,
+    /// 当前驱动版本
+    pub driver_version: String,
+}
+
+impl DeviceDriver {
+    /// 由 PnP 设备实例 ID 创建，缺少 VEN/DEV 或驱动版本未知时返回 None
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::analysis::driver_advisor::DeviceDriver;
+    /// let device = DeviceDriver::from_pnp_id(
+    ///     "Realtek PCIe GbE Family Controller",
+    ///     "PCI\\VEN_10EC&DEV_8168&SUBSYS_7B861462&REV_15\\01000000684CE00000",
+    ///     "10.68.815.2023",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(device.vendor_id, "10ec");
+    /// assert_eq!(device.device_id, "8168");
+    /// assert_eq!(device.subsys.as_deref(), Some("7b861462"));
+    /// assert!(DeviceDriver::from_pnp_id("蓝牙", "BTH\\MS_BTHPAN\\7&1", "10.0.1").is_none());
+    /// ```
+    pub fn from_pnp_id(name: &str, pnp_device_id: &str, driver_version: &str) -> Option<Self> {
+        if driver_version == "未知" || driver_version.is_empty() {
+            return None;
+        }
+        // 硬件参数形如 "VEN_10EC&DEV_8168&SUBSYS_7B861462&REV_15"
+        let hw_params = pnp_device_id.split('\\').nth(1)?.to_ascii_lowercase();
+        let param = |key: &str| {
+            hw_params
+                .split('&')
+                .find_map(|pair| pair.strip_prefix(key))
+                .map(str::to_string)
+        };
+        let (vendor_id, device_id) = (param("ven_")?, param("dev_")?);
+        let subsys = param("subsys_").filter(|subsys| subsys.len() == 8);
+        Some(Self {
+            name: name.to_string(),
+            vendor_id,
+            device_id,
+            subsys,
+            driver_version: driver_version.to_string(),
+        })
+    }
+}
+
+/// 驱动规则
+#[derive(Debug, Clone, Deserialize)]
+pub struct DriverRule {
+    /// 规则标识
+    pub id: String,
+    /// 厂商 ID
+    pub vendor: String,
+    /// 设备 ID，省略时匹配该厂商的所有设备
+    #[serde(default)]
+    pub device: Option<String>,
+    /// 子系统 ID，省略时匹配所有子系统
+    #[serde(default)]
+    pub subsys: Option<String>,
+    /// 推荐的最低驱动版本
+    pub min_version: String,
+    /// 建议内容模板，支持 {device}、{current}、{min_version}
+    #[serde(default)]
+    pub message: Option<String>,
+    /// 驱动下载链接
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
+impl DriverRule {
+    /// 规则是否适用于设备（ID 不区分大小写）
+    pub fn matches(&self, device: &DeviceDriver) -> bool {
+        let same = |rule: &Option<String>, actual: Option<&str>| match rule {
+            Some(rule) => actual.is_some_and(|actual| rule.eq_ignore_ascii_case(actual)),
+            None => true,
+        };
+        self.vendor.eq_ignore_ascii_case(&device.vendor_id)
+            && same(&self.device, Some(&device.device_id))
+            && same(&self.subsys, device.subsys.as_deref())
+    }
+
+    /// 规则的具体程度，同一设备命中多条规则时以最具体的为准
+    fn specificity(&self) -> u8 {
+        self.device.is_some() as u8 + self.subsys.is_some() as u8 * 2
+    }
+}
+
+/// 驱动规则集
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DriverRuleSet {
+    /// 规则列表
+    #[serde(default)]
+    pub rules: Vec<DriverRule>,
+}
+
+impl DriverRuleSet {
+    /// 从 TOML 文本解析规则集
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        rules::parse(content)
+    }
+
+    /// 合并另一规则集，id 相同的规则以本规则集为准
+    pub fn merge(&mut self, other: DriverRuleSet) {
+        for rule in other.rules {
+            if !self.rules.iter().any(|existing| existing.id == rule.id) {
+                self.rules.push(rule);
+            }
+        }
+    }
+
+    /// 检查设备驱动，返回驱动版本低于规则推荐版本的建议
+    ///
+    /// 每个设备只取最具体的一条规则；驱动版本无法比较时不给出建议
+    pub fn evaluate(&self, devices: &[DeviceDriver]) -> Vec<Advice> {
+        devices
+            .iter()
+            .filter_map(|device| {
+                let rule = self
+                    .rules
+                    .iter()
+                    .filter(|rule| rule.matches(device))
+                    .max_by_key(|rule| rule.specificity())?;
+                let outdated =
+                    compare_versions(&device.driver_version, &rule.min_version)? == Ordering::Less;
+                outdated.then(|| Advice {
+                    rule_id: rule.id.clone(),
+                    level: AdviceLevel::Warning,
+                    message: rule
+                        .message
+                        .as_deref()
+                        .unwrap_or(DEFAULT_MESSAGE)
+                        .replace("{device}", &device.name)
+                        .replace("{current}", &device.driver_version)
+                        .replace("{min_version}", &rule.min_version),
+                    link: rule.link.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 驱动规则提供者
+pub trait DriverProvider {
+    /// 获取驱动规则
+    fn rules(&self) -> Result<DriverRuleSet, String>;
+}
+
+/// 离线规则文件提供者
+#[derive(Debug, Clone)]
+pub struct OfflineProvider {
+    /// 规则文件路径
+    pub path: PathBuf,
+}
+
+impl OfflineProvider {
+    /// 程序所在目录下的 `driver_rules.toml`
+    pub fn beside_exe() -> Option<Self> {
+        let exe = std::env::current_exe().ok()?;
+        Some(Self {
+            path: exe.parent()?.join(USER_RULES_FILE),
+        })
+    }
+}
+
+impl DriverProvider for OfflineProvider {
+    /// 读取规则文件，文件不存在时返回空规则集
+    fn rules(&self) -> Result<DriverRuleSet, String> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(content) => DriverRuleSet::from_toml(&content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(DriverRuleSet::default()),
+            Err(e) => Err(format!("读取 {} 失败: {}", self.path.display(), e)),
+        }
+    }
+}
+
+/// 在线规则提供者，通过 WinHTTP 从用户提供的地址下载规则文件
+#[cfg(all(windows, feature = "driver-update-check"))]
+#[derive(Debug, Clone)]
+pub struct UrlProvider {
+    /// 规则文件地址，除回环地址外只接受 https
+    pub url: String,
+}
+
+#[cfg(all(windows, feature = "driver-update-check"))]
+impl DriverProvider for UrlProvider {
+    /// 下载并解析规则文件，服务端返回非 2xx 状态码时返回错误
+    fn rules(&self) -> Result<DriverRuleSet, String> {
+        use crate::constants::DRIVER_RULES_MAX_BYTES;
+
+        let endpoint = crate::agent::Endpoint::parse(&self.url)?;
+        let (status, content) = crate::agent::get_text(&endpoint, DRIVER_RULES_MAX_BYTES)
+            .map_err(|e| format!("下载驱动规则失败: {}", e))?;
+        if !(200..300).contains(&status) {
+            return Err(format!("下载驱动规则失败，服务端返回 HTTP {}", status));
+        }
+        DriverRuleSet::from_toml(&content)
+    }
+}
+
+/// 比较两个点分驱动版本号，含非数字段时返回 None
+///
+/// 段数不同时缺少的段按 0 处理
+///
+/// 示例
+/// ```
+/// use hardware_master::analysis::driver_advisor::compare_versions;
+/// use std::cmp::Ordering;
+/// assert_eq!(compare_versions("31.0.15.5222", "32.0.15.6094"), Some(Ordering::Less));
+/// assert_eq!(compare_versions("10.68.815.2023", "10.68.815"), Some(Ordering::Greater));
+/// assert_eq!(compare_versions("1.2", "1.2.0.0"), Some(Ordering::Equal));
+/// assert_eq!(compare_versions("未知", "1.0"), None);
+/// ```
+pub fn compare_versions(left: &str, right: &str) -> Option<Ordering> {
+    let parse = |version: &str| -> Option<Vec<u64>> {
+        version
+            .trim()
+            .split('.')
+            .map(|part| part.parse::<u64>().ok())
+            .collect()
+    };
+    let (left, right) = (parse(left)?, parse(right)?);
+    let len = left.len().max(right.len());
+    let part = |parts: &[u64], i: usize| parts.get(i).copied().unwrap_or(0);
+    Some(
+        (0..len)
+            .map(|i| part(&left, i).cmp(&part(&right, i)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal),
+    )
+}
+
+/// 收集检测结果中带硬件 ID 与驱动版本的设备（显卡与网卡）
+pub fn collect_devices(detector: &HardwareDetector) -> Vec<DeviceDriver> {
+    let gpus = detector.gpu_info.gpus.iter().filter_map(gpu_device);
+    let adapters = detector.network_info.adapters.iter().filter_map(|adapter| {
+        DeviceDriver::from_pnp_id(
+            &adapter.name,
+            &adapter.pnp_device_id,
+            &adapter.driver_version,
+        )
+    });
+    gpus.chain(adapters).collect()
+}
+
+/// 显卡的驱动信息
+///
+/// 优先从设备实例 ID 读取，带上子系统 ID 以便匹配按板卡区分的规则；
+/// 未找到设备实例时退回 DXGI 报告的厂商与设备 ID
+fn gpu_device(gpu: &Gpu) -> Option<DeviceDriver> {
+    if let Some(device) =
+        DeviceDriver::from_pnp_id(&gpu.description, &gpu.instance_id, &gpu.driver_version)
+    {
+        return Some(device);
+    }
+    let known = |value: &str| value != "未知" && !value.is_empty();
+    (known(&gpu.vendor_id) && known(&gpu.device_id) && known(&gpu.driver_version)).then(|| {
+        DeviceDriver {
+            name: gpu.description.clone(),
+            vendor_id: gpu.vendor_id.to_ascii_lowercase(),
+            device_id: gpu.device_id.to_ascii_lowercase(),
+            subsys: None,
+            driver_version: gpu.driver_version.clone(),
+        }
+    })
+}
+
+/// 使用指定的规则提供者检查驱动
+pub fn check_with(
+    detector: &HardwareDetector,
+    provider: &dyn DriverProvider,
+) -> Result<Vec<Advice>, String> {
+    Ok(provider.rules()?.evaluate(&collect_devices(detector)))
+}
+
+/// 使用程序目录下的离线规则文件检查驱动，规则文件不存在或无法读取时只使用在线规则
+///
+/// * `online` - 已下载的在线规则，未配置或尚未下载时为 None
+pub fn check(detector: &HardwareDetector, online: Option<&DriverRuleSet>) -> Vec<Advice> {
+    let mut rules = OfflineProvider::beside_exe()
+        .map(|provider| {
+            provider.rules().unwrap_or_else(|e| {
+                log::warn!("{}", e);
+                DriverRuleSet::default()
+            })
+        })
+        .unwrap_or_default();
+    if let Some(online) = online {
+        rules.merge(online.clone());
+    }
+    rules.evaluate(&collect_devices(detector))
+}
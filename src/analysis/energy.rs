@@ -0,0 +1,232 @@
+//! 功耗估算模块
+//!
+//! 使用电池供电时以电池放电功率为准；接通电源时按各部件的典型功耗粗略估算。
+//! 部件功耗数据见 `energy_profile.toml`。
+
+use super::rules;
+use crate::constants::ENERGY_HOURS_PER_DAY;
+use crate::detector::{battery::Battery, disk::DiskType, gpu::GpuType, HardwareDetector};
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use std::fmt;
+
+/// 内置功耗数据
+const BUILTIN_PROFILE: &str = include_str!("energy_profile.toml");
+
+/// 用户功耗数据文件名（放在程序所在目录）
+pub const USER_PROFILE_FILE: &str = "energy_profile.toml";
+
+/// 功耗数据来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSource {
+    /// 电池放电功率
+    BatteryDischarge,
+    /// 部件典型功耗估算
+    ComponentEstimate,
+}
+
+impl fmt::Display for PowerSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PowerSource::BatteryDischarge => "电池放电",
+            PowerSource::ComponentEstimate => "部件估算",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 功耗估算结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerEstimate {
+    /// 功率 (W)
+    pub watts: f64,
+    /// 数据来源
+    pub source: PowerSource,
+}
+
+impl PowerEstimate {
+    /// 每天耗电量 (kWh)
+    ///
+    /// * `hours` - 每天使用时长（小时）
+    pub fn daily_kwh(&self, hours: f64) -> f64 {
+        self.watts * hours / 1000.0
+    }
+
+    /// 显示文本，如 "约 35 W（部件估算），每天约 0.28 kWh"
+    pub fn summary(&self) -> String {
+        format!(
+            "约 {:.0} W（{}），每天约 {:.2} kWh",
+            self.watts,
+            self.source,
+            self.daily_kwh(ENERGY_HOURS_PER_DAY)
+        )
+    }
+}
+
+/// 平台基础功耗参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct PlatformPower {
+    /// 台式机平台基础功耗 (W)
+    pub desktop_w: f64,
+    /// 笔记本平台基础功耗 (W)
+    pub laptop_w: f64,
+    /// 每条内存功耗 (W)
+    pub memory_module_w: f64,
+    /// 固态硬盘功耗 (W)
+    pub ssd_w: f64,
+    /// 机械硬盘功耗 (W)
+    pub hdd_w: f64,
+    /// 处理器与显卡的平均负载比例
+    pub load_factor: f64,
+    /// 未匹配时的台式机处理器功耗 (W)
+    pub default_desktop_cpu_w: f64,
+    /// 未匹配时的笔记本处理器功耗 (W)
+    pub default_laptop_cpu_w: f64,
+    /// 未匹配时的独立显卡功耗 (W)
+    pub default_gpu_w: f64,
+    /// 笔记本独立显卡相对桌面版的功耗比例
+    pub laptop_gpu_factor: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TdpEntryFile {
+    pattern: String,
+    tdp_w: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnergyProfileFile {
+    platform: PlatformPower,
+    #[serde(default)]
+    cpu: Vec<TdpEntryFile>,
+    #[serde(default)]
+    gpu: Vec<TdpEntryFile>,
+}
+
+/// 部件功耗数据
+#[derive(Debug, Clone)]
+pub struct EnergyProfile {
+    /// 平台基础功耗参数
+    pub platform: PlatformPower,
+    cpu: Vec<(Regex, f64)>,
+    gpu: Vec<(Regex, f64)>,
+}
+
+impl EnergyProfile {
+    /// 从 TOML 文本解析功耗数据
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        let file: EnergyProfileFile = rules::parse(content)?;
+        Ok(Self {
+            platform: file.platform,
+            cpu: Self::compile(file.cpu)?,
+            gpu: Self::compile(file.gpu)?,
+        })
+    }
+
+    fn compile(entries: Vec<TdpEntryFile>) -> Result<Vec<(Regex, f64)>, String> {
+        entries
+            .into_iter()
+            .map(|entry| {
+                RegexBuilder::new(&entry.pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map(|regex| (regex, entry.tdp_w))
+                    .map_err(|e| format!("无效的匹配规则 {:?}: {}", entry.pattern, e))
+            })
+            .collect()
+    }
+
+    /// 内置功耗数据
+    pub fn builtin() -> Self {
+        Self::from_toml(BUILTIN_PROFILE).expect("内置功耗数据无效")
+    }
+
+    /// 加载功耗数据，程序目录下存在用户数据时优先使用
+    pub fn load() -> Self {
+        if let Some(content) = rules::read_beside_exe(USER_PROFILE_FILE) {
+            match Self::from_toml(&content) {
+                Ok(profile) => return profile,
+                Err(e) => log::warn!("{}: {}", USER_PROFILE_FILE, e),
+            }
+        }
+        Self::builtin()
+    }
+
+    /// 处理器典型功耗 (W)
+    pub fn cpu_tdp(&self, name: &str, is_laptop: bool) -> f64 {
+        Self::find(&self.cpu, name).unwrap_or(if is_laptop {
+            self.platform.default_laptop_cpu_w
+        } else {
+            self.platform.default_desktop_cpu_w
+        })
+    }
+
+    /// 独立显卡典型功耗 (W)
+    pub fn gpu_tdp(&self, name: &str, is_laptop: bool) -> f64 {
+        let tdp = Self::find(&self.gpu, name).unwrap_or(self.platform.default_gpu_w);
+        if is_laptop {
+            tdp * self.platform.laptop_gpu_factor
+        } else {
+            tdp
+        }
+    }
+
+    fn find(entries: &[(Regex, f64)], name: &str) -> Option<f64> {
+        entries
+            .iter()
+            .find(|(regex, _)| regex.is_match(name))
+            .map(|(_, tdp)| *tdp)
+    }
+
+    /// 估算整机功耗
+    pub fn estimate(&self, detector: &HardwareDetector) -> PowerEstimate {
+        if let Some(watts) = battery_discharge_watts(&detector.battery_info.batteries) {
+            return PowerEstimate {
+                watts,
+                source: PowerSource::BatteryDischarge,
+            };
+        }
+
+        let platform = &self.platform;
+        let is_laptop = detector.system_info.computer_type.is_portable();
+
+        let mut watts = if is_laptop {
+            platform.laptop_w
+        } else {
+            platform.desktop_w
+        };
+        watts += platform.memory_module_w * detector.memory_info.slots.len() as f64;
+        watts += match detector.disk_info.disk_type {
+            DiskType::HDD => platform.hdd_w,
+            DiskType::SSD | DiskType::Unknown => platform.ssd_w,
+        };
+
+        let mut load = self.cpu_tdp(&detector.cpu_info.name, is_laptop);
+        for gpu in detector.gpu_info.gpus.iter() {
+            if gpu.gpu_type == GpuType::DiscreteGpu {
+                load += self.gpu_tdp(&gpu.description, is_laptop);
+            }
+        }
+        watts += load * platform.load_factor;
+
+        PowerEstimate {
+            watts,
+            source: PowerSource::ComponentEstimate,
+        }
+    }
+}
+
+/// 电池放电总功率 (W)，接通电源或功率未知时返回 None
+pub fn battery_discharge_watts(batteries: &[Battery]) -> Option<f64> {
+    let discharging: Vec<i32> = batteries
+        .iter()
+        .filter(|battery| !battery.power_online)
+        .filter_map(|battery| battery.rate)
+        .filter(|rate| *rate < 0)
+        .collect();
+
+    if discharging.is_empty() {
+        return None;
+    }
+    Some(discharging.iter().map(|rate| -(*rate as f64)).sum::<f64>() / 1000.0)
+}
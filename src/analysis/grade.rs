@@ -0,0 +1,271 @@
+//! 综合评级模块
+//!
+//! 按处理器发布年份与核心数、显卡档次、内存容量以及是否使用固态硬盘为整机打分，
+//! 再按分数线给出入门、主流、高端、旗舰四档评级。打分规则见 `grade_rules.toml`。
+
+use super::rules::{self, Condition};
+use super::{collect_facts, Facts};
+use crate::detector::HardwareDetector;
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use std::fmt;
+
+/// 内置评级规则
+const BUILTIN_RULES: &str = include_str!("grade_rules.toml");
+
+/// 用户评级规则文件名（放在程序所在目录）
+pub const USER_RULES_FILE: &str = "grade_rules.toml";
+
+/// 整机档次
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Tier {
+    /// 入门
+    Entry,
+    /// 主流
+    Mainstream,
+    /// 高端
+    HighEnd,
+    /// 旗舰
+    Flagship,
+}
+
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Tier::Entry => "入门",
+            Tier::Mainstream => "主流",
+            Tier::HighEnd => "高端",
+            Tier::Flagship => "旗舰",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 各档次的最低分数
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct TierThresholds {
+    /// 主流
+    pub mainstream: f64,
+    /// 高端
+    pub high_end: f64,
+    /// 旗舰
+    pub flagship: f64,
+}
+
+impl TierThresholds {
+    /// 分数对应的档次
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::analysis::grade::{Tier, TierThresholds};
+    /// let thresholds = TierThresholds { mainstream: 40.0, high_end: 65.0, flagship: 85.0 };
+    /// assert_eq!(thresholds.tier(39.0), Tier::Entry);
+    /// assert_eq!(thresholds.tier(65.0), Tier::HighEnd);
+    /// assert_eq!(thresholds.tier(100.0), Tier::Flagship);
+    /// ```
+    pub fn tier(&self, score: f64) -> Tier {
+        if score >= self.flagship {
+            Tier::Flagship
+        } else if score >= self.high_end {
+            Tier::HighEnd
+        } else if score >= self.mainstream {
+            Tier::Mainstream
+        } else {
+            Tier::Entry
+        }
+    }
+}
+
+/// 部件得分规则
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScoreRule {
+    /// 部件名称，如 "处理器"
+    pub component: String,
+    /// 得分
+    pub points: f64,
+    /// 触发条件
+    #[serde(default)]
+    pub when: Vec<Condition>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CpuYearFile {
+    pattern: String,
+    year: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct GpuClassFile {
+    pattern: String,
+    class: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct GradeRulesFile {
+    tiers: TierThresholds,
+    #[serde(default)]
+    cpu_year: Vec<CpuYearFile>,
+    #[serde(default)]
+    gpu_class: Vec<GpuClassFile>,
+    #[serde(default)]
+    score: Vec<ScoreRule>,
+}
+
+/// 单个部件的得分
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentScore {
+    /// 部件名称
+    pub component: String,
+    /// 得分
+    pub points: f64,
+}
+
+/// 综合评级结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grade {
+    /// 档次
+    pub tier: Tier,
+    /// 总分
+    pub score: f64,
+    /// 各部件得分，按规则文件中首次出现的顺序排列
+    pub components: Vec<ComponentScore>,
+}
+
+impl Grade {
+    /// 横幅文本，如 "综合评级: 高端 (71 分)"
+    pub fn summary(&self) -> String {
+        format!("综合评级: {} ({:.0} 分)", self.tier, self.score)
+    }
+
+    /// 各部件得分明细，如 "处理器 28 / 显卡 18 / 内存 10 / 硬盘 15"
+    pub fn breakdown(&self) -> String {
+        self.components
+            .iter()
+            .map(|item| format!("{} {:.0}", item.component, item.points))
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+/// 综合评级规则
+#[derive(Debug, Clone)]
+pub struct GradeRules {
+    /// 各档次的最低分数
+    pub thresholds: TierThresholds,
+    /// 部件得分规则
+    pub scores: Vec<ScoreRule>,
+    cpu_years: Vec<(Regex, u32)>,
+    gpu_classes: Vec<(Regex, u32)>,
+}
+
+impl GradeRules {
+    /// 从 TOML 文本解析评级规则
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        let file: GradeRulesFile = rules::parse(content)?;
+        Ok(Self {
+            thresholds: file.tiers,
+            scores: file.score,
+            cpu_years: Self::compile(file.cpu_year.into_iter().map(|e| (e.pattern, e.year)))?,
+            gpu_classes: Self::compile(file.gpu_class.into_iter().map(|e| (e.pattern, e.class)))?,
+        })
+    }
+
+    fn compile(entries: impl Iterator<Item = (String, u32)>) -> Result<Vec<(Regex, u32)>, String> {
+        entries
+            .map(|(pattern, value)| {
+                RegexBuilder::new(&pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map(|regex| (regex, value))
+                    .map_err(|e| format!("无效的匹配规则 {:?}: {}", pattern, e))
+            })
+            .collect()
+    }
+
+    /// 内置评级规则
+    pub fn builtin() -> Self {
+        Self::from_toml(BUILTIN_RULES).expect("内置评级规则无效")
+    }
+
+    /// 加载评级规则，程序目录下存在用户规则时优先使用
+    pub fn load() -> Self {
+        if let Some(content) = rules::read_beside_exe(USER_RULES_FILE) {
+            match Self::from_toml(&content) {
+                Ok(rules) => return rules,
+                Err(e) => log::warn!("{}: {}", USER_RULES_FILE, e),
+            }
+        }
+        Self::builtin()
+    }
+
+    fn find(entries: &[(Regex, u32)], name: &str) -> Option<u32> {
+        entries
+            .iter()
+            .find(|(regex, _)| regex.is_match(name))
+            .map(|(_, value)| *value)
+    }
+
+    /// 处理器发布年份，未匹配时返回 None
+    pub fn cpu_release_year(&self, name: &str) -> Option<u32> {
+        Self::find(&self.cpu_years, name)
+    }
+
+    /// 显卡档次（1 入门 ~ 4 旗舰），未匹配时为 0
+    pub fn gpu_class(&self, name: &str) -> u32 {
+        Self::find(&self.gpu_classes, name).unwrap_or(0)
+    }
+
+    /// 在通用事实的基础上补充处理器发布年份与显卡档次（取所有显卡中最高的档次）
+    pub fn facts(&self, detector: &HardwareDetector) -> Facts {
+        let mut facts = collect_facts(detector);
+        if let Some(year) = self.cpu_release_year(&detector.cpu_info.name) {
+            facts.set_number("cpu.release_year", year as f64);
+        }
+        let gpu_class = detector
+            .gpu_info
+            .gpus
+            .iter()
+            .map(|gpu| self.gpu_class(&gpu.description))
+            .max()
+            .unwrap_or(0);
+        facts.set_number("gpu.class", gpu_class as f64);
+        facts
+    }
+
+    /// 根据事实打分评级，每个部件取第一条满足条件的规则，没有满足的规则时该部件记 0 分
+    pub fn evaluate(&self, facts: &Facts) -> Grade {
+        let mut components: Vec<ComponentScore> = Vec::new();
+        for rule in self.scores.iter() {
+            if components
+                .iter()
+                .any(|item| item.component == rule.component)
+            {
+                continue;
+            }
+            let points = self
+                .scores
+                .iter()
+                .filter(|candidate| candidate.component == rule.component)
+                .find(|candidate| {
+                    !candidate.when.is_empty() && rules::all_match(&candidate.when, facts)
+                })
+                .map_or(0.0, |candidate| candidate.points);
+            components.push(ComponentScore {
+                component: rule.component.clone(),
+                points,
+            });
+        }
+
+        let score = components.iter().map(|item| item.points).sum();
+        Grade {
+            tier: self.thresholds.tier(score),
+            score,
+            components,
+        }
+    }
+
+    /// 为检测结果评级
+    pub fn grade(&self, detector: &HardwareDetector) -> Grade {
+        self.evaluate(&self.facts(detector))
+    }
+}
@@ -0,0 +1,174 @@
+//! 验机模块
+//!
+//! 针对二手笔记本交易，对硬盘通电时间、电池循环、SMART 错误、序列号变化、
+//! 虚拟机等高风险项逐条核对，生成通过/警告清单。检查项见 `inspection_rules.toml`
+
+use super::rules::{self, Condition};
+use super::Facts;
+use crate::history::Snapshot;
+use serde::Deserialize;
+use std::fmt;
+
+/// 内置检查项
+const BUILTIN_CHECKS: &str = include_str!("inspection_rules.toml");
+
+/// 用户自定义检查项文件名（放在程序所在目录）
+pub const USER_CHECKS_FILE: &str = "inspection_rules.toml";
+
+/// 检查项定义
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRule {
+    /// 检查项标识
+    pub id: String,
+    /// 检查项名称
+    pub name: String,
+    /// 判断检查项能否执行的事实，缺失时结果为未知
+    pub fact: String,
+    /// 检查详情模板
+    pub detail: String,
+    /// 警告条件，全部满足时判定为警告
+    #[serde(default)]
+    pub warn_when: Vec<Condition>,
+    /// 警告时的说明
+    #[serde(default)]
+    pub advice: Option<String>,
+}
+
+/// 检查项集合
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CheckRuleSet {
+    /// 检查项列表
+    #[serde(default)]
+    pub checks: Vec<CheckRule>,
+}
+
+/// 检查结果状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// 通过
+    Pass,
+    /// 警告
+    Warn,
+    /// 无法检测
+    Unknown,
+}
+
+impl CheckStatus {
+    /// 状态图标
+    pub fn icon(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "✔",
+            CheckStatus::Warn => "⚠",
+            CheckStatus::Unknown => "?",
+        }
+    }
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CheckStatus::Pass => "通过",
+            CheckStatus::Warn => "警告",
+            CheckStatus::Unknown => "未知",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 单个检查结果
+#[derive(Debug, Clone)]
+pub struct CheckItem {
+    /// 检查项标识
+    pub id: String,
+    /// 检查项名称
+    pub name: String,
+    /// 检查结果
+    pub status: CheckStatus,
+    /// 检查详情
+    pub detail: String,
+    /// 警告时的说明
+    pub advice: Option<String>,
+}
+
+impl CheckRuleSet {
+    /// 从 TOML 文本解析检查项
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        rules::parse(content)
+    }
+
+    /// 内置检查项
+    pub fn builtin() -> Self {
+        Self::from_toml(BUILTIN_CHECKS).unwrap_or_else(|e| {
+            log::warn!("{}", e);
+            Self::default()
+        })
+    }
+
+    /// 加载内置检查项，并合并程序目录下的用户检查项
+    pub fn load() -> Self {
+        let mut check_set = Self::builtin();
+        if let Some(content) = rules::read_beside_exe(USER_CHECKS_FILE) {
+            match Self::from_toml(&content) {
+                Ok(user_checks) => check_set.merge(user_checks),
+                Err(e) => log::warn!("{}: {}", USER_CHECKS_FILE, e),
+            }
+        }
+        check_set
+    }
+
+    /// 合并检查项，标识相同的检查项以后者为准
+    pub fn merge(&mut self, other: CheckRuleSet) {
+        for check in other.checks {
+            match self.checks.iter_mut().find(|c| c.id == check.id) {
+                Some(existing) => *existing = check,
+                None => self.checks.push(check),
+            }
+        }
+    }
+
+    /// 根据事实逐项检查，结果顺序与检查项定义一致
+    pub fn evaluate(&self, facts: &Facts) -> Vec<CheckItem> {
+        self.checks
+            .iter()
+            .map(|check| {
+                let (status, detail) = if facts.number(&check.fact).is_none() {
+                    (CheckStatus::Unknown, "未能检测".to_string())
+                } else if !check.warn_when.is_empty() && rules::all_match(&check.warn_when, facts) {
+                    (CheckStatus::Warn, facts.render(&check.detail))
+                } else {
+                    (CheckStatus::Pass, facts.render(&check.detail))
+                };
+                let advice = match status {
+                    CheckStatus::Warn => check.advice.as_ref().map(|a| facts.render(a)),
+                    _ => None,
+                };
+                CheckItem {
+                    id: check.id.clone(),
+                    name: check.name.clone(),
+                    status,
+                    detail,
+                    advice,
+                }
+            })
+            .collect()
+    }
+}
+
+/// 与上一次检测的快照比较序列号，写入 `serial.system_changed` 与 `serial.disk_changed`
+///
+/// 任意一方序列号未知时不写入对应事实
+///
+/// * `facts` - 事实集合
+/// * `current` - 本次检测的快照
+/// * `previous` - 上一次检测的快照
+pub fn add_serial_facts(facts: &mut Facts, current: &Snapshot, previous: Option<&Snapshot>) {
+    let Some(previous) = previous else {
+        return;
+    };
+    if let (Some(now), Some(before)) = (&current.system_serial, &previous.system_serial) {
+        facts.set_bool("serial.system_changed", now != before);
+    }
+    if let (Some(now), Some(before)) = (&current.disk_serial, &previous.disk_serial) {
+        facts.set_bool("serial.disk_changed", now != before);
+    }
+}
@@ -0,0 +1,230 @@
+//! 配置分析模块
+//!
+//! 基于检测结果进行离线分析。分析逻辑由数据文件描述的规则驱动，
+//! 无需修改代码即可扩展。
+
+pub mod advice;
+pub mod baseline;
+pub mod driver_advisor;
+pub mod energy;
+pub mod grade;
+pub mod inspection;
+pub mod rules;
+pub mod upgrade;
+
+use crate::detector::{
+    disk::DiskType,
+    gpu::GpuType,
+    memory::{ErrorCorrection, MemoryProfile},
+    volume, HardwareDetector,
+};
+use crate::utils::string::mask_sensitive;
+use std::collections::BTreeMap;
+
+/// 含序列号等敏感信息的文本事实，隐私模式下遮盖
+pub const SENSITIVE_FACTS: &[&str] = &["system.serial_number", "disk.serial_number"];
+
+/// 从检测结果中提取的事实集合
+///
+/// 数值事实用于规则条件判断，文本事实用于消息模板替换
+#[derive(Debug, Clone, Default)]
+pub struct Facts {
+    /// 数值事实，布尔值以 1.0 / 0.0 表示
+    pub numbers: BTreeMap<String, f64>,
+    /// 文本事实
+    pub texts: BTreeMap<String, String>,
+}
+
+impl Facts {
+    /// 设置数值事实
+    pub fn set_number(&mut self, key: &str, value: f64) {
+        self.numbers.insert(key.to_string(), value);
+    }
+
+    /// 设置布尔事实
+    pub fn set_bool(&mut self, key: &str, value: bool) {
+        self.set_number(key, if value { 1.0 } else { 0.0 });
+    }
+
+    /// 设置文本事实
+    pub fn set_text(&mut self, key: &str, value: impl ToString) {
+        self.texts.insert(key.to_string(), value.to_string());
+    }
+
+    /// 遮盖序列号等敏感的文本事实，规则条件只比较数值事实，不受影响
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::analysis::Facts;
+    /// let mut facts = Facts::default();
+    /// facts.set_text("system.serial_number", "PF2ABCDE");
+    /// facts.mask_sensitive();
+    /// assert_eq!(facts.render("序列号 {system.serial_number}"), "序列号 PF****DE");
+    /// ```
+    pub fn mask_sensitive(&mut self) {
+        for key in SENSITIVE_FACTS {
+            if let Some(value) = self.texts.get_mut(*key) {
+                *value = mask_sensitive(value);
+            }
+        }
+    }
+
+    /// 获取数值事实
+    pub fn number(&self, key: &str) -> Option<f64> {
+        self.numbers.get(key).copied()
+    }
+
+    /// 将模板中的 `{key}` 替换为对应事实
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::analysis::Facts;
+    /// let mut facts = Facts::default();
+    /// facts.set_text("memory.type", "DDR4");
+    /// facts.set_number("memory.total_gb", 8.0);
+    /// assert_eq!(facts.render("{memory.type} {memory.total_gb}GB"), "DDR4 8GB");
+    /// ```
+    pub fn render(&self, template: &str) -> String {
+        let mut result = template.to_string();
+        for (key, value) in self.texts.iter() {
+            result = result.replace(&format!("{{{}}}", key), value);
+        }
+        for (key, value) in self.numbers.iter() {
+            result = result.replace(&format!("{{{}}}", key), &format!("{}", value));
+        }
+        result
+    }
+}
+
+/// 从硬件检测器中收集事实
+pub fn collect_facts(detector: &HardwareDetector) -> Facts {
+    let mut facts = Facts::default();
+
+    // 系统
+    let system = &detector.system_info;
+    facts.set_text("system.manufacturer", &system.system_manufacturer);
+    facts.set_text("system.model", &system.computer_model);
+    facts.set_text("system.serial_number", &system.serial_number);
+    if system.system_manufacturer != "未知" {
+        facts.set_bool("system.is_vm", system.is_virtual_machine());
+    }
+
+    // 处理器
+    facts.set_text("cpu.name", &detector.cpu_info.name);
+    facts.set_number(
+        "cpu.physical_cores",
+        detector.cpu_info.physical_cores as f64,
+    );
+    facts.set_number("cpu.logical_cores", detector.cpu_info.logical_cores as f64);
+    facts.set_number(
+        "cpu.efficiency_cores",
+        detector.cpu_info.efficiency_cores as f64,
+    );
+    facts.set_number(
+        "cpu.base_frequency_mhz",
+        detector.cpu_info.base_frequency as f64,
+    );
+    facts.set_number(
+        "cpu.max_frequency_mhz",
+        detector.cpu_info.max_frequency as f64,
+    );
+
+    // 内存
+    let memory = &detector.memory_info;
+    if !memory.slots.is_empty() {
+        facts.set_number("memory.total_gb", memory.total_memory.as_gb().round());
+        facts.set_number("memory.module_count", memory.slots.len() as f64);
+        if let Some(channels) = memory.channel_mode.count() {
+            facts.set_number("memory.channel_count", channels as f64);
+        }
+        if let Some(empty) = memory.empty_slots() {
+            facts.set_number("memory.empty_slots", empty as f64);
+        }
+        if memory.error_correction != ErrorCorrection::Unknown {
+            facts.set_bool("memory.ecc", memory.error_correction.is_ecc());
+        }
+        let first = &memory.slots[0];
+        let same_spec = memory
+            .slots
+            .iter()
+            .all(|slot| slot.capacity == first.capacity && slot.frequency == first.frequency);
+        facts.set_bool("memory.same_spec", same_spec);
+        facts.set_text("memory.type", &first.memory_type);
+        facts.set_number("memory.frequency_mhz", first.frequency as f64);
+        if first.profile != MemoryProfile::Unknown {
+            let profile_disabled = memory
+                .slots
+                .iter()
+                .any(|slot| slot.profile == MemoryProfile::Available);
+            facts.set_bool("memory.profile_disabled", profile_disabled);
+        }
+        facts.set_number("memory.module_gb", first.capacity.as_gb().round());
+    }
+
+    // 硬盘
+    let disk = &detector.disk_info;
+    match disk.disk_type {
+        DiskType::SSD => facts.set_bool("disk.is_hdd", false),
+        DiskType::HDD => facts.set_bool("disk.is_hdd", true),
+        DiskType::Unknown => {}
+    }
+    if let Some(system_disk) = disk.system_disk(&volume::system_drive()) {
+        match system_disk.disk_type {
+            DiskType::SSD => facts.set_bool("disk.system_is_hdd", false),
+            DiskType::HDD => facts.set_bool("disk.system_is_hdd", true),
+            DiskType::Unknown => {}
+        }
+    }
+    if !disk.total_capacity.is_zero() {
+        facts.set_number("disk.capacity_gb", disk.total_capacity.as_gb().round());
+    }
+    if let Some(wear) = disk.wear_percent {
+        facts.set_number("disk.wear_percent", wear);
+    }
+    if let Some(temperature) = disk.temperature {
+        facts.set_number("disk.temperature", temperature);
+    }
+    if let Some(hours) = disk.power_on_hours {
+        facts.set_number("disk.power_on_hours", hours as f64);
+    }
+    if let Some(errors) = disk.uncorrected_errors {
+        facts.set_number("disk.uncorrected_errors", errors as f64);
+    }
+    facts.set_text("disk.serial_number", &disk.serial_number);
+
+    // 显卡
+    let discrete: Vec<_> = detector
+        .gpu_info
+        .gpus
+        .iter()
+        .filter(|gpu| gpu.gpu_type == GpuType::DiscreteGpu)
+        .collect();
+    facts.set_number("gpu.discrete_count", discrete.len() as f64);
+    if let Some(gpu) = discrete.first() {
+        facts.set_text("gpu.name", &gpu.description);
+        facts.set_number("gpu.vram_gb", gpu.dedicated_vram.as_gb().round());
+    }
+
+    // 显示器
+    if let Some(max_refresh) = detector.monitor_info.refresh_rates().last() {
+        facts.set_number("monitor.max_refresh_hz", *max_refresh as f64);
+    }
+
+    // 电池
+    let batteries = &detector.battery_info.batteries;
+    facts.set_number("battery.count", batteries.len() as f64);
+    if let Some(min_health) = batteries.iter().map(|b| b.health).reduce(f64::min) {
+        facts.set_number("battery.health", min_health.round());
+    }
+    if let Some(max_cycles) = batteries.iter().map(|b| b.cycle_count).max() {
+        facts.set_number("battery.cycle_count", max_cycles as f64);
+    }
+
+    // 安全特性
+    let security = &detector.security_info;
+    if let Some(major) = security.tpm.as_ref().and_then(|tpm| tpm.major_version()) {
+        facts.set_number("security.tpm_version", major as f64);
+    }
+
+    facts
+}
@@ -0,0 +1,80 @@
+//! 规则引擎模块
+//!
+//! 规则以 TOML 描述，每条规则由若干条件组成，全部满足时命中
+
+use super::Facts;
+use serde::Deserialize;
+
+/// 比较运算符
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Operator {
+    /// 小于
+    Lt,
+    /// 小于等于
+    Le,
+    /// 大于
+    Gt,
+    /// 大于等于
+    Ge,
+    /// 等于
+    Eq,
+    /// 不等于
+    Ne,
+}
+
+impl Operator {
+    /// 执行比较
+    pub fn compare(&self, left: f64, right: f64) -> bool {
+        match self {
+            Operator::Lt => left < right,
+            Operator::Le => left <= right,
+            Operator::Gt => left > right,
+            Operator::Ge => left >= right,
+            Operator::Eq => (left - right).abs() < f64::EPSILON,
+            Operator::Ne => (left - right).abs() >= f64::EPSILON,
+        }
+    }
+}
+
+/// 规则条件
+#[derive(Debug, Clone, Deserialize)]
+pub struct Condition {
+    /// 事实名称，如 "memory.module_count"
+    pub fact: String,
+    /// 比较运算符
+    pub op: Operator,
+    /// 比较值
+    pub value: f64,
+}
+
+impl Condition {
+    /// 判断条件是否成立，事实缺失时视为不成立
+    pub fn matches(&self, facts: &Facts) -> bool {
+        facts
+            .number(&self.fact)
+            .map(|actual| self.op.compare(actual, self.value))
+            .unwrap_or(false)
+    }
+}
+
+/// 判断一组条件是否全部成立
+pub fn all_match(conditions: &[Condition], facts: &Facts) -> bool {
+    conditions.iter().all(|condition| condition.matches(facts))
+}
+
+/// 解析 TOML 规则文件
+///
+/// * `content` - TOML 文本
+pub fn parse<T: for<'de> Deserialize<'de>>(content: &str) -> Result<T, String> {
+    toml::from_str(content).map_err(|e| format!("解析规则文件失败: {}", e))
+}
+
+/// 读取程序所在目录下的规则文件
+///
+/// * `file_name` - 文件名，如 "advice_rules.toml"
+pub fn read_beside_exe(file_name: &str) -> Option<String> {
+    let exe = std::env::current_exe().ok()?;
+    let path = exe.parent()?.join(file_name);
+    std::fs::read_to_string(path).ok()
+}
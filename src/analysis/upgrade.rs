@@ -0,0 +1,175 @@
+//! 升级建议模块
+//!
+//! 检查内存通道、空余内存插槽、系统盘类型与显卡驱动日期等可以动手改进的项，
+//! 按优先级给出升级建议。规则见 `upgrade_rules.toml`
+
+use super::rules::{self, Condition};
+use super::{collect_facts, Facts};
+use crate::detector::gpu::GpuType;
+use crate::detector::HardwareDetector;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::fmt;
+
+/// 内置升级规则
+const BUILTIN_RULES: &str = include_str!("upgrade_rules.toml");
+
+/// 用户升级规则文件名（放在程序所在目录）
+pub const USER_RULES_FILE: &str = "upgrade_rules.toml";
+
+/// 建议优先级，按高、中、低排序
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    /// 高
+    High,
+    /// 中
+    #[default]
+    Medium,
+    /// 低
+    Low,
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Priority::High => "高",
+            Priority::Medium => "中",
+            Priority::Low => "低",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 升级规则
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpgradeRule {
+    /// 规则标识
+    pub id: String,
+    /// 涉及的部件，如 "内存"
+    pub component: String,
+    /// 优先级
+    #[serde(default)]
+    pub priority: Priority,
+    /// 建议标题模板
+    pub title: String,
+    /// 具体做法模板
+    pub detail: String,
+    /// 触发条件
+    #[serde(default)]
+    pub when: Vec<Condition>,
+}
+
+/// 升级规则集
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpgradeRuleSet {
+    /// 规则列表
+    #[serde(default)]
+    pub rules: Vec<UpgradeRule>,
+}
+
+/// 单条升级建议
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    /// 命中的规则标识
+    pub rule_id: String,
+    /// 涉及的部件
+    pub component: String,
+    /// 优先级
+    pub priority: Priority,
+    /// 建议标题
+    pub title: String,
+    /// 具体做法
+    pub detail: String,
+}
+
+impl UpgradeRuleSet {
+    /// 从 TOML 文本解析规则集
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        rules::parse(content)
+    }
+
+    /// 内置规则集
+    pub fn builtin() -> Self {
+        Self::from_toml(BUILTIN_RULES).unwrap_or_else(|e| {
+            log::warn!("{}", e);
+            Self::default()
+        })
+    }
+
+    /// 加载内置规则，并合并程序目录下的用户规则
+    pub fn load() -> Self {
+        let mut rule_set = Self::builtin();
+        if let Some(content) = rules::read_beside_exe(USER_RULES_FILE) {
+            match Self::from_toml(&content) {
+                Ok(user_rules) => rule_set.merge(user_rules),
+                Err(e) => log::warn!("{}: {}", USER_RULES_FILE, e),
+            }
+        }
+        rule_set
+    }
+
+    /// 合并规则集，标识相同的规则以后者为准
+    pub fn merge(&mut self, other: UpgradeRuleSet) {
+        for rule in other.rules {
+            match self.rules.iter_mut().find(|r| r.id == rule.id) {
+                Some(existing) => *existing = rule,
+                None => self.rules.push(rule),
+            }
+        }
+    }
+
+    /// 根据事实评估规则，返回按优先级排列的建议，同一优先级保持规则定义的顺序
+    pub fn evaluate(&self, facts: &Facts) -> Vec<Suggestion> {
+        let mut suggestions: Vec<Suggestion> = self
+            .rules
+            .iter()
+            .filter(|rule| !rule.when.is_empty() && rules::all_match(&rule.when, facts))
+            .map(|rule| Suggestion {
+                rule_id: rule.id.clone(),
+                component: rule.component.clone(),
+                priority: rule.priority,
+                title: facts.render(&rule.title),
+                detail: facts.render(&rule.detail),
+            })
+            .collect();
+        suggestions.sort_by_key(|suggestion| suggestion.priority);
+        suggestions
+    }
+
+    /// 为检测结果生成升级建议
+    ///
+    /// * `detector` - 硬件检测器
+    /// * `today` - 当天日期，用于计算驱动发布至今的天数
+    pub fn suggest(&self, detector: &HardwareDetector, today: NaiveDate) -> Vec<Suggestion> {
+        let mut facts = collect_facts(detector);
+        add_driver_facts(&mut facts, detector, today);
+        self.evaluate(&facts)
+    }
+}
+
+/// 写入显卡驱动发布至今的天数 `gpu.driver_age_days`，以及对应的 `gpu.driver_device` 与 `gpu.driver_date`
+///
+/// 有多块显卡时取驱动最旧的一块，驱动日期均未知时不写入
+///
+/// * `facts` - 事实集合
+/// * `detector` - 硬件检测器
+/// * `today` - 当天日期
+pub fn add_driver_facts(facts: &mut Facts, detector: &HardwareDetector, today: NaiveDate) {
+    let oldest = detector
+        .gpu_info
+        .gpus
+        .iter()
+        .filter(|gpu| matches!(gpu.gpu_type, GpuType::DiscreteGpu | GpuType::IntegratedGpu))
+        .filter_map(|gpu| {
+            let date = NaiveDate::parse_from_str(&gpu.driver_date, "%Y-%m-%d").ok()?;
+            Some((date, gpu))
+        })
+        .min_by_key(|(date, _)| *date);
+    let Some((date, gpu)) = oldest else {
+        return;
+    };
+    facts.set_number("gpu.driver_age_days", (today - date).num_days() as f64);
+    facts.set_text("gpu.driver_device", &gpu.description);
+    facts.set_text("gpu.driver_date", &gpu.driver_date);
+}
@@ -0,0 +1,154 @@
+//! 资产信息模块
+//!
+//! 按机器记录购买日期、保修截止日期、使用人、备注与自定义字段，保存在配置文件的 `assets` 中，
+//! 以 [`asset_key`] 区分，同一份配置文件可在多台电脑之间共用。
+//! 远程上报、导出的报告与本地接口会附带当前机器的资产信息，小型 IT 团队可直接将其作为资产台账。
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// 资产信息中日期的格式
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// 单台机器的资产信息，未填写的字段为空字符串
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssetRecord {
+    /// 购买日期，如 "2023-06-18"
+    pub purchase_date: String,
+    /// 保修截止日期，如 "2026-06-17"
+    pub warranty_end: String,
+    /// 使用人
+    pub owner: String,
+    /// 备注
+    pub notes: String,
+    /// 自定义字段，如 "资产编号" = "IT-0042"
+    pub custom_fields: BTreeMap<String, String>,
+}
+
+/// 保修状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarrantyStatus {
+    /// 未填写保修截止日期
+    Unknown,
+    /// 保修中，保修截止当天仍在保修期内
+    Active {
+        /// 剩余天数
+        days_left: i64,
+    },
+    /// 已过保
+    Expired {
+        /// 过保天数
+        days_ago: i64,
+    },
+}
+
+impl fmt::Display for WarrantyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarrantyStatus::Unknown => write!(f, "未知"),
+            WarrantyStatus::Active { days_left } => write!(f, "保修中（剩余 {} 天）", days_left),
+            WarrantyStatus::Expired { days_ago } => write!(f, "已过保 {} 天", days_ago),
+        }
+    }
+}
+
+/// 资产信息的键：配置中指定的机器标识，未指定时为计算机名
+///
+/// 与远程上报的机器标识不同，不使用检测到的序列号，检测完成前后保持不变
+///
+/// * `configured` - 配置中的机器标识（`agent_machine_id`）
+/// * `hostname` - 计算机名
+///
+/// 示例
+/// ```
+/// use hardware_master::asset::asset_key;
+/// assert_eq!(asset_key("", "PC-07"), "PC-07");
+/// assert_eq!(asset_key(" 财务-03 ", "PC-07"), "财务-03");
+/// assert_eq!(asset_key("", ""), "未知");
+/// ```
+pub fn asset_key(configured: &str, hostname: &str) -> String {
+    [configured, hostname]
+        .iter()
+        .map(|key| key.trim())
+        .find(|key| !key.is_empty())
+        .unwrap_or("未知")
+        .to_string()
+}
+
+/// 解析资产信息中的日期，如 "2023-06-18"
+///
+/// 示例
+/// ```
+/// use hardware_master::asset::parse_date;
+/// assert!(parse_date(" 2023-06-18 ").is_some());
+/// assert_eq!(parse_date("2023/06/18"), None);
+/// assert_eq!(parse_date(""), None);
+/// ```
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim(), DATE_FORMAT).ok()
+}
+
+impl AssetRecord {
+    /// 是否所有字段都未填写
+    pub fn is_empty(&self) -> bool {
+        self.purchase_date.trim().is_empty()
+            && self.warranty_end.trim().is_empty()
+            && self.owner.trim().is_empty()
+            && self.notes.trim().is_empty()
+            && self.custom_fields.is_empty()
+    }
+
+    /// 检查日期格式，以及保修截止日期不早于购买日期
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::asset::AssetRecord;
+    /// let mut record = AssetRecord {
+    ///     purchase_date: "2023-06-18".to_string(),
+    ///     warranty_end: "2026-06-17".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(record.validate().is_ok());
+    /// record.warranty_end = "2022-01-01".to_string();
+    /// assert!(record.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        let date = |name: &str, text: &str| -> Result<Option<NaiveDate>, String> {
+            if text.trim().is_empty() {
+                return Ok(None);
+            }
+            parse_date(text)
+                .map(Some)
+                .ok_or_else(|| format!("{}格式应为 2023-06-18: {}", name, text.trim()))
+        };
+        let purchase = date("购买日期", &self.purchase_date)?;
+        let warranty_end = date("保修截止日期", &self.warranty_end)?;
+        if let (Some(purchase), Some(warranty_end)) = (purchase, warranty_end) {
+            if warranty_end < purchase {
+                return Err("保修截止日期早于购买日期".to_string());
+            }
+        }
+        if self.custom_fields.keys().any(|name| name.trim().is_empty()) {
+            return Err("自定义字段名称不能为空".to_string());
+        }
+        Ok(())
+    }
+
+    /// 保修状态，保修截止日期未填写或格式错误时为未知
+    ///
+    /// * `today` - 当天日期
+    pub fn warranty_status(&self, today: NaiveDate) -> WarrantyStatus {
+        let Some(warranty_end) = parse_date(&self.warranty_end) else {
+            return WarrantyStatus::Unknown;
+        };
+        let days = (warranty_end - today).num_days();
+        if days >= 0 {
+            WarrantyStatus::Active { days_left: days }
+        } else {
+            WarrantyStatus::Expired { days_ago: -days }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! 硬盘性能测试
+//!
+//! 在所选分区上创建临时文件，测量顺序读写带宽与 4K 随机读写 IOPS。
+//! 文件以无缓冲、直写方式打开，使结果反映硬盘本身而非系统缓存的性能。
+
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+#[cfg(windows)]
+use std::os::windows::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+#[cfg(windows)]
+use windows::Win32::Storage::FileSystem::{FILE_FLAG_NO_BUFFERING, FILE_FLAG_WRITE_THROUGH};
+
+/// 测试文件名
+pub const BENCH_FILE_NAME: &str = "hardware-master-bench.tmp";
+
+/// 无缓冲读写要求的缓冲区与偏移对齐 (B)
+const ALIGNMENT: usize = 4096;
+
+/// 硬盘测试参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiskBenchOptions {
+    /// 测试文件大小 (B)
+    pub file_size: u64,
+    /// 顺序读写的块大小 (B)
+    pub block_size: usize,
+    /// 随机读写的块大小 (B)
+    pub random_block_size: usize,
+    /// 随机读写各自的持续时间
+    pub random_duration: Duration,
+}
+
+impl Default for DiskBenchOptions {
+    fn default() -> Self {
+        Self {
+            file_size: 256 * 1024 * 1024,
+            block_size: 1024 * 1024,
+            random_block_size: 4096,
+            random_duration: Duration::from_secs(3),
+        }
+    }
+}
+
+/// 硬盘测试结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiskBenchResult {
+    /// 测试时间（Unix 时间戳，秒）
+    pub timestamp: i64,
+    /// 测试的分区，如 "C:"
+    pub drive_letter: String,
+    /// 顺序读取带宽 (B/s)
+    pub seq_read_bytes_per_sec: f64,
+    /// 顺序写入带宽 (B/s)
+    pub seq_write_bytes_per_sec: f64,
+    /// 随机读取 IOPS
+    pub random_read_iops: f64,
+    /// 随机写入 IOPS
+    pub random_write_iops: f64,
+    /// 随机读写的块大小 (B)
+    pub random_block_size: usize,
+}
+
+impl DiskBenchResult {
+    /// 随机读取带宽 (B/s)
+    pub fn random_read_bytes_per_sec(&self) -> f64 {
+        self.random_read_iops * self.random_block_size as f64
+    }
+
+    /// 随机写入带宽 (B/s)
+    pub fn random_write_bytes_per_sec(&self) -> f64 {
+        self.random_write_iops * self.random_block_size as f64
+    }
+}
+
+/// 获取分区上存放测试文件的目录
+///
+/// 系统盘根目录通常需要管理员权限才能写入，因此使用临时目录
+///
+/// * `drive_letter` - 盘符，如 "D:"
+pub fn bench_dir(drive_letter: &str) -> PathBuf {
+    let temp_dir = std::env::temp_dir();
+    let on_same_drive = temp_dir
+        .to_string_lossy()
+        .to_uppercase()
+        .starts_with(&drive_letter.to_uppercase());
+    if on_same_drive {
+        temp_dir
+    } else {
+        PathBuf::from(format!("{}\\", drive_letter))
+    }
+}
+
+/// 运行硬盘测试，完成后删除测试文件
+///
+/// * `drive_letter` - 测试的分区，如 "C:"
+/// * `options` - 测试参数
+pub fn run(drive_letter: &str, options: &DiskBenchOptions) -> Result<DiskBenchResult, String> {
+    let path = bench_dir(drive_letter).join(BENCH_FILE_NAME);
+    let _cleanup = scopeguard::guard(path.clone(), |path| {
+        let _ = std::fs::remove_file(path);
+    });
+
+    let block_size = align_up(options.block_size.max(1));
+    let random_block_size = align_up(options.random_block_size.max(1));
+    let blocks = (options.file_size / block_size as u64).max(1);
+    let file_size = blocks * block_size as u64;
+
+    let mut buffer = AlignedBuffer::new(block_size);
+    fill_pseudo_random(buffer.as_mut_slice());
+
+    // 顺序写入
+    let mut file = open_unbuffered(&path, true)?;
+    let start = Instant::now();
+    for _ in 0..blocks {
+        file.write_all(buffer.as_slice())
+            .map_err(|e| format!("写入测试文件失败: {}", e))?;
+    }
+    file.sync_all()
+        .map_err(|e| format!("写入测试文件失败: {}", e))?;
+    let seq_write_secs = start.elapsed().as_secs_f64();
+    drop(file);
+
+    // 顺序读取
+    let mut file = open_unbuffered(&path, false)?;
+    let start = Instant::now();
+    for _ in 0..blocks {
+        file.read_exact(buffer.as_mut_slice())
+            .map_err(|e| format!("读取测试文件失败: {}", e))?;
+    }
+    let seq_read_secs = start.elapsed().as_secs_f64();
+    drop(file);
+
+    // 随机读写
+    let mut random_buffer = AlignedBuffer::new(random_block_size);
+    let slots = file_size / random_block_size as u64;
+    let mut file = open_unbuffered(&path, false)?;
+    let random_read_iops = random_io(&mut file, slots, random_block_size, options, |file| {
+        file.read_exact(random_buffer.as_mut_slice())
+    })
+    .map_err(|e| format!("随机读取测试文件失败: {}", e))?;
+    drop(file);
+
+    let mut file = open_unbuffered(&path, true)?;
+    let random_write_iops = random_io(&mut file, slots, random_block_size, options, |file| {
+        file.write_all(random_buffer.as_slice())
+    })
+    .map_err(|e| format!("随机写入测试文件失败: {}", e))?;
+
+    let bytes = file_size as f64;
+    Ok(DiskBenchResult {
+        timestamp: chrono::Local::now().timestamp(),
+        drive_letter: drive_letter.to_string(),
+        seq_read_bytes_per_sec: bytes / seq_read_secs.max(f64::EPSILON),
+        seq_write_bytes_per_sec: bytes / seq_write_secs.max(f64::EPSILON),
+        random_read_iops,
+        random_write_iops,
+        random_block_size,
+    })
+}
+
+/// 以无缓冲方式打开测试文件
+///
+/// * `write` - 是否以写入方式打开，写入时同时启用直写并在文件不存在时创建
+///
+/// 非 Windows 平台以普通方式打开，测试结果会受系统缓存影响
+fn open_unbuffered(path: &Path, write: bool) -> Result<File, String> {
+    let mut options = OpenOptions::new();
+    options
+        .read(true)
+        .write(write)
+        .create(write)
+        .truncate(false);
+    #[cfg(windows)]
+    {
+        let mut flags = FILE_FLAG_NO_BUFFERING.0;
+        if write {
+            flags |= FILE_FLAG_WRITE_THROUGH.0;
+        }
+        options.custom_flags(flags);
+    }
+    options
+        .open(path)
+        .map_err(|e| format!("打开测试文件 {} 失败: {}", path.display(), e))
+}
+
+/// 在持续时间内反复对随机对齐位置执行一次读写操作，返回 IOPS
+///
+/// * `slots` - 文件中可用的块数量
+/// * `block_size` - 块大小 (B)
+/// * `io` - 单次读写操作
+fn random_io(
+    file: &mut File,
+    slots: u64,
+    block_size: usize,
+    options: &DiskBenchOptions,
+    mut io: impl FnMut(&mut File) -> std::io::Result<()>,
+) -> std::io::Result<f64> {
+    let mut state = 0x2545_F491_4F6C_DD1D_u64;
+    let mut operations = 0u64;
+    let start = Instant::now();
+    while start.elapsed() < options.random_duration {
+        state = xorshift(state);
+        let offset = (state % slots.max(1)) * block_size as u64;
+        file.seek(SeekFrom::Start(offset))?;
+        io(file)?;
+        operations += 1;
+    }
+    Ok(operations as f64 / start.elapsed().as_secs_f64().max(f64::EPSILON))
+}
+
+/// 按 [`ALIGNMENT`] 向上对齐
+fn align_up(size: usize) -> usize {
+    size.div_ceil(ALIGNMENT) * ALIGNMENT
+}
+
+/// xorshift64 伪随机数
+fn xorshift(mut state: u64) -> u64 {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+}
+
+/// 填充伪随机数据，避免硬盘主控压缩数据导致结果偏高
+fn fill_pseudo_random(buffer: &mut [u8]) {
+    let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+    for chunk in buffer.chunks_mut(8) {
+        state = xorshift(state);
+        let bytes = state.to_le_bytes();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
+
+/// 起始地址按 [`ALIGNMENT`] 对齐的缓冲区
+struct AlignedBuffer {
+    data: Vec<u8>,
+    offset: usize,
+    len: usize,
+}
+
+impl AlignedBuffer {
+    fn new(len: usize) -> Self {
+        let data = vec![0u8; len + ALIGNMENT];
+        let offset = data.as_ptr().align_offset(ALIGNMENT);
+        Self { data, offset, len }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.data[self.offset..self.offset + self.len]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data[self.offset..self.offset + self.len]
+    }
+}
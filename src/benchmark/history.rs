@@ -0,0 +1,296 @@
+//! 性能测试历史模块
+//!
+//! 每次测试完成后追加一条记录到 `%APPDATA%\hardware-master\benchmarks.jsonl`（每行一条 JSON）。
+//! 记录附带硬件与驱动指纹：只与同一硬件的历史成绩比较，并标记驱动更新后出现的性能回退。
+
+use super::disk::DiskBenchResult;
+use super::memory::MemoryBenchResult;
+use crate::config::AppConfig;
+use crate::constants::{
+    BENCHMARK_HISTORY_FILE_NAME, BENCHMARK_REGRESSION_PERCENT, BYTES_PER_GB, BYTES_PER_MB,
+};
+use crate::history::{append_jsonl, load_jsonl};
+use crate::HardwareDetector;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// 单次测试结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum BenchmarkResult {
+    /// 内存测试
+    Memory(MemoryBenchResult),
+    /// 硬盘测试
+    Disk(DiskBenchResult),
+}
+
+/// 测试指标
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metric {
+    /// 指标名称
+    pub name: &'static str,
+    /// 单位
+    pub unit: &'static str,
+    /// 数值
+    pub value: f64,
+    /// 数值越大是否越好
+    pub higher_is_better: bool,
+}
+
+impl BenchmarkResult {
+    /// 测试时间（Unix 时间戳，秒）
+    pub fn timestamp(&self) -> i64 {
+        match self {
+            BenchmarkResult::Memory(result) => result.timestamp,
+            BenchmarkResult::Disk(result) => result.timestamp,
+        }
+    }
+
+    /// 测试对象，如 "内存" 或 "硬盘 C:"，只有测试对象相同的成绩才能相互比较
+    pub fn target(&self) -> String {
+        match self {
+            BenchmarkResult::Memory(_) => "内存".to_string(),
+            BenchmarkResult::Disk(result) => format!("硬盘 {}", result.drive_letter),
+        }
+    }
+
+    /// 全部测试指标
+    pub fn metrics(&self) -> Vec<Metric> {
+        let metric = |name, unit, value, higher_is_better| Metric {
+            name,
+            unit,
+            value,
+            higher_is_better,
+        };
+        match self {
+            BenchmarkResult::Memory(result) => vec![
+                metric(
+                    "读取带宽",
+                    "GB/s",
+                    result.read_bytes_per_sec / BYTES_PER_GB,
+                    true,
+                ),
+                metric(
+                    "写入带宽",
+                    "GB/s",
+                    result.write_bytes_per_sec / BYTES_PER_GB,
+                    true,
+                ),
+                metric("延迟", "ns", result.latency_ns, false),
+            ],
+            BenchmarkResult::Disk(result) => vec![
+                metric(
+                    "顺序读取",
+                    "MB/s",
+                    result.seq_read_bytes_per_sec / BYTES_PER_MB,
+                    true,
+                ),
+                metric(
+                    "顺序写入",
+                    "MB/s",
+                    result.seq_write_bytes_per_sec / BYTES_PER_MB,
+                    true,
+                ),
+                metric("4K 随机读取", "IOPS", result.random_read_iops, true),
+                metric("4K 随机写入", "IOPS", result.random_write_iops, true),
+            ],
+        }
+    }
+}
+
+/// 性能测试记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    /// 硬件指纹，硬件变化后成绩不再与之前的记录比较
+    pub hardware_hash: String,
+    /// 驱动指纹，用于判断两次测试之间是否更新过驱动或系统
+    pub driver_hash: String,
+    /// 测试结果
+    pub result: BenchmarkResult,
+}
+
+impl BenchmarkRecord {
+    /// 根据当前检测结果生成记录
+    ///
+    /// * `result` - 测试结果
+    /// * `detector` - 硬件检测器
+    pub fn new(result: BenchmarkResult, detector: &HardwareDetector) -> Self {
+        let mut hardware = vec![
+            detector.cpu_info.name.clone(),
+            detector.motherboard_info.product_name.clone(),
+            detector.disk_info.model.clone(),
+            detector.memory_info.total_memory.bytes().to_string(),
+        ];
+        hardware.extend(
+            detector
+                .memory_info
+                .slots
+                .iter()
+                .map(|slot| slot.name.clone()),
+        );
+        hardware.extend(
+            detector
+                .gpu_info
+                .gpus
+                .iter()
+                .filter(|gpu| !gpu.gpu_type.is_virtual())
+                .map(|gpu| gpu.description.clone()),
+        );
+
+        let mut drivers = vec![
+            detector.system_info.os_name.clone(),
+            detector.motherboard_info.bios_version.clone(),
+        ];
+        drivers.extend(
+            detector
+                .gpu_info
+                .gpus
+                .iter()
+                .map(|gpu| gpu.driver_version.clone()),
+        );
+        drivers.extend(
+            detector
+                .network_info
+                .adapters
+                .iter()
+                .map(|adapter| adapter.driver_version.clone()),
+        );
+
+        Self {
+            hardware_hash: fingerprint(&hardware),
+            driver_hash: fingerprint(&drivers),
+            result,
+        }
+    }
+}
+
+/// 性能回退
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    /// 指标名称
+    pub metric: &'static str,
+    /// 相比上一次的变化（百分比），负数表示变差
+    pub change_percent: f64,
+    /// 两次测试之间是否更新过驱动或系统
+    pub after_driver_update: bool,
+}
+
+impl Regression {
+    /// 描述，如 "驱动更新后 读取带宽 下降 23%"
+    pub fn description(&self) -> String {
+        let prefix = if self.after_driver_update {
+            "驱动更新后 "
+        } else {
+            ""
+        };
+        format!(
+            "{}{} 下降 {:.0}%",
+            prefix, self.metric, -self.change_percent
+        )
+    }
+}
+
+/// 将记录与同一硬件、同一测试对象的上一次记录比较，找出变差超过阈值的指标
+///
+/// * `records` - 按时间排序的全部记录
+/// * `index` - 要检查的记录下标
+///
+/// 示例
+/// ```
+/// use hardware_master::benchmark::history::{find_regressions, BenchmarkRecord, BenchmarkResult};
+/// use hardware_master::benchmark::memory::MemoryBenchResult;
+/// let record = |read, driver: &str| BenchmarkRecord {
+///     hardware_hash: "hw".to_string(),
+///     driver_hash: driver.to_string(),
+///     result: BenchmarkResult::Memory(MemoryBenchResult {
+///         timestamp: 0,
+///         read_bytes_per_sec: read,
+///         write_bytes_per_sec: 1.0,
+///         latency_ns: 80.0,
+///     }),
+/// };
+/// let records = vec![record(100.0, "a"), record(70.0, "b")];
+/// let regressions = find_regressions(&records, 1);
+/// assert_eq!(regressions.len(), 1);
+/// assert!(regressions[0].after_driver_update);
+/// ```
+pub fn find_regressions(records: &[BenchmarkRecord], index: usize) -> Vec<Regression> {
+    let Some(current) = records.get(index) else {
+        return Vec::new();
+    };
+    let target = current.result.target();
+    let Some(previous) = records[..index].iter().rev().find(|record| {
+        record.hardware_hash == current.hardware_hash && record.result.target() == target
+    }) else {
+        return Vec::new();
+    };
+
+    let after_driver_update = previous.driver_hash != current.driver_hash;
+    current
+        .result
+        .metrics()
+        .into_iter()
+        .zip(previous.result.metrics())
+        .filter(|(_, before)| before.value > 0.0)
+        .filter_map(|(after, before)| {
+            let mut change_percent = (after.value - before.value) / before.value * 100.0;
+            if !after.higher_is_better {
+                change_percent = -change_percent;
+            }
+            (change_percent <= -BENCHMARK_REGRESSION_PERCENT).then_some(Regression {
+                metric: after.name,
+                change_percent,
+                after_driver_update,
+            })
+        })
+        .collect()
+}
+
+/// 计算一组文本的指纹（FNV-1a 64 位），结果在不同版本的程序间保持稳定
+///
+/// * `parts` - 参与计算的文本
+pub fn fingerprint(parts: &[String]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// 性能测试历史存储
+#[derive(Debug, Clone)]
+pub struct BenchmarkStore {
+    path: PathBuf,
+}
+
+impl BenchmarkStore {
+    /// 使用指定文件创建存储
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// 使用配置目录下的默认文件创建存储
+    pub fn open_default() -> Option<Self> {
+        AppConfig::config_dir().map(|dir| Self::new(dir.join(BENCHMARK_HISTORY_FILE_NAME)))
+    }
+
+    /// 存储文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 追加一条记录
+    pub fn append(&self, record: &BenchmarkRecord) -> Result<(), String> {
+        append_jsonl(&self.path, record)
+    }
+
+    /// 读取全部记录，按记录顺序排列
+    ///
+    /// 文件不存在时返回空列表，无法解析的行会被跳过
+    pub fn load(&self) -> Result<Vec<BenchmarkRecord>, String> {
+        load_jsonl(&self.path)
+    }
+}
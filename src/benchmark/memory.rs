@@ -0,0 +1,148 @@
+//! 内存性能测试
+//!
+//! 测量顺序读写带宽与随机访问延迟。测试缓冲区远大于 CPU 缓存，
+//! 使结果反映内存本身而非缓存的性能。
+
+use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use std::time::Instant;
+
+/// 内存测试参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryBenchOptions {
+    /// 带宽测试缓冲区大小 (B)
+    pub buffer_size: usize,
+    /// 带宽测试重复次数，取最快的一次
+    pub passes: u32,
+    /// 延迟测试缓冲区大小 (B)
+    pub latency_buffer_size: usize,
+    /// 延迟测试的访问次数
+    pub latency_steps: usize,
+}
+
+impl Default for MemoryBenchOptions {
+    fn default() -> Self {
+        Self {
+            buffer_size: 256 * 1024 * 1024,
+            passes: 3,
+            latency_buffer_size: 128 * 1024 * 1024,
+            latency_steps: 4_000_000,
+        }
+    }
+}
+
+/// 内存测试结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryBenchResult {
+    /// 测试时间（Unix 时间戳，秒）
+    pub timestamp: i64,
+    /// 顺序读取带宽 (B/s)
+    pub read_bytes_per_sec: f64,
+    /// 顺序写入带宽 (B/s)
+    pub write_bytes_per_sec: f64,
+    /// 随机访问延迟 (ns)
+    pub latency_ns: f64,
+}
+
+/// 运行内存测试
+///
+/// * `options` - 测试参数
+///
+/// 示例
+/// ```
+/// use hardware_master::benchmark::memory::{run, MemoryBenchOptions};
+/// let options = MemoryBenchOptions {
+///     buffer_size: 1024 * 1024,
+///     passes: 1,
+///     latency_buffer_size: 1024 * 1024,
+///     latency_steps: 1000,
+/// };
+/// let result = run(&options);
+/// assert!(result.read_bytes_per_sec > 0.0);
+/// ```
+pub fn run(options: &MemoryBenchOptions) -> MemoryBenchResult {
+    let words = (options.buffer_size / std::mem::size_of::<u64>()).max(1);
+    let mut buffer = vec![1u64; words];
+    let bytes = (words * std::mem::size_of::<u64>()) as f64;
+
+    let mut read_secs = f64::MAX;
+    let mut write_secs = f64::MAX;
+    for pass in 0..options.passes.max(1) {
+        let start = Instant::now();
+        black_box(sequential_read(&buffer));
+        read_secs = read_secs.min(start.elapsed().as_secs_f64());
+
+        let start = Instant::now();
+        sequential_write(&mut buffer, pass as u64);
+        black_box(&buffer);
+        write_secs = write_secs.min(start.elapsed().as_secs_f64());
+    }
+    drop(buffer);
+
+    let nodes = (options.latency_buffer_size / std::mem::size_of::<usize>()).max(2);
+    let chain = random_cycle(nodes, 0x9E37_79B9_7F4A_7C15);
+    let steps = options.latency_steps.max(1);
+    let start = Instant::now();
+    black_box(chase(&chain, steps));
+    let latency_ns = start.elapsed().as_nanos() as f64 / steps as f64;
+
+    MemoryBenchResult {
+        timestamp: chrono::Local::now().timestamp(),
+        read_bytes_per_sec: bytes / read_secs.max(f64::EPSILON),
+        write_bytes_per_sec: bytes / write_secs.max(f64::EPSILON),
+        latency_ns,
+    }
+}
+
+/// 顺序读取整个缓冲区
+fn sequential_read(buffer: &[u64]) -> u64 {
+    buffer
+        .iter()
+        .fold(0u64, |acc, &value| acc.wrapping_add(value))
+}
+
+/// 顺序写入整个缓冲区
+fn sequential_write(buffer: &mut [u64], value: u64) {
+    buffer.fill(value);
+}
+
+/// 沿访问链前进指定步数，返回最终位置
+fn chase(chain: &[usize], steps: usize) -> usize {
+    let mut index = 0;
+    for _ in 0..steps {
+        index = chain[index];
+    }
+    index
+}
+
+/// 生成覆盖全部节点的单个随机环（Sattolo 算法）
+///
+/// 每个节点保存下一个访问位置，随机跳转使硬件预取失效，从而测得真实延迟
+///
+/// * `len` - 节点数量
+/// * `seed` - 随机种子
+///
+/// 示例
+/// ```
+/// use hardware_master::benchmark::memory::random_cycle;
+/// let chain = random_cycle(8, 1);
+/// let mut index = 0;
+/// for _ in 0..7 {
+///     index = chain[index];
+///     assert_ne!(index, 0);
+/// }
+/// assert_eq!(chain[index], 0);
+/// ```
+pub fn random_cycle(len: usize, seed: u64) -> Vec<usize> {
+    let mut chain: Vec<usize> = (0..len).collect();
+    let mut state = seed | 1;
+    for i in (1..len).rev() {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let j = (state % i as u64) as usize;
+        chain.swap(i, j);
+    }
+    chain
+}
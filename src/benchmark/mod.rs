@@ -0,0 +1,8 @@
+//! 性能测试模块
+//!
+//! 在本机上实际运行测试负载并测量性能，与只读取硬件参数的检测模块互为补充。
+//! 测试耗时较长，调用方应在后台线程中运行。
+
+pub mod disk;
+pub mod history;
+pub mod memory;
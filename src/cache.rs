@@ -0,0 +1,209 @@
+//! 检测结果缓存模块
+//!
+//! 每次检测完成后将全部检测结果保存到 `%APPDATA%\hardware-master\detection_cache.json`，
+//! 下次启动时先显示缓存（标注“上次检测于 X 分钟前”），同时在后台重新检测，避免启动时只有加载动画。
+//!
+//! 缓存依赖检测结果的序列化，需要启用 `serialize` 特性。
+
+#[cfg(feature = "serialize")]
+use crate::config::AppConfig;
+#[cfg(feature = "serialize")]
+use crate::constants::{DETECTION_CACHE_FILE_NAME, DETECTION_CACHE_MAX_AGE_SECS};
+#[cfg(feature = "serialize")]
+use crate::detector::{
+    AudioInfo, BatteryInfo, CpuInfo, DiskInfo, GpuInfo, MemoryInfo, MonitorInfo, MotherboardInfo,
+    NetworkInfo, SecurityInfo, SystemInfo,
+};
+#[cfg(feature = "serialize")]
+use crate::detector::timing::DetectionTiming;
+#[cfg(feature = "serialize")]
+use crate::utils::string::mask_sensitive;
+#[cfg(feature = "serialize")]
+use crate::HardwareDetector;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serialize")]
+use std::path::{Path, PathBuf};
+
+/// 缓存的检测结果
+#[cfg(feature = "serialize")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedDetection {
+    /// 检测时间（Unix 时间戳，秒）
+    pub timestamp: i64,
+    /// 写入缓存的程序版本，版本不同时缓存作废
+    pub version: String,
+    /// 系统信息
+    pub system_info: SystemInfo,
+    /// CPU 信息
+    pub cpu_info: CpuInfo,
+    /// 内存信息
+    pub memory_info: MemoryInfo,
+    /// 磁盘信息
+    pub disk_info: DiskInfo,
+    /// 显卡信息
+    pub gpu_info: GpuInfo,
+    /// 主板信息
+    pub motherboard_info: MotherboardInfo,
+    /// 网络信息
+    pub network_info: NetworkInfo,
+    /// 声卡信息
+    pub audio_info: AudioInfo,
+    /// 显示器信息
+    pub monitor_info: MonitorInfo,
+    /// 电池信息
+    pub battery_info: BatteryInfo,
+    /// 安全特性信息
+    pub security_info: SecurityInfo,
+    /// 各类别的检测耗时，用于找出在该电脑上特别慢的 WMI 类
+    #[serde(default)]
+    pub timings: Vec<DetectionTiming>,
+}
+
+#[cfg(feature = "serialize")]
+impl CachedDetection {
+    /// 从检测结果生成缓存
+    ///
+    /// * `detector` - 硬件检测器
+    /// * `timestamp` - 检测时间（Unix 时间戳，秒）
+    pub fn from_detector(detector: &HardwareDetector, timestamp: i64) -> Self {
+        Self {
+            timestamp,
+            version: crate::VERSION.to_string(),
+            system_info: detector.system_info.clone(),
+            cpu_info: detector.cpu_info.clone(),
+            memory_info: detector.memory_info.clone(),
+            disk_info: detector.disk_info.clone(),
+            gpu_info: detector.gpu_info.clone(),
+            motherboard_info: detector.motherboard_info.clone(),
+            network_info: detector.network_info.clone(),
+            audio_info: detector.audio_info.clone(),
+            monitor_info: detector.monitor_info.clone(),
+            battery_info: detector.battery_info.clone(),
+            security_info: detector.security_info.clone(),
+            timings: detector.timings().to_vec(),
+        }
+    }
+
+    /// 遮盖序列号、UUID 与资产标签等敏感信息，用于隐私模式下的导出
+    pub fn mask_sensitive(&mut self) {
+        let fields = [
+            &mut self.system_info.serial_number,
+            &mut self.disk_info.serial_number,
+            &mut self.motherboard_info.serial_number,
+            &mut self.motherboard_info.bios_serial,
+            &mut self.motherboard_info.system_uuid,
+            &mut self.motherboard_info.asset_tag,
+            &mut self.motherboard_info.chassis_serial,
+        ];
+        for field in fields {
+            *field = mask_sensitive(field);
+        }
+        for battery in self.battery_info.batteries.iter_mut() {
+            battery.serial_number = mask_sensitive(&battery.serial_number);
+        }
+    }
+
+    /// 将缓存的检测结果写入检测器
+    pub fn apply_to(self, detector: &mut HardwareDetector) {
+        detector.system_info = self.system_info;
+        detector.cpu_info = self.cpu_info;
+        detector.memory_info = self.memory_info;
+        detector.disk_info = self.disk_info;
+        detector.gpu_info = self.gpu_info;
+        detector.motherboard_info = self.motherboard_info;
+        detector.network_info = self.network_info;
+        detector.audio_info = self.audio_info;
+        detector.monitor_info = self.monitor_info;
+        detector.battery_info = self.battery_info;
+        detector.security_info = self.security_info;
+    }
+
+    /// 缓存是否已过期：超过有效期、由其他版本写入或时间晚于当前时间（系统时间被调整过）
+    ///
+    /// * `now` - 当前时间（Unix 时间戳，秒）
+    pub fn is_stale(&self, now: i64) -> bool {
+        let age = now - self.timestamp;
+        self.version != crate::VERSION || !(0..=DETECTION_CACHE_MAX_AGE_SECS).contains(&age)
+    }
+}
+
+/// 描述缓存距今的时间
+///
+/// * `age_secs` - 缓存距今的秒数
+///
+/// 示例
+/// ```
+/// use hardware_master::cache::describe_age;
+/// assert_eq!(describe_age(30), "上次检测于刚刚");
+/// assert_eq!(describe_age(5 * 60), "上次检测于 5 分钟前");
+/// assert_eq!(describe_age(3 * 3600 + 59), "上次检测于 3 小时前");
+/// assert_eq!(describe_age(2 * 86400), "上次检测于 2 天前");
+/// ```
+pub fn describe_age(age_secs: i64) -> String {
+    let minutes = age_secs.max(0) / 60;
+    match minutes {
+        0 => "上次检测于刚刚".to_string(),
+        1..=59 => format!("上次检测于 {} 分钟前", minutes),
+        60..=1439 => format!("上次检测于 {} 小时前", minutes / 60),
+        _ => format!("上次检测于 {} 天前", minutes / 1440),
+    }
+}
+
+/// 检测结果缓存存储
+#[cfg(feature = "serialize")]
+#[derive(Debug, Clone)]
+pub struct DetectionCache {
+    path: PathBuf,
+}
+
+#[cfg(feature = "serialize")]
+impl DetectionCache {
+    /// 使用指定文件创建存储
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// 使用配置目录下的默认文件创建存储
+    pub fn open_default() -> Option<Self> {
+        AppConfig::config_dir().map(|dir| Self::new(dir.join(DETECTION_CACHE_FILE_NAME)))
+    }
+
+    /// 存储文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 读取缓存，文件不存在时返回 None
+    pub fn load(&self) -> Result<Option<CachedDetection>, String> {
+        let content = match std::fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("读取检测缓存失败: {}", e)),
+        };
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| format!("解析检测缓存失败: {}", e))
+    }
+
+    /// 读取未过期的缓存，没有缓存、缓存已过期或无法解析时返回 None
+    ///
+    /// * `now` - 当前时间（Unix 时间戳，秒）
+    pub fn load_fresh(&self, now: i64) -> Option<CachedDetection> {
+        self.load()
+            .map_err(|e| log::warn!("{}", e))
+            .ok()
+            .flatten()
+            .filter(|cached| !cached.is_stale(now))
+    }
+
+    /// 保存缓存，覆盖上一次的缓存
+    pub fn save(&self, cached: &CachedDetection) -> Result<(), String> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("创建缓存目录失败: {}", e))?;
+        }
+        let content =
+            serde_json::to_string(cached).map_err(|e| format!("序列化检测缓存失败: {}", e))?;
+        std::fs::write(&self.path, content).map_err(|e| format!("写入检测缓存失败: {}", e))
+    }
+}
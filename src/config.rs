@@ -0,0 +1,287 @@
+//! 配置持久化模块
+//!
+//! 将用户偏好保存到 `%APPDATA%\hardware-master\config.toml`，
+//! 启动时加载，退出时保存。
+//!
+//! 界面语言目前只有简体中文，`language` 字段先行保存，以后增加语言时无需迁移配置。
+
+use crate::asset::AssetRecord;
+use crate::constants::{
+    BATTERY_DEGRADATION_WARNING_PERCENT, CONFIG_DIR_NAME, CONFIG_FILE_NAME, DEFAULT_ACCENT_COLOR,
+    DEFAULT_DETECTION_THREADS, WINDOW_HEIGHT, WINDOW_WIDTH, WMI_TIMEOUT_SECS,
+};
+use crate::detector::{DetectionCategory, DetectionPriority};
+use crate::report::template::CopyTemplate;
+use crate::ui::font::FontChoice;
+use crate::ui::tab::AppTab;
+use crate::ui::theme::{AppTheme, Density, ThemeStyle};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// 虚拟设备（虚拟机显卡、虚拟显示器驱动）的显示方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VirtualDeviceMode {
+    /// 归入“虚拟设备”分组显示
+    #[default]
+    Group,
+    /// 不显示
+    Hide,
+}
+
+impl VirtualDeviceMode {
+    /// 获取显示方式名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            VirtualDeviceMode::Group => "归入虚拟设备",
+            VirtualDeviceMode::Hide => "隐藏",
+        }
+    }
+}
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Language {
+    /// 简体中文，无法识别的语言也按简体中文处理
+    #[default]
+    #[serde(rename = "zh-CN", other)]
+    ZhCn,
+}
+
+impl Language {
+    /// 所有可选的界面语言
+    pub const ALL: [Language; 1] = [Language::ZhCn];
+
+    /// 获取语言名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::ZhCn => "简体中文",
+        }
+    }
+}
+
+/// 旧版本配置保存的是启用的类别列表（`enabled_detectors`），其中只会出现当时已有的类别
+const LEGACY_DETECTORS: [DetectionCategory; 10] = [
+    DetectionCategory::System,
+    DetectionCategory::Cpu,
+    DetectionCategory::Gpu,
+    DetectionCategory::Memory,
+    DetectionCategory::Disk,
+    DetectionCategory::Motherboard,
+    DetectionCategory::Network,
+    DetectionCategory::Audio,
+    DetectionCategory::Monitor,
+    DetectionCategory::Battery,
+];
+
+/// 旧版本配置中需要迁移的字段
+#[derive(Deserialize)]
+struct LegacyConfig {
+    enabled_detectors: Option<Vec<DetectionCategory>>,
+    disabled_detectors: Option<Vec<DetectionCategory>>,
+}
+
+/// 用户配置
+///
+/// 缺失的字段使用默认值，保证旧版本配置文件可以继续使用
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// 主题
+    pub theme: AppTheme,
+    /// 强调色（RGB）
+    pub accent_color: [u8; 3],
+    /// 界面间距预设
+    pub density: Density,
+    /// 是否为概览中的每个区块绘制卡片背景
+    pub section_cards: bool,
+    /// 界面字体
+    pub font: FontChoice,
+    /// 无障碍模式：放大字体、提高对比度
+    pub accessibility_mode: bool,
+    /// 界面语言
+    pub language: Language,
+    /// 窗口宽度
+    pub window_width: f32,
+    /// 窗口高度
+    pub window_height: f32,
+    /// 上次退出时所在的标签页
+    pub last_tab: AppTab,
+    /// 是否显示购买建议
+    pub show_advice: bool,
+    /// 各标签页的滚动位置（像素），启动后首次显示该页时恢复
+    pub scroll_offsets: BTreeMap<AppTab, f32>,
+    /// 自动刷新间隔（秒），0 表示关闭
+    pub auto_refresh_secs: u64,
+    /// 停用的检测类别，未列出的类别均启用，以后新增的类别默认启用
+    pub disabled_detectors: Vec<DetectionCategory>,
+    /// 检测工作线程数
+    pub detection_threads: usize,
+    /// 检测线程优先级
+    pub detection_priority: DetectionPriority,
+    /// WMI 连接或单次查询的超时时间（秒），超时的类别本次检测失败，其余类别继续检测
+    pub wmi_timeout_secs: u64,
+    /// 硬件插拔时是否自动重新检测受影响的类别
+    pub hotplug_refresh: bool,
+    /// 托盘模式，开启后关闭主窗口时隐藏到系统托盘
+    pub tray_enabled: bool,
+    /// 是否启用高级模式（显示 WMI 控制台等工具）
+    pub advanced_mode: bool,
+    /// 是否将常见厂商名称显示为中文品牌名
+    pub localize_vendors: bool,
+    /// 隐私模式，开启后始终遮盖序列号等敏感信息
+    pub privacy_mode: bool,
+    /// 虚拟设备的显示方式
+    pub virtual_device_mode: VirtualDeviceMode,
+    /// 复制到剪贴板时使用的格式
+    pub copy_template: CopyTemplate,
+    /// 电池损耗（百分比）达到该值时在趋势页提示
+    pub battery_degradation_warning: f64,
+    /// 上次在线更新设备数据库的时间（Unix 时间戳，秒），从未更新时为 None
+    pub ids_updated_at: Option<i64>,
+    /// 远程上报的收集服务地址（https），为空表示未配置
+    pub agent_endpoint: String,
+    /// 远程上报的认证令牌，以 Bearer 方式发送
+    pub agent_token: String,
+    /// 远程上报使用的机器标识，为空时由序列号或计算机名生成
+    pub agent_machine_id: String,
+    /// 各机器的资产信息，以机器标识为键
+    pub assets: BTreeMap<String, AssetRecord>,
+    /// 在线驱动规则文件地址（https），为空时只使用程序目录下的离线规则，需要启用 `driver-update-check` 特性
+    pub driver_rules_url: String,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            theme: AppTheme::default(),
+            accent_color: DEFAULT_ACCENT_COLOR,
+            density: Density::default(),
+            section_cards: true,
+            font: FontChoice::default(),
+            accessibility_mode: false,
+            language: Language::default(),
+            window_width: WINDOW_WIDTH,
+            window_height: WINDOW_HEIGHT,
+            last_tab: AppTab::default(),
+            show_advice: false,
+            scroll_offsets: BTreeMap::new(),
+            auto_refresh_secs: 0,
+            disabled_detectors: Vec::new(),
+            detection_threads: DEFAULT_DETECTION_THREADS,
+            detection_priority: DetectionPriority::default(),
+            wmi_timeout_secs: WMI_TIMEOUT_SECS,
+            hotplug_refresh: true,
+            tray_enabled: false,
+            advanced_mode: false,
+            localize_vendors: true,
+            privacy_mode: false,
+            virtual_device_mode: VirtualDeviceMode::default(),
+            copy_template: CopyTemplate::default(),
+            battery_degradation_warning: BATTERY_DEGRADATION_WARNING_PERCENT,
+            ids_updated_at: None,
+            agent_endpoint: String::new(),
+            agent_token: String::new(),
+            agent_machine_id: String::new(),
+            assets: BTreeMap::new(),
+            driver_rules_url: String::new(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// 配置目录：`%APPDATA%\hardware-master`
+    pub fn config_dir() -> Option<PathBuf> {
+        std::env::var_os("APPDATA").map(|appdata| PathBuf::from(appdata).join(CONFIG_DIR_NAME))
+    }
+
+    /// 配置文件路径
+    pub fn config_path() -> Option<PathBuf> {
+        Self::config_dir().map(|dir| dir.join(CONFIG_FILE_NAME))
+    }
+
+    /// 由主题与外观设置生成界面样式
+    pub fn theme_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            theme: self.theme,
+            accent: self.accent_color,
+            density: self.density,
+            cards: self.section_cards,
+            accessible: self.accessibility_mode,
+        }
+    }
+
+    /// 获取机器的资产信息，未记录时为 None
+    ///
+    /// * `machine_id` - 机器标识
+    pub fn asset(&self, machine_id: &str) -> Option<&AssetRecord> {
+        self.assets.get(machine_id)
+    }
+
+    /// 记录机器的资产信息，所有字段均未填写时删除该机器的记录
+    ///
+    /// * `machine_id` - 机器标识
+    /// * `record` - 资产信息
+    pub fn set_asset(&mut self, machine_id: &str, record: AssetRecord) {
+        if record.is_empty() {
+            self.assets.remove(machine_id);
+        } else {
+            self.assets.insert(machine_id.to_string(), record);
+        }
+    }
+
+    /// 启用的检测类别
+    pub fn enabled_detectors(&self) -> Vec<DetectionCategory> {
+        DetectionCategory::ALL
+            .into_iter()
+            .filter(|category| !self.disabled_detectors.contains(category))
+            .collect()
+    }
+
+    /// 从 TOML 文本解析配置
+    ///
+    /// 旧版本配置中的 `enabled_detectors` 会转换为 `disabled_detectors`，
+    /// 只有当时已有但未启用的类别才视为停用
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        let mut config: Self =
+            toml::from_str(content).map_err(|e| format!("解析配置文件失败: {}", e))?;
+        let legacy: LegacyConfig =
+            toml::from_str(content).map_err(|e| format!("解析配置文件失败: {}", e))?;
+        if let (Some(enabled), None) = (legacy.enabled_detectors, legacy.disabled_detectors) {
+            config.disabled_detectors = LEGACY_DETECTORS
+                .into_iter()
+                .filter(|category| !enabled.contains(category))
+                .collect();
+        }
+        Ok(config)
+    }
+
+    /// 序列化为 TOML 文本
+    pub fn to_toml(&self) -> Result<String, String> {
+        toml::to_string_pretty(self).map_err(|e| format!("序列化配置失败: {}", e))
+    }
+
+    /// 加载配置，文件不存在或解析失败时使用默认配置
+    pub fn load() -> Self {
+        let Some(path) = Self::config_path() else {
+            return Self::default();
+        };
+
+        match std::fs::read_to_string(&path) {
+            Ok(content) => Self::from_toml(&content).unwrap_or_else(|e| {
+                log::warn!("{}", e);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// 保存配置
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::config_path().ok_or_else(|| "无法确定配置目录".to_string())?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("创建配置目录失败: {}", e))?;
+        }
+        std::fs::write(&path, self.to_toml()?).map_err(|e| format!("写入配置文件失败: {}", e))
+    }
+}
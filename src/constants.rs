@@ -30,3 +30,112 @@ pub const TEXT_FORMAT_PRECISION: usize = 0;
 pub const THEME_SYSTEM: &str = "系统";
 pub const THEME_LIGHT: &str = "亮色";
 pub const THEME_DARK: &str = "暗色";
+/// 默认强调色（RGB）
+pub const DEFAULT_ACCENT_COLOR: [u8; 3] = [0, 120, 215];
+/// 无障碍模式下字体相对默认大小的放大比例
+pub const ACCESSIBLE_FONT_SCALE: f32 = 1.25;
+
+/// 配置相关常量
+pub const CONFIG_DIR_NAME: &str = "hardware-master";
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// 功耗估算常量：每天使用时长（小时）
+pub const ENERGY_HOURS_PER_DAY: f64 = 8.0;
+
+/// 默认检测工作线程数
+pub const DEFAULT_DETECTION_THREADS: usize = 4;
+
+/// 检测工作线程数上限
+pub const MAX_DETECTION_THREADS: usize = 16;
+
+/// 设置菜单中可选的自动刷新间隔（秒），0 表示关闭
+pub const AUTO_REFRESH_CHOICES: [u64; 5] = [0, 5, 10, 30, 60];
+
+/// 实时面板采样间隔（毫秒）
+pub const LIVE_SAMPLE_INTERVAL_MS: u64 = 1000;
+
+/// 实时面板每隔多少次采样读取一次传感器（WMI 查询较慢，不必每秒读取）
+pub const SENSOR_SAMPLE_EVERY: u32 = 5;
+
+/// 实时面板中列出的资源占用最高的进程数
+pub const TOP_PROCESS_COUNT: usize = 8;
+
+/// 保存截图时在硬件概览区域四周保留的边距（逻辑像素）
+pub const SCREENSHOT_MARGIN: f32 = 8.0;
+
+/// 历史记录文件名（位于配置目录）
+pub const HISTORY_FILE_NAME: &str = "history.jsonl";
+
+/// 检测结果缓存文件名（位于配置目录）
+pub const DETECTION_CACHE_FILE_NAME: &str = "detection_cache.json";
+
+/// 检测结果缓存的有效期（秒），超过后启动时不再显示缓存
+pub const DETECTION_CACHE_MAX_AGE_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// pci.ids 下载地址
+pub const PCI_IDS_URL: &str = "https://pci-ids.ucw.cz/v2.2/pci.ids";
+
+/// usb.ids 下载地址
+pub const USB_IDS_URL: &str = "https://www.linux-usb.org/usb.ids";
+
+/// 下载的设备数据至少应包含的厂商数，少于该数量视为下载不完整
+pub const IDS_MIN_VENDORS: usize = 500;
+
+/// 上一次检测的硬件快照文件名（位于配置目录）
+pub const SNAPSHOT_FILE_NAME: &str = "last_snapshot.json";
+
+/// 性能测试历史文件名（位于配置目录）
+pub const BENCHMARK_HISTORY_FILE_NAME: &str = "benchmarks.jsonl";
+
+/// 性能测试成绩下降超过该比例（百分比）时视为性能回退
+pub const BENCHMARK_REGRESSION_PERCENT: f64 = 10.0;
+
+/// 电池损耗的默认提示阈值（百分比），即健康度低于 80% 时提示
+pub const BATTERY_DEGRADATION_WARNING_PERCENT: f64 = 20.0;
+
+/// 以管理员身份重新启动时传递交接文件路径的命令行参数
+pub const HANDOVER_ARG: &str = "--handover";
+
+/// 硬件变化事件合并等待时间（毫秒），设备在此时间内没有新事件才触发重新检测
+pub const HOTPLUG_SETTLE_MS: u64 = 1500;
+
+/// 托盘提示中 CPU 占用与温度的刷新间隔（毫秒）
+pub const TRAY_REFRESH_MS: u32 = 1000;
+
+/// WMI 连接或单次查询的默认超时时间（秒）
+///
+/// Win32_PnPSignedDriver 等查询在低配电脑上需要数秒，超时时间不宜过短
+pub const WMI_TIMEOUT_SECS: u64 = 20;
+
+/// 以定时检测模式运行（不显示界面，检测并记录日志后退出）的命令行参数
+pub const DAEMON_ARG: &str = "--daemon";
+
+/// 定时检测日志目录名（位于配置目录）
+pub const DAEMON_LOG_DIR_NAME: &str = "logs";
+
+/// 定时检测日志最多保留的文件数（每月一个文件）
+pub const DAEMON_LOG_KEEP_FILES: usize = 24;
+
+/// 启动本地 HTTP 接口的命令行参数，后跟监听地址，如 `--serve 127.0.0.1:9182`
+pub const SERVE_ARG: &str = "--serve";
+
+/// 本地 HTTP 接口读取单个请求的超时时间（秒）
+pub const SERVE_READ_TIMEOUT_SECS: u64 = 5;
+
+/// 本地 HTTP 接口请求行与单个请求头的最大长度（字节）
+pub const SERVE_MAX_LINE_BYTES: usize = 8 * 1024;
+
+/// 本地 HTTP 接口单个请求最多的请求头数
+pub const SERVE_MAX_HEADERS: usize = 64;
+
+/// 以远程上报模式运行（不显示界面，检测并上报到收集服务后退出）的命令行参数
+pub const AGENT_ARG: &str = "--agent";
+
+/// 远程上报认证令牌的环境变量，优先于配置文件中的 `agent_token`
+pub const AGENT_TOKEN_ENV: &str = "HARDWARE_MASTER_AGENT_TOKEN";
+
+/// 远程上报连接、发送与接收的超时时间（秒）
+pub const AGENT_TIMEOUT_SECS: u64 = 30;
+
+/// 在线驱动规则文件的最大长度（字节），超出时视为下载失败
+pub const DRIVER_RULES_MAX_BYTES: usize = 1024 * 1024;
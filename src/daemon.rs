@@ -0,0 +1,226 @@
+//! 定时检测模式
+//!
+//! 以 `--daemon` 启动时不显示界面：按计划执行若干次检测，每次将完整检测结果追加到配置目录下的
+//! `logs\detections-YYYY-MM.jsonl`（按月分文件，只保留最近若干个文件），同时写入历史记录供趋势页使用，
+//! 全部完成后退出。配合 Windows 任务计划程序定期运行，可在数月内跟踪电池损耗与硬盘健康。
+//!
+//! 检测结果日志依赖检测结果的序列化，需要启用 `serialize` 特性。
+
+#[cfg(feature = "serialize")]
+use crate::cache::CachedDetection;
+use crate::config::AppConfig;
+use crate::constants::{DAEMON_ARG, DAEMON_LOG_DIR_NAME, DAEMON_LOG_KEEP_FILES};
+#[cfg(feature = "serialize")]
+use crate::history::{append_jsonl, HistoryStore, Snapshot};
+use crate::HardwareDetector;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// 检测结果日志文件名前缀
+const LOG_FILE_PREFIX: &str = "detections-";
+
+/// 检测结果日志文件扩展名
+const LOG_FILE_EXTENSION: &str = ".jsonl";
+
+/// 定时检测参数
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaemonOptions {
+    /// 两次检测的间隔
+    pub interval: Duration,
+    /// 检测次数，完成后退出
+    pub runs: u32,
+}
+
+impl Default for DaemonOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(60 * 60),
+            runs: 1,
+        }
+    }
+}
+
+impl DaemonOptions {
+    /// 解析命令行参数，未指定 `--daemon` 时返回 `Ok(None)`
+    ///
+    /// 支持 `--interval <分钟>` 与 `--runs <次数>`，默认只检测一次
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::daemon::DaemonOptions;
+    /// use std::time::Duration;
+    /// let args = ["硬大师.exe", "--daemon", "--interval", "30", "--runs", "4"].map(String::from);
+    /// let options = DaemonOptions::from_args(args).unwrap().unwrap();
+    /// assert_eq!(options.interval, Duration::from_secs(30 * 60));
+    /// assert_eq!(options.runs, 4);
+    /// assert_eq!(DaemonOptions::from_args(["硬大师.exe".to_string()]), Ok(None));
+    /// ```
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut daemon = false;
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                DAEMON_ARG => daemon = true,
+                "--interval" => {
+                    let minutes = parse_positive(args.next(), "--interval")?;
+                    options.interval = Duration::from_secs(u64::from(minutes) * 60);
+                }
+                "--runs" => options.runs = parse_positive(args.next(), "--runs")?,
+                _ => {}
+            }
+        }
+        Ok(daemon.then_some(options))
+    }
+}
+
+/// 解析正整数参数值
+fn parse_positive(value: Option<String>, name: &str) -> Result<u32, String> {
+    value
+        .as_deref()
+        .and_then(|value| value.parse::<u32>().ok())
+        .filter(|value| *value > 0)
+        .ok_or_else(|| format!("{} 需要一个正整数", name))
+}
+
+/// 按月分文件的检测结果日志
+#[derive(Debug, Clone)]
+pub struct DetectionLog {
+    dir: PathBuf,
+    /// 最多保留的日志文件数
+    keep_files: usize,
+}
+
+impl DetectionLog {
+    /// 使用指定目录创建日志
+    ///
+    /// * `dir` - 日志目录
+    /// * `keep_files` - 最多保留的日志文件数，超出时删除最早的文件
+    pub fn new(dir: impl Into<PathBuf>, keep_files: usize) -> Self {
+        Self {
+            dir: dir.into(),
+            keep_files,
+        }
+    }
+
+    /// 使用配置目录下的默认日志目录创建日志
+    pub fn open_default() -> Option<Self> {
+        AppConfig::config_dir()
+            .map(|dir| Self::new(dir.join(DAEMON_LOG_DIR_NAME), DAEMON_LOG_KEEP_FILES))
+    }
+
+    /// 日志目录
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 记录时间所在月份的日志文件名
+    ///
+    /// * `timestamp` - 记录时间（Unix 时间戳，秒），按本地时区划分月份
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::daemon::DetectionLog;
+    /// // 2024-10-16 12:00:00 UTC，任何时区下都在 10 月
+    /// assert_eq!(DetectionLog::file_name(1729080000), "detections-2024-10.jsonl");
+    /// ```
+    pub fn file_name(timestamp: i64) -> String {
+        let month = chrono::DateTime::from_timestamp(timestamp, 0)
+            .map(|time| {
+                time.with_timezone(&chrono::Local)
+                    .format("%Y-%m")
+                    .to_string()
+            })
+            .unwrap_or_else(|| "unknown".to_string());
+        format!("{}{}{}", LOG_FILE_PREFIX, month, LOG_FILE_EXTENSION)
+    }
+
+    /// 追加一条检测结果，并删除超出保留数量的旧日志，返回写入的文件路径
+    #[cfg(feature = "serialize")]
+    pub fn append(&self, record: &CachedDetection) -> Result<PathBuf, String> {
+        let path = self.dir.join(Self::file_name(record.timestamp));
+        append_jsonl(&path, record)?;
+        self.rotate()?;
+        Ok(path)
+    }
+
+    /// 删除超出保留数量的最早日志文件，返回删除的文件数
+    ///
+    /// 文件名中的年月按字典序即为时间顺序
+    pub fn rotate(&self) -> Result<usize, String> {
+        let entries =
+            std::fs::read_dir(&self.dir).map_err(|e| format!("读取日志目录失败: {}", e))?;
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| {
+                        name.starts_with(LOG_FILE_PREFIX) && name.ends_with(LOG_FILE_EXTENSION)
+                    })
+            })
+            .collect();
+        if files.len() <= self.keep_files {
+            return Ok(0);
+        }
+        files.sort();
+        let expired = files.len() - self.keep_files;
+        for path in &files[..expired] {
+            std::fs::remove_file(path)
+                .map_err(|e| format!("删除旧日志 {} 失败: {}", path.display(), e))?;
+        }
+        Ok(expired)
+    }
+}
+
+/// 按用户配置执行一次完整检测
+///
+/// 单个类别检测失败只记录警告，其余类别的结果照常返回
+///
+/// * `config` - 用户配置（启用的检测类别、并发度与 WMI 超时时间）
+pub fn detect_once(config: &AppConfig) -> HardwareDetector {
+    #[cfg(windows)]
+    crate::utils::wmi::set_default_timeout(Duration::from_secs(config.wmi_timeout_secs));
+    let mut detector = HardwareDetector::new();
+    detector.set_enabled_categories(&config.enabled_detectors());
+    detector.set_concurrency(config.detection_threads, config.detection_priority);
+    if let Err(e) = detector.detect_all() {
+        log::warn!("部分检测失败: {}", e);
+    }
+    detector
+}
+
+/// 按参数执行定时检测，全部完成后返回
+///
+/// 单个类别检测失败不影响记录，其余类别的结果照常写入日志
+///
+/// * `options` - 定时检测参数
+/// * `config` - 用户配置（启用的检测类别与并发度）
+#[cfg(feature = "serialize")]
+pub fn run(options: &DaemonOptions, config: &AppConfig) -> Result<(), String> {
+    let log = DetectionLog::open_default().ok_or_else(|| "无法确定配置目录".to_string())?;
+    let history = HistoryStore::open_default();
+
+    for run in 0..options.runs {
+        if run > 0 {
+            std::thread::sleep(options.interval);
+        }
+
+        let detector = detect_once(config);
+        let timestamp = chrono::Local::now().timestamp();
+        let path = log.append(&CachedDetection::from_detector(&detector, timestamp))?;
+        if let Some(history) = &history {
+            if let Err(e) = history.append(&Snapshot::from_detector(&detector, timestamp)) {
+                log::warn!("{}", e);
+            }
+        }
+        log::info!(
+            "第 {}/{} 次检测已记录到 {}",
+            run + 1,
+            options.runs,
+            path.display()
+        );
+    }
+    Ok(())
+}
@@ -1,39 +1,46 @@
+#[cfg(windows)]
+use crate::detector::pci;
+#[cfg(windows)]
 use crate::detector::DetectionError;
+use crate::iddb;
+#[cfg(windows)]
 use crate::utils;
+#[cfg(windows)]
 use std::mem;
+#[cfg(windows)]
 use windows::Win32::Devices::DeviceAndDriverInstallation::{
     SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInfo, DIGCF_DEVICEINTERFACE, DIGCF_PRESENT,
     HDEVINFO, SPDRP_DEVICEDESC, SPDRP_FRIENDLYNAME, SPDRP_HARDWAREID, SPDRP_MFG, SP_DEVINFO_DATA,
 };
+#[cfg(windows)]
 use windows::Win32::Foundation::{GetLastError, ERROR_NO_MORE_ITEMS};
+#[cfg(windows)]
 use windows::Win32::Media::KernelStreaming::KSCATEGORY_AUDIO;
 
 /// 声卡信息
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct AudioInfo {
     /// 音频设备列表
     pub devices: Vec<AudioDevice>,
 }
 
-impl Default for AudioInfo {
-    fn default() -> Self {
-        Self {
-            devices: Vec::new(),
-        }
-    }
-}
-
 /// 音频设备信息
 ///
 /// 表示单个音频设备的基本信息。
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct AudioDevice {
     /// 设备名称
     pub name: String,
     /// 制造商
     pub manufacturer: String,
+    /// 板卡厂商（子系统厂商），如显卡 HDMI 音频所在显卡的厂商
+    pub board_vendor: String,
     /// 设备 ID（硬件 ID）
     pub device_id: String,
+    /// 连接方式
+    pub transport: AudioTransport,
 }
 
 impl Default for AudioDevice {
@@ -41,12 +48,93 @@ impl Default for AudioDevice {
         Self {
             name: "未知".to_string(),
             manufacturer: "未知".to_string(),
+            board_vendor: "未知".to_string(),
             device_id: "未知".to_string(),
+            transport: AudioTransport::default(),
+        }
+    }
+}
+
+/// 音频设备连接方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum AudioTransport {
+    /// 主板或显卡上的 HD Audio 控制器
+    #[default]
+    HdAudio,
+    /// USB 声卡、耳机等
+    Usb,
+    /// 蓝牙耳机、音箱
+    Bluetooth,
+}
+
+impl std::fmt::Display for AudioTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            AudioTransport::HdAudio => "HD Audio",
+            AudioTransport::Usb => "USB",
+            AudioTransport::Bluetooth => "蓝牙",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl AudioTransport {
+    /// 根据设备实例 ID 的总线前缀判断连接方式，非音频总线返回 None
+    ///
+    /// 蓝牙音频设备的实例 ID 以 `BTHENUM\`（A2DP）或 `BTHHFENUM\`（免提）开头
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::audio::AudioTransport;
+    /// assert_eq!(
+    ///     AudioTransport::from_instance_id("USB\\VID_046D&PID_0A44&MI_00\\7&1A2B3C4D&0&0000"),
+    ///     Some(AudioTransport::Usb)
+    /// );
+    /// assert_eq!(
+    ///     AudioTransport::from_instance_id("BTHHFENUM\\BthHFPAudio\\8&2F1E0A7B&0&97"),
+    ///     Some(AudioTransport::Bluetooth)
+    /// );
+    /// assert_eq!(AudioTransport::from_instance_id("SWD\\MMDEVAPI\\{0.0.0.00000000}"), None);
+    /// ```
+    pub fn from_instance_id(instance_id: &str) -> Option<Self> {
+        let bus = instance_id.split('\\').next()?.to_uppercase();
+        match bus.as_str() {
+            "HDAUDIO" => Some(AudioTransport::HdAudio),
+            "USB" => Some(AudioTransport::Usb),
+            "BTHENUM" | "BTHHFENUM" => Some(AudioTransport::Bluetooth),
+            _ => None,
+        }
+    }
+}
+
+/// 从 USB 设备实例 ID 中提取厂商 ID 与产品 ID
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::audio::parse_usb_vid_pid;
+/// assert_eq!(
+///     parse_usb_vid_pid("USB\\VID_046D&PID_0A44&MI_00\\7&1A2B3C4D&0&0000"),
+///     Some((0x046d, 0x0a44))
+/// );
+/// assert_eq!(parse_usb_vid_pid("HDAUDIO\\FUNC_01&VEN_10EC&DEV_0897"), None);
+/// ```
+pub fn parse_usb_vid_pid(instance_id: &str) -> Option<(u16, u16)> {
+    let hardware_params = instance_id.split('\\').nth(1)?;
+    let mut vendor_id = None;
+    let mut product_id = None;
+    for pair in hardware_params.split('&') {
+        match pair.split_once('_') {
+            Some(("VID", value)) => vendor_id = iddb::parse_id(value),
+            Some(("PID", value)) => product_id = iddb::parse_id(value),
+            _ => {}
         }
     }
+    Some((vendor_id?, product_id?))
 }
 
 /// 检测声卡信息
+#[cfg(windows)]
 pub fn detect_audio() -> Result<AudioInfo, DetectionError> {
     unsafe {
         let mut devices: Vec<AudioDevice> = Vec::new();
@@ -61,69 +149,59 @@ pub fn detect_audio() -> Result<AudioInfo, DetectionError> {
             let mut device_index = 0;
 
             loop {
-                match enumerate_device(device_info_set, device_index) {
-                    Ok(Some(device)) => {
-                        devices.push(device);
-                        device_index += 1;
-                    }
-                    Ok(None) => {
-                        break;
-                    }
-                    Err(e) => {
-                        log::warn!("枚举设备索引 {} 时出错: {:?}", device_index, e);
-                        device_index += 1;
-                        continue;
+                let mut device_info_data = SP_DEVINFO_DATA {
+                    cbSize: mem::size_of::<SP_DEVINFO_DATA>() as u32,
+                    ..Default::default()
+                };
+                if let Err(e) =
+                    SetupDiEnumDeviceInfo(device_info_set, device_index, &mut device_info_data)
+                {
+                    if GetLastError() != ERROR_NO_MORE_ITEMS {
+                        log::warn!("SetupDiEnumDeviceInfo 失败: {:?}", e);
                     }
+                    break;
+                }
+
+                match enumerate_device(device_info_set, &device_info_data) {
+                    Ok(Some(device)) => devices.push(device),
+                    Ok(None) => {}
+                    Err(e) => log::warn!("枚举设备索引 {} 时出错: {:?}", device_index, e),
                 }
+                device_index += 1;
             }
         }
         Ok(AudioInfo { devices })
     }
 }
 
-/// 枚举指定索引的设备
+/// 读取单个设备的信息
 ///
-/// 获取指定索引的设备信息，并返回设备元组。
-/// 如果设备不是 HDAUDIO 设备，返回 `Ok(None)`。
+/// 仅保留 HD Audio、USB 与蓝牙音频设备，其他设备（如软件设备）返回 `Ok(None)`。
 ///
 /// * `device_info_set` - 设备信息集句柄
-/// * `device_index` - 设备索引
+/// * `device_info_data` - 设备信息数据
+#[cfg(windows)]
 unsafe fn enumerate_device(
     device_info_set: HDEVINFO,
-    device_index: u32,
+    device_info_data: &SP_DEVINFO_DATA,
 ) -> Result<Option<AudioDevice>, DetectionError> {
-    let mut device_info_data = SP_DEVINFO_DATA {
-        cbSize: mem::size_of::<SP_DEVINFO_DATA>() as u32,
-        ..Default::default()
-    };
-
-    SetupDiEnumDeviceInfo(device_info_set, device_index, &mut device_info_data).map_err(|e| {
-        let err = GetLastError();
-        if err == ERROR_NO_MORE_ITEMS {
-            return DetectionError::AudioError("没有更多设备".to_string());
-        }
-        DetectionError::AudioError(format!("SetupDiEnumDeviceInfo 失败: {:?}", e))
-    })?;
-
     let device_instance_id =
-        utils::device::get_device_instance_id(device_info_set, &device_info_data)?;
+        utils::device::get_device_instance_id(device_info_set, device_info_data)?;
 
-    // 过滤非 HDAUDIO 设备
-    let is_hd_audio_device = device_instance_id.starts_with("HDAUDIO\\");
-    if !is_hd_audio_device {
+    let Some(transport) = AudioTransport::from_instance_id(&device_instance_id) else {
         return Ok(None);
-    }
+    };
 
     // 优先使用设备描述，其次使用友好名称
     let name = utils::device::get_device_property(
         device_info_set,
-        &device_info_data,
+        device_info_data,
         SPDRP_DEVICEDESC,
     )
     .or_else(|| {
         utils::device::get_device_property(
             device_info_set,
-            &device_info_data,
+            device_info_data,
             SPDRP_FRIENDLYNAME,
         )
     })
@@ -131,21 +209,46 @@ unsafe fn enumerate_device(
 
     let manufacturer = utils::device::get_device_property(
         device_info_set,
-        &device_info_data,
+        device_info_data,
         SPDRP_MFG
     )
     .unwrap_or_else(|| "未知制造商".to_string());
 
     let device_id = utils::device::get_device_property(
         device_info_set,
-        &device_info_data,
+        device_info_data,
         SPDRP_HARDWAREID,
     )
     .unwrap_or_default();
 
+    // USB 音频类驱动的设备描述与制造商多为通用名称，优先使用 usb.ids 中的名称
+    let (name, manufacturer) = match lookup_usb_names(&device_instance_id) {
+        Some((vendor_name, device_name)) if transport == AudioTransport::Usb => {
+            (device_name.unwrap_or(name), vendor_name)
+        }
+        _ => (name, manufacturer),
+    };
+
+    let board_vendor = pci::resolve_vendors(&device_instance_id)
+        .board_vendor
+        .unwrap_or_else(|| "未知".to_string());
+
     Ok(Some(AudioDevice {
         name,
         manufacturer,
+        board_vendor,
         device_id,
+        transport,
     }))
 }
+
+/// 在 usb.ids 中查询 USB 设备的厂商名称与产品名称
+///
+/// * `instance_id` - 设备实例 ID
+#[cfg(windows)]
+fn lookup_usb_names(instance_id: &str) -> Option<(String, Option<String>)> {
+    let (vendor_id, product_id) = parse_usb_vid_pid(instance_id)?;
+    let db = iddb::db();
+    let vendor = db.usb().vendor(vendor_id);
+    Some((vendor.name()?, vendor.device(product_id).name()))
+}
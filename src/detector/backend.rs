@@ -0,0 +1,182 @@
+//! 平台检测后端模块
+//!
+//! 每个检测类别对应一个后端 trait，[`HardwareDetector`](super::HardwareDetector) 通过后端执行检测。
+//! Windows 上使用 [`WindowsBackend`] 调用 WMI、注册表、SetupAPI 等系统接口；
+//! 其他平台使用 [`UnsupportedBackend`]，所有类别返回 [`DetectionError::Unsupported`]，
+//! 库仍可编译，报告、历史记录、快照对比等不依赖系统接口的功能照常使用。
+//! 测试中可以使用 [`SourceBackend`](super::source::SourceBackend) 搭配模拟数据源运行部分检测逻辑。
+//!
+//! 各 trait 的方法都有返回“不支持”的默认实现，实现者只需覆盖能够检测的类别。
+
+use super::{
+    AudioInfo, BatteryInfo, CpuInfo, DetectionCategory, DetectionError, DiskInfo, GpuInfo,
+    MemoryInfo, MonitorInfo, MotherboardInfo, NetworkInfo, SecurityInfo, SystemInfo,
+};
+use std::sync::Arc;
+
+/// 宏：声明单个类别的检测后端 trait，默认实现返回“不支持”错误
+macro_rules! category_backend {
+    ($(#[$meta:meta])* $name:ident, $detect_fn:ident, $info:ty, $category:ident) => {
+        $(#[$meta])*
+        pub trait $name {
+            /// 执行检测，默认返回 [`DetectionError::Unsupported`]
+            fn $detect_fn(&self) -> Result<$info, DetectionError> {
+                Err(DetectionError::Unsupported(
+                    DetectionCategory::$category.name().to_string(),
+                ))
+            }
+        }
+    };
+}
+
+category_backend!(
+    /// 系统信息检测后端
+    SystemBackend, detect_system, SystemInfo, System
+);
+category_backend!(
+    /// CPU 信息检测后端
+    CpuBackend, detect_cpu, CpuInfo, Cpu
+);
+category_backend!(
+    /// 显卡信息检测后端
+    GpuBackend, detect_gpu, GpuInfo, Gpu
+);
+category_backend!(
+    /// 内存信息检测后端
+    MemoryBackend, detect_memory, MemoryInfo, Memory
+);
+category_backend!(
+    /// 磁盘信息检测后端
+    DiskBackend, detect_disk, DiskInfo, Disk
+);
+category_backend!(
+    /// 主板信息检测后端
+    MotherboardBackend, detect_motherboard, MotherboardInfo, Motherboard
+);
+category_backend!(
+    /// 网络信息检测后端
+    NetworkBackend, detect_network, NetworkInfo, Network
+);
+category_backend!(
+    /// 声卡信息检测后端
+    AudioBackend, detect_audio, AudioInfo, Audio
+);
+category_backend!(
+    /// 显示器信息检测后端
+    MonitorBackend, detect_display, MonitorInfo, Monitor
+);
+category_backend!(
+    /// 电池信息检测后端
+    BatteryBackend, detect_battery, BatteryInfo, Battery
+);
+category_backend!(
+    /// 安全特性检测后端
+    SecurityBackend, detect_security, SecurityInfo, Security
+);
+
+/// 完整的硬件检测后端，实现全部类别 trait 的类型自动实现
+pub trait HardwareBackend:
+    SystemBackend
+    + CpuBackend
+    + GpuBackend
+    + MemoryBackend
+    + DiskBackend
+    + MotherboardBackend
+    + NetworkBackend
+    + AudioBackend
+    + MonitorBackend
+    + BatteryBackend
+    + SecurityBackend
+    + Send
+    + Sync
+{
+}
+
+impl<T> HardwareBackend for T where
+    T: SystemBackend
+        + CpuBackend
+        + GpuBackend
+        + MemoryBackend
+        + DiskBackend
+        + MotherboardBackend
+        + NetworkBackend
+        + AudioBackend
+        + MonitorBackend
+        + BatteryBackend
+        + SecurityBackend
+        + Send
+        + Sync
+{
+}
+
+/// 不支持任何检测的后端，非 Windows 平台的默认后端
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::backend::{MemoryBackend, UnsupportedBackend};
+/// use hardware_master::detector::DetectionError;
+/// assert!(matches!(
+///     UnsupportedBackend.detect_memory(),
+///     Err(DetectionError::Unsupported(_))
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnsupportedBackend;
+
+impl SystemBackend for UnsupportedBackend {}
+impl CpuBackend for UnsupportedBackend {}
+impl GpuBackend for UnsupportedBackend {}
+impl MemoryBackend for UnsupportedBackend {}
+impl DiskBackend for UnsupportedBackend {}
+impl MotherboardBackend for UnsupportedBackend {}
+impl NetworkBackend for UnsupportedBackend {}
+impl AudioBackend for UnsupportedBackend {}
+impl MonitorBackend for UnsupportedBackend {}
+impl BatteryBackend for UnsupportedBackend {}
+impl SecurityBackend for UnsupportedBackend {}
+
+/// 使用 Windows 系统接口的检测后端
+#[cfg(windows)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WindowsBackend;
+
+/// 宏：将后端 trait 方法委托给检测模块中的同名函数
+#[cfg(windows)]
+macro_rules! delegate_backend {
+    ($($name:ident => $module:ident::$detect_fn:ident -> $info:ty;)*) => {
+        $(
+            impl $name for WindowsBackend {
+                fn $detect_fn(&self) -> Result<$info, DetectionError> {
+                    super::$module::$detect_fn()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(windows)]
+delegate_backend! {
+    SystemBackend => system::detect_system -> SystemInfo;
+    CpuBackend => cpu::detect_cpu -> CpuInfo;
+    GpuBackend => gpu::detect_gpu -> GpuInfo;
+    MemoryBackend => memory::detect_memory -> MemoryInfo;
+    DiskBackend => disk::detect_disk -> DiskInfo;
+    MotherboardBackend => motherboard::detect_motherboard -> MotherboardInfo;
+    NetworkBackend => network::detect_network -> NetworkInfo;
+    AudioBackend => audio::detect_audio -> AudioInfo;
+    MonitorBackend => monitor::detect_display -> MonitorInfo;
+    BatteryBackend => battery::detect_battery -> BatteryInfo;
+    SecurityBackend => security::detect_security -> SecurityInfo;
+}
+
+/// 当前平台的默认检测后端
+pub fn platform() -> Arc<dyn HardwareBackend> {
+    #[cfg(windows)]
+    {
+        Arc::new(WindowsBackend)
+    }
+    #[cfg(not(windows))]
+    {
+        Arc::new(UnsupportedBackend)
+    }
+}
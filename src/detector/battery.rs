@@ -1,32 +1,45 @@
+use crate::detector::power::PowerStatus;
+#[cfg(windows)]
+use crate::detector::power::{charger_watts, detect_power_status};
+#[cfg(windows)]
 use crate::detector::DetectionError;
+#[cfg(windows)]
 use crate::utils;
+#[cfg(windows)]
 use std::os::windows::io::RawHandle;
+#[cfg(windows)]
 use windows::core::PCWSTR;
+#[cfg(windows)]
 use windows::Win32::Devices::DeviceAndDriverInstallation::GUID_DEVCLASS_BATTERY;
+#[cfg(windows)]
 use windows::Win32::Foundation::{CloseHandle, GetLastError, GENERIC_READ, GENERIC_WRITE, HANDLE};
+#[cfg(windows)]
 use windows::Win32::Storage::FileSystem::*;
+#[cfg(windows)]
 use windows::Win32::System::Power::{
-    BatteryDeviceName, BatteryInformation, BatteryManufactureName, BATTERY_INFORMATION,
-    BATTERY_QUERY_INFORMATION, BATTERY_QUERY_INFORMATION_LEVEL, BATTERY_TAG_INVALID,
-    IOCTL_BATTERY_QUERY_INFORMATION, IOCTL_BATTERY_QUERY_TAG,
+    BatteryChargingSourceType_USB, BatteryDeviceName, BatteryInformation, BatteryManufactureDate,
+    BatteryManufactureName, BatterySerialNumber, BatteryTemperature, BATTERY_INFORMATION,
+    BATTERY_MANUFACTURE_DATE, BATTERY_POWER_ON_LINE, BATTERY_QUERY_INFORMATION,
+    BATTERY_QUERY_INFORMATION_LEVEL, BATTERY_STATUS, BATTERY_TAG_INVALID, BATTERY_UNKNOWN_RATE,
+    BATTERY_USB_CHARGER_STATUS, BATTERY_WAIT_STATUS, IOCTL_BATTERY_QUERY_INFORMATION,
+    IOCTL_BATTERY_QUERY_STATUS, IOCTL_BATTERY_QUERY_TAG,
 };
+#[cfg(windows)]
 use windows::Win32::System::IO::DeviceIoControl;
 
 /// 电池信息
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct BatteryInfo {
     /// 电池列表
     pub batteries: Vec<Battery>,
-}
-
-impl Default for BatteryInfo {
-    fn default() -> Self {
-        Self { batteries: vec![] }
-    }
+    /// 电源状态（供电方式、电源计划、充电器功率），台式机同样可用
+    pub power: PowerStatus,
 }
 
 /// 电池
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Battery {
     /// 电池名称
     pub name: String,
@@ -42,6 +55,18 @@ pub struct Battery {
     pub full_charged_capacity: u32,
     /// 循环次数
     pub cycle_count: u32,
+    /// 当前剩余容量 (mWh)
+    pub remaining_capacity: u32,
+    /// 是否接通外部电源
+    pub power_online: bool,
+    /// 充放电功率 (mW)，负数表示放电，未知时为 None
+    pub rate: Option<i32>,
+    /// 电池温度 (°C)，电池不支持时为 None
+    pub temperature: Option<f64>,
+    /// 序列号
+    pub serial_number: String,
+    /// 生产日期（YYYY-MM-DD），电池不支持时为 None
+    pub manufacture_date: Option<String>,
 }
 
 impl Default for Battery {
@@ -54,12 +79,19 @@ impl Default for Battery {
             designed_capacity: 0,
             full_charged_capacity: 0,
             cycle_count: 0,
+            remaining_capacity: 0,
+            power_online: false,
+            rate: None,
+            temperature: None,
+            serial_number: "未知".to_string(),
+            manufacture_date: None,
         }
     }
 }
 
 /// 电池化学成分
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum BatteryChemistry {
     Other = 1,
     Unknown,
@@ -86,25 +118,68 @@ impl From<&String> for BatteryChemistry {
     }
 }
 
-impl ToString for BatteryChemistry {
+impl std::fmt::Display for BatteryChemistry {
     /// 转换为可视化字符串，比如 "NickelCadmium" -> "镍镉电池"
-    fn to_string(&self) -> String {
-        match self {
-            BatteryChemistry::Other => "其他".to_string(),
-            BatteryChemistry::Unknown => "未知".to_string(),
-            BatteryChemistry::LeadAcid => "铅酸电池".to_string(),
-            BatteryChemistry::NickelCadmium => "镍镉电池".to_string(),
-            BatteryChemistry::NickelMetalHydride => "镍氢电池".to_string(),
-            BatteryChemistry::LithiumIon => "锂离子电池".to_string(),
-            BatteryChemistry::ZincAir => "锌空气电池".to_string(),
-            BatteryChemistry::LithiumPolymer => "锂聚合物电池".to_string(),
-        }
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            BatteryChemistry::Other => "其他",
+            BatteryChemistry::Unknown => "未知",
+            BatteryChemistry::LeadAcid => "铅酸电池",
+            BatteryChemistry::NickelCadmium => "镍镉电池",
+            BatteryChemistry::NickelMetalHydride => "镍氢电池",
+            BatteryChemistry::LithiumIon => "锂离子电池",
+            BatteryChemistry::ZincAir => "锌空气电池",
+            BatteryChemistry::LithiumPolymer => "锂聚合物电池",
+        };
+        write!(f, "{}", name)
     }
 }
 
+/// 将电池报告的温度（单位 0.1 K）转换为摄氏度
+///
+/// 返回 0 或超出合理范围（-40 ~ 120 °C）的值视为未知
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::battery::temperature_from_tenths_kelvin;
+/// assert_eq!(temperature_from_tenths_kelvin(3031), Some(30.0));
+/// assert_eq!(temperature_from_tenths_kelvin(0), None);
+/// assert_eq!(temperature_from_tenths_kelvin(65535), None);
+/// ```
+pub fn temperature_from_tenths_kelvin(value: u32) -> Option<f64> {
+    let celsius = (value as f64 - 2731.5).round() / 10.0;
+    (value != 0 && (-40.0..=120.0).contains(&celsius)).then_some(celsius)
+}
+
+/// 格式化电池生产日期，日期无效时返回 None
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::battery::format_manufacture_date;
+/// assert_eq!(format_manufacture_date(2023, 5, 12), Some("2023-05-12".to_string()));
+/// assert_eq!(format_manufacture_date(0, 0, 0), None);
+/// assert_eq!(format_manufacture_date(2023, 13, 1), None);
+/// ```
+pub fn format_manufacture_date(year: u16, month: u8, day: u8) -> Option<String> {
+    if year < 1990 {
+        return None;
+    }
+    chrono::NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// 电池充电器状态信息级别（BatteryChargerStatus），windows crate 未导出该常量
+#[cfg(windows)]
+const BATTERY_CHARGER_STATUS_LEVEL: BATTERY_QUERY_INFORMATION_LEVEL =
+    BATTERY_QUERY_INFORMATION_LEVEL(9);
+
 /// 检测电池信息
+#[cfg(windows)]
 pub fn detect_battery() -> Result<BatteryInfo, DetectionError> {
-    let mut info = BatteryInfo::default();
+    let mut info = BatteryInfo {
+        power: detect_power_status(),
+        ..Default::default()
+    };
 
     unsafe {
         let device_paths = utils::device::enumerate_device_paths(&GUID_DEVCLASS_BATTERY)?;
@@ -179,6 +254,33 @@ pub fn detect_battery() -> Result<BatteryInfo, DetectionError> {
                 _ => "未知".to_string(),
             };
 
+            // 查询序列号、温度与生产日期，部分电池不支持这些信息
+            let serial_number = match query_string_info(handle_raw, tag, BatterySerialNumber) {
+                Ok(serial) if !serial.trim().is_empty() => serial.trim().to_string(),
+                _ => "未知".to_string(),
+            };
+            let temperature = query_u32_info(handle_raw, tag, BatteryTemperature)
+                .ok()
+                .and_then(temperature_from_tenths_kelvin);
+            let manufacture_date = query_manufacture_date(handle_raw, tag)
+                .ok()
+                .and_then(|date| format_manufacture_date(date.Year, date.Month, date.Day));
+
+            // 查询 USB PD 充电器功率，多块电池时取最大值
+            if let Some(watts) = query_usb_charger_watts(handle_raw, tag) {
+                let current = info.power.charger_watts.unwrap_or(0.0);
+                info.power.charger_watts = Some(current.max(watts));
+            }
+
+            // 查询当前状态（电源、剩余容量、充放电功率）
+            let status = match query_status(handle_raw, tag) {
+                Ok(status) => Some(status),
+                Err(e) => {
+                    log::warn!("query_status 失败: {:?}", e);
+                    None
+                }
+            };
+
             // 构建 Battery 结构
             let battery = Battery {
                 name,
@@ -188,6 +290,14 @@ pub fn detect_battery() -> Result<BatteryInfo, DetectionError> {
                 designed_capacity: battery_info.DesignedCapacity,
                 full_charged_capacity: battery_info.FullChargedCapacity,
                 cycle_count: battery_info.CycleCount,
+                remaining_capacity: status.map_or(0, |s| s.Capacity),
+                power_online: status.map_or(false, |s| s.PowerState & BATTERY_POWER_ON_LINE != 0),
+                rate: status
+                    .map(|s| s.Rate)
+                    .filter(|rate| *rate as u32 != BATTERY_UNKNOWN_RATE),
+                temperature,
+                serial_number,
+                manufacture_date,
             };
 
             info.batteries.push(battery);
@@ -209,6 +319,7 @@ pub fn detect_battery() -> Result<BatteryInfo, DetectionError> {
 ///     query_tag(handle)?
 /// };
 /// ```
+#[cfg(windows)]
 unsafe fn query_tag(handle: RawHandle) -> Result<u32, DetectionError> {
     let mut tag = BATTERY_TAG_INVALID;
     let mut bytes_returned = 0u32;
@@ -247,6 +358,7 @@ unsafe fn query_tag(handle: RawHandle) -> Result<u32, DetectionError> {
 ///     query_information(handle, tag)?
 /// };
 /// ```
+#[cfg(windows)]
 unsafe fn query_information(
     handle: RawHandle,
     tag: u32,
@@ -277,16 +389,50 @@ unsafe fn query_information(
     Ok(info)
 }
 
-/// 查询字符串类型的电池信息
+/// 查询电池当前状态
+///
+/// * `handle` - 电池设备句柄
+/// * `tag` - 电池标签
+#[cfg(windows)]
+unsafe fn query_status(handle: RawHandle, tag: u32) -> Result<BATTERY_STATUS, DetectionError> {
+    let input = BATTERY_WAIT_STATUS {
+        BatteryTag: tag,
+        ..Default::default()
+    };
+
+    let in_bytes = std::slice::from_raw_parts(
+        &input as *const _ as *const u8,
+        std::mem::size_of_val(&input),
+    );
+
+    let out_bytes = utils::device::device_io_control(
+        handle,
+        IOCTL_BATTERY_QUERY_STATUS,
+        in_bytes,
+        std::mem::size_of::<BATTERY_STATUS>(),
+    )?;
+
+    if out_bytes.len() < std::mem::size_of::<BATTERY_STATUS>() {
+        return Err(DetectionError::BatteryError("返回数据不足".to_string()));
+    }
+
+    let status: BATTERY_STATUS = std::ptr::read(out_bytes.as_ptr() as *const _);
+    Ok(status)
+}
+
+/// 按信息级别查询电池信息，返回原始数据
 ///
 /// * `handle` - 电池设备句柄
 /// * `tag` - 电池标签
 /// * `info_level` - 信息级别
-unsafe fn query_string_info(
+/// * `out_size` - 输出缓冲区大小（字节）
+#[cfg(windows)]
+unsafe fn query_info_bytes(
     handle: RawHandle,
     tag: u32,
     info_level: BATTERY_QUERY_INFORMATION_LEVEL,
-) -> Result<String, DetectionError> {
+    out_size: usize,
+) -> Result<Vec<u8>, DetectionError> {
     let input = BATTERY_QUERY_INFORMATION {
         BatteryTag: tag,
         InformationLevel: info_level,
@@ -298,13 +444,96 @@ unsafe fn query_string_info(
         std::mem::size_of_val(&input),
     );
 
+    utils::device::device_io_control(handle, IOCTL_BATTERY_QUERY_INFORMATION, in_bytes, out_size)
+}
+
+/// 查询字符串类型的电池信息
+///
+/// * `handle` - 电池设备句柄
+/// * `tag` - 电池标签
+/// * `info_level` - 信息级别
+#[cfg(windows)]
+unsafe fn query_string_info(
+    handle: RawHandle,
+    tag: u32,
+    info_level: BATTERY_QUERY_INFORMATION_LEVEL,
+) -> Result<String, DetectionError> {
     const BUF_WCHAR: usize = 256;
-    let out_bytes = utils::device::device_io_control(
+    let out_bytes = query_info_bytes(
         handle,
-        IOCTL_BATTERY_QUERY_INFORMATION,
-        in_bytes,
+        tag,
+        info_level,
         BUF_WCHAR * std::mem::size_of::<u16>(),
     )?;
 
     Ok(utils::u16_bytes_to_string(&out_bytes))
 }
+
+/// 查询 ULONG 类型的电池信息（如温度）
+///
+/// * `handle` - 电池设备句柄
+/// * `tag` - 电池标签
+/// * `info_level` - 信息级别
+#[cfg(windows)]
+unsafe fn query_u32_info(
+    handle: RawHandle,
+    tag: u32,
+    info_level: BATTERY_QUERY_INFORMATION_LEVEL,
+) -> Result<u32, DetectionError> {
+    let out_bytes = query_info_bytes(handle, tag, info_level, std::mem::size_of::<u32>())?;
+
+    match out_bytes.get(..4) {
+        Some(bytes) => Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+        None => Err(DetectionError::BatteryError("返回数据不足".to_string())),
+    }
+}
+
+/// 查询 USB 充电器功率 (W)，充电器不是 USB 类型或驱动不支持时返回 None
+///
+/// * `handle` - 电池设备句柄
+/// * `tag` - 电池标签
+#[cfg(windows)]
+unsafe fn query_usb_charger_watts(handle: RawHandle, tag: u32) -> Option<f64> {
+    let out_bytes = query_info_bytes(
+        handle,
+        tag,
+        BATTERY_CHARGER_STATUS_LEVEL,
+        std::mem::size_of::<BATTERY_USB_CHARGER_STATUS>(),
+    )
+    .ok()?;
+
+    if out_bytes.len() < std::mem::size_of::<BATTERY_USB_CHARGER_STATUS>() {
+        return None;
+    }
+
+    let status: BATTERY_USB_CHARGER_STATUS =
+        std::ptr::read_unaligned(out_bytes.as_ptr() as *const _);
+    if status.Type != BatteryChargingSourceType_USB {
+        return None;
+    }
+    charger_watts(status.MaxCurrent, status.Voltage)
+}
+
+/// 查询电池生产日期
+///
+/// * `handle` - 电池设备句柄
+/// * `tag` - 电池标签
+#[cfg(windows)]
+unsafe fn query_manufacture_date(
+    handle: RawHandle,
+    tag: u32,
+) -> Result<BATTERY_MANUFACTURE_DATE, DetectionError> {
+    let out_bytes = query_info_bytes(
+        handle,
+        tag,
+        BatteryManufactureDate,
+        std::mem::size_of::<BATTERY_MANUFACTURE_DATE>(),
+    )?;
+
+    if out_bytes.len() < std::mem::size_of::<BATTERY_MANUFACTURE_DATE>() {
+        return Err(DetectionError::BatteryError("返回数据不足".to_string()));
+    }
+
+    let date: BATTERY_MANUFACTURE_DATE = std::ptr::read_unaligned(out_bytes.as_ptr() as *const _);
+    Ok(date)
+}
@@ -1,29 +1,46 @@
+#[cfg(windows)]
+use crate::detector::source::WindowsSource;
+use crate::detector::source::{RegistryRoot, RegistrySource};
+#[cfg(windows)]
 use crate::detector::DetectionError;
-use crate::utils;
+#[cfg(windows)]
 use std::mem;
-use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+#[cfg(windows)]
+use windows::Win32::System::Power::{
+    CallNtPowerInformation, ProcessorInformation, PROCESSOR_POWER_INFORMATION,
+};
+#[cfg(windows)]
 use windows::Win32::System::SystemInformation::{
-    GetLogicalProcessorInformation, GetNativeSystemInfo, RelationProcessorCore,
+    GetLogicalProcessorInformationEx, GetNativeSystemInfo, RelationProcessorCore,
     PROCESSOR_ARCHITECTURE_AMD64, PROCESSOR_ARCHITECTURE_ARM, PROCESSOR_ARCHITECTURE_ARM64,
     PROCESSOR_ARCHITECTURE_IA64, PROCESSOR_ARCHITECTURE_INTEL, SYSTEM_INFO,
-    SYSTEM_LOGICAL_PROCESSOR_INFORMATION,
+    SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
 };
 
 /// CPU 信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct CpuInfo {
     /// 处理器名称
     pub name: String,
     /// 处理器架构
     pub architecture: String,
-    /// 核心数
-    pub cores: String,
+    /// 物理核心数，0 表示未知
+    pub physical_cores: u32,
+    /// 逻辑核心（线程）数，0 表示未知
+    pub logical_cores: u32,
+    /// 能效核心（E 核）数，非混合架构处理器为 0
+    pub efficiency_cores: u32,
     /// 制造商
     pub vendor: String,
     /// CPU ID
     pub cpu_id: String,
-    /// 最大频率 (MHz)
+    /// 基准频率 (MHz)
+    pub base_frequency: u16,
+    /// 最大（睿频）频率 (MHz)，无法获取时与基准频率相同
     pub max_frequency: u16,
+    /// 检测时的当前频率 (MHz)，0 表示未知
+    pub current_frequency: u16,
 }
 
 impl Default for CpuInfo {
@@ -31,63 +48,224 @@ impl Default for CpuInfo {
         Self {
             name: "未知".to_string(),
             architecture: "未知".to_string(),
-            cores: "未知".to_string(),
+            physical_cores: 0,
+            logical_cores: 0,
+            efficiency_cores: 0,
+            base_frequency: 0,
             max_frequency: 0,
+            current_frequency: 0,
             vendor: "未知".to_string(),
             cpu_id: "未知".to_string(),
         }
     }
 }
 
+impl CpuInfo {
+    /// 频率描述，如 "基准 2.50 GHz / 当前 3.10 GHz / 最大 4.40 GHz"，未知的频率不显示
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::cpu::CpuInfo;
+    /// let cpu = CpuInfo {
+    ///     base_frequency: 2500,
+    ///     current_frequency: 3100,
+    ///     max_frequency: 4400,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(cpu.frequency_desc(), "基准 2.50 GHz / 当前 3.10 GHz / 最大 4.40 GHz");
+    /// let cpu = CpuInfo { base_frequency: 3600, max_frequency: 3600, ..Default::default() };
+    /// assert_eq!(cpu.frequency_desc(), "基准 3.60 GHz");
+    /// assert_eq!(CpuInfo::default().frequency_desc(), "未知");
+    /// ```
+    pub fn frequency_desc(&self) -> String {
+        let mut parts = Vec::new();
+        if self.base_frequency > 0 {
+            parts.push(format!("基准 {}", format_mhz(self.base_frequency as f64)));
+        }
+        if self.current_frequency > 0 {
+            parts.push(format!(
+                "当前 {}",
+                format_mhz(self.current_frequency as f64)
+            ));
+        }
+        if self.max_frequency > self.base_frequency {
+            parts.push(format!("最大 {}", format_mhz(self.max_frequency as f64)));
+        }
+        if parts.is_empty() {
+            "未知".to_string()
+        } else {
+            parts.join(" / ")
+        }
+    }
+}
+
+/// 将 MHz 格式化为 GHz，保留两位小数
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::cpu::format_mhz;
+/// assert_eq!(format_mhz(3792.0), "3.79 GHz");
+/// ```
+pub fn format_mhz(mhz: f64) -> String {
+    format!("{:.2} GHz", mhz / 1000.0)
+}
+
+/// 由各物理核心的效率等级统计能效核心数
+///
+/// 混合架构处理器中效率等级最高的是性能核，其余均视为能效核；所有核心等级相同时返回 0
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::cpu::count_efficiency_cores;
+/// // 6 个性能核（等级 1）+ 8 个能效核（等级 0）
+/// let classes = [[1u8; 6].as_slice(), [0u8; 8].as_slice()].concat();
+/// assert_eq!(count_efficiency_cores(&classes), 8);
+/// assert_eq!(count_efficiency_cores(&[0, 0, 0, 0]), 0);
+/// assert_eq!(count_efficiency_cores(&[]), 0);
+/// ```
+pub fn count_efficiency_cores(classes: &[u8]) -> u32 {
+    let Some(&performance_class) = classes.iter().max() else {
+        return 0;
+    };
+    classes
+        .iter()
+        .filter(|class| **class < performance_class)
+        .count() as u32
+}
+
+/// 通过 CPUID 0x16 读取处理器最大（睿频）频率 (MHz)
+///
+/// 仅较新的 Intel 处理器支持该功能叶，AMD 处理器与虚拟机通常返回 None
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn cpuid_max_frequency() -> Option<u16> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // 较新的工具链中 __cpuid 已是安全函数
+    #[allow(unused_unsafe)]
+    let max_leaf = unsafe { __cpuid(0) }.eax;
+    if max_leaf < 0x16 {
+        return None;
+    }
+    #[allow(unused_unsafe)]
+    let frequency = unsafe { __cpuid(0x16) };
+    // EBX 位 0-15：最大频率 (MHz)
+    let mhz = (frequency.ebx & 0xFFFF) as u16;
+    (mhz > 0).then_some(mhz)
+}
+
+/// 非 x86 平台无法通过 CPUID 读取频率
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+pub fn cpuid_max_frequency() -> Option<u16> {
+    None
+}
+
 /// CPU 注册表路径
 const CPU_KEY_PATH: &str = r"HARDWARE\DESCRIPTION\System\CentralProcessor\0";
 
 /// 检测 CPU 信息
+#[cfg(windows)]
 pub fn detect_cpu() -> Result<CpuInfo, DetectionError> {
+    let mut info = detect_cpu_with(&WindowsSource);
     unsafe {
-        let name = get_processor_name().unwrap_or_else(|| "未知".to_string());
-        let architecture = get_processor_architecture();
-        let cores = get_cores_info();
-        let vendor = get_vendor_identifier().unwrap_or_else(|| "未知".to_string());
-        let cpu_id = get_cpu_identifier().unwrap_or_else(|| "未知".to_string());
-        let max_frequency = get_max_frequency().unwrap_or(0);
-
-        return Ok(CpuInfo {
-            name,
-            architecture,
-            cores,
-            vendor,
-            cpu_id,
-            max_frequency,
-        });
+        info.architecture = get_processor_architecture();
+        (
+            info.physical_cores,
+            info.logical_cores,
+            info.efficiency_cores,
+        ) = get_core_counts().unwrap_or((0, 0, 0));
+        let power_info = get_power_information();
+        info.current_frequency = power_info
+            .iter()
+            .map(|info| info.CurrentMhz)
+            .max()
+            .unwrap_or(0) as u16;
+        info.max_frequency = [
+            Some(info.base_frequency),
+            cpuid_max_frequency(),
+            power_info.iter().map(|info| info.MaxMhz as u16).max(),
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(0);
     }
+    Ok(info)
 }
 
-/// 获取处理器名称
-pub unsafe fn get_processor_name() -> Option<String> {
-    utils::registry::read_registry_string(HKEY_LOCAL_MACHINE, CPU_KEY_PATH, "ProcessorNameString")
-}
+/// 从注册表读取处理器名称、制造商、CPU ID 与基准频率
+///
+/// 架构、核心数与实时频率依赖其他系统接口，由 [`detect_cpu`] 补充；
+/// 这里的最大频率与基准频率相同
+///
+/// * `source` - 注册表数据源
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::cpu::detect_cpu_with;
+/// use hardware_master::detector::source::{MockSource, RegistryRoot};
+/// let key = r"HARDWARE\DESCRIPTION\System\CentralProcessor\0";
+/// let source = MockSource::new()
+///     .with_registry_string(RegistryRoot::LocalMachine, key, "VendorIdentifier", "GenuineIntel")
+///     .with_registry_dword(RegistryRoot::LocalMachine, key, "~MHz", 3600);
+/// let cpu = detect_cpu_with(&source);
+/// assert_eq!(cpu.vendor, "GenuineIntel");
+/// assert_eq!(cpu.base_frequency, 3600);
+/// assert_eq!(cpu.name, "未知");
+/// ```
+pub fn detect_cpu_with(source: &impl RegistrySource) -> CpuInfo {
+    let read = |value| source.registry_string(RegistryRoot::LocalMachine, CPU_KEY_PATH, value);
+    let unknown = || "未知".to_string();
 
-/// 获取供应商标识符
-pub unsafe fn get_vendor_identifier() -> Option<String> {
-    utils::registry::read_registry_string(HKEY_LOCAL_MACHINE, CPU_KEY_PATH, "VendorIdentifier")
-        .or_else(|| {
-            utils::registry::read_registry_string(HKEY_LOCAL_MACHINE, CPU_KEY_PATH, "Identifier")
-        })
-}
+    let base_frequency = source
+        .registry_dword(RegistryRoot::LocalMachine, CPU_KEY_PATH, "~MHz")
+        .map(|mhz| mhz as u16)
+        .unwrap_or(0);
 
-/// 获取 CPU 标识符
-pub unsafe fn get_cpu_identifier() -> Option<String> {
-    utils::registry::read_registry_string(HKEY_LOCAL_MACHINE, CPU_KEY_PATH, "Identifier")
+    CpuInfo {
+        name: read("ProcessorNameString").unwrap_or_else(unknown),
+        // 没有 VendorIdentifier 时退回 Identifier
+        vendor: read("VendorIdentifier")
+            .or_else(|| read("Identifier"))
+            .unwrap_or_else(unknown),
+        cpu_id: read("Identifier").unwrap_or_else(unknown),
+        base_frequency,
+        max_frequency: base_frequency,
+        ..Default::default()
+    }
 }
 
-/// 获取最大频率
-pub unsafe fn get_max_frequency() -> Option<u16> {
-    utils::registry::read_registry_dword(HKEY_LOCAL_MACHINE, CPU_KEY_PATH, "~MHz")
-        .map(|mhz| mhz as u16)
+/// 通过 CallNtPowerInformation 获取各逻辑处理器的频率信息，失败时返回空列表
+///
+/// CurrentMhz 受电源管理影响，睿频时通常不超过基准频率，实时频率需通过性能计数器采样
+#[cfg(windows)]
+pub unsafe fn get_power_information() -> Vec<PROCESSOR_POWER_INFORMATION> {
+    let mut sys_info: SYSTEM_INFO = mem::zeroed();
+    GetNativeSystemInfo(&mut sys_info);
+
+    let count = sys_info.dwNumberOfProcessors as usize;
+    if count == 0 {
+        return Vec::new();
+    }
+    let mut buffer = vec![PROCESSOR_POWER_INFORMATION::default(); count];
+    let status = CallNtPowerInformation(
+        ProcessorInformation,
+        None,
+        0,
+        Some(buffer.as_mut_ptr() as *mut _),
+        (count * mem::size_of::<PROCESSOR_POWER_INFORMATION>()) as u32,
+    );
+    if status.is_err() {
+        log::warn!("CallNtPowerInformation 失败: {:?}", status);
+        return Vec::new();
+    }
+    buffer
 }
 
 /// 获取处理器架构
+#[cfg(windows)]
 pub unsafe fn get_processor_architecture() -> String {
     let mut sys_info: SYSTEM_INFO = mem::zeroed();
     GetNativeSystemInfo(&mut sys_info);
@@ -102,31 +280,52 @@ pub unsafe fn get_processor_architecture() -> String {
     }
 }
 
-/// 获取物理核心和逻辑核心数。
-pub unsafe fn get_cores_info() -> String {
+/// 获取物理核心、逻辑核心与能效核心数
+///
+/// 通过 GetLogicalProcessorInformationEx 枚举处理器核心，每个核心的 EfficiencyClass
+/// 用于区分混合架构处理器的性能核与能效核
+#[cfg(windows)]
+pub unsafe fn get_core_counts() -> Option<(u32, u32, u32)> {
     let mut buffer_size = 0u32;
-    let _ = GetLogicalProcessorInformation(None, &mut buffer_size);
-
+    let _ = GetLogicalProcessorInformationEx(RelationProcessorCore, None, &mut buffer_size);
     if buffer_size == 0 {
-        return "未知".to_string();
+        return None;
     }
 
-    let num_entries = buffer_size as usize / mem::size_of::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION>();
-    let mut buffer: Vec<SYSTEM_LOGICAL_PROCESSOR_INFORMATION> = vec![mem::zeroed(); num_entries];
-
-    if GetLogicalProcessorInformation(Some(buffer.as_mut_ptr()), &mut buffer_size).is_err() {
-        return "未知".to_string();
-    }
+    // 使用 u64 缓冲区保证结构体按 8 字节对齐
+    let mut buffer = vec![0u64; (buffer_size as usize).div_ceil(mem::size_of::<u64>())];
+    GetLogicalProcessorInformationEx(
+        RelationProcessorCore,
+        Some(buffer.as_mut_ptr() as *mut SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX),
+        &mut buffer_size,
+    )
+    .ok()?;
 
-    let mut physical_cores = 0;
+    // 每条记录的实际长度由 Size 字段给出，可能小于结构体大小，只按字段读取
+    let base = buffer.as_ptr() as *const u8;
+    let mut offset = 0usize;
     let mut logical_cores = 0;
-
-    for entry in &buffer {
-        if entry.Relationship == RelationProcessorCore {
-            physical_cores += 1;
-            logical_cores += entry.ProcessorMask.count_ones();
+    let mut classes = Vec::new();
+    while offset < buffer_size as usize {
+        let entry = base.add(offset) as *const SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX;
+        let size = (*entry).Size as usize;
+        if size == 0 {
+            break;
         }
+        if (*entry).Relationship == RelationProcessorCore {
+            // 核心关系中 GroupCount 恒为 1
+            logical_cores += (*entry).Anonymous.Processor.GroupMask[0].Mask.count_ones();
+            classes.push((*entry).Anonymous.Processor.EfficiencyClass);
+        }
+        offset += size;
     }
 
-    format!("物理核：{} / 逻辑核：{}", physical_cores, logical_cores)
+    if classes.is_empty() {
+        return None;
+    }
+    Some((
+        classes.len() as u32,
+        logical_cores,
+        count_efficiency_cores(&classes),
+    ))
 }
@@ -0,0 +1,299 @@
+//! 设备节点详情
+//!
+//! 按设备实例 ID 读取驱动信息与系统分配的硬件资源（内存区间、I/O 端口、中断），
+//! 供设备详情窗口打开时按需查询，不参与常规检测
+
+use std::fmt;
+
+#[cfg(windows)]
+use crate::utils::string::wide_str;
+#[cfg(windows)]
+use windows::core::PCWSTR;
+#[cfg(windows)]
+use windows::Win32::Devices::DeviceAndDriverInstallation::{
+    CM_Free_Log_Conf_Handle, CM_Free_Res_Des_Handle, CM_Get_DevNode_PropertyW,
+    CM_Get_First_Log_Conf, CM_Get_Next_Res_Des, CM_Get_Res_Des_Data, CM_Get_Res_Des_Data_Size,
+    CM_Locate_DevNodeW, ResType_All, ResType_IO, ResType_IRQ, ResType_Mem, ResType_MemLarge,
+    ALLOC_LOG_CONF, CM_LOCATE_DEVNODE_NORMAL, CM_RESTYPE, CR_SUCCESS, IO_DES, IRQ_DES_32, MEM_DES,
+    MEM_LARGE_DES,
+};
+#[cfg(windows)]
+use windows::Win32::Devices::Properties::{
+    DEVPKEY_Device_DriverDate, DEVPKEY_Device_DriverInfPath, DEVPKEY_Device_DriverProvider,
+    DEVPKEY_Device_DriverVersion, DEVPKEY_Device_HardwareIds, DEVPKEY_Device_LocationInfo,
+    DEVPKEY_Device_Service, DEVPROPTYPE, DEVPROP_TYPE_FILETIME,
+};
+#[cfg(windows)]
+use windows::Win32::Foundation::DEVPROPKEY;
+
+/// FILETIME 起点（1601-01-01）与 Unix 纪元相差的秒数
+const FILETIME_UNIX_OFFSET_SECS: i64 = 11_644_473_600;
+
+/// 系统分配给设备的硬件资源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceResource {
+    /// 内存区间（起始地址, 结束地址）
+    Memory(u64, u64),
+    /// I/O 端口区间（起始端口, 结束端口）
+    Io(u64, u64),
+    /// 中断号，消息信号中断（MSI）为负数
+    Irq(i32),
+}
+
+impl DeviceResource {
+    /// 资源类型名称
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DeviceResource::Memory(..) => "内存范围",
+            DeviceResource::Io(..) => "I/O 端口",
+            DeviceResource::Irq(_) => "中断请求 (IRQ)",
+        }
+    }
+}
+
+impl fmt::Display for DeviceResource {
+    /// 资源范围，如 "0xF6000000-0xF6FFFFFF"、"0x0000E000-0x0000E07F"、"16"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceResource::Memory(start, end) => write!(f, "0x{:08X}-0x{:08X}", start, end),
+            DeviceResource::Io(start, end) => write!(f, "0x{:04X}-0x{:04X}", start, end),
+            DeviceResource::Irq(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+/// 设备节点详情，读取不到的属性为 None
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DevNodeDetails {
+    /// 驱动提供商
+    pub driver_provider: Option<String>,
+    /// 驱动版本
+    pub driver_version: Option<String>,
+    /// 驱动日期，如 "2024-05-17"
+    pub driver_date: Option<String>,
+    /// 驱动 INF 文件名，如 "oem42.inf"
+    pub inf_path: Option<String>,
+    /// 驱动服务名
+    pub service: Option<String>,
+    /// 位置信息，如 "PCI 总线 1、设备 0、功能 0"
+    pub location: Option<String>,
+    /// 硬件 ID 列表
+    pub hardware_ids: Vec<String>,
+    /// 分配的硬件资源
+    pub resources: Vec<DeviceResource>,
+}
+
+impl DevNodeDetails {
+    /// 可显示的字段（字段名, 字段值），跳过读取不到的属性
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::devnode::{DevNodeDetails, DeviceResource};
+    /// let details = DevNodeDetails {
+    ///     service: Some("nvlddmkm".to_string()),
+    ///     resources: vec![DeviceResource::Irq(-18), DeviceResource::Memory(0xF600_0000, 0xF6FF_FFFF)],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     details.rows(),
+    ///     [
+    ///         ("驱动服务".to_string(), "nvlddmkm".to_string()),
+    ///         ("中断请求 (IRQ)".to_string(), "-18".to_string()),
+    ///         ("内存范围".to_string(), "0xF6000000-0xF6FFFFFF".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn rows(&self) -> Vec<(String, String)> {
+        let mut rows: Vec<(String, String)> = [
+            ("驱动提供商", &self.driver_provider),
+            ("驱动版本", &self.driver_version),
+            ("驱动日期", &self.driver_date),
+            ("INF 文件", &self.inf_path),
+            ("驱动服务", &self.service),
+            ("位置", &self.location),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some((key.to_string(), value.clone()?)))
+        .collect();
+        for id in self.hardware_ids.iter() {
+            rows.push(("硬件 ID".to_string(), id.clone()));
+        }
+        for resource in self.resources.iter() {
+            rows.push((resource.kind().to_string(), resource.to_string()));
+        }
+        rows
+    }
+}
+
+/// 将 FILETIME（自 1601-01-01 起的 100 纳秒数）转换为 UTC 日期
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::devnode::filetime_to_date;
+/// // 2024-05-17 00:00:00 UTC
+/// assert_eq!(filetime_to_date(133_603_776_000_000_000), Some("2024-05-17".to_string()));
+/// assert_eq!(filetime_to_date(0), None);
+/// ```
+pub fn filetime_to_date(ticks: u64) -> Option<String> {
+    if ticks == 0 {
+        return None;
+    }
+    let secs = (ticks / 10_000_000) as i64 - FILETIME_UNIX_OFFSET_SECS;
+    chrono::DateTime::from_timestamp(secs, 0).map(|time| time.format("%Y-%m-%d").to_string())
+}
+
+/// 按设备实例 ID 读取设备节点详情，设备不存在时返回 None
+///
+/// * `instance_id` - 设备实例 ID，如 `PCI\VEN_10DE&DEV_2684&...`
+#[cfg(windows)]
+pub fn query(instance_id: &str) -> Option<DevNodeDetails> {
+    let id = wide_str(instance_id);
+    let mut devinst = 0u32;
+    unsafe {
+        if CM_Locate_DevNodeW(&mut devinst, PCWSTR(id.as_ptr()), CM_LOCATE_DEVNODE_NORMAL)
+            != CR_SUCCESS
+        {
+            return None;
+        }
+        let string = |key: &DEVPROPKEY| {
+            read_property(devinst, key)
+                .and_then(|(_, buffer)| utf16_strings(&buffer).into_iter().next())
+        };
+        let driver_date = read_property(devinst, &DEVPKEY_Device_DriverDate)
+            .filter(|(property_type, buffer)| {
+                *property_type == DEVPROP_TYPE_FILETIME && buffer.len() >= 8
+            })
+            .and_then(|(_, buffer)| {
+                filetime_to_date(u64::from_le_bytes(buffer[..8].try_into().ok()?))
+            });
+
+        Some(DevNodeDetails {
+            driver_provider: string(&DEVPKEY_Device_DriverProvider),
+            driver_version: string(&DEVPKEY_Device_DriverVersion),
+            driver_date,
+            inf_path: string(&DEVPKEY_Device_DriverInfPath),
+            service: string(&DEVPKEY_Device_Service),
+            location: string(&DEVPKEY_Device_LocationInfo),
+            hardware_ids: read_property(devinst, &DEVPKEY_Device_HardwareIds)
+                .map(|(_, buffer)| utf16_strings(&buffer))
+                .unwrap_or_default(),
+            resources: allocated_resources(devinst),
+        })
+    }
+}
+
+/// 读取设备节点属性的类型与原始数据
+#[cfg(windows)]
+unsafe fn read_property(devinst: u32, key: &DEVPROPKEY) -> Option<(DEVPROPTYPE, Vec<u8>)> {
+    let mut property_type = DEVPROPTYPE::default();
+    let mut size = 0u32;
+    let _ = CM_Get_DevNode_PropertyW(devinst, key, &mut property_type, None, &mut size, 0);
+    if size == 0 {
+        return None;
+    }
+    let mut buffer = vec![0u8; size as usize];
+    if CM_Get_DevNode_PropertyW(
+        devinst,
+        key,
+        &mut property_type,
+        Some(buffer.as_mut_ptr()),
+        &mut size,
+        0,
+    ) != CR_SUCCESS
+    {
+        return None;
+    }
+    buffer.truncate(size as usize);
+    Some((property_type, buffer))
+}
+
+/// 将以空字符分隔的 UTF-16 字节数据拆分为字符串列表（单个字符串或 REG_MULTI_SZ 风格的列表）
+#[cfg(windows)]
+fn utf16_strings(buffer: &[u8]) -> Vec<String> {
+    let wide: Vec<u16> = buffer
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    wide.split(|&c| c == 0)
+        .filter(|part| !part.is_empty())
+        .map(String::from_utf16_lossy)
+        .collect()
+}
+
+/// 读取系统为设备分配的资源（ALLOC_LOG_CONF）
+#[cfg(windows)]
+unsafe fn allocated_resources(devinst: u32) -> Vec<DeviceResource> {
+    let mut resources = Vec::new();
+    let mut log_conf = 0usize;
+    if CM_Get_First_Log_Conf(Some(&mut log_conf), devinst, ALLOC_LOG_CONF) != CR_SUCCESS {
+        return resources;
+    }
+    let _log_conf = scopeguard::guard(log_conf, |handle| {
+        let _ = CM_Free_Log_Conf_Handle(handle);
+    });
+
+    // 第一次以逻辑配置句柄为起点，之后以上一个资源描述符为起点
+    let mut current = log_conf;
+    loop {
+        let mut next = 0usize;
+        let mut kind = CM_RESTYPE::default();
+        let result = CM_Get_Next_Res_Des(&mut next, current, ResType_All, Some(&mut kind), 0);
+        if current != log_conf {
+            let _ = CM_Free_Res_Des_Handle(current);
+        }
+        if result != CR_SUCCESS {
+            break;
+        }
+        current = next;
+        if let Some(resource) = read_resource(next, kind) {
+            resources.push(resource);
+        }
+    }
+    resources
+}
+
+/// 读取单个资源描述符，只解析内存、I/O 与中断资源
+#[cfg(windows)]
+unsafe fn read_resource(res_des: usize, kind: CM_RESTYPE) -> Option<DeviceResource> {
+    let mut size = 0u32;
+    if CM_Get_Res_Des_Data_Size(&mut size, res_des, 0) != CR_SUCCESS || size == 0 {
+        return None;
+    }
+    let mut buffer = vec![0u8; size as usize];
+    if CM_Get_Res_Des_Data(res_des, buffer.as_mut_ptr().cast(), size, 0) != CR_SUCCESS {
+        return None;
+    }
+
+    if kind == ResType_Mem {
+        let header: MEM_DES = read_header(&buffer)?;
+        Some(DeviceResource::Memory(
+            header.MD_Alloc_Base,
+            header.MD_Alloc_End,
+        ))
+    } else if kind == ResType_MemLarge {
+        let header: MEM_LARGE_DES = read_header(&buffer)?;
+        Some(DeviceResource::Memory(
+            header.MLD_Alloc_Base,
+            header.MLD_Alloc_End,
+        ))
+    } else if kind == ResType_IO {
+        let header: IO_DES = read_header(&buffer)?;
+        Some(DeviceResource::Io(
+            header.IOD_Alloc_Base,
+            header.IOD_Alloc_End,
+        ))
+    } else if kind == ResType_IRQ {
+        // 64 位系统上为 IRQ_DES_64，中断号之前的字段与 32 位版本相同
+        let header: IRQ_DES_32 = read_header(&buffer)?;
+        Some(DeviceResource::Irq(header.IRQD_Alloc_Num as i32))
+    } else {
+        None
+    }
+}
+
+/// 从资源数据开头读取描述符头，数据长度不足时返回 None
+#[cfg(windows)]
+unsafe fn read_header<T: Copy>(buffer: &[u8]) -> Option<T> {
+    (buffer.len() >= std::mem::size_of::<T>())
+        .then(|| std::ptr::read_unaligned(buffer.as_ptr().cast::<T>()))
+}
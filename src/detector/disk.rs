@@ -1,89 +1,583 @@
+#[cfg(windows)]
+use crate::detector::{optical, volume};
+use crate::detector::optical::OpticalDrive;
+use crate::detector::volume::Volume;
+#[cfg(windows)]
 use crate::detector::DetectionError;
+use crate::utils::size::ByteSize;
+#[cfg(windows)]
+use crate::utils::string::wide_str;
+#[cfg(windows)]
+use crate::utils::wmi;
+#[cfg(windows)]
+use crate::wmi_row;
+#[cfg(windows)]
 use windows::Win32::Foundation::GetLastError;
+#[cfg(windows)]
 use std::ffi::CStr;
+#[cfg(windows)]
 use std::mem;
+#[cfg(windows)]
 use std::os::windows::io::RawHandle;
+#[cfg(windows)]
 use windows::core::PCWSTR;
+#[cfg(windows)]
 use windows::Win32::Foundation::CloseHandle;
-use windows::Win32::Foundation::HANDLE;
+#[cfg(windows)]
+use windows::Win32::Foundation::{GENERIC_READ, GENERIC_WRITE, HANDLE};
+#[cfg(windows)]
+use windows::Win32::Storage::IscsiDisc::{
+    ATA_FLAGS_DATA_IN, ATA_FLAGS_DRDY_REQUIRED, ATA_PASS_THROUGH_EX, IOCTL_ATA_PASS_THROUGH,
+};
+#[cfg(windows)]
 use windows::Win32::Storage::FileSystem::*;
+#[cfg(windows)]
 use windows::Win32::System::Ioctl::{
     PropertyStandardQuery, StorageDeviceProperty, StorageDeviceSeekPenaltyProperty,
-    DEVICE_SEEK_PENALTY_DESCRIPTOR, DISK_GEOMETRY, IOCTL_DISK_GET_DRIVE_GEOMETRY,
+    DEVICE_SEEK_PENALTY_DESCRIPTOR, DISK_EXTENT, DISK_GEOMETRY, IOCTL_DISK_GET_DRIVE_GEOMETRY,
     IOCTL_STORAGE_QUERY_PROPERTY, STORAGE_DEVICE_DESCRIPTOR, STORAGE_PROPERTY_QUERY,
+    VOLUME_DISK_EXTENTS,
 };
+#[cfg(windows)]
 use windows::Win32::System::IO::DeviceIoControl;
 
 /// 整个硬盘的信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct DiskInfo {
     /// 磁盘名称，如：(标准磁盘驱动器) PCIe-8 SSD 1TB
     pub model: String,
-    /// 总容量 (B)
-    pub total_capacity: f64,
+    /// 总容量
+    pub total_capacity: ByteSize,
     /// 磁盘类型
     pub disk_type: DiskType,
+    /// 接口类型
+    pub bus: DiskBus,
+    /// 协商的链路速率，如 "SATA 6 Gb/s"，需要管理员权限，未知时为 None
+    pub link_speed: Option<String>,
+    /// 所有本地分区的可用空间合计
+    pub free_space: ByteSize,
+    /// 磨损程度（百分比），需要管理员权限，未知时为 None
+    pub wear_percent: Option<f64>,
+    /// 温度 (°C)，未知时为 None
+    pub temperature: Option<f64>,
+    /// 序列号
+    pub serial_number: String,
+    /// 通电时间（小时），未知时为 None
+    pub power_on_hours: Option<u64>,
+    /// 无法纠正的读写错误次数，未知时为 None
+    pub uncorrected_errors: Option<u64>,
+    /// 所有逻辑卷
+    pub volumes: Vec<Volume>,
+    /// 所有物理磁盘（包含主硬盘与读卡器等可移动存储），按编号排列
+    pub physical_disks: Vec<PhysicalDisk>,
+    /// 光驱
+    pub optical_drives: Vec<OpticalDrive>,
 }
 
 impl Default for DiskInfo {
     fn default() -> Self {
         Self {
             model: String::from("未知"),
-            total_capacity: 0.0,
+            total_capacity: ByteSize::ZERO,
             disk_type: DiskType::Unknown,
+            bus: DiskBus::Unknown,
+            link_speed: None,
+            free_space: ByteSize::ZERO,
+            wear_percent: None,
+            temperature: None,
+            serial_number: String::from("未知"),
+            power_on_hours: None,
+            uncorrected_errors: None,
+            volumes: Vec::new(),
+            physical_disks: Vec::new(),
+            optical_drives: Vec::new(),
+        }
+    }
+}
+
+impl DiskInfo {
+    /// 接口与介质类型描述，如 "NVMe 固态硬盘"
+    pub fn type_desc(&self) -> String {
+        self.bus.describe(&self.disk_type)
+    }
+
+    /// 系统盘所在的物理磁盘，找不到系统盘符对应的卷或物理磁盘时返回 None
+    ///
+    /// * `system_drive` - 系统盘符，如 "C:"
+    pub fn system_disk(&self, system_drive: &str) -> Option<&PhysicalDisk> {
+        let volume = self
+            .volumes
+            .iter()
+            .find(|volume| volume.drive_letter.eq_ignore_ascii_case(system_drive))?;
+        let number = *volume.disk_numbers().first()?;
+        self.physical_disks
+            .iter()
+            .find(|disk| disk.number == number)
+    }
+}
+
+/// 物理磁盘概要
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhysicalDisk {
+    /// 磁盘编号，对应 `\\.\PhysicalDriveN`
+    pub number: u32,
+    /// 磁盘型号
+    pub model: String,
+    /// 总容量
+    pub total_capacity: ByteSize,
+    /// 磁盘类型
+    pub disk_type: DiskType,
+    /// 接口类型
+    pub bus: DiskBus,
+    /// 是否为可移动介质（存储卡、U 盘等）
+    pub removable: bool,
+    /// 是否已插入介质，读卡器未插卡时为 false
+    pub media_present: bool,
+}
+
+impl PhysicalDisk {
+    /// 类型描述，可移动介质单独标注，避免与内置硬盘混淆
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::disk::{DiskBus, DiskType, PhysicalDisk};
+    /// use hardware_master::utils::size::ByteSize;
+    /// let disk = |bus, removable, media_present| PhysicalDisk {
+    ///     number: 2,
+    ///     model: "SD/MMC".to_string(),
+    ///     total_capacity: ByteSize::ZERO,
+    ///     disk_type: DiskType::Unknown,
+    ///     bus,
+    ///     removable,
+    ///     media_present,
+    /// };
+    /// assert_eq!(disk(DiskBus::Sd, true, true).kind_desc(), "SD 存储卡");
+    /// assert_eq!(disk(DiskBus::Usb, true, true).kind_desc(), "USB 可移动存储");
+    /// assert_eq!(disk(DiskBus::Usb, true, false).kind_desc(), "读卡器（未插卡）");
+    /// assert_eq!(disk(DiskBus::Usb, false, true).kind_desc(), "USB 硬盘");
+    /// ```
+    pub fn kind_desc(&self) -> String {
+        match (self.removable, self.media_present, self.bus) {
+            (true, false, _) => "读卡器（未插卡）".to_string(),
+            (true, true, DiskBus::Sd) => "SD 存储卡".to_string(),
+            (true, true, DiskBus::Usb) => "USB 可移动存储".to_string(),
+            (true, true, _) => "可移动存储".to_string(),
+            (false, _, bus) => bus.describe(&self.disk_type),
         }
     }
+
+    /// 概要，如 "磁盘 0: Samsung SSD 980 (1 TB, NVMe 固态硬盘)"，读卡器未插卡时不显示容量
+    pub fn summary(&self) -> String {
+        if !self.media_present {
+            return format!(
+                "磁盘 {}: {} ({})",
+                self.number,
+                self.model,
+                self.kind_desc()
+            );
+        }
+        format!(
+            "磁盘 {}: {} ({}, {})",
+            self.number,
+            self.model,
+            self.total_capacity,
+            self.kind_desc()
+        )
+    }
+}
+
+/// 卷在物理磁盘上的区段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskExtent {
+    /// 物理磁盘编号
+    pub disk_number: u32,
+    /// 起始偏移 (B)
+    pub starting_offset: u64,
+    /// 区段长度 (B)
+    pub length: u64,
 }
 
 /// 硬盘类型
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum DiskType {
     SSD,
     HDD,
     Unknown,
 }
 
-impl DiskType {
+impl std::fmt::Display for DiskType {
     /// 转换为可视化字符串，比如 "SSD" -> "固态硬盘"
-    pub fn to_string(&self) -> String {
-        match self {
-            DiskType::SSD => String::from("固态硬盘"),
-            DiskType::HDD => String::from("机械硬盘"),
-            DiskType::Unknown => String::from("未知类型"),
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DiskType::SSD => "固态硬盘",
+            DiskType::HDD => "机械硬盘",
+            DiskType::Unknown => "未知类型",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 硬盘接口类型，来自 STORAGE_DEVICE_DESCRIPTOR 的 BusType
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiskBus {
+    /// 并口 IDE/ATA 或 ATAPI
+    Ata,
+    Sata,
+    Nvme,
+    Usb,
+    /// SD 或 MMC 存储卡
+    Sd,
+    /// 硬件 RAID 或存储空间
+    Raid,
+    /// SCSI 或 SAS
+    Scsi,
+    /// 虚拟磁盘（VHD 等）
+    Virtual,
+    /// 其他总线（1394、光纤通道、iSCSI 等）
+    Other,
+    #[default]
+    Unknown,
+}
+
+impl DiskBus {
+    /// 由 STORAGE_BUS_TYPE 的取值转换
+    ///
+    /// * `bus_type` - STORAGE_BUS_TYPE 的取值，如 BusTypeNvme = 17
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::disk::DiskBus;
+    /// assert_eq!(DiskBus::from_bus_type(11), DiskBus::Sata);
+    /// assert_eq!(DiskBus::from_bus_type(17), DiskBus::Nvme);
+    /// assert_eq!(DiskBus::from_bus_type(7), DiskBus::Usb);
+    /// assert_eq!(DiskBus::from_bus_type(0), DiskBus::Unknown);
+    /// ```
+    pub fn from_bus_type(bus_type: i32) -> Self {
+        match bus_type {
+            2 | 3 => DiskBus::Ata,
+            11 => DiskBus::Sata,
+            17 => DiskBus::Nvme,
+            7 => DiskBus::Usb,
+            12 | 13 => DiskBus::Sd,
+            8 | 16 => DiskBus::Raid,
+            1 | 10 => DiskBus::Scsi,
+            14 | 15 => DiskBus::Virtual,
+            0 => DiskBus::Unknown,
+            _ => DiskBus::Other,
+        }
+    }
+
+    /// 与磁盘类型组合的描述，接口未知时只显示磁盘类型
+    ///
+    /// * `disk_type` - 磁盘类型
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::disk::{DiskBus, DiskType};
+    /// assert_eq!(DiskBus::Nvme.describe(&DiskType::SSD), "NVMe 固态硬盘");
+    /// assert_eq!(DiskBus::Usb.describe(&DiskType::Unknown), "USB 硬盘");
+    /// assert_eq!(DiskBus::Unknown.describe(&DiskType::HDD), "机械硬盘");
+    /// ```
+    pub fn describe(&self, disk_type: &DiskType) -> String {
+        match (self, disk_type) {
+            (DiskBus::Unknown, _) => disk_type.to_string(),
+            (_, DiskType::Unknown) => format!("{} 硬盘", self),
+            _ => format!("{} {}", self, disk_type),
         }
     }
 }
 
+impl std::fmt::Display for DiskBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DiskBus::Ata => "IDE",
+            DiskBus::Sata => "SATA",
+            DiskBus::Nvme => "NVMe",
+            DiskBus::Usb => "USB",
+            DiskBus::Sd => "SD",
+            DiskBus::Raid => "RAID",
+            DiskBus::Scsi => "SCSI",
+            DiskBus::Virtual => "虚拟",
+            DiskBus::Other => "其他",
+            DiskBus::Unknown => "未知",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 解析 ATA IDENTIFY DEVICE 第 77 字中的 SATA 当前协商速率
+///
+/// * `word_77` - IDENTIFY DEVICE 数据的第 77 字
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::disk::parse_sata_link_speed;
+/// assert_eq!(parse_sata_link_speed(0x0006).as_deref(), Some("SATA 6 Gb/s"));
+/// assert_eq!(parse_sata_link_speed(0x0004).as_deref(), Some("SATA 3 Gb/s"));
+/// assert_eq!(parse_sata_link_speed(0x0000), None);
+/// assert_eq!(parse_sata_link_speed(0xFFFF), None);
+/// ```
+pub fn parse_sata_link_speed(word_77: u16) -> Option<String> {
+    // 0xFFFF 表示该字无效
+    if word_77 == 0xFFFF {
+        return None;
+    }
+    let speed = match (word_77 >> 1) & 0x7 {
+        1 => "1.5",
+        2 => "3",
+        3 => "6",
+        _ => return None,
+    };
+    Some(format!("SATA {} Gb/s", speed))
+}
+
 /// 检测磁盘信息（返回主要物理硬盘的信息）
+#[cfg(windows)]
 pub fn detect_disk() -> Result<DiskInfo, DetectionError> {
     unsafe {
         // 获取磁盘 0 的信息（主要的物理硬盘）
-        let (model, total_capacity, disk_type) = get_disk_info(0)?;
-        Ok(DiskInfo {
-            model,
-            total_capacity: total_capacity as f64,
-            disk_type,
+        let mut info = get_disk_info(0)?;
+        info.free_space = ByteSize::from_bytes(get_free_space());
+        if let Some(counters) = get_reliability_counters(0) {
+            info.wear_percent = counters.wear_percent;
+            info.temperature = counters.temperature;
+            info.power_on_hours = counters.power_on_hours;
+            info.uncorrected_errors = counters.uncorrected_errors;
+        }
+        info.volumes = volume::detect_volumes().unwrap_or_else(|e| {
+            log::warn!("{}", e);
+            Vec::new()
+        });
+
+        // 主硬盘、承载逻辑卷的其他物理磁盘，以及未分区或未插卡的读卡器等磁盘
+        let mut numbers: Vec<u32> = info
+            .volumes
+            .iter()
+            .flat_map(|v| v.disk_numbers())
+            .chain([0])
+            .chain(list_disk_numbers())
+            .collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+        info.physical_disks = numbers
+            .into_iter()
+            .filter_map(|number| {
+                get_physical_disk(number)
+                    .map_err(|e| log::warn!("{}", e))
+                    .ok()
+            })
+            .collect();
+        info.optical_drives = optical::detect_optical_drives();
+        Ok(info)
+    }
+}
+
+/// 通过 IOCTL_VOLUME_GET_VOLUME_DISK_EXTENTS 获取卷所在的物理磁盘区段
+///
+/// 普通分区只有一个区段，跨区卷、带区卷可能分布在多块磁盘上；
+/// 网络驱动器等无法查询时返回空列表
+///
+/// * `drive_letter` - 盘符，如 "C:"
+#[cfg(windows)]
+pub unsafe fn get_volume_disk_extents(drive_letter: &str) -> Vec<DiskExtent> {
+    /// 最多读取的区段数
+    const MAX_EXTENTS: usize = 16;
+
+    let path = wide_str(&format!(r"\\.\{}", drive_letter));
+    let Ok(handle) = CreateFileW(
+        PCWSTR::from_raw(path.as_ptr()),
+        0,
+        FILE_SHARE_READ | FILE_SHARE_WRITE,
+        None,
+        OPEN_EXISTING,
+        FILE_ATTRIBUTE_NORMAL,
+        None,
+    ) else {
+        return Vec::new();
+    };
+    let _guard = scopeguard::guard(handle, |h| {
+        let _ = CloseHandle(h);
+    });
+
+    // VOLUME_DISK_EXTENTS 末尾为变长数组，按最大区段数分配缓冲区
+    let buffer_size =
+        mem::size_of::<VOLUME_DISK_EXTENTS>() + (MAX_EXTENTS - 1) * mem::size_of::<DISK_EXTENT>();
+    let mut buffer = vec![0u64; buffer_size.div_ceil(mem::size_of::<u64>())];
+    let mut bytes_returned = 0u32;
+    let result = DeviceIoControl(
+        handle,
+        IOCTL_VOLUME_GET_VOLUME_DISK_EXTENTS,
+        None,
+        0,
+        Some(buffer.as_mut_ptr() as _),
+        buffer_size as u32,
+        Some(&mut bytes_returned),
+        None,
+    );
+    if result.is_err() {
+        return Vec::new();
+    }
+
+    let extents = &*(buffer.as_ptr() as *const VOLUME_DISK_EXTENTS);
+    let count = (extents.NumberOfDiskExtents as usize).min(MAX_EXTENTS);
+    std::slice::from_raw_parts(extents.Extents.as_ptr(), count)
+        .iter()
+        .map(|extent| DiskExtent {
+            disk_number: extent.DiskNumber,
+            starting_offset: extent.StartingOffset as u64,
+            length: extent.ExtentLength as u64,
         })
+        .collect()
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_DiskDrive 中用到的属性
+    struct DiskDriveRow {
+        index: u32 = "Index",
     }
 }
 
-/// 获取磁盘信息
+/// 通过 Win32_DiskDrive 列出所有物理磁盘编号（包括未插卡的读卡器），查询失败时返回空列表
+#[cfg(windows)]
+fn list_disk_numbers() -> Vec<u32> {
+    let rows: Vec<DiskDriveRow> = wmi::WmiClient::connect(&wmi::WmiConfig::default())
+        .and_then(|client| client.query_as("SELECT Index FROM Win32_DiskDrive"))
+        .unwrap_or_else(|e| {
+            log::warn!("枚举物理磁盘失败: {}", e);
+            Vec::new()
+        });
+    rows.into_iter().filter_map(|row| row.index).collect()
+}
+
+/// 获取所有本地（不可移除）分区的可用空间合计
+#[cfg(windows)]
+pub(crate) fn get_free_space() -> u64 {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| !disk.is_removable())
+        .map(|disk| disk.available_space())
+        .sum()
+}
+
+/// 获取硬盘温度 (°C)，需要管理员权限，未知时为 None
 ///
 /// * `disk_number` - 磁盘编号 (0, 1, 2, ...)
-/// ```
-pub unsafe fn get_disk_info(
-    disk_number: u32,
-) -> Result<(String, u64, DiskType), DetectionError> {
-    let disk_path = format!(r"\\.\PhysicalDrive{}", disk_number);
+#[cfg(windows)]
+pub fn detect_disk_temperature(disk_number: u32) -> Option<f64> {
+    unsafe { get_reliability_counters(disk_number) }.and_then(|counters| counters.temperature)
+}
 
-    let handle = CreateFileW(
-        PCWSTR::from_raw(
-            disk_path
-                .encode_utf16()
-                .chain([0])
-                .collect::<Vec<u16>>()
-                .as_ptr(),
-        ),
+/// 硬盘可靠性计数器
+#[derive(Debug, Clone, Default)]
+#[cfg(windows)]
+struct ReliabilityCounters {
+    wear_percent: Option<f64>,
+    temperature: Option<f64>,
+    power_on_hours: Option<u64>,
+    uncorrected_errors: Option<u64>,
+}
+
+/// 通过 MSFT_StorageReliabilityCounter 获取磨损程度、温度、通电时间与错误计数
+///
+/// 该类通常需要管理员权限，查询失败时返回 None
+///
+/// * `disk_number` - 磁盘编号 (0, 1, 2, ...)
+#[cfg(windows)]
+unsafe fn get_reliability_counters(disk_number: u32) -> Option<ReliabilityCounters> {
+    let config = wmi::WmiConfig {
+        namespace: "ROOT\\Microsoft\\Windows\\Storage".to_string(),
+        ..Default::default()
+    };
+    let client = match wmi::WmiClient::connect(&config) {
+        Ok(c) => c,
+        Err(e) => {
+            log::warn!("{}", e);
+            return None;
+        }
+    };
+
+    let mut enumerator = match client.query("SELECT * FROM MSFT_StorageReliabilityCounter") {
+        Ok(e) => e,
+        Err(e) => {
+            log::warn!("{}", e);
+            return None;
+        }
+    };
+
+    while let Some(obj) = enumerator.next() {
+        let device_id = wmi::get_property(&obj, "DeviceId")
+            .ok()
+            .and_then(|var| wmi::variant_to_string(&var));
+        if device_id.as_deref() != Some(disk_number.to_string().as_str()) {
+            continue;
+        }
+
+        let wear_percent = wmi::get_property(&obj, "Wear")
+            .ok()
+            .and_then(|var| wmi::variant_to_u8(&var))
+            .map(|v| v as f64);
+        // 温度为 0 表示驱动未提供
+        let temperature = wmi::get_property(&obj, "Temperature")
+            .ok()
+            .and_then(|var| wmi::variant_to_u16(&var))
+            .filter(|v| *v > 0)
+            .map(|v| v as f64);
+        let power_on_hours = wmi::get_property(&obj, "PowerOnHours")
+            .ok()
+            .and_then(|var| wmi::variant_to_u64(&var));
+        let read_errors = wmi::get_property(&obj, "ReadErrorsUncorrected")
+            .ok()
+            .and_then(|var| wmi::variant_to_u64(&var));
+        let write_errors = wmi::get_property(&obj, "WriteErrorsUncorrected")
+            .ok()
+            .and_then(|var| wmi::variant_to_u64(&var));
+        let uncorrected_errors = match (read_errors, write_errors) {
+            (None, None) => None,
+            (read, write) => Some(read.unwrap_or(0) + write.unwrap_or(0)),
+        };
+
+        return Some(ReliabilityCounters {
+            wear_percent,
+            temperature,
+            power_on_hours,
+            uncorrected_errors,
+        });
+    }
+
+    None
+}
+
+/// 读取卷所在设备的接口类型，光驱未放入光盘时同样可用
+///
+/// * `drive_letter` - 盘符，如 "E:"
+#[cfg(windows)]
+pub(crate) unsafe fn get_volume_bus(drive_letter: &str) -> Option<DiskBus> {
+    let handle = open_physical_disk(&format!(r"\\.\{}", drive_letter))
+        .map_err(|e| log::debug!("{}", e))
+        .ok()?;
+    let _guard = scopeguard::guard(handle, |h| {
+        let _ = CloseHandle(h);
+    });
+    let (_, _, bus, _) = get_disk_model(handle.0).ok()?;
+    (bus != DiskBus::Unknown).then_some(bus)
+}
+
+/// 以查询方式打开物理磁盘
+///
+/// * `disk_path` - 物理磁盘路径，如 `\\.\PhysicalDrive0`
+#[cfg(windows)]
+unsafe fn open_physical_disk(disk_path: &str) -> Result<HANDLE, DetectionError> {
+    let path = wide_str(disk_path);
+    CreateFileW(
+        PCWSTR::from_raw(path.as_ptr()),
         0,
         FILE_SHARE_READ | FILE_SHARE_WRITE,
         None,
@@ -91,23 +585,81 @@ pub unsafe fn get_disk_info(
         FILE_ATTRIBUTE_NORMAL,
         None,
     )
-    .map_err(|e| DetectionError::DiskError(format!("无法打开物理磁盘 {}: {}", disk_path, e)))?;
+    .map_err(|e| DetectionError::DiskError(format!("无法打开物理磁盘 {}: {}", disk_path, e)))
+}
 
+/// 获取磁盘信息
+///
+/// * `disk_number` - 磁盘编号 (0, 1, 2, ...)
+/// ```
+#[cfg(windows)]
+pub unsafe fn get_disk_info(
+    disk_number: u32,
+) -> Result<DiskInfo, DetectionError> {
+    let disk_path = format!(r"\\.\PhysicalDrive{}", disk_number);
+    let handle = open_physical_disk(&disk_path)?;
     let _guard = scopeguard::guard(handle, |h| {
         let _ = CloseHandle(h);
     });
 
     let disk_type = get_disk_type(handle.0)?;
     let total_capacity = get_disk_capacity(handle.0)?;
-    let model = get_disk_model(handle.0)?;
+    let (model, serial_number, bus, _) = get_disk_model(handle.0)?;
+    let link_speed = match bus {
+        DiskBus::Sata => get_sata_link_speed(&disk_path),
+        _ => None,
+    };
 
-    Ok((model, total_capacity, disk_type))
+    Ok(DiskInfo {
+        model,
+        total_capacity: ByteSize::from_bytes(total_capacity),
+        disk_type,
+        bus,
+        link_speed,
+        serial_number,
+        ..Default::default()
+    })
+}
+
+/// 获取物理磁盘概要
+///
+/// 设备描述符在未插卡时也能读取；读不到几何信息时视为未插入介质，容量记为 0
+///
+/// * `disk_number` - 磁盘编号 (0, 1, 2, ...)
+#[cfg(windows)]
+unsafe fn get_physical_disk(disk_number: u32) -> Result<PhysicalDisk, DetectionError> {
+    let disk_path = format!(r"\\.\PhysicalDrive{}", disk_number);
+    let handle = open_physical_disk(&disk_path)?;
+    let _guard = scopeguard::guard(handle, |h| {
+        let _ = CloseHandle(h);
+    });
+
+    let (model, _, bus, removable) = get_disk_model(handle.0)?;
+    let capacity = get_disk_capacity(handle.0)
+        .ok()
+        .filter(|capacity| *capacity > 0);
+    // 存储卡通常不支持寻道延迟查询
+    let disk_type = match capacity {
+        Some(_) => get_disk_type(handle.0).unwrap_or(DiskType::Unknown),
+        None => DiskType::Unknown,
+    };
+
+    Ok(PhysicalDisk {
+        number: disk_number,
+        model,
+        total_capacity: ByteSize::from_bytes(capacity.unwrap_or(0)),
+        disk_type,
+        bus,
+        removable,
+        media_present: capacity.is_some(),
+    })
 }
 
 /// 使用 IOCTL 获取磁盘类型
 ///
 /// * `handle` - 磁盘设备句柄
 /// ```
+#[cfg(windows)]
 pub unsafe fn get_disk_type(
     handle: RawHandle,
 ) -> Result<crate::detector::disk::DiskType, DetectionError> {
@@ -150,6 +702,7 @@ pub unsafe fn get_disk_type(
 /// 获取磁盘容量
 ///
 /// * `handle` - 磁盘设备句柄
+#[cfg(windows)]
 unsafe fn get_disk_capacity(handle: RawHandle) -> Result<u64, DetectionError> {
     let mut geometry: DISK_GEOMETRY = mem::zeroed();
     let mut bytes_returned = 0u32;
@@ -179,10 +732,13 @@ unsafe fn get_disk_capacity(handle: RawHandle) -> Result<u64, DetectionError> {
     Ok(total_sectors * bytes_per_sector as u64)
 }
 
-/// 获取磁盘型号
+/// 获取磁盘型号、序列号、接口类型以及是否为可移动介质
 ///
 /// * `handle` - 磁盘设备句柄
-unsafe fn get_disk_model(handle: RawHandle) -> Result<String, DetectionError> {
+#[cfg(windows)]
+unsafe fn get_disk_model(
+    handle: RawHandle,
+) -> Result<(String, String, DiskBus, bool), DetectionError> {
     let spq = STORAGE_PROPERTY_QUERY {
         PropertyId: StorageDeviceProperty,
         QueryType: PropertyStandardQuery,
@@ -211,15 +767,88 @@ unsafe fn get_disk_model(handle: RawHandle) -> Result<String, DetectionError> {
 
     let descriptor = &*(buffer.as_ptr() as *const STORAGE_DEVICE_DESCRIPTOR);
 
-    if descriptor.ProductIdOffset > 0 {
-        let offset = descriptor.ProductIdOffset as usize;
+    let model = descriptor_string(&buffer, descriptor.ProductIdOffset)
+        .unwrap_or_else(|| "未知磁盘".to_string());
+    let serial_number = descriptor_string(&buffer, descriptor.SerialNumberOffset)
+        .unwrap_or_else(|| "未知".to_string());
+    let bus = DiskBus::from_bus_type(descriptor.BusType.0);
 
-        let name_ptr = buffer.as_ptr().add(offset) as *const i8;
+    Ok((model, serial_number, bus, descriptor.RemovableMedia))
+}
 
-        if let Ok(c_str) = CStr::from_ptr(name_ptr).to_str() {
-            return Ok(c_str.trim().to_string());
-        }
+/// ATA 直通请求与 IDENTIFY DEVICE 数据缓冲区
+#[cfg(windows)]
+#[repr(C)]
+struct AtaIdentifyBuffer {
+    header: ATA_PASS_THROUGH_EX,
+    data: [u16; 256],
+}
+
+/// 通过 ATA 直通发送 IDENTIFY DEVICE 获取 SATA 当前协商速率
+///
+/// 需要管理员权限以读写方式打开磁盘，失败时返回 None
+///
+/// * `disk_path` - 物理磁盘路径，如 `\\.\PhysicalDrive0`
+#[cfg(windows)]
+unsafe fn get_sata_link_speed(disk_path: &str) -> Option<String> {
+    let path_wide = wide_str(disk_path);
+    let handle = CreateFileW(
+        PCWSTR::from_raw(path_wide.as_ptr()),
+        GENERIC_READ.0 | GENERIC_WRITE.0,
+        FILE_SHARE_READ | FILE_SHARE_WRITE,
+        None,
+        OPEN_EXISTING,
+        FILE_ATTRIBUTE_NORMAL,
+        None,
+    )
+    .map_err(|e| log::debug!("以读写方式打开 {} 失败: {}", disk_path, e))
+    .ok()?;
+    let _guard = scopeguard::guard(handle, |h| {
+        let _ = CloseHandle(h);
+    });
+
+    let mut buffer: AtaIdentifyBuffer = mem::zeroed();
+    buffer.header.Length = mem::size_of::<ATA_PASS_THROUGH_EX>() as u16;
+    buffer.header.AtaFlags = (ATA_FLAGS_DATA_IN | ATA_FLAGS_DRDY_REQUIRED) as u16;
+    buffer.header.DataTransferLength = mem::size_of_val(&buffer.data) as u32;
+    buffer.header.TimeOutValue = 2;
+    buffer.header.DataBufferOffset = mem::offset_of!(AtaIdentifyBuffer, data);
+    // 命令寄存器位于任务文件第 7 字节，0xEC 为 IDENTIFY DEVICE
+    buffer.header.CurrentTaskFile[6] = 0xEC;
+
+    let mut bytes_returned = 0u32;
+    DeviceIoControl(
+        handle,
+        IOCTL_ATA_PASS_THROUGH,
+        Some(&buffer as *const _ as *const _),
+        mem::size_of_val(&buffer) as u32,
+        Some(&mut buffer as *mut _ as *mut _),
+        mem::size_of_val(&buffer) as u32,
+        Some(&mut bytes_returned),
+        None,
+    )
+    .map_err(|e| log::debug!("ATA IDENTIFY 失败: {}", e))
+    .ok()?;
+
+    parse_sata_link_speed(buffer.data[77])
+}
+
+/// 读取设备描述符中指定偏移处的字符串
+///
+/// * `buffer` - STORAGE_DEVICE_DESCRIPTOR 缓冲区
+/// * `offset` - 字符串偏移，0 表示不存在
+#[cfg(windows)]
+unsafe fn descriptor_string(buffer: &[u8], offset: u32) -> Option<String> {
+    let offset = offset as usize;
+    if offset == 0 || offset >= buffer.len() {
+        return None;
     }
 
-    Ok("未知磁盘".to_string())
+    let name_ptr = buffer.as_ptr().add(offset) as *const i8;
+    let value = CStr::from_ptr(name_ptr).to_str().ok()?.trim().to_string();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
 }
@@ -0,0 +1,464 @@
+//! 内核驱动模块
+//!
+//! 通过 EnumDeviceDrivers 枚举已加载的内核驱动，读取驱动文件的版本信息并检查数字签名，
+//! 按文件名归类出存储、显卡、网络等与硬件相关的驱动，供高级模式下的驱动程序标签页显示。
+
+#[cfg(windows)]
+use super::DetectionError;
+#[cfg(windows)]
+use crate::utils::string::{u16_slice_to_string, wide_str};
+#[cfg(windows)]
+use std::ffi::c_void;
+use std::fmt;
+#[cfg(windows)]
+use std::os::windows::io::AsRawHandle;
+#[cfg(windows)]
+use windows::core::{w, PCWSTR};
+#[cfg(windows)]
+use windows::Win32::Foundation::{HANDLE, HWND};
+#[cfg(windows)]
+use windows::Win32::Security::Cryptography::Catalog::{
+    CryptCATAdminAcquireContext2, CryptCATAdminCalcHashFromFileHandle2,
+    CryptCATAdminEnumCatalogFromHash, CryptCATAdminReleaseCatalogContext,
+    CryptCATAdminReleaseContext,
+};
+#[cfg(windows)]
+use windows::Win32::Security::WinTrust::{
+    WinVerifyTrust, WINTRUST_ACTION_GENERIC_VERIFY_V2, WINTRUST_DATA, WINTRUST_FILE_INFO,
+    WTD_CACHE_ONLY_URL_RETRIEVAL, WTD_CHOICE_FILE, WTD_REVOKE_NONE, WTD_STATEACTION_CLOSE,
+    WTD_STATEACTION_VERIFY, WTD_UI_NONE,
+};
+#[cfg(windows)]
+use windows::Win32::Storage::FileSystem::{
+    GetFileVersionInfoSizeW, GetFileVersionInfoW, VerQueryValueW, VS_FIXEDFILEINFO,
+};
+#[cfg(windows)]
+use windows::Win32::System::ProcessStatus::{EnumDeviceDrivers, GetDeviceDriverFileNameW};
+
+/// 存储驱动文件名（不含扩展名）前缀
+const STORAGE_DRIVERS: [&str; 22] = [
+    "stornvme", "storahci", "storport", "storufs", "iastor", "iavroc", "nvme", "secnvme", "disk",
+    "classpnp", "partmgr", "volmgr", "volsnap", "msahci", "pciide", "atapi", "ataport", "amdsata",
+    "rcraid", "sdstor", "usbstor", "uaspstor",
+];
+
+/// 显卡驱动文件名前缀
+const GPU_DRIVERS: [&str; 9] = [
+    "nvlddmkm",
+    "amdkmdag",
+    "atikmdag",
+    "atikmpag",
+    "igdkmd",
+    "igfx",
+    "basicdisplay",
+    "basicrender",
+    "dxgkrnl",
+];
+
+/// 网络驱动文件名前缀
+const NETWORK_DRIVERS: [&str; 21] = [
+    "ndis", "tcpip", "netio", "rt640x64", "rt68cx", "rtux64", "rtwlane", "e1d", "e1i", "e2f",
+    "netwtw", "netwbw", "netwlv", "athw", "qcamain", "bcmwl", "mrvlpcie", "l1c", "ixgbe", "nwifi",
+    "vwifibus",
+];
+
+/// 驱动类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum DriverCategory {
+    /// 存储控制器与磁盘
+    Storage,
+    /// 显卡
+    Gpu,
+    /// 有线与无线网络
+    Network,
+    /// 其他驱动
+    #[default]
+    Other,
+}
+
+impl DriverCategory {
+    /// 所有类别，按显示顺序排列
+    pub const ALL: [DriverCategory; 4] = [
+        DriverCategory::Storage,
+        DriverCategory::Gpu,
+        DriverCategory::Network,
+        DriverCategory::Other,
+    ];
+
+    /// 按驱动文件名归类
+    ///
+    /// * `file_name` - 驱动文件名，如 "stornvme.sys"
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::drivers::DriverCategory;
+    /// assert_eq!(DriverCategory::from_file_name("stornvme.sys"), DriverCategory::Storage);
+    /// assert_eq!(DriverCategory::from_file_name("nvlddmkm.sys"), DriverCategory::Gpu);
+    /// assert_eq!(DriverCategory::from_file_name("Netwtw10.sys"), DriverCategory::Network);
+    /// assert_eq!(DriverCategory::from_file_name("ntoskrnl.exe"), DriverCategory::Other);
+    /// ```
+    pub fn from_file_name(file_name: &str) -> Self {
+        let name = file_name.to_ascii_lowercase();
+        let stem = name.strip_suffix(".sys").unwrap_or(&name);
+        let matches = |prefixes: &[&str]| prefixes.iter().any(|prefix| stem.starts_with(prefix));
+        if matches(&STORAGE_DRIVERS) {
+            DriverCategory::Storage
+        } else if matches(&GPU_DRIVERS) {
+            DriverCategory::Gpu
+        } else if matches(&NETWORK_DRIVERS) {
+            DriverCategory::Network
+        } else {
+            DriverCategory::Other
+        }
+    }
+
+    /// 是否为与硬件相关的类别（存储、显卡、网络）
+    pub fn is_hardware(&self) -> bool {
+        !matches!(self, DriverCategory::Other)
+    }
+}
+
+impl fmt::Display for DriverCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DriverCategory::Storage => "存储",
+            DriverCategory::Gpu => "显卡",
+            DriverCategory::Network => "网络",
+            DriverCategory::Other => "其他",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 已加载的内核驱动
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KernelDriver {
+    /// 驱动文件名，如 "stornvme.sys"
+    pub name: String,
+    /// 驱动文件完整路径
+    pub path: String,
+    /// 文件描述
+    pub description: String,
+    /// 厂商
+    pub company: String,
+    /// 文件版本，如 "10.0.22621.2506"
+    pub version: String,
+    /// 是否有有效的数字签名（内嵌签名或系统编录签名），无法检查时为 None
+    pub signed: Option<bool>,
+    /// 类别
+    pub category: DriverCategory,
+}
+
+impl KernelDriver {
+    /// 签名状态描述
+    pub fn signature_desc(&self) -> &'static str {
+        match self.signed {
+            Some(true) => "已签名",
+            Some(false) => "未签名",
+            None => "未知",
+        }
+    }
+}
+
+/// 格式化文件版本号
+///
+/// * `version_ms` - 版本号高 32 位（主版本、次版本）
+/// * `version_ls` - 版本号低 32 位（生成号、修订号）
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::drivers::format_file_version;
+/// assert_eq!(format_file_version(0x000A_0000, 0x585D_09CA), "10.0.22621.2506");
+/// ```
+pub fn format_file_version(version_ms: u32, version_ls: u32) -> String {
+    format!(
+        "{}.{}.{}.{}",
+        version_ms >> 16,
+        version_ms & 0xFFFF,
+        version_ls >> 16,
+        version_ls & 0xFFFF
+    )
+}
+
+/// 将 GetDeviceDriverFileName 返回的内核路径转换为普通文件路径
+///
+/// * `raw` - 内核路径，如 `\SystemRoot\system32\drivers\disk.sys`
+/// * `system_root` - Windows 目录，如 `C:\Windows`
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::drivers::resolve_driver_path;
+/// let root = "C:\\Windows";
+/// assert_eq!(
+///     resolve_driver_path("\\SystemRoot\\system32\\drivers\\disk.sys", root),
+///     "C:\\Windows\\system32\\drivers\\disk.sys"
+/// );
+/// assert_eq!(
+///     resolve_driver_path("\\??\\D:\\Tools\\kprocesshacker.sys", root),
+///     "D:\\Tools\\kprocesshacker.sys"
+/// );
+/// assert_eq!(
+///     resolve_driver_path("System32\\drivers\\ndis.sys", root),
+///     "C:\\Windows\\System32\\drivers\\ndis.sys"
+/// );
+/// assert_eq!(
+///     resolve_driver_path("\\Windows\\System32\\drivers\\tcpip.sys", root),
+///     "C:\\Windows\\System32\\drivers\\tcpip.sys"
+/// );
+/// ```
+pub fn resolve_driver_path(raw: &str, system_root: &str) -> String {
+    let system_root = system_root.trim_end_matches('\\');
+    if let Some(rest) = strip_prefix_ignore_case(raw, "\\SystemRoot\\") {
+        return format!("{}\\{}", system_root, rest);
+    }
+    if let Some(rest) = raw.strip_prefix("\\??\\") {
+        return rest.to_string();
+    }
+    if raw.starts_with('\\') {
+        // 不带盘符的绝对路径位于系统盘
+        let drive = system_root.get(..2).unwrap_or("C:");
+        return format!("{}{}", drive, raw);
+    }
+    if raw.get(1..2) == Some(":") {
+        return raw.to_string();
+    }
+    format!("{}\\{}", system_root, raw)
+}
+
+/// 忽略大小写去除前缀
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    s.get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &s[prefix.len()..])
+}
+
+/// 枚举已加载的内核驱动，按文件名排序
+#[cfg(windows)]
+pub fn detect_drivers() -> Result<Vec<KernelDriver>, DetectionError> {
+    let system_root = std::env::var("SystemRoot").unwrap_or_else(|_| "C:\\Windows".to_string());
+    let bases = unsafe { enumerate_driver_bases()? };
+    let catalog = unsafe { CatalogAdmin::acquire() };
+
+    let mut drivers: Vec<KernelDriver> = bases
+        .into_iter()
+        .filter_map(|base| unsafe { driver_file_name(base) })
+        .map(|raw| unsafe {
+            read_driver(resolve_driver_path(&raw, &system_root), catalog.as_ref())
+        })
+        .collect();
+    drivers.sort_by_key(|driver| driver.name.to_lowercase());
+    Ok(drivers)
+}
+
+/// 获取所有已加载驱动的加载基址
+#[cfg(windows)]
+unsafe fn enumerate_driver_bases() -> Result<Vec<*mut c_void>, DetectionError> {
+    let pointer_size = std::mem::size_of::<*mut c_void>();
+    let mut bases: Vec<*mut c_void> = vec![std::ptr::null_mut(); 512];
+    loop {
+        let size = (bases.len() * pointer_size) as u32;
+        let mut needed = 0u32;
+        EnumDeviceDrivers(bases.as_mut_ptr(), size, &mut needed)
+            .map_err(|e| DetectionError::WindowsApiError(format!("枚举内核驱动失败: {}", e)))?;
+        if needed <= size {
+            bases.truncate(needed as usize / pointer_size);
+            return Ok(bases);
+        }
+        // 两次调用之间可能有新驱动加载，多留一些余量
+        bases.resize(needed as usize / pointer_size + 64, std::ptr::null_mut());
+    }
+}
+
+/// 获取驱动的内核文件路径
+#[cfg(windows)]
+unsafe fn driver_file_name(base: *mut c_void) -> Option<String> {
+    let mut buffer = [0u16; 1024];
+    let len = GetDeviceDriverFileNameW(base, &mut buffer) as usize;
+    (len > 0).then(|| u16_slice_to_string(&buffer[..len]))
+}
+
+/// 读取驱动文件的版本信息与签名状态
+#[cfg(windows)]
+unsafe fn read_driver(path: String, catalog: Option<&CatalogAdmin>) -> KernelDriver {
+    let name = path.rsplit('\\').next().unwrap_or(&path).to_string();
+    let version = read_version_info(&path).unwrap_or_default();
+    let unknown = || "未知".to_string();
+    KernelDriver {
+        category: DriverCategory::from_file_name(&name),
+        description: version.description.unwrap_or_else(unknown),
+        company: version.company.unwrap_or_else(unknown),
+        version: version.version.unwrap_or_else(unknown),
+        signed: verify_signature(&path, catalog),
+        name,
+        path,
+    }
+}
+
+/// 驱动文件的版本资源
+#[cfg(windows)]
+#[derive(Default)]
+struct FileVersion {
+    version: Option<String>,
+    company: Option<String>,
+    description: Option<String>,
+}
+
+/// 读取文件的版本资源
+#[cfg(windows)]
+unsafe fn read_version_info(path: &str) -> Option<FileVersion> {
+    let path_wide = wide_str(path);
+    let path_ptr = PCWSTR::from_raw(path_wide.as_ptr());
+    let size = GetFileVersionInfoSizeW(path_ptr, None);
+    if size == 0 {
+        return None;
+    }
+    let mut block = vec![0u8; size as usize];
+    GetFileVersionInfoW(path_ptr, None, size, block.as_mut_ptr().cast()).ok()?;
+    let block_ptr = block.as_ptr().cast::<c_void>();
+
+    let version = query_value(block_ptr, "\\")
+        .filter(|(_, len)| *len as usize >= std::mem::size_of::<VS_FIXEDFILEINFO>())
+        .map(|(ptr, _)| {
+            let info = &*(ptr as *const VS_FIXEDFILEINFO);
+            format_file_version(info.dwFileVersionMS, info.dwFileVersionLS)
+        });
+
+    // 字符串资源按语言与代码页存放，默认取美国英语 Unicode
+    let (language, code_page) = query_value(block_ptr, "\\VarFileInfo\\Translation")
+        .filter(|(_, len)| *len >= 4)
+        .map(|(ptr, _)| {
+            let translation = ptr as *const u16;
+            (*translation, *translation.add(1))
+        })
+        .unwrap_or((0x0409, 0x04B0));
+    let string_value = |key: &str| {
+        let sub_block = format!(
+            "\\StringFileInfo\\{:04x}{:04x}\\{}",
+            language, code_page, key
+        );
+        query_value(block_ptr, &sub_block)
+            .map(|(ptr, len)| {
+                u16_slice_to_string(std::slice::from_raw_parts(ptr as *const u16, len as usize))
+            })
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    Some(FileVersion {
+        version,
+        company: string_value("CompanyName"),
+        description: string_value("FileDescription"),
+    })
+}
+
+/// 查询版本资源中的值，返回值指针与长度
+#[cfg(windows)]
+unsafe fn query_value(block: *const c_void, sub_block: &str) -> Option<(*mut c_void, u32)> {
+    let sub_block_wide = wide_str(sub_block);
+    let mut value = std::ptr::null_mut();
+    let mut len = 0u32;
+    VerQueryValueW(
+        block,
+        PCWSTR::from_raw(sub_block_wide.as_ptr()),
+        &mut value,
+        &mut len,
+    )
+    .as_bool()
+    .then_some((value, len))
+    .filter(|(value, len)| !value.is_null() && *len > 0)
+}
+
+/// 检查驱动文件的数字签名
+///
+/// 先检查内嵌签名，没有内嵌签名时在系统编录中查找文件哈希（大多数系统自带驱动以编录签名）
+#[cfg(windows)]
+unsafe fn verify_signature(path: &str, catalog: Option<&CatalogAdmin>) -> Option<bool> {
+    if !std::path::Path::new(path).exists() {
+        return None;
+    }
+    if verify_embedded_signature(path) {
+        return Some(true);
+    }
+    catalog?.contains(path)
+}
+
+/// 使用 WinVerifyTrust 检查文件的内嵌签名，不联网检查吊销状态
+#[cfg(windows)]
+unsafe fn verify_embedded_signature(path: &str) -> bool {
+    let path_wide = wide_str(path);
+    let mut file_info = WINTRUST_FILE_INFO {
+        cbStruct: std::mem::size_of::<WINTRUST_FILE_INFO>() as u32,
+        pcwszFilePath: PCWSTR::from_raw(path_wide.as_ptr()),
+        ..Default::default()
+    };
+    let mut data = WINTRUST_DATA {
+        cbStruct: std::mem::size_of::<WINTRUST_DATA>() as u32,
+        dwUIChoice: WTD_UI_NONE,
+        fdwRevocationChecks: WTD_REVOKE_NONE,
+        dwUnionChoice: WTD_CHOICE_FILE,
+        dwStateAction: WTD_STATEACTION_VERIFY,
+        dwProvFlags: WTD_CACHE_ONLY_URL_RETRIEVAL,
+        ..Default::default()
+    };
+    data.Anonymous.pFile = &mut file_info;
+
+    let mut action = WINTRUST_ACTION_GENERIC_VERIFY_V2;
+    let data_ptr = (&mut data as *mut WINTRUST_DATA).cast::<c_void>();
+    let status = WinVerifyTrust(HWND::default(), &mut action, data_ptr);
+    // 释放验证时分配的状态数据
+    data.dwStateAction = WTD_STATEACTION_CLOSE;
+    WinVerifyTrust(HWND::default(), &mut action, data_ptr);
+    status == 0
+}
+
+/// 系统编录管理上下文
+#[cfg(windows)]
+struct CatalogAdmin(isize);
+
+#[cfg(windows)]
+impl CatalogAdmin {
+    /// 获取使用 SHA256 哈希的编录上下文
+    unsafe fn acquire() -> Option<Self> {
+        let mut handle = 0isize;
+        CryptCATAdminAcquireContext2(&mut handle, None, w!("SHA256"), None, None)
+            .map_err(|e| log::warn!("获取编录上下文失败: {}", e))
+            .ok()?;
+        Some(Self(handle))
+    }
+
+    /// 文件哈希是否收录在系统编录中，无法读取文件时返回 None
+    unsafe fn contains(&self, path: &str) -> Option<bool> {
+        let file = std::fs::File::open(path).ok()?;
+        let handle = HANDLE(file.as_raw_handle());
+        // 第一次调用获取哈希长度
+        let mut size = 0u32;
+        let _ = CryptCATAdminCalcHashFromFileHandle2(self.0, handle, &mut size, None, None);
+        if size == 0 {
+            return None;
+        }
+        let mut hash = vec![0u8; size as usize];
+        CryptCATAdminCalcHashFromFileHandle2(
+            self.0,
+            handle,
+            &mut size,
+            Some(hash.as_mut_ptr()),
+            None,
+        )
+        .ok()?;
+        let catalog = CryptCATAdminEnumCatalogFromHash(self.0, &hash, None, None);
+        if catalog == 0 {
+            return Some(false);
+        }
+        let _ = CryptCATAdminReleaseCatalogContext(self.0, catalog, 0);
+        Some(true)
+    }
+}
+
+#[cfg(windows)]
+impl Drop for CatalogAdmin {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CryptCATAdminReleaseContext(self.0, 0);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! 动态数据刷新模块
+//!
+//! 电池状态、分区可用空间、硬盘温度等数据会随时间变化，但检测开销很小。
+//! 自动刷新时只重新读取这些数据，不重新执行完整的 WMI 检测。
+
+use super::battery::BatteryInfo;
+use super::volume::Volume;
+#[cfg(windows)]
+use super::{battery, disk, volume};
+use super::{DetectionCategory, HardwareDetector};
+use crate::utils::size::ByteSize;
+
+/// 一次动态刷新的结果，读取失败或类别未启用的项为 None，应用时保留原值
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynamicData {
+    /// 电池状态
+    pub battery: Option<BatteryInfo>,
+    /// 逻辑卷（含可用空间）
+    pub volumes: Option<Vec<Volume>>,
+    /// 本地分区可用空间合计
+    pub free_space: Option<ByteSize>,
+    /// 主硬盘温度 (°C)
+    pub disk_temperature: Option<f64>,
+}
+
+impl DynamicData {
+    /// 读取动态数据，可在后台线程中调用，不需要持有检测器
+    ///
+    /// * `categories` - 已启用的检测类别，只刷新其中包含的动态数据
+    #[cfg(windows)]
+    pub fn detect(categories: &[DetectionCategory]) -> Self {
+        let mut data = Self::default();
+
+        if categories.contains(&DetectionCategory::Battery) {
+            data.battery = battery::detect_battery()
+                .map_err(|e| log::warn!("{}", e))
+                .ok();
+        }
+
+        if categories.contains(&DetectionCategory::Disk) {
+            data.volumes = volume::detect_volumes()
+                .map_err(|e| log::warn!("{}", e))
+                .ok();
+            data.free_space = Some(ByteSize::from_bytes(disk::get_free_space()));
+            data.disk_temperature = disk::detect_disk_temperature(0);
+        }
+
+        data
+    }
+
+    /// 读取动态数据，非 Windows 平台不支持，所有项均为 None
+    #[cfg(not(windows))]
+    pub fn detect(_categories: &[DetectionCategory]) -> Self {
+        Self::default()
+    }
+}
+
+impl HardwareDetector {
+    /// 写入动态刷新的结果，其余检测结果保持不变
+    ///
+    /// * `data` - 动态刷新的结果
+    pub fn apply_dynamic(&mut self, data: DynamicData) {
+        if let Some(battery) = data.battery {
+            self.battery_info = battery;
+        }
+        if let Some(volumes) = data.volumes {
+            self.disk_info.volumes = volumes;
+        }
+        if let Some(free_space) = data.free_space {
+            self.disk_info.free_space = free_space;
+        }
+        if let Some(temperature) = data.disk_temperature {
+            self.disk_info.temperature = Some(temperature);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! 硬件变化事件模块
+//!
+//! 通过 `CM_Register_Notification` 订阅设备接口的插入与移除通知（U 盘、显示器、
+//! 电池、声卡、网卡等），将事件映射为受影响的检测类别。短时间内的连续事件会合并，
+//! 设备稳定后再一次性通知调用方重新检测。
+
+use super::{DetectionCategory, DetectionError};
+use crate::constants::HOTPLUG_SETTLE_MS;
+use std::sync::{mpsc, Mutex};
+use std::time::Duration;
+use windows::core::GUID;
+use windows::Win32::Devices::DeviceAndDriverInstallation::{
+    CM_Register_Notification, CM_Unregister_Notification, CM_NOTIFY_ACTION,
+    CM_NOTIFY_ACTION_DEVICEINTERFACEARRIVAL, CM_NOTIFY_ACTION_DEVICEINTERFACEREMOVAL,
+    CM_NOTIFY_EVENT_DATA, CM_NOTIFY_FILTER, CM_NOTIFY_FILTER_TYPE_DEVICEINTERFACE, CR_SUCCESS,
+    HCMNOTIFICATION,
+};
+use windows::Win32::Devices::Display::{
+    GUID_DEVINTERFACE_DISPLAY_ADAPTER, GUID_DEVINTERFACE_MONITOR,
+};
+use windows::Win32::Media::KernelStreaming::KSCATEGORY_AUDIO;
+use windows::Win32::NetworkManagement::Ndis::GUID_DEVINTERFACE_NET;
+use windows::Win32::System::Ioctl::{GUID_DEVINTERFACE_DISK, GUID_DEVINTERFACE_VOLUME};
+use windows::Win32::System::Power::GUID_DEVICE_BATTERY;
+
+/// 订阅的设备接口类别及其对应的检测类别
+///
+/// U 盘、移动硬盘等 USB 设备插拔时会触发硬盘与分区接口通知，无需单独订阅 USB 设备接口
+pub const WATCHED_INTERFACES: [(GUID, DetectionCategory); 7] = [
+    (GUID_DEVINTERFACE_DISK, DetectionCategory::Disk),
+    (GUID_DEVINTERFACE_VOLUME, DetectionCategory::Disk),
+    (GUID_DEVINTERFACE_MONITOR, DetectionCategory::Monitor),
+    (GUID_DEVINTERFACE_DISPLAY_ADAPTER, DetectionCategory::Gpu),
+    (GUID_DEVICE_BATTERY, DetectionCategory::Battery),
+    (KSCATEGORY_AUDIO, DetectionCategory::Audio),
+    (GUID_DEVINTERFACE_NET, DetectionCategory::Network),
+];
+
+/// 根据设备接口类别获取受影响的检测类别
+///
+/// * `class_guid` - 设备接口类别 GUID
+pub fn category_for_interface(class_guid: &GUID) -> Option<DetectionCategory> {
+    WATCHED_INTERFACES
+        .iter()
+        .find(|(guid, _)| guid == class_guid)
+        .map(|(_, category)| *category)
+}
+
+/// 硬件变化监听器，释放时取消全部订阅
+pub struct HotplugWatcher {
+    /// 已注册的通知句柄
+    notifications: Vec<HCMNOTIFICATION>,
+    /// 通知回调上下文，必须在取消订阅之后释放
+    context: Box<Mutex<mpsc::Sender<DetectionCategory>>>,
+}
+
+// 通知句柄只用于取消订阅，可以在线程间转移
+unsafe impl Send for HotplugWatcher {}
+
+impl HotplugWatcher {
+    /// 开始监听硬件变化
+    ///
+    /// 事件在系统线程池中到达，合并后在后台线程调用 `on_change`
+    ///
+    /// * `on_change` - 硬件变化回调，参数为受影响的检测类别（已去重）
+    pub fn start<F>(on_change: F) -> Result<Self, DetectionError>
+    where
+        F: Fn(Vec<DetectionCategory>) + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        let context = Box::new(Mutex::new(tx));
+        let mut watcher = Self {
+            notifications: Vec::new(),
+            context,
+        };
+
+        for (class_guid, _) in WATCHED_INTERFACES.iter() {
+            let mut filter = CM_NOTIFY_FILTER {
+                cbSize: std::mem::size_of::<CM_NOTIFY_FILTER>() as u32,
+                FilterType: CM_NOTIFY_FILTER_TYPE_DEVICEINTERFACE,
+                ..Default::default()
+            };
+            filter.u.DeviceInterface.ClassGuid = *class_guid;
+
+            let mut notification = HCMNOTIFICATION(std::ptr::null_mut());
+            let context_ptr = &*watcher.context as *const Mutex<mpsc::Sender<DetectionCategory>>;
+            let result = unsafe {
+                CM_Register_Notification(
+                    &filter,
+                    Some(context_ptr as *const core::ffi::c_void),
+                    Some(notify_callback),
+                    &mut notification,
+                )
+            };
+            if result != CR_SUCCESS {
+                return Err(DetectionError::WindowsApiError(format!(
+                    "订阅设备变化通知失败: {:?}",
+                    result
+                )));
+            }
+            watcher.notifications.push(notification);
+        }
+
+        std::thread::spawn(move || coalesce_events(rx, on_change));
+        Ok(watcher)
+    }
+}
+
+impl Drop for HotplugWatcher {
+    fn drop(&mut self) {
+        // 取消订阅会等待正在执行的回调结束，之后才能安全释放回调上下文
+        for notification in self.notifications.drain(..) {
+            unsafe {
+                let _ = CM_Unregister_Notification(notification);
+            }
+        }
+    }
+}
+
+/// 设备通知回调，在系统线程池中执行
+unsafe extern "system" fn notify_callback(
+    _notify: HCMNOTIFICATION,
+    context: *const core::ffi::c_void,
+    action: CM_NOTIFY_ACTION,
+    event_data: *const CM_NOTIFY_EVENT_DATA,
+    _event_data_size: u32,
+) -> u32 {
+    if action != CM_NOTIFY_ACTION_DEVICEINTERFACEARRIVAL
+        && action != CM_NOTIFY_ACTION_DEVICEINTERFACEREMOVAL
+    {
+        return 0;
+    }
+    if context.is_null() || event_data.is_null() {
+        return 0;
+    }
+
+    let sender = &*(context as *const Mutex<mpsc::Sender<DetectionCategory>>);
+    let class_guid = (*event_data).u.DeviceInterface.ClassGuid;
+    if let Some(category) = category_for_interface(&class_guid) {
+        if let Ok(sender) = sender.lock() {
+            let _ = sender.send(category);
+        }
+    }
+    0
+}
+
+/// 合并短时间内的连续事件，设备稳定后通知一次
+///
+/// 监听器释放后通道关闭，线程随之退出
+fn coalesce_events<F>(rx: mpsc::Receiver<DetectionCategory>, on_change: F)
+where
+    F: Fn(Vec<DetectionCategory>),
+{
+    let settle = Duration::from_millis(HOTPLUG_SETTLE_MS);
+    while let Ok(first) = rx.recv() {
+        let mut categories = vec![first];
+        loop {
+            match rx.recv_timeout(settle) {
+                Ok(category) => {
+                    if !categories.contains(&category) {
+                        categories.push(category);
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => break,
+                Err(mpsc::RecvTimeoutError::Disconnected) => return,
+            }
+        }
+        log::info!("检测到硬件变化: {:?}", categories);
+        on_change(categories);
+    }
+}
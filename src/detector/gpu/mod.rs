@@ -0,0 +1,751 @@
+pub mod pcie;
+pub mod vendor;
+
+#[cfg(windows)]
+use crate::detector::monitor::{self, DisplayTarget};
+#[cfg(windows)]
+use crate::detector::DetectionError;
+use crate::iddb::gpu::GPU_RULE_DB;
+use crate::iddb::parse_id;
+#[cfg(windows)]
+use crate::iddb::{self, Bus};
+#[cfg(windows)]
+use crate::utils;
+#[cfg(windows)]
+use crate::utils::device::{find_device_instance_id, parse_device_instance_id};
+#[cfg(windows)]
+use crate::utils::registry::read_registry_string;
+#[cfg(windows)]
+use crate::utils::subsystem::SubsystemId;
+use crate::utils::size::ByteSize;
+#[cfg(windows)]
+use crate::utils::wmi;
+#[cfg(windows)]
+use crate::utils::wmi_date::parse_wmi_date;
+#[cfg(windows)]
+use crate::wmi_row;
+#[cfg(windows)]
+use windows::core::Interface;
+#[cfg(windows)]
+use windows::Win32::Devices::DeviceAndDriverInstallation::GUID_DEVCLASS_DISPLAY;
+#[cfg(windows)]
+use windows::Win32::Graphics::Dxgi::{
+    CreateDXGIFactory1, IDXGIAdapter1, IDXGIFactory1, IDXGIFactory6, DXGI_ADAPTER_FLAG_SOFTWARE,
+    DXGI_ERROR_NOT_FOUND, DXGI_GPU_PREFERENCE_HIGH_PERFORMANCE,
+};
+#[cfg(windows)]
+use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+
+/// 显示适配器设备类的注册表路径
+#[cfg(windows)]
+const DISPLAY_CLASS_KEY_PATH: &str =
+    r"SYSTEM\CurrentControlSet\Control\Class\{4d36e968-e325-11ce-bfc1-08002be10318}";
+
+/// 显示适配器设备类下最多检查的子键数量
+#[cfg(windows)]
+const DISPLAY_CLASS_MAX_SUBKEYS: u32 = 32;
+
+/// 显卡信息
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GpuInfo {
+    /// 显卡列表
+    pub gpus: Vec<Gpu>,
+    /// 混合输出（集成显卡与独立显卡并存）状态，单显卡时为 None
+    pub hybrid: Option<HybridGraphics>,
+}
+
+/// 混合输出状态
+///
+/// 笔记本的内置屏幕通常由集成显卡输出、独立显卡渲染（NVIDIA Optimus / AMD 可切换显卡）；
+/// 通过 MUX 开关或 Advanced Optimus 切换到独显直连后，内置屏幕改由独立显卡直接输出
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct HybridGraphics {
+    /// 输出内置屏幕（没有内置屏幕时为主显示器）的显卡类型
+    pub display_gpu: GpuType,
+    /// 系统首选的高性能渲染显卡类型
+    pub render_gpu: GpuType,
+}
+
+impl HybridGraphics {
+    /// 由显卡列表判断混合输出状态，没有同时存在集成显卡与独立显卡时返回 None
+    ///
+    /// 输出显卡取连接内置屏幕的显卡，没有内置屏幕时取第一个有显示输出的显卡
+    ///
+    /// * `gpus` - 显卡列表
+    /// * `high_performance` - 系统首选高性能显卡在列表中的序号，未知时默认为独立显卡
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::{Gpu, GpuOutput, GpuType, HybridGraphics};
+    /// let internal = GpuOutput {
+    ///     connection: "内置".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let integrated = Gpu {
+    ///     gpu_type: GpuType::IntegratedGpu,
+    ///     outputs: vec![internal],
+    ///     ..Default::default()
+    /// };
+    /// let discrete = Gpu {
+    ///     gpu_type: GpuType::DiscreteGpu,
+    ///     ..Default::default()
+    /// };
+    /// let hybrid = HybridGraphics::from_gpus(&[integrated.clone(), discrete], None).unwrap();
+    /// assert_eq!(hybrid.display_gpu, GpuType::IntegratedGpu);
+    /// assert_eq!(hybrid.render_gpu, GpuType::DiscreteGpu);
+    /// assert!(HybridGraphics::from_gpus(&[integrated], None).is_none());
+    /// ```
+    pub fn from_gpus(gpus: &[Gpu], high_performance: Option<usize>) -> Option<Self> {
+        let has_type = |gpu_type: GpuType| gpus.iter().any(|gpu| gpu.gpu_type == gpu_type);
+        if !has_type(GpuType::IntegratedGpu) || !has_type(GpuType::DiscreteGpu) {
+            return None;
+        }
+
+        let display_gpu = gpus
+            .iter()
+            .find(|gpu| gpu.outputs.iter().any(|output| output.connection == "内置"))
+            .or_else(|| gpus.iter().find(|gpu| !gpu.outputs.is_empty()))
+            .map(|gpu| gpu.gpu_type.clone())
+            .unwrap_or(GpuType::IntegratedGpu);
+        let render_gpu = high_performance
+            .and_then(|index| gpus.get(index))
+            .map(|gpu| gpu.gpu_type.clone())
+            .unwrap_or(GpuType::DiscreteGpu);
+        Some(Self {
+            display_gpu,
+            render_gpu,
+        })
+    }
+
+    /// 是否为独显直连（内置屏幕由独立显卡直接输出，需要 MUX 开关或 Advanced Optimus）
+    pub fn is_direct(&self) -> bool {
+        self.display_gpu == GpuType::DiscreteGpu
+    }
+
+    /// 生成一行摘要，如 "核显输出，独显渲染"
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::{GpuType, HybridGraphics};
+    /// let optimus = HybridGraphics {
+    ///     display_gpu: GpuType::IntegratedGpu,
+    ///     render_gpu: GpuType::DiscreteGpu,
+    /// };
+    /// assert_eq!(optimus.summary(), "核显输出，独显渲染");
+    /// let direct = HybridGraphics {
+    ///     display_gpu: GpuType::DiscreteGpu,
+    ///     render_gpu: GpuType::DiscreteGpu,
+    /// };
+    /// assert_eq!(direct.summary(), "独显输出，独显渲染（独显直连）");
+    /// ```
+    pub fn summary(&self) -> String {
+        let short = |gpu_type: &GpuType| match gpu_type {
+            GpuType::IntegratedGpu => "核显",
+            GpuType::DiscreteGpu => "独显",
+            _ => "其它显卡",
+        };
+        let mut text = format!(
+            "{}输出，{}渲染",
+            short(&self.display_gpu),
+            short(&self.render_gpu)
+        );
+        if self.is_direct() {
+            text.push_str("（独显直连）");
+        }
+        text
+    }
+}
+
+/// 单个显卡信息
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gpu {
+    /// 显卡描述
+    pub description: String,
+    /// 制造商
+    pub manufacturer: String,
+    /// 芯片商
+    pub chip_vendor: String,
+    /// 显卡类型
+    pub gpu_type: GpuType,
+    /// 专用显存，包括显卡独占的显存与系统为其保留的内存
+    pub dedicated_vram: ByteSize,
+    /// 可共享给显卡使用的系统内存
+    pub shared_system_memory: ByteSize,
+    /// 显卡 ID
+    pub device_id: String,
+    /// 厂商 ID
+    pub vendor_id: String,
+    /// 设备实例 ID，如 `PCI\VEN_10DE&DEV_2684&SUBSYS_16F310DE&REV_A1\4&...`
+    pub instance_id: String,
+    /// 显卡 BIOS (VBIOS) 版本，仅独立显卡
+    pub vbios_version: String,
+    /// 驱动版本，如 "32.0.15.6094"
+    pub driver_version: String,
+    /// 驱动日期，如 "2024-05-17"
+    pub driver_date: String,
+    /// 厂商运行库提供的运行数据，运行库不可用时为 None
+    pub vendor_stats: Option<vendor::VendorGpuStats>,
+    /// 由该显卡驱动的显示输出
+    pub outputs: Vec<GpuOutput>,
+    /// PCIe 链路状态，集成显卡或驱动未提供时为 None
+    pub pcie_link: Option<pcie::PcieLink>,
+}
+
+/// 显卡的显示输出（已连接的显示器）
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GpuOutput {
+    /// GDI 设备名，如 `\\.\DISPLAY1`
+    pub device_name: String,
+    /// 显示器名称
+    pub monitor: String,
+    /// 接口类型，如 "HDMI"、"DisplayPort"、"内置"
+    pub connection: String,
+    /// 桌面分辨率宽度
+    pub width: u32,
+    /// 桌面分辨率高度
+    pub height: u32,
+}
+
+impl Default for GpuOutput {
+    fn default() -> Self {
+        Self {
+            device_name: "未知".to_string(),
+            monitor: "未知".to_string(),
+            connection: "未知".to_string(),
+            width: 0,
+            height: 0,
+        }
+    }
+}
+
+impl GpuOutput {
+    /// 生成一行摘要，如 "DELL U2720Q (DisplayPort, 3840x2160)"
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::GpuOutput;
+    /// let output = GpuOutput {
+    ///     device_name: r"\\.\DISPLAY2".to_string(),
+    ///     monitor: "DELL U2720Q".to_string(),
+    ///     connection: "DisplayPort".to_string(),
+    ///     width: 3840,
+    ///     height: 2160,
+    /// };
+    /// assert_eq!(output.summary(), "DELL U2720Q (DisplayPort, 3840x2160)");
+    /// let unknown = GpuOutput {
+    ///     device_name: r"\\.\DISPLAY1".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(unknown.summary(), r"\\.\DISPLAY1");
+    /// ```
+    pub fn summary(&self) -> String {
+        let name = if self.monitor == "未知" {
+            &self.device_name
+        } else {
+            &self.monitor
+        };
+        let mut details = Vec::new();
+        if self.connection != "未知" {
+            details.push(self.connection.clone());
+        }
+        if self.width > 0 && self.height > 0 {
+            details.push(format!("{}x{}", self.width, self.height));
+        }
+        if details.is_empty() {
+            name.to_string()
+        } else {
+            format!("{} ({})", name, details.join(", "))
+        }
+    }
+}
+
+impl Gpu {
+    /// 有效显存
+    ///
+    /// 集成显卡的专用显存通常只有几十到几百 MB，实际主要使用共享的系统内存，
+    /// 因此为专用显存与共享内存之和；其他显卡为专用显存
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::{Gpu, GpuType};
+    /// use hardware_master::utils::size::ByteSize;
+    /// let integrated = Gpu {
+    ///     gpu_type: GpuType::IntegratedGpu,
+    ///     dedicated_vram: ByteSize::from_mb(512),
+    ///     shared_system_memory: ByteSize::from_mb(7680),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(integrated.effective_vram(), ByteSize::from_gb(8));
+    /// let discrete = Gpu {
+    ///     gpu_type: GpuType::DiscreteGpu,
+    ///     dedicated_vram: ByteSize::from_gb(8),
+    ///     shared_system_memory: ByteSize::from_gb(16),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(discrete.effective_vram(), ByteSize::from_gb(8));
+    /// ```
+    pub fn effective_vram(&self) -> ByteSize {
+        match self.gpu_type {
+            GpuType::IntegratedGpu => self.dedicated_vram + self.shared_system_memory,
+            _ => self.dedicated_vram,
+        }
+    }
+}
+
+impl Default for Gpu {
+    fn default() -> Self {
+        Self {
+            description: "未知".to_string(),
+            manufacturer: "未知".to_string(),
+            chip_vendor: "未知".to_string(),
+            gpu_type: GpuType::Other,
+            dedicated_vram: ByteSize::ZERO,
+            shared_system_memory: ByteSize::ZERO,
+            device_id: "未知".to_string(),
+            vendor_id: "未知".to_string(),
+            instance_id: "未知".to_string(),
+            vbios_version: "未知".to_string(),
+            driver_version: "未知".to_string(),
+            driver_date: "未知".to_string(),
+            vendor_stats: None,
+            outputs: Vec::new(),
+            pcie_link: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GpuType {
+    /// Other or Unknown.
+    Other,
+    /// Integrated GPU with shared CPU/GPU memory.
+    IntegratedGpu,
+    /// Discrete GPU with separate CPU/GPU memory.
+    DiscreteGpu,
+    /// Virtual / Hosted.
+    VirtualGpu,
+    /// Indirect display / streaming driver (IddCx, Parsec, spacedesk...).
+    VirtualDisplay,
+    /// Cpu / Software Rendering.
+    Cpu,
+}
+
+impl std::fmt::Display for GpuType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            GpuType::IntegratedGpu => "集成显卡",
+            GpuType::DiscreteGpu => "独立显卡",
+            GpuType::VirtualGpu => "虚拟显卡",
+            GpuType::VirtualDisplay => "虚拟显示器",
+            _ => "其它显卡",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl GpuType {
+    /// 是否为虚拟设备（虚拟机显卡或虚拟显示器驱动）
+    pub fn is_virtual(&self) -> bool {
+        matches!(self, GpuType::VirtualGpu | GpuType::VirtualDisplay)
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_VideoController 中用到的属性
+    struct VideoControllerRow {
+        name: String = "Name",
+        adapter_compatibility: String = "AdapterCompatibility",
+        adapter_ram: u32 = "AdapterRAM",
+        driver_version: String = "DriverVersion",
+        driver_date: String = "DriverDate",
+        pnp_device_id: String = "PNPDeviceID",
+    }
+}
+
+/// 检测显卡信息
+///
+/// 优先通过 DXGI 枚举显示适配器；DXGI 不可用或没有枚举到显卡时
+/// （远程桌面会话、驱动损坏、Server Core 等），改用 WMI 的 Win32_VideoController
+#[cfg(windows)]
+pub fn detect_gpu() -> Result<GpuInfo, DetectionError> {
+    let mut info = GpuInfo::default();
+
+    unsafe {
+        let targets = monitor::query_display_targets();
+        let factory = CreateDXGIFactory1::<IDXGIFactory1>()
+            .map_err(|e| log::warn!("创建 DXGI 工厂失败: {:?}", e))
+            .ok();
+        if let Some(factory) = factory {
+            let mut adapter_index = 0;
+            loop {
+                let adapter: IDXGIAdapter1 = match factory.EnumAdapters1(adapter_index) {
+                    Ok(a) => a,
+                    Err(ref e) if e.code() == DXGI_ERROR_NOT_FOUND => {
+                        break;
+                    }
+                    Err(e) => {
+                        log::warn!("枚举 IDXGI 适配器时发生错误: {:?}", e);
+                        break;
+                    }
+                };
+
+                let desc = match adapter.GetDesc1() {
+                    Ok(d) => d,
+                    Err(e) => {
+                        log::warn!("获取适配器描述时发生错误: {:?}", e);
+                        continue;
+                    }
+                };
+
+                let is_software = (desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32) != 0;
+                if is_software {
+                    adapter_index += 1;
+                    continue;
+                }
+
+                let description = utils::u16_slice_to_string(&desc.Description);
+                let vendor_id = format!("{:04x}", desc.VendorId);
+                let device_id = format!("{:04x}", desc.DeviceId);
+                // 子系统厂商即板卡厂商，驱动未提供子系统 ID 时为未知
+                let manufacturer = match SubsystemId::from_raw(desc.SubSysId) {
+                    Some(subsystem) => get_vendor_by_id(Bus::Pci, subsystem.vendor_id),
+                    None => "未知".to_string(),
+                };
+                let chip_vendor = get_vendor_by_id(Bus::Pci, desc.VendorId as u16);
+                let gpu_type = get_gpu_type(&description, &vendor_id, &device_id);
+                let dedicated_vram = ByteSize::from_bytes(
+                    (desc.DedicatedVideoMemory + desc.DedicatedSystemMemory) as u64,
+                );
+                let shared_system_memory = ByteSize::from_bytes(desc.SharedSystemMemory as u64);
+                let driver_key = find_driver_key(&vendor_id, &device_id);
+                let instance_id = find_device_instance_id(
+                    &GUID_DEVCLASS_DISPLAY,
+                    &format!("ven_{}&dev_{}", vendor_id, device_id),
+                )
+                .unwrap_or_else(|| "未知".to_string());
+                let driver_version = driver_key
+                    .as_deref()
+                    .and_then(|key| read_registry_string(HKEY_LOCAL_MACHINE, key, "DriverVersion"))
+                    .unwrap_or_else(|| "未知".to_string());
+                let driver_date = driver_key
+                    .as_deref()
+                    .and_then(|key| read_registry_string(HKEY_LOCAL_MACHINE, key, "DriverDate"))
+                    .and_then(|date| parse_driver_date(&date))
+                    .unwrap_or_else(|| "未知".to_string());
+                let vbios_version = match (&gpu_type, driver_key.as_deref()) {
+                    (GpuType::DiscreteGpu, Some(key)) => {
+                        read_vbios_version(key).unwrap_or_else(|| "未知".to_string())
+                    }
+                    _ => "未知".to_string(),
+                };
+
+                let mut gpu = Gpu {
+                    description,
+                    manufacturer,
+                    chip_vendor,
+                    gpu_type,
+                    dedicated_vram,
+                    shared_system_memory,
+                    device_id,
+                    vendor_id,
+                    instance_id,
+                    vbios_version,
+                    driver_version,
+                    driver_date,
+                    vendor_stats: None,
+                    outputs: enum_outputs(&adapter, &targets),
+                    pcie_link: None,
+                };
+                // 集成显卡不经过 PCIe 插槽，驱动报告的链路没有参考意义
+                if gpu.gpu_type == GpuType::DiscreteGpu {
+                    gpu.pcie_link = pcie::read_display_link(&gpu.vendor_id, &gpu.device_id);
+                }
+                apply_vendor_stats(&mut gpu);
+
+                info.gpus.push(gpu);
+
+                adapter_index += 1;
+            }
+
+            let high_performance =
+                high_performance_adapter(&factory).and_then(|(vendor, device)| {
+                    info.gpus
+                        .iter()
+                        .position(|gpu| gpu.vendor_id == vendor && gpu.device_id == device)
+                });
+            info.hybrid = HybridGraphics::from_gpus(&info.gpus, high_performance);
+        }
+    }
+
+    if info.gpus.is_empty() {
+        log::info!("DXGI 未检测到显卡，改用 WMI 检测");
+        info.gpus = detect_video_controllers().map_err(|e| e.within(DetectionError::GpuError))?;
+        info.hybrid = HybridGraphics::from_gpus(&info.gpus, None);
+    }
+
+    Ok(info)
+}
+
+/// 查询系统首选的高性能显卡，返回其厂商与设备 ID（十六进制小写）
+///
+/// IDXGIFactory6 需要 Windows 10 1803 及以上
+#[cfg(windows)]
+unsafe fn high_performance_adapter(factory: &IDXGIFactory1) -> Option<(String, String)> {
+    let factory: IDXGIFactory6 = factory.cast().ok()?;
+    let adapter: IDXGIAdapter1 = factory
+        .EnumAdapterByGpuPreference(0, DXGI_GPU_PREFERENCE_HIGH_PERFORMANCE)
+        .map_err(|e| log::warn!("查询高性能显卡失败: {:?}", e))
+        .ok()?;
+    let desc = adapter.GetDesc1().ok()?;
+    Some((
+        format!("{:04x}", desc.VendorId),
+        format!("{:04x}", desc.DeviceId),
+    ))
+}
+
+/// 枚举显卡的显示输出，并按 GDI 设备名与活动显示路径对应，补充显示器名称与接口类型
+///
+/// * `adapter` - DXGI 适配器
+/// * `targets` - 活动显示路径的目标显示器
+#[cfg(windows)]
+unsafe fn enum_outputs(adapter: &IDXGIAdapter1, targets: &[DisplayTarget]) -> Vec<GpuOutput> {
+    let mut outputs = Vec::new();
+    let mut output_index = 0;
+    loop {
+        let output = match adapter.EnumOutputs(output_index) {
+            Ok(output) => output,
+            Err(ref e) if e.code() == DXGI_ERROR_NOT_FOUND => break,
+            Err(e) => {
+                log::warn!("枚举显示输出时发生错误: {:?}", e);
+                break;
+            }
+        };
+        output_index += 1;
+
+        let desc = match output.GetDesc() {
+            Ok(desc) => desc,
+            Err(e) => {
+                log::warn!("获取显示输出描述时发生错误: {:?}", e);
+                continue;
+            }
+        };
+        if !desc.AttachedToDesktop.as_bool() {
+            continue;
+        }
+
+        let device_name = utils::u16_slice_to_string(&desc.DeviceName);
+        let mut gpu_output = GpuOutput {
+            width: (desc.DesktopCoordinates.right - desc.DesktopCoordinates.left).unsigned_abs(),
+            height: (desc.DesktopCoordinates.bottom - desc.DesktopCoordinates.top).unsigned_abs(),
+            ..Default::default()
+        };
+        if let Some(target) = targets
+            .iter()
+            .find(|target| target.gdi_name.eq_ignore_ascii_case(&device_name))
+        {
+            gpu_output.connection =
+                monitor::output_technology_name(target.technology.0).to_string();
+            gpu_output.monitor = target.display_name();
+        }
+        gpu_output.device_name = device_name;
+        outputs.push(gpu_output);
+    }
+    outputs
+}
+
+/// 通过 WMI 的 Win32_VideoController 检测显卡
+///
+/// AdapterRAM 为 32 位字段，超过 4 GB 的显存只能显示为 4 GB
+#[cfg(windows)]
+fn detect_video_controllers() -> Result<Vec<Gpu>, DetectionError> {
+    let config = wmi::WmiConfig::default();
+    let client = wmi::WmiClient::connect(&config)?;
+    let rows: Vec<VideoControllerRow> = client.query_as(
+        "SELECT Name, AdapterCompatibility, AdapterRAM, DriverVersion, DriverDate, PNPDeviceID FROM Win32_VideoController",
+    )?;
+    Ok(rows.into_iter().map(parse_video_controller_row).collect())
+}
+
+/// 解析 WMI 显卡对象，厂商与设备 ID 取自 PNPDeviceID
+#[cfg(windows)]
+fn parse_video_controller_row(row: VideoControllerRow) -> Gpu {
+    let mut gpu = Gpu::default();
+
+    if let Some(name) = row.name.filter(|name| !name.trim().is_empty()) {
+        gpu.description = name;
+    }
+    if let Some(version) = row
+        .driver_version
+        .filter(|version| !version.trim().is_empty())
+    {
+        gpu.driver_version = version;
+    }
+    if let Some(date) = row.driver_date.filter(|date| date.len() >= 8) {
+        gpu.driver_date = parse_wmi_date(&date);
+    }
+    gpu.dedicated_vram = ByteSize::from_bytes(row.adapter_ram.unwrap_or(0) as u64);
+
+    if let Some(pnp_device_id) = row.pnp_device_id.as_deref() {
+        gpu.instance_id = pnp_device_id.to_string();
+        let ids = parse_device_instance_id(pnp_device_id);
+        let bus = Bus::from_enumerator(&ids.bus_type);
+        if ids.vendor_id != "未知" {
+            gpu.vendor_id = ids.vendor_id.to_lowercase();
+            if let (Some(bus), Some(vendor_id)) = (bus, iddb::parse_id(&ids.vendor_id)) {
+                gpu.chip_vendor = get_vendor_by_id(bus, vendor_id);
+            }
+        }
+        if ids.device_id != "未知" {
+            gpu.device_id = ids.device_id.to_lowercase();
+        }
+        if let Some(subsystem) = SubsystemId::from_instance_id(pnp_device_id) {
+            gpu.manufacturer = get_vendor_by_id(Bus::Pci, subsystem.vendor_id);
+        }
+    }
+    if gpu.chip_vendor == "未知" {
+        if let Some(compatibility) = row.adapter_compatibility {
+            gpu.chip_vendor = compatibility;
+        }
+    }
+
+    gpu.gpu_type = get_gpu_type(&gpu.description, &gpu.vendor_id, &gpu.device_id);
+    if gpu.gpu_type == GpuType::DiscreteGpu {
+        let driver_key = unsafe { find_driver_key(&gpu.vendor_id, &gpu.device_id) };
+        if let Some(version) = driver_key.and_then(|key| unsafe { read_vbios_version(&key) }) {
+            gpu.vbios_version = version;
+        }
+        gpu.pcie_link = pcie::read_display_link(&gpu.vendor_id, &gpu.device_id);
+    }
+    apply_vendor_stats(&mut gpu);
+
+    gpu
+}
+
+/// 通过厂商运行库补充运行数据，未从注册表读取到 VBIOS 版本时一并补充
+#[cfg(windows)]
+fn apply_vendor_stats(gpu: &mut Gpu) {
+    if !matches!(gpu.gpu_type, GpuType::DiscreteGpu | GpuType::IntegratedGpu) {
+        return;
+    }
+    gpu.vendor_stats = vendor::query(&gpu.vendor_id, &gpu.device_id);
+    if gpu.vbios_version == "未知" {
+        if let Some(version) = gpu
+            .vendor_stats
+            .as_ref()
+            .and_then(|stats| stats.vbios_version.clone())
+        {
+            gpu.vbios_version = version;
+        }
+    }
+}
+
+/// 查找显卡的驱动注册表项
+///
+/// 通过显示适配器设备类下各子键 `MatchingDeviceId` 中的 `ven_xxxx&dev_xxxx` 匹配对应显卡
+///
+/// * `vendor_id` - 厂商 ID（十六进制，如 "10de"）
+/// * `device_id` - 设备 ID（十六进制，如 "2504"）
+#[cfg(windows)]
+unsafe fn find_driver_key(vendor_id: &str, device_id: &str) -> Option<String> {
+    let pattern = format!("ven_{}&dev_{}", vendor_id, device_id).to_lowercase();
+
+    (0..DISPLAY_CLASS_MAX_SUBKEYS)
+        .map(|index| format!("{}\\{:04}", DISPLAY_CLASS_KEY_PATH, index))
+        .find(|sub_key| {
+            read_registry_string(HKEY_LOCAL_MACHINE, sub_key, "MatchingDeviceId")
+                .is_some_and(|matching_id| matching_id.to_lowercase().contains(&pattern))
+        })
+}
+
+/// 从显卡的驱动注册表项读取 VBIOS 版本
+///
+/// 驱动会将 VBIOS 版本写入 `HardwareInformation.BiosString`
+///
+/// * `driver_key` - 驱动注册表项路径
+#[cfg(windows)]
+unsafe fn read_vbios_version(driver_key: &str) -> Option<String> {
+    // NVIDIA 以 REG_BINARY 保存 UTF-16 文本，AMD 为 REG_SZ，均可按宽字符读取
+    read_registry_string(
+        HKEY_LOCAL_MACHINE,
+        driver_key,
+        "HardwareInformation.BiosString",
+    )
+    .and_then(|bios| normalize_vbios_version(&bios))
+}
+
+/// 将驱动注册表项中的 DriverDate（月-日-年）转换为 "2024-05-17" 格式
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::gpu::parse_driver_date;
+/// assert_eq!(parse_driver_date("5-17-2024"), Some("2024-05-17".to_string()));
+/// assert_eq!(parse_driver_date("12-1-2023 "), Some("2023-12-01".to_string()));
+/// assert_eq!(parse_driver_date("2024-05-17"), None);
+/// assert_eq!(parse_driver_date(""), None);
+/// ```
+pub fn parse_driver_date(date: &str) -> Option<String> {
+    chrono::NaiveDate::parse_from_str(date.trim(), "%m-%d-%Y")
+        .ok()
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// 规范化 VBIOS 版本字符串，去除 "Version" 前缀与首尾空白
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::gpu::normalize_vbios_version;
+/// assert_eq!(normalize_vbios_version("Version95.2.1f.0.1 "), Some("95.2.1f.0.1".to_string()));
+/// assert_eq!(normalize_vbios_version("  "), None);
+/// ```
+pub fn normalize_vbios_version(bios: &str) -> Option<String> {
+    let trimmed = bios.trim_matches(|c: char| c.is_whitespace() || c == '\0');
+    let version = trimmed
+        .strip_prefix("Version")
+        .unwrap_or(trimmed)
+        .trim_start_matches([' ', ':']);
+    if version.is_empty() {
+        None
+    } else {
+        Some(version.to_string())
+    }
+}
+
+/// 获取厂商名称
+#[cfg(windows)]
+fn get_vendor_by_id(bus: Bus, vendor_id: u16) -> String {
+    match iddb::db().bus(bus).vendor(vendor_id).name() {
+        Some(name) => name,
+        None => {
+            log::warn!("未查询到 bus={:?}; vendor_id={:04x} 的信息", bus, vendor_id);
+            return "未知".to_string();
+        }
+    }
+}
+
+/// 获取显卡类型
+///
+/// 按 [`GPU_RULE_DB`] 中的规则判断，没有命中的规则时返回 `GpuType::Other`
+///
+/// * `description` - 显卡描述
+/// * `vendor_id` - 厂商 ID（十六进制，如 "10de"）
+/// * `device_id` - 设备 ID（十六进制，如 "2882"）
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::gpu::{get_gpu_type, GpuType};
+/// assert_eq!(get_gpu_type("Parsec Virtual Display Adapter", "0000", "0000"), GpuType::VirtualDisplay);
+/// assert_eq!(get_gpu_type("NVIDIA GeForce RTX 4060", "10de", "2882"), GpuType::DiscreteGpu);
+/// assert_eq!(get_gpu_type("AMD Radeon 780M Graphics", "1002", "15bf"), GpuType::IntegratedGpu);
+/// assert_eq!(get_gpu_type("Intel(R) Arc(TM) A770 Graphics", "8086", "56a0"), GpuType::DiscreteGpu);
+/// ```
+pub fn get_gpu_type(description: &str, vendor_id: &str, device_id: &str) -> GpuType {
+    GPU_RULE_DB
+        .classify(description, parse_id(vendor_id), parse_id(device_id))
+        .map_or(GpuType::Other, |rule| rule.gpu_type.clone())
+}
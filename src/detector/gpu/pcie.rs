@@ -0,0 +1,194 @@
+//! PCIe 链路模块
+//!
+//! 读取设备当前协商的 PCIe 代数与通道宽度，以及设备支持的最大值（DEVPKEY_PciDevice_* 设备属性）。
+//!
+//! 显卡空闲时驱动会主动把链路降到 1.1 以省电，因此代数偏低不一定异常；
+//! 宽度不会随负载变化，x16 显卡只跑在 x4/x8 通常说明插在了通道较少的插槽上，或金手指接触不良。
+
+#[cfg(windows)]
+use crate::utils::device::{get_device_instance_id, get_device_property_u32};
+#[cfg(windows)]
+use windows::core::{GUID, PCWSTR};
+#[cfg(windows)]
+use windows::Win32::Devices::DeviceAndDriverInstallation::{
+    SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInfo, SetupDiGetClassDevsW, DIGCF_PRESENT,
+    GUID_DEVCLASS_DISPLAY, SP_DEVINFO_DATA,
+};
+#[cfg(windows)]
+use windows::Win32::Foundation::DEVPROPKEY;
+
+/// DEVPKEY_PciDevice_* 属性集
+#[cfg(windows)]
+const PCI_DEVICE_FMTID: GUID = GUID::from_u128(0x3ab22e31_8264_4b4e_9af5_a8d2d8e33e62);
+
+/// 属性集中的当前链路速率、当前链路宽度、最大链路速率与最大链路宽度
+#[cfg(windows)]
+const CURRENT_LINK_SPEED: u32 = 9;
+#[cfg(windows)]
+const CURRENT_LINK_WIDTH: u32 = 10;
+#[cfg(windows)]
+const MAX_LINK_SPEED: u32 = 11;
+#[cfg(windows)]
+const MAX_LINK_WIDTH: u32 = 12;
+
+/// 最高支持的 PCIe 代数（6.0，64 GT/s）
+const MAX_GENERATION: u32 = 6;
+
+/// PCIe 链路状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PcieLink {
+    /// 当前代数，如 4 表示 PCIe 4.0
+    pub current_generation: u8,
+    /// 当前通道宽度，如 16 表示 x16
+    pub current_width: u8,
+    /// 设备支持的最高代数
+    pub max_generation: u8,
+    /// 设备支持的最大通道宽度
+    pub max_width: u8,
+}
+
+impl PcieLink {
+    /// 由设备属性的原始值创建，任一值无效时返回 None
+    ///
+    /// 链路速率取值 1~6 依次对应 2.5/5/8/16/32/64 GT/s，即 PCIe 1.x~6.0
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::pcie::PcieLink;
+    /// let link = PcieLink::from_raw(4, 8, 4, 16).unwrap();
+    /// assert_eq!(link.current_generation, 4);
+    /// assert_eq!(link.current_width, 8);
+    /// assert!(PcieLink::from_raw(0, 16, 4, 16).is_none());
+    /// assert!(PcieLink::from_raw(4, 0, 4, 16).is_none());
+    /// ```
+    pub fn from_raw(
+        current_speed: u32,
+        current_width: u32,
+        max_speed: u32,
+        max_width: u32,
+    ) -> Option<Self> {
+        let generation = |speed: u32| (1..=MAX_GENERATION).contains(&speed).then_some(speed as u8);
+        let width = |width: u32| (1..=32).contains(&width).then_some(width as u8);
+        Some(Self {
+            current_generation: generation(current_speed)?,
+            current_width: width(current_width)?,
+            max_generation: generation(max_speed)?,
+            max_width: width(max_width)?,
+        })
+    }
+
+    /// 通道宽度是否低于设备支持的最大宽度
+    pub fn is_width_degraded(&self) -> bool {
+        self.current_width < self.max_width
+    }
+
+    /// 摘要，如 "PCIe 4.0 x8 (最高 4.0 x16)"，与最大值相同时只显示当前值
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::pcie::PcieLink;
+    /// let link = PcieLink::from_raw(1, 16, 4, 16).unwrap();
+    /// assert_eq!(link.summary(), "PCIe 1.1 x16 (最高 4.0 x16)");
+    /// let link = PcieLink::from_raw(3, 16, 3, 16).unwrap();
+    /// assert_eq!(link.summary(), "PCIe 3.0 x16");
+    /// ```
+    pub fn summary(&self) -> String {
+        let current = format!(
+            "{} x{}",
+            generation_name(self.current_generation),
+            self.current_width
+        );
+        if self.current_generation == self.max_generation && !self.is_width_degraded() {
+            return format!("PCIe {}", current);
+        }
+        format!(
+            "PCIe {} (最高 {} x{})",
+            current,
+            generation_name(self.max_generation),
+            self.max_width
+        )
+    }
+
+    /// 通道宽度降级时的提示，代数降低可能只是空闲节能，不提示
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::pcie::PcieLink;
+    /// let link = PcieLink::from_raw(4, 4, 4, 16).unwrap();
+    /// assert_eq!(
+    ///     link.warning().as_deref(),
+    ///     Some("PCIe 链路仅 x4 (设备支持 x16)，请检查是否插在 x16 插槽上")
+    /// );
+    /// assert_eq!(PcieLink::from_raw(1, 16, 4, 16).unwrap().warning(), None);
+    /// ```
+    pub fn warning(&self) -> Option<String> {
+        self.is_width_degraded().then(|| {
+            format!(
+                "PCIe 链路仅 x{} (设备支持 x{})，请检查是否插在 x{} 插槽上",
+                self.current_width, self.max_width, self.max_width
+            )
+        })
+    }
+}
+
+/// PCIe 代数的版本名称，1 代为 "1.1"
+fn generation_name(generation: u8) -> String {
+    match generation {
+        1 => "1.1".to_string(),
+        generation => format!("{}.0", generation),
+    }
+}
+
+/// 读取显示适配器的 PCIe 链路状态，非 PCIe 设备或驱动未提供时返回 None
+///
+/// * `vendor_id` - 厂商 ID，小写十六进制，如 "10de"
+/// * `device_id` - 设备 ID，小写十六进制
+#[cfg(windows)]
+pub fn read_display_link(vendor_id: &str, device_id: &str) -> Option<PcieLink> {
+    let pattern = format!("ven_{}&dev_{}", vendor_id, device_id).to_lowercase();
+    unsafe {
+        let device_info_set = SetupDiGetClassDevsW(
+            Some(&GUID_DEVCLASS_DISPLAY),
+            PCWSTR::null(),
+            None,
+            DIGCF_PRESENT,
+        )
+        .ok()?;
+        let _guard = scopeguard::guard(device_info_set, |set| {
+            let _ = SetupDiDestroyDeviceInfoList(set);
+        });
+
+        let mut index = 0;
+        loop {
+            let mut device_info_data = SP_DEVINFO_DATA {
+                cbSize: std::mem::size_of::<SP_DEVINFO_DATA>() as u32,
+                ..Default::default()
+            };
+            SetupDiEnumDeviceInfo(device_info_set, index, &mut device_info_data).ok()?;
+            index += 1;
+
+            let matched = get_device_instance_id(device_info_set, &device_info_data)
+                .is_ok_and(|id| id.to_lowercase().contains(&pattern));
+            if !matched {
+                continue;
+            }
+            let property = |pid: u32| {
+                get_device_property_u32(
+                    device_info_set,
+                    &device_info_data,
+                    &DEVPROPKEY {
+                        fmtid: PCI_DEVICE_FMTID,
+                        pid,
+                    },
+                )
+            };
+            return PcieLink::from_raw(
+                property(CURRENT_LINK_SPEED)?,
+                property(CURRENT_LINK_WIDTH)?,
+                property(MAX_LINK_SPEED)?,
+                property(MAX_LINK_WIDTH)?,
+            );
+        }
+    }
+}
@@ -0,0 +1,491 @@
+//! 显卡厂商运行库模块
+//!
+//! 通过驱动附带的厂商运行库读取 DXGI 无法提供的运行数据（核心/显存频率、功耗、风扇转速、VBIOS 版本）：
+//! - NVIDIA: NVML（`nvml.dll`）
+//! - AMD: ADL（`atiadlxx.dll`）。ADLX 为 C++ 接口，AGS 需要随程序分发运行库，均不便直接调用
+//!
+//! 运行库在运行时动态加载，未安装厂商驱动或查询失败时返回 None，不影响其余检测
+
+/// 厂商运行库提供的显卡运行数据，运行库不支持的项为 None
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct VendorGpuStats {
+    /// 数据来源，如 "NVML"、"ADL"
+    pub source: String,
+    /// 核心频率 (MHz)
+    pub core_clock_mhz: Option<u32>,
+    /// 显存频率 (MHz)
+    pub memory_clock_mhz: Option<u32>,
+    /// 功耗 (W)
+    pub power_watts: Option<f64>,
+    /// 风扇转速（百分比）
+    pub fan_percent: Option<u32>,
+    /// VBIOS 版本
+    pub vbios_version: Option<String>,
+}
+
+impl VendorGpuStats {
+    /// 运行数据摘要，没有任何数据时为空字符串
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::vendor::VendorGpuStats;
+    /// let stats = VendorGpuStats {
+    ///     core_clock_mhz: Some(1830),
+    ///     memory_clock_mhz: Some(8001),
+    ///     power_watts: Some(115.24),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(stats.summary(), "核心 1830 MHz · 显存 8001 MHz · 功耗 115.2 W");
+    /// assert_eq!(VendorGpuStats::default().summary(), "");
+    /// ```
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(clock) = self.core_clock_mhz {
+            parts.push(format!("核心 {} MHz", clock));
+        }
+        if let Some(clock) = self.memory_clock_mhz {
+            parts.push(format!("显存 {} MHz", clock));
+        }
+        if let Some(power) = self.power_watts {
+            parts.push(format!("功耗 {:.1} W", power));
+        }
+        if let Some(fan) = self.fan_percent {
+            parts.push(format!("风扇 {}%", fan));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// 厂商运行库
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VendorLibrary {
+    /// NVIDIA Management Library
+    Nvml,
+    /// AMD Display Library
+    Adl,
+}
+
+impl VendorLibrary {
+    /// 按厂商 ID 选择运行库，不支持的厂商返回 None
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::vendor::VendorLibrary;
+    /// assert_eq!(VendorLibrary::for_vendor("10DE"), Some(VendorLibrary::Nvml));
+    /// assert_eq!(VendorLibrary::for_vendor("1002"), Some(VendorLibrary::Adl));
+    /// assert_eq!(VendorLibrary::for_vendor("8086"), None);
+    /// ```
+    pub fn for_vendor(vendor_id: &str) -> Option<Self> {
+        match vendor_id.to_lowercase().as_str() {
+            "10de" => Some(VendorLibrary::Nvml),
+            "1002" => Some(VendorLibrary::Adl),
+            _ => None,
+        }
+    }
+
+    /// 数据来源名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            VendorLibrary::Nvml => "NVML",
+            VendorLibrary::Adl => "ADL",
+        }
+    }
+
+    /// 将运行库的原始读数转换为运行数据，NVML 的功耗以毫瓦为单位，ADL 以瓦为单位
+    pub fn stats(&self, readings: RawReadings) -> VendorGpuStats {
+        let power_watts = readings.power.map(|power| match self {
+            VendorLibrary::Nvml => power as f64 / 1000.0,
+            VendorLibrary::Adl => power as f64,
+        });
+        VendorGpuStats {
+            source: self.name().to_string(),
+            core_clock_mhz: readings.core_clock_mhz,
+            memory_clock_mhz: readings.memory_clock_mhz,
+            power_watts,
+            fan_percent: readings.fan_percent,
+            vbios_version: readings.vbios_version,
+        }
+    }
+}
+
+/// 运行库返回的原始读数，单位与运行库接口一致，读取失败的项为 None
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RawReadings {
+    /// 核心频率 (MHz)
+    pub core_clock_mhz: Option<u32>,
+    /// 显存频率 (MHz)
+    pub memory_clock_mhz: Option<u32>,
+    /// 功耗，单位见 [`VendorLibrary::stats`]
+    pub power: Option<u32>,
+    /// 风扇转速（百分比）
+    pub fan_percent: Option<u32>,
+    /// VBIOS 版本
+    pub vbios_version: Option<String>,
+}
+
+/// 通过厂商运行库查询显卡运行数据
+///
+/// 有多块同型号显卡时返回第一块的数据
+///
+/// * `vendor_id` - 厂商 ID（十六进制，如 "10de"）
+/// * `device_id` - 设备 ID（十六进制，如 "2504"）
+#[cfg(windows)]
+pub fn query(vendor_id: &str, device_id: &str) -> Option<VendorGpuStats> {
+    query_with(vendor_id, device_id, |library, device_id| match library {
+        VendorLibrary::Nvml => nvml::read(device_id),
+        VendorLibrary::Adl => adl::read(device_id),
+    })
+}
+
+/// 使用指定的读取函数查询显卡运行数据
+///
+/// `read` 接收运行库与设备 ID，运行库不存在或初始化失败时返回 None
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::gpu::vendor::{query_with, RawReadings};
+/// // 运行库不存在
+/// assert_eq!(query_with("10de", "2504", |_, _| None), None);
+/// // 不支持的厂商不会调用读取函数
+/// assert_eq!(query_with("8086", "a780", |_, _| unreachable!()), None);
+///
+/// let stats = query_with("10de", "2504", |_, device_id| {
+///     assert_eq!(device_id, 0x2504);
+///     Some(RawReadings { power: Some(115240), ..Default::default() })
+/// })
+/// .unwrap();
+/// assert_eq!(stats.power_watts, Some(115.24));
+/// ```
+pub fn query_with<F>(vendor_id: &str, device_id: &str, read: F) -> Option<VendorGpuStats>
+where
+    F: FnOnce(VendorLibrary, u32) -> Option<RawReadings>,
+{
+    let library = VendorLibrary::for_vendor(vendor_id)?;
+    let device_id = u32::from_str_radix(device_id.trim(), 16).ok()?;
+    read(library, device_id).map(|readings| library.stats(readings))
+}
+
+/// NVML `nvmlPciInfo_t::pciDeviceId` 是否为指定设备，高 16 位为设备 ID，低 16 位为厂商 ID
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::gpu::vendor::nvml_device_matches;
+/// assert!(nvml_device_matches(0x250410de, 0x2504));
+/// assert!(!nvml_device_matches(0x250410de, 0x10de));
+/// ```
+pub fn nvml_device_matches(pci_device_id: u32, device_id: u32) -> bool {
+    pci_device_id >> 16 == device_id
+}
+
+/// ADL 适配器的 PNP 字符串（如 "PCI_VEN_1002&DEV_73BF&SUBSYS_..."）是否为指定设备
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::gpu::vendor::adl_pnp_matches;
+/// assert!(adl_pnp_matches("PCI_VEN_1002&DEV_73BF&SUBSYS_23181458", 0x73bf));
+/// assert!(adl_pnp_matches("pci_ven_1002&dev_73bf", 0x73bf));
+/// assert!(!adl_pnp_matches("PCI_VEN_1002&DEV_73BF", 0x73a5));
+/// ```
+pub fn adl_pnp_matches(pnp: &str, device_id: u32) -> bool {
+    pnp.to_uppercase()
+        .contains(&format!("DEV_{:04X}", device_id))
+}
+
+/// ADL 传感器读数，不支持的传感器或负值返回 None
+///
+/// * `supported` - `ADLSingleSensorData::supported`
+/// * `value` - `ADLSingleSensorData::value`
+pub fn adl_sensor_value(supported: i32, value: i32) -> Option<u32> {
+    (supported != 0 && value >= 0).then_some(value as u32)
+}
+
+/// 将 C 字符串缓冲区转换为字符串，空字符串返回 None
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::gpu::vendor::c_buffer_to_string;
+/// let buffer = [b' ' as _, b'9' as _, b'4' as _, b'.' as _, b'0' as _, 0, b'x' as _];
+/// assert_eq!(c_buffer_to_string(&buffer).as_deref(), Some("94.0"));
+/// assert_eq!(c_buffer_to_string(&[0; 4]), None);
+/// ```
+pub fn c_buffer_to_string(buffer: &[std::ffi::c_char]) -> Option<String> {
+    let bytes: Vec<u8> = buffer
+        .iter()
+        .take_while(|c| **c != 0)
+        .map(|c| *c as u8)
+        .collect();
+    let text = String::from_utf8_lossy(&bytes).trim().to_string();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// NVIDIA Management Library
+#[cfg(windows)]
+mod nvml {
+    use super::{c_buffer_to_string, nvml_device_matches, RawReadings};
+    use crate::utils::library::Library;
+    use std::ffi::{c_char, c_void};
+
+    /// 旧版驱动将 NVML 安装在 NVSMI 目录，新版驱动安装在系统目录
+    const NVSMI_PATH: &str = r"C:\Program Files\NVIDIA Corporation\NVSMI\nvml.dll";
+    const NVML_SUCCESS: i32 = 0;
+    const NVML_CLOCK_GRAPHICS: u32 = 0;
+    const NVML_CLOCK_MEM: u32 = 2;
+    const NVML_DEVICE_VBIOS_VERSION_BUFFER_SIZE: usize = 32;
+
+    type Device = *mut c_void;
+
+    /// nvmlPciInfo_t
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct PciInfo {
+        bus_id_legacy: [c_char; 16],
+        domain: u32,
+        bus: u32,
+        device: u32,
+        /// 高 16 位为设备 ID，低 16 位为厂商 ID
+        pci_device_id: u32,
+        pci_sub_system_id: u32,
+        bus_id: [c_char; 32],
+    }
+
+    type InitFn = unsafe extern "C" fn() -> i32;
+    type ShutdownFn = unsafe extern "C" fn() -> i32;
+    type GetCountFn = unsafe extern "C" fn(*mut u32) -> i32;
+    type GetHandleByIndexFn = unsafe extern "C" fn(u32, *mut Device) -> i32;
+    type GetPciInfoFn = unsafe extern "C" fn(Device, *mut PciInfo) -> i32;
+    type GetClockInfoFn = unsafe extern "C" fn(Device, u32, *mut u32) -> i32;
+    type GetU32Fn = unsafe extern "C" fn(Device, *mut u32) -> i32;
+    type GetVbiosVersionFn = unsafe extern "C" fn(Device, *mut c_char, u32) -> i32;
+
+    /// 读取指定设备 ID 的 NVIDIA 显卡
+    pub fn read(device_id: u32) -> Option<RawReadings> {
+        let library = Library::open_system("nvml.dll").or_else(|| Library::open(NVSMI_PATH))?;
+        unsafe {
+            let init: InitFn = library.symbol("nvmlInit_v2")?;
+            let shutdown: ShutdownFn = library.symbol("nvmlShutdown")?;
+            if init() != NVML_SUCCESS {
+                log::debug!("nvmlInit_v2 失败");
+                return None;
+            }
+            let _shutdown = scopeguard::guard((), |_| {
+                shutdown();
+            });
+
+            let device = find_device(&library, device_id)?;
+            let read_u32 = |name: &str| -> Option<u32> {
+                let get: GetU32Fn = library.symbol(name)?;
+                let mut value = 0u32;
+                (get(device, &mut value) == NVML_SUCCESS).then_some(value)
+            };
+            let read_clock = |clock_type: u32| -> Option<u32> {
+                let get: GetClockInfoFn = library.symbol("nvmlDeviceGetClockInfo")?;
+                let mut value = 0u32;
+                (get(device, clock_type, &mut value) == NVML_SUCCESS).then_some(value)
+            };
+
+            Some(RawReadings {
+                core_clock_mhz: read_clock(NVML_CLOCK_GRAPHICS),
+                memory_clock_mhz: read_clock(NVML_CLOCK_MEM),
+                power: read_u32("nvmlDeviceGetPowerUsage"),
+                fan_percent: read_u32("nvmlDeviceGetFanSpeed"),
+                vbios_version: read_vbios_version(&library, device),
+            })
+        }
+    }
+
+    /// 按 PCI 设备 ID 查找设备句柄
+    unsafe fn find_device(library: &Library, device_id: u32) -> Option<Device> {
+        let get_count: GetCountFn = library.symbol("nvmlDeviceGetCount_v2")?;
+        let get_handle: GetHandleByIndexFn = library.symbol("nvmlDeviceGetHandleByIndex_v2")?;
+        let get_pci_info: GetPciInfoFn = library.symbol("nvmlDeviceGetPciInfo_v3")?;
+
+        let mut count = 0u32;
+        if get_count(&mut count) != NVML_SUCCESS {
+            return None;
+        }
+        (0..count).find_map(|index| {
+            let mut device: Device = std::ptr::null_mut();
+            if get_handle(index, &mut device) != NVML_SUCCESS {
+                return None;
+            }
+            let mut pci: PciInfo = std::mem::zeroed();
+            if get_pci_info(device, &mut pci) != NVML_SUCCESS {
+                return None;
+            }
+            nvml_device_matches(pci.pci_device_id, device_id).then_some(device)
+        })
+    }
+
+    /// 读取 VBIOS 版本
+    unsafe fn read_vbios_version(library: &Library, device: Device) -> Option<String> {
+        let get: GetVbiosVersionFn = library.symbol("nvmlDeviceGetVbiosVersion")?;
+        let mut buffer = [0 as c_char; NVML_DEVICE_VBIOS_VERSION_BUFFER_SIZE];
+        if get(device, buffer.as_mut_ptr(), buffer.len() as u32) != NVML_SUCCESS {
+            return None;
+        }
+        c_buffer_to_string(&buffer)
+    }
+}
+
+/// AMD Display Library
+#[cfg(windows)]
+mod adl {
+    use super::{adl_pnp_matches, adl_sensor_value, c_buffer_to_string, RawReadings};
+    use crate::utils::library::Library;
+    use std::ffi::{c_char, c_void};
+
+    const ADL_OK: i32 = 0;
+    const ADL_MAX_PATH: usize = 256;
+    const ADL_PMLOG_MAX_SENSORS: usize = 256;
+    const PMLOG_CLK_GFXCLK: usize = 1;
+    const PMLOG_CLK_MEMCLK: usize = 2;
+    const PMLOG_FAN_PERCENTAGE: usize = 15;
+    const PMLOG_ASIC_POWER: usize = 23;
+
+    type Context = *mut c_void;
+
+    /// AdapterInfo（Windows 版本）
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct AdapterInfo {
+        size: i32,
+        adapter_index: i32,
+        udid: [c_char; ADL_MAX_PATH],
+        bus_number: i32,
+        device_number: i32,
+        function_number: i32,
+        vendor_id: i32,
+        adapter_name: [c_char; ADL_MAX_PATH],
+        display_name: [c_char; ADL_MAX_PATH],
+        present: i32,
+        exist: i32,
+        driver_path: [c_char; ADL_MAX_PATH],
+        driver_path_ext: [c_char; ADL_MAX_PATH],
+        /// 如 "PCI_VEN_1002&DEV_73BF&SUBSYS_..."
+        pnp_string: [c_char; ADL_MAX_PATH],
+        os_display_index: i32,
+    }
+
+    /// ADLSingleSensorData
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct SensorData {
+        supported: i32,
+        value: i32,
+    }
+
+    /// ADLPMLogDataOutput
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct PmLogDataOutput {
+        size: i32,
+        sensors: [SensorData; ADL_PMLOG_MAX_SENSORS],
+    }
+
+    /// ADLBiosInfo
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct BiosInfo {
+        part_number: [c_char; ADL_MAX_PATH],
+        version: [c_char; ADL_MAX_PATH],
+        date: [c_char; ADL_MAX_PATH],
+    }
+
+    type MallocCallback = unsafe extern "system" fn(i32) -> *mut c_void;
+    type ControlCreateFn = unsafe extern "system" fn(MallocCallback, i32, *mut Context) -> i32;
+    type ControlDestroyFn = unsafe extern "system" fn(Context) -> i32;
+    type NumberOfAdaptersFn = unsafe extern "system" fn(Context, *mut i32) -> i32;
+    type AdapterInfoFn = unsafe extern "system" fn(Context, *mut AdapterInfo, i32) -> i32;
+    type PmLogDataFn = unsafe extern "system" fn(Context, i32, *mut PmLogDataOutput) -> i32;
+    type BiosInfoFn = unsafe extern "system" fn(Context, i32, *mut BiosInfo) -> i32;
+
+    /// ADL 内部分配内存的回调，本模块调用的接口不返回 ADL 分配的内存
+    unsafe extern "system" fn adl_malloc(size: i32) -> *mut c_void {
+        let layout = std::alloc::Layout::from_size_align(size.max(1) as usize, 8)
+            .expect("ADL 内存分配参数无效");
+        std::alloc::alloc_zeroed(layout) as *mut c_void
+    }
+
+    /// 读取指定设备 ID 的 AMD 显卡
+    pub fn read(device_id: u32) -> Option<RawReadings> {
+        let library = Library::open_system("atiadlxx.dll")?;
+        unsafe {
+            let create: ControlCreateFn = library.symbol("ADL2_Main_Control_Create")?;
+            let destroy: ControlDestroyFn = library.symbol("ADL2_Main_Control_Destroy")?;
+            let mut context: Context = std::ptr::null_mut();
+            if create(adl_malloc, 1, &mut context) != ADL_OK {
+                log::debug!("ADL2_Main_Control_Create 失败");
+                return None;
+            }
+            let _destroy = scopeguard::guard(context, |context| {
+                destroy(context);
+            });
+
+            let adapter_index = find_adapter(&library, context, device_id)?;
+            let mut readings = RawReadings {
+                vbios_version: read_vbios_version(&library, context, adapter_index),
+                ..Default::default()
+            };
+
+            if let Some(get_pm_log) = library.symbol::<PmLogDataFn>("ADL2_New_QueryPMLogData_Get") {
+                let mut output: PmLogDataOutput = std::mem::zeroed();
+                output.size = std::mem::size_of::<PmLogDataOutput>() as i32;
+                if get_pm_log(context, adapter_index, &mut output) == ADL_OK {
+                    let sensor = |index: usize| {
+                        let data = output.sensors[index];
+                        adl_sensor_value(data.supported, data.value)
+                    };
+                    readings.core_clock_mhz = sensor(PMLOG_CLK_GFXCLK);
+                    readings.memory_clock_mhz = sensor(PMLOG_CLK_MEMCLK);
+                    readings.fan_percent = sensor(PMLOG_FAN_PERCENTAGE);
+                    readings.power = sensor(PMLOG_ASIC_POWER);
+                }
+            }
+
+            Some(readings)
+        }
+    }
+
+    /// 按 PNP 字符串中的设备 ID 查找适配器序号
+    unsafe fn find_adapter(library: &Library, context: Context, device_id: u32) -> Option<i32> {
+        let get_count: NumberOfAdaptersFn = library.symbol("ADL2_Adapter_NumberOfAdapters_Get")?;
+        let get_info: AdapterInfoFn = library.symbol("ADL2_Adapter_AdapterInfo_Get")?;
+
+        let mut count = 0i32;
+        if get_count(context, &mut count) != ADL_OK || count <= 0 {
+            return None;
+        }
+        let mut adapters: Vec<AdapterInfo> = (0..count).map(|_| std::mem::zeroed()).collect();
+        let size = (std::mem::size_of::<AdapterInfo>() * adapters.len()) as i32;
+        if get_info(context, adapters.as_mut_ptr(), size) != ADL_OK {
+            return None;
+        }
+
+        adapters
+            .iter()
+            .find(|adapter| {
+                c_buffer_to_string(&adapter.pnp_string)
+                    .is_some_and(|pnp| adl_pnp_matches(&pnp, device_id))
+            })
+            .map(|adapter| adapter.adapter_index)
+    }
+
+    /// 读取 VBIOS 版本
+    unsafe fn read_vbios_version(
+        library: &Library,
+        context: Context,
+        adapter_index: i32,
+    ) -> Option<String> {
+        let get: BiosInfoFn = library.symbol("ADL2_Adapter_VideoBiosInfo_Get")?;
+        let mut info: BiosInfo = std::mem::zeroed();
+        if get(context, adapter_index, &mut info) != ADL_OK {
+            return None;
+        }
+        c_buffer_to_string(&info.version)
+    }
+}
@@ -0,0 +1,197 @@
+//! 虚拟化检测模块
+//!
+//! 通过 CPUID 的虚拟机监视器标志位与厂商字符串，以及 SMBIOS 制造商、型号中的特征字符串，
+//! 判断系统是否运行在虚拟机中以及虚拟机监视器的类型。
+
+use std::fmt;
+
+/// 虚拟机监视器
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum Hypervisor {
+    /// Microsoft Hyper-V
+    HyperV,
+    /// VMware
+    VMware,
+    /// Oracle VirtualBox
+    VirtualBox,
+    /// KVM
+    Kvm,
+    /// QEMU（纯软件模拟）
+    Qemu,
+    /// Xen
+    Xen,
+    /// Parallels
+    Parallels,
+    /// 其他虚拟机监视器，保存厂商字符串
+    Other(String),
+}
+
+/// 制造商与型号中的特征字符串（小写）及对应的虚拟机监视器
+const SYSTEM_STRING_HINTS: [(&str, Hypervisor); 8] = [
+    ("vmware", Hypervisor::VMware),
+    ("virtualbox", Hypervisor::VirtualBox),
+    ("innotek", Hypervisor::VirtualBox),
+    ("kvm", Hypervisor::Kvm),
+    ("qemu", Hypervisor::Qemu),
+    ("xen", Hypervisor::Xen),
+    ("parallels", Hypervisor::Parallels),
+    ("microsoft corporation virtual machine", Hypervisor::HyperV),
+];
+
+impl Hypervisor {
+    /// 由 CPUID 0x40000000 返回的厂商字符串识别
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::hypervisor::Hypervisor;
+    /// assert_eq!(Hypervisor::from_cpuid_vendor("VMwareVMware"), Hypervisor::VMware);
+    /// assert_eq!(Hypervisor::from_cpuid_vendor("KVMKVMKVM"), Hypervisor::Kvm);
+    /// assert_eq!(
+    ///     Hypervisor::from_cpuid_vendor("ACRNACRNACRN"),
+    ///     Hypervisor::Other("ACRNACRNACRN".to_string())
+    /// );
+    /// ```
+    pub fn from_cpuid_vendor(vendor: &str) -> Self {
+        match vendor.trim_end_matches('\0').trim() {
+            "Microsoft Hv" => Hypervisor::HyperV,
+            "VMwareVMware" => Hypervisor::VMware,
+            "VBoxVBoxVBox" => Hypervisor::VirtualBox,
+            "KVMKVMKVM" | "Linux KVM Hv" => Hypervisor::Kvm,
+            "TCGTCGTCGTCG" => Hypervisor::Qemu,
+            "XenVMMXenVMM" => Hypervisor::Xen,
+            "prl hyperv" | "lrpepyh  vr" => Hypervisor::Parallels,
+            other => Hypervisor::Other(other.to_string()),
+        }
+    }
+
+    /// 由系统制造商与型号识别，不含虚拟机特征时返回 None
+    ///
+    /// * `manufacturer` - 系统制造商
+    /// * `model` - 电脑型号
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::hypervisor::Hypervisor;
+    /// assert_eq!(
+    ///     Hypervisor::from_system_strings("innotek GmbH", "VirtualBox"),
+    ///     Some(Hypervisor::VirtualBox)
+    /// );
+    /// assert_eq!(
+    ///     Hypervisor::from_system_strings("Microsoft Corporation", "Virtual Machine"),
+    ///     Some(Hypervisor::HyperV)
+    /// );
+    /// assert_eq!(
+    ///     Hypervisor::from_system_strings("Microsoft Corporation", "Surface Pro 9"),
+    ///     None
+    /// );
+    /// ```
+    pub fn from_system_strings(manufacturer: &str, model: &str) -> Option<Self> {
+        let text = format!("{} {}", manufacturer.trim(), model.trim()).to_lowercase();
+        if text.contains("bochs") {
+            return Some(Hypervisor::Other("Bochs".to_string()));
+        }
+        SYSTEM_STRING_HINTS
+            .iter()
+            .find(|(keyword, _)| text.contains(keyword))
+            .map(|(_, hypervisor)| hypervisor.clone())
+    }
+}
+
+impl fmt::Display for Hypervisor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Hypervisor::HyperV => "Hyper-V",
+            Hypervisor::VMware => "VMware",
+            Hypervisor::VirtualBox => "VirtualBox",
+            Hypervisor::Kvm => "KVM",
+            Hypervisor::Qemu => "QEMU",
+            Hypervisor::Xen => "Xen",
+            Hypervisor::Parallels => "Parallels",
+            Hypervisor::Other(vendor) => vendor,
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 由 CPUID 0x40000000 的 EBX、ECX、EDX 拼出厂商字符串
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::hypervisor::cpuid_vendor;
+/// assert_eq!(cpuid_vendor(0x7263694D, 0x666F736F, 0x76482074), "Microsoft Hv");
+/// ```
+pub fn cpuid_vendor(ebx: u32, ecx: u32, edx: u32) -> String {
+    let bytes: Vec<u8> = [ebx, ecx, edx]
+        .iter()
+        .flat_map(|register| register.to_le_bytes())
+        .collect();
+    String::from_utf8_lossy(&bytes)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+/// 综合 CPUID 与制造商信息判断虚拟机监视器
+///
+/// 嵌套或开启了 Hyper-V 兼容接口的虚拟机（如 KVM、QEMU）会在 CPUID 中报告 "Microsoft Hv"，
+/// 此时以制造商信息为准
+///
+/// * `cpuid` - CPUID 报告的虚拟机监视器，未运行在虚拟机中时为 None
+/// * `manufacturer` - 系统制造商
+/// * `model` - 电脑型号
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::hypervisor::{resolve, Hypervisor};
+/// assert_eq!(
+///     resolve(Some(Hypervisor::HyperV), "QEMU", "Standard PC (Q35 + ICH9, 2009)"),
+///     Some(Hypervisor::Qemu)
+/// );
+/// assert_eq!(
+///     resolve(Some(Hypervisor::VMware), "Dell Inc.", "OptiPlex 7090"),
+///     Some(Hypervisor::VMware)
+/// );
+/// assert_eq!(resolve(None, "LENOVO", "ThinkPad X1"), None);
+/// ```
+pub fn resolve(cpuid: Option<Hypervisor>, manufacturer: &str, model: &str) -> Option<Hypervisor> {
+    Hypervisor::from_system_strings(manufacturer, model).or(cpuid)
+}
+
+/// 通过 CPUID 检测虚拟机监视器
+///
+/// 开启了基于虚拟化的安全（VBS）或 Hyper-V 的物理机，Windows 本身运行在 Hyper-V 的根分区中，
+/// 同样会设置虚拟机监视器标志位；根分区拥有创建分区的权限（CPUID 0x40000003 EBX 位 0），据此排除
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn detect_cpuid() -> Option<Hypervisor> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // 较新的工具链中 __cpuid 已是安全函数
+    #[allow(unused_unsafe)]
+    let (features, vendor_leaf) = unsafe { (__cpuid(1), __cpuid(0x4000_0000)) };
+    // CPUID 1 ECX 位 31：运行在虚拟机监视器之下
+    if features.ecx & (1 << 31) == 0 {
+        return None;
+    }
+    let hypervisor = Hypervisor::from_cpuid_vendor(&cpuid_vendor(
+        vendor_leaf.ebx,
+        vendor_leaf.ecx,
+        vendor_leaf.edx,
+    ));
+    if hypervisor == Hypervisor::HyperV && vendor_leaf.eax >= 0x4000_0003 {
+        #[allow(unused_unsafe)]
+        let privileges = unsafe { __cpuid(0x4000_0003) };
+        if privileges.ebx & 1 != 0 {
+            return None;
+        }
+    }
+    Some(hypervisor)
+}
+
+/// 非 x86 平台无法通过 CPUID 检测
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+pub fn detect_cpuid() -> Option<Hypervisor> {
+    None
+}
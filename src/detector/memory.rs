@@ -1,139 +1,578 @@
+#[cfg(windows)]
+use crate::detector::source::WindowsSource;
+use crate::detector::source::{WmiSource, CIMV2_NAMESPACE};
 use crate::detector::DetectionError;
-use crate::utils::{string, wmi};
-use std::collections::BTreeMap;
-use windows::Win32::System::Wmi::IWbemClassObject;
+use crate::utils::size::ByteSize;
+use crate::wmi_row;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// 内存信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryInfo {
     /// 内存条信息名称
     pub name: String,
-    /// 总内存大小 (B)
-    pub total_memory: f64,
+    /// 总内存大小
+    pub total_memory: ByteSize,
     /// 内存插槽信息
     pub slots: Vec<MemorySlot>,
+    /// 纠错方式（ECC）
+    pub error_correction: ErrorCorrection,
+    /// 通道模式，按已安装内存的插槽位置推断
+    pub channel_mode: ChannelMode,
+    /// 主板内存插槽总数，未知时为 None
+    pub slot_count: Option<u32>,
 }
 
 impl Default for MemoryInfo {
     fn default() -> Self {
         Self {
             name: "未获取到内存条信息".to_string(),
-            total_memory: 0.0,
+            total_memory: ByteSize::ZERO,
             slots: Vec::new(),
+            error_correction: ErrorCorrection::default(),
+            channel_mode: ChannelMode::default(),
+            slot_count: None,
         }
     }
 }
 
+impl MemoryInfo {
+    /// 空余的内存插槽数量，插槽总数未知或少于已安装的内存条数量时返回 None
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::memory::{MemoryInfo, MemorySlot};
+    /// let mut info = MemoryInfo {
+    ///     slots: vec![MemorySlot::default(), MemorySlot::default()],
+    ///     slot_count: Some(4),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(info.empty_slots(), Some(2));
+    /// info.slot_count = Some(1);
+    /// assert_eq!(info.empty_slots(), None);
+    /// ```
+    pub fn empty_slots(&self) -> Option<u32> {
+        self.slot_count?.checked_sub(self.slots.len() as u32)
+    }
+}
+
+/// 内存纠错方式，取自 Win32_PhysicalMemoryArray 的 MemoryErrorCorrection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ErrorCorrection {
+    /// 未知
+    #[default]
+    Unknown,
+    /// 无纠错
+    None,
+    /// 奇偶校验
+    Parity,
+    /// 单比特 ECC
+    SingleBitEcc,
+    /// 多比特 ECC
+    MultiBitEcc,
+    /// CRC 校验
+    Crc,
+}
+
+impl ErrorCorrection {
+    /// 由 MemoryErrorCorrection 的取值转换
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::memory::ErrorCorrection;
+    /// assert_eq!(ErrorCorrection::from_wmi(3), ErrorCorrection::None);
+    /// assert_eq!(ErrorCorrection::from_wmi(6), ErrorCorrection::MultiBitEcc);
+    /// assert_eq!(ErrorCorrection::from_wmi(2), ErrorCorrection::Unknown);
+    /// ```
+    pub fn from_wmi(value: u32) -> Self {
+        match value {
+            3 => ErrorCorrection::None,
+            4 => ErrorCorrection::Parity,
+            5 => ErrorCorrection::SingleBitEcc,
+            6 => ErrorCorrection::MultiBitEcc,
+            7 => ErrorCorrection::Crc,
+            _ => ErrorCorrection::Unknown,
+        }
+    }
+
+    /// 是否为 ECC 内存
+    pub fn is_ecc(&self) -> bool {
+        matches!(
+            self,
+            ErrorCorrection::SingleBitEcc | ErrorCorrection::MultiBitEcc | ErrorCorrection::Crc
+        )
+    }
+}
+
+impl std::fmt::Display for ErrorCorrection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ErrorCorrection::Unknown => "未知",
+            ErrorCorrection::None => "无",
+            ErrorCorrection::Parity => "奇偶校验",
+            ErrorCorrection::SingleBitEcc => "单比特 ECC",
+            ErrorCorrection::MultiBitEcc => "多比特 ECC",
+            ErrorCorrection::Crc => "CRC",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 内存通道模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChannelMode {
+    /// 未知（插槽位置无法识别）
+    #[default]
+    Unknown,
+    /// 单通道
+    Single,
+    /// 双通道
+    Dual,
+    /// 三通道
+    Triple,
+    /// 四通道
+    Quad,
+    /// 更多通道，如板载 LPDDR 内存
+    Multi(u32),
+}
+
+impl ChannelMode {
+    /// 由通道数量转换，0 表示未知
+    pub fn from_count(count: u32) -> Self {
+        match count {
+            0 => ChannelMode::Unknown,
+            1 => ChannelMode::Single,
+            2 => ChannelMode::Dual,
+            3 => ChannelMode::Triple,
+            4 => ChannelMode::Quad,
+            count => ChannelMode::Multi(count),
+        }
+    }
+
+    /// 通道数量，未知时为 None
+    pub fn count(&self) -> Option<u32> {
+        match self {
+            ChannelMode::Unknown => None,
+            ChannelMode::Single => Some(1),
+            ChannelMode::Dual => Some(2),
+            ChannelMode::Triple => Some(3),
+            ChannelMode::Quad => Some(4),
+            ChannelMode::Multi(count) => Some(*count),
+        }
+    }
+
+    /// 按已安装内存的插槽位置推断通道模式
+    ///
+    /// 只有一根内存时为单通道；多根内存时需每根都能识别出通道，否则为未知
+    ///
+    /// * `slots` - 已安装的内存
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::memory::{ChannelMode, MemorySlot};
+    /// let slot = |locator: &str| MemorySlot {
+    ///     device_locator: locator.to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     ChannelMode::infer(&[slot("DIMM_A2"), slot("DIMM_B2")]),
+    ///     ChannelMode::Dual
+    /// );
+    /// assert_eq!(
+    ///     ChannelMode::infer(&[slot("DIMM_A1"), slot("DIMM_A2")]),
+    ///     ChannelMode::Single
+    /// );
+    /// assert_eq!(ChannelMode::infer(&[slot("未知"), slot("未知")]), ChannelMode::Unknown);
+    /// ```
+    pub fn infer(slots: &[MemorySlot]) -> Self {
+        if slots.len() == 1 {
+            return ChannelMode::Single;
+        }
+        let channels: Option<BTreeSet<_>> = slots
+            .iter()
+            .map(|slot| {
+                parse_channel(&slot.device_locator).or_else(|| parse_channel(&slot.bank_label))
+            })
+            .collect();
+        channels.map_or(ChannelMode::Unknown, |channels| {
+            Self::from_count(channels.len() as u32)
+        })
+    }
+}
+
+impl std::fmt::Display for ChannelMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChannelMode::Unknown => write!(f, "未知"),
+            ChannelMode::Single => write!(f, "单通道"),
+            ChannelMode::Dual => write!(f, "双通道"),
+            ChannelMode::Triple => write!(f, "三通道"),
+            ChannelMode::Quad => write!(f, "四通道"),
+            ChannelMode::Multi(count) => write!(f, "{} 通道", count),
+        }
+    }
+}
+
+/// 从插槽位置中解析内存控制器编号与通道字母，无法识别时返回 None
+///
+/// 支持的写法如 "ChannelA-DIMM0"、"Controller1-ChannelA-DIMM0"、"P0 CHANNEL A"、
+/// "DIMM_A1"、"DDR5_B2"、"A1_DIMM0"
+///
+/// * `locator` - 插槽位置（DeviceLocator 或 BankLabel）
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::memory::parse_channel;
+/// assert_eq!(parse_channel("Controller1-ChannelA-DIMM0"), Some((1, 'A')));
+/// assert_eq!(parse_channel("P0 CHANNEL B"), Some((0, 'B')));
+/// assert_eq!(parse_channel("DIMM_B2"), Some((0, 'B')));
+/// assert_eq!(parse_channel("BANK 0"), None);
+/// ```
+pub fn parse_channel(locator: &str) -> Option<(u32, char)> {
+    let upper = locator.trim().to_uppercase();
+    let tokens: Vec<&str> = upper
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    // 控制器编号，如 "Controller1"
+    let controller = tokens
+        .iter()
+        .find_map(|token| token.strip_prefix("CONTROLLER")?.parse().ok())
+        .unwrap_or(0);
+
+    // "ChannelA" 或 "Channel A"
+    for (index, token) in tokens.iter().enumerate() {
+        if let Some(rest) = token.strip_prefix("CHANNEL") {
+            let name = if rest.is_empty() {
+                tokens.get(index + 1).copied()?
+            } else {
+                rest
+            };
+            return channel_letter(name).map(|letter| (controller, letter));
+        }
+    }
+
+    // "DIMM_A1"、"DDR4_A1"、"A1_DIMM0"、"DIMMA1" 等，通道字母后跟插槽序号
+    tokens.iter().find_map(|token| {
+        let name = ["DIMM", "DDR5", "DDR4", "DDR3"]
+            .iter()
+            .find_map(|prefix| token.strip_prefix(prefix))
+            .unwrap_or(token);
+        let mut chars = name.chars();
+        let letter = chars.next()?;
+        let index = chars.as_str();
+        (!index.is_empty() && index.chars().all(|c| c.is_ascii_digit()))
+            .then(|| channel_letter(&letter.to_string()))
+            .flatten()
+            .map(|letter| (controller, letter))
+    })
+}
+
+/// 单个通道字母（A-H）
+fn channel_letter(name: &str) -> Option<char> {
+    let mut chars = name.chars();
+    let letter = chars.next()?;
+    (chars.next().is_none() && ('A'..='H').contains(&letter)).then_some(letter)
+}
+
 /// 内存插槽信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemorySlot {
     /// 内存条名称
     pub name: String,
-    /// 容量 (B)
-    pub capacity: f64,
+    /// 容量
+    pub capacity: ByteSize,
     /// 制造商
     pub manufacturer: String,
     /// 类型 (DDR3, DDR4, DDR5 等)
     pub memory_type: String,
     /// 频率 (MHz)
     pub frequency: u64,
+    /// 标称最高频率 (MHz)，开启 XMP/EXPO 前部分主板报告 JEDEC 频率
+    pub rated_frequency: u64,
+    /// 当前配置电压 (mV)
+    pub configured_voltage: Option<u32>,
+    /// 最低工作电压 (mV)
+    pub min_voltage: Option<u32>,
+    /// 最高工作电压 (mV)
+    pub max_voltage: Option<u32>,
+    /// 超频配置（XMP/EXPO）状态
+    pub profile: MemoryProfile,
+    /// 内存组标签，如 "P0 CHANNEL A"、"BANK 0"
+    pub bank_label: String,
+    /// 插槽位置，如 "DIMM_A1"、"Controller0-ChannelA-DIMM0"
+    pub device_locator: String,
 }
 
 impl Default for MemorySlot {
     fn default() -> Self {
         Self {
             name: "未知".to_string(),
-            capacity: 0.0,
+            capacity: ByteSize::ZERO,
             manufacturer: "未知".to_string(),
             memory_type: "未知".to_string(),
             frequency: 0,
+            rated_frequency: 0,
+            configured_voltage: None,
+            min_voltage: None,
+            max_voltage: None,
+            profile: MemoryProfile::default(),
+            bank_label: "未知".to_string(),
+            device_locator: "未知".to_string(),
+        }
+    }
+}
+
+impl MemorySlot {
+    /// 超频配置与电压概要
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::memory::{MemoryProfile, MemorySlot};
+    /// let slot = MemorySlot {
+    ///     configured_voltage: Some(1350),
+    ///     profile: MemoryProfile::Enabled,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(slot.profile_summary(), "XMP/EXPO 已开启 (1.35 V)");
+    /// assert_eq!(MemorySlot::default().profile_summary(), "未知");
+    /// ```
+    pub fn profile_summary(&self) -> String {
+        match self.configured_voltage {
+            Some(voltage) => format!("{} ({})", self.profile, format_voltage(voltage)),
+            None => self.profile.to_string(),
         }
     }
 }
 
+/// 内存超频配置（XMP/EXPO）状态
+///
+/// SMBIOS 不区分 XMP 与 EXPO，按电压与频率是否超出 JEDEC 标准判断
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum MemoryProfile {
+    /// 未知（内存类型或电压未知）
+    #[default]
+    Unknown,
+    /// 运行在 JEDEC 标准配置，未发现超频配置
+    Jedec,
+    /// 支持 XMP/EXPO，但当前运行在 JEDEC 标准配置
+    Available,
+    /// 正在以 XMP/EXPO 配置运行
+    Enabled,
+}
+
+impl std::fmt::Display for MemoryProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            MemoryProfile::Unknown => "未知",
+            MemoryProfile::Jedec => "JEDEC 标准",
+            MemoryProfile::Available => "XMP/EXPO 未开启",
+            MemoryProfile::Enabled => "XMP/EXPO 已开启",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl MemoryProfile {
+    /// 根据 SMBIOS 报告的频率与电压判断超频配置状态
+    ///
+    /// 配置电压或当前频率超出 JEDEC 标准时视为已开启；最高电压或标称频率超出标准时视为支持但未开启
+    ///
+    /// * `slot` - 内存插槽，需已填写类型、频率与电压
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::memory::{MemoryProfile, MemorySlot};
+    /// let slot = MemorySlot {
+    ///     memory_type: "DDR5".to_string(),
+    ///     frequency: 4800,
+    ///     rated_frequency: 6000,
+    ///     configured_voltage: Some(1100),
+    ///     max_voltage: Some(1350),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(MemoryProfile::detect(&slot), MemoryProfile::Available);
+    /// ```
+    pub fn detect(slot: &MemorySlot) -> Self {
+        let Some((nominal_voltage, jedec_max_frequency)) = jedec_limits(&slot.memory_type) else {
+            return MemoryProfile::Unknown;
+        };
+        let above_nominal = |voltage: Option<u32>| voltage.is_some_and(|v| v > nominal_voltage);
+
+        if above_nominal(slot.configured_voltage) || slot.frequency > jedec_max_frequency {
+            MemoryProfile::Enabled
+        } else if above_nominal(slot.max_voltage) || slot.rated_frequency > jedec_max_frequency {
+            MemoryProfile::Available
+        } else if slot.configured_voltage.is_some() {
+            MemoryProfile::Jedec
+        } else {
+            MemoryProfile::Unknown
+        }
+    }
+}
+
+/// 内存类型的 JEDEC 标准电压 (mV) 与最高标准频率 (MHz)，不支持超频配置的类型返回 None
+fn jedec_limits(memory_type: &str) -> Option<(u32, u64)> {
+    match memory_type {
+        "DDR3" => Some((1500, 2133)),
+        "DDR4" => Some((1200, 3200)),
+        "DDR5" => Some((1100, 8800)),
+        _ => None,
+    }
+}
+
+/// 格式化电压，如 1350 mV 显示为 "1.35 V"
+///
+/// * `millivolts` - 电压 (mV)
+pub fn format_voltage(millivolts: u32) -> String {
+    format!("{:.2} V", millivolts as f64 / 1000.0)
+}
+
+wmi_row! {
+    /// Win32_PhysicalMemory 中用到的属性
+    struct PhysicalMemoryRow {
+        capacity: u64 = "Capacity",
+        manufacturer: String = "Manufacturer",
+        memory_type: u32 = "SMBIOSMemoryType",
+        configured_clock_speed: u32 = "ConfiguredClockSpeed",
+        speed: u32 = "Speed",
+        configured_voltage: u32 = "ConfiguredVoltage",
+        min_voltage: u32 = "MinVoltage",
+        max_voltage: u32 = "MaxVoltage",
+        bank_label: String = "BankLabel",
+        device_locator: String = "DeviceLocator",
+    }
+}
+
+wmi_row! {
+    /// Win32_PhysicalMemoryArray 中用到的属性
+    struct PhysicalMemoryArrayRow {
+        memory_error_correction: u32 = "MemoryErrorCorrection",
+        memory_devices: u32 = "MemoryDevices",
+        usage: u32 = "Use",
+    }
+}
+
+/// Win32_PhysicalMemoryArray 的 Use 取值：系统内存
+const SYSTEM_MEMORY_USE: u32 = 3;
+
 /// 检测内存信息
+#[cfg(windows)]
 pub fn detect_memory() -> Result<MemoryInfo, DetectionError> {
-    let mut info = MemoryInfo::default();
+    detect_memory_with(&WindowsSource)
+}
 
-    unsafe {
-        let config = wmi::WmiConfig::default();
-        let client =
-            wmi::WmiClient::connect(&config).map_err(|e| DetectionError::MemoryError(e))?;
+/// 从数据源查询 Win32_PhysicalMemory 并生成内存信息，容量为 0 的插槽会被忽略
+///
+/// * `source` - WMI 数据源
+pub fn detect_memory_with(source: &impl WmiSource) -> Result<MemoryInfo, DetectionError> {
+    let mut info = MemoryInfo::default();
 
-        let mut enumerator = client
-            .query("SELECT * FROM Win32_PhysicalMemory")
-            .map_err(|e| DetectionError::MemoryError(e))?;
+    let rows: Vec<PhysicalMemoryRow> = source
+        .wmi_query_as(CIMV2_NAMESPACE, "SELECT * FROM Win32_PhysicalMemory")
+        .map_err(|e| e.within(DetectionError::MemoryError))?;
 
-        while let Some(obj) = enumerator.next() {
-            if let Some(slot) = parse_memory_object(&obj) {
-                info.total_memory += slot.capacity;
-                info.slots.push(slot);
-            }
-        }
+    for slot in rows.into_iter().filter_map(parse_memory_row) {
+        info.total_memory += slot.capacity;
+        info.slots.push(slot);
     }
 
     info.name = generate_total_name(&info.slots);
+    info.channel_mode = ChannelMode::infer(&info.slots);
+
+    // 纠错方式与插槽数量只是补充信息，查询失败不影响内存检测
+    match source.wmi_query_as::<PhysicalMemoryArrayRow>(
+        CIMV2_NAMESPACE,
+        "SELECT * FROM Win32_PhysicalMemoryArray",
+    ) {
+        Ok(arrays) => {
+            let arrays = system_memory_arrays(&arrays);
+            info.error_correction = arrays
+                .first()
+                .and_then(|array| array.memory_error_correction)
+                .map_or(ErrorCorrection::Unknown, ErrorCorrection::from_wmi);
+            info.slot_count = arrays
+                .iter()
+                .map(|array| array.memory_devices)
+                .sum::<Option<u32>>()
+                .filter(|count| *count > 0);
+        }
+        Err(e) => log::warn!("查询内存阵列失败: {}", e),
+    }
 
     Ok(info)
 }
 
+/// 筛选系统内存阵列（多路服务器每个处理器各有一个），没有标明用途的阵列时取第一个
+fn system_memory_arrays(arrays: &[PhysicalMemoryArrayRow]) -> Vec<&PhysicalMemoryArrayRow> {
+    let system: Vec<_> = arrays
+        .iter()
+        .filter(|array| array.usage == Some(SYSTEM_MEMORY_USE))
+        .collect();
+    if system.is_empty() {
+        arrays.first().into_iter().collect()
+    } else {
+        system
+    }
+}
+
 /// 解析 WMI 内存对象
-unsafe fn parse_memory_object(obj: &IWbemClassObject) -> Option<MemorySlot> {
+fn parse_memory_row(row: PhysicalMemoryRow) -> Option<MemorySlot> {
     let mut slot = MemorySlot::default();
 
-    // 获取 Capacity 属性
-    if let Ok(var) = wmi::get_property(obj, "Capacity") {
-        if let Some(capacity) = wmi::variant_to_u64(&var) {
-            slot.capacity = capacity as f64;
-        }
+    if let Some(capacity) = row.capacity {
+        slot.capacity = ByteSize::from_bytes(capacity);
     }
 
-    // 获取 Manufacturer 属性
-    if let Ok(var) = wmi::get_property(obj, "Manufacturer") {
-        if let Some(manufacturer) = wmi::variant_to_string(&var) {
-            slot.manufacturer = if manufacturer.trim().is_empty() {
-                "未知".to_string()
-            } else {
-                manufacturer
-            };
+    if let Some(manufacturer) = row.manufacturer {
+        if !manufacturer.trim().is_empty() {
+            slot.manufacturer = manufacturer;
         }
     }
 
-    // 获取 SMBIOSMemoryType 属性
-    if let Ok(var) = wmi::get_property(obj, "SMBIOSMemoryType") {
-        if let Some(mem_type) = wmi::variant_to_u32(&var) {
-            slot.memory_type = parse_memory_type(mem_type);
-        }
+    if let Some(mem_type) = row.memory_type {
+        slot.memory_type = parse_memory_type(mem_type);
     }
 
-    // 获取 Speed 和 ConfiguredClockSpeed 属性
-    let speed = if let Ok(var) = wmi::get_property(obj, "ConfiguredClockSpeed") {
-        wmi::variant_to_u32(&var)
-    } else {
-        None
-    };
-
-    slot.frequency = match speed {
-        Some(s) if s > 0 => s as u64,
-        _ => {
-            // 如果 ConfiguredClockSpeed 不可用或为 0，尝试使用 Speed
-            if let Ok(var) = wmi::get_property(obj, "Speed") {
-                wmi::variant_to_u32(&var).unwrap_or(0) as u64
-            } else {
-                0
-            }
-        }
-    };
+    // 如果 ConfiguredClockSpeed 不可用或为 0，尝试使用 Speed
+    slot.frequency = row
+        .configured_clock_speed
+        .filter(|speed| *speed > 0)
+        .or(row.speed)
+        .unwrap_or(0) as u64;
+    slot.rated_frequency = row
+        .speed
+        .filter(|speed| *speed > 0)
+        .map_or(slot.frequency, u64::from);
+
+    // 电压为 0 表示 BIOS 未提供
+    slot.configured_voltage = row.configured_voltage.filter(|voltage| *voltage > 0);
+    slot.min_voltage = row.min_voltage.filter(|voltage| *voltage > 0);
+    slot.max_voltage = row.max_voltage.filter(|voltage| *voltage > 0);
+    slot.profile = MemoryProfile::detect(&slot);
 
-    let capacity = string::format_size(slot.capacity);
+    for (label, field) in [
+        (row.bank_label, &mut slot.bank_label),
+        (row.device_locator, &mut slot.device_locator),
+    ] {
+        if let Some(label) = label.filter(|label| !label.trim().is_empty()) {
+            *field = label.trim().to_string();
+        }
+    }
 
     // 生成名称
     slot.name = format!(
         "{} {} {} {}",
-        slot.manufacturer, slot.memory_type, slot.frequency, capacity
+        slot.manufacturer, slot.memory_type, slot.frequency, slot.capacity
     );
 
-    if slot.capacity > 0.0 {
+    if !slot.capacity.is_zero() {
         Some(slot)
     } else {
         None
@@ -181,7 +620,7 @@ fn parse_memory_type(mem_type: u32) -> String {
 /// 根据内存插槽生成总名称
 pub fn generate_total_name(slots: &[MemorySlot]) -> String {
     let mut name_counts = BTreeMap::new();
-    let mut total_capacity = 0.0;
+    let mut total_capacity = ByteSize::ZERO;
 
     // 单次遍历同时计数和求和容量
     for slot in slots {
@@ -197,5 +636,5 @@ pub fn generate_total_name(slots: &[MemorySlot]) -> String {
 
     let names_str = name_parts.join("  ");
 
-    format!("{} ({})", string::format_size(total_capacity), names_str)
+    format!("{} ({})", total_capacity, names_str)
 }
@@ -3,15 +3,32 @@
 //! 提供各种硬件设备的检测功能
 
 pub mod audio;
+pub mod backend;
 pub mod battery;
 pub mod cpu;
+pub mod devnode;
 pub mod disk;
+pub mod drivers;
+pub mod dynamic;
+#[cfg(windows)]
+pub mod events;
 pub mod gpu;
+pub mod hypervisor;
 pub mod memory;
 pub mod monitor;
 pub mod motherboard;
 pub mod network;
+pub mod optical;
+pub mod pci;
+pub mod peripherals;
+pub mod power;
+pub mod processes;
+pub mod security;
+pub mod sensors;
+pub mod source;
 pub mod system;
+pub mod timing;
+pub mod volume;
 
 pub use audio::AudioInfo;
 pub use battery::BatteryInfo;
@@ -22,16 +39,28 @@ pub use memory::MemoryInfo;
 pub use monitor::MonitorInfo;
 pub use motherboard::MotherboardInfo;
 pub use network::NetworkInfo;
-pub use system::{ComputerType, SystemInfo};
+pub use security::SecurityInfo;
+pub use system::{ActivationStatus, ComputerType, SystemInfo};
 
-use crate::impl_detect_method;
+use crate::constants::DEFAULT_DETECTION_THREADS;
+use crate::detect_output;
+use backend::HardwareBackend;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::Instant;
 use thiserror::Error;
+use timing::DetectionTiming;
+#[cfg(windows)]
+use windows::Win32::System::Threading::{
+    GetCurrentThread, SetThreadPriority, THREAD_MODE_BACKGROUND_BEGIN,
+};
 
-/// 进度回调函数类型
-pub type ProgressCallback = Box<dyn Fn(f32, &str) + Send>;
+/// 进度回调函数类型，每个检测事件调用一次
+pub type ProgressCallback = Box<dyn Fn(&DetectionEvent) + Send>;
 
 /// 检测任务总数
-pub const TOTAL_DETECTION_TASKS: usize = 10;
+pub const TOTAL_DETECTION_TASKS: usize = 11;
 
 /// 进度完成标记
 pub const PROGRESS_COMPLETE: f32 = 1.0;
@@ -39,6 +68,263 @@ pub const PROGRESS_COMPLETE: f32 = 1.0;
 /// 防抖时间（秒）
 pub const DEBOUNCE_DURATION_SECS: u64 = 1;
 
+/// 检测进度事件
+///
+/// 通过 [`HardwareDetector::set_progress_callback`] 接收，界面据此显示进度，
+/// 调用方也可以根据失败事件决定是否重试，无需解析进度文字
+#[derive(Debug, Clone)]
+pub enum DetectionEvent {
+    /// 某个类别开始检测（在工作线程取到任务时发出）
+    TaskStarted {
+        /// 检测类别
+        category: DetectionCategory,
+    },
+    /// 某个类别检测完成
+    TaskFinished {
+        /// 检测类别
+        category: DetectionCategory,
+        /// 已结束的类别数（含本类别）
+        done: usize,
+        /// 本次检测的类别总数
+        total: usize,
+    },
+    /// 某个类别检测失败，该类别保留上次的检测结果
+    TaskFailed {
+        /// 检测类别
+        category: DetectionCategory,
+        /// 失败原因
+        error: DetectionError,
+        /// 已结束的类别数（含本类别）
+        done: usize,
+        /// 本次检测的类别总数
+        total: usize,
+    },
+    /// 本次检测全部结束
+    AllDone {
+        /// 本次检测的类别总数
+        total: usize,
+        /// 失败的类别数
+        failed: usize,
+    },
+}
+
+impl DetectionEvent {
+    /// 本事件对应的整体进度 (0.0 - 1.0)，开始事件不改变进度，返回 None
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::{DetectionCategory, DetectionEvent};
+    /// let event = DetectionEvent::TaskFinished {
+    ///     category: DetectionCategory::Cpu,
+    ///     done: 1,
+    ///     total: 4,
+    /// };
+    /// assert_eq!(event.progress(), Some(0.25));
+    /// assert_eq!(DetectionEvent::AllDone { total: 0, failed: 0 }.progress(), Some(1.0));
+    /// ```
+    pub fn progress(&self) -> Option<f32> {
+        match self {
+            DetectionEvent::TaskStarted { .. } => None,
+            DetectionEvent::TaskFinished { done, total, .. }
+            | DetectionEvent::TaskFailed { done, total, .. } => {
+                Some(*done as f32 / (*total).max(1) as f32)
+            }
+            DetectionEvent::AllDone { .. } => Some(PROGRESS_COMPLETE),
+        }
+    }
+
+    /// 界面显示的进度文字
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::{DetectionCategory, DetectionEvent};
+    /// let event = DetectionEvent::TaskFinished {
+    ///     category: DetectionCategory::Cpu,
+    ///     done: 1,
+    ///     total: 4,
+    /// };
+    /// assert_eq!(event.message(), "(1/4) CPU信息√");
+    /// ```
+    pub fn message(&self) -> String {
+        match self {
+            DetectionEvent::TaskStarted { category } => {
+                format!("正在检测{}...", category.name())
+            }
+            DetectionEvent::TaskFinished {
+                category,
+                done,
+                total,
+            } => format!("({}/{}) {}√", done, total, category.name()),
+            DetectionEvent::TaskFailed {
+                category,
+                done,
+                total,
+                ..
+            } => format!("({}/{}) {}×", done, total, category.name()),
+            DetectionEvent::AllDone { total, failed: 0 } => {
+                format!("({}/{}) 本次检测完成√", total, total)
+            }
+            DetectionEvent::AllDone { total, failed } => {
+                format!("({}/{}) 本次检测完成，{} 项失败", total, total, failed)
+            }
+        }
+    }
+}
+
+/// 检测类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DetectionCategory {
+    /// 系统
+    System,
+    /// 处理器
+    Cpu,
+    /// 显卡
+    Gpu,
+    /// 内存
+    Memory,
+    /// 磁盘
+    Disk,
+    /// 主板
+    Motherboard,
+    /// 网络
+    Network,
+    /// 声卡
+    Audio,
+    /// 显示器
+    Monitor,
+    /// 电池
+    Battery,
+    /// 安全特性
+    Security,
+}
+
+impl DetectionCategory {
+    /// 所有检测类别（按检测顺序排列）
+    pub const ALL: [DetectionCategory; TOTAL_DETECTION_TASKS] = [
+        DetectionCategory::System,
+        DetectionCategory::Cpu,
+        DetectionCategory::Gpu,
+        DetectionCategory::Memory,
+        DetectionCategory::Disk,
+        DetectionCategory::Motherboard,
+        DetectionCategory::Network,
+        DetectionCategory::Audio,
+        DetectionCategory::Monitor,
+        DetectionCategory::Battery,
+        DetectionCategory::Security,
+    ];
+
+    /// 获取类别名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            DetectionCategory::System => "系统信息",
+            DetectionCategory::Cpu => "CPU信息",
+            DetectionCategory::Gpu => "显卡信息",
+            DetectionCategory::Memory => "内存信息",
+            DetectionCategory::Disk => "磁盘信息",
+            DetectionCategory::Motherboard => "主板信息",
+            DetectionCategory::Network => "网络信息",
+            DetectionCategory::Audio => "声卡信息",
+            DetectionCategory::Monitor => "显示器信息",
+            DetectionCategory::Battery => "电池信息",
+            DetectionCategory::Security => "安全特性",
+        }
+    }
+}
+
+impl std::fmt::Display for DetectionCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// 检测线程优先级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DetectionPriority {
+    /// 普通优先级，检测最快
+    #[default]
+    Normal,
+    /// 后台优先级，同时降低 CPU 与磁盘 IO 优先级，减少对前台程序的影响
+    Background,
+}
+
+impl DetectionPriority {
+    /// 获取优先级名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            DetectionPriority::Normal => "普通",
+            DetectionPriority::Background => "后台",
+        }
+    }
+
+    /// 将优先级应用到当前线程
+    #[cfg(windows)]
+    fn apply_to_current_thread(&self) {
+        if *self == DetectionPriority::Background {
+            if let Err(e) =
+                unsafe { SetThreadPriority(GetCurrentThread(), THREAD_MODE_BACKGROUND_BEGIN) }
+            {
+                log::warn!("设置检测线程优先级失败: {:?}", e);
+            }
+        }
+    }
+
+    /// 将优先级应用到当前线程（仅 Windows 支持，其他平台忽略）
+    #[cfg(not(windows))]
+    fn apply_to_current_thread(&self) {}
+}
+
+/// 单个类别的检测结果，由工作线程产生后写回检测器
+enum CategoryOutput {
+    System(SystemInfo),
+    Cpu(CpuInfo),
+    Gpu(GpuInfo),
+    Memory(MemoryInfo),
+    Disk(DiskInfo),
+    Motherboard(MotherboardInfo),
+    Network(NetworkInfo),
+    Audio(AudioInfo),
+    Monitor(MonitorInfo),
+    Battery(BatteryInfo),
+    Security(SecurityInfo),
+}
+
+impl CategoryOutput {
+    /// 使用指定后端执行单个类别的检测
+    fn detect(
+        category: DetectionCategory,
+        backend: &dyn HardwareBackend,
+    ) -> Result<Self, DetectionError> {
+        match category {
+            DetectionCategory::System => {
+                detect_output!(System, backend, detect_system, SystemError)
+            }
+            DetectionCategory::Cpu => detect_output!(Cpu, backend, detect_cpu, CpuError),
+            DetectionCategory::Gpu => detect_output!(Gpu, backend, detect_gpu, GpuError),
+            DetectionCategory::Memory => {
+                detect_output!(Memory, backend, detect_memory, MemoryError)
+            }
+            DetectionCategory::Disk => detect_output!(Disk, backend, detect_disk, DiskError),
+            DetectionCategory::Motherboard => {
+                detect_output!(Motherboard, backend, detect_motherboard, MotherboardError)
+            }
+            DetectionCategory::Network => {
+                detect_output!(Network, backend, detect_network, NetworkError)
+            }
+            DetectionCategory::Audio => detect_output!(Audio, backend, detect_audio, AudioError),
+            DetectionCategory::Monitor => {
+                detect_output!(Monitor, backend, detect_display, MonitorError)
+            }
+            DetectionCategory::Battery => {
+                detect_output!(Battery, backend, detect_battery, BatteryError)
+            }
+            DetectionCategory::Security => {
+                detect_output!(Security, backend, detect_security, SecurityError)
+            }
+        }
+    }
+}
+
 /// 硬件检测器
 pub struct HardwareDetector {
     /// 系统信息
@@ -61,8 +347,24 @@ pub struct HardwareDetector {
     pub monitor_info: MonitorInfo,
     /// 电池信息
     pub battery_info: BatteryInfo,
+    /// 安全特性信息
+    pub security_info: SecurityInfo,
+    /// 启用的检测类别
+    enabled_categories: Vec<DetectionCategory>,
+    /// 检测工作线程数
+    worker_threads: usize,
+    /// 检测线程优先级
+    priority: DetectionPriority,
     /// 进度回调函数
     progress_callback: Option<ProgressCallback>,
+    /// 检测后端
+    backend: Arc<dyn HardwareBackend>,
+    /// 最近一次检测失败的类别及原因
+    errors: Vec<(DetectionCategory, DetectionError)>,
+    /// 各类别最近一次检测的耗时
+    timings: Vec<DetectionTiming>,
+    /// 创建或 fork 以来检测过的类别，[`adopt_results`](Self::adopt_results) 只采用这些类别
+    detected: Vec<DetectionCategory>,
 }
 
 impl std::fmt::Debug for HardwareDetector {
@@ -78,6 +380,9 @@ impl std::fmt::Debug for HardwareDetector {
             .field("audio_info", &self.audio_info)
             .field("monitor_info", &self.monitor_info)
             .field("battery_info", &self.battery_info)
+            .field("security_info", &self.security_info)
+            .field("errors", &self.errors)
+            .field("timings", &self.timings)
             .finish()
     }
 }
@@ -95,7 +400,15 @@ impl Default for HardwareDetector {
             audio_info: AudioInfo::default(),
             monitor_info: MonitorInfo::default(),
             battery_info: BatteryInfo::default(),
+            security_info: SecurityInfo::default(),
+            enabled_categories: DetectionCategory::ALL.to_vec(),
+            worker_threads: DEFAULT_DETECTION_THREADS,
+            priority: DetectionPriority::default(),
             progress_callback: None,
+            backend: backend::platform(),
+            errors: Vec::new(),
+            timings: Vec::new(),
+            detected: Vec::new(),
         }
     }
 }
@@ -114,7 +427,15 @@ impl HardwareDetector {
             audio_info: AudioInfo::default(),
             monitor_info: MonitorInfo::default(),
             battery_info: BatteryInfo::default(),
+            security_info: SecurityInfo::default(),
+            enabled_categories: DetectionCategory::ALL.to_vec(),
+            worker_threads: DEFAULT_DETECTION_THREADS,
+            priority: DetectionPriority::default(),
             progress_callback: None,
+            backend: backend::platform(),
+            errors: Vec::new(),
+            timings: Vec::new(),
+            detected: Vec::new(),
         }
     }
 
@@ -123,103 +444,304 @@ impl HardwareDetector {
         self.progress_callback = Some(callback);
     }
 
-    /// 更新进度
-    fn update_progress(&self, progress: f32, message: &str) {
+    /// 设置检测后端，默认使用当前平台的后端
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::backend::UnsupportedBackend;
+    /// use hardware_master::detector::{DetectionCategory, DetectionError};
+    /// use hardware_master::HardwareDetector;
+    /// use std::sync::Arc;
+    ///
+    /// let mut detector = HardwareDetector::new();
+    /// detector.set_backend(Arc::new(UnsupportedBackend));
+    /// let result = detector.detect(&[DetectionCategory::Memory]);
+    /// assert!(matches!(result, Err(DetectionError::Unsupported(_))));
+    /// ```
+    pub fn set_backend(&mut self, backend: Arc<dyn HardwareBackend>) {
+        self.backend = backend;
+    }
+
+    /// 设置启用的检测类别，未启用的类别在检测时跳过
+    pub fn set_enabled_categories(&mut self, categories: &[DetectionCategory]) {
+        self.enabled_categories = categories.to_vec();
+    }
+
+    /// 获取启用的检测类别
+    pub fn enabled_categories(&self) -> &[DetectionCategory] {
+        &self.enabled_categories
+    }
+
+    /// 设置检测并发度
+    ///
+    /// * `worker_threads` - 工作线程数，至少为 1
+    /// * `priority` - 工作线程优先级
+    pub fn set_concurrency(&mut self, worker_threads: usize, priority: DetectionPriority) {
+        self.worker_threads = worker_threads.max(1);
+        self.priority = priority;
+    }
+
+    /// 复制检测配置与当前检测结果，得到一个独立的检测器（不含进度回调）
+    ///
+    /// 在复制出的检测器上执行检测，完成后再通过 [`adopt_results`](Self::adopt_results) 写回，
+    /// 检测期间原检测器不被锁定，界面可以继续显示旧结果。
+    pub fn fork(&self) -> Self {
+        Self {
+            system_info: self.system_info.clone(),
+            cpu_info: self.cpu_info.clone(),
+            memory_info: self.memory_info.clone(),
+            disk_info: self.disk_info.clone(),
+            gpu_info: self.gpu_info.clone(),
+            motherboard_info: self.motherboard_info.clone(),
+            network_info: self.network_info.clone(),
+            audio_info: self.audio_info.clone(),
+            monitor_info: self.monitor_info.clone(),
+            battery_info: self.battery_info.clone(),
+            security_info: self.security_info.clone(),
+            enabled_categories: self.enabled_categories.clone(),
+            worker_threads: self.worker_threads,
+            priority: self.priority,
+            progress_callback: None,
+            backend: Arc::clone(&self.backend),
+            errors: self.errors.clone(),
+            timings: self.timings.clone(),
+            detected: Vec::new(),
+        }
+    }
+
+    /// 采用另一个检测器检测过的类别的结果、失败原因与耗时，检测配置保持不变
+    ///
+    /// 另一个检测器未检测的类别保留本检测器的结果，检测期间对本检测器的更新（如设备插拔后的刷新）不会被覆盖
+    ///
+    /// * `other` - 通常是由 [`fork`](Self::fork) 复制并完成检测的检测器
+    pub fn adopt_results(&mut self, mut other: HardwareDetector) {
+        for category in std::mem::take(&mut other.detected) {
+            self.store(other.take_output(category));
+            self.errors.retain(|(failed, _)| *failed != category);
+            if let Some(error) = other.error(category) {
+                self.errors.push((category, error.clone()));
+            }
+            self.timings.retain(|timing| timing.category != category);
+            if let Some(timing) = other.timing(category) {
+                self.timings.push(timing.clone());
+            }
+            if !self.detected.contains(&category) {
+                self.detected.push(category);
+            }
+        }
+    }
+
+    /// 最近一次检测失败的类别及原因，按失败的先后排列
+    ///
+    /// 类别重新检测成功后对应条目被移除，失败类别保留上次成功的检测结果
+    pub fn errors(&self) -> &[(DetectionCategory, DetectionError)] {
+        &self.errors
+    }
+
+    /// 指定类别最近一次检测的失败原因，检测成功或尚未检测时为 None
+    pub fn error(&self, category: DetectionCategory) -> Option<&DetectionError> {
+        self.errors
+            .iter()
+            .find(|(failed, _)| *failed == category)
+            .map(|(_, error)| error)
+    }
+
+    /// 各类别最近一次检测的耗时，按检测完成的先后排列
+    ///
+    /// 类别重新检测后对应条目被替换，尚未检测的类别没有条目
+    pub fn timings(&self) -> &[DetectionTiming] {
+        &self.timings
+    }
+
+    /// 指定类别最近一次检测的耗时
+    pub fn timing(&self, category: DetectionCategory) -> Option<&DetectionTiming> {
+        self.timings
+            .iter()
+            .find(|timing| timing.category == category)
+    }
+
+    /// 发送检测事件
+    fn emit(&self, event: DetectionEvent) {
         if let Some(ref callback) = self.progress_callback {
-            callback(progress, message);
+            callback(&event);
         }
     }
 
-    /// 检测所有硬件信息
+    /// 检测所有已启用的硬件信息
     pub fn detect_all(&mut self) -> Result<(), DetectionError> {
-        let tasks: [(&str, fn(&mut Self) -> Result<(), DetectionError>); TOTAL_DETECTION_TASKS] = [
-            ("系统信息", Self::detect_system_info),
-            ("CPU信息", Self::detect_cpu_info),
-            ("显卡信息", Self::detect_gpu_info),
-            ("内存信息", Self::detect_memory_info),
-            ("磁盘信息", Self::detect_disk_info),
-            ("主板信息", Self::detect_motherboard_info),
-            ("网络信息", Self::detect_network_info),
-            ("声卡信息", Self::detect_audio_info),
-            ("显示器信息", Self::detect_monitor_info),
-            ("电池信息", Self::detect_battery_info),
-        ];
+        let categories = self.enabled_categories.clone();
+        self.detect(&categories)
+    }
+
+    /// 仅检测指定类别，其余类别保留上次的检测结果
+    ///
+    /// * `categories` - 要检测的类别，按 `DetectionCategory::ALL` 的顺序分配给工作线程，重复项只检测一次
+    ///
+    /// 某个类别检测失败时其余类别仍会继续检测，最后返回第一个错误；
+    /// 全部失败的类别及原因可通过 [`errors`](Self::errors) 获取
+    ///
+    /// 示例
+    /// ```ignore
+    /// detector.detect(&[DetectionCategory::Battery, DetectionCategory::Disk])?;
+    /// ```
+    pub fn detect(&mut self, categories: &[DetectionCategory]) -> Result<(), DetectionError> {
+        let tasks: Vec<DetectionCategory> = DetectionCategory::ALL
+            .iter()
+            .copied()
+            .filter(|category| categories.contains(category))
+            .collect();
 
         let total = tasks.len();
-        let mut prev_name = "开始检测";
-
-        for (index, task) in tasks.iter().enumerate() {
-            let (name, func) = task;
-            let progress = index as f32 / total as f32;
-            let message = format!("({}/{}) {}√ {}...", index, total, prev_name, name);
-            self.update_progress(progress, &message);
-            (func)(self)?;
-            prev_name = name;
-        }
-
-        let final_message = format!("({}/{}) 本次检测完成√", total, total);
-        self.update_progress(PROGRESS_COMPLETE, &final_message);
-
-        Ok(())
-    }
-
-    // 使用宏生成的检测方法
-    impl_detect_method!(
-        detect_system_info,
-        system_info,
-        system,
-        detect_system,
-        SystemError
-    );
-    impl_detect_method!(detect_cpu_info, cpu_info, cpu, detect_cpu, CpuError);
-    impl_detect_method!(detect_gpu_info, gpu_info, gpu, detect_gpu, GpuError);
-    impl_detect_method!(
-        detect_memory_info,
-        memory_info,
-        memory,
-        detect_memory,
-        MemoryError
-    );
-    impl_detect_method!(detect_disk_info, disk_info, disk, detect_disk, DiskError);
-    impl_detect_method!(
-        detect_motherboard_info,
-        motherboard_info,
-        motherboard,
-        detect_motherboard,
-        MotherboardError
-    );
-    impl_detect_method!(
-        detect_network_info,
-        network_info,
-        network,
-        detect_network,
-        NetworkError
-    );
-    impl_detect_method!(
-        detect_audio_info,
-        audio_info,
-        audio,
-        detect_audio,
-        AudioError
-    );
-    impl_detect_method!(
-        detect_monitor_info,
-        monitor_info,
-        monitor,
-        detect_display,
-        MonitorError
-    );
-    impl_detect_method!(
-        detect_battery_info,
-        battery_info,
-        battery,
-        detect_battery,
-        BatteryError
-    );
+        let workers = self.worker_threads.clamp(1, total.max(1));
+        let priority = self.priority;
+        let backend = Arc::clone(&self.backend);
+        let next_task = AtomicUsize::new(0);
+        self.errors
+            .retain(|(category, _)| !tasks.contains(category));
+        for category in &tasks {
+            if !self.detected.contains(category) {
+                self.detected.push(*category);
+            }
+        }
+
+        std::thread::scope(|scope| {
+            let (tx, rx) = mpsc::channel();
+            for _ in 0..workers {
+                let tx = tx.clone();
+                let tasks = &tasks;
+                let next_task = &next_task;
+                let backend = &*backend;
+                scope.spawn(move || {
+                    priority.apply_to_current_thread();
+                    while let Some(&category) = tasks.get(next_task.fetch_add(1, Ordering::Relaxed))
+                    {
+                        if tx.send((category, None)).is_err() {
+                            break;
+                        }
+                        let started = Instant::now();
+                        timing::start_recording();
+                        let result = CategoryOutput::detect(category, backend);
+                        let timing = DetectionTiming::new(
+                            category,
+                            started.elapsed(),
+                            result.is_ok(),
+                            timing::finish_recording(),
+                        );
+                        if tx.send((category, Some((result, timing)))).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(tx);
+
+            // 按完成顺序写回结果并发送事件，None 表示工作线程刚开始检测该类别
+            let mut done = 0;
+            let mut failed = 0;
+            for (category, message) in rx.iter() {
+                let Some((result, timing)) = message else {
+                    self.emit(DetectionEvent::TaskStarted { category });
+                    continue;
+                };
+                self.record_timing(timing);
+                let event = match result {
+                    Ok(output) => {
+                        self.store(output);
+                        done += 1;
+                        DetectionEvent::TaskFinished {
+                            category,
+                            done,
+                            total,
+                        }
+                    }
+                    Err(e) => {
+                        log::warn!("{}", e);
+                        self.errors.push((category, e.clone()));
+                        done += 1;
+                        failed += 1;
+                        DetectionEvent::TaskFailed {
+                            category,
+                            error: e,
+                            done,
+                            total,
+                        }
+                    }
+                };
+                self.emit(event);
+            }
+            self.emit(DetectionEvent::AllDone { total, failed });
+        });
+
+        // 保留的旧错误属于本次未检测的类别，只返回本次检测中的错误
+        match self
+            .errors
+            .iter()
+            .find(|(category, _)| tasks.contains(category))
+        {
+            Some((_, e)) => Err(e.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// 记录单个类别的耗时，替换该类别上次的记录
+    fn record_timing(&mut self, timing: DetectionTiming) {
+        log::debug!("{} 耗时 {} ms", timing.category.name(), timing.millis);
+        self.timings
+            .retain(|recorded| recorded.category != timing.category);
+        self.timings.push(timing);
+    }
+
+    /// 取出单个类别的检测结果，检测器中该类别恢复为默认值
+    fn take_output(&mut self, category: DetectionCategory) -> CategoryOutput {
+        match category {
+            DetectionCategory::System => {
+                CategoryOutput::System(std::mem::take(&mut self.system_info))
+            }
+            DetectionCategory::Cpu => CategoryOutput::Cpu(std::mem::take(&mut self.cpu_info)),
+            DetectionCategory::Gpu => CategoryOutput::Gpu(std::mem::take(&mut self.gpu_info)),
+            DetectionCategory::Memory => {
+                CategoryOutput::Memory(std::mem::take(&mut self.memory_info))
+            }
+            DetectionCategory::Disk => CategoryOutput::Disk(std::mem::take(&mut self.disk_info)),
+            DetectionCategory::Motherboard => {
+                CategoryOutput::Motherboard(std::mem::take(&mut self.motherboard_info))
+            }
+            DetectionCategory::Network => {
+                CategoryOutput::Network(std::mem::take(&mut self.network_info))
+            }
+            DetectionCategory::Audio => CategoryOutput::Audio(std::mem::take(&mut self.audio_info)),
+            DetectionCategory::Monitor => {
+                CategoryOutput::Monitor(std::mem::take(&mut self.monitor_info))
+            }
+            DetectionCategory::Battery => {
+                CategoryOutput::Battery(std::mem::take(&mut self.battery_info))
+            }
+            DetectionCategory::Security => {
+                CategoryOutput::Security(std::mem::take(&mut self.security_info))
+            }
+        }
+    }
+
+    /// 将单个类别的检测结果写回检测器
+    fn store(&mut self, output: CategoryOutput) {
+        match output {
+            CategoryOutput::System(info) => self.system_info = info,
+            CategoryOutput::Cpu(info) => self.cpu_info = info,
+            CategoryOutput::Gpu(info) => self.gpu_info = info,
+            CategoryOutput::Memory(info) => self.memory_info = info,
+            CategoryOutput::Disk(info) => self.disk_info = info,
+            CategoryOutput::Motherboard(info) => self.motherboard_info = info,
+            CategoryOutput::Network(info) => self.network_info = info,
+            CategoryOutput::Audio(info) => self.audio_info = info,
+            CategoryOutput::Monitor(info) => self.monitor_info = info,
+            CategoryOutput::Battery(info) => self.battery_info = info,
+            CategoryOutput::Security(info) => self.security_info = info,
+        }
+    }
 }
 
 /// 硬件检测错误类型
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum DetectionError {
     #[error("Windows API 检测失败: {0}")]
     WindowsApiError(String),
@@ -243,4 +765,37 @@ pub enum DetectionError {
     MonitorError(String),
     #[error("电池信息检测失败: {0}")]
     BatteryError(String),
+    #[error("安全特性检测失败: {0}")]
+    SecurityError(String),
+    #[error("检测超时: {0}")]
+    Timeout(String),
+    #[error("当前平台不支持: {0}")]
+    Unsupported(String),
+}
+
+impl DetectionError {
+    /// 将通用的 Windows API 错误归入指定检测类别，其余错误（如超时）保持不变
+    ///
+    /// * `category` - 检测类别对应的错误构造函数，如 `DetectionError::MemoryError`
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::DetectionError;
+    /// let error = DetectionError::WindowsApiError("连接失败".to_string());
+    /// assert!(matches!(
+    ///     error.within(DetectionError::MemoryError),
+    ///     DetectionError::MemoryError(_)
+    /// ));
+    /// let timeout = DetectionError::Timeout("WMI 查询".to_string());
+    /// assert!(matches!(
+    ///     timeout.within(DetectionError::MemoryError),
+    ///     DetectionError::Timeout(_)
+    /// ));
+    /// ```
+    pub fn within(self, category: fn(String) -> DetectionError) -> DetectionError {
+        match self {
+            DetectionError::WindowsApiError(message) => category(message),
+            other => other,
+        }
+    }
 }
@@ -1,11 +1,272 @@
+#[cfg(windows)]
 use crate::detector::DetectionError;
+use crate::iddb::panel::BacklightType;
+#[cfg(windows)]
+use crate::iddb::panel::{PanelDatabase, PANEL_DB};
+#[cfg(windows)]
 use crate::utils::math::diagonal_inches_from_cm;
-use crate::utils::string::u16_slice_to_string;
+#[cfg(windows)]
+use crate::utils::string::{u16_slice_to_string, wide_str};
+#[cfg(windows)]
 use crate::utils::wmi;
-use windows::Win32::System::Wmi::IWbemClassObject;
+#[cfg(windows)]
+use crate::wmi_row;
+#[cfg(windows)]
+use std::collections::HashMap;
+#[cfg(windows)]
+use windows::core::PCWSTR;
+#[cfg(windows)]
+use windows::Win32::Devices::Display::{
+    DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes, QueryDisplayConfig,
+    DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO, DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+    DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME, DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO,
+    DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_MODE_INFO_TYPE_SOURCE,
+    DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EMBEDDED, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INTERNAL,
+    DISPLAYCONFIG_OUTPUT_TECHNOLOGY_UDI_EMBEDDED, DISPLAYCONFIG_PATH_INFO,
+    DISPLAYCONFIG_SOURCE_DEVICE_NAME, DISPLAYCONFIG_TARGET_DEVICE_NAME,
+    DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY, QDC_ONLY_ACTIVE_PATHS,
+};
+#[cfg(windows)]
+use windows::Win32::Foundation::{ERROR_SUCCESS, POINT};
+#[cfg(windows)]
+use windows::Win32::Graphics::Gdi::{
+    EnumDisplaySettingsW, MonitorFromPoint, DEVMODEW, ENUM_CURRENT_SETTINGS,
+    ENUM_DISPLAY_SETTINGS_MODE, MONITOR_DEFAULTTONEAREST,
+};
+#[cfg(windows)]
+use windows::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
+
+/// 显示模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayMode {
+    /// 水平分辨率
+    pub width: u32,
+    /// 垂直分辨率
+    pub height: u32,
+    /// 刷新率 (Hz)
+    pub refresh_rate: u32,
+}
+
+impl std::fmt::Display for DisplayMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}x{} @ {}Hz",
+            self.width, self.height, self.refresh_rate
+        )
+    }
+}
+
+/// HDR（高级颜色）状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum HdrStatus {
+    /// 显示器不支持 HDR
+    Unsupported,
+    /// 支持但未开启
+    Off,
+    /// 已开启
+    On,
+}
+
+impl HdrStatus {
+    /// 由 DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO 的标志位解析
+    ///
+    /// 位 0 为 advancedColorSupported，位 1 为 advancedColorEnabled
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::monitor::HdrStatus;
+    /// assert_eq!(HdrStatus::from_advanced_color(0b00), HdrStatus::Unsupported);
+    /// assert_eq!(HdrStatus::from_advanced_color(0b01), HdrStatus::Off);
+    /// assert_eq!(HdrStatus::from_advanced_color(0b11), HdrStatus::On);
+    /// ```
+    pub fn from_advanced_color(flags: u32) -> Self {
+        if flags & 0b10 != 0 {
+            HdrStatus::On
+        } else if flags & 0b01 != 0 {
+            HdrStatus::Off
+        } else {
+            HdrStatus::Unsupported
+        }
+    }
+}
+
+impl std::fmt::Display for HdrStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let desc = match self {
+            HdrStatus::Unsupported => "不支持",
+            HdrStatus::Off => "未开启",
+            HdrStatus::On => "已开启",
+        };
+        write!(f, "{}", desc)
+    }
+}
+
+/// 正在使用的显示器及其当前模式
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActiveDisplay {
+    /// 显示器名称，内置面板为 "内置屏幕"
+    pub name: String,
+    /// 连接接口，如 "HDMI"
+    pub connection: String,
+    /// GDI 设备名，如 `\\.\DISPLAY1`
+    pub device_name: String,
+    /// 当前显示模式
+    pub mode: Option<DisplayMode>,
+    /// 精确刷新率 (Hz)，如 59.951
+    pub refresh_rate: Option<f64>,
+    /// 每个颜色通道的位数，如 8、10
+    pub bits_per_channel: Option<u32>,
+    /// HDR 状态，系统不支持查询时为 None
+    pub hdr: Option<HdrStatus>,
+    /// 在虚拟桌面中的左上角坐标，主显示器为 (0, 0)
+    pub position: Option<(i32, i32)>,
+    /// 是否为主显示器
+    pub primary: bool,
+    /// Windows 缩放比例 (%)，如 150
+    pub scale_percent: Option<u32>,
+}
+
+impl Default for ActiveDisplay {
+    fn default() -> Self {
+        Self {
+            name: "未知".to_string(),
+            connection: "未知".to_string(),
+            device_name: String::new(),
+            mode: None,
+            refresh_rate: None,
+            bits_per_channel: None,
+            hdr: None,
+            position: None,
+            primary: false,
+            scale_percent: None,
+        }
+    }
+}
+
+impl ActiveDisplay {
+    /// 当前模式描述，如 "3840x2160 @ 59.95Hz, 10 位色深, HDR 已开启"
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::monitor::{ActiveDisplay, DisplayMode, HdrStatus};
+    /// let display = ActiveDisplay {
+    ///     mode: Some(DisplayMode { width: 3840, height: 2160, refresh_rate: 59 }),
+    ///     refresh_rate: Some(59.951),
+    ///     bits_per_channel: Some(10),
+    ///     hdr: Some(HdrStatus::On),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(display.mode_desc(), "3840x2160 @ 59.95Hz, 10 位色深, HDR 已开启");
+    /// assert_eq!(ActiveDisplay::default().mode_desc(), "未知");
+    /// ```
+    pub fn mode_desc(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(mode) = self.mode {
+            match self.refresh_rate {
+                Some(rate) => parts.push(format!("{}x{} @ {:.2}Hz", mode.width, mode.height, rate)),
+                None => parts.push(mode.to_string()),
+            }
+        }
+        if let Some(bits) = self.bits_per_channel {
+            parts.push(format!("{} 位色深", bits));
+        }
+        if let Some(hdr) = self.hdr {
+            parts.push(format!("HDR {}", hdr));
+        }
+        if parts.is_empty() {
+            return "未知".to_string();
+        }
+        parts.join(", ")
+    }
+
+    /// 概要，如 "DELL U2720Q (DisplayPort): 3840x2160 @ 59.95Hz, 10 位色深, HDR 已开启"
+    pub fn summary(&self) -> String {
+        format!("{} ({}): {}", self.name, self.connection, self.mode_desc())
+    }
+
+    /// 在虚拟桌面中占据的矩形 (左, 上, 右, 下)，坐标或模式未知时返回 None
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::monitor::{ActiveDisplay, DisplayMode};
+    /// let display = ActiveDisplay {
+    ///     mode: Some(DisplayMode { width: 1920, height: 1080, refresh_rate: 60 }),
+    ///     position: Some((-1920, 0)),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(display.rect(), Some((-1920, 0, 0, 1080)));
+    /// assert_eq!(ActiveDisplay::default().rect(), None);
+    /// ```
+    pub fn rect(&self) -> Option<(i32, i32, i32, i32)> {
+        let (left, top) = self.position?;
+        let mode = self.mode?;
+        Some((
+            left,
+            top,
+            left + mode.width as i32,
+            top + mode.height as i32,
+        ))
+    }
+
+    /// 相对于另一显示器的方位（"左侧"、"右侧"、"上方"、"下方"），按两者中心点的偏移判断
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::monitor::{ActiveDisplay, DisplayMode};
+    /// let display = |x, y| ActiveDisplay {
+    ///     mode: Some(DisplayMode { width: 1920, height: 1080, refresh_rate: 60 }),
+    ///     position: Some((x, y)),
+    ///     ..Default::default()
+    /// };
+    /// let primary = display(0, 0);
+    /// assert_eq!(display(1920, 200).direction_from(&primary), Some("右侧"));
+    /// assert_eq!(display(-1920, 0).direction_from(&primary), Some("左侧"));
+    /// assert_eq!(display(0, -1080).direction_from(&primary), Some("上方"));
+    /// assert_eq!(ActiveDisplay::default().direction_from(&primary), None);
+    /// ```
+    pub fn direction_from(&self, other: &ActiveDisplay) -> Option<&'static str> {
+        let center = |display: &ActiveDisplay| {
+            display
+                .rect()
+                .map(|(left, top, right, bottom)| ((left + right) / 2, (top + bottom) / 2))
+        };
+        let (x, y) = center(self)?;
+        let (other_x, other_y) = center(other)?;
+        let (dx, dy) = (x - other_x, y - other_y);
+        Some(if dx.abs() >= dy.abs() {
+            if dx >= 0 {
+                "右侧"
+            } else {
+                "左侧"
+            }
+        } else if dy >= 0 {
+            "下方"
+        } else {
+            "上方"
+        })
+    }
+}
+
+/// 由显示器的有效 DPI 计算 Windows 缩放比例 (%)，96 DPI 为 100%
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::monitor::scale_from_dpi;
+/// assert_eq!(scale_from_dpi(96), 100);
+/// assert_eq!(scale_from_dpi(144), 150);
+/// assert_eq!(scale_from_dpi(120), 125);
+/// ```
+pub fn scale_from_dpi(dpi: u32) -> u32 {
+    (dpi * 100 + 48) / 96
+}
 
 /// 显示器信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct MonitorInfo {
     /// 显示器名称
     pub name: String,
@@ -15,6 +276,20 @@ pub struct MonitorInfo {
     pub size_desc: f64,
     /// 生成日期，某年第几周
     pub manufacture_date: String,
+    /// 面板标识（厂商代码 + 产品代码），如 "BOE0900"
+    pub panel_id: String,
+    /// 背光类型
+    pub backlight: BacklightType,
+    /// 背光类型是否为推测值（面板数据库未收录）
+    pub backlight_guessed: bool,
+    /// 当前显示模式
+    pub current_mode: Option<DisplayMode>,
+    /// 当前精确刷新率 (Hz)，如 165.004
+    pub current_refresh_rate: Option<f64>,
+    /// 面板支持的全部显示模式
+    pub modes: Vec<DisplayMode>,
+    /// 所有正在使用的显示器
+    pub displays: Vec<ActiveDisplay>,
 }
 
 impl Default for MonitorInfo {
@@ -24,104 +299,496 @@ impl Default for MonitorInfo {
             manufacturer: "未知".to_string(),
             size_desc: 0.0,
             manufacture_date: "0年第0周".to_string(),
+            panel_id: "未知".to_string(),
+            backlight: BacklightType::Unknown,
+            backlight_guessed: true,
+            current_mode: None,
+            current_refresh_rate: None,
+            modes: Vec::new(),
+            displays: Vec::new(),
+        }
+    }
+}
+
+impl MonitorInfo {
+    /// 背光描述，如 "WLED" 或 "CCFL（推测）"
+    pub fn backlight_desc(&self) -> String {
+        let mut desc = self.backlight.to_string();
+        if self.backlight_guessed && self.backlight != BacklightType::Unknown {
+            desc.push_str("（推测）");
+        }
+        desc
+    }
+
+    /// 主显示器
+    pub fn primary_display(&self) -> Option<&ActiveDisplay> {
+        self.displays.iter().find(|display| display.primary)
+    }
+
+    /// 所有显示器组成的虚拟桌面范围 (左, 上, 右, 下)，可用于绘制排列示意图
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::monitor::{ActiveDisplay, DisplayMode, MonitorInfo};
+    /// let display = |width, height, x, y| ActiveDisplay {
+    ///     mode: Some(DisplayMode { width, height, refresh_rate: 60 }),
+    ///     position: Some((x, y)),
+    ///     ..Default::default()
+    /// };
+    /// let info = MonitorInfo {
+    ///     displays: vec![display(2560, 1440, 0, 0), display(1920, 1080, -1920, 360)],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(info.desktop_bounds(), Some((-1920, 0, 2560, 1440)));
+    /// assert_eq!(MonitorInfo::default().desktop_bounds(), None);
+    /// ```
+    pub fn desktop_bounds(&self) -> Option<(i32, i32, i32, i32)> {
+        self.displays
+            .iter()
+            .filter_map(ActiveDisplay::rect)
+            .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))
+    }
+
+    /// 显示器的排列与缩放描述，如 "主显示器, 缩放 150%" 或 "主显示器左侧 (-1920, 360), 缩放 100%"
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::monitor::{ActiveDisplay, DisplayMode, MonitorInfo};
+    /// let display = |x, y, primary| ActiveDisplay {
+    ///     mode: Some(DisplayMode { width: 1920, height: 1080, refresh_rate: 60 }),
+    ///     position: Some((x, y)),
+    ///     primary,
+    ///     scale_percent: Some(100),
+    ///     ..Default::default()
+    /// };
+    /// let info = MonitorInfo {
+    ///     displays: vec![display(0, 0, true), display(-1920, 360, false)],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(info.layout_desc(&info.displays[0]), "主显示器, 缩放 100%");
+    /// assert_eq!(
+    ///     info.layout_desc(&info.displays[1]),
+    ///     "主显示器左侧 (-1920, 360), 缩放 100%"
+    /// );
+    /// ```
+    pub fn layout_desc(&self, display: &ActiveDisplay) -> String {
+        let mut parts = Vec::new();
+        if display.primary {
+            parts.push("主显示器".to_string());
+        } else if let Some((x, y)) = display.position {
+            let direction = self
+                .primary_display()
+                .and_then(|primary| display.direction_from(primary));
+            parts.push(match direction {
+                Some(direction) => format!("主显示器{} ({}, {})", direction, x, y),
+                None => format!("位置 ({}, {})", x, y),
+            });
+        }
+        if let Some(scale) = display.scale_percent {
+            parts.push(format!("缩放 {}%", scale));
+        }
+        if parts.is_empty() {
+            return "未知".to_string();
+        }
+        parts.join(", ")
+    }
+
+    /// 最高分辨率下支持的刷新率 (Hz)，从低到高排列
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::monitor::{DisplayMode, MonitorInfo};
+    /// let mode = |width, height, refresh_rate| DisplayMode { width, height, refresh_rate };
+    /// let info = MonitorInfo {
+    ///     modes: vec![mode(2560, 1600, 165), mode(1920, 1200, 165), mode(2560, 1600, 60)],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(info.refresh_rates(), vec![60, 165]);
+    /// ```
+    pub fn refresh_rates(&self) -> Vec<u32> {
+        let Some(native) = self
+            .modes
+            .iter()
+            .max_by_key(|mode| mode.width as u64 * mode.height as u64)
+        else {
+            return Vec::new();
+        };
+
+        let mut rates: Vec<u32> = self
+            .modes
+            .iter()
+            .filter(|mode| mode.width == native.width && mode.height == native.height)
+            // 刷新率为 0 或 1 表示使用硬件默认值
+            .filter(|mode| mode.refresh_rate > 1)
+            .map(|mode| mode.refresh_rate)
+            .collect();
+        rates.sort_unstable();
+        rates.dedup();
+        rates
+    }
+
+    /// 刷新率描述，如 "60/120/165Hz（当前 165.00Hz）"
+    pub fn refresh_desc(&self) -> String {
+        let rates = self.refresh_rates();
+        let mut desc = if rates.is_empty() {
+            "未知".to_string()
+        } else {
+            let rates: Vec<String> = rates.iter().map(|rate| rate.to_string()).collect();
+            format!("{}Hz", rates.join("/"))
+        };
+        let current = self
+            .current_refresh_rate
+            .or_else(|| self.current_mode.map(|mode| mode.refresh_rate as f64));
+        if let Some(current) = current {
+            desc.push_str(&format!("（当前 {:.2}Hz）", current));
         }
+        desc
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// WmiMonitorBasicDisplayParams 中用到的属性
+    struct DisplayParamsRow {
+        instance_name: String = "InstanceName",
+        width_cm: u8 = "MaxHorizontalImageSize",
+        height_cm: u8 = "MaxVerticalImageSize",
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// WmiMonitorID 中用到的属性
+    struct MonitorIdRow {
+        instance_name: String = "InstanceName",
+        manufacturer: Vec<u16> = "ManufacturerName",
+        product_code: Vec<u16> = "ProductCodeID",
+        week: u8 = "WeekOfManufacture",
+        year: u16 = "YearOfManufacture",
     }
 }
 
 /// 检测显示器信息
+#[cfg(windows)]
 pub fn detect_display() -> Result<MonitorInfo, DetectionError> {
     let mut info = MonitorInfo::default();
 
-    unsafe {
-        let config = wmi::WmiConfig {
-            namespace: "ROOT\\wmi".to_string(),
-        };
-        let client =
-            wmi::WmiClient::connect(&config).map_err(|e| DetectionError::MonitorError(e))?;
-
-        // 查询 WmiMonitorBasicDisplayParams 获取显示器尺寸
-        let mut display_params_map = std::collections::HashMap::new();
-        let mut display_params_enumerator = client
-            .query("SELECT * FROM WmiMonitorBasicDisplayParams")
-            .map_err(|e| DetectionError::MonitorError(e))?;
-
-        while let Some(obj) = display_params_enumerator.next() {
-            if let Some(instance_name) = get_instance_name(&obj) {
-                let (w_cm, h_cm) = get_display_params(&obj);
-                display_params_map.insert(instance_name, (w_cm, h_cm));
-            }
-        }
+    let config = wmi::WmiConfig {
+        namespace: "ROOT\\wmi".to_string(),
+        ..Default::default()
+    };
+    let client =
+        wmi::WmiClient::connect(&config).map_err(|e| e.within(DetectionError::MonitorError))?;
 
-        // 查询 WmiMonitorID 获取显示器基本信息
-        let mut monitor_id_enumerator = client
-            .query("SELECT * FROM WmiMonitorID")
-            .map_err(|e| DetectionError::MonitorError(e))?;
+    // 查询 WmiMonitorBasicDisplayParams 获取显示器尺寸
+    let display_params: Vec<DisplayParamsRow> = client
+        .query_as("SELECT * FROM WmiMonitorBasicDisplayParams")
+        .map_err(|e| e.within(DetectionError::MonitorError))?;
 
-        while let Some(obj) = monitor_id_enumerator.next() {
-            if let Some(monitor_info) = parse_monitor_object(&obj, &display_params_map) {
-                info = monitor_info;
-                break; // 只获取第一个显示器
-            }
+    let display_params_map: HashMap<String, (u8, u8)> = display_params
+        .into_iter()
+        .filter_map(|row| {
+            let size = (row.width_cm.unwrap_or(0), row.height_cm.unwrap_or(0));
+            Some((row.instance_name?, size))
+        })
+        .collect();
+
+    // 查询 WmiMonitorID 获取显示器基本信息
+    let monitor_ids: Vec<MonitorIdRow> = client
+        .query_as("SELECT * FROM WmiMonitorID")
+        .map_err(|e| e.within(DetectionError::MonitorError))?;
+
+    // 只获取第一个显示器
+    if let Some(monitor_info) = monitor_ids
+        .into_iter()
+        .find_map(|row| parse_monitor_row(row, &display_params_map))
+    {
+        info = monitor_info;
+    }
+
+    // 枚举内置面板支持的显示模式，以及每个活动显示器的当前模式
+    unsafe {
+        let targets = query_display_targets();
+        if let Some(target) = find_panel_target(&targets) {
+            let (current_mode, modes) = enum_display_modes(&target.gdi_name);
+            info.current_mode = current_mode;
+            info.current_refresh_rate = target.refresh_rate;
+            info.modes = modes;
         }
+        info.displays = targets
+            .iter()
+            .map(|target| ActiveDisplay {
+                name: target.display_name(),
+                connection: output_technology_name(target.technology.0).to_string(),
+                device_name: target.gdi_name.clone(),
+                mode: enum_display_modes(&target.gdi_name).0,
+                refresh_rate: target.refresh_rate,
+                bits_per_channel: target.bits_per_channel,
+                hdr: target.hdr,
+                position: target.position,
+                // 虚拟桌面以主显示器左上角为原点
+                primary: target.position == Some((0, 0)),
+                scale_percent: target.position.and_then(|(x, y)| monitor_scale(x, y)),
+            })
+            .collect();
     }
 
     Ok(info)
 }
 
-/// 获取实例名称
-unsafe fn get_instance_name(obj: &IWbemClassObject) -> Option<String> {
-    if let Ok(var) = wmi::get_property(obj, "InstanceName") {
-        wmi::variant_to_string(&var)
-    } else {
-        None
+/// 是否为笔记本内置面板的输出接口
+#[cfg(windows)]
+fn is_internal_output(technology: DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY) -> bool {
+    technology == DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INTERNAL
+        || technology == DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EMBEDDED
+        || technology == DISPLAYCONFIG_OUTPUT_TECHNOLOGY_UDI_EMBEDDED
+}
+
+/// 显示输出接口类型名称
+///
+/// * `technology` - DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY 的取值
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::monitor::output_technology_name;
+/// assert_eq!(output_technology_name(5), "HDMI");
+/// assert_eq!(output_technology_name(10), "DisplayPort");
+/// assert_eq!(output_technology_name(i32::MIN), "内置");
+/// assert_eq!(output_technology_name(-1), "其他");
+/// ```
+pub fn output_technology_name(technology: i32) -> &'static str {
+    match technology {
+        0 => "VGA",
+        1 => "S-Video",
+        2 => "复合视频",
+        3 => "分量视频",
+        4 => "DVI",
+        5 => "HDMI",
+        6 | 11 | 13 | i32::MIN => "内置",
+        8 => "D 端子",
+        9 => "SDI",
+        10 => "DisplayPort",
+        12 => "UDI",
+        14 => "电视转换器",
+        15 => "Miracast 无线",
+        16 => "间接有线",
+        17 => "虚拟显示",
+        18 => "USB-C DisplayPort",
+        _ => "其他",
     }
 }
 
-/// 获取显示器尺寸参数
-unsafe fn get_display_params(obj: &IWbemClassObject) -> (u8, u8) {
-    let w_cm = if let Ok(var) = wmi::get_property(obj, "MaxHorizontalImageSize") {
-        wmi::variant_to_u8(&var).unwrap_or(0)
-    } else {
-        0
+/// 活动显示路径的目标显示器
+#[cfg(windows)]
+#[derive(Debug, Clone)]
+pub(crate) struct DisplayTarget {
+    /// 源的 GDI 设备名，如 `\\.\DISPLAY1`
+    pub gdi_name: String,
+    /// 显示器友好名称，内置面板通常为空
+    pub monitor_name: String,
+    /// 输出接口类型
+    pub technology: DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY,
+    /// 精确刷新率 (Hz)
+    pub refresh_rate: Option<f64>,
+    /// 每个颜色通道的位数
+    pub bits_per_channel: Option<u32>,
+    /// HDR 状态
+    pub hdr: Option<HdrStatus>,
+    /// 源在虚拟桌面中的左上角坐标
+    pub position: Option<(i32, i32)>,
+}
+
+#[cfg(windows)]
+impl DisplayTarget {
+    /// 是否为笔记本内置面板
+    pub fn is_internal(&self) -> bool {
+        is_internal_output(self.technology)
+    }
+
+    /// 显示器名称，友好名称为空时按接口类型给出
+    pub fn display_name(&self) -> String {
+        if !self.monitor_name.is_empty() {
+            self.monitor_name.clone()
+        } else if self.is_internal() {
+            "内置屏幕".to_string()
+        } else {
+            "未知".to_string()
+        }
+    }
+}
+
+/// 枚举所有活动显示路径的目标显示器
+#[cfg(windows)]
+pub(crate) unsafe fn query_display_targets() -> Vec<DisplayTarget> {
+    let mut path_count = 0u32;
+    let mut mode_count = 0u32;
+    if GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut path_count, &mut mode_count)
+        != ERROR_SUCCESS
+    {
+        return Vec::new();
+    }
+
+    let mut paths = vec![DISPLAYCONFIG_PATH_INFO::default(); path_count as usize];
+    let mut modes = vec![DISPLAYCONFIG_MODE_INFO::default(); mode_count as usize];
+    let result = QueryDisplayConfig(
+        QDC_ONLY_ACTIVE_PATHS,
+        &mut path_count,
+        paths.as_mut_ptr(),
+        &mut mode_count,
+        modes.as_mut_ptr(),
+        None,
+    );
+    if result != ERROR_SUCCESS {
+        log::warn!("QueryDisplayConfig 失败: {:?}", result);
+        return Vec::new();
+    }
+    paths.truncate(path_count as usize);
+
+    paths
+        .iter()
+        .filter_map(|path| {
+            let mut source_name = DISPLAYCONFIG_SOURCE_DEVICE_NAME::default();
+            source_name.header.r#type = DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME;
+            source_name.header.size =
+                std::mem::size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>() as u32;
+            source_name.header.adapterId = path.sourceInfo.adapterId;
+            source_name.header.id = path.sourceInfo.id;
+            if DisplayConfigGetDeviceInfo(&mut source_name.header) != 0 {
+                return None;
+            }
+
+            let mut target_name = DISPLAYCONFIG_TARGET_DEVICE_NAME::default();
+            target_name.header.r#type = DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME;
+            target_name.header.size =
+                std::mem::size_of::<DISPLAYCONFIG_TARGET_DEVICE_NAME>() as u32;
+            target_name.header.adapterId = path.targetInfo.adapterId;
+            target_name.header.id = path.targetInfo.id;
+            let monitor_name = if DisplayConfigGetDeviceInfo(&mut target_name.header) == 0 {
+                u16_slice_to_string(&target_name.monitorFriendlyDeviceName)
+            } else {
+                String::new()
+            };
+
+            // 高级颜色信息需要 Windows 10 1709 及以上
+            let mut color_info = DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO::default();
+            color_info.header.r#type = DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO;
+            color_info.header.size =
+                std::mem::size_of::<DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO>() as u32;
+            color_info.header.adapterId = path.targetInfo.adapterId;
+            color_info.header.id = path.targetInfo.id;
+            let (bits_per_channel, hdr) = if DisplayConfigGetDeviceInfo(&mut color_info.header) == 0
+            {
+                (
+                    (color_info.bitsPerColorChannel > 0).then_some(color_info.bitsPerColorChannel),
+                    Some(HdrStatus::from_advanced_color(color_info.Anonymous.value)),
+                )
+            } else {
+                (None, None)
+            };
+
+            let position = modes
+                .get(path.sourceInfo.Anonymous.modeInfoIdx as usize)
+                .filter(|mode| mode.infoType == DISPLAYCONFIG_MODE_INFO_TYPE_SOURCE)
+                .map(|mode| {
+                    let position = mode.Anonymous.sourceMode.position;
+                    (position.x, position.y)
+                });
+
+            let rate = path.targetInfo.refreshRate;
+            Some(DisplayTarget {
+                gdi_name: u16_slice_to_string(&source_name.viewGdiDeviceName),
+                monitor_name,
+                technology: path.targetInfo.outputTechnology,
+                refresh_rate: (rate.Denominator != 0)
+                    .then(|| rate.Numerator as f64 / rate.Denominator as f64),
+                bits_per_channel,
+                hdr,
+                position,
+            })
+        })
+        .collect()
+}
+
+/// 读取虚拟桌面中某点所在显示器的缩放比例
+///
+/// 进程未声明按显示器感知 DPI 时系统对所有显示器都返回 96 DPI，此时结果不准确
+#[cfg(windows)]
+unsafe fn monitor_scale(x: i32, y: i32) -> Option<u32> {
+    let monitor = MonitorFromPoint(POINT { x, y }, MONITOR_DEFAULTTONEAREST);
+    let (mut dpi_x, mut dpi_y) = (0u32, 0u32);
+    GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y).ok()?;
+    (dpi_x > 0).then(|| scale_from_dpi(dpi_x))
+}
+
+/// 查找内置面板对应的显示路径
+///
+/// 没有内置面板时（如台式机）使用第一个活动显示器
+#[cfg(windows)]
+fn find_panel_target(targets: &[DisplayTarget]) -> Option<&DisplayTarget> {
+    targets
+        .iter()
+        .find(|target| target.is_internal())
+        .or_else(|| targets.first())
+}
+
+/// 枚举显示设备的当前模式与全部支持的模式
+///
+/// * `device_name` - GDI 设备名，如 `\\.\DISPLAY1`
+#[cfg(windows)]
+unsafe fn enum_display_modes(device_name: &str) -> (Option<DisplayMode>, Vec<DisplayMode>) {
+    let name = wide_str(device_name);
+    let name = PCWSTR(name.as_ptr());
+    let to_mode = |devmode: &DEVMODEW| DisplayMode {
+        width: devmode.dmPelsWidth,
+        height: devmode.dmPelsHeight,
+        refresh_rate: devmode.dmDisplayFrequency,
     };
 
-    let h_cm = if let Ok(var) = wmi::get_property(obj, "MaxVerticalImageSize") {
-        wmi::variant_to_u8(&var).unwrap_or(0)
-    } else {
-        0
+    let mut devmode = DEVMODEW {
+        dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+        ..Default::default()
     };
+    let current = EnumDisplaySettingsW(name, ENUM_CURRENT_SETTINGS, &mut devmode)
+        .as_bool()
+        .then(|| to_mode(&devmode));
 
-    (w_cm, h_cm)
+    // 不同色深会产生重复的分辨率与刷新率组合
+    let mut modes = Vec::new();
+    let mut index = 0;
+    while EnumDisplaySettingsW(name, ENUM_DISPLAY_SETTINGS_MODE(index), &mut devmode).as_bool() {
+        let mode = to_mode(&devmode);
+        if !modes.contains(&mode) {
+            modes.push(mode);
+        }
+        index += 1;
+    }
+
+    (current, modes)
 }
 
 /// 解析显示器对象
-unsafe fn parse_monitor_object(
-    obj: &IWbemClassObject,
-    display_params_map: &std::collections::HashMap<String, (u8, u8)>,
+#[cfg(windows)]
+fn parse_monitor_row(
+    row: MonitorIdRow,
+    display_params_map: &HashMap<String, (u8, u8)>,
 ) -> Option<MonitorInfo> {
     let mut info = MonitorInfo::default();
 
     // 获取制造商名称
-    let manufacturer = if let Ok(var) = wmi::get_property(obj, "ManufacturerName") {
-        wmi::variant_to_u16_slice(&var)
-            .map(|v| u16_slice_to_string(&v))
-            .unwrap_or_else(|| "未知厂商".to_string())
-    } else {
-        "未知厂商".to_string()
-    };
+    let manufacturer = row
+        .manufacturer
+        .map(|v| u16_slice_to_string(&v))
+        .unwrap_or_else(|| "未知厂商".to_string());
     info.manufacturer = manufacturer.clone();
 
     // 获取产品代码
-    let product_code = if let Ok(var) = wmi::get_property(obj, "ProductCodeID") {
-        wmi::variant_to_u16_slice(&var)
-            .map(|v| u16_slice_to_string(&v))
-            .unwrap_or_else(|| "未知".to_string())
-    } else {
-        "未知".to_string()
-    };
+    let product_code = row
+        .product_code
+        .map(|v| u16_slice_to_string(&v))
+        .unwrap_or_else(|| "未知".to_string());
 
     // 获取实例名称用于查找尺寸信息
-    let instance_name = get_instance_name(obj)?;
+    let instance_name = row.instance_name?;
 
     // 查找对应的尺寸信息
     if let Some((w_cm, h_cm)) = display_params_map.get(&instance_name) {
@@ -136,17 +803,8 @@ unsafe fn parse_monitor_object(
     }
 
     // 获取制造日期
-    let week = if let Ok(var) = wmi::get_property(obj, "WeekOfManufacture") {
-        wmi::variant_to_u8(&var).unwrap_or(0)
-    } else {
-        0
-    };
-
-    let year = if let Ok(var) = wmi::get_property(obj, "YearOfManufacture") {
-        wmi::variant_to_u16(&var).unwrap_or(0)
-    } else {
-        0
-    };
+    let week = row.week.unwrap_or(0);
+    let year = row.year.unwrap_or(0);
 
     let manufacture_date = if week != 0 {
         format!("{}年第{}周", year, week)
@@ -155,6 +813,19 @@ unsafe fn parse_monitor_object(
     };
     info.manufacture_date = manufacture_date.to_string();
 
+    // 查询面板数据库获取背光信息，未收录时只推测早期面板的 CCFL 背光
+    info.panel_id = PanelDatabase::panel_id(&manufacturer, &product_code);
+    match PANEL_DB.lookup(&manufacturer, &product_code) {
+        Some(panel) => {
+            info.backlight = panel.backlight;
+            info.backlight_guessed = false;
+        }
+        None => {
+            info.backlight = BacklightType::guess_from_year(year);
+            info.backlight_guessed = true;
+        }
+    }
+
     // 生成名称
     info.name = format!(
         "{} {} ({:.1}英寸, {}产)",
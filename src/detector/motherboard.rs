@@ -1,9 +1,23 @@
+#[cfg(windows)]
+use crate::detector::security;
+use crate::detector::security::SecureBootState;
+#[cfg(windows)]
+use crate::detector::source::WindowsSource;
+use crate::detector::source::{DeviceClass, DeviceSource, WmiSource, CIMV2_NAMESPACE};
 use crate::detector::DetectionError;
-use crate::utils::wmi;
+use crate::iddb::chipset::CHIPSET_DB;
+use crate::utils::smbios::{self, SmbiosStructure};
 use crate::utils::wmi_date::parse_wmi_date;
+use crate::wmi_row;
+use std::fmt;
+#[cfg(windows)]
+use windows::Win32::System::SystemInformation::{
+    FirmwareTypeBios, FirmwareTypeUefi, GetFirmwareType, FIRMWARE_TYPE,
+};
 
 /// 主板信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct MotherboardInfo {
     /// 制造商
     pub manufacturer: String,
@@ -21,6 +35,54 @@ pub struct MotherboardInfo {
     pub bios_date: String,
     /// BIOS 序列号
     pub bios_serial: String,
+    /// SMBIOS 系统 UUID
+    pub system_uuid: String,
+    /// 主板资产标签
+    pub asset_tag: String,
+    /// 机箱序列号
+    pub chassis_serial: String,
+    /// 固件启动模式
+    pub firmware_type: FirmwareType,
+    /// 安全启动状态
+    pub secure_boot: SecureBootState,
+    /// SMBIOS 版本，如 "3.4"
+    pub smbios_version: String,
+    /// 系统设备类中的 PCI 设备（芯片组桥、SMBus 控制器等），用于识别芯片组，也可供 PCI 设备树复用
+    pub system_devices: Vec<SystemDevice>,
+}
+
+/// 系统设备类中的 PCI 设备
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemDevice {
+    /// 设备描述，如 "Intel(R) Z690 LPC/eSPI Controller - 7A84"
+    pub name: String,
+    /// 设备实例 ID，如 `PCI\VEN_8086&DEV_7A84&SUBSYS_7D251462&REV_11\3&11583659&0&F8`
+    pub instance_id: String,
+}
+
+/// 固件启动模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum FirmwareType {
+    /// 未知
+    #[default]
+    Unknown,
+    /// UEFI 启动
+    Uefi,
+    /// 传统 BIOS 启动（含 UEFI 的 CSM 兼容模式）
+    Legacy,
+}
+
+impl fmt::Display for FirmwareType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FirmwareType::Unknown => "未知",
+            FirmwareType::Uefi => "UEFI",
+            FirmwareType::Legacy => "Legacy BIOS",
+        };
+        write!(f, "{}", name)
+    }
 }
 
 impl Default for MotherboardInfo {
@@ -34,84 +96,183 @@ impl Default for MotherboardInfo {
             bios_version: "未知".to_string(),
             bios_date: "未知".to_string(),
             bios_serial: "未知".to_string(),
+            system_uuid: "未知".to_string(),
+            asset_tag: "未知".to_string(),
+            chassis_serial: "未知".to_string(),
+            firmware_type: FirmwareType::Unknown,
+            secure_boot: SecureBootState::Unsupported,
+            smbios_version: "未知".to_string(),
+            system_devices: Vec::new(),
         }
     }
 }
 
+wmi_row! {
+    /// Win32_BaseBoard 中用到的属性
+    struct BaseBoardRow {
+        manufacturer: String = "Manufacturer",
+        product: String = "Product",
+        serial_number: String = "SerialNumber",
+    }
+}
+
+wmi_row! {
+    /// Win32_BIOS 中用到的属性
+    struct BiosRow {
+        manufacturer: String = "Manufacturer",
+        version: String = "SMBIOSBIOSVersion",
+        release_date: String = "ReleaseDate",
+        serial_number: String = "SerialNumber",
+    }
+}
+
 /// 检测主板信息
+#[cfg(windows)]
 pub fn detect_motherboard() -> Result<MotherboardInfo, DetectionError> {
+    let mut info = detect_motherboard_with(&WindowsSource)?;
+
+    let table = smbios::read_table();
+    info.smbios_version = table.version;
+    fill_from_smbios(&mut info, &table.structures);
+
+    info.firmware_type = detect_firmware_type();
+    info.secure_boot = unsafe { security::detect_secure_boot() };
+
+    Ok(info)
+}
+
+/// 从数据源读取主板、BIOS 与芯片组信息
+///
+/// SMBIOS、固件启动模式与安全启动状态依赖其他系统接口，由 [`detect_motherboard`] 补充
+///
+/// * `source` - WMI 与设备枚举数据源
+pub fn detect_motherboard_with<S>(source: &S) -> Result<MotherboardInfo, DetectionError>
+where
+    S: WmiSource + DeviceSource,
+{
     let mut info = MotherboardInfo::default();
+    let unknown = || "未知".to_string();
 
-    unsafe {
-        let config = wmi::WmiConfig::default();
-        let client =
-            wmi::WmiClient::connect(&config).map_err(|e| DetectionError::MotherboardError(e))?;
+    // 获取主板信息
+    let baseboard: Vec<BaseBoardRow> = source
+        .wmi_query_as(CIMV2_NAMESPACE, "SELECT * FROM Win32_BaseBoard")
+        .map_err(|e| e.within(DetectionError::MotherboardError))?;
 
-        // 获取主板信息
-        let mut baseboard_enumerator = client
-            .query("SELECT * FROM Win32_BaseBoard")
-            .map_err(|e| DetectionError::MotherboardError(e))?;
+    if let Some(row) = baseboard.into_iter().next() {
+        info.manufacturer = row.manufacturer.unwrap_or_else(unknown);
+        info.product_name = row.product.unwrap_or_else(unknown);
+        info.serial_number = row.serial_number.unwrap_or_else(unknown);
+    }
 
-        if let Some(obj) = baseboard_enumerator.next() {
-            if let Ok(var) = wmi::get_property(&obj, "Manufacturer") {
-                info.manufacturer =
-                    wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
-            }
-            if let Ok(var) = wmi::get_property(&obj, "Product") {
-                info.product_name =
-                    wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
-            }
-            if let Ok(var) = wmi::get_property(&obj, "SerialNumber") {
-                info.serial_number =
-                    wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
-            }
+    // 获取 BIOS 信息
+    let bios: Vec<BiosRow> = source
+        .wmi_query_as(CIMV2_NAMESPACE, "SELECT * FROM Win32_BIOS")
+        .map_err(|e| e.within(DetectionError::MotherboardError))?;
+
+    if let Some(row) = bios.into_iter().next() {
+        info.bios_vendor = row.manufacturer.unwrap_or_else(unknown);
+        info.bios_version = row.version.unwrap_or_else(unknown);
+        info.bios_date = parse_wmi_date(&row.release_date.unwrap_or_else(unknown));
+        info.bios_serial = row.serial_number.unwrap_or_else(unknown);
+    }
+
+    // 获取芯片组信息：芯片组核心设备都在系统设备类中，只枚举该类，避免扫描全部 PnP 设备
+    match source.class_devices(DeviceClass::System) {
+        Ok(devices) => {
+            info.system_devices = devices
+                .into_iter()
+                .filter(|device| device.instance_id.starts_with("PCI\\"))
+                .map(|device| SystemDevice {
+                    name: device.description.unwrap_or_else(unknown),
+                    instance_id: device.instance_id,
+                })
+                .collect();
         }
+        Err(e) => log::warn!("枚举系统设备失败: {}", e),
+    }
+    if let Some(chipset) = identify_chipset(&info.system_devices) {
+        info.chipset = chipset;
+    }
 
-        // 获取 BIOS 信息
-        let mut bios_enumerator = client
-            .query("SELECT * FROM Win32_BIOS")
-            .map_err(|e| DetectionError::MotherboardError(e))?;
+    Ok(info)
+}
 
-        if let Some(obj) = bios_enumerator.next() {
-            if let Ok(var) = wmi::get_property(&obj, "Manufacturer") {
-                info.bios_vendor =
-                    wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
-            }
-            if let Ok(var) = wmi::get_property(&obj, "SMBIOSBIOSVersion") {
-                info.bios_version =
-                    wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
-            }
-            if let Ok(var) = wmi::get_property(&obj, "ReleaseDate") {
-                let date_str = wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
-                info.bios_date = parse_wmi_date(&date_str);
+/// 识别芯片组：按 ISA/LPC 桥等芯片组核心设备的 PCI ID 查表，查不到时按名称查找 LPC 控制器
+///
+/// * `devices` - 系统设备类中的 PCI 设备
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::motherboard::{identify_chipset, SystemDevice};
+/// let devices = [SystemDevice {
+///     name: "Intel(R) 100 Series Chipset Family LPC Controller/eSPI Controller - A143".to_string(),
+///     instance_id: "PCI\\VEN_8086&DEV_FFFF&SUBSYS_79941462&REV_31\\3&11583659&0&F8".to_string(),
+/// }];
+/// assert_eq!(
+///     identify_chipset(&devices).as_deref(),
+///     Some("Intel(R) 100 Series Chipset Family LPC Controller/eSPI Controller - A143")
+/// );
+/// assert_eq!(identify_chipset(&[]), None);
+/// ```
+pub fn identify_chipset(devices: &[SystemDevice]) -> Option<String> {
+    let instance_ids = devices.iter().map(|device| device.instance_id.as_str());
+    if let Some(chipset) = CHIPSET_DB.identify(instance_ids) {
+        return Some(chipset.name.clone());
+    }
+    devices
+        .iter()
+        .map(|device| &device.name)
+        .find(|name| {
+            name.contains("LPC") || name.contains("ISA Bridge") || name.contains("SMBus Controller")
+        })
+        .cloned()
+}
+
+/// 通过 GetFirmwareType 判断当前系统的启动模式
+#[cfg(windows)]
+fn detect_firmware_type() -> FirmwareType {
+    let mut firmware_type = FIRMWARE_TYPE::default();
+    if let Err(e) = unsafe { GetFirmwareType(&mut firmware_type) } {
+        log::warn!("GetFirmwareType 失败: {:?}", e);
+        return FirmwareType::Unknown;
+    }
+    match firmware_type {
+        FirmwareTypeUefi => FirmwareType::Uefi,
+        FirmwareTypeBios => FirmwareType::Legacy,
+        _ => FirmwareType::Unknown,
+    }
+}
+
+/// 从 SMBIOS 结构中补充系统 UUID、主板资产标签与机箱序列号
+///
+/// * `info` - 主板信息
+/// * `structures` - 解析后的 SMBIOS 结构
+pub fn fill_from_smbios(info: &mut MotherboardInfo, structures: &[SmbiosStructure]) {
+    for structure in structures {
+        match structure.kind {
+            // 系统信息：UUID 位于偏移 0x08，共 16 字节
+            1 => {
+                if let Some(uuid) = structure
+                    .formatted
+                    .get(0x08..0x18)
+                    .and_then(smbios::format_uuid)
+                {
+                    info.system_uuid = uuid;
+                }
             }
-            if let Ok(var) = wmi::get_property(&obj, "SerialNumber") {
-                info.bios_serial =
-                    wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
+            // 主板信息：资产标签位于偏移 0x08
+            2 => {
+                if let Some(tag) = structure.string(0x08) {
+                    info.asset_tag = tag.to_string();
+                }
             }
-        }
-
-        // 获取芯片组信息（查找LPC控制器等芯片组核心设备）
-        let mut pnp_enumerator = client
-            .query("SELECT * FROM Win32_PnPEntity")
-            .map_err(|e| DetectionError::MotherboardError(e))?;
-
-        while let Some(obj) = pnp_enumerator.next() {
-            if let Ok(var) = wmi::get_property(&obj, "Name") {
-                if let Some(name) = wmi::variant_to_string(&var) {
-                    // 查找LPC控制器（BIOS对应的芯片组设备）
-                    if name.contains("LPC Controller")
-                        || name.contains("LPC")
-                        || name.contains("ISA Bridge")
-                        || name.contains("SMBus Controller")
-                    {
-                        info.chipset = name;
-                        break;
-                    }
+            // 机箱信息：序列号位于偏移 0x07
+            3 => {
+                if let Some(serial) = structure.string(0x07) {
+                    info.chassis_serial = serial.to_string();
                 }
             }
+            _ => {}
         }
     }
-
-    Ok(info)
 }
@@ -1,88 +1,584 @@
+#[cfg(windows)]
 use crate::detector::DetectionError;
+#[cfg(windows)]
+use crate::utils::string::u16_slice_to_string;
+#[cfg(windows)]
 use crate::utils::wmi;
+#[cfg(windows)]
+use crate::wmi_row;
+#[cfg(windows)]
+use std::collections::HashMap;
+use std::fmt;
+#[cfg(windows)]
+use windows::Win32::Foundation::{ERROR_SUCCESS, HANDLE};
+#[cfg(windows)]
+use windows::Win32::NetworkManagement::WiFi::{
+    dot11_BSS_type_any, wlan_interface_state_connected, wlan_intf_opcode_channel_number,
+    wlan_intf_opcode_current_connection, WlanCloseHandle, WlanEnumInterfaces, WlanFreeMemory,
+    WlanGetInterfaceCapability, WlanGetNetworkBssList, WlanOpenHandle, WlanQueryInterface,
+    WLAN_BSS_LIST, WLAN_CONNECTION_ATTRIBUTES, WLAN_INTERFACE_CAPABILITY, WLAN_INTERFACE_INFO,
+    WLAN_INTERFACE_INFO_LIST,
+};
 
 /// 网络信息
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkInfo {
-    pub adapters: Vec<String>,
+    /// 物理网卡列表
+    pub adapters: Vec<NetworkAdapter>,
+    /// 无线网卡详细信息
+    pub wifi_adapters: Vec<WifiAdapter>,
+}
+
+/// 无线频段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum WifiBand {
+    /// 2.4 GHz
+    Band2_4G,
+    /// 5 GHz
+    Band5G,
+    /// 6 GHz
+    Band6G,
+}
+
+impl WifiBand {
+    /// 根据中心频率（kHz）判断频段
+    pub fn from_frequency_khz(frequency: u32) -> Option<Self> {
+        match frequency / 1000 {
+            2400..=2500 => Some(WifiBand::Band2_4G),
+            4900..=5900 => Some(WifiBand::Band5G),
+            5925..=7125 => Some(WifiBand::Band6G),
+            _ => None,
+        }
+    }
+
+    /// 根据信道号判断频段
+    ///
+    /// 6 GHz 信道号与 2.4/5 GHz 重叠，无法仅凭信道号区分，此时按 2.4/5 GHz 处理
+    pub fn from_channel(channel: u32) -> Option<Self> {
+        match channel {
+            1..=14 => Some(WifiBand::Band2_4G),
+            32..=177 => Some(WifiBand::Band5G),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for WifiBand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            WifiBand::Band2_4G => "2.4 GHz",
+            WifiBand::Band5G => "5 GHz",
+            WifiBand::Band6G => "6 GHz",
+        };
+        write!(f, "{}", name)
+    }
 }
 
-impl Default for NetworkInfo {
+/// 无线网卡
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct WifiAdapter {
+    /// 网卡名称
+    pub name: String,
+    /// 支持的无线标准，如 "802.11ax (Wi-Fi 6)"
+    pub standards: Vec<String>,
+    /// 是否已连接
+    pub connected: bool,
+    /// 当前连接的网络名称
+    pub ssid: Option<String>,
+    /// 当前连接使用的无线标准
+    pub current_standard: Option<String>,
+    /// 当前频段
+    pub band: Option<WifiBand>,
+    /// 当前信道
+    pub channel: Option<u32>,
+    /// 连接速率 (Mbps)
+    pub link_speed_mbps: Option<u32>,
+    /// 信号质量 (0 - 100)
+    pub signal_quality: Option<u32>,
+    /// 信号强度 (dBm)
+    pub rssi: Option<i32>,
+}
+
+impl Default for WifiAdapter {
     fn default() -> Self {
-        Self { adapters: vec![] }
+        Self {
+            name: "未知".to_string(),
+            standards: Vec::new(),
+            connected: false,
+            ssid: None,
+            current_standard: None,
+            band: None,
+            channel: None,
+            link_speed_mbps: None,
+            signal_quality: None,
+            rssi: None,
+        }
+    }
+}
+
+impl WifiAdapter {
+    /// 生成单行摘要，如 "Intel(R) Wi-Fi 6 AX201 (802.11ax (Wi-Fi 6), 已连接 5 GHz, 866 Mbps, 信号 90%)"
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(standard) = self.standards.last() {
+            parts.push(standard.clone());
+        }
+        if self.connected {
+            let mut connection = "已连接".to_string();
+            if let Some(band) = self.band {
+                connection.push_str(&format!(" {}", band));
+            }
+            parts.push(connection);
+            if let Some(speed) = self.link_speed_mbps {
+                parts.push(format!("{} Mbps", speed));
+            }
+            if let Some(quality) = self.signal_quality {
+                parts.push(format!("信号 {}%", quality));
+            }
+        } else {
+            parts.push("未连接".to_string());
+        }
+        format!("{} ({})", self.name, parts.join(", "))
+    }
+}
+
+/// 将 DOT11_PHY_TYPE 转换为无线标准名称
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::network::phy_type_name;
+/// assert_eq!(phy_type_name(10), Some("802.11ax (Wi-Fi 6)"));
+/// ```
+pub fn phy_type_name(phy_type: i32) -> Option<&'static str> {
+    match phy_type {
+        4 => Some("802.11a"),
+        5 => Some("802.11b"),
+        6 => Some("802.11g"),
+        7 => Some("802.11n (Wi-Fi 4)"),
+        8 => Some("802.11ac (Wi-Fi 5)"),
+        9 => Some("802.11ad"),
+        10 => Some("802.11ax (Wi-Fi 6)"),
+        11 => Some("802.11be (Wi-Fi 7)"),
+        _ => None,
+    }
+}
+
+/// 网卡类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum AdapterType {
+    /// 有线网卡
+    Ethernet,
+    /// 无线网卡
+    Wifi,
+    /// 其他
+    Other,
+}
+
+impl fmt::Display for AdapterType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AdapterType::Ethernet => "有线",
+            AdapterType::Wifi => "无线",
+            AdapterType::Other => "其他",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 网卡连接状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectionStatus {
+    /// 已连接
+    Connected,
+    /// 正在连接
+    Connecting,
+    /// 未连接
+    Disconnected,
+    /// 网线未插入
+    MediaDisconnected,
+    /// 未知
+    Unknown,
+}
+
+impl From<u16> for ConnectionStatus {
+    /// 将 Win32_NetworkAdapter.NetConnectionStatus 转换为连接状态
+    fn from(value: u16) -> Self {
+        match value {
+            0 => ConnectionStatus::Disconnected,
+            1 => ConnectionStatus::Connecting,
+            2 => ConnectionStatus::Connected,
+            7 => ConnectionStatus::MediaDisconnected,
+            _ => ConnectionStatus::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for ConnectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ConnectionStatus::Connected => "已连接",
+            ConnectionStatus::Connecting => "正在连接",
+            ConnectionStatus::Disconnected => "未连接",
+            ConnectionStatus::MediaDisconnected => "网线未插入",
+            ConnectionStatus::Unknown => "未知",
+        };
+        write!(f, "{}", name)
     }
 }
 
 /// 网络适配器
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkAdapter {
+    /// 网卡名称
     pub name: String,
+    /// MAC 地址
+    pub mac_address: String,
+    /// 连接速率 (bps)，未连接时为 None
+    pub speed: Option<u64>,
+    /// 网卡类型
+    pub adapter_type: AdapterType,
+    /// PnP 设备 ID
+    pub pnp_device_id: String,
+    /// 驱动版本
+    pub driver_version: String,
+    /// 连接状态
+    pub status: ConnectionStatus,
 }
 
 impl Default for NetworkAdapter {
     fn default() -> Self {
         Self {
             name: "未知".to_string(),
+            mac_address: "未知".to_string(),
+            speed: None,
+            adapter_type: AdapterType::Other,
+            pnp_device_id: "未知".to_string(),
+            driver_version: "未知".to_string(),
+            status: ConnectionStatus::Unknown,
         }
     }
 }
 
+impl NetworkAdapter {
+    /// 生成单行摘要，如 "Realtek PCIe GbE Family Controller (有线, 已连接 1 Gbps)"
+    pub fn summary(&self) -> String {
+        let mut state = self.status.to_string();
+        if let Some(speed) = self.speed {
+            state.push_str(&format!(" {}", format_link_speed(speed)));
+        }
+        format!("{} ({}, {})", self.name, self.adapter_type, state)
+    }
+}
+
+/// 格式化连接速率
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::network::format_link_speed;
+/// assert_eq!(format_link_speed(1_000_000_000), "1 Gbps");
+/// assert_eq!(format_link_speed(2_500_000_000), "2.5 Gbps");
+/// assert_eq!(format_link_speed(100_000_000), "100 Mbps");
+/// ```
+pub fn format_link_speed(bps: u64) -> String {
+    if bps >= 1_000_000_000 {
+        let gbps = bps as f64 / 1e9;
+        format!("{} Gbps", (gbps * 10.0).round() / 10.0)
+    } else if bps >= 1_000_000 {
+        format!("{} Mbps", bps / 1_000_000)
+    } else {
+        format!("{} Kbps", bps / 1000)
+    }
+}
+
+/// 未连接时 WMI 返回的占位速率
+#[cfg(windows)]
+const UNKNOWN_SPEED: u64 = i64::MAX as u64;
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_NetworkAdapter 中用到的属性
+    struct NetworkAdapterRow {
+        physical_adapter: bool = "PhysicalAdapter",
+        pnp_device_id: String = "PNPDeviceID",
+        adapter_type_id: u16 = "AdapterTypeID",
+        name: String = "Name",
+        mac_address: String = "MACAddress",
+        speed: u64 = "Speed",
+        connection_status: u16 = "NetConnectionStatus",
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_PnPSignedDriver 中用到的属性
+    struct SignedDriverRow {
+        device_id: String = "DeviceID",
+        driver_version: String = "DriverVersion",
+    }
+}
+
 /// 检测网络信息
+#[cfg(windows)]
 pub fn detect_network() -> Result<NetworkInfo, DetectionError> {
     let mut info = NetworkInfo::default();
 
-    unsafe {
-        let config = wmi::WmiConfig::default();
-        let client =
-            wmi::WmiClient::connect(&config).map_err(|e| DetectionError::NetworkError(e))?;
-
-        let mut enumerator = client
-            .query("SELECT * FROM Win32_NetworkAdapter")
-            .map_err(|e| DetectionError::NetworkError(e))?;
-
-        while let Some(obj) = enumerator.next() {
-            // 获取物理适配器标志
-            let physical_adapter = if let Ok(var) = wmi::get_property(&obj, "PhysicalAdapter") {
-                wmi::variant_to_bool(&var).unwrap_or(false)
-            } else {
-                false
-            };
-
-            // 获取 PnP 设备 ID
-            let pnp_device_id = if let Ok(var) = wmi::get_property(&obj, "PNPDeviceID") {
-                wmi::variant_to_string(&var)
-            } else {
-                None
-            };
-
-            // 获取适配器类型 ID
-            let adapter_type_id = if let Ok(var) = wmi::get_property(&obj, "AdapterTypeID") {
-                wmi::variant_to_u16(&var)
-            } else {
-                None
-            };
-
-            // 获取适配器名称
-            let name = if let Ok(var) = wmi::get_property(&obj, "Name") {
-                wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string())
-            } else {
-                "未知".to_string()
-            };
-
-            // 只处理物理 PCI 适配器
-            let is_physical = physical_adapter
-                && pnp_device_id.as_deref().map(|p| p.starts_with("PCI")) == Some(true);
-
-            if !is_physical {
-                continue;
-            }
+    let config = wmi::WmiConfig::default();
+    let client =
+        wmi::WmiClient::connect(&config).map_err(|e| e.within(DetectionError::NetworkError))?;
 
-            match adapter_type_id {
-                Some(0) | Some(9) => info.adapters.push(name),
-                _ => {}
-            }
+    let driver_versions = query_driver_versions(&client);
+
+    let rows: Vec<NetworkAdapterRow> = client
+        .query_as("SELECT * FROM Win32_NetworkAdapter")
+        .map_err(|e| e.within(DetectionError::NetworkError))?;
+
+    for row in rows {
+        let name = row.name.unwrap_or_else(|| "未知".to_string());
+
+        // 只处理物理 PCI 适配器
+        let is_physical = row.physical_adapter.unwrap_or(false)
+            && row.pnp_device_id.as_deref().map(|p| p.starts_with("PCI")) == Some(true);
+
+        if !is_physical {
+            continue;
         }
+
+        let adapter_type = match row.adapter_type_id {
+            Some(9) => AdapterType::Wifi,
+            Some(0) if is_wireless_name(&name) => AdapterType::Wifi,
+            Some(0) => AdapterType::Ethernet,
+            _ => continue,
+        };
+
+        let pnp_device_id = row.pnp_device_id.unwrap_or_default();
+        let driver_version = driver_versions
+            .get(&pnp_device_id.to_uppercase())
+            .cloned()
+            .unwrap_or_else(|| "未知".to_string());
+
+        info.adapters.push(NetworkAdapter {
+            name,
+            mac_address: row.mac_address.unwrap_or_else(|| "未知".to_string()),
+            speed: row
+                .speed
+                .filter(|speed| *speed > 0 && *speed < UNKNOWN_SPEED),
+            adapter_type,
+            pnp_device_id,
+            driver_version,
+            status: row
+                .connection_status
+                .map(ConnectionStatus::from)
+                .unwrap_or(ConnectionStatus::Unknown),
+        });
     }
 
+    info.wifi_adapters = unsafe { detect_wifi_adapters() };
+
     Ok(info)
 }
+
+/// 根据名称判断是否为无线网卡
+///
+/// 部分无线网卡在 WMI 中的 AdapterTypeID 仍报告为以太网
+#[cfg(windows)]
+fn is_wireless_name(name: &str) -> bool {
+    let name = name.to_lowercase();
+    ["wi-fi", "wifi", "wireless", "wlan", "802.11"]
+        .iter()
+        .any(|keyword| name.contains(keyword))
+}
+
+/// 查询网卡驱动版本，键为大写的 PnP 设备 ID
+#[cfg(windows)]
+fn query_driver_versions(client: &wmi::WmiClient) -> HashMap<String, String> {
+    let rows: Vec<SignedDriverRow> = match client.query_as(
+        "SELECT DeviceID, DriverVersion FROM Win32_PnPSignedDriver WHERE DeviceClass = 'NET'",
+    ) {
+        Ok(rows) => rows,
+        Err(e) => {
+            log::warn!("查询网卡驱动失败: {}", e);
+            return HashMap::new();
+        }
+    };
+
+    rows.into_iter()
+        .filter_map(|row| Some((row.device_id?.to_uppercase(), row.driver_version?)))
+        .collect()
+}
+
+/// WLAN API 客户端版本（Windows Vista 及以上）
+#[cfg(windows)]
+const WLAN_CLIENT_VERSION: u32 = 2;
+
+/// 通过 WLAN API 检测无线网卡
+///
+/// 未安装无线网卡或 WLAN 服务未启动时返回空列表
+#[cfg(windows)]
+unsafe fn detect_wifi_adapters() -> Vec<WifiAdapter> {
+    let mut adapters = Vec::new();
+
+    let mut negotiated_version = 0u32;
+    let mut handle = HANDLE::default();
+    let result = WlanOpenHandle(
+        WLAN_CLIENT_VERSION,
+        None,
+        &mut negotiated_version,
+        &mut handle,
+    );
+    if result != ERROR_SUCCESS.0 {
+        log::info!("WlanOpenHandle 失败（可能没有无线网卡）: {}", result);
+        return adapters;
+    }
+    let _guard = scopeguard::guard(handle, |h| {
+        WlanCloseHandle(h, None);
+    });
+
+    let mut interface_list: *mut WLAN_INTERFACE_INFO_LIST = std::ptr::null_mut();
+    let result = WlanEnumInterfaces(handle, None, &mut interface_list);
+    if result != ERROR_SUCCESS.0 || interface_list.is_null() {
+        log::warn!("WlanEnumInterfaces 失败: {}", result);
+        return adapters;
+    }
+
+    let count = (*interface_list).dwNumberOfItems as usize;
+    let interfaces = std::slice::from_raw_parts(
+        (*interface_list).InterfaceInfo.as_ptr() as *const WLAN_INTERFACE_INFO,
+        count,
+    );
+    for interface in interfaces {
+        adapters.push(query_wifi_adapter(handle, interface));
+    }
+
+    WlanFreeMemory(interface_list as *const core::ffi::c_void);
+    adapters
+}
+
+/// 查询单个无线网卡的能力与连接状态
+#[cfg(windows)]
+unsafe fn query_wifi_adapter(handle: HANDLE, interface: &WLAN_INTERFACE_INFO) -> WifiAdapter {
+    let guid = &interface.InterfaceGuid;
+    let mut adapter = WifiAdapter {
+        name: u16_slice_to_string(&interface.strInterfaceDescription),
+        ..Default::default()
+    };
+
+    // 支持的无线标准
+    let mut capability: *mut WLAN_INTERFACE_CAPABILITY = std::ptr::null_mut();
+    if WlanGetInterfaceCapability(handle, guid, None, &mut capability) == ERROR_SUCCESS.0
+        && !capability.is_null()
+    {
+        let count = ((*capability).dwNumberOfSupportedPhys as usize).min(64);
+        let mut phy_types: Vec<i32> = (*capability).dot11PhyTypes[..count]
+            .iter()
+            .map(|phy_type| phy_type.0)
+            .collect();
+        phy_types.sort_unstable();
+        phy_types.dedup();
+        adapter.standards = phy_types
+            .into_iter()
+            .filter_map(phy_type_name)
+            .map(str::to_string)
+            .collect();
+        WlanFreeMemory(capability as *const core::ffi::c_void);
+    }
+
+    if interface.isState != wlan_interface_state_connected {
+        return adapter;
+    }
+    adapter.connected = true;
+
+    // 当前连接
+    let mut data_size = 0u32;
+    let mut data: *mut core::ffi::c_void = std::ptr::null_mut();
+    if WlanQueryInterface(
+        handle,
+        guid,
+        wlan_intf_opcode_current_connection,
+        None,
+        &mut data_size,
+        &mut data,
+        None,
+    ) == ERROR_SUCCESS.0
+        && !data.is_null()
+    {
+        let connection = &*(data as *const WLAN_CONNECTION_ATTRIBUTES);
+        let association = &connection.wlanAssociationAttributes;
+        let ssid_len = (association.dot11Ssid.uSSIDLength as usize).min(32);
+        adapter.ssid =
+            Some(String::from_utf8_lossy(&association.dot11Ssid.ucSSID[..ssid_len]).to_string());
+        adapter.current_standard = phy_type_name(association.dot11PhyType.0).map(str::to_string);
+        adapter.signal_quality = Some(association.wlanSignalQuality);
+        // 速率单位为 kbps
+        adapter.link_speed_mbps = Some(association.ulRxRate.max(association.ulTxRate) / 1000);
+
+        let (rssi, frequency) = query_bss(handle, guid, &association.dot11Bssid);
+        adapter.rssi = rssi;
+        adapter.band = frequency.and_then(WifiBand::from_frequency_khz);
+
+        WlanFreeMemory(data as *const core::ffi::c_void);
+    }
+
+    // 当前信道
+    let mut data_size = 0u32;
+    let mut data: *mut core::ffi::c_void = std::ptr::null_mut();
+    if WlanQueryInterface(
+        handle,
+        guid,
+        wlan_intf_opcode_channel_number,
+        None,
+        &mut data_size,
+        &mut data,
+        None,
+    ) == ERROR_SUCCESS.0
+        && !data.is_null()
+    {
+        let channel = *(data as *const u32);
+        adapter.channel = Some(channel);
+        if adapter.band.is_none() {
+            adapter.band = WifiBand::from_channel(channel);
+        }
+        WlanFreeMemory(data as *const core::ffi::c_void);
+    }
+
+    adapter
+}
+
+/// 在扫描列表中查找当前连接的接入点，返回 (信号强度 dBm, 中心频率 kHz)
+#[cfg(windows)]
+unsafe fn query_bss(
+    handle: HANDLE,
+    guid: &windows::core::GUID,
+    bssid: &[u8; 6],
+) -> (Option<i32>, Option<u32>) {
+    let mut bss_list: *mut WLAN_BSS_LIST = std::ptr::null_mut();
+    if WlanGetNetworkBssList(
+        handle,
+        guid,
+        None,
+        dot11_BSS_type_any,
+        false,
+        None,
+        &mut bss_list,
+    ) != ERROR_SUCCESS.0
+        || bss_list.is_null()
+    {
+        return (None, None);
+    }
+
+    let count = (*bss_list).dwNumberOfItems as usize;
+    let entries = std::slice::from_raw_parts((*bss_list).wlanBssEntries.as_ptr(), count);
+    let result = entries
+        .iter()
+        .find(|entry| entry.dot11Bssid == *bssid)
+        .map(|entry| (Some(entry.lRssi), Some(entry.ulChCenterFrequency)))
+        .unwrap_or((None, None));
+
+    WlanFreeMemory(bss_list as *const core::ffi::c_void);
+    result
+}
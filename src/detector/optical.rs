@@ -0,0 +1,182 @@
+//! 光驱检测模块
+//!
+//! 通过 Win32_CDROMDrive 枚举 CD/DVD/BD 光驱，接口类型以设备描述符的 BusType 为准
+//! （SATA 光驱在 AHCI 模式下的 PnP ID 以 "SCSI\" 开头，无法据此区分），读取失败时按 PnP ID 推断。
+
+use super::disk::DiskBus;
+#[cfg(windows)]
+use crate::utils::wmi;
+#[cfg(windows)]
+use crate::wmi_row;
+
+/// 光驱
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpticalDrive {
+    /// 光驱型号，如 "HL-DT-ST DVDRAM GU90N"
+    pub model: String,
+    /// 盘符，如 "E:"，未分配时为 None
+    pub drive_letter: Option<String>,
+    /// 驱动器类型，如 "DVD Writer"
+    pub media_type: String,
+    /// 接口类型
+    pub bus: DiskBus,
+    /// 是否已放入光盘
+    pub media_loaded: bool,
+}
+
+impl Default for OpticalDrive {
+    fn default() -> Self {
+        Self {
+            model: "未知".to_string(),
+            drive_letter: None,
+            media_type: "未知".to_string(),
+            bus: DiskBus::Unknown,
+            media_loaded: false,
+        }
+    }
+}
+
+impl OpticalDrive {
+    /// 驱动器类型的中文描述
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::optical::OpticalDrive;
+    /// let drive = |media_type: &str| OpticalDrive {
+    ///     media_type: media_type.to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(drive("DVD Writer").kind_desc(), "DVD 刻录机");
+    /// assert_eq!(drive("CD-ROM").kind_desc(), "CD 光驱");
+    /// assert_eq!(drive("BD-ROM").kind_desc(), "蓝光光驱");
+    /// assert_eq!(drive("Unknown").kind_desc(), "光驱");
+    /// ```
+    pub fn kind_desc(&self) -> String {
+        let media_type = self.media_type.to_ascii_uppercase();
+        let format = if media_type.contains("BD") || media_type.contains("BLU") {
+            "蓝光"
+        } else if media_type.contains("DVD") {
+            "DVD "
+        } else if media_type.contains("CD") {
+            "CD "
+        } else {
+            ""
+        };
+        let kind = if media_type.contains("WRITER") {
+            "刻录机"
+        } else {
+            "光驱"
+        };
+        format!("{}{}", format, kind)
+    }
+
+    /// 概要，如 "E: HL-DT-ST DVDRAM GU90N (SATA DVD 刻录机, 已放入光盘)"
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::disk::DiskBus;
+    /// use hardware_master::detector::optical::OpticalDrive;
+    /// let drive = OpticalDrive {
+    ///     model: "HL-DT-ST DVDRAM GU90N".to_string(),
+    ///     drive_letter: Some("E:".to_string()),
+    ///     media_type: "DVD Writer".to_string(),
+    ///     bus: DiskBus::Sata,
+    ///     media_loaded: false,
+    /// };
+    /// assert_eq!(drive.summary(), "E: HL-DT-ST DVDRAM GU90N (SATA DVD 刻录机, 无光盘)");
+    /// ```
+    pub fn summary(&self) -> String {
+        let letter = self
+            .drive_letter
+            .as_deref()
+            .map(|letter| format!("{} ", letter))
+            .unwrap_or_default();
+        let bus = match self.bus {
+            DiskBus::Unknown => String::new(),
+            bus => format!("{} ", bus),
+        };
+        let media = if self.media_loaded {
+            "已放入光盘"
+        } else {
+            "无光盘"
+        };
+        format!(
+            "{}{} ({}{}, {})",
+            letter,
+            self.model,
+            bus,
+            self.kind_desc(),
+            media
+        )
+    }
+}
+
+/// 由光驱的 PnP 设备 ID 推断接口类型
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::disk::DiskBus;
+/// use hardware_master::detector::optical::bus_from_pnp_id;
+/// assert_eq!(bus_from_pnp_id("USBSTOR\\CDROM&VEN_ASUS&PROD_SDRW-08D2S-U\\1"), DiskBus::Usb);
+/// assert_eq!(bus_from_pnp_id("IDE\\CDROMHL-DT-ST_DVDRAM\\5&1"), DiskBus::Ata);
+/// assert_eq!(bus_from_pnp_id("SCSI\\CDROM&VEN_HL-DT-ST\\4&1"), DiskBus::Unknown);
+/// ```
+pub fn bus_from_pnp_id(pnp_device_id: &str) -> DiskBus {
+    let bus = pnp_device_id
+        .split('\\')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_uppercase();
+    match bus.as_str() {
+        "USBSTOR" => DiskBus::Usb,
+        "IDE" => DiskBus::Ata,
+        _ => DiskBus::Unknown,
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_CDROMDrive 中用到的属性
+    struct CdRomRow {
+        name: String = "Name",
+        drive: String = "Drive",
+        media_type: String = "MediaType",
+        media_loaded: bool = "MediaLoaded",
+        pnp_device_id: String = "PNPDeviceID",
+    }
+}
+
+/// 检测所有光驱，查询失败时返回空列表
+#[cfg(windows)]
+pub fn detect_optical_drives() -> Vec<OpticalDrive> {
+    let rows: Vec<CdRomRow> = wmi::WmiClient::connect(&wmi::WmiConfig::default())
+        .and_then(|client| {
+            client.query_as(
+                "SELECT Name, Drive, MediaType, MediaLoaded, PNPDeviceID FROM Win32_CDROMDrive",
+            )
+        })
+        .unwrap_or_else(|e| {
+            log::warn!("枚举光驱失败: {}", e);
+            Vec::new()
+        });
+
+    rows.into_iter()
+        .map(|row| {
+            let drive_letter = row.drive.filter(|drive| !drive.trim().is_empty());
+            let bus = drive_letter
+                .as_deref()
+                .and_then(|letter| unsafe { super::disk::get_volume_bus(letter) })
+                .unwrap_or_else(|| {
+                    bus_from_pnp_id(row.pnp_device_id.as_deref().unwrap_or_default())
+                });
+            OpticalDrive {
+                model: row.name.unwrap_or_else(|| "未知".to_string()),
+                drive_letter,
+                media_type: row.media_type.unwrap_or_else(|| "未知".to_string()),
+                bus,
+                media_loaded: row.media_loaded.unwrap_or(false),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,72 @@
+//! PCI 设备厂商识别
+//!
+//! 由设备实例 ID 中的厂商 ID 与子系统 ID 查询芯片厂商与板卡厂商（如七彩虹、微星），
+//! 适用于显卡、网卡等所有 PCI 设备，以及同样带有子系统 ID 的 HD Audio 设备。
+
+use crate::iddb::{self, Bus};
+use crate::utils::device_id::parse_device_instance_id;
+use crate::utils::subsystem::SubsystemId;
+
+/// PCI 设备的厂商
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PciVendors {
+    /// 芯片厂商，如 "NVIDIA Corporation"
+    pub chip_vendor: Option<String>,
+    /// 板卡厂商（子系统厂商），如 "Micro-Star International Co., Ltd. [MSI]"
+    pub board_vendor: Option<String>,
+    /// 子系统 ID
+    pub subsystem: Option<SubsystemId>,
+}
+
+/// 查询子系统厂商（板卡厂商）名称，pci.ids 中没有该厂商时返回 None
+///
+/// * `subsystem` - 子系统 ID
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::pci::board_vendor_name;
+/// use hardware_master::utils::subsystem::SubsystemId;
+/// let subsystem = SubsystemId::parse("7D251462").unwrap();
+/// assert_eq!(
+///     board_vendor_name(subsystem).as_deref(),
+///     Some("Micro-Star International Co., Ltd. [MSI]")
+/// );
+/// ```
+pub fn board_vendor_name(subsystem: SubsystemId) -> Option<String> {
+    iddb::db().pci().vendor(subsystem.vendor_id).name()
+}
+
+/// 由设备实例 ID 识别芯片厂商与板卡厂商，非 PCI、HD Audio 设备返回空结果
+///
+/// HD Audio 设备的厂商 ID 与子系统厂商 ID 同样在 pci.ids 中登记
+///
+/// * `instance_id` - 设备实例 ID，如 `PCI\VEN_10DE&DEV_2684&SUBSYS_16F37377&REV_A1\4&...`
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::pci::resolve_vendors;
+/// let vendors = resolve_vendors(r"PCI\VEN_10DE&DEV_2684&SUBSYS_16F37377&REV_A1\4&1");
+/// assert_eq!(vendors.chip_vendor.as_deref(), Some("NVIDIA Corporation"));
+/// assert_eq!(
+///     vendors.board_vendor.as_deref(),
+///     Some("Shenzhen Colorful Yugong Technology and Development Co.")
+/// );
+/// assert_eq!(resolve_vendors(r"USB\VID_046D&PID_0A44\7&1"), Default::default());
+/// ```
+pub fn resolve_vendors(instance_id: &str) -> PciVendors {
+    let info = parse_device_instance_id(instance_id);
+    let is_pci = Bus::from_enumerator(&info.bus_type) == Some(Bus::Pci)
+        || info.bus_type.eq_ignore_ascii_case("HDAUDIO");
+    if !is_pci {
+        return PciVendors::default();
+    }
+
+    let chip_vendor = iddb::parse_id(&info.vendor_id)
+        .and_then(|vendor_id| iddb::db().pci().vendor(vendor_id).name());
+    let subsystem = SubsystemId::from_instance_id(instance_id);
+    PciVendors {
+        chip_vendor,
+        board_vendor: subsystem.and_then(board_vendor_name),
+        subsystem,
+    }
+}
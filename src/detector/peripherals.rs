@@ -0,0 +1,619 @@
+//! 外设检测模块
+//!
+//! 通过 EnumPrinters 枚举已安装的打印机（本机与网络连接），通过 WMI 的 Win32_PnPSignedDriver
+//! 枚举扫描仪等图像设备，通过 GetRawInputDeviceList 枚举键盘、鼠标、触控板与游戏控制器，
+//! 列出连接方式与驱动，供外设标签页显示。
+
+#[cfg(windows)]
+use super::DetectionError;
+#[cfg(windows)]
+use crate::iddb;
+#[cfg(windows)]
+use crate::utils::string::{pwstr_to_string, u16_slice_to_string};
+#[cfg(windows)]
+use crate::utils::wmi;
+#[cfg(windows)]
+use crate::wmi_row;
+use std::fmt;
+#[cfg(windows)]
+use windows::core::PWSTR;
+#[cfg(windows)]
+use windows::Win32::Graphics::Printing::{
+    EnumPrintersW, GetDefaultPrinterW, PRINTER_ATTRIBUTE_NETWORK, PRINTER_ATTRIBUTE_SHARED,
+    PRINTER_ATTRIBUTE_WORK_OFFLINE, PRINTER_ENUM_CONNECTIONS, PRINTER_ENUM_LOCAL, PRINTER_INFO_2W,
+    PRINTER_STATUS_OFFLINE,
+};
+
+/// 外设的连接方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum PeripheralConnection {
+    /// USB
+    Usb,
+    /// 网络（TCP/IP、WSD 或共享打印机）
+    Network,
+    /// 蓝牙
+    Bluetooth,
+    /// 并口
+    Parallel,
+    /// 串口
+    Serial,
+    /// 内置（PS/2、I2C 等主板直连的设备）
+    Internal,
+    /// 虚拟设备（打印到 PDF、传真等）
+    Virtual,
+    /// 其他或未知
+    Other,
+}
+
+impl PeripheralConnection {
+    /// 由打印机端口名判断连接方式
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::peripherals::PeripheralConnection;
+    /// assert_eq!(PeripheralConnection::from_port_name("USB001"), PeripheralConnection::Usb);
+    /// assert_eq!(
+    ///     PeripheralConnection::from_port_name("192.168.1.20"),
+    ///     PeripheralConnection::Network
+    /// );
+    /// assert_eq!(
+    ///     PeripheralConnection::from_port_name("WSD-6a1c2f3e-0000-4f5d-9d8a-000000000001"),
+    ///     PeripheralConnection::Network
+    /// );
+    /// assert_eq!(
+    ///     PeripheralConnection::from_port_name("PORTPROMPT:"),
+    ///     PeripheralConnection::Virtual
+    /// );
+    /// assert_eq!(PeripheralConnection::from_port_name("LPT1:"), PeripheralConnection::Parallel);
+    /// ```
+    pub fn from_port_name(port: &str) -> Self {
+        let port = port.trim().to_lowercase();
+        let is_ip = port.split('.').count() == 4
+            && port
+                .split('.')
+                .all(|part| !part.is_empty() && part.parse::<u8>().is_ok());
+        if port.starts_with("usb") || port.starts_with("dot4") {
+            PeripheralConnection::Usb
+        } else if port.starts_with("lpt") {
+            PeripheralConnection::Parallel
+        } else if port.starts_with("com") {
+            PeripheralConnection::Serial
+        } else if port.starts_with("bth") {
+            PeripheralConnection::Bluetooth
+        } else if is_ip
+            || port.starts_with("wsd")
+            || port.starts_with("ip_")
+            || port.starts_with("http")
+            || port.starts_with(r"\\")
+        {
+            PeripheralConnection::Network
+        } else if ["portprompt:", "file:", "nul:", "shrfax:", "xps", "onenote"]
+            .iter()
+            .any(|prefix| port.starts_with(prefix))
+        {
+            PeripheralConnection::Virtual
+        } else {
+            PeripheralConnection::Other
+        }
+    }
+
+    /// 由设备实例 ID 的总线前缀判断连接方式
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::peripherals::PeripheralConnection;
+    /// assert_eq!(
+    ///     PeripheralConnection::from_device_id(r"USB\VID_04A9&PID_1912\5&1A2B3C&0&1"),
+    ///     PeripheralConnection::Usb
+    /// );
+    /// assert_eq!(
+    ///     PeripheralConnection::from_device_id(r"SWD\DAFWSDPROVIDER\UUID:6A1C2F3E"),
+    ///     PeripheralConnection::Network
+    /// );
+    /// assert_eq!(
+    ///     PeripheralConnection::from_device_id(r"ROOT\IMAGE\0000"),
+    ///     PeripheralConnection::Virtual
+    /// );
+    /// ```
+    pub fn from_device_id(device_id: &str) -> Self {
+        let device_id = device_id.trim().to_uppercase();
+        let bus = device_id.split('\\').next().unwrap_or_default();
+        match bus {
+            "USB" | "USBPRINT" => PeripheralConnection::Usb,
+            "BTH" | "BTHENUM" | "BTHLE" => PeripheralConnection::Bluetooth,
+            "LPTENUM" => PeripheralConnection::Parallel,
+            "SERENUM" => PeripheralConnection::Serial,
+            "ACPI" => PeripheralConnection::Internal,
+            "SWD" | "UMB" | "WSDPRINT" if device_id.contains("WSD") => {
+                PeripheralConnection::Network
+            }
+            "ROOT" | "SWD" => PeripheralConnection::Virtual,
+            _ => PeripheralConnection::Other,
+        }
+    }
+}
+
+impl fmt::Display for PeripheralConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PeripheralConnection::Usb => "USB",
+            PeripheralConnection::Network => "网络",
+            PeripheralConnection::Bluetooth => "蓝牙",
+            PeripheralConnection::Parallel => "并口",
+            PeripheralConnection::Serial => "串口",
+            PeripheralConnection::Internal => "内置",
+            PeripheralConnection::Virtual => "虚拟",
+            PeripheralConnection::Other => "其他",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 打印机
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Printer {
+    /// 打印机名称，网络连接的打印机为 `\\服务器\共享名`
+    pub name: String,
+    /// 驱动名称
+    pub driver: String,
+    /// 端口名
+    pub port: String,
+    /// 连接方式
+    pub connection: PeripheralConnection,
+    /// 是否为默认打印机
+    pub is_default: bool,
+    /// 是否已共享给网络上的其他电脑
+    pub shared: bool,
+    /// 是否脱机
+    pub offline: bool,
+}
+
+impl Printer {
+    /// 状态描述，如 "默认, 已共享"，没有特殊状态时为空
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::peripherals::{PeripheralConnection, Printer};
+    /// let printer = Printer {
+    ///     name: "HP LaserJet Pro M404".to_string(),
+    ///     driver: "HP Universal Printing PCL 6".to_string(),
+    ///     port: "USB001".to_string(),
+    ///     connection: PeripheralConnection::Usb,
+    ///     is_default: true,
+    ///     shared: false,
+    ///     offline: true,
+    /// };
+    /// assert_eq!(printer.status_desc(), "默认, 脱机");
+    /// ```
+    pub fn status_desc(&self) -> String {
+        [
+            (self.is_default, "默认"),
+            (self.shared, "已共享"),
+            (self.offline, "脱机"),
+        ]
+        .iter()
+        .filter(|(flag, _)| *flag)
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+/// 扫描仪等图像设备
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImagingDevice {
+    /// 设备名称
+    pub name: String,
+    /// 制造商
+    pub manufacturer: String,
+    /// 驱动版本
+    pub driver_version: String,
+    /// 连接方式
+    pub connection: PeripheralConnection,
+    /// 设备实例 ID
+    pub device_id: String,
+}
+
+/// 输入设备类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputDeviceKind {
+    /// 键盘
+    Keyboard,
+    /// 鼠标
+    Mouse,
+    /// 触控板
+    Touchpad,
+    /// 游戏控制器（手柄、摇杆、方向盘）
+    GameController,
+}
+
+impl InputDeviceKind {
+    /// 由原始输入设备类型与 HID 用途判断输入设备类型，其他 HID 设备（媒体键、传感器等）返回 None
+    ///
+    /// * `raw_type` - RID_DEVICE_INFO 的 dwType（0 鼠标，1 键盘，2 其他 HID 设备）
+    /// * `usage_page` - HID 用途页，仅 `raw_type` 为 2 时有效
+    /// * `usage` - HID 用途，仅 `raw_type` 为 2 时有效
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::peripherals::InputDeviceKind;
+    /// assert_eq!(InputDeviceKind::from_raw_input(1, 0, 0), Some(InputDeviceKind::Keyboard));
+    /// // 精确式触控板：数字化仪用途页 0x0D，用途 0x05
+    /// assert_eq!(
+    ///     InputDeviceKind::from_raw_input(2, 0x0D, 0x05),
+    ///     Some(InputDeviceKind::Touchpad)
+    /// );
+    /// assert_eq!(
+    ///     InputDeviceKind::from_raw_input(2, 0x01, 0x05),
+    ///     Some(InputDeviceKind::GameController)
+    /// );
+    /// // 消费类控制（音量、媒体键）
+    /// assert_eq!(InputDeviceKind::from_raw_input(2, 0x0C, 0x01), None);
+    /// ```
+    pub fn from_raw_input(raw_type: u32, usage_page: u16, usage: u16) -> Option<Self> {
+        match (raw_type, usage_page, usage) {
+            (0, _, _) => Some(InputDeviceKind::Mouse),
+            (1, _, _) => Some(InputDeviceKind::Keyboard),
+            (2, 0x0D, 0x05) => Some(InputDeviceKind::Touchpad),
+            (2, 0x01, 0x04 | 0x05 | 0x08) => Some(InputDeviceKind::GameController),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for InputDeviceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            InputDeviceKind::Keyboard => "键盘",
+            InputDeviceKind::Mouse => "鼠标",
+            InputDeviceKind::Touchpad => "触控板",
+            InputDeviceKind::GameController => "游戏控制器",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 键盘、鼠标等输入设备
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputDevice {
+    /// 设备类型
+    pub kind: InputDeviceKind,
+    /// 设备名称，设备数据库中没有时为硬件 ID
+    pub name: String,
+    /// 厂商名称
+    pub vendor: String,
+    /// USB 厂商 ID 与产品 ID
+    pub usb_ids: Option<(u16, u16)>,
+    /// 连接方式
+    pub connection: PeripheralConnection,
+    /// 原始输入设备路径
+    pub device_path: String,
+}
+
+/// 从原始输入设备路径中解析 USB 厂商 ID 与产品 ID
+///
+/// 支持 USB 设备的 `VID_xxxx&PID_xxxx` 与蓝牙设备的 `VID&0002xxxx_PID&xxxx` 两种格式；
+/// 蓝牙设备的 ID 来源为蓝牙 SIG 时不是 USB ID，返回 None
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::peripherals::parse_usb_ids;
+/// assert_eq!(
+///     parse_usb_ids(r"\\?\HID#VID_046D&PID_C52B&MI_00#8&2f1d4a&0&0000#{884b96c3}"),
+///     Some((0x046D, 0xC52B))
+/// );
+/// assert_eq!(
+///     parse_usb_ids(r"\\?\HID#{00001124-0000-1000-8000-00805f9b34fb}_VID&0002045e_PID&0b13#9"),
+///     Some((0x045E, 0x0B13))
+/// );
+/// assert_eq!(
+///     parse_usb_ids(r"\\?\HID#{00001812-0000-1000-8000-00805f9b34fb}_Dev_VID&01000f_PID&1234"),
+///     None
+/// );
+/// assert_eq!(parse_usb_ids(r"\\?\ACPI#PNP0303#4&1d401fb5&0#{884b96c3}"), None);
+/// ```
+pub fn parse_usb_ids(device_path: &str) -> Option<(u16, u16)> {
+    let path = device_path.to_uppercase();
+    let hex = |start: usize| {
+        path.get(start..start + 4)
+            .and_then(|id| u16::from_str_radix(id, 16).ok())
+    };
+    if let (Some(vid), Some(pid)) = (path.find("VID_"), path.find("PID_")) {
+        return Some((hex(vid + 4)?, hex(pid + 4)?));
+    }
+    let (vid, pid) = (path.find("VID&")?, path.find("PID&")?);
+    // 厂商 ID 前为 ID 来源（经典蓝牙 4 位，低功耗蓝牙 2 位）：1 蓝牙 SIG，2 USB-IF
+    let digits: String = path[vid + 4..]
+        .chars()
+        .take_while(|c| c.is_ascii_hexdigit())
+        .collect();
+    let source_len = digits.len().checked_sub(4).filter(|len| *len > 0)?;
+    if u16::from_str_radix(&digits[..source_len], 16).ok()? != 2 {
+        return None;
+    }
+    Some((hex(vid + 4 + source_len)?, hex(pid + 4)?))
+}
+
+/// 由原始输入设备路径判断连接方式
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::peripherals::{input_connection, PeripheralConnection};
+/// assert_eq!(
+///     input_connection(r"\\?\HID#VID_046D&PID_C52B&MI_00#8&2f1d4a&0&0000#{884b96c3}"),
+///     PeripheralConnection::Usb
+/// );
+/// assert_eq!(
+///     input_connection(r"\\?\HID#ELAN0676&Col02#5&2a7ad9c4&0&0001#{4d1e55b2}"),
+///     PeripheralConnection::Internal
+/// );
+/// assert_eq!(
+///     input_connection(r"\\?\Root#RDP_MOU#0000#{378de44c}"),
+///     PeripheralConnection::Virtual
+/// );
+/// ```
+pub fn input_connection(device_path: &str) -> PeripheralConnection {
+    let path = device_path.to_uppercase();
+    let bus = path
+        .trim_start_matches(r"\\?\")
+        .split('#')
+        .next()
+        .unwrap_or_default();
+    if bus == "ROOT" {
+        PeripheralConnection::Virtual
+    } else if bus == "ACPI" {
+        PeripheralConnection::Internal
+    } else if path.contains("{00001124-") || path.contains("{00001812-") || path.contains("BTH") {
+        // 经典蓝牙 HID 与低功耗蓝牙 HID 服务
+        PeripheralConnection::Bluetooth
+    } else if path.contains("VID_") {
+        PeripheralConnection::Usb
+    } else if bus == "HID" {
+        // 没有 USB ID 的 HID 设备通常为 I2C 连接的内置触控板、键盘
+        PeripheralConnection::Internal
+    } else {
+        PeripheralConnection::Other
+    }
+}
+
+/// 外设信息
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeripheralInfo {
+    /// 打印机列表，默认打印机在前
+    pub printers: Vec<Printer>,
+    /// 扫描仪等图像设备列表
+    pub imaging_devices: Vec<ImagingDevice>,
+    /// 键盘、鼠标等输入设备列表，按类型排列
+    pub input_devices: Vec<InputDevice>,
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_PnPSignedDriver 中用到的属性
+    struct ImagingDriverRow {
+        device_name: String = "DeviceName",
+        manufacturer: String = "Manufacturer",
+        driver_version: String = "DriverVersion",
+        device_id: String = "DeviceID",
+    }
+}
+
+/// 检测打印机、图像设备与输入设备
+///
+/// 各类设备分别检测，部分失败时只记录日志；全部失败时返回错误
+#[cfg(windows)]
+pub fn detect_peripherals() -> Result<PeripheralInfo, DetectionError> {
+    let printers = unsafe { enumerate_printers() };
+    let imaging_devices = detect_imaging_devices();
+    let input_devices = unsafe { enumerate_input_devices() };
+    if let (Err(e), Err(_), Err(_)) = (&printers, &imaging_devices, &input_devices) {
+        return Err(e.clone());
+    }
+    Ok(PeripheralInfo {
+        printers: ok_or_warn(printers),
+        imaging_devices: ok_or_warn(imaging_devices),
+        input_devices: ok_or_warn(input_devices),
+    })
+}
+
+/// 取出检测结果，失败时记录日志并返回空列表
+#[cfg(windows)]
+fn ok_or_warn<T>(result: Result<Vec<T>, DetectionError>) -> Vec<T> {
+    result.map_err(|e| log::warn!("{}", e)).unwrap_or_default()
+}
+
+/// 枚举本机安装与网络连接的打印机
+#[cfg(windows)]
+unsafe fn enumerate_printers() -> Result<Vec<Printer>, DetectionError> {
+    let flags = PRINTER_ENUM_LOCAL | PRINTER_ENUM_CONNECTIONS;
+    let mut needed = 0u32;
+    let mut returned = 0u32;
+    // 第一次调用获取所需缓冲区大小，缓冲区不足时返回错误属预期
+    let _ = EnumPrintersW(flags, None, 2, None, &mut needed, &mut returned);
+    if needed == 0 {
+        return Ok(Vec::new());
+    }
+
+    // 结构体中包含指针，缓冲区按 8 字节对齐
+    let mut buffer = vec![0u64; (needed as usize).div_ceil(8)];
+    let bytes = std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, needed as usize);
+    EnumPrintersW(flags, None, 2, Some(bytes), &mut needed, &mut returned)
+        .map_err(|e| DetectionError::WindowsApiError(format!("枚举打印机失败: {}", e)))?;
+
+    let default_printer = default_printer_name();
+    let infos =
+        std::slice::from_raw_parts(buffer.as_ptr() as *const PRINTER_INFO_2W, returned as usize);
+    let mut printers: Vec<Printer> = infos
+        .iter()
+        .map(|info| {
+            let name = pwstr_to_string(info.pPrinterName);
+            let port = pwstr_to_string(info.pPortName);
+            let connection = if info.Attributes & PRINTER_ATTRIBUTE_NETWORK != 0 {
+                PeripheralConnection::Network
+            } else {
+                PeripheralConnection::from_port_name(&port)
+            };
+            Printer {
+                is_default: default_printer.as_deref() == Some(name.as_str()),
+                driver: pwstr_to_string(info.pDriverName),
+                connection,
+                shared: info.Attributes & PRINTER_ATTRIBUTE_SHARED != 0,
+                offline: info.Attributes & PRINTER_ATTRIBUTE_WORK_OFFLINE != 0
+                    || info.Status & PRINTER_STATUS_OFFLINE != 0,
+                name,
+                port,
+            }
+        })
+        .collect();
+    printers.sort_by_key(|printer| !printer.is_default);
+    Ok(printers)
+}
+
+/// 获取默认打印机名称
+#[cfg(windows)]
+unsafe fn default_printer_name() -> Option<String> {
+    let mut size = 0u32;
+    let _ = GetDefaultPrinterW(None, &mut size);
+    if size == 0 {
+        return None;
+    }
+    let mut buffer = vec![0u16; size as usize];
+    GetDefaultPrinterW(Some(PWSTR(buffer.as_mut_ptr())), &mut size)
+        .as_bool()
+        .then(|| pwstr_to_string(PWSTR(buffer.as_mut_ptr())))
+}
+
+/// 通过 WMI 检测扫描仪等图像设备（设备类 Image）
+#[cfg(windows)]
+fn detect_imaging_devices() -> Result<Vec<ImagingDevice>, DetectionError> {
+    let config = wmi::WmiConfig::default();
+    let client = wmi::WmiClient::connect(&config)?;
+    let rows: Vec<ImagingDriverRow> = client.query_as(
+        "SELECT DeviceName, Manufacturer, DriverVersion, DeviceID FROM Win32_PnPSignedDriver WHERE DeviceClass = 'IMAGE'",
+    )?;
+    let unknown = || "未知".to_string();
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let device_id = row.device_id.unwrap_or_else(unknown);
+            ImagingDevice {
+                name: row.device_name.unwrap_or_else(unknown),
+                manufacturer: row.manufacturer.unwrap_or_else(unknown),
+                driver_version: row.driver_version.unwrap_or_else(unknown),
+                connection: PeripheralConnection::from_device_id(&device_id),
+                device_id,
+            }
+        })
+        .collect())
+}
+
+/// 通过原始输入接口枚举键盘、鼠标、触控板与游戏控制器
+///
+/// 同一设备的多个 HID 集合（如带多媒体键的键盘、无线接收器）只保留一个
+#[cfg(windows)]
+unsafe fn enumerate_input_devices() -> Result<Vec<InputDevice>, DetectionError> {
+    let entry_size = std::mem::size_of::<RAWINPUTDEVICELIST>() as u32;
+    let mut count = 0u32;
+    if GetRawInputDeviceList(None, &mut count, entry_size) == u32::MAX {
+        return Err(DetectionError::WindowsApiError(
+            "GetRawInputDeviceList 失败".to_string(),
+        ));
+    }
+    let mut list = vec![RAWINPUTDEVICELIST::default(); count as usize];
+    let returned = GetRawInputDeviceList(Some(list.as_mut_ptr()), &mut count, entry_size);
+    if returned == u32::MAX {
+        return Err(DetectionError::WindowsApiError(
+            "GetRawInputDeviceList 失败".to_string(),
+        ));
+    }
+    list.truncate(returned as usize);
+
+    let db = iddb::db();
+    let mut devices: Vec<InputDevice> = Vec::new();
+    for entry in list {
+        let Some(device_path) = raw_input_device_name(entry) else {
+            continue;
+        };
+        let mut info = RID_DEVICE_INFO {
+            cbSize: std::mem::size_of::<RID_DEVICE_INFO>() as u32,
+            ..Default::default()
+        };
+        let mut size = info.cbSize;
+        if GetRawInputDeviceInfoW(
+            Some(entry.hDevice),
+            RIDI_DEVICEINFO,
+            Some(&mut info as *mut _ as *mut _),
+            &mut size,
+        ) == u32::MAX
+        {
+            continue;
+        }
+        let (usage_page, usage) = (info.Anonymous.hid.usUsagePage, info.Anonymous.hid.usUsage);
+        let Some(kind) = InputDeviceKind::from_raw_input(info.dwType.0, usage_page, usage) else {
+            continue;
+        };
+        let connection = input_connection(&device_path);
+        if connection == PeripheralConnection::Virtual {
+            continue;
+        }
+
+        let usb_ids = parse_usb_ids(&device_path);
+        if devices.iter().any(|device| {
+            device.kind == kind && device.usb_ids.is_some() && device.usb_ids == usb_ids
+        }) {
+            continue;
+        }
+        let vendor = usb_ids
+            .and_then(|(vid, _)| db.usb().vendor(vid).name())
+            .unwrap_or_else(|| "未知".to_string());
+        let name = usb_ids
+            .and_then(|(vid, pid)| db.usb().vendor(vid).device(pid).name())
+            .unwrap_or_else(|| hardware_id(&device_path));
+        devices.push(InputDevice {
+            kind,
+            name,
+            vendor,
+            usb_ids,
+            connection,
+            device_path,
+        });
+    }
+    devices.sort_by_key(|device| device.kind);
+    Ok(devices)
+}
+
+/// 获取原始输入设备的设备路径
+#[cfg(windows)]
+unsafe fn raw_input_device_name(entry: RAWINPUTDEVICELIST) -> Option<String> {
+    let mut size = 0u32;
+    GetRawInputDeviceInfoW(Some(entry.hDevice), RIDI_DEVICENAME, None, &mut size);
+    if size == 0 {
+        return None;
+    }
+    let mut buffer = vec![0u16; size as usize];
+    let copied = GetRawInputDeviceInfoW(
+        Some(entry.hDevice),
+        RIDI_DEVICENAME,
+        Some(buffer.as_mut_ptr() as *mut _),
+        &mut size,
+    );
+    (copied != u32::MAX).then(|| u16_slice_to_string(&buffer))
+}
+
+/// 设备路径中的硬件 ID，如 `\\?\ACPI#PNP0303#...` 中的 "PNP0303"
+#[cfg(windows)]
+fn hardware_id(device_path: &str) -> String {
+    device_path
+        .split('#')
+        .nth(1)
+        .and_then(|id| id.split('&').next())
+        .filter(|id| !id.is_empty())
+        .unwrap_or("未知")
+        .to_string()
+}
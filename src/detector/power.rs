@@ -0,0 +1,178 @@
+//! 电源检测模块
+//!
+//! 检测当前是否接通交流电源、正在使用的 Windows 电源计划，以及 USB PD 充电器的功率（驱动提供时）。
+
+#[cfg(windows)]
+use crate::utils;
+use std::fmt;
+#[cfg(windows)]
+use windows::core::GUID;
+#[cfg(windows)]
+use windows::Win32::Foundation::{LocalFree, ERROR_SUCCESS, HLOCAL};
+#[cfg(windows)]
+use windows::Win32::System::Power::{
+    GetSystemPowerStatus, PowerGetActiveScheme, PowerReadFriendlyName, SYSTEM_POWER_STATUS,
+};
+
+/// 供电方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerLine {
+    /// 交流电源（电源适配器）
+    Ac,
+    /// 电池供电
+    Battery,
+    /// 未知
+    #[default]
+    Unknown,
+}
+
+impl PowerLine {
+    /// 由 SYSTEM_POWER_STATUS 的 ACLineStatus 转换
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::power::PowerLine;
+    /// assert_eq!(PowerLine::from_ac_line_status(1), PowerLine::Ac);
+    /// assert_eq!(PowerLine::from_ac_line_status(0), PowerLine::Battery);
+    /// assert_eq!(PowerLine::from_ac_line_status(255), PowerLine::Unknown);
+    /// ```
+    pub fn from_ac_line_status(status: u8) -> Self {
+        match status {
+            0 => PowerLine::Battery,
+            1 => PowerLine::Ac,
+            _ => PowerLine::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for PowerLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PowerLine::Ac => "交流电源",
+            PowerLine::Battery => "电池供电",
+            PowerLine::Unknown => "未知",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 电源状态
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerStatus {
+    /// 供电方式
+    pub line: PowerLine,
+    /// 当前电源计划名称
+    pub plan: String,
+    /// 充电器功率 (W)，仅 USB PD 等驱动提供时可用
+    pub charger_watts: Option<f64>,
+}
+
+impl Default for PowerStatus {
+    fn default() -> Self {
+        Self {
+            line: PowerLine::Unknown,
+            plan: "未知".to_string(),
+            charger_watts: None,
+        }
+    }
+}
+
+impl PowerStatus {
+    /// 生成一行摘要，如 "交流电源 (65 W 充电器), 电源计划: 平衡"
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::power::{PowerLine, PowerStatus};
+    /// let status = PowerStatus {
+    ///     line: PowerLine::Ac,
+    ///     plan: "平衡".to_string(),
+    ///     charger_watts: Some(65.0),
+    /// };
+    /// assert_eq!(status.summary(), "交流电源 (65 W 充电器), 电源计划: 平衡");
+    /// assert_eq!(PowerStatus::default().summary(), "未知, 电源计划: 未知");
+    /// ```
+    pub fn summary(&self) -> String {
+        let line = match self.charger_watts {
+            Some(watts) => format!("{} ({:.0} W 充电器)", self.line, watts),
+            None => self.line.to_string(),
+        };
+        format!("{}, 电源计划: {}", line, self.plan)
+    }
+}
+
+/// 由 USB 充电器报告的最大电流与电压计算功率 (W)，数据缺失或未知（0xFFFFFFFF）时返回 None
+///
+/// * `max_current` - 最大电流 (mA)
+/// * `voltage` - 电压 (mV)
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::power::charger_watts;
+/// assert_eq!(charger_watts(3250, 20000), Some(65.0));
+/// assert_eq!(charger_watts(0, 20000), None);
+/// assert_eq!(charger_watts(3000, u32::MAX), None);
+/// ```
+pub fn charger_watts(max_current: u32, voltage: u32) -> Option<f64> {
+    let known = |value: u32| value > 0 && value != u32::MAX;
+    (known(max_current) && known(voltage))
+        .then(|| max_current as f64 * voltage as f64 / 1_000_000.0)
+}
+
+/// 检测供电方式与当前电源计划
+///
+/// 充电器功率需通过电池设备查询，由电池检测填写
+#[cfg(windows)]
+pub fn detect_power_status() -> PowerStatus {
+    let mut status = PowerStatus::default();
+
+    let mut system_status = SYSTEM_POWER_STATUS::default();
+    match unsafe { GetSystemPowerStatus(&mut system_status) } {
+        Ok(()) => status.line = PowerLine::from_ac_line_status(system_status.ACLineStatus),
+        Err(e) => log::warn!("GetSystemPowerStatus 失败: {:?}", e),
+    }
+
+    match unsafe { active_scheme_name() } {
+        Some(name) => status.plan = name,
+        None => log::warn!("读取当前电源计划失败"),
+    }
+
+    status
+}
+
+/// 读取当前电源计划的名称
+#[cfg(windows)]
+unsafe fn active_scheme_name() -> Option<String> {
+    let mut scheme: *mut GUID = std::ptr::null_mut();
+    if PowerGetActiveScheme(None, &mut scheme) != ERROR_SUCCESS || scheme.is_null() {
+        return None;
+    }
+    let _guard = scopeguard::guard(scheme, |scheme| {
+        let _ = LocalFree(Some(HLOCAL(scheme as _)));
+    });
+    let scheme = scheme as *const GUID;
+
+    // 第一次调用获取所需缓冲区大小
+    let mut size = 0u32;
+    if PowerReadFriendlyName(None, Some(scheme), None, None, None, &mut size) != ERROR_SUCCESS
+        || size == 0
+    {
+        return None;
+    }
+    let mut buffer = vec![0u8; size as usize];
+    if PowerReadFriendlyName(
+        None,
+        Some(scheme),
+        None,
+        None,
+        Some(buffer.as_mut_ptr()),
+        &mut size,
+    ) != ERROR_SUCCESS
+    {
+        return None;
+    }
+
+    let name = utils::u16_bytes_to_string(&buffer);
+    (!name.is_empty()).then_some(name)
+}
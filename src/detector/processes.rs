@@ -0,0 +1,217 @@
+//! 进程资源占用模块
+//!
+//! 通过 Toolhelp 快照枚举进程，用 GetProcessTimes 与 GetProcessMemoryInfo 读取 CPU 时间与工作集。
+//! 两次采样之间的 CPU 时间差换算为占用率，供实时面板列出占用资源最多的进程。
+
+#[cfg(windows)]
+use crate::utils::string::u16_slice_to_string;
+#[cfg(windows)]
+use std::collections::HashMap;
+use std::time::Duration;
+#[cfg(windows)]
+use std::time::Instant;
+#[cfg(windows)]
+use windows::Win32::Foundation::{CloseHandle, FILETIME, HANDLE};
+#[cfg(windows)]
+use windows::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W, TH32CS_SNAPPROCESS,
+};
+#[cfg(windows)]
+use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+#[cfg(windows)]
+use windows::Win32::System::Threading::{
+    GetProcessTimes, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+};
+
+/// 单个进程的资源占用
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessUsage {
+    /// 进程 ID
+    pub pid: u32,
+    /// 可执行文件名，如 "chrome.exe"
+    pub name: String,
+    /// CPU 占用率（占全部逻辑处理器的百分比）
+    pub cpu_percent: f64,
+    /// 工作集（物理内存占用，B）
+    pub working_set: u64,
+}
+
+/// 将一段时间内的进程 CPU 时间换算为占用率
+///
+/// * `cpu_time` - 该时间段内进程消耗的 CPU 时间（内核态与用户态之和）
+/// * `elapsed` - 时间段长度
+/// * `logical_cpus` - 逻辑处理器数
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::processes::cpu_percent;
+/// use std::time::Duration;
+/// assert_eq!(cpu_percent(Duration::from_millis(500), Duration::from_secs(1), 4), 12.5);
+/// assert_eq!(cpu_percent(Duration::from_secs(8), Duration::from_secs(1), 4), 100.0);
+/// assert_eq!(cpu_percent(Duration::from_secs(1), Duration::ZERO, 4), 0.0);
+/// ```
+pub fn cpu_percent(cpu_time: Duration, elapsed: Duration, logical_cpus: usize) -> f64 {
+    if elapsed.is_zero() || logical_cpus == 0 {
+        return 0.0;
+    }
+    let percent = cpu_time.as_secs_f64() / elapsed.as_secs_f64() / logical_cpus as f64 * 100.0;
+    percent.clamp(0.0, 100.0)
+}
+
+/// CPU 占用最高的若干进程，按占用率从高到低排列
+///
+/// * `processes` - 全部进程
+/// * `count` - 返回的进程数
+pub fn top_by_cpu(processes: &[ProcessUsage], count: usize) -> Vec<ProcessUsage> {
+    let mut sorted = processes.to_vec();
+    sorted.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent));
+    sorted.truncate(count);
+    sorted
+}
+
+/// 内存占用最高的若干进程，按工作集从大到小排列
+///
+/// * `processes` - 全部进程
+/// * `count` - 返回的进程数
+pub fn top_by_memory(processes: &[ProcessUsage], count: usize) -> Vec<ProcessUsage> {
+    let mut sorted = processes.to_vec();
+    sorted.sort_by_key(|process| std::cmp::Reverse(process.working_set));
+    sorted.truncate(count);
+    sorted
+}
+
+/// 进程资源占用采样器
+///
+/// CPU 占用率依据两次采样之间的 CPU 时间差计算，第一次采样时所有进程的占用率为 0
+#[cfg(windows)]
+pub struct ProcessSampler {
+    /// 上一次采样时各进程的 CPU 时间，以进程 ID 与创建时间区分被复用的进程 ID
+    cpu_times: HashMap<(u32, u64), Duration>,
+    /// 上一次采样的时间
+    last_sample: Instant,
+    /// 逻辑处理器数
+    logical_cpus: usize,
+}
+
+#[cfg(windows)]
+impl ProcessSampler {
+    /// 创建采样器
+    pub fn new() -> Self {
+        Self {
+            cpu_times: HashMap::new(),
+            last_sample: Instant::now(),
+            logical_cpus: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+        }
+    }
+
+    /// 采样一次，无权访问的系统进程跳过
+    pub fn sample(&mut self) -> Result<Vec<ProcessUsage>, String> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_sample);
+        self.last_sample = now;
+
+        let mut cpu_times = HashMap::new();
+        let mut processes = Vec::new();
+        for (pid, name) in unsafe { enumerate_processes()? } {
+            let Some(stats) = (unsafe { query_process(pid) }) else {
+                continue;
+            };
+            let key = (pid, stats.creation_time);
+            let cpu_time = match self.cpu_times.get(&key) {
+                Some(last) => stats.cpu_time.saturating_sub(*last),
+                None => Duration::ZERO,
+            };
+            cpu_times.insert(key, stats.cpu_time);
+            processes.push(ProcessUsage {
+                pid,
+                name,
+                cpu_percent: cpu_percent(cpu_time, elapsed, self.logical_cpus),
+                working_set: stats.working_set,
+            });
+        }
+        self.cpu_times = cpu_times;
+        Ok(processes)
+    }
+}
+
+#[cfg(windows)]
+impl Default for ProcessSampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 单次查询到的进程计数
+#[cfg(windows)]
+struct ProcessStats {
+    /// 创建时间（FILETIME 数值）
+    creation_time: u64,
+    /// 累计 CPU 时间
+    cpu_time: Duration,
+    /// 工作集 (B)
+    working_set: u64,
+}
+
+/// 通过 Toolhelp 快照枚举所有进程的 ID 与可执行文件名
+#[cfg(windows)]
+unsafe fn enumerate_processes() -> Result<Vec<(u32, String)>, String> {
+    let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0)
+        .map_err(|e| format!("创建进程快照失败: {}", e))?;
+    let _guard = scopeguard::guard(snapshot, |h| {
+        let _ = CloseHandle(h);
+    });
+
+    let mut entry = PROCESSENTRY32W {
+        dwSize: std::mem::size_of::<PROCESSENTRY32W>() as u32,
+        ..Default::default()
+    };
+    let mut processes = Vec::new();
+    let mut result = Process32FirstW(snapshot, &mut entry);
+    while result.is_ok() {
+        // 进程 ID 0 为系统空闲进程
+        if entry.th32ProcessID != 0 {
+            processes.push((entry.th32ProcessID, u16_slice_to_string(&entry.szExeFile)));
+        }
+        result = Process32NextW(snapshot, &mut entry);
+    }
+    Ok(processes)
+}
+
+/// 读取进程的创建时间、CPU 时间与工作集，无法打开进程时返回 None
+///
+/// * `pid` - 进程 ID
+#[cfg(windows)]
+unsafe fn query_process(pid: u32) -> Option<ProcessStats> {
+    let handle: HANDLE = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+    let _guard = scopeguard::guard(handle, |h| {
+        let _ = CloseHandle(h);
+    });
+
+    let mut creation = FILETIME::default();
+    let mut exit = FILETIME::default();
+    let mut kernel = FILETIME::default();
+    let mut user = FILETIME::default();
+    GetProcessTimes(handle, &mut creation, &mut exit, &mut kernel, &mut user).ok()?;
+
+    let mut counters = PROCESS_MEMORY_COUNTERS {
+        cb: std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32,
+        ..Default::default()
+    };
+    GetProcessMemoryInfo(handle, &mut counters, counters.cb).ok()?;
+
+    // FILETIME 以 100 纳秒为单位
+    let cpu_ticks = filetime_value(&kernel) + filetime_value(&user);
+    Some(ProcessStats {
+        creation_time: filetime_value(&creation),
+        cpu_time: Duration::from_nanos(cpu_ticks.saturating_mul(100)),
+        working_set: counters.WorkingSetSize as u64,
+    })
+}
+
+/// FILETIME 转换为 64 位数值
+#[cfg(windows)]
+fn filetime_value(time: &FILETIME) -> u64 {
+    ((time.dwHighDateTime as u64) << 32) | time.dwLowDateTime as u64
+}
@@ -0,0 +1,330 @@
+//! 安全特性检测模块
+//!
+//! 检测 TPM、安全启动与 BitLocker 状态，这些是升级 Windows 11 的必要条件。
+//! 其中 TPM 详细信息与 BitLocker 状态需要管理员权限。
+
+#[cfg(windows)]
+use crate::detector::DetectionError;
+#[cfg(windows)]
+use crate::utils::registry::read_registry_dword;
+#[cfg(windows)]
+use crate::utils::wmi;
+use std::fmt;
+#[cfg(windows)]
+use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+#[cfg(windows)]
+use windows::Win32::System::TpmBaseServices::{
+    Tbsi_GetDeviceInfo, TPM_DEVICE_INFO, TPM_VERSION_12, TPM_VERSION_20,
+};
+
+/// TPM 信息
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TpmInfo {
+    /// 规范版本，如 "2.0"
+    pub version: String,
+    /// 制造商，如 "INTC"
+    pub manufacturer: String,
+    /// 固件版本
+    pub firmware_version: String,
+    /// 是否已启用，需要管理员权限，未知时为 None
+    pub enabled: Option<bool>,
+    /// 是否已激活，需要管理员权限，未知时为 None
+    pub activated: Option<bool>,
+}
+
+impl Default for TpmInfo {
+    fn default() -> Self {
+        Self {
+            version: "未知".to_string(),
+            manufacturer: "未知".to_string(),
+            firmware_version: "未知".to_string(),
+            enabled: None,
+            activated: None,
+        }
+    }
+}
+
+impl TpmInfo {
+    /// 主版本号，如 2.0 返回 2
+    pub fn major_version(&self) -> Option<u32> {
+        self.version.split('.').next()?.trim().parse().ok()
+    }
+}
+
+/// 安全启动状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum SecureBootState {
+    /// 已开启
+    Enabled,
+    /// 已关闭
+    Disabled,
+    /// 不支持（通常为传统 BIOS 启动）
+    Unsupported,
+}
+
+impl fmt::Display for SecureBootState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SecureBootState::Enabled => "已开启",
+            SecureBootState::Disabled => "已关闭",
+            SecureBootState::Unsupported => "不支持",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// BitLocker 加密状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum BitLockerStatus {
+    /// 未加密
+    Off,
+    /// 已加密并受保护
+    On,
+    /// 已加密但保护已暂停
+    Suspended,
+    /// 正在加密
+    Encrypting,
+    /// 正在解密
+    Decrypting,
+    /// 未知
+    Unknown,
+}
+
+impl BitLockerStatus {
+    /// 根据 Win32_EncryptableVolume 的 ConversionStatus 与 ProtectionStatus 判断状态
+    ///
+    /// * `conversion` - 0 完全解密，1 完全加密，2/4 加密中，3/5 解密中
+    /// * `protection` - 0 保护关闭，1 保护开启
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::security::BitLockerStatus;
+    /// assert_eq!(BitLockerStatus::from_wmi(Some(1), Some(1)), BitLockerStatus::On);
+    /// assert_eq!(BitLockerStatus::from_wmi(Some(1), Some(0)), BitLockerStatus::Suspended);
+    /// assert_eq!(BitLockerStatus::from_wmi(Some(0), Some(0)), BitLockerStatus::Off);
+    /// ```
+    pub fn from_wmi(conversion: Option<u32>, protection: Option<u32>) -> Self {
+        match (conversion, protection) {
+            (Some(0), _) => BitLockerStatus::Off,
+            (Some(1), Some(1)) => BitLockerStatus::On,
+            (Some(1), Some(0)) => BitLockerStatus::Suspended,
+            (Some(2) | Some(4), _) => BitLockerStatus::Encrypting,
+            (Some(3) | Some(5), _) => BitLockerStatus::Decrypting,
+            _ => BitLockerStatus::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for BitLockerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            BitLockerStatus::Off => "未加密",
+            BitLockerStatus::On => "已加密",
+            BitLockerStatus::Suspended => "已加密（保护已暂停）",
+            BitLockerStatus::Encrypting => "正在加密",
+            BitLockerStatus::Decrypting => "正在解密",
+            BitLockerStatus::Unknown => "未知",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 单个卷的加密状态
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct VolumeEncryption {
+    /// 盘符，如 "C:"
+    pub drive_letter: String,
+    /// BitLocker 状态
+    pub status: BitLockerStatus,
+}
+
+/// 安全特性信息
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SecurityInfo {
+    /// TPM 信息，未检测到 TPM 时为 None
+    pub tpm: Option<TpmInfo>,
+    /// 安全启动状态
+    pub secure_boot: SecureBootState,
+    /// 各卷的 BitLocker 状态，无管理员权限无法查询时为 None
+    pub volumes: Option<Vec<VolumeEncryption>>,
+}
+
+impl Default for SecurityInfo {
+    fn default() -> Self {
+        Self {
+            tpm: None,
+            secure_boot: SecureBootState::Unsupported,
+            volumes: None,
+        }
+    }
+}
+
+impl SecurityInfo {
+    /// TPM 概要，如 "TPM 2.0 (INTC)"
+    pub fn tpm_summary(&self) -> String {
+        match &self.tpm {
+            Some(tpm) if tpm.manufacturer != "未知" => {
+                format!("TPM {} ({})", tpm.version, tpm.manufacturer)
+            }
+            Some(tpm) => format!("TPM {}", tpm.version),
+            None => "未检测到".to_string(),
+        }
+    }
+
+    /// BitLocker 概要，如 "C: 已加密, D: 未加密"
+    pub fn bitlocker_summary(&self) -> String {
+        match &self.volumes {
+            Some(volumes) if volumes.is_empty() => "无可加密的卷".to_string(),
+            Some(volumes) => volumes
+                .iter()
+                .map(|v| format!("{} {}", v.drive_letter, v.status))
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => "需要管理员权限".to_string(),
+        }
+    }
+}
+
+/// 检测安全特性
+#[cfg(windows)]
+pub fn detect_security() -> Result<SecurityInfo, DetectionError> {
+    let mut info = SecurityInfo::default();
+
+    unsafe {
+        info.tpm = detect_tpm();
+        info.secure_boot = detect_secure_boot();
+        info.volumes = detect_bitlocker();
+    }
+
+    Ok(info)
+}
+
+/// 通过 TBS 获取 TPM 版本，无需管理员权限；再尝试从 Win32_Tpm 补充详细信息
+#[cfg(windows)]
+unsafe fn detect_tpm() -> Option<TpmInfo> {
+    let mut device_info = TPM_DEVICE_INFO {
+        structVersion: 1,
+        ..Default::default()
+    };
+    let result = Tbsi_GetDeviceInfo(
+        std::mem::size_of::<TPM_DEVICE_INFO>() as u32,
+        &mut device_info as *mut _ as *mut _,
+    );
+    // 非 0 表示未找到 TPM 或 TBS 服务不可用
+    if result != 0 {
+        return None;
+    }
+
+    let mut tpm = TpmInfo::default();
+    tpm.version = match device_info.tpmVersion {
+        TPM_VERSION_12 => "1.2".to_string(),
+        TPM_VERSION_20 => "2.0".to_string(),
+        _ => "未知".to_string(),
+    };
+
+    let config = wmi::WmiConfig {
+        namespace: "ROOT\\CIMV2\\Security\\MicrosoftTpm".to_string(),
+        ..Default::default()
+    };
+    let client = match wmi::WmiClient::connect(&config) {
+        Ok(c) => c,
+        Err(e) => {
+            log::warn!("{}", e);
+            return Some(tpm);
+        }
+    };
+    let mut enumerator = match client.query("SELECT * FROM Win32_Tpm") {
+        Ok(e) => e,
+        Err(e) => {
+            log::warn!("{}", e);
+            return Some(tpm);
+        }
+    };
+
+    if let Some(obj) = enumerator.next() {
+        if let Ok(var) = wmi::get_property(&obj, "ManufacturerIdTxt") {
+            if let Some(manufacturer) = wmi::variant_to_string(&var) {
+                tpm.manufacturer = manufacturer.trim().to_string();
+            }
+        }
+        if let Ok(var) = wmi::get_property(&obj, "ManufacturerVersion") {
+            if let Some(version) = wmi::variant_to_string(&var) {
+                tpm.firmware_version = version;
+            }
+        }
+        tpm.enabled = wmi::get_property(&obj, "IsEnabled_InitialValue")
+            .ok()
+            .and_then(|var| wmi::variant_to_bool(&var));
+        tpm.activated = wmi::get_property(&obj, "IsActivated_InitialValue")
+            .ok()
+            .and_then(|var| wmi::variant_to_bool(&var));
+    }
+
+    Some(tpm)
+}
+
+/// 从注册表读取安全启动状态
+#[cfg(windows)]
+pub(crate) unsafe fn detect_secure_boot() -> SecureBootState {
+    match read_registry_dword(
+        HKEY_LOCAL_MACHINE,
+        r"SYSTEM\CurrentControlSet\Control\SecureBoot\State",
+        "UEFISecureBootEnabled",
+    ) {
+        Some(0) => SecureBootState::Disabled,
+        Some(_) => SecureBootState::Enabled,
+        None => SecureBootState::Unsupported,
+    }
+}
+
+/// 通过 Win32_EncryptableVolume 获取各卷的 BitLocker 状态，需要管理员权限
+#[cfg(windows)]
+unsafe fn detect_bitlocker() -> Option<Vec<VolumeEncryption>> {
+    let config = wmi::WmiConfig {
+        namespace: "ROOT\\CIMV2\\Security\\MicrosoftVolumeEncryption".to_string(),
+        ..Default::default()
+    };
+    let client = match wmi::WmiClient::connect(&config) {
+        Ok(c) => c,
+        Err(e) => {
+            log::warn!("{}", e);
+            return None;
+        }
+    };
+    let mut enumerator = match client.query("SELECT * FROM Win32_EncryptableVolume") {
+        Ok(e) => e,
+        Err(e) => {
+            log::warn!("{}", e);
+            return None;
+        }
+    };
+
+    let mut volumes = Vec::new();
+    while let Some(obj) = enumerator.next() {
+        // 没有盘符的卷（如恢复分区）不显示
+        let Some(drive_letter) = wmi::get_property(&obj, "DriveLetter")
+            .ok()
+            .and_then(|var| wmi::variant_to_string(&var))
+        else {
+            continue;
+        };
+        let conversion = wmi::get_property(&obj, "ConversionStatus")
+            .ok()
+            .and_then(|var| wmi::variant_to_u32(&var));
+        let protection = wmi::get_property(&obj, "ProtectionStatus")
+            .ok()
+            .and_then(|var| wmi::variant_to_u32(&var));
+        volumes.push(VolumeEncryption {
+            drive_letter,
+            status: BitLockerStatus::from_wmi(conversion, protection),
+        });
+    }
+    volumes.sort_by(|a, b| a.drive_letter.cmp(&b.drive_letter));
+
+    Some(volumes)
+}
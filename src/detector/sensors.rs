@@ -0,0 +1,308 @@
+//! 传感器模块
+//!
+//! 尽力读取温度、风扇转速与电压：ACPI 热区（MSAcpi_ThermalZoneTemperature）、处理器核心电压
+//! （Win32_Processor 的 CurrentVoltage）、主板提供的风扇与电压探针（Win32_Fan、Win32_VoltageProbe），
+//! 以及 HP 等厂商 BIOS 通过 WMI 公开的数值传感器。
+//!
+//! 读取嵌入式控制器（EC）的 IO 端口需要内核驱动，且不同机型的寄存器布局不同，误写可能导致关机或风扇失控，
+//! 因此不直接访问 EC。各来源在多数电脑上只有部分可用，读取不到的传感器不会出现在结果中。
+
+#[cfg(windows)]
+use super::battery::temperature_from_tenths_kelvin;
+#[cfg(windows)]
+use crate::utils::wmi;
+#[cfg(windows)]
+use crate::wmi_row;
+use std::fmt;
+
+/// 传感器类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum SensorKind {
+    /// 温度 (°C)
+    Temperature,
+    /// 风扇转速 (RPM)
+    Fan,
+    /// 电压 (V)
+    Voltage,
+}
+
+impl SensorKind {
+    /// 由 CIM_NumericSensor 的 BaseUnits 判断传感器类型，不支持的单位返回 None
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::sensors::SensorKind;
+    /// assert_eq!(SensorKind::from_base_units(2), Some(SensorKind::Temperature));
+    /// assert_eq!(SensorKind::from_base_units(5), Some(SensorKind::Voltage));
+    /// assert_eq!(SensorKind::from_base_units(19), Some(SensorKind::Fan));
+    /// assert_eq!(SensorKind::from_base_units(6), None);
+    /// ```
+    pub fn from_base_units(base_units: u32) -> Option<Self> {
+        match base_units {
+            2 => Some(SensorKind::Temperature),
+            5 => Some(SensorKind::Voltage),
+            19 => Some(SensorKind::Fan),
+            _ => None,
+        }
+    }
+
+    /// 单位
+    pub fn unit(&self) -> &'static str {
+        match self {
+            SensorKind::Temperature => "°C",
+            SensorKind::Fan => "RPM",
+            SensorKind::Voltage => "V",
+        }
+    }
+}
+
+impl fmt::Display for SensorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SensorKind::Temperature => "温度",
+            SensorKind::Fan => "风扇",
+            SensorKind::Voltage => "电压",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 单个传感器读数
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SensorReading {
+    /// 传感器名称，如 "CPU Fan"、"TZ00"
+    pub name: String,
+    /// 传感器类型
+    pub kind: SensorKind,
+    /// 读数，单位见 [`SensorKind::unit`]
+    pub value: f64,
+    /// 数据来源，如 "ACPI 热区"
+    pub source: String,
+}
+
+impl SensorReading {
+    /// 带单位的读数
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::sensors::{SensorKind, SensorReading};
+    /// let reading = |kind, value| SensorReading {
+    ///     name: "CPU".to_string(),
+    ///     kind,
+    ///     value,
+    ///     source: "BIOS".to_string(),
+    /// };
+    /// assert_eq!(reading(SensorKind::Fan, 2150.0).value_desc(), "2150 RPM");
+    /// assert_eq!(reading(SensorKind::Voltage, 1.2).value_desc(), "1.20 V");
+    /// assert_eq!(reading(SensorKind::Temperature, 47.26).value_desc(), "47.3 °C");
+    /// ```
+    pub fn value_desc(&self) -> String {
+        match self.kind {
+            SensorKind::Temperature => format!("{:.1} {}", self.value, self.kind.unit()),
+            SensorKind::Fan => format!("{:.0} {}", self.value, self.kind.unit()),
+            SensorKind::Voltage => format!("{:.2} {}", self.value, self.kind.unit()),
+        }
+    }
+}
+
+/// 解析 Win32_Processor 的 CurrentVoltage
+///
+/// 位 7 置位时，低 7 位为电压乘以 10；否则为支持的电压档位掩码，不是当前电压
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::sensors::processor_voltage;
+/// assert_eq!(processor_voltage(0x80 | 12), Some(1.2));
+/// assert_eq!(processor_voltage(0x02), None);
+/// assert_eq!(processor_voltage(0x80), None);
+/// ```
+pub fn processor_voltage(current_voltage: u16) -> Option<f64> {
+    let tenths = current_voltage & 0x7F;
+    (current_voltage & 0x80 != 0 && tenths > 0).then(|| tenths as f64 / 10.0)
+}
+
+/// 按 CIM_NumericSensor 的 UnitModifier（10 的幂）换算读数
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::sensors::scale_reading;
+/// assert_eq!(scale_reading(1250, -3), 1.25);
+/// assert_eq!(scale_reading(2400, 0), 2400.0);
+/// ```
+pub fn scale_reading(value: i64, unit_modifier: i32) -> f64 {
+    value as f64 * 10f64.powi(unit_modifier)
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// MSAcpi_ThermalZoneTemperature 中用到的属性
+    struct ThermalZoneRow {
+        instance_name: String = "InstanceName",
+        current_temperature: u32 = "CurrentTemperature",
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_Processor 中用到的属性
+    struct ProcessorVoltageRow {
+        device_id: String = "DeviceID",
+        current_voltage: u16 = "CurrentVoltage",
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_Fan 中用到的属性
+    struct FanRow {
+        name: String = "Name",
+        desired_speed: u64 = "DesiredSpeed",
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_VoltageProbe 中用到的属性
+    struct VoltageProbeRow {
+        name: String = "Name",
+        current_reading: i32 = "CurrentReading",
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// HPBIOS_BIOSNumericSensor 中用到的属性
+    struct NumericSensorRow {
+        name: String = "Name",
+        current_reading: u32 = "CurrentReading",
+        base_units: u32 = "BaseUnits",
+        unit_modifier: i32 = "UnitModifier",
+    }
+}
+
+/// 读取所有可用的传感器，按类型排列
+///
+/// 每个来源单独查询，查询失败的来源只记录调试日志
+#[cfg(windows)]
+pub fn read_sensors() -> Vec<SensorReading> {
+    let mut readings = Vec::new();
+    readings.extend(read_thermal_zones());
+    readings.extend(read_processor_voltage());
+    readings.extend(read_cim_probes());
+    readings.extend(read_vendor_sensors());
+    readings.sort_by_key(|reading| reading.kind);
+    readings
+}
+
+/// 在指定命名空间中查询，失败时记录调试日志并返回空列表
+#[cfg(windows)]
+fn query<T: wmi::FromWmiRow>(namespace: &str, query: &str) -> Vec<T> {
+    let config = wmi::WmiConfig {
+        namespace: namespace.to_string(),
+        ..Default::default()
+    };
+    wmi::WmiClient::connect(&config)
+        .and_then(|client| client.query_as(query))
+        .unwrap_or_else(|e| {
+            log::debug!("读取传感器失败 ({}): {}", namespace, e);
+            Vec::new()
+        })
+}
+
+/// 读取 ACPI 热区温度（通常需要管理员权限）
+#[cfg(windows)]
+fn read_thermal_zones() -> Vec<SensorReading> {
+    let rows: Vec<ThermalZoneRow> = query(
+        "ROOT\\WMI",
+        "SELECT InstanceName, CurrentTemperature FROM MSAcpi_ThermalZoneTemperature",
+    );
+    rows.into_iter()
+        .filter_map(|row| {
+            let value = temperature_from_tenths_kelvin(row.current_temperature?)?;
+            // 实例名形如 "ACPI\ThermalZone\TZ00_0"，只保留热区名
+            let name = row
+                .instance_name
+                .as_deref()
+                .and_then(|name| name.rsplit('\\').next())
+                .map(|name| name.trim_end_matches("_0").to_string())
+                .unwrap_or_else(|| "热区".to_string());
+            Some(SensorReading {
+                name,
+                kind: SensorKind::Temperature,
+                value,
+                source: "ACPI 热区".to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 读取处理器核心电压
+#[cfg(windows)]
+fn read_processor_voltage() -> Vec<SensorReading> {
+    let rows: Vec<ProcessorVoltageRow> = query(
+        "ROOT\\CIMV2",
+        "SELECT DeviceID, CurrentVoltage FROM Win32_Processor",
+    );
+    rows.into_iter()
+        .filter_map(|row| {
+            Some(SensorReading {
+                name: row.device_id.unwrap_or_else(|| "处理器".to_string()),
+                kind: SensorKind::Voltage,
+                value: processor_voltage(row.current_voltage?)?,
+                source: "SMBIOS".to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 读取主板通过 CIM 公开的风扇转速与电压探针，多数主板不提供
+#[cfg(windows)]
+fn read_cim_probes() -> Vec<SensorReading> {
+    let fans: Vec<FanRow> = query("ROOT\\CIMV2", "SELECT Name, DesiredSpeed FROM Win32_Fan");
+    let probes: Vec<VoltageProbeRow> = query(
+        "ROOT\\CIMV2",
+        "SELECT Name, CurrentReading FROM Win32_VoltageProbe",
+    );
+    let fans = fans.into_iter().filter_map(|row| {
+        let speed = row.desired_speed.filter(|speed| *speed > 0)?;
+        Some(SensorReading {
+            name: row.name.unwrap_or_else(|| "风扇".to_string()),
+            kind: SensorKind::Fan,
+            value: speed as f64,
+            source: "Win32_Fan".to_string(),
+        })
+    });
+    // CurrentReading 单位为毫伏
+    let probes = probes.into_iter().filter_map(|row| {
+        let millivolts = row.current_reading.filter(|value| *value > 0)?;
+        Some(SensorReading {
+            name: row.name.unwrap_or_else(|| "电压".to_string()),
+            kind: SensorKind::Voltage,
+            value: scale_reading(millivolts as i64, -3),
+            source: "Win32_VoltageProbe".to_string(),
+        })
+    });
+    fans.chain(probes).collect()
+}
+
+/// 读取厂商 BIOS 公开的数值传感器（目前支持 HP 商用机型）
+#[cfg(windows)]
+fn read_vendor_sensors() -> Vec<SensorReading> {
+    let rows: Vec<NumericSensorRow> = query(
+        "ROOT\\HP\\InstrumentedBIOS",
+        "SELECT Name, CurrentReading, BaseUnits, UnitModifier FROM HPBIOS_BIOSNumericSensor",
+    );
+    rows.into_iter()
+        .filter_map(|row| {
+            let kind = SensorKind::from_base_units(row.base_units?)?;
+            Some(SensorReading {
+                name: row.name.unwrap_or_else(|| kind.to_string()),
+                kind,
+                value: scale_reading(row.current_reading? as i64, row.unit_modifier.unwrap_or(0)),
+                source: "HP BIOS".to_string(),
+            })
+        })
+        .collect()
+}
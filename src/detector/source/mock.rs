@@ -0,0 +1,155 @@
+//! 模拟数据源
+//!
+//! 按查询语句、注册表路径与设备类预设返回的数据，用于在没有 Windows 硬件的环境中测试检测逻辑
+
+use super::{
+    DataRow, DeviceClass, DeviceRecord, DeviceSource, RegistryRoot, RegistrySource, WmiSource,
+};
+use crate::detector::DetectionError;
+use std::collections::HashMap;
+
+/// 预设的注册表值
+#[derive(Debug, Clone)]
+enum RegistryValue {
+    String(String),
+    Dword(u32),
+}
+
+/// 模拟数据源
+///
+/// 未预设的 WMI 查询返回空结果，未预设的注册表值返回 None，未预设的设备类返回空列表，
+/// 与真实系统中查不到数据时的表现一致
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::source::{
+///     DataRow, DeviceClass, DeviceRecord, DeviceSource, MockSource, RegistryRoot,
+///     RegistrySource, WmiSource, CIMV2_NAMESPACE,
+/// };
+/// let source = MockSource::new()
+///     .with_wmi("SELECT * FROM Win32_BIOS", vec![DataRow::new().with("Version", "1.0")])
+///     .with_registry_dword(RegistryRoot::LocalMachine, "HARDWARE\\Test", "~MHz", 3600)
+///     .with_device(DeviceClass::System, "PCI\\VEN_8086&DEV_7A84\\0", "LPC Controller");
+///
+/// let rows = source.wmi_query(CIMV2_NAMESPACE, "select * from Win32_BIOS").unwrap();
+/// assert_eq!(rows[0].get::<String>("Version").as_deref(), Some("1.0"));
+/// assert_eq!(
+///     source.registry_dword(RegistryRoot::LocalMachine, "hardware\\test", "~MHz"),
+///     Some(3600)
+/// );
+/// assert_eq!(source.class_devices(DeviceClass::System).unwrap().len(), 1);
+/// assert!(source.class_devices(DeviceClass::Display).unwrap().is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockSource {
+    /// (命名空间, 查询语句) -> 查询结果，均为小写
+    wmi: HashMap<(String, String), Result<Vec<DataRow>, DetectionError>>,
+    /// (根键, 小写路径, 小写值名称) -> 值
+    registry: HashMap<(RegistryRoot, String, String), RegistryValue>,
+    /// 各设备类中的设备
+    devices: HashMap<DeviceClass, Vec<DeviceRecord>>,
+}
+
+impl MockSource {
+    /// 创建不含任何数据的数据源
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 预设 ROOT\CIMV2 中查询语句的结果，查询语句不区分大小写
+    pub fn with_wmi(self, query: &str, rows: Vec<DataRow>) -> Self {
+        self.with_wmi_in(super::CIMV2_NAMESPACE, query, Ok(rows))
+    }
+
+    /// 预设查询失败，用于测试超时等错误的处理
+    pub fn with_wmi_error(self, query: &str, error: DetectionError) -> Self {
+        self.with_wmi_in(super::CIMV2_NAMESPACE, query, Err(error))
+    }
+
+    /// 预设指定命名空间中查询语句的结果
+    pub fn with_wmi_in(
+        mut self,
+        namespace: &str,
+        query: &str,
+        result: Result<Vec<DataRow>, DetectionError>,
+    ) -> Self {
+        self.wmi.insert(wmi_key(namespace, query), result);
+        self
+    }
+
+    /// 预设注册表字符串值，路径与值名称不区分大小写
+    pub fn with_registry_string(
+        mut self,
+        root: RegistryRoot,
+        key: &str,
+        value: &str,
+        data: &str,
+    ) -> Self {
+        self.registry.insert(
+            registry_key(root, key, value),
+            RegistryValue::String(data.to_string()),
+        );
+        self
+    }
+
+    /// 预设注册表 32 位整数值，路径与值名称不区分大小写
+    pub fn with_registry_dword(
+        mut self,
+        root: RegistryRoot,
+        key: &str,
+        value: &str,
+        data: u32,
+    ) -> Self {
+        self.registry
+            .insert(registry_key(root, key, value), RegistryValue::Dword(data));
+        self
+    }
+
+    /// 预设设备类中的设备
+    pub fn with_device(mut self, class: DeviceClass, instance_id: &str, description: &str) -> Self {
+        self.devices.entry(class).or_default().push(DeviceRecord {
+            instance_id: instance_id.to_string(),
+            description: Some(description.to_string()),
+        });
+        self
+    }
+}
+
+fn wmi_key(namespace: &str, query: &str) -> (String, String) {
+    (namespace.to_lowercase(), query.trim().to_lowercase())
+}
+
+fn registry_key(root: RegistryRoot, key: &str, value: &str) -> (RegistryRoot, String, String) {
+    (root, key.to_lowercase(), value.to_lowercase())
+}
+
+impl WmiSource for MockSource {
+    fn wmi_query(&self, namespace: &str, query: &str) -> Result<Vec<DataRow>, DetectionError> {
+        self.wmi
+            .get(&wmi_key(namespace, query))
+            .cloned()
+            .unwrap_or_else(|| Ok(Vec::new()))
+    }
+}
+
+impl RegistrySource for MockSource {
+    fn registry_string(&self, root: RegistryRoot, key: &str, value: &str) -> Option<String> {
+        match self.registry.get(&registry_key(root, key, value))? {
+            RegistryValue::String(data) => Some(data.clone()),
+            RegistryValue::Dword(_) => None,
+        }
+    }
+
+    fn registry_dword(&self, root: RegistryRoot, key: &str, value: &str) -> Option<u32> {
+        match self.registry.get(&registry_key(root, key, value))? {
+            RegistryValue::Dword(data) => Some(*data),
+            RegistryValue::String(_) => None,
+        }
+    }
+}
+
+impl DeviceSource for MockSource {
+    fn class_devices(&self, class: DeviceClass) -> Result<Vec<DeviceRecord>, DetectionError> {
+        Ok(self.devices.get(&class).cloned().unwrap_or_default())
+    }
+}
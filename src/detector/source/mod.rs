@@ -0,0 +1,365 @@
+//! 系统数据源模块
+//!
+//! 将检测器依赖的 WMI 查询、注册表读取与 SetupAPI 设备枚举抽象为 [`WmiSource`]、
+//! [`RegistrySource`]、[`DeviceSource`] 三个 trait。Windows 上由 [`WindowsSource`] 调用系统接口；
+//! 测试中使用 [`MockSource`] 提供预设的数据，解析、分类与名称生成等检测逻辑无需真实硬件即可验证。
+//!
+//! [`SourceBackend`] 基于数据源实现内存、主板与处理器的检测后端，
+//! 依赖其他系统接口（SMBIOS、CPUID 等）的部分只在 Windows 的检测函数中补充
+
+mod mock;
+#[cfg(windows)]
+mod windows;
+
+pub use mock::MockSource;
+#[cfg(windows)]
+pub use windows::WindowsSource;
+
+use super::backend::{
+    AudioBackend, BatteryBackend, CpuBackend, DiskBackend, GpuBackend, MemoryBackend,
+    MonitorBackend, MotherboardBackend, NetworkBackend, SecurityBackend, SystemBackend,
+};
+use super::{cpu, memory, motherboard, CpuInfo, DetectionError, MemoryInfo, MotherboardInfo};
+
+/// 默认的 WMI 命名空间
+pub const CIMV2_NAMESPACE: &str = "ROOT\\CIMV2";
+
+/// 数据源返回的属性值
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// 空值
+    Null,
+    /// 布尔值
+    Bool(bool),
+    /// 有符号整数
+    Int(i64),
+    /// 无符号整数
+    UInt(u64),
+    /// 浮点数
+    Float(f64),
+    /// 文本，WMI 的 uint64 等类型也以文本返回
+    Text(String),
+    /// 16 位整数数组，如显示器 EDID 中的名称
+    U16Array(Vec<u16>),
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Int(value as i64)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Int(value)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Value::UInt(value as u64)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::UInt(value)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl From<Vec<u16>> for Value {
+    fn from(value: Vec<u16>) -> Self {
+        Value::U16Array(value)
+    }
+}
+
+impl Value {
+    /// 整数值，文本形式的整数同样可以读取
+    fn as_i128(&self) -> Option<i128> {
+        match self {
+            Value::Int(value) => Some(*value as i128),
+            Value::UInt(value) => Some(*value as i128),
+            Value::Text(text) => text.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// 可以从 [`Value`] 读取的属性类型，转换规则与 WMI 的 VARIANT 读取一致
+pub trait FromValue: Sized {
+    /// 读取属性值，类型无法转换时返回 None
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Text(text) => Some(text.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// 宏：整数类型按范围转换
+macro_rules! integer_from_value {
+    ($($ty:ty),*) => {
+        $(
+            impl FromValue for $ty {
+                fn from_value(value: &Value) -> Option<Self> {
+                    value.as_i128().and_then(|value| <$ty>::try_from(value).ok())
+                }
+            }
+        )*
+    };
+}
+
+integer_from_value!(u8, u16, u64, i32, i64);
+
+impl FromValue for u32 {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            // WMI 的 uint32 以 32 位有符号整数返回，超过 i32 范围的值需要按位还原
+            Value::Int(value) if (i32::MIN as i64..0).contains(value) => Some(*value as i32 as u32),
+            value => value.as_i128().and_then(|value| u32::try_from(value).ok()),
+        }
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Float(value) => Some(*value),
+            value => value.as_i128().map(|value| value as f64),
+        }
+    }
+}
+
+impl FromValue for Vec<u16> {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::U16Array(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// 一行查询结果，属性名不区分大小写
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::source::DataRow;
+/// let row = DataRow::new()
+///     .with("Capacity", "8589934592")
+///     .with("SMBIOSMemoryType", 26u32);
+/// assert_eq!(row.get::<u64>("capacity"), Some(8589934592));
+/// assert_eq!(row.get::<u32>("SMBIOSMemoryType"), Some(26));
+/// assert_eq!(row.get::<String>("SMBIOSMemoryType"), None);
+/// assert_eq!(row.get::<String>("Manufacturer"), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataRow {
+    properties: Vec<(String, Value)>,
+}
+
+impl DataRow {
+    /// 创建空行
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加属性
+    pub fn with(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.properties.push((name.to_string(), value.into()));
+        self
+    }
+
+    /// 读取属性，属性不存在、值为空或类型无法转换时返回 None
+    pub fn get<T: FromValue>(&self, name: &str) -> Option<T> {
+        self.properties
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| T::from_value(value))
+    }
+
+    /// 全部属性
+    pub fn properties(&self) -> &[(String, Value)] {
+        &self.properties
+    }
+}
+
+impl FromIterator<(String, Value)> for DataRow {
+    fn from_iter<I: IntoIterator<Item = (String, Value)>>(iter: I) -> Self {
+        Self {
+            properties: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// 可以从 [`DataRow`] 构造的类型，通常由 [`crate::wmi_row!`] 宏生成实现
+pub trait FromDataRow: Sized {
+    /// 从一行查询结果中读取属性
+    fn from_data_row(row: &DataRow) -> Self;
+}
+
+/// 注册表根键
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegistryRoot {
+    /// HKEY_LOCAL_MACHINE
+    LocalMachine,
+    /// HKEY_CURRENT_USER
+    CurrentUser,
+}
+
+/// 设备安装类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeviceClass {
+    /// 系统设备（芯片组桥、SMBus 控制器等）
+    System,
+    /// 显示适配器
+    Display,
+    /// 声音、视频和游戏控制器
+    Media,
+    /// 电池
+    Battery,
+}
+
+/// 枚举到的设备
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeviceRecord {
+    /// 设备实例 ID
+    pub instance_id: String,
+    /// 设备描述，驱动未提供时为 None
+    pub description: Option<String>,
+}
+
+/// WMI 查询数据源
+pub trait WmiSource {
+    /// 执行 WQL 查询
+    ///
+    /// * `namespace` - 命名空间，如 [`CIMV2_NAMESPACE`]
+    /// * `query` - WQL 查询语句
+    fn wmi_query(&self, namespace: &str, query: &str) -> Result<Vec<DataRow>, DetectionError>;
+
+    /// 执行 WQL 查询并将每行转换为属性结构体
+    fn wmi_query_as<T: FromDataRow>(
+        &self,
+        namespace: &str,
+        query: &str,
+    ) -> Result<Vec<T>, DetectionError>
+    where
+        Self: Sized,
+    {
+        let rows = self.wmi_query(namespace, query)?;
+        Ok(rows.iter().map(T::from_data_row).collect())
+    }
+}
+
+/// 注册表数据源
+pub trait RegistrySource {
+    /// 读取字符串值，键或值不存在时返回 None
+    fn registry_string(&self, root: RegistryRoot, key: &str, value: &str) -> Option<String>;
+
+    /// 读取 32 位整数值，键或值不存在时返回 None
+    fn registry_dword(&self, root: RegistryRoot, key: &str, value: &str) -> Option<u32>;
+}
+
+/// SetupAPI 设备枚举数据源
+pub trait DeviceSource {
+    /// 枚举指定安装类中当前存在的设备
+    fn class_devices(&self, class: DeviceClass) -> Result<Vec<DeviceRecord>, DetectionError>;
+}
+
+/// 完整的系统数据源，实现全部数据源 trait 的类型自动实现
+pub trait SystemSource: WmiSource + RegistrySource + DeviceSource + Send + Sync {}
+
+impl<T> SystemSource for T where T: WmiSource + RegistrySource + DeviceSource + Send + Sync {}
+
+/// 基于数据源的检测后端，支持内存、主板与处理器，其余类别返回不支持
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::backend::MemoryBackend;
+/// use hardware_master::detector::source::{DataRow, MockSource, SourceBackend};
+/// let source = MockSource::new().with_wmi(
+///     "SELECT * FROM Win32_PhysicalMemory",
+///     vec![DataRow::new().with("Capacity", "8589934592")],
+/// );
+/// let memory = SourceBackend::new(source).detect_memory().unwrap();
+/// assert_eq!(memory.slots.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SourceBackend<S> {
+    source: S,
+}
+
+impl<S: SystemSource> SourceBackend<S> {
+    /// 使用指定数据源创建后端
+    pub fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// 数据源
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+}
+
+impl<S: SystemSource> CpuBackend for SourceBackend<S> {
+    fn detect_cpu(&self) -> Result<CpuInfo, DetectionError> {
+        Ok(cpu::detect_cpu_with(&self.source))
+    }
+}
+
+impl<S: SystemSource> MemoryBackend for SourceBackend<S> {
+    fn detect_memory(&self) -> Result<MemoryInfo, DetectionError> {
+        memory::detect_memory_with(&self.source)
+    }
+}
+
+impl<S: SystemSource> MotherboardBackend for SourceBackend<S> {
+    fn detect_motherboard(&self) -> Result<MotherboardInfo, DetectionError> {
+        motherboard::detect_motherboard_with(&self.source)
+    }
+}
+
+impl<S: SystemSource> SystemBackend for SourceBackend<S> {}
+impl<S: SystemSource> GpuBackend for SourceBackend<S> {}
+impl<S: SystemSource> DiskBackend for SourceBackend<S> {}
+impl<S: SystemSource> NetworkBackend for SourceBackend<S> {}
+impl<S: SystemSource> AudioBackend for SourceBackend<S> {}
+impl<S: SystemSource> MonitorBackend for SourceBackend<S> {}
+impl<S: SystemSource> BatteryBackend for SourceBackend<S> {}
+impl<S: SystemSource> SecurityBackend for SourceBackend<S> {}
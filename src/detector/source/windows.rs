@@ -0,0 +1,102 @@
+//! Windows 系统数据源
+//!
+//! 通过 WMI、注册表与 SetupAPI 读取真实系统数据
+
+use super::{
+    DataRow, DeviceClass, DeviceRecord, DeviceSource, RegistryRoot, RegistrySource, WmiSource,
+};
+use crate::detector::DetectionError;
+use crate::utils::{device, registry, wmi};
+use windows::core::GUID;
+use windows::Win32::Devices::DeviceAndDriverInstallation::{
+    SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInfo, DIGCF_PRESENT, GUID_DEVCLASS_BATTERY,
+    GUID_DEVCLASS_DISPLAY, GUID_DEVCLASS_MEDIA, GUID_DEVCLASS_SYSTEM, SPDRP_DEVICEDESC,
+    SP_DEVINFO_DATA,
+};
+use windows::Win32::System::Registry::{HKEY, HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+
+/// Windows 系统数据源，WMI 连接由 [`wmi::WmiClient`] 的连接缓存复用
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WindowsSource;
+
+impl WmiSource for WindowsSource {
+    fn wmi_query(&self, namespace: &str, query: &str) -> Result<Vec<DataRow>, DetectionError> {
+        let config = wmi::WmiConfig {
+            namespace: namespace.to_string(),
+            ..Default::default()
+        };
+        let client = wmi::WmiClient::connect(&config)?;
+        Ok(client
+            .query_rows(query)?
+            .iter()
+            .map(wmi::WmiRow::to_data_row)
+            .collect())
+    }
+}
+
+fn root_key(root: RegistryRoot) -> HKEY {
+    match root {
+        RegistryRoot::LocalMachine => HKEY_LOCAL_MACHINE,
+        RegistryRoot::CurrentUser => HKEY_CURRENT_USER,
+    }
+}
+
+impl RegistrySource for WindowsSource {
+    fn registry_string(&self, root: RegistryRoot, key: &str, value: &str) -> Option<String> {
+        unsafe { registry::read_registry_string(root_key(root), key, value) }
+    }
+
+    fn registry_dword(&self, root: RegistryRoot, key: &str, value: &str) -> Option<u32> {
+        unsafe { registry::read_registry_dword(root_key(root), key, value) }
+    }
+}
+
+fn class_guid(class: DeviceClass) -> GUID {
+    match class {
+        DeviceClass::System => GUID_DEVCLASS_SYSTEM,
+        DeviceClass::Display => GUID_DEVCLASS_DISPLAY,
+        DeviceClass::Media => GUID_DEVCLASS_MEDIA,
+        DeviceClass::Battery => GUID_DEVCLASS_BATTERY,
+    }
+}
+
+impl DeviceSource for WindowsSource {
+    fn class_devices(&self, class: DeviceClass) -> Result<Vec<DeviceRecord>, DetectionError> {
+        unsafe {
+            let guid = class_guid(class);
+            let device_info_set = device::get_device_info_set(&guid, DIGCF_PRESENT)?;
+            let _guard = scopeguard::guard(device_info_set, |set| {
+                let _ = SetupDiDestroyDeviceInfoList(set);
+            });
+
+            let mut devices = Vec::new();
+            let mut index = 0;
+            loop {
+                let mut device_info_data = SP_DEVINFO_DATA {
+                    cbSize: std::mem::size_of::<SP_DEVINFO_DATA>() as u32,
+                    ..Default::default()
+                };
+                if SetupDiEnumDeviceInfo(device_info_set, index, &mut device_info_data).is_err() {
+                    break;
+                }
+                index += 1;
+
+                let Ok(instance_id) =
+                    device::get_device_instance_id(device_info_set, &device_info_data)
+                else {
+                    continue;
+                };
+                let description = device::get_device_property(
+                    device_info_set,
+                    &device_info_data,
+                    SPDRP_DEVICEDESC,
+                );
+                devices.push(DeviceRecord {
+                    instance_id,
+                    description,
+                });
+            }
+            Ok(devices)
+        }
+    }
+}
@@ -1,19 +1,190 @@
+#[cfg(windows)]
+use crate::detector::hypervisor;
+use crate::detector::hypervisor::Hypervisor;
+#[cfg(windows)]
 use crate::detector::DetectionError;
+#[cfg(windows)]
+use crate::utils::registry::{read_registry_dword, read_registry_string};
+#[cfg(windows)]
+use crate::utils::smbios;
+use crate::utils::smbios::SmbiosStructure;
+#[cfg(windows)]
 use crate::utils::wmi;
+#[cfg(windows)]
+use crate::utils::wmi_date::parse_wmi_datetime;
+#[cfg(windows)]
+use crate::wmi_row;
+use std::fmt;
+#[cfg(windows)]
+use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+
+/// 系统版本信息所在的注册表项
+#[cfg(windows)]
+const CURRENT_VERSION_KEY: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion";
+
+/// Windows 授权在 SoftwareLicensingProduct 中的应用 ID
+#[cfg(windows)]
+const WINDOWS_APPLICATION_ID: &str = "55c92734-d682-4d71-983e-d6ec3f16059f";
 
 /// 电脑类型
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum ComputerType {
     /// 笔记本
     Laptop,
+    /// 二合一（平板、可翻转、可拆卸）
+    Convertible,
     /// 台式机
     Desktop,
+    /// 塔式机（含迷你塔式）
+    MiniTower,
+    /// 迷你主机
+    MiniPc,
+    /// 服务器
+    Server,
+    /// 未知
+    Unknown,
+}
+
+impl ComputerType {
+    /// 由 SMBIOS 机箱信息（类型 3）中的机箱类型转换
+    ///
+    /// * `chassis_type` - 机箱类型，最高位（机箱锁标志）需预先去除
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::ComputerType;
+    /// assert_eq!(ComputerType::from_chassis_type(10), ComputerType::Laptop);
+    /// assert_eq!(ComputerType::from_chassis_type(31), ComputerType::Convertible);
+    /// assert_eq!(ComputerType::from_chassis_type(6), ComputerType::MiniTower);
+    /// assert_eq!(ComputerType::from_chassis_type(35), ComputerType::MiniPc);
+    /// assert_eq!(ComputerType::from_chassis_type(23), ComputerType::Server);
+    /// assert_eq!(ComputerType::from_chassis_type(2), ComputerType::Unknown);
+    /// ```
+    pub fn from_chassis_type(chassis_type: u8) -> Self {
+        match chassis_type {
+            // Portable / Laptop / Notebook / Hand Held / Sub Notebook
+            8..=11 | 14 => ComputerType::Laptop,
+            // Tablet / Convertible / Detachable
+            30..=32 => ComputerType::Convertible,
+            // Desktop / Low Profile Desktop / Pizza Box / All in One / Space-saving / Lunch Box / Sealed-case PC
+            3..=5 | 13 | 15 | 16 | 24 => ComputerType::Desktop,
+            // Mini Tower / Tower
+            6 | 7 => ComputerType::MiniTower,
+            // Embedded PC / Mini PC / Stick PC
+            34..=36 => ComputerType::MiniPc,
+            // Main Server Chassis / Rack Mount / Multi-system / Blade / Blade Enclosure
+            17 | 23 | 25 | 28 | 29 => ComputerType::Server,
+            _ => ComputerType::Unknown,
+        }
+    }
+
+    /// 由 Win32_ComputerSystem 的 PCSystemType 转换
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::ComputerType;
+    /// assert_eq!(ComputerType::from_pc_system_type(2), ComputerType::Laptop);
+    /// assert_eq!(ComputerType::from_pc_system_type(4), ComputerType::Server);
+    /// ```
+    pub fn from_pc_system_type(pc_system_type: u16) -> Self {
+        match pc_system_type {
+            // Desktop / Workstation
+            1 | 3 => ComputerType::Desktop,
+            // Mobile
+            2 => ComputerType::Laptop,
+            // Enterprise Server / SOHO Server / Performance Server
+            4 | 5 | 7 => ComputerType::Server,
+            _ => ComputerType::Unknown,
+        }
+    }
+
+    /// 是否为使用电池的便携设备（笔记本、二合一）
+    pub fn is_portable(&self) -> bool {
+        matches!(self, ComputerType::Laptop | ComputerType::Convertible)
+    }
+}
+
+impl fmt::Display for ComputerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ComputerType::Laptop => "笔记本",
+            ComputerType::Convertible => "二合一",
+            ComputerType::Desktop => "台式机",
+            ComputerType::MiniTower => "塔式机",
+            ComputerType::MiniPc => "迷你主机",
+            ComputerType::Server => "服务器",
+            ComputerType::Unknown => "未知",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 在 SMBIOS 结构中查找机箱类型（机箱信息偏移 0x05，去除机箱锁标志位）
+///
+/// * `structures` - 解析后的 SMBIOS 结构
+pub fn chassis_type(structures: &[SmbiosStructure]) -> Option<u8> {
+    structures
+        .iter()
+        .find(|structure| structure.kind == 3)
+        .and_then(|structure| structure.byte(0x05))
+        .map(|value| value & 0x7F)
+}
+
+/// Windows 激活状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActivationStatus {
+    /// 已激活
+    Licensed,
+    /// 未激活
+    Unlicensed,
+    /// 处于宽限期
+    Grace,
+    /// 通知模式（激活失效或非正版）
+    Notification,
     /// 未知
+    #[default]
     Unknown,
 }
 
+impl ActivationStatus {
+    /// 由 SoftwareLicensingProduct 的 LicenseStatus 转换
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::system::ActivationStatus;
+    /// assert_eq!(ActivationStatus::from_license_status(1), ActivationStatus::Licensed);
+    /// assert_eq!(ActivationStatus::from_license_status(3), ActivationStatus::Grace);
+    /// assert_eq!(ActivationStatus::from_license_status(9), ActivationStatus::Unknown);
+    /// ```
+    pub fn from_license_status(status: u32) -> Self {
+        match status {
+            0 => ActivationStatus::Unlicensed,
+            1 => ActivationStatus::Licensed,
+            2 | 3 | 4 | 6 => ActivationStatus::Grace,
+            5 => ActivationStatus::Notification,
+            _ => ActivationStatus::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for ActivationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ActivationStatus::Licensed => "已激活",
+            ActivationStatus::Unlicensed => "未激活",
+            ActivationStatus::Grace => "宽限期",
+            ActivationStatus::Notification => "通知模式",
+            ActivationStatus::Unknown => "未知",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 /// 系统信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct SystemInfo {
     /// 电脑型号
     pub computer_model: String,
@@ -23,6 +194,22 @@ pub struct SystemInfo {
     pub system_manufacturer: String,
     /// 电脑类型
     pub computer_type: ComputerType,
+    /// 整机序列号
+    pub serial_number: String,
+    /// 系统版本号（含修订号），如 "22631.4317"
+    pub os_build: String,
+    /// 功能更新版本，如 "23H2"
+    pub display_version: String,
+    /// 系统安装时间（Unix 时间戳，秒）
+    pub install_date: Option<i64>,
+    /// 上次开机时间（Unix 时间戳，秒）
+    pub last_boot_time: Option<i64>,
+    /// 激活状态
+    pub activation: ActivationStatus,
+    /// 虚拟机监视器，运行在物理机上时为 None
+    pub virtualization: Option<Hypervisor>,
+    /// 已安装的系统更新，按安装日期从新到旧排列
+    pub hotfixes: Vec<Hotfix>,
 }
 
 impl Default for SystemInfo {
@@ -32,83 +219,469 @@ impl Default for SystemInfo {
             os_name: "未知".to_string(),
             system_manufacturer: "未知".to_string(),
             computer_type: ComputerType::Unknown,
+            serial_number: "未知".to_string(),
+            os_build: "未知".to_string(),
+            display_version: "未知".to_string(),
+            install_date: None,
+            last_boot_time: None,
+            activation: ActivationStatus::Unknown,
+            virtualization: None,
+            hotfixes: Vec::new(),
+        }
+    }
+}
+
+impl SystemInfo {
+    /// 是否运行在虚拟机中，检测结果缺失时根据制造商和型号判断
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::system::SystemInfo;
+    /// let info = SystemInfo {
+    ///     system_manufacturer: "Microsoft Corporation".to_string(),
+    ///     computer_model: "Virtual Machine".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(info.is_virtual_machine());
+    /// ```
+    pub fn is_virtual_machine(&self) -> bool {
+        self.virtualization.is_some()
+            || Hypervisor::from_system_strings(&self.system_manufacturer, &self.computer_model)
+                .is_some()
+    }
+
+    /// 系统已运行的秒数，开机时间未知时返回 None
+    ///
+    /// * `now` - 当前时间（Unix 时间戳，秒）
+    pub fn uptime_secs(&self, now: i64) -> Option<i64> {
+        self.last_boot_time.map(|boot| (now - boot).max(0))
+    }
+}
+
+/// 已安装的系统更新（Win32_QuickFixEngineering）
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hotfix {
+    /// 更新编号，如 "KB5031455"
+    pub kb: String,
+    /// 更新类型，如 "Security Update"
+    pub description: String,
+    /// 安装日期，格式为 yyyy-mm-dd
+    pub installed_on: String,
+}
+
+impl Default for Hotfix {
+    fn default() -> Self {
+        Self {
+            kb: "未知".to_string(),
+            description: "未知".to_string(),
+            installed_on: "未知".to_string(),
+        }
+    }
+}
+
+/// 更新类型的中文名称，未收录的类型原样返回
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::system::hotfix_kind_name;
+/// assert_eq!(hotfix_kind_name("Security Update"), "安全更新");
+/// assert_eq!(hotfix_kind_name("Servicing Stack Update"), "服务堆栈更新");
+/// assert_eq!(hotfix_kind_name("Feature Pack"), "Feature Pack");
+/// ```
+pub fn hotfix_kind_name(description: &str) -> &str {
+    match description.trim() {
+        "Security Update" => "安全更新",
+        "Update" => "更新",
+        "Hotfix" => "修补程序",
+        "Servicing Stack Update" => "服务堆栈更新",
+        other => other,
+    }
+}
+
+/// 解析 Win32_QuickFixEngineering 的 InstalledOn，返回 yyyy-mm-dd
+///
+/// 通常为 "月/日/年"，部分系统为 "年/月/日"、"yyyymmdd" 或十六进制的 FILETIME
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::system::parse_hotfix_date;
+/// assert_eq!(parse_hotfix_date("10/8/2024").as_deref(), Some("2024-10-08"));
+/// assert_eq!(parse_hotfix_date("2024/10/8").as_deref(), Some("2024-10-08"));
+/// assert_eq!(parse_hotfix_date("20241008").as_deref(), Some("2024-10-08"));
+/// assert_eq!(parse_hotfix_date("01d3b4c1e2f3a500").as_deref(), Some("2018-03-05"));
+/// assert_eq!(parse_hotfix_date(""), None);
+/// ```
+pub fn parse_hotfix_date(installed_on: &str) -> Option<String> {
+    let value = installed_on.trim();
+    let date = if value.len() == 16 && value.chars().all(|c| c.is_ascii_hexdigit()) {
+        // FILETIME：自 1601-01-01 起的 100 纳秒数
+        let ticks = u64::from_str_radix(value, 16).ok()?;
+        let secs = (ticks / 10_000_000) as i64 - 11_644_473_600;
+        chrono::DateTime::from_timestamp(secs, 0)?.date_naive()
+    } else {
+        ["%m/%d/%Y", "%Y/%m/%d", "%Y%m%d", "%Y-%m-%d"]
+            .iter()
+            .find_map(|format| chrono::NaiveDate::parse_from_str(value, format).ok())?
+    };
+    Some(date.format("%Y-%m-%d").to_string())
+}
+
+/// Windows 11 的起始版本号，Windows 11 的 ProductName 与部分版本的 Caption 仍为 "Windows 10"
+const WINDOWS_11_MIN_BUILD: u32 = 22000;
+
+/// 注册表 EditionID 对应的中文版本名称
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::system::edition_name;
+/// assert_eq!(edition_name("Professional"), Some("专业版"));
+/// assert_eq!(edition_name("CoreCountrySpecific"), Some("家庭中文版"));
+/// assert_eq!(edition_name("ServerStandard"), None);
+/// ```
+pub fn edition_name(edition_id: &str) -> Option<&'static str> {
+    let name = match edition_id.trim() {
+        "Core" | "CoreN" => "家庭版",
+        "CoreSingleLanguage" => "家庭单语言版",
+        "CoreCountrySpecific" => "家庭中文版",
+        "Professional" | "ProfessionalN" => "专业版",
+        "ProfessionalWorkstation" => "专业工作站版",
+        "ProfessionalEducation" => "专业教育版",
+        "Education" | "EducationN" => "教育版",
+        "Enterprise" | "EnterpriseN" => "企业版",
+        "EnterpriseS" | "EnterpriseSN" => "企业版 LTSC",
+        "EnterpriseG" => "企业版 G",
+        "IoTEnterprise" => "IoT 企业版",
+        "IoTEnterpriseS" => "IoT 企业版 LTSC",
+        "ServerRdsh" => "企业版多会话",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// 由 Win32_OperatingSystem 的 OSArchitecture 得到系统位数
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::system::architecture_name;
+/// assert_eq!(architecture_name("64 位"), Some("64位"));
+/// assert_eq!(architecture_name("64-bit"), Some("64位"));
+/// assert_eq!(architecture_name("ARM 64-bit Processor"), Some("ARM64"));
+/// assert_eq!(architecture_name("32-bit"), Some("32位"));
+/// assert_eq!(architecture_name(""), None);
+/// ```
+pub fn architecture_name(os_architecture: &str) -> Option<&'static str> {
+    let architecture = os_architecture.to_lowercase();
+    if architecture.contains("arm") {
+        Some("ARM64")
+    } else if architecture.contains("64") {
+        Some("64位")
+    } else if architecture.contains("32") {
+        Some("32位")
+    } else {
+        None
+    }
+}
+
+/// 组合准确的操作系统名称，如 "Windows 11 专业版 23H2 64位"
+///
+/// 客户端系统的大版本按版本号判断（22000 及以上为 Windows 11），版本名称取自 EditionID，
+/// 未知时沿用 Caption 中的版本名称；服务器系统的版本号与客户端重叠，沿用 Caption
+///
+/// * `caption` - Win32_OperatingSystem 的 Caption，如 "Microsoft Windows 10 专业版"
+/// * `build` - 系统版本号（不含修订号）
+/// * `edition_id` - 注册表中的 EditionID
+/// * `display_version` - 功能更新版本，如 "23H2"
+/// * `architecture` - 系统位数，如 "64位"
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::system::compose_os_name;
+/// assert_eq!(
+///     compose_os_name(
+///         "Microsoft Windows 10 专业版",
+///         Some(22631),
+///         Some("Professional"),
+///         Some("23H2"),
+///         Some("64位")
+///     ),
+///     "Windows 11 专业版 23H2 64位"
+/// );
+/// assert_eq!(
+///     compose_os_name("Microsoft Windows 10 家庭中文版", Some(19045), None, Some("22H2"), None),
+///     "Windows 10 家庭中文版 22H2"
+/// );
+/// assert_eq!(
+///     compose_os_name(
+///         "Microsoft Windows Server 2025 Datacenter",
+///         Some(26100),
+///         Some("ServerDatacenter"),
+///         Some("24H2"),
+///         Some("64位")
+///     ),
+///     "Windows Server 2025 Datacenter 24H2 64位"
+/// );
+/// ```
+pub fn compose_os_name(
+    caption: &str,
+    build: Option<u32>,
+    edition_id: Option<&str>,
+    display_version: Option<&str>,
+    architecture: Option<&str>,
+) -> String {
+    let caption = caption.trim();
+    let caption = caption.strip_prefix("Microsoft ").unwrap_or(caption).trim();
+    let is_server = caption.contains("Server")
+        || edition_id
+            .is_some_and(|edition| edition.starts_with("Server") && edition != "ServerRdsh");
+
+    let mut parts = Vec::new();
+    match build {
+        Some(build) if !is_server && build >= 10240 => {
+            let major = if build >= WINDOWS_11_MIN_BUILD {
+                "Windows 11"
+            } else {
+                "Windows 10"
+            };
+            parts.push(major.to_string());
+            // Caption 中大版本之后的部分为版本名称
+            let caption_edition = ["Windows 11", "Windows 10"]
+                .iter()
+                .find_map(|major| caption.strip_prefix(major))
+                .map(str::trim)
+                .filter(|edition| !edition.is_empty());
+            if let Some(edition) = edition_id.and_then(edition_name).or(caption_edition) {
+                parts.push(edition.to_string());
+            }
         }
+        _ if !caption.is_empty() => parts.push(caption.to_string()),
+        _ => parts.push("Windows".to_string()),
+    }
+    if let Some(version) = display_version
+        .map(str::trim)
+        .filter(|version| !version.is_empty() && *version != "未知")
+    {
+        parts.push(version.to_string());
+    }
+    if let Some(architecture) = architecture {
+        parts.push(architecture.to_string());
+    }
+    parts.join(" ")
+}
+
+/// 描述系统运行时长
+///
+/// * `secs` - 运行秒数
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::system::format_uptime;
+/// assert_eq!(format_uptime(45), "不到 1 分钟");
+/// assert_eq!(format_uptime(2 * 3600 + 5 * 60), "2 小时 5 分钟");
+/// assert_eq!(format_uptime(3 * 86400 + 4 * 3600 + 59), "3 天 4 小时 0 分钟");
+/// ```
+pub fn format_uptime(secs: i64) -> String {
+    let minutes = secs.max(0) / 60;
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    match (days, hours, minutes) {
+        (0, 0, 0) => "不到 1 分钟".to_string(),
+        (0, 0, _) => format!("{} 分钟", minutes),
+        (0, _, _) => format!("{} 小时 {} 分钟", hours, minutes),
+        _ => format!("{} 天 {} 小时 {} 分钟", days, hours, minutes),
+    }
+}
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_ComputerSystem 中用到的属性
+    struct ComputerSystemRow {
+        manufacturer: String = "Manufacturer",
+        model: String = "Model",
+        pc_system_type: u16 = "PCSystemType",
     }
 }
 
 /// 检测系统信息
+#[cfg(windows)]
 pub fn detect_system() -> Result<SystemInfo, DetectionError> {
     let mut info = SystemInfo::default();
 
+    let config = wmi::WmiConfig::default();
+    let client =
+        wmi::WmiClient::connect(&config).map_err(|e| e.within(DetectionError::SystemError))?;
+
+    // 查询操作系统信息
+    let os_rows = client
+        .query_rows("SELECT * FROM Win32_OperatingSystem")
+        .map_err(|e| e.within(DetectionError::SystemError))?;
+
+    let Some(os) = os_rows.first() else {
+        return Err(DetectionError::SystemError(
+            "未找到操作系统信息".to_string(),
+        ));
+    };
+    let caption = os.get::<String>("Caption");
+    info.install_date = os
+        .get::<String>("InstallDate")
+        .and_then(|date| parse_wmi_datetime(&date));
+    info.last_boot_time = os
+        .get::<String>("LastBootUpTime")
+        .and_then(|date| parse_wmi_datetime(&date));
+
+    // 版本号与功能更新版本以注册表为准，WMI 的 BuildNumber 不含修订号
     unsafe {
-        let config = wmi::WmiConfig::default();
-        let client = wmi::WmiClient::connect(&config)
-            .map_err(|e| DetectionError::SystemError(e))?;
-
-        // 查询操作系统信息
-        let mut os_enumerator = client
-            .query("SELECT * FROM Win32_OperatingSystem")
-            .map_err(|e| DetectionError::SystemError(e))?;
-
-        if let Some(obj) = os_enumerator.next() {
-            if let Ok(var) = wmi::get_property(&obj, "Caption") {
-                info.os_name = wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
-            }
-        } else {
-            return Err(DetectionError::SystemError("未找到操作系统信息".to_string()));
+        let build = read_registry_string(
+            HKEY_LOCAL_MACHINE,
+            CURRENT_VERSION_KEY,
+            "CurrentBuildNumber",
+        )
+        .or_else(|| os.get::<String>("BuildNumber"));
+        let revision = read_registry_dword(HKEY_LOCAL_MACHINE, CURRENT_VERSION_KEY, "UBR");
+        if let Some(build) = &build {
+            info.os_build = match revision {
+                Some(revision) => format!("{}.{}", build, revision),
+                None => build.clone(),
+            };
+        }
+        // Windows 10 20H2 之前只有 ReleaseId（如 "2004"）
+        if let Some(version) =
+            read_registry_string(HKEY_LOCAL_MACHINE, CURRENT_VERSION_KEY, "DisplayVersion").or_else(
+                || read_registry_string(HKEY_LOCAL_MACHINE, CURRENT_VERSION_KEY, "ReleaseId"),
+            )
+        {
+            info.display_version = version;
+        }
+        // Windows 11 的 ProductName 仍为 "Windows 10"，部分版本的 Caption 也是如此，
+        // 按版本号与 EditionID 重新组合系统名称
+        let edition_id = read_registry_string(HKEY_LOCAL_MACHINE, CURRENT_VERSION_KEY, "EditionID");
+        let architecture = os
+            .get::<String>("OSArchitecture")
+            .and_then(|architecture| architecture_name(&architecture));
+        if caption.is_some() || build.is_some() {
+            info.os_name = compose_os_name(
+                caption.as_deref().unwrap_or_default(),
+                build.as_deref().and_then(|build| build.trim().parse().ok()),
+                edition_id.as_deref(),
+                (info.display_version != "未知").then_some(info.display_version.as_str()),
+                architecture,
+            );
         }
+        // WMI 未提供安装时间时读取注册表中的 Unix 时间戳
+        if info.install_date.is_none() {
+            info.install_date =
+                read_registry_dword(HKEY_LOCAL_MACHINE, CURRENT_VERSION_KEY, "InstallDate")
+                    .map(i64::from);
+        }
+    }
 
-        // 查询计算机系统信息
-        let mut cs_enumerator = client
-            .query("SELECT * FROM Win32_ComputerSystem")
-            .map_err(|e| DetectionError::SystemError(e))?;
+    // 查询计算机系统信息
+    let cs_rows: Vec<ComputerSystemRow> = client
+        .query_as("SELECT * FROM Win32_ComputerSystem")
+        .map_err(|e| e.within(DetectionError::SystemError))?;
 
-        if let Some(obj) = cs_enumerator.next() {
-            // 获取制造商
-            if let Ok(var) = wmi::get_property(&obj, "Manufacturer") {
-                info.system_manufacturer =
-                    wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
-            }
+    let Some(cs) = cs_rows.into_iter().next() else {
+        return Err(DetectionError::SystemError(
+            "未找到计算机系统信息".to_string(),
+        ));
+    };
 
-            // 获取型号
-            if let Ok(var) = wmi::get_property(&obj, "Model") {
-                info.computer_model =
-                    wmi::variant_to_string(&var).unwrap_or_else(|| "未知".to_string());
-            }
+    // 获取制造商
+    info.system_manufacturer = cs.manufacturer.unwrap_or_else(|| "未知".to_string());
 
-            // 获取系统类型
-            let pc_system_type = if let Ok(var) = wmi::get_property(&obj, "PCSystemType") {
-                wmi::variant_to_u16(&var)
-            } else {
-                None
-            };
+    // 获取型号
+    info.computer_model = cs.model.unwrap_or_else(|| "未知".to_string());
 
-            // 根据系统类型判断电脑类型
-            info.computer_type = if let Some(pc_type) = pc_system_type {
-                match pc_type {
-                    1 | 2 => ComputerType::Laptop,  // Portable / Mobile
-                    3 | 4 => ComputerType::Desktop, // Desktop
-                    _ => ComputerType::Unknown,
-                }
-            } else {
-                // 如果没有系统类型，尝试从型号判断
-                let model_lower = info.computer_model.to_lowercase();
-                if model_lower.contains("notebook")
-                    || model_lower.contains("laptop")
-                    || model_lower.contains("book")
-                    || model_lower.contains("mobile")
-                {
-                    ComputerType::Laptop
-                } else if model_lower.contains("desktop") || model_lower.contains("tower") {
-                    ComputerType::Desktop
-                } else {
-                    ComputerType::Unknown
-                }
-            };
-        } else {
-            return Err(DetectionError::SystemError("未找到计算机系统信息".to_string()));
+    // 优先按 SMBIOS 机箱类型判断电脑类型（可区分二合一、迷你主机等），
+    // 机箱类型未填写（如 "Other"）时按 PCSystemType 判断
+    let chassis = chassis_type(&smbios::read_table().structures)
+        .map(ComputerType::from_chassis_type)
+        .unwrap_or(ComputerType::Unknown);
+    info.computer_type = match (chassis, cs.pc_system_type) {
+        (ComputerType::Unknown, Some(pc_type)) => ComputerType::from_pc_system_type(pc_type),
+        (chassis, _) => chassis,
+    };
+
+    info.virtualization = hypervisor::resolve(
+        hypervisor::detect_cpuid(),
+        &info.system_manufacturer,
+        &info.computer_model,
+    );
+
+    // 查询整机序列号，部分品牌机未写入时忽略
+    if let Ok(bios_rows) = client.query_rows("SELECT SerialNumber FROM Win32_BIOS") {
+        if let Some(serial) = bios_rows
+            .first()
+            .and_then(|row| row.get::<String>("SerialNumber"))
+        {
+            let serial = serial.trim();
+            if !serial.is_empty() {
+                info.serial_number = serial.to_string();
+            }
+        }
+    }
+
+    // 查询激活状态，该查询较慢且在精简系统上可能失败，失败时保持未知
+    let license_query = format!(
+        "SELECT LicenseStatus FROM SoftwareLicensingProduct \
+         WHERE ApplicationID = '{}' AND PartialProductKey IS NOT NULL",
+        WINDOWS_APPLICATION_ID
+    );
+    if let Ok(license_rows) = client.query_rows(&license_query) {
+        if let Some(status) = license_rows
+            .first()
+            .and_then(|row| row.get::<u32>("LicenseStatus"))
+        {
+            info.activation = ActivationStatus::from_license_status(status);
         }
     }
 
+    info.hotfixes = detect_hotfixes(&client);
+
     Ok(info)
 }
+
+#[cfg(windows)]
+wmi_row! {
+    /// Win32_QuickFixEngineering 中用到的属性
+    struct HotfixRow {
+        hotfix_id: String = "HotFixID",
+        description: String = "Description",
+        installed_on: String = "InstalledOn",
+    }
+}
+
+/// 查询已安装的系统更新，查询失败时返回空列表
+#[cfg(windows)]
+fn detect_hotfixes(client: &wmi::WmiClient) -> Vec<Hotfix> {
+    let rows: Vec<HotfixRow> = match client
+        .query_as("SELECT HotFixID, Description, InstalledOn FROM Win32_QuickFixEngineering")
+    {
+        Ok(rows) => rows,
+        Err(e) => {
+            log::warn!("查询系统更新失败: {}", e);
+            return Vec::new();
+        }
+    };
+    let mut hotfixes: Vec<Hotfix> = rows
+        .into_iter()
+        .filter_map(|row| {
+            // 未安装完成的更新 HotFixID 为 "File 1"
+            let kb = row.hotfix_id.filter(|id| id.starts_with("KB"))?;
+            Some(Hotfix {
+                kb,
+                description: row.description.unwrap_or_else(|| "未知".to_string()),
+                installed_on: row
+                    .installed_on
+                    .as_deref()
+                    .and_then(parse_hotfix_date)
+                    .unwrap_or_else(|| "未知".to_string()),
+            })
+        })
+        .collect();
+    // 未知日期排在最后
+    hotfixes.sort_by(|a, b| {
+        (b.installed_on != "未知", &b.installed_on)
+            .cmp(&(a.installed_on != "未知", &a.installed_on))
+    });
+    hotfixes
+}
@@ -0,0 +1,136 @@
+//! 检测耗时统计
+//!
+//! 记录每个检测类别的耗时，以及该类别中每条 WMI 查询的耗时，
+//! 用于找出在某台电脑上特别慢的 WMI 类，并据此停用对应的检测类别。
+//!
+//! WMI 查询耗时按线程收集：检测工作线程在执行某个类别前开始记录，结束后取出本线程记录的查询
+
+use super::DetectionCategory;
+use std::cell::RefCell;
+use std::time::Duration;
+
+thread_local! {
+    /// 当前线程正在记录的查询耗时，未在记录时为 None
+    static QUERIES: RefCell<Option<Vec<QueryTiming>>> = const { RefCell::new(None) };
+}
+
+/// 单条 WMI 查询的耗时
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryTiming {
+    /// 查询语句
+    pub query: String,
+    /// 耗时（毫秒）
+    pub millis: u64,
+}
+
+/// 单个检测类别的耗时
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DetectionTiming {
+    /// 检测类别
+    pub category: DetectionCategory,
+    /// 耗时（毫秒）
+    pub millis: u64,
+    /// 是否检测成功
+    pub succeeded: bool,
+    /// 该类别中执行的 WMI 查询，按耗时从高到低排列
+    pub queries: Vec<QueryTiming>,
+}
+
+impl DetectionTiming {
+    /// 创建耗时记录，查询按耗时从高到低排序
+    ///
+    /// * `category` - 检测类别
+    /// * `elapsed` - 检测耗时
+    /// * `succeeded` - 是否检测成功
+    /// * `queries` - 该类别中执行的 WMI 查询
+    pub fn new(
+        category: DetectionCategory,
+        elapsed: Duration,
+        succeeded: bool,
+        mut queries: Vec<QueryTiming>,
+    ) -> Self {
+        queries.sort_by_key(|query| std::cmp::Reverse(query.millis));
+        Self {
+            category,
+            millis: elapsed.as_millis() as u64,
+            succeeded,
+            queries,
+        }
+    }
+
+    /// 耗时最长的 WMI 查询
+    pub fn slowest_query(&self) -> Option<&QueryTiming> {
+        self.queries.first()
+    }
+
+    /// WMI 查询的总耗时（毫秒），其余时间花在 Windows API、注册表等其他调用上
+    pub fn query_millis(&self) -> u64 {
+        self.queries.iter().map(|query| query.millis).sum()
+    }
+}
+
+/// 记录一条 WMI 查询的耗时，当前线程未在记录时忽略
+///
+/// * `query` - 查询语句
+/// * `elapsed` - 耗时
+pub fn record_query(query: &str, elapsed: Duration) {
+    QUERIES.with(|queries| {
+        if let Some(queries) = queries.borrow_mut().as_mut() {
+            queries.push(QueryTiming {
+                query: query.to_string(),
+                millis: elapsed.as_millis() as u64,
+            });
+        }
+    });
+}
+
+/// 开始记录当前线程的查询耗时，之前未取出的记录被丢弃
+pub fn start_recording() {
+    QUERIES.with(|queries| *queries.borrow_mut() = Some(Vec::new()));
+}
+
+/// 停止记录，取出当前线程记录的查询耗时
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::timing::{finish_recording, record_query, start_recording};
+/// use std::time::Duration;
+///
+/// record_query("SELECT * FROM Win32_BIOS", Duration::from_millis(5)); // 未在记录，忽略
+/// start_recording();
+/// record_query("SELECT * FROM Win32_Battery", Duration::from_millis(1200));
+/// let queries = finish_recording();
+/// assert_eq!(queries.len(), 1);
+/// assert_eq!(queries[0].millis, 1200);
+/// assert!(finish_recording().is_empty());
+/// ```
+pub fn finish_recording() -> Vec<QueryTiming> {
+    QUERIES
+        .with(|queries| queries.borrow_mut().take())
+        .unwrap_or_default()
+}
+
+/// 按耗时从高到低排列的耗时记录
+pub fn slowest_first(timings: &[DetectionTiming]) -> Vec<&DetectionTiming> {
+    let mut sorted: Vec<&DetectionTiming> = timings.iter().collect();
+    sorted.sort_by_key(|timing| std::cmp::Reverse(timing.millis));
+    sorted
+}
+
+/// 格式化耗时，不足 1 秒时以毫秒显示
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::timing::format_millis;
+/// assert_eq!(format_millis(850), "850 ms");
+/// assert_eq!(format_millis(1250), "1.25 s");
+/// ```
+pub fn format_millis(millis: u64) -> String {
+    if millis < 1000 {
+        format!("{} ms", millis)
+    } else {
+        format!("{:.2} s", millis as f64 / 1000.0)
+    }
+}
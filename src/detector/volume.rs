@@ -0,0 +1,220 @@
+//! 逻辑卷检测模块
+//!
+//! 枚举所有盘符，获取文件系统、卷标、容量与可用空间，以及卷在物理磁盘上的区段
+
+#[cfg(windows)]
+use crate::detector::disk::get_volume_disk_extents;
+use crate::detector::disk::DiskExtent;
+#[cfg(windows)]
+use crate::detector::DetectionError;
+use crate::utils::size::ByteSize;
+#[cfg(windows)]
+use crate::utils::string::{u16_slice_to_string, wide_str};
+use std::collections::BTreeMap;
+use std::fmt;
+#[cfg(windows)]
+use windows::core::PCWSTR;
+#[cfg(windows)]
+use windows::Win32::Storage::FileSystem::{
+    GetDiskFreeSpaceExW, GetDriveTypeW, GetLogicalDrives, GetVolumeInformationW,
+};
+
+/// 卷所在驱动器类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum DriveKind {
+    /// 本地硬盘
+    Fixed,
+    /// 可移动磁盘（U 盘、读卡器等）
+    Removable,
+    /// 网络驱动器
+    Network,
+    /// 光驱
+    CdRom,
+    /// 内存盘
+    RamDisk,
+    /// 未知
+    Unknown,
+}
+
+impl From<u32> for DriveKind {
+    /// 根据 GetDriveTypeW 的返回值转换
+    fn from(value: u32) -> Self {
+        match value {
+            2 => DriveKind::Removable,
+            3 => DriveKind::Fixed,
+            4 => DriveKind::Network,
+            5 => DriveKind::CdRom,
+            6 => DriveKind::RamDisk,
+            _ => DriveKind::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for DriveKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DriveKind::Fixed => "本地磁盘",
+            DriveKind::Removable => "可移动磁盘",
+            DriveKind::Network => "网络驱动器",
+            DriveKind::CdRom => "光驱",
+            DriveKind::RamDisk => "内存盘",
+            DriveKind::Unknown => "未知",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 逻辑卷信息
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Volume {
+    /// 盘符，如 "C:"
+    pub drive_letter: String,
+    /// 卷标
+    pub label: String,
+    /// 文件系统，如 "NTFS"
+    pub file_system: String,
+    /// 驱动器类型
+    pub kind: DriveKind,
+    /// 总容量
+    pub total_space: ByteSize,
+    /// 可用空间
+    pub free_space: ByteSize,
+    /// 在物理磁盘上的区段，网络驱动器等无法获取时为空
+    pub extents: Vec<DiskExtent>,
+}
+
+impl Default for Volume {
+    fn default() -> Self {
+        Self {
+            drive_letter: "未知".to_string(),
+            label: String::new(),
+            file_system: "未知".to_string(),
+            kind: DriveKind::Unknown,
+            total_space: ByteSize::ZERO,
+            free_space: ByteSize::ZERO,
+            extents: Vec::new(),
+        }
+    }
+}
+
+impl Volume {
+    /// 已用空间占比（0.0 - 1.0）
+    pub fn used_ratio(&self) -> f64 {
+        if self.total_space.is_zero() {
+            return 0.0;
+        }
+        (1.0 - self.free_space.as_f64() / self.total_space.as_f64()).clamp(0.0, 1.0)
+    }
+
+    /// 所在物理磁盘编号，跨多块磁盘的卷返回多个编号
+    pub fn disk_numbers(&self) -> Vec<u32> {
+        let mut numbers: Vec<u32> = self.extents.iter().map(|e| e.disk_number).collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+        numbers
+    }
+
+    /// 显示名称，如 "系统 (C:)"，无卷标时为 "本地磁盘 (C:)"
+    pub fn display_name(&self) -> String {
+        if self.label.is_empty() {
+            format!("{} ({})", self.kind, self.drive_letter)
+        } else {
+            format!("{} ({})", self.label, self.drive_letter)
+        }
+    }
+}
+
+/// 系统盘符，取自环境变量 SystemDrive，未设置时为 "C:"
+pub fn system_drive() -> String {
+    std::env::var("SystemDrive").unwrap_or_else(|_| "C:".to_string())
+}
+
+/// 检测所有已挂载的逻辑卷，未插入介质的驱动器会被跳过
+#[cfg(windows)]
+pub fn detect_volumes() -> Result<Vec<Volume>, DetectionError> {
+    let mask = unsafe { GetLogicalDrives() };
+    if mask == 0 {
+        return Err(DetectionError::DiskError("获取盘符列表失败".to_string()));
+    }
+
+    let volumes = (0..26u8)
+        .filter(|i| mask & (1 << i) != 0)
+        .filter_map(|i| unsafe { query_volume((b'A' + i) as char) })
+        .collect();
+    Ok(volumes)
+}
+
+/// 查询单个盘符的卷信息
+///
+/// * `letter` - 盘符字母，如 'C'
+#[cfg(windows)]
+unsafe fn query_volume(letter: char) -> Option<Volume> {
+    let drive_letter = format!("{}:", letter);
+    let root = wide_str(&format!("{}\\", drive_letter));
+    let root_ptr = PCWSTR::from_raw(root.as_ptr());
+
+    let mut total_space = 0u64;
+    let mut free_space = 0u64;
+    // 光驱、读卡器等未插入介质时查询失败
+    GetDiskFreeSpaceExW(
+        root_ptr,
+        None,
+        Some(&mut total_space),
+        Some(&mut free_space),
+    )
+    .ok()?;
+
+    let mut label = [0u16; 261];
+    let mut file_system = [0u16; 261];
+    let (label, file_system) = match GetVolumeInformationW(
+        root_ptr,
+        Some(&mut label),
+        None,
+        None,
+        None,
+        Some(&mut file_system),
+    ) {
+        Ok(()) => (
+            u16_slice_to_string(&label),
+            u16_slice_to_string(&file_system),
+        ),
+        Err(_) => (String::new(), "未知".to_string()),
+    };
+
+    let kind = DriveKind::from(GetDriveTypeW(root_ptr));
+    let extents = match kind {
+        DriveKind::Fixed | DriveKind::Removable => get_volume_disk_extents(&drive_letter),
+        _ => Vec::new(),
+    };
+
+    Some(Volume {
+        drive_letter,
+        label,
+        file_system,
+        kind,
+        total_space: ByteSize::from_bytes(total_space),
+        free_space: ByteSize::from_bytes(free_space),
+        extents,
+    })
+}
+
+/// 按物理磁盘编号对逻辑卷分组，用于显示“磁盘 → 分区”树
+///
+/// 跨多块磁盘的卷会出现在每块磁盘下；无法确定所在磁盘的卷归入 `None`
+///
+/// * `volumes` - 逻辑卷列表
+pub fn group_by_disk(volumes: &[Volume]) -> BTreeMap<Option<u32>, Vec<&Volume>> {
+    let mut groups: BTreeMap<Option<u32>, Vec<&Volume>> = BTreeMap::new();
+    for volume in volumes {
+        let numbers = volume.disk_numbers();
+        if numbers.is_empty() {
+            groups.entry(None).or_default().push(volume);
+        }
+        for number in numbers {
+            groups.entry(Some(number)).or_default().push(volume);
+        }
+    }
+    groups
+}
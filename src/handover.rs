@@ -0,0 +1,136 @@
+//! 状态交接模块
+//!
+//! 以管理员身份重新启动时，将当前配置与界面状态写入临时文件，
+//! 新进程通过 `--handover <文件路径>` 参数读取后删除该文件。
+//! 临时文件名随机生成并以独占方式创建，其他进程无法预先放置同名文件让管理员进程读取。
+
+use crate::config::AppConfig;
+use crate::constants::HANDOVER_ARG;
+use crate::detector::DetectionCategory;
+use crate::ui::tab::AppTab;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// 创建临时交接文件的最大尝试次数（文件名冲突时重试）
+const MAX_CREATE_ATTEMPTS: usize = 8;
+
+/// 生成 128 位随机十六进制串
+///
+/// `RandomState` 的密钥来自操作系统随机数，无需额外依赖
+fn random_hex() -> String {
+    let part = || {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default(),
+        );
+        hasher.finish()
+    };
+    format!("{:016x}{:016x}", part(), part())
+}
+
+/// 交接给新进程的状态
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HandoverState {
+    /// 用户配置
+    pub config: AppConfig,
+    /// 当前标签页
+    pub current_tab: AppTab,
+    /// 点击刷新时重新检测的类别
+    pub refresh_categories: Vec<DetectionCategory>,
+    /// 是否显示购买建议面板
+    pub show_advice: bool,
+}
+
+impl Default for HandoverState {
+    fn default() -> Self {
+        Self {
+            config: AppConfig::default(),
+            current_tab: AppTab::default(),
+            refresh_categories: DetectionCategory::ALL.to_vec(),
+            show_advice: false,
+        }
+    }
+}
+
+impl HandoverState {
+    /// 写入系统临时目录，返回文件路径
+    ///
+    /// 文件名随机生成，并且只创建新文件，已存在同名文件时换一个文件名重试
+    pub fn write_temp(&self) -> Result<PathBuf, String> {
+        let content = self.to_toml()?;
+        for _ in 0..MAX_CREATE_ATTEMPTS {
+            let path = std::env::temp_dir()
+                .join(format!("hardware-master-handover-{}.toml", random_hex()));
+            let mut file = match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(file) => file,
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(format!("创建交接文件失败: {}", e)),
+            };
+            if let Err(e) = file.write_all(content.as_bytes()) {
+                drop(file);
+                let _ = std::fs::remove_file(&path);
+                return Err(format!("写入交接文件失败: {}", e));
+            }
+            return Ok(path);
+        }
+        Err("创建交接文件失败: 文件名冲突".to_string())
+    }
+
+    /// 写入指定文件
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_toml()?).map_err(|e| format!("写入交接文件失败: {}", e))
+    }
+
+    /// 序列化为 TOML 文本
+    fn to_toml(&self) -> Result<String, String> {
+        toml::to_string_pretty(self).map_err(|e| format!("序列化交接状态失败: {}", e))
+    }
+
+    /// 读取交接文件并删除
+    pub fn take(path: &Path) -> Result<Self, String> {
+        let content =
+            std::fs::read_to_string(path).map_err(|e| format!("读取交接文件失败: {}", e))?;
+        if let Err(e) = std::fs::remove_file(path) {
+            log::warn!("删除交接文件失败: {}", e);
+        }
+        toml::from_str(&content).map_err(|e| format!("解析交接文件失败: {}", e))
+    }
+
+    /// 生成传给新进程的命令行参数
+    pub fn to_args(path: &Path) -> Vec<String> {
+        vec![
+            HANDOVER_ARG.to_string(),
+            path.to_string_lossy().into_owned(),
+        ]
+    }
+
+    /// 从命令行参数中查找交接文件路径
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::handover::HandoverState;
+    /// let args = ["hardware-master.exe", "--handover", "C:\\Temp\\state.toml"];
+    /// let path = HandoverState::path_from_args(args.iter().map(|s| s.to_string()));
+    /// assert_eq!(path, Some("C:\\Temp\\state.toml".into()));
+    /// ```
+    pub fn path_from_args(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == HANDOVER_ARG {
+                return args.next().map(PathBuf::from);
+            }
+        }
+        None
+    }
+}
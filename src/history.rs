@@ -0,0 +1,197 @@
+//! 历史记录模块
+//!
+//! 每次检测完成后追加一条快照到 `%APPDATA%\hardware-master\history.jsonl`（每行一条 JSON），
+//! 用于观察电池容量、硬盘空间、磨损与温度等指标的长期变化。
+
+use crate::config::AppConfig;
+use crate::constants::HISTORY_FILE_NAME;
+use crate::HardwareDetector;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// 单次检测的指标快照
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Snapshot {
+    /// 记录时间（Unix 时间戳，秒）
+    pub timestamp: i64,
+    /// 电池满充容量合计 (mWh)
+    pub battery_full_capacity: Option<u32>,
+    /// 电池设计容量合计 (mWh)
+    pub battery_designed_capacity: Option<u32>,
+    /// 硬盘可用空间 (B)
+    pub disk_free_space: Option<f64>,
+    /// 硬盘磨损程度（百分比）
+    pub disk_wear_percent: Option<f64>,
+    /// 主硬盘温度 (°C)，兼容旧版本记录中的 `average_temperature`
+    #[serde(alias = "average_temperature")]
+    pub disk_temperature: Option<f64>,
+    /// 整机序列号
+    pub system_serial: Option<String>,
+    /// 主硬盘序列号
+    pub disk_serial: Option<String>,
+}
+
+impl Snapshot {
+    /// 从检测结果生成快照
+    ///
+    /// * `detector` - 硬件检测器
+    /// * `timestamp` - 记录时间（Unix 时间戳，秒）
+    pub fn from_detector(detector: &HardwareDetector, timestamp: i64) -> Self {
+        let batteries = &detector.battery_info.batteries;
+        let (battery_full_capacity, battery_designed_capacity) = if batteries.is_empty() {
+            (None, None)
+        } else {
+            (
+                Some(batteries.iter().map(|b| b.full_charged_capacity).sum()),
+                Some(batteries.iter().map(|b| b.designed_capacity).sum()),
+            )
+        };
+
+        let disk = &detector.disk_info;
+        let disk_free_space = (!disk.free_space.is_zero()).then(|| disk.free_space.as_f64());
+
+        let known = |value: &str| (value != "未知").then(|| value.to_string());
+
+        Self {
+            timestamp,
+            battery_full_capacity,
+            battery_designed_capacity,
+            disk_free_space,
+            disk_wear_percent: disk.wear_percent,
+            disk_temperature: disk.temperature,
+            system_serial: known(&detector.system_info.serial_number),
+            disk_serial: known(&disk.serial_number),
+        }
+    }
+
+    /// 电池健康度（满充容量占设计容量的百分比），缺少容量数据时返回 None
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::history::Snapshot;
+    /// let snapshot = Snapshot {
+    ///     battery_full_capacity: Some(42_000),
+    ///     battery_designed_capacity: Some(50_000),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(snapshot.battery_health(), Some(84.0));
+    /// assert_eq!(Snapshot::default().battery_health(), None);
+    /// ```
+    pub fn battery_health(&self) -> Option<f64> {
+        match (self.battery_full_capacity, self.battery_designed_capacity) {
+            (Some(full), Some(designed)) if designed > 0 => {
+                Some(full as f64 / designed as f64 * 100.0)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 最近一次记录的电池损耗（100% 减去健康度），没有电池容量记录时返回 None
+///
+/// 满充容量高于设计容量的新电池视为没有损耗
+///
+/// * `snapshots` - 按时间排序的快照
+///
+/// 示例
+/// ```
+/// use hardware_master::history::{battery_degradation, Snapshot};
+/// let record = |timestamp, full| Snapshot {
+///     timestamp,
+///     battery_full_capacity: Some(full),
+///     battery_designed_capacity: Some(50_000),
+///     ..Default::default()
+/// };
+/// let snapshots = [record(1, 51_000), record(2, 45_000), Snapshot::default()];
+/// assert_eq!(battery_degradation(&snapshots[..1]), Some(0.0));
+/// assert_eq!(battery_degradation(&snapshots), Some(10.0));
+/// assert_eq!(battery_degradation(&[]), None);
+/// ```
+pub fn battery_degradation(snapshots: &[Snapshot]) -> Option<f64> {
+    snapshots
+        .iter()
+        .rev()
+        .find_map(Snapshot::battery_health)
+        .map(|health| (100.0 - health).max(0.0))
+}
+
+/// 历史记录存储
+#[derive(Debug, Clone)]
+pub struct HistoryStore {
+    path: PathBuf,
+}
+
+impl HistoryStore {
+    /// 使用指定文件创建存储
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// 使用配置目录下的默认文件创建存储
+    pub fn open_default() -> Option<Self> {
+        AppConfig::config_dir().map(|dir| Self::new(dir.join(HISTORY_FILE_NAME)))
+    }
+
+    /// 存储文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 追加一条快照
+    pub fn append(&self, snapshot: &Snapshot) -> Result<(), String> {
+        append_jsonl(&self.path, snapshot)
+    }
+
+    /// 读取全部快照，按记录顺序排列
+    ///
+    /// 文件不存在时返回空列表，无法解析的行会被跳过
+    pub fn load(&self) -> Result<Vec<Snapshot>, String> {
+        load_jsonl(&self.path)
+    }
+}
+
+/// 向 JSON Lines 文件追加一条记录，目录不存在时自动创建
+///
+/// * `path` - 文件路径
+/// * `record` - 记录
+pub(crate) fn append_jsonl<T: Serialize>(path: &Path, record: &T) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("创建历史记录目录失败: {}", e))?;
+    }
+    let line = serde_json::to_string(record).map_err(|e| format!("序列化历史记录失败: {}", e))?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("打开历史记录失败: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("写入历史记录失败: {}", e))
+}
+
+/// 读取 JSON Lines 文件中的全部记录，按记录顺序排列
+///
+/// 文件不存在时返回空列表，无法解析的行会被跳过
+///
+/// * `path` - 文件路径
+pub(crate) fn load_jsonl<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, String> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("读取历史记录失败: {}", e)),
+    };
+
+    let records = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                log::warn!("跳过无法解析的历史记录: {}", e);
+                None
+            }
+        })
+        .collect();
+    Ok(records)
+}
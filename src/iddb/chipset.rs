@@ -0,0 +1,122 @@
+//! 芯片组数据库
+//!
+//! 以芯片组核心 PCI 设备（Intel 的 ISA/LPC 桥、AMD 的芯片组上行端口）的厂商 ID 与设备 ID 查询芯片组名称。
+//! 内置数据见 `chipsets.toml`，程序所在目录下的同名文件可补充或覆盖内置条目。
+
+use super::format_id;
+use crate::analysis::rules;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// 内置芯片组数据
+const BUILTIN_CHIPSETS: &str = include_str!("chipsets.toml");
+
+/// 用户芯片组数据文件名（放在程序所在目录）
+pub const USER_CHIPSETS_FILE: &str = "chipsets.toml";
+
+/// 芯片组条目
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChipsetEntry {
+    /// "厂商ID:设备ID"，如 "8086:7A84"
+    pub id: String,
+    /// 芯片组名称，如 "Intel Z690"
+    pub name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ChipsetFile {
+    #[serde(default)]
+    chipsets: Vec<ChipsetEntry>,
+}
+
+/// 芯片组数据库
+#[derive(Debug, Default)]
+pub struct ChipsetDatabase {
+    chipsets: HashMap<String, ChipsetEntry>,
+}
+
+impl ChipsetDatabase {
+    /// 从 TOML 文本加载芯片组数据
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        let mut db = Self::default();
+        db.merge_toml(content)?;
+        Ok(db)
+    }
+
+    /// 合并 TOML 文本中的芯片组数据，标识相同的条目以后者为准
+    pub fn merge_toml(&mut self, content: &str) -> Result<(), String> {
+        let file: ChipsetFile = rules::parse(content)?;
+        for chipset in file.chipsets {
+            self.chipsets.insert(format_id(&chipset.id), chipset);
+        }
+        Ok(())
+    }
+
+    /// 加载内置数据，并合并程序目录下的用户数据
+    pub fn load() -> Self {
+        let mut db = Self::from_toml(BUILTIN_CHIPSETS).unwrap_or_else(|e| {
+            log::warn!("{}", e);
+            Self::default()
+        });
+        if let Some(content) = rules::read_beside_exe(USER_CHIPSETS_FILE) {
+            if let Err(e) = db.merge_toml(&content) {
+                log::warn!("{}: {}", USER_CHIPSETS_FILE, e);
+            }
+        }
+        db
+    }
+
+    /// 查询芯片组
+    ///
+    /// * `vendor_id` - PCI 厂商 ID，如 "8086"
+    /// * `device_id` - PCI 设备 ID，如 "7A84"
+    pub fn lookup(&self, vendor_id: &str, device_id: &str) -> Option<&ChipsetEntry> {
+        self.chipsets
+            .get(&format_id(&format!("{}:{}", vendor_id, device_id)))
+    }
+
+    /// 在 PCI 设备实例 ID 列表中查找芯片组核心设备，返回第一个命中的条目
+    ///
+    /// * `instance_ids` - 设备实例 ID，如 `PCI\VEN_8086&DEV_7A84&SUBSYS_...`
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::iddb::chipset::CHIPSET_DB;
+    /// let ids = [
+    ///     "PCI\\VEN_8086&DEV_7AF0&SUBSYS_00748086&REV_11\\3&11583659&0&A3",
+    ///     "PCI\\VEN_8086&DEV_7A84&SUBSYS_7D251462&REV_11\\3&11583659&0&F8",
+    /// ];
+    /// let chipset = CHIPSET_DB.identify(ids).expect("应识别出芯片组");
+    /// assert_eq!(chipset.name, "Intel Z690");
+    /// ```
+    pub fn identify<'a>(
+        &self,
+        instance_ids: impl IntoIterator<Item = &'a str>,
+    ) -> Option<&ChipsetEntry> {
+        instance_ids.into_iter().find_map(|instance_id| {
+            let (vendor_id, device_id) = parse_pci_ids(instance_id)?;
+            self.lookup(vendor_id, device_id)
+        })
+    }
+}
+
+/// 从 PCI 设备实例 ID 中提取厂商 ID 与设备 ID，非 PCI 设备返回 None
+fn parse_pci_ids(instance_id: &str) -> Option<(&str, &str)> {
+    let mut parts = instance_id.split('\\');
+    if !parts.next()?.eq_ignore_ascii_case("PCI") {
+        return None;
+    }
+    let mut vendor_id = None;
+    let mut device_id = None;
+    for pair in parts.next()?.split('&') {
+        match pair.split_once('_') {
+            Some(("VEN", value)) => vendor_id = Some(value),
+            Some(("DEV", value)) => device_id = Some(value),
+            _ => {}
+        }
+    }
+    Some((vendor_id?, device_id?))
+}
+
+pub static CHIPSET_DB: LazyLock<ChipsetDatabase> = LazyLock::new(ChipsetDatabase::load);
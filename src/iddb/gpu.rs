@@ -0,0 +1,226 @@
+//! 显卡类型规则
+//!
+//! 按 PCI 厂商 ID、设备 ID 范围与描述关键字判断显卡类型，规则按顺序匹配，第一条命中的规则生效。
+//! 内置规则见 `gpu_rules.toml`，程序所在目录下的同名文件可补充规则，其中的规则先于内置规则匹配。
+
+use super::parse_id;
+use crate::analysis::rules;
+use crate::detector::gpu::GpuType;
+use serde::Deserialize;
+use std::ops::RangeInclusive;
+use std::sync::LazyLock;
+
+/// 内置显卡类型规则
+const BUILTIN_GPU_RULES: &str = include_str!("gpu_rules.toml");
+
+/// 用户显卡类型规则文件名（放在程序所在目录）
+pub const USER_GPU_RULES_FILE: &str = "gpu_rules.toml";
+
+/// 规则文件中的显卡类型
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum GpuKind {
+    Integrated,
+    Discrete,
+    Virtual,
+    VirtualDisplay,
+    Software,
+}
+
+impl From<GpuKind> for GpuType {
+    fn from(kind: GpuKind) -> Self {
+        match kind {
+            GpuKind::Integrated => GpuType::IntegratedGpu,
+            GpuKind::Discrete => GpuType::DiscreteGpu,
+            GpuKind::Virtual => GpuType::VirtualGpu,
+            GpuKind::VirtualDisplay => GpuType::VirtualDisplay,
+            GpuKind::Software => GpuType::Cpu,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GpuRuleEntry {
+    name: String,
+    #[serde(rename = "type")]
+    kind: GpuKind,
+    vendor: Option<String>,
+    #[serde(default)]
+    devices: Vec<String>,
+    #[serde(default)]
+    keywords: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GpuRuleFile {
+    #[serde(default)]
+    rules: Vec<GpuRuleEntry>,
+}
+
+/// 显卡类型规则
+#[derive(Debug, Clone)]
+pub struct GpuRule {
+    /// 规则说明
+    pub name: String,
+    /// 命中时的显卡类型
+    pub gpu_type: GpuType,
+    /// 厂商 ID，None 表示不限
+    pub vendor_id: Option<u16>,
+    /// 设备 ID 范围，为空表示不限
+    pub device_ids: Vec<RangeInclusive<u16>>,
+    /// 小写的描述关键字，为空表示不限
+    pub keywords: Vec<String>,
+}
+
+impl GpuRule {
+    /// 判断显卡是否满足规则中指定的全部条件
+    ///
+    /// * `description` - 小写的显卡描述
+    /// * `vendor_id` - 厂商 ID
+    /// * `device_id` - 设备 ID
+    fn matches(&self, description: &str, vendor_id: Option<u16>, device_id: Option<u16>) -> bool {
+        if self.vendor_id.is_some() && self.vendor_id != vendor_id {
+            return false;
+        }
+        if !self.device_ids.is_empty()
+            && !device_id.is_some_and(|id| self.device_ids.iter().any(|range| range.contains(&id)))
+        {
+            return false;
+        }
+        self.keywords.is_empty()
+            || self
+                .keywords
+                .iter()
+                .any(|keyword| description.contains(keyword.as_str()))
+    }
+}
+
+impl TryFrom<GpuRuleEntry> for GpuRule {
+    type Error = String;
+
+    fn try_from(entry: GpuRuleEntry) -> Result<Self, String> {
+        let vendor_id = match &entry.vendor {
+            Some(vendor) => Some(
+                parse_id(vendor)
+                    .ok_or_else(|| format!("规则 {}: 无效的厂商 ID {}", entry.name, vendor))?,
+            ),
+            None => None,
+        };
+        if vendor_id.is_none() && entry.keywords.is_empty() {
+            return Err(format!("规则 {}: 需要指定 vendor 或 keywords", entry.name));
+        }
+        if vendor_id.is_none() && !entry.devices.is_empty() {
+            return Err(format!(
+                "规则 {}: 指定 devices 时需要指定 vendor",
+                entry.name
+            ));
+        }
+        let device_ids = entry
+            .devices
+            .iter()
+            .map(|devices| {
+                parse_id_range(devices)
+                    .ok_or_else(|| format!("规则 {}: 无效的设备 ID {}", entry.name, devices))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            gpu_type: entry.kind.into(),
+            vendor_id,
+            device_ids,
+            keywords: entry
+                .keywords
+                .iter()
+                .map(|keyword| keyword.to_lowercase())
+                .collect(),
+            name: entry.name,
+        })
+    }
+}
+
+/// 解析设备 ID 或 ID 范围，如 "1681"、"1304-131D"
+fn parse_id_range(text: &str) -> Option<RangeInclusive<u16>> {
+    let (start, end) = match text.split_once('-') {
+        Some((start, end)) => (parse_id(start)?, parse_id(end)?),
+        None => {
+            let id = parse_id(text)?;
+            (id, id)
+        }
+    };
+    (start <= end).then_some(start..=end)
+}
+
+/// 显卡类型规则表
+#[derive(Debug, Default)]
+pub struct GpuRuleDatabase {
+    rules: Vec<GpuRule>,
+}
+
+impl GpuRuleDatabase {
+    /// 从 TOML 文本加载规则
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        let file: GpuRuleFile = rules::parse(content)?;
+        let rules = file
+            .rules
+            .into_iter()
+            .map(GpuRule::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rules })
+    }
+
+    /// 合并 TOML 文本中的规则，新规则先于已有规则匹配
+    pub fn merge_toml(&mut self, content: &str) -> Result<(), String> {
+        let mut db = Self::from_toml(content)?;
+        db.rules.append(&mut self.rules);
+        self.rules = db.rules;
+        Ok(())
+    }
+
+    /// 加载内置规则，并合并程序目录下的用户规则
+    pub fn load() -> Self {
+        let mut db = Self::from_toml(BUILTIN_GPU_RULES).unwrap_or_else(|e| {
+            log::warn!("{}", e);
+            Self::default()
+        });
+        if let Some(content) = rules::read_beside_exe(USER_GPU_RULES_FILE) {
+            if let Err(e) = db.merge_toml(&content) {
+                log::warn!("{}: {}", USER_GPU_RULES_FILE, e);
+            }
+        }
+        db
+    }
+
+    /// 全部规则，按匹配顺序排列
+    pub fn rules(&self) -> &[GpuRule] {
+        &self.rules
+    }
+
+    /// 查找第一条命中的规则
+    ///
+    /// * `description` - 显卡描述
+    /// * `vendor_id` - 厂商 ID，未知时为 None
+    /// * `device_id` - 设备 ID，未知时为 None
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::gpu::GpuType;
+    /// use hardware_master::iddb::gpu::GPU_RULE_DB;
+    /// let rule = GPU_RULE_DB
+    ///     .classify("AMD Radeon(TM) Graphics", Some(0x1002), Some(0x1681))
+    ///     .expect("应命中规则");
+    /// assert_eq!(rule.gpu_type, GpuType::IntegratedGpu);
+    /// assert!(GPU_RULE_DB.classify("Unknown Adapter", None, None).is_none());
+    /// ```
+    pub fn classify(
+        &self,
+        description: &str,
+        vendor_id: Option<u16>,
+        device_id: Option<u16>,
+    ) -> Option<&GpuRule> {
+        let description = description.to_lowercase();
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(&description, vendor_id, device_id))
+    }
+}
+
+pub static GPU_RULE_DB: LazyLock<GpuRuleDatabase> = LazyLock::new(GpuRuleDatabase::load);
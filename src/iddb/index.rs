@@ -0,0 +1,197 @@
+//! .ids 文件的紧凑索引
+//!
+//! 只记录厂商行与设备行在原始数据中的位置，按 ID 排序后二分查找，名称在查询时才从原始数据中解析。
+//! 内置数据直接引用嵌入程序的字节，不再复制成大量字符串。
+
+use std::borrow::Cow;
+
+/// 索引中的一个厂商或设备
+#[derive(Debug, Clone, Copy)]
+struct IdsEntry {
+    /// 厂商 ID
+    vendor_id: u16,
+    /// 设备 ID，厂商条目为 0
+    device_id: u16,
+    /// 该行在原始数据中的起始位置
+    start: u32,
+    /// 该条目（含下属设备或子系统行）在原始数据中的结束位置
+    end: u32,
+}
+
+/// 单个 .ids 文件的索引
+#[derive(Debug, Default)]
+pub(super) struct IdsIndex {
+    content: Cow<'static, [u8]>,
+    /// 按厂商 ID 排序
+    vendors: Vec<IdsEntry>,
+    /// 按厂商 ID、设备 ID 排序
+    devices: Vec<IdsEntry>,
+}
+
+impl IdsIndex {
+    /// 为 .ids 文件内容建立索引
+    pub(super) fn new(content: Cow<'static, [u8]>) -> Self {
+        let (vendors, devices) = index_entries(&content);
+        Self {
+            content,
+            vendors,
+            devices,
+        }
+    }
+
+    /// 厂商数
+    pub(super) fn vendor_count(&self) -> usize {
+        self.vendors.len()
+    }
+
+    /// 查询厂商名称
+    pub(super) fn vendor_name(&self, vendor_id: u16) -> Option<String> {
+        let index = self
+            .vendors
+            .binary_search_by_key(&vendor_id, |entry| entry.vendor_id)
+            .ok()?;
+        self.entry_name(&self.vendors[index])
+    }
+
+    /// 查询设备名称
+    pub(super) fn device_name(&self, vendor_id: u16, device_id: u16) -> Option<String> {
+        let entry = self.device(vendor_id, device_id)?;
+        self.entry_name(entry)
+    }
+
+    /// 在设备条目的下属行中查询子系统（PCI）或接口（USB）名称
+    ///
+    /// * `ids` - 下属行的 ID，PCI 为 [子系统厂商 ID, 子系统设备 ID]，USB 为 [接口 ID]
+    pub(super) fn subsystem_name(
+        &self,
+        vendor_id: u16,
+        device_id: u16,
+        ids: &[u16],
+    ) -> Option<String> {
+        let entry = self.device(vendor_id, device_id)?;
+        let block = &self.content[entry.start as usize..entry.end as usize];
+        block
+            .split(|b| *b == b'\n')
+            .filter(|line| line.starts_with(b"\t\t"))
+            .filter_map(|line| split_line(line))
+            .find(|(id_part, _)| {
+                let parsed: Vec<Option<u16>> = id_part
+                    .split(|b| b.is_ascii_whitespace())
+                    .filter(|part| !part.is_empty())
+                    .map(parse_hex)
+                    .collect();
+                parsed.len() == ids.len() && parsed.iter().zip(ids).all(|(a, b)| *a == Some(*b))
+            })
+            .map(|(_, name)| to_name(name))
+    }
+
+    fn device(&self, vendor_id: u16, device_id: u16) -> Option<&IdsEntry> {
+        let index = self
+            .devices
+            .binary_search_by_key(&(vendor_id, device_id), |entry| {
+                (entry.vendor_id, entry.device_id)
+            })
+            .ok()?;
+        Some(&self.devices[index])
+    }
+
+    /// 解析条目所在行的名称
+    fn entry_name(&self, entry: &IdsEntry) -> Option<String> {
+        let line = self.content[entry.start as usize..entry.end as usize]
+            .split(|b| *b == b'\n')
+            .next()?;
+        split_line(line).map(|(_, name)| to_name(name))
+    }
+}
+
+/// 统计 .ids 文件内容中的厂商数
+#[cfg(feature = "ids-updater")]
+pub(super) fn count_vendors(content: &[u8]) -> usize {
+    index_entries(content).0.len()
+}
+
+/// 扫描一遍 .ids 文件内容，记录厂商行与设备行的位置
+///
+/// 顶层 ID 不是 4 位十六进制的段落（如 `C 00` 设备类别）及其下属行不计入索引；
+/// 同一 ID 重复出现时以第一次为准
+fn index_entries(content: &[u8]) -> (Vec<IdsEntry>, Vec<IdsEntry>) {
+    let mut vendors: Vec<IdsEntry> = Vec::new();
+    let mut devices: Vec<IdsEntry> = Vec::new();
+    let mut vendor: Option<IdsEntry> = None;
+    let mut device: Option<IdsEntry> = None;
+
+    let mut offset = 0usize;
+    for line in content.split_inclusive(|b| *b == b'\n') {
+        let start = offset;
+        offset += line.len();
+        if line.trim_ascii().is_empty() || line.starts_with(b"#") {
+            continue;
+        }
+
+        let level = line.iter().take_while(|b| **b == b'\t').count();
+        if level >= 2 {
+            continue;
+        }
+        if let Some(mut entry) = device.take() {
+            entry.end = start as u32;
+            devices.push(entry);
+        }
+        let id = split_line(line).and_then(|(id_part, _)| parse_hex_exact(id_part));
+        if level == 0 {
+            if let Some(mut entry) = vendor.take() {
+                entry.end = start as u32;
+                vendors.push(entry);
+            }
+            vendor = id.map(|vendor_id| IdsEntry {
+                vendor_id,
+                device_id: 0,
+                start: start as u32,
+                end: 0,
+            });
+        } else if let (Some(vendor), Some(device_id)) = (&vendor, id) {
+            device = Some(IdsEntry {
+                vendor_id: vendor.vendor_id,
+                device_id,
+                start: start as u32,
+                end: 0,
+            });
+        }
+    }
+    let end = content.len() as u32;
+    devices.extend(device.map(|entry| IdsEntry { end, ..entry }));
+    vendors.extend(vendor.map(|entry| IdsEntry { end, ..entry }));
+
+    vendors.sort_by_key(|entry| entry.vendor_id);
+    vendors.dedup_by_key(|entry| entry.vendor_id);
+    devices.sort_by_key(|entry| (entry.vendor_id, entry.device_id));
+    devices.dedup_by_key(|entry| (entry.vendor_id, entry.device_id));
+    (vendors, devices)
+}
+
+/// 将一行拆分为 ID 部分与名称，两者以两个空格分隔
+fn split_line(line: &[u8]) -> Option<(&[u8], &[u8])> {
+    let line = line.trim_ascii();
+    let pos = line.windows(2).position(|pair| pair == b"  ")?;
+    Some((line[..pos].trim_ascii(), line[pos..].trim_ascii()))
+}
+
+/// 将名称字节转换为字符串
+fn to_name(name: &[u8]) -> String {
+    String::from_utf8_lossy(name).into_owned()
+}
+
+/// 解析 1 到 4 位十六进制 ID
+fn parse_hex(bytes: &[u8]) -> Option<u16> {
+    if bytes.is_empty() || bytes.len() > 4 || !bytes.iter().all(u8::is_ascii_hexdigit) {
+        return None;
+    }
+    u16::from_str_radix(std::str::from_utf8(bytes).ok()?, 16).ok()
+}
+
+/// 解析恰好 4 位的十六进制 ID（厂商行与设备行的格式）
+fn parse_hex_exact(bytes: &[u8]) -> Option<u16> {
+    if bytes.len() != 4 {
+        return None;
+    }
+    parse_hex(bytes)
+}
@@ -1,197 +1,337 @@
-use std::collections::HashMap;
-use std::io::{BufRead, BufReader, Cursor};
-use std::sync::LazyLock;
+pub mod chipset;
+pub mod gpu;
+mod index;
+pub mod panel;
+#[cfg(feature = "ids-updater")]
+pub mod updater;
+pub mod vendor_zh;
 
-/// 描述查询结果的结构体
-#[derive(Debug, Clone)]
-pub struct DeviceDescription {
-    pub vendor_name: String,
-    pub device_name: Option<String>,
-    pub subsystem_name: Option<String>,
+use crate::config::AppConfig;
+use index::IdsIndex;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, RwLock};
+
+/// 用户 PCI 设备数据文件名
+pub const PCI_IDS_FILE: &str = "pci.ids";
+
+/// 用户 USB 设备数据文件名
+pub const USB_IDS_FILE: &str = "usb.ids";
+
+/// 设备数据来源
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum IdsSource {
+    /// 程序内置的数据
+    #[default]
+    Embedded,
+    /// 用户提供的数据文件
+    File(PathBuf),
+}
+
+impl std::fmt::Display for IdsSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdsSource::Embedded => write!(f, "内置"),
+            IdsSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// 设备 ID 所属的总线
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bus {
+    Pci,
+    Usb,
 }
 
-// 内部存储结构
-#[derive(Debug, Clone)]
-struct DeviceEntry {
-    name: String,
-    // Key: 对于 PCI 是 "sub_vendor sub_device"，对于 USB 是 Interface ID
-    subsystems: HashMap<String, String>,
+impl Bus {
+    /// 由设备实例 ID 的枚举器名称（第一段）转换，不支持的总线返回 None
+    ///
+    /// * `enumerator` - 枚举器名称，如 "PCI"
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::iddb::Bus;
+    /// assert_eq!(Bus::from_enumerator("PCI"), Some(Bus::Pci));
+    /// assert_eq!(Bus::from_enumerator("usb"), Some(Bus::Usb));
+    /// assert_eq!(Bus::from_enumerator("HDAUDIO"), None);
+    /// ```
+    pub fn from_enumerator(enumerator: &str) -> Option<Self> {
+        if enumerator.eq_ignore_ascii_case("PCI") {
+            Some(Bus::Pci)
+        } else if enumerator.eq_ignore_ascii_case("USB") {
+            Some(Bus::Usb)
+        } else {
+            None
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
-struct VendorEntry {
-    name: String,
-    devices: HashMap<String, DeviceEntry>,
+/// 解析十六进制 ID（允许 0x 前缀与首尾空白），用于转换设备实例 ID 中的字符串 ID
+///
+/// * `id` - 十六进制 ID，如 "10EC"
+///
+/// 示例
+/// ```
+/// use hardware_master::iddb::parse_id;
+/// assert_eq!(parse_id("10EC"), Some(0x10ec));
+/// assert_eq!(parse_id(" 0x8168 "), Some(0x8168));
+/// assert_eq!(parse_id("未知"), None);
+/// ```
+pub fn parse_id(id: &str) -> Option<u16> {
+    let id = id.trim().trim_start_matches("0x").trim_start_matches("0X");
+    u16::from_str_radix(id, 16).ok()
+}
+
+/// 格式化数据表中的标识（去除首尾空白，转大写），芯片组与面板数据按格式化后的标识查找
+///
+/// * `id` - 标识，如 "8086:7a84"
+///
+/// 示例
+/// ```
+/// use hardware_master::iddb::format_id;
+/// assert_eq!(format_id(" 8086:7a84\n"), "8086:7A84");
+/// assert_eq!(format_id("boe0900"), "BOE0900");
+/// ```
+pub fn format_id(id: &str) -> String {
+    id.trim().to_uppercase()
 }
 
 /// 设备 ID 数据库
+///
+/// 只为原始 .ids 数据建立按 ID 排序的索引，名称在查询时才解析，内置数据不复制
+#[derive(Default)]
 pub struct DeviceIdDatabase {
-    pci_db: HashMap<String, VendorEntry>,
-    usb_db: HashMap<String, VendorEntry>,
+    pci_db: IdsIndex,
+    usb_db: IdsIndex,
+    pci_source: IdsSource,
+    usb_source: IdsSource,
 }
 
 impl DeviceIdDatabase {
     /// 创建并加载数据库
     pub fn new(pci_content: &[u8], usb_content: &[u8]) -> std::io::Result<Self> {
-        let pci_db = Self::load_ids_content(pci_content);
-        let usb_db = Self::load_ids_content(usb_content);
-        Ok(Self { pci_db, usb_db })
+        let pci_db = IdsIndex::new(Cow::Owned(pci_content.to_vec()));
+        let usb_db = IdsIndex::new(Cow::Owned(usb_content.to_vec()));
+        Ok(Self {
+            pci_db,
+            usb_db,
+            ..Default::default()
+        })
     }
 
-    /// 解析 .ids 字符串内容
-    fn load_ids_content(content: &[u8]) -> HashMap<String, VendorEntry> {
-        let mut map = HashMap::new();
+    /// 由 .ids 格式的文本创建数据库，用于测试与示例中构造小型数据
+    ///
+    /// * `pci` - pci.ids 格式的文本
+    /// * `usb` - usb.ids 格式的文本
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::iddb::DeviceIdDatabase;
+    /// let db = DeviceIdDatabase::from_strings("10ec  Realtek\n\t8168  RTL8168\n", "");
+    /// assert_eq!(db.pci().vendor(0x10ec).device(0x8168).name().as_deref(), Some("RTL8168"));
+    /// assert_eq!(db.usb().vendor(0x10ec).name(), None);
+    /// ```
+    pub fn from_strings(pci: &str, usb: &str) -> Self {
+        Self {
+            pci_db: IdsIndex::new(Cow::Owned(pci.as_bytes().to_vec())),
+            usb_db: IdsIndex::new(Cow::Owned(usb.as_bytes().to_vec())),
+            ..Default::default()
+        }
+    }
 
-        let cursor = Cursor::new(content);
-        let reader = BufReader::new(cursor);
+    /// 依次在给定目录中查找 pci.ids 与 usb.ids，找到的文件优先于内置数据
+    ///
+    /// 每种数据各自取第一个能读取且包含有效条目的文件，都没有时使用内置数据
+    ///
+    /// * `dirs` - 按优先级排列的查找目录
+    pub fn load_from_paths(dirs: &[PathBuf]) -> Self {
+        let (pci_db, pci_source) = Self::load_ids_file(dirs, PCI_IDS_FILE, PCI_IDS_BYTES);
+        let (usb_db, usb_source) = Self::load_ids_file(dirs, USB_IDS_FILE, USB_IDS_BYTES);
+        Self {
+            pci_db,
+            usb_db,
+            pci_source,
+            usb_source,
+        }
+    }
 
-        let mut current_vendor_id: Option<String> = None;
-        let mut current_device_id: Option<String> = None;
+    /// 默认查找目录：程序所在目录优先，其次是配置目录 (%APPDATA%\hardware-master)
+    pub fn default_search_dirs() -> Vec<PathBuf> {
+        let exe_dir = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf));
+        exe_dir.into_iter().chain(AppConfig::config_dir()).collect()
+    }
 
-        for line in reader.lines() {
-            let line = match line {
-                Ok(l) => l,
-                Err(_) => continue,
-            };
+    /// PCI 设备数据来源
+    pub fn pci_source(&self) -> &IdsSource {
+        &self.pci_source
+    }
 
-            let line_str = line.trim();
-            if line_str.is_empty() || line_str.starts_with('#') {
-                continue;
-            }
+    /// USB 设备数据来源
+    pub fn usb_source(&self) -> &IdsSource {
+        &self.usb_source
+    }
 
-            let level = line.len() - line.trim_start_matches('\t').len();
-
-            match level {
-                0 => {
-                    if let Some((vid, vname)) = line_str.split_once("  ") {
-                        current_vendor_id = Some(vid.trim().to_string());
-                        current_device_id = None;
-                        map.entry(vid.trim().to_string())
-                            .or_insert_with(|| VendorEntry {
-                                name: vname.trim().to_string(),
-                                devices: HashMap::new(),
-                            });
-                    }
-                }
-                1 => {
-                    if let (Some(ref vid), Some((did, dname))) =
-                        (&current_vendor_id, line_str.split_once("  "))
-                    {
-                        current_device_id = Some(did.trim().to_string());
-                        if let Some(vendor) = map.get_mut(vid) {
-                            vendor
-                                .devices
-                                .entry(did.trim().to_string())
-                                .or_insert_with(|| DeviceEntry {
-                                    name: dname.trim().to_string(),
-                                    subsystems: HashMap::new(),
-                                });
-                        }
-                    }
-                }
-                2 => {
-                    if let (Some(ref vid), Some(ref did)) = (&current_vendor_id, &current_device_id)
-                    {
-                        if let Some(vendor) = map.get_mut(vid) {
-                            if let Some(device) = vendor.devices.get_mut(did) {
-                                if let Some((id_part, sub_name)) = line_str.split_once("  ") {
-                                    device.subsystems.insert(
-                                        id_part.trim().to_string(),
-                                        sub_name.trim().to_string(),
-                                    );
-                                }
-                            }
-                        }
-                    }
+    /// 在查找目录中加载指定的数据文件，没有可用文件时解析内置数据
+    ///
+    /// * `dirs` - 按优先级排列的查找目录
+    /// * `file_name` - 数据文件名
+    /// * `embedded` - 内置数据
+    fn load_ids_file(
+        dirs: &[PathBuf],
+        file_name: &str,
+        embedded: &'static [u8],
+    ) -> (IdsIndex, IdsSource) {
+        for dir in dirs {
+            let path = dir.join(file_name);
+            let content = match std::fs::read(&path) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    log::warn!("读取 {} 失败: {}", path.display(), e);
+                    continue;
                 }
-                _ => {}
+            };
+            let index = IdsIndex::new(Cow::Owned(content));
+            if index.vendor_count() == 0 {
+                log::warn!("{} 中没有有效的设备数据，已忽略", path.display());
+                continue;
             }
+            log::info!("使用设备数据文件 {}", path.display());
+            return (index, IdsSource::File(path));
         }
-        map
+        (IdsIndex::new(Cow::Borrowed(embedded)), IdsSource::Embedded)
     }
 
-    /// 核心查询函数
+    /// 开始查询指定总线上的设备
     ///
-    /// * `bus_type`: "PCI" 或 "USB"
-    /// * `vendor_id`: 厂商 ID (例如 "10EC")
-    /// * `device_id`: 设备 ID (可选，例如 "8168")
-    /// * `subsystem_vendor_id`: 子系统厂商 ID (可选，仅 PCI 有效)
-    /// * `subsystem_device_id`: 子系统设备 ID (可选，仅 PCI 有效)
-    pub fn lookup(
-        &self,
-        bus_type: &str,
-        vendor_id: &str,
-        device_id: Option<&str>,
-        subsystem_vendor_id: Option<&str>,
-        subsystem_device_id: Option<&str>,
-    ) -> Option<DeviceDescription> {
-        let db = match bus_type.to_uppercase().as_str() {
-            "PCI" => &self.pci_db,
-            "USB" => &self.usb_db,
-            _ => return None,
+    /// 示例
+    /// ```
+    /// use hardware_master::iddb::{self, Bus};
+    /// let db = iddb::db();
+    /// let realtek = db.bus(Bus::Pci).vendor(0x10ec);
+    /// assert_eq!(realtek.name().as_deref(), Some("Realtek Semiconductor Co., Ltd."));
+    /// ```
+    pub fn bus(&self, bus: Bus) -> BusQuery<'_> {
+        let index = match bus {
+            Bus::Pci => &self.pci_db,
+            Bus::Usb => &self.usb_db,
         };
+        BusQuery { index }
+    }
 
-        // 格式化 ID：去除 0x 前缀，不足4位补0
-        let fmt_vid = Self::format_id(vendor_id);
-
-        let vendor_entry = db.get(&fmt_vid)?;
-        let mut device_name_opt = None;
-        let mut subsystem_name_opt = None;
-
-        // 查询设备
-        if let Some(did) = device_id {
-            let fmt_did = Self::format_id(did);
-            if let Some(device_entry) = vendor_entry.devices.get(&fmt_did) {
-                device_name_opt = Some(device_entry.name.clone());
-
-                // 查询子系统
-                if bus_type.to_uppercase() == "PCI" {
-                    if let (Some(svid), Some(sdid)) = (subsystem_vendor_id, subsystem_device_id) {
-                        let fmt_svid = Self::format_id(svid);
-                        let fmt_sdid = Self::format_id(sdid);
-                        let sub_key = format!("{} {}", fmt_svid, fmt_sdid);
-                        if let Some(sub_name) = device_entry.subsystems.get(&sub_key) {
-                            subsystem_name_opt = Some(sub_name.to_string());
-                        }
-                    }
-                } else if bus_type.to_uppercase() == "USB" {
-                    // USB 通常只有一个 Interface ID，复用 subsystem_vendor_id 参数或者忽略 subsystem
-                    // subsystem_vendor_id 当作 interface ID 传入
-                    if let Some(intf_id) = subsystem_vendor_id {
-                        let fmt_intfid = Self::format_id(intf_id);
-                        if let Some(intf_name) = device_entry.subsystems.get(&fmt_intfid) {
-                            subsystem_name_opt = Some(intf_name.to_string());
-                        }
-                    }
-                }
-            }
-        }
+    /// 开始查询 PCI 设备
+    pub fn pci(&self) -> BusQuery<'_> {
+        self.bus(Bus::Pci)
+    }
 
-        Some(DeviceDescription {
-            vendor_name: vendor_entry.name.to_string(),
-            device_name: device_name_opt,
-            subsystem_name: subsystem_name_opt,
-        })
+    /// 开始查询 USB 设备
+    pub fn usb(&self) -> BusQuery<'_> {
+        self.bus(Bus::Usb)
     }
+}
+
+const PCI_IDS_BYTES: &[u8] = include_bytes!("pci.ids");
+const USB_IDS_BYTES: &[u8] = include_bytes!("usb.ids");
 
-    /// 格式化 ID (去除 0x，转小写，补全4位)
-    fn format_id(id: &str) -> String {
-        let id = id.trim().trim_start_matches("0x").trim_start_matches("0X");
-        format!("{:0>4}", id.to_lowercase())
+/// 某一总线上的查询
+#[derive(Debug, Clone, Copy)]
+pub struct BusQuery<'a> {
+    index: &'a IdsIndex,
+}
+
+impl<'a> BusQuery<'a> {
+    /// 指定厂商 ID
+    pub fn vendor(self, vendor_id: u16) -> VendorQuery<'a> {
+        VendorQuery {
+            index: self.index,
+            vendor_id,
+        }
     }
 }
 
-impl Default for DeviceIdDatabase {
-    fn default() -> Self {
-        Self {
-            pci_db: HashMap::new(),
-            usb_db: HashMap::new(),
+/// 某一厂商的查询
+#[derive(Debug, Clone, Copy)]
+pub struct VendorQuery<'a> {
+    index: &'a IdsIndex,
+    vendor_id: u16,
+}
+
+impl<'a> VendorQuery<'a> {
+    /// 厂商名称，数据库中没有该厂商时为 None
+    pub fn name(&self) -> Option<String> {
+        self.index.vendor_name(self.vendor_id)
+    }
+
+    /// 指定设备 ID
+    pub fn device(self, device_id: u16) -> DeviceQuery<'a> {
+        DeviceQuery {
+            index: self.index,
+            vendor_id: self.vendor_id,
+            device_id,
         }
     }
 }
 
-const PCI_IDS_BYTES: &[u8] = include_bytes!("pci.ids");
-const USB_IDS_BYTES: &[u8] = include_bytes!("usb.ids");
+/// 某一设备的查询
+#[derive(Debug, Clone, Copy)]
+pub struct DeviceQuery<'a> {
+    index: &'a IdsIndex,
+    vendor_id: u16,
+    device_id: u16,
+}
+
+impl DeviceQuery<'_> {
+    /// 设备名称，数据库中没有该设备时为 None
+    pub fn name(&self) -> Option<String> {
+        self.index.device_name(self.vendor_id, self.device_id)
+    }
+
+    /// PCI 子系统名称（通常是整机或板卡型号）
+    ///
+    /// * `subsystem_vendor_id` - 子系统厂商 ID
+    /// * `subsystem_device_id` - 子系统设备 ID
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::iddb;
+    /// let db = iddb::db();
+    /// let nic = db.pci().vendor(0x10ec).device(0x8168);
+    /// assert_eq!(nic.subsystem(0x1028, 0x0283).as_deref(), Some("Vostro 220"));
+    /// ```
+    pub fn subsystem(&self, subsystem_vendor_id: u16, subsystem_device_id: u16) -> Option<String> {
+        self.index.subsystem_name(
+            self.vendor_id,
+            self.device_id,
+            &[subsystem_vendor_id, subsystem_device_id],
+        )
+    }
+
+    /// USB 接口名称
+    ///
+    /// * `interface_id` - 接口 ID
+    pub fn interface(&self, interface_id: u16) -> Option<String> {
+        self.index
+            .subsystem_name(self.vendor_id, self.device_id, &[interface_id])
+    }
+}
 
-pub static DB: LazyLock<DeviceIdDatabase> =
-    LazyLock::new(|| DeviceIdDatabase::new(PCI_IDS_BYTES, USB_IDS_BYTES).unwrap_or_default());
+/// 当前使用的设备 ID 数据库，更新数据文件后可通过 [`reload`] 替换
+pub static DB: LazyLock<RwLock<Arc<DeviceIdDatabase>>> = LazyLock::new(|| {
+    RwLock::new(Arc::new(DeviceIdDatabase::load_from_paths(
+        &DeviceIdDatabase::default_search_dirs(),
+    )))
+});
+
+/// 获取当前使用的设备 ID 数据库
+pub fn db() -> Arc<DeviceIdDatabase> {
+    Arc::clone(&DB.read().expect("设备数据库读写锁被污染"))
+}
+
+/// 从默认查找目录重新加载设备 ID 数据库，之后的查询使用新数据
+pub fn reload() {
+    let db = DeviceIdDatabase::load_from_paths(&DeviceIdDatabase::default_search_dirs());
+    *DB.write().expect("设备数据库读写锁被污染") = Arc::new(db);
+}
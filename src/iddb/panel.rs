@@ -0,0 +1,151 @@
+//! 面板数据库
+//!
+//! 以 EDID 面板标识（厂商代码 + 产品代码，如 "BOE0900"）查询背光类型。
+//! 内置数据见 `panels.toml`，程序所在目录下的同名文件可补充或覆盖内置条目。
+
+use super::format_id;
+use crate::analysis::rules;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
+
+/// 内置面板数据
+const BUILTIN_PANELS: &str = include_str!("panels.toml");
+
+/// 用户面板数据文件名（放在程序所在目录）
+pub const USER_PANELS_FILE: &str = "panels.toml";
+
+/// 背光类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(rename_all = "lowercase")]
+pub enum BacklightType {
+    /// 未知
+    #[default]
+    Unknown,
+    /// 冷阴极荧光灯管
+    Ccfl,
+    /// 白光 LED
+    Wled,
+    /// Mini LED
+    MiniLed,
+    /// OLED（自发光，无背光）
+    Oled,
+}
+
+impl BacklightType {
+    /// 根据面板生产年份推测背光类型
+    ///
+    /// 只推测 2009 年及以前的 CCFL 背光；之后的面板可能是 WLED、Mini LED 或 OLED，
+    /// 仅凭年份无法区分，与年份未知时一样返回 `Unknown`
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::iddb::panel::BacklightType;
+    /// assert_eq!(BacklightType::guess_from_year(2008), BacklightType::Ccfl);
+    /// assert_eq!(BacklightType::guess_from_year(2021), BacklightType::Unknown);
+    /// ```
+    pub fn guess_from_year(year: u16) -> Self {
+        match year {
+            1..=2009 => BacklightType::Ccfl,
+            _ => BacklightType::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for BacklightType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            BacklightType::Unknown => "未知",
+            BacklightType::Ccfl => "CCFL",
+            BacklightType::Wled => "WLED",
+            BacklightType::MiniLed => "Mini LED",
+            BacklightType::Oled => "OLED",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 面板条目
+#[derive(Debug, Clone, Deserialize)]
+pub struct PanelEntry {
+    /// 面板标识，如 "BOE0900"
+    pub id: String,
+    /// 面板型号
+    #[serde(default)]
+    pub model: Option<String>,
+    /// 背光类型
+    #[serde(default)]
+    pub backlight: BacklightType,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PanelFile {
+    #[serde(default)]
+    panels: Vec<PanelEntry>,
+}
+
+/// 面板数据库
+#[derive(Debug, Default)]
+pub struct PanelDatabase {
+    panels: HashMap<String, PanelEntry>,
+}
+
+impl PanelDatabase {
+    /// 从 TOML 文本加载面板数据
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        let mut db = Self::default();
+        db.merge_toml(content)?;
+        Ok(db)
+    }
+
+    /// 合并 TOML 文本中的面板数据，标识相同的条目以后者为准
+    pub fn merge_toml(&mut self, content: &str) -> Result<(), String> {
+        let file: PanelFile = rules::parse(content)?;
+        for panel in file.panels {
+            self.panels.insert(format_id(&panel.id), panel);
+        }
+        Ok(())
+    }
+
+    /// 加载内置数据，并合并程序目录下的用户数据
+    pub fn load() -> Self {
+        let mut db = Self::from_toml(BUILTIN_PANELS).unwrap_or_else(|e| {
+            log::warn!("{}", e);
+            Self::default()
+        });
+        if let Some(content) = rules::read_beside_exe(USER_PANELS_FILE) {
+            if let Err(e) = db.merge_toml(&content) {
+                log::warn!("{}: {}", USER_PANELS_FILE, e);
+            }
+        }
+        db
+    }
+
+    /// 查询面板
+    ///
+    /// * `manufacturer` - EDID 厂商代码，如 "BOE"
+    /// * `product_code` - EDID 产品代码，如 "0900"
+    pub fn lookup(&self, manufacturer: &str, product_code: &str) -> Option<&PanelEntry> {
+        self.panels
+            .get(&format_id(&Self::panel_id(manufacturer, product_code)))
+    }
+
+    /// 拼接面板标识
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::iddb::panel::PanelDatabase;
+    /// assert_eq!(PanelDatabase::panel_id("boe", "900"), "BOE0900");
+    /// ```
+    pub fn panel_id(manufacturer: &str, product_code: &str) -> String {
+        format!(
+            "{}{:0>4}",
+            manufacturer.trim().to_uppercase(),
+            product_code.trim().to_uppercase()
+        )
+    }
+}
+
+pub static PANEL_DB: LazyLock<PanelDatabase> = LazyLock::new(PanelDatabase::load);
@@ -0,0 +1,178 @@
+//! 设备数据库在线更新模块
+//!
+//! 从官方镜像下载最新的 pci.ids 与 usb.ids，校验通过后保存到配置目录并重新加载 [`DB`](super::DB)。
+//! 程序目录下的同名文件优先级更高，存在时更新后的数据不会生效。
+//!
+//! 需要启用 `ids-updater` 特性。
+
+#[cfg(windows)]
+use super::{PCI_IDS_FILE, USB_IDS_FILE};
+#[cfg(windows)]
+use crate::config::AppConfig;
+use crate::constants::IDS_MIN_VENDORS;
+#[cfg(windows)]
+use crate::constants::{PCI_IDS_URL, USB_IDS_URL};
+#[cfg(windows)]
+use crate::utils::string::wide_str;
+use std::path::{Path, PathBuf};
+#[cfg(windows)]
+use windows::core::PCWSTR;
+#[cfg(windows)]
+use windows::Win32::System::Com::Urlmon::URLDownloadToFileW;
+
+/// 一次成功更新的结果
+#[derive(Debug, Clone)]
+pub struct IdsUpdate {
+    /// 新 pci.ids 中的厂商数
+    pub pci_vendors: usize,
+    /// 新 usb.ids 中的厂商数
+    pub usb_vendors: usize,
+    /// 更新时间（Unix 时间戳，秒）
+    pub updated_at: i64,
+}
+
+/// 校验下载的 .ids 数据，返回其中的厂商数
+///
+/// 下载中断或被代理替换为网页时解析出的厂商很少，此时返回错误
+///
+/// * `content` - .ids 文件内容
+///
+/// 示例
+/// ```
+/// use hardware_master::iddb::updater::validate_ids;
+/// assert!(validate_ids(b"<html>404 Not Found</html>").is_err());
+/// ```
+pub fn validate_ids(content: &[u8]) -> Result<usize, String> {
+    let vendors = super::index::count_vendors(content);
+    if vendors < IDS_MIN_VENDORS {
+        return Err(format!("数据不完整，仅包含 {} 个厂商", vendors));
+    }
+    Ok(vendors)
+}
+
+/// 下载并更新 pci.ids 与 usb.ids，完成后重新加载设备数据库
+///
+/// 两个文件都下载并校验通过后才通过 [`replace_files`] 一并替换旧文件，任一失败时保留原有数据
+#[cfg(windows)]
+pub fn update_ids() -> Result<IdsUpdate, String> {
+    let dir = AppConfig::config_dir().ok_or_else(|| "无法确定配置目录".to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| format!("创建配置目录失败: {}", e))?;
+
+    let mut downloads = Vec::new();
+    let mut vendors = Vec::new();
+    for (url, file_name) in [(PCI_IDS_URL, PCI_IDS_FILE), (USB_IDS_URL, USB_IDS_FILE)] {
+        let temp_path = dir.join(format!("{}.download", file_name));
+        let result = download(url, &temp_path).and_then(|()| {
+            let content = std::fs::read(&temp_path)
+                .map_err(|e| format!("读取下载的 {} 失败: {}", file_name, e))?;
+            validate_ids(&content).map_err(|e| format!("{}: {}", file_name, e))
+        });
+        match result {
+            Ok(count) => {
+                vendors.push(count);
+                downloads.push((temp_path, dir.join(file_name)));
+            }
+            Err(e) => {
+                let _ = std::fs::remove_file(&temp_path);
+                for (temp_path, _) in &downloads {
+                    let _ = std::fs::remove_file(temp_path);
+                }
+                return Err(e);
+            }
+        }
+    }
+
+    replace_files(&downloads)?;
+    super::reload();
+
+    Ok(IdsUpdate {
+        pci_vendors: vendors[0],
+        usb_vendors: vendors[1],
+        updated_at: chrono::Local::now().timestamp(),
+    })
+}
+
+/// 用临时文件一并替换目标文件
+///
+/// 先将已有的目标文件改名为 `.backup` 备份，再逐个将临时文件改名为目标文件。
+/// 任一步失败时删除已替换的文件并恢复全部备份，目标文件要么全部更新，要么全部保持原样。
+/// 成功后删除备份，失败时删除临时文件
+///
+/// * `files` - (临时文件, 目标文件) 列表
+pub fn replace_files(files: &[(PathBuf, PathBuf)]) -> Result<(), String> {
+    let backup_path = |path: &Path| {
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(".backup");
+        path.with_file_name(name)
+    };
+    // (目标文件, 备份文件)，目标文件原本不存在时备份为 None
+    let mut backups: Vec<(&Path, Option<PathBuf>)> = Vec::new();
+    let mut replaced = 0;
+
+    let result = (|| {
+        for (_, path) in files {
+            let backup = if path.exists() {
+                let backup = backup_path(path);
+                std::fs::rename(path, &backup)
+                    .map_err(|e| format!("备份 {} 失败: {}", path.display(), e))?;
+                Some(backup)
+            } else {
+                None
+            };
+            backups.push((path, backup));
+        }
+        for (temp_path, path) in files {
+            std::fs::rename(temp_path, path)
+                .map_err(|e| format!("保存 {} 失败: {}", path.display(), e))?;
+            replaced += 1;
+        }
+        Ok(())
+    })();
+
+    match result {
+        Ok(()) => {
+            for (_, backup) in &backups {
+                if let Some(backup) = backup {
+                    let _ = std::fs::remove_file(backup);
+                }
+            }
+            Ok(())
+        }
+        Err(e) => {
+            for (_, path) in &files[..replaced] {
+                let _ = std::fs::remove_file(path);
+            }
+            for (path, backup) in &backups {
+                if let Some(backup) = backup {
+                    if let Err(e) = std::fs::rename(backup, path) {
+                        log::error!("恢复 {} 失败: {}", path.display(), e);
+                    }
+                }
+            }
+            for (temp_path, _) in files {
+                let _ = std::fs::remove_file(temp_path);
+            }
+            Err(e)
+        }
+    }
+}
+
+/// 下载文件
+///
+/// * `url` - 下载地址
+/// * `path` - 保存路径
+#[cfg(windows)]
+fn download(url: &str, path: &Path) -> Result<(), String> {
+    let url_wide = wide_str(url);
+    let path_wide = wide_str(&path.to_string_lossy());
+    unsafe {
+        URLDownloadToFileW(
+            None,
+            PCWSTR::from_raw(url_wide.as_ptr()),
+            PCWSTR::from_raw(path_wide.as_ptr()),
+            0,
+            None,
+        )
+    }
+    .map_err(|e| format!("下载 {} 失败: {}", url, e))
+}
@@ -0,0 +1,104 @@
+//! 厂商中文名称
+//!
+//! 将 pci.ids / usb.ids 中常见厂商的英文名称映射为国内通用的中文品牌名，仅用于界面显示，
+//! 导出的报告与悬停提示仍保留英文原名。
+
+/// 厂商英文名称前缀与中文品牌名对照表
+///
+/// 按前缀匹配，较长的前缀需排在较短的前缀之前
+const VENDOR_NAMES: &[(&str, &str)] = &[
+    ("Advanced Micro Devices", "超威"),
+    ("NVIDIA", "英伟达"),
+    ("Intel", "英特尔"),
+    ("Hygon", "海光"),
+    ("Zhaoxin", "兆芯"),
+    ("Loongson", "龙芯"),
+    ("Moore Threads", "摩尔线程"),
+    ("Micron", "美光"),
+    ("Samsung", "三星"),
+    ("SK hynix", "海力士"),
+    ("Hynix", "海力士"),
+    ("Kingston", "金士顿"),
+    ("Western Digital", "西部数据"),
+    ("Seagate", "希捷"),
+    ("KIOXIA", "铠侠"),
+    ("Toshiba", "东芝"),
+    ("SanDisk", "闪迪"),
+    ("Yangtze Memory", "长江存储"),
+    ("ChangXin", "长鑫存储"),
+    ("Silicon Motion", "慧荣"),
+    ("Phison", "群联"),
+    ("Realtek", "瑞昱"),
+    ("MediaTek", "联发科"),
+    ("Qualcomm", "高通"),
+    ("Broadcom", "博通"),
+    ("Marvell", "美满"),
+    ("VIA Technologies", "威盛"),
+    ("Silicon Integrated Systems", "矽统"),
+    ("ASMedia", "祥硕"),
+    ("Genesys Logic", "创惟"),
+    ("ASUSTeK", "华硕"),
+    ("Micro-Star", "微星"),
+    ("Gigabyte", "技嘉"),
+    ("ASRock", "华擎"),
+    ("Biostar", "映泰"),
+    ("Shenzhen Colorful", "七彩虹"),
+    ("GALAX", "影驰"),
+    ("ZOTAC", "索泰"),
+    ("Palit", "同德"),
+    ("CardExpert", "耕升"),
+    ("Sapphire", "蓝宝石"),
+    ("Tul Corporation", "撼讯"),
+    ("XFX", "讯景"),
+    ("Yeston", "盈通"),
+    ("Manli", "万丽"),
+    ("Lenovo", "联想"),
+    ("Hewlett-Packard", "惠普"),
+    ("Hewlett Packard", "惠普"),
+    ("Dell", "戴尔"),
+    ("Acer", "宏碁"),
+    ("Huawei", "华为"),
+    ("Xiaomi", "小米"),
+    ("Logitech", "罗技"),
+    ("Lite-On", "光宝"),
+    ("Hon Hai", "富士康"),
+    ("Foxconn", "富士康"),
+    ("Sony", "索尼"),
+    ("Panasonic", "松下"),
+    ("Creative", "创新"),
+];
+
+/// 查询厂商的中文品牌名
+///
+/// 厂商名称以对照表中的前缀开头（忽略大小写），且前缀后紧跟非字母数字字符或结束时命中
+///
+/// 示例
+/// ```
+/// use hardware_master::iddb::vendor_zh::localize_vendor;
+/// assert_eq!(localize_vendor("Micron Technology Inc"), Some("美光"));
+/// assert_eq!(localize_vendor("Intellon Corporation"), None);
+/// ```
+pub fn localize_vendor(name: &str) -> Option<&'static str> {
+    let name = name.trim();
+    VENDOR_NAMES.iter().find_map(|(prefix, zh)| {
+        let head = name.get(..prefix.len())?;
+        let boundary = name[prefix.len()..]
+            .chars()
+            .next()
+            .is_none_or(|c| !c.is_alphanumeric());
+        (head.eq_ignore_ascii_case(prefix) && boundary).then_some(*zh)
+    })
+}
+
+/// 获取用于显示的厂商名称
+///
+/// * `name` - 英文原名
+/// * `localize` - 是否启用中文名称，未收录的厂商返回原名
+pub fn display_vendor(name: &str, localize: bool) -> String {
+    if !localize {
+        return name.to_string();
+    }
+    localize_vendor(name)
+        .map(str::to_string)
+        .unwrap_or_else(|| name.to_string())
+}
@@ -3,9 +3,22 @@
 //! 这是一个类似鲁大师的硬件检测工具，使用 Rust 和 egui 开发。
 //! 提供全面的硬件信息检测和友好的 GUI 界面。
 
+pub mod agent;
+pub mod analysis;
+pub mod asset;
+pub mod benchmark;
+pub mod cache;
+pub mod config;
 pub mod constants;
+pub mod daemon;
 pub mod detector;
+pub mod handover;
+pub mod history;
 pub mod iddb;
+pub mod realtime;
+pub mod report;
+pub mod server;
+pub mod snapshot;
 pub mod ui;
 pub mod utils;
 
@@ -1,20 +1,78 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+#[cfg(windows)]
 use eframe::egui;
+#[cfg(windows)]
 use egui::IconData;
+#[cfg(windows)]
 use hardware_master::{
-    constants::{WINDOW_HEIGHT, WINDOW_MIN_HEIGHT, WINDOW_MIN_WIDTH, WINDOW_WIDTH},
+    agent::AgentOptions,
+    config::AppConfig,
+    constants::{WINDOW_MIN_HEIGHT, WINDOW_MIN_WIDTH},
+    daemon::DaemonOptions,
+    handover::HandoverState,
+    server::ServeOptions,
     ui::HardwareMasterApp,
     NAME, VERSION,
 };
+#[cfg(windows)]
 use image;
+#[cfg(windows)]
 use log::LevelFilter;
 
+#[cfg(windows)]
 fn main() -> Result<(), eframe::Error> {
     // 初始化日志记录器
     env_logger::builder()
         .filter(Some(&NAME.replace("-", "_")), LevelFilter::Debug)
         .init();
 
+    // 定时检测模式：不显示界面，检测并记录日志后退出
+    match DaemonOptions::from_args(std::env::args()) {
+        Ok(Some(daemon)) => {
+            let code = match run_daemon(&daemon) {
+                Ok(()) => 0,
+                Err(e) => {
+                    log::error!("{}", e);
+                    1
+                }
+            };
+            std::process::exit(code);
+        }
+        Ok(None) => {}
+        Err(e) => {
+            log::error!("{}", e);
+            std::process::exit(2);
+        }
+    }
+
+    // 远程上报模式：不显示界面，检测并上报到收集服务后退出
+    match AgentOptions::from_args(std::env::args()) {
+        Ok(Some(agent)) => {
+            let code = match run_agent(&agent) {
+                Ok(()) => 0,
+                Err(e) => {
+                    log::error!("{}", e);
+                    1
+                }
+            };
+            std::process::exit(code);
+        }
+        Ok(None) => {}
+        Err(e) => {
+            log::error!("{}", e);
+            std::process::exit(2);
+        }
+    }
+
+    // 本地 HTTP 接口参数
+    let serve = match ServeOptions::from_args(std::env::args()) {
+        Ok(serve) => serve,
+        Err(e) => {
+            log::error!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
     // 应用图标创建
     let icon_bytes = include_bytes!("assets/icons/icon.webp");
     let icon_img = image::load_from_memory_with_format(icon_bytes, image::ImageFormat::WebP)
@@ -28,14 +86,27 @@ fn main() -> Result<(), eframe::Error> {
         height: h,
     };
 
-    // 创建窗口选项
+    // 以管理员身份重新启动时，读取上一个进程交接的状态
+    let handover = HandoverState::path_from_args(std::env::args()).and_then(|path| {
+        HandoverState::take(&path)
+            .map_err(|e| log::warn!("{}", e))
+            .ok()
+    });
+
+    // 加载用户配置，优先使用交接的配置
+    let config = match &handover {
+        Some(state) => state.config.clone(),
+        None => AppConfig::load(),
+    };
+
+    // 创建窗口选项（窗口尺寸由用户配置决定）
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_icon(icon)
-            .with_inner_size([WINDOW_WIDTH, WINDOW_HEIGHT])
+            .with_inner_size([config.window_width, config.window_height])
             .with_min_inner_size([WINDOW_MIN_WIDTH, WINDOW_MIN_HEIGHT])
-            .with_max_inner_size([WINDOW_WIDTH, WINDOW_HEIGHT])
             .with_maximize_button(false),
+        persist_window: false,
         ..Default::default()
     };
 
@@ -46,6 +117,47 @@ fn main() -> Result<(), eframe::Error> {
             VERSION
         ),
         options,
-        Box::new(|cc| Ok(Box::new(HardwareMasterApp::new(cc)))),
+        Box::new(move |cc| {
+            let mut app = HardwareMasterApp::new(cc, config, handover);
+            if let Some(serve) = &serve {
+                app.start_server(serve);
+            }
+            Ok(Box::new(app))
+        }),
     )
 }
+
+/// 执行定时检测
+#[cfg(all(windows, feature = "serialize"))]
+fn run_daemon(options: &DaemonOptions) -> Result<(), String> {
+    hardware_master::daemon::run(options, &AppConfig::load())
+}
+
+/// 未启用 serialize 特性时无法记录检测结果
+#[cfg(all(windows, not(feature = "serialize")))]
+fn run_daemon(_options: &DaemonOptions) -> Result<(), String> {
+    Err("定时检测模式需要启用 serialize 特性".to_string())
+}
+
+/// 执行远程上报
+#[cfg(all(windows, feature = "serialize"))]
+fn run_agent(options: &AgentOptions) -> Result<(), String> {
+    hardware_master::agent::run(options, &AppConfig::load())
+}
+
+/// 未启用 serialize 特性时无法生成上报内容
+#[cfg(all(windows, not(feature = "serialize")))]
+fn run_agent(_options: &AgentOptions) -> Result<(), String> {
+    Err("远程上报模式需要启用 serialize 特性".to_string())
+}
+
+/// 界面依赖 Windows 系统接口，其他平台只能以库的形式使用
+#[cfg(not(windows))]
+fn main() {
+    eprintln!(
+        "{} v{} 目前仅支持 Windows",
+        hardware_master::NAME,
+        hardware_master::VERSION
+    );
+    std::process::exit(1);
+}
@@ -0,0 +1,225 @@
+//! CPU 负载采样模块
+//!
+//! 通过 PDH 的 Processor 计数器获取总体占用率，通过 Processor Information 计数器计算实时频率
+//! （与任务管理器相同，标称频率乘以处理器性能百分比，可反映睿频），并尝试读取 ACPI 热区温度。
+//! 多数台式机主板不通过 ACPI 热区报告温度，此时温度为 None。
+
+#[cfg(windows)]
+use super::disk_io::read_counter_array;
+use crate::detector::cpu::format_mhz;
+#[cfg(windows)]
+use crate::utils::string::wide_str;
+#[cfg(windows)]
+use windows::core::PCWSTR;
+#[cfg(windows)]
+use windows::Win32::System::Performance::{
+    PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterValue,
+    PdhOpenQueryW, PDH_FMT_COUNTERVALUE, PDH_FMT_DOUBLE, PDH_HCOUNTER, PDH_HQUERY,
+};
+
+/// PDH 调用成功
+#[cfg(windows)]
+const ERROR_SUCCESS: u32 = 0;
+
+/// 开尔文与摄氏度的换算差值
+const KELVIN_OFFSET: f64 = 273.15;
+
+/// CPU 负载
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuLoad {
+    /// 总体占用率（百分比）
+    pub usage_percent: f64,
+    /// 热区最高温度（摄氏度），系统未提供时为 None
+    pub temperature: Option<f64>,
+    /// 实时频率 (MHz)，系统未提供性能计数器时为 None
+    pub frequency_mhz: Option<f64>,
+    /// 处理器性能（相对标称频率的百分比，超过 100% 表示睿频），未知时为 None
+    pub performance_percent: Option<f64>,
+}
+
+impl CpuLoad {
+    /// 简短摘要，用于托盘提示
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::realtime::cpu_load::CpuLoad;
+    /// let load = CpuLoad {
+    ///     usage_percent: 23.4,
+    ///     temperature: Some(57.6),
+    ///     frequency_mhz: Some(4210.0),
+    ///     performance_percent: Some(120.3),
+    /// };
+    /// assert_eq!(load.summary(), "CPU 23% · 4.21 GHz · 58°C");
+    /// let load = CpuLoad { usage_percent: 5.0, ..Default::default() };
+    /// assert_eq!(load.summary(), "CPU 5%");
+    /// ```
+    pub fn summary(&self) -> String {
+        let mut summary = format!("CPU {:.0}%", self.usage_percent);
+        if let Some(mhz) = self.frequency_mhz {
+            summary.push_str(&format!(" · {}", format_mhz(mhz)));
+        }
+        if let Some(temperature) = self.temperature {
+            summary.push_str(&format!(" · {:.0}°C", temperature));
+        }
+        summary
+    }
+}
+
+/// 将热区计数器的开尔文读数转换为摄氏度，取所有热区中的最高值
+///
+/// 示例
+/// ```
+/// use hardware_master::realtime::cpu_load::max_zone_celsius;
+/// assert_eq!(max_zone_celsius(&[300.15, 330.15]), Some(57.0));
+/// assert_eq!(max_zone_celsius(&[0.0]), None);
+/// assert_eq!(max_zone_celsius(&[]), None);
+/// ```
+pub fn max_zone_celsius(kelvins: &[f64]) -> Option<f64> {
+    kelvins
+        .iter()
+        .filter(|kelvin| **kelvin > 0.0)
+        .map(|kelvin| ((kelvin - KELVIN_OFFSET) * 100.0).round() / 100.0)
+        .reduce(f64::max)
+}
+
+/// 由标称频率与处理器性能百分比计算实时频率 (MHz)，数据无效时返回 None
+///
+/// * `base_mhz` - 标称频率（Processor Frequency 计数器）
+/// * `performance_percent` - 处理器性能百分比（% Processor Performance 计数器）
+///
+/// 示例
+/// ```
+/// use hardware_master::realtime::cpu_load::effective_frequency;
+/// assert_eq!(effective_frequency(3000.0, 130.0), Some(3900.0));
+/// assert_eq!(effective_frequency(0.0, 130.0), None);
+/// ```
+pub fn effective_frequency(base_mhz: f64, performance_percent: f64) -> Option<f64> {
+    (base_mhz > 0.0 && performance_percent > 0.0)
+        .then(|| (base_mhz * performance_percent / 100.0).round())
+}
+
+/// CPU 负载采样器
+///
+/// 与 [`super::disk_io::DiskIoSampler`] 相同，创建后第一次采样前应至少间隔一个采样周期
+#[cfg(windows)]
+pub struct CpuLoadSampler {
+    query: PDH_HQUERY,
+    processor_time: PDH_HCOUNTER,
+    /// 热区温度计数器，系统没有 ACPI 热区时为 None
+    zone_temperature: Option<PDH_HCOUNTER>,
+    /// 标称频率与处理器性能计数器，旧系统不支持时为 None
+    frequency: Option<(PDH_HCOUNTER, PDH_HCOUNTER)>,
+}
+
+#[cfg(windows)]
+impl CpuLoadSampler {
+    /// 创建采样器并完成首次采样
+    pub fn new() -> Result<Self, String> {
+        unsafe {
+            let mut query = PDH_HQUERY::default();
+            let status = PdhOpenQueryW(PCWSTR::null(), 0, &mut query);
+            if status != ERROR_SUCCESS {
+                return Err(format!("PdhOpenQueryW 失败: 0x{:08X}", status));
+            }
+
+            let mut sampler = Self {
+                query,
+                processor_time: PDH_HCOUNTER::default(),
+                zone_temperature: None,
+                frequency: None,
+            };
+            sampler.processor_time = sampler.add_counter(r"\Processor(_Total)\% Processor Time")?;
+            sampler.zone_temperature = sampler
+                .add_counter(r"\Thermal Zone Information(*)\Temperature")
+                .map_err(|e| log::debug!("{}", e))
+                .ok();
+            sampler.frequency = sampler
+                .add_counter(r"\Processor Information(_Total)\Processor Frequency")
+                .and_then(|base| {
+                    sampler
+                        .add_counter(r"\Processor Information(_Total)\% Processor Performance")
+                        .map(|performance| (base, performance))
+                })
+                .map_err(|e| log::debug!("{}", e))
+                .ok();
+
+            let status = PdhCollectQueryData(sampler.query);
+            if status != ERROR_SUCCESS {
+                return Err(format!("PdhCollectQueryData 失败: 0x{:08X}", status));
+            }
+            Ok(sampler)
+        }
+    }
+
+    /// 添加计数器（使用英文计数器名称，不受系统语言影响）
+    unsafe fn add_counter(&self, path: &str) -> Result<PDH_HCOUNTER, String> {
+        let path_wide = wide_str(path);
+        let mut counter = PDH_HCOUNTER::default();
+        let status = PdhAddEnglishCounterW(
+            self.query,
+            PCWSTR::from_raw(path_wide.as_ptr()),
+            0,
+            &mut counter,
+        );
+        if status != ERROR_SUCCESS {
+            return Err(format!("添加计数器 {} 失败: 0x{:08X}", path, status));
+        }
+        Ok(counter)
+    }
+
+    /// 采样一次
+    pub fn sample(&mut self) -> Result<CpuLoad, String> {
+        unsafe {
+            let status = PdhCollectQueryData(self.query);
+            if status != ERROR_SUCCESS {
+                return Err(format!("PdhCollectQueryData 失败: 0x{:08X}", status));
+            }
+
+            let usage = read_counter(self.processor_time)?;
+            let performance = self.frequency.and_then(|(base, performance)| {
+                let base = read_counter(base).ok()?;
+                let performance = read_counter(performance).ok()?;
+                Some((base, performance))
+            });
+
+            let temperature = self
+                .zone_temperature
+                .and_then(|counter| read_counter_array(counter).ok())
+                .and_then(|zones| {
+                    let kelvins: Vec<f64> = zones.into_iter().map(|(_, value)| value).collect();
+                    max_zone_celsius(&kelvins)
+                });
+
+            Ok(CpuLoad {
+                usage_percent: usage.clamp(0.0, 100.0),
+                temperature,
+                frequency_mhz: performance
+                    .and_then(|(base, performance)| effective_frequency(base, performance)),
+                performance_percent: performance.map(|(_, performance)| performance),
+            })
+        }
+    }
+}
+
+/// 读取单值计数器的格式化结果
+#[cfg(windows)]
+unsafe fn read_counter(counter: PDH_HCOUNTER) -> Result<f64, String> {
+    let mut value = PDH_FMT_COUNTERVALUE::default();
+    let status = PdhGetFormattedCounterValue(counter, PDH_FMT_DOUBLE, None, &mut value);
+    if status != ERROR_SUCCESS {
+        return Err(format!(
+            "PdhGetFormattedCounterValue 失败: 0x{:08X}",
+            status
+        ));
+    }
+    Ok(value.Anonymous.doubleValue)
+}
+
+#[cfg(windows)]
+impl Drop for CpuLoadSampler {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = PdhCloseQuery(self.query);
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! 硬盘 IO 活动采样模块
+//!
+//! 通过 PDH 的 PhysicalDisk 计数器获取每块物理硬盘的活动时间、读写速度与队列深度
+
+#[cfg(windows)]
+use crate::utils::string::{pwstr_to_string, wide_str};
+#[cfg(windows)]
+use windows::core::PCWSTR;
+#[cfg(windows)]
+use windows::Win32::System::Performance::{
+    PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterArrayW,
+    PdhOpenQueryW, PDH_FMT_COUNTERVALUE_ITEM_W, PDH_FMT_DOUBLE, PDH_HCOUNTER, PDH_HQUERY,
+    PDH_MORE_DATA,
+};
+
+/// PDH 调用成功
+#[cfg(windows)]
+const ERROR_SUCCESS: u32 = 0;
+
+/// 汇总实例名称
+#[cfg(windows)]
+const TOTAL_INSTANCE: &str = "_Total";
+
+/// 判断为硬盘瓶颈的活动时间阈值（百分比）
+pub const DISK_BUSY_THRESHOLD: f64 = 90.0;
+
+/// 判断为硬盘瓶颈的队列深度阈值
+pub const DISK_QUEUE_THRESHOLD: f64 = 2.0;
+
+/// 单块物理硬盘的 IO 活动
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiskIoStats {
+    /// PDH 实例名称，如 "0 C: D:"
+    pub instance: String,
+    /// 物理磁盘编号
+    pub disk_number: Option<u32>,
+    /// 活动时间（百分比）
+    pub busy_percent: f64,
+    /// 读取速度 (B/s)
+    pub read_bytes_per_sec: f64,
+    /// 写入速度 (B/s)
+    pub write_bytes_per_sec: f64,
+    /// 当前队列深度
+    pub queue_length: f64,
+}
+
+impl DiskIoStats {
+    /// 是否处于繁忙状态（活动时间或队列深度超过阈值）
+    pub fn is_busy(&self) -> bool {
+        self.busy_percent >= DISK_BUSY_THRESHOLD || self.queue_length >= DISK_QUEUE_THRESHOLD
+    }
+}
+
+/// 从 PDH 实例名称中解析物理磁盘编号
+///
+/// 示例
+/// ```
+/// use hardware_master::realtime::disk_io::parse_disk_number;
+/// assert_eq!(parse_disk_number("0 C: D:"), Some(0));
+/// assert_eq!(parse_disk_number("1"), Some(1));
+/// assert_eq!(parse_disk_number("_Total"), None);
+/// ```
+pub fn parse_disk_number(instance: &str) -> Option<u32> {
+    instance.split_whitespace().next()?.parse().ok()
+}
+
+/// 硬盘 IO 采样器
+///
+/// 速率类计数器需要两次采样才能得到结果，因此创建后第一次调用 [`DiskIoSampler::sample`]
+/// 前应至少间隔一个采样周期
+#[cfg(windows)]
+pub struct DiskIoSampler {
+    query: PDH_HQUERY,
+    idle_time: PDH_HCOUNTER,
+    read_bytes: PDH_HCOUNTER,
+    write_bytes: PDH_HCOUNTER,
+    queue_length: PDH_HCOUNTER,
+}
+
+#[cfg(windows)]
+impl DiskIoSampler {
+    /// 创建采样器并完成首次采样
+    pub fn new() -> Result<Self, String> {
+        unsafe {
+            let mut query = PDH_HQUERY::default();
+            let status = PdhOpenQueryW(PCWSTR::null(), 0, &mut query);
+            if status != ERROR_SUCCESS {
+                return Err(format!("PdhOpenQueryW 失败: 0x{:08X}", status));
+            }
+
+            let mut sampler = Self {
+                query,
+                idle_time: PDH_HCOUNTER::default(),
+                read_bytes: PDH_HCOUNTER::default(),
+                write_bytes: PDH_HCOUNTER::default(),
+                queue_length: PDH_HCOUNTER::default(),
+            };
+            sampler.idle_time = sampler.add_counter(r"\PhysicalDisk(*)\% Idle Time")?;
+            sampler.read_bytes = sampler.add_counter(r"\PhysicalDisk(*)\Disk Read Bytes/sec")?;
+            sampler.write_bytes = sampler.add_counter(r"\PhysicalDisk(*)\Disk Write Bytes/sec")?;
+            sampler.queue_length =
+                sampler.add_counter(r"\PhysicalDisk(*)\Current Disk Queue Length")?;
+
+            let status = PdhCollectQueryData(sampler.query);
+            if status != ERROR_SUCCESS {
+                return Err(format!("PdhCollectQueryData 失败: 0x{:08X}", status));
+            }
+            Ok(sampler)
+        }
+    }
+
+    /// 添加计数器（使用英文计数器名称，不受系统语言影响）
+    unsafe fn add_counter(&self, path: &str) -> Result<PDH_HCOUNTER, String> {
+        let path_wide = wide_str(path);
+        let mut counter = PDH_HCOUNTER::default();
+        let status = PdhAddEnglishCounterW(
+            self.query,
+            PCWSTR::from_raw(path_wide.as_ptr()),
+            0,
+            &mut counter,
+        );
+        if status != ERROR_SUCCESS {
+            return Err(format!("添加计数器 {} 失败: 0x{:08X}", path, status));
+        }
+        Ok(counter)
+    }
+
+    /// 采样一次，返回每块物理硬盘的 IO 活动（不含汇总实例），按磁盘编号排序
+    pub fn sample(&mut self) -> Result<Vec<DiskIoStats>, String> {
+        unsafe {
+            let status = PdhCollectQueryData(self.query);
+            if status != ERROR_SUCCESS {
+                return Err(format!("PdhCollectQueryData 失败: 0x{:08X}", status));
+            }
+
+            let mut stats: Vec<DiskIoStats> = read_counter_array(self.idle_time)?
+                .into_iter()
+                .filter(|(instance, _)| instance != TOTAL_INSTANCE)
+                .map(|(instance, idle)| DiskIoStats {
+                    disk_number: parse_disk_number(&instance),
+                    instance,
+                    busy_percent: (100.0 - idle).clamp(0.0, 100.0),
+                    ..Default::default()
+                })
+                .collect();
+
+            fill_counter(self.read_bytes, &mut stats, |s, v| s.read_bytes_per_sec = v)?;
+            fill_counter(self.write_bytes, &mut stats, |s, v| {
+                s.write_bytes_per_sec = v
+            })?;
+            fill_counter(self.queue_length, &mut stats, |s, v| s.queue_length = v)?;
+
+            stats.sort_by_key(|s| s.disk_number.unwrap_or(u32::MAX));
+            Ok(stats)
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Drop for DiskIoSampler {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = PdhCloseQuery(self.query);
+        }
+    }
+}
+
+/// 将计数器各实例的值写入对应硬盘的统计
+///
+/// * `counter` - 计数器句柄
+/// * `stats` - 各硬盘的统计
+/// * `set` - 写入字段的函数
+#[cfg(windows)]
+unsafe fn fill_counter(
+    counter: PDH_HCOUNTER,
+    stats: &mut [DiskIoStats],
+    set: fn(&mut DiskIoStats, f64),
+) -> Result<(), String> {
+    for (instance, value) in read_counter_array(counter)? {
+        if let Some(item) = stats.iter_mut().find(|s| s.instance == instance) {
+            set(item, value);
+        }
+    }
+    Ok(())
+}
+
+/// 读取通配符计数器的所有实例值
+///
+/// * `counter` - 计数器句柄
+#[cfg(windows)]
+pub(crate) unsafe fn read_counter_array(
+    counter: PDH_HCOUNTER,
+) -> Result<Vec<(String, f64)>, String> {
+    let mut buffer_size = 0u32;
+    let mut item_count = 0u32;
+    let status = PdhGetFormattedCounterArrayW(
+        counter,
+        PDH_FMT_DOUBLE,
+        &mut buffer_size,
+        &mut item_count,
+        None,
+    );
+    if status != PDH_MORE_DATA {
+        return Err(format!(
+            "PdhGetFormattedCounterArrayW 失败: 0x{:08X}",
+            status
+        ));
+    }
+
+    // 缓冲区同时存放条目数组与实例名称字符串
+    let item_size = std::mem::size_of::<PDH_FMT_COUNTERVALUE_ITEM_W>();
+    let mut buffer: Vec<PDH_FMT_COUNTERVALUE_ITEM_W> =
+        vec![Default::default(); (buffer_size as usize).div_ceil(item_size)];
+    let status = PdhGetFormattedCounterArrayW(
+        counter,
+        PDH_FMT_DOUBLE,
+        &mut buffer_size,
+        &mut item_count,
+        Some(buffer.as_mut_ptr()),
+    );
+    if status != ERROR_SUCCESS {
+        return Err(format!(
+            "PdhGetFormattedCounterArrayW 失败: 0x{:08X}",
+            status
+        ));
+    }
+
+    Ok(buffer[..item_count as usize]
+        .iter()
+        .map(|item| {
+            (
+                pwstr_to_string(item.szName),
+                item.FmtValue.Anonymous.doubleValue,
+            )
+        })
+        .collect())
+}
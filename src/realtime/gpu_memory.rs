@@ -0,0 +1,114 @@
+//! 显存占用采样模块
+//!
+//! 通过 WDDM 2.0 的 `IDXGIAdapter3::QueryVideoMemoryInfo` 读取每块显卡的显存占用与系统分配的预算。
+//! 集成显卡没有独立显存，其“本地”显存即系统为其划分的内存。
+
+#[cfg(windows)]
+use crate::utils::string::u16_slice_to_string;
+#[cfg(windows)]
+use windows::core::Interface;
+#[cfg(windows)]
+use windows::Win32::Graphics::Dxgi::{
+    CreateDXGIFactory1, IDXGIAdapter3, IDXGIFactory1, DXGI_ADAPTER_FLAG_SOFTWARE,
+    DXGI_MEMORY_SEGMENT_GROUP, DXGI_MEMORY_SEGMENT_GROUP_LOCAL,
+    DXGI_MEMORY_SEGMENT_GROUP_NON_LOCAL, DXGI_QUERY_VIDEO_MEMORY_INFO,
+};
+
+/// 单块显卡的显存占用
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpuMemoryUsage {
+    /// 显卡描述
+    pub description: String,
+    /// 本地显存已用 (B)
+    pub local_usage: u64,
+    /// 本地显存预算 (B)，即系统当前允许该显卡使用的上限
+    pub local_budget: u64,
+    /// 共享系统内存已用 (B)
+    pub shared_usage: u64,
+}
+
+impl GpuMemoryUsage {
+    /// 本地显存占用率（百分比），预算未知时为 0
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::realtime::gpu_memory::GpuMemoryUsage;
+    /// let usage = GpuMemoryUsage { local_usage: 3 << 30, local_budget: 12 << 30, ..Default::default() };
+    /// assert_eq!(usage.local_percent(), 25.0);
+    /// assert_eq!(GpuMemoryUsage::default().local_percent(), 0.0);
+    /// ```
+    pub fn local_percent(&self) -> f64 {
+        if self.local_budget == 0 {
+            return 0.0;
+        }
+        (self.local_usage as f64 / self.local_budget as f64 * 100.0).clamp(0.0, 100.0)
+    }
+}
+
+/// 显存占用采样器
+#[cfg(windows)]
+pub struct GpuMemorySampler {
+    /// 各显卡的描述与适配器
+    adapters: Vec<(String, IDXGIAdapter3)>,
+}
+
+#[cfg(windows)]
+impl GpuMemorySampler {
+    /// 枚举支持 WDDM 2.0 的硬件显卡
+    pub fn new() -> Result<Self, String> {
+        unsafe {
+            let factory = CreateDXGIFactory1::<IDXGIFactory1>()
+                .map_err(|e| format!("创建 DXGI 工厂失败: {:?}", e))?;
+            let mut adapters = Vec::new();
+            let mut index = 0;
+            while let Ok(adapter) = factory.EnumAdapters1(index) {
+                index += 1;
+                let Ok(desc) = adapter.GetDesc1() else {
+                    continue;
+                };
+                if (desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32) != 0 {
+                    continue;
+                }
+                // WDDM 2.0 之前的驱动不支持 IDXGIAdapter3
+                if let Ok(adapter) = adapter.cast::<IDXGIAdapter3>() {
+                    adapters.push((u16_slice_to_string(&desc.Description), adapter));
+                }
+            }
+            if adapters.is_empty() {
+                return Err("没有支持显存占用查询的显卡".to_string());
+            }
+            Ok(Self { adapters })
+        }
+    }
+
+    /// 采样一次，查询失败的显卡跳过
+    pub fn sample(&self) -> Vec<GpuMemoryUsage> {
+        self.adapters
+            .iter()
+            .filter_map(|(description, adapter)| unsafe {
+                let local = query_segment(adapter, DXGI_MEMORY_SEGMENT_GROUP_LOCAL)?;
+                let shared = query_segment(adapter, DXGI_MEMORY_SEGMENT_GROUP_NON_LOCAL)?;
+                Some(GpuMemoryUsage {
+                    description: description.clone(),
+                    local_usage: local.CurrentUsage,
+                    local_budget: local.Budget,
+                    shared_usage: shared.CurrentUsage,
+                })
+            })
+            .collect()
+    }
+}
+
+/// 查询显卡第一个节点的指定显存段
+#[cfg(windows)]
+unsafe fn query_segment(
+    adapter: &IDXGIAdapter3,
+    group: DXGI_MEMORY_SEGMENT_GROUP,
+) -> Option<DXGI_QUERY_VIDEO_MEMORY_INFO> {
+    let mut info = DXGI_QUERY_VIDEO_MEMORY_INFO::default();
+    adapter
+        .QueryVideoMemoryInfo(0, group, &mut info)
+        .map_err(|e| log::debug!("QueryVideoMemoryInfo 失败: {:?}", e))
+        .ok()?;
+    Some(info)
+}
@@ -0,0 +1,8 @@
+//! 实时监控模块
+//!
+//! 周期性采样系统运行状态（如 CPU 占用、硬盘读写活动、显存占用），供实时面板显示。
+//! 与一次性的硬件检测不同，采样器需要在后台线程中持续运行。
+
+pub mod cpu_load;
+pub mod disk_io;
+pub mod gpu_memory;
@@ -0,0 +1,161 @@
+//! CSV 导出模块
+//!
+//! 将内存插槽、物理磁盘、分区等表格数据导出为 CSV，便于在电子表格中汇总多台机器。
+//! 容量等数值列只输出数字（单位写在表头中），方便直接求和、排序。
+
+use crate::detector::HardwareDetector;
+use crate::utils::size::ByteSize;
+
+/// UTF-8 BOM，使 Excel 正确识别中文
+const UTF8_BOM: &str = "\u{feff}";
+
+/// CSV 表格
+#[derive(Debug, Clone, Default)]
+pub struct CsvTable {
+    /// 表格名称，用作默认文件名
+    pub title: String,
+    /// 表头
+    pub headers: Vec<String>,
+    /// 数据行
+    pub rows: Vec<Vec<String>>,
+}
+
+impl CsvTable {
+    fn new(title: &str, headers: &[&str]) -> Self {
+        Self {
+            title: title.to_string(),
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    fn row(&mut self, values: Vec<String>) {
+        self.rows.push(values);
+    }
+
+    /// 默认文件名，如 "内存插槽.csv"
+    pub fn file_name(&self) -> String {
+        format!("{}.csv", self.title)
+    }
+
+    /// 生成 CSV 文本（带 BOM，行尾为 CRLF）
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(UTF8_BOM);
+        for line in std::iter::once(&self.headers).chain(self.rows.iter()) {
+            let fields: Vec<String> = line.iter().map(|field| escape(field)).collect();
+            csv.push_str(&fields.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+}
+
+/// 转义 CSV 字段，包含逗号、引号或换行时用双引号包裹
+///
+/// 示例
+/// ```
+/// use hardware_master::report::csv::escape;
+/// assert_eq!(escape("DDR4"), "DDR4");
+/// assert_eq!(escape("Kingston, Inc."), "\"Kingston, Inc.\"");
+/// assert_eq!(escape("15.6\" 屏幕"), "\"15.6\"\" 屏幕\"");
+/// ```
+pub fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// 容量转换为 GB 数值文本，保留一位小数
+fn gb(size: ByteSize) -> String {
+    format!("{:.1}", size.as_gb())
+}
+
+/// 内存插槽表
+pub fn memory_slots(detector: &HardwareDetector) -> CsvTable {
+    let mut table = CsvTable::new(
+        "内存插槽",
+        &[
+            "插槽",
+            "位置",
+            "名称",
+            "容量 (GB)",
+            "类型",
+            "频率 (MHz)",
+            "制造商",
+            "电压 (V)",
+            "超频配置",
+        ],
+    );
+    for (index, slot) in detector.memory_info.slots.iter().enumerate() {
+        let voltage = slot
+            .configured_voltage
+            .map(|voltage| format!("{:.2}", voltage as f64 / 1000.0))
+            .unwrap_or_default();
+        table.row(vec![
+            (index + 1).to_string(),
+            slot.device_locator.clone(),
+            slot.name.clone(),
+            gb(slot.capacity),
+            slot.memory_type.clone(),
+            slot.frequency.to_string(),
+            slot.manufacturer.clone(),
+            voltage,
+            slot.profile.to_string(),
+        ]);
+    }
+    table
+}
+
+/// 物理磁盘表
+pub fn physical_disks(detector: &HardwareDetector) -> CsvTable {
+    let mut table = CsvTable::new(
+        "物理磁盘",
+        &["编号", "型号", "容量 (GB)", "类型", "接口", "可移动"],
+    );
+    for disk in detector.disk_info.physical_disks.iter() {
+        table.row(vec![
+            disk.number.to_string(),
+            disk.model.clone(),
+            gb(disk.total_capacity),
+            disk.disk_type.to_string(),
+            disk.bus.to_string(),
+            if disk.removable { "是" } else { "否" }.to_string(),
+        ]);
+    }
+    table
+}
+
+/// 分区表
+pub fn volumes(detector: &HardwareDetector) -> CsvTable {
+    let mut table = CsvTable::new(
+        "分区",
+        &[
+            "盘符",
+            "卷标",
+            "文件系统",
+            "类型",
+            "总容量 (GB)",
+            "可用空间 (GB)",
+            "所在磁盘",
+        ],
+    );
+    for volume in detector.disk_info.volumes.iter() {
+        let disks: Vec<String> = volume
+            .disk_numbers()
+            .iter()
+            .map(|number| number.to_string())
+            .collect();
+        table.row(vec![
+            volume.drive_letter.clone(),
+            volume.label.clone(),
+            volume.file_system.clone(),
+            volume.kind.to_string(),
+            gb(volume.total_space),
+            gb(volume.free_space),
+            disks.join(";"),
+        ]);
+    }
+    table
+}
@@ -0,0 +1,95 @@
+//! HTML 报告生成模块
+//!
+//! 生成内联样式的独立 HTML 文件，无需任何外部资源即可在浏览器中打开
+
+use super::build_sections_with_asset;
+use crate::asset::AssetRecord;
+use crate::detector::HardwareDetector;
+
+/// 报告内联样式
+const STYLE: &str = r#"
+body { font-family: "Microsoft YaHei", "PingFang SC", sans-serif; background: #f4f6f9; color: #222; margin: 0; padding: 24px; }
+.container { max-width: 860px; margin: 0 auto; }
+h1 { font-size: 22px; margin: 0 0 4px; }
+.meta { color: #777; font-size: 13px; margin-bottom: 20px; }
+section { background: #fff; border-radius: 8px; box-shadow: 0 1px 3px rgba(0,0,0,.08); margin-bottom: 16px; padding: 12px 18px; }
+h2 { font-size: 16px; border-left: 4px solid #2f80ed; padding-left: 8px; margin: 6px 0 10px; }
+table { width: 100%; border-collapse: collapse; font-size: 14px; }
+td { padding: 6px 4px; border-bottom: 1px solid #eee; vertical-align: top; }
+td.key { width: 120px; color: #555; white-space: nowrap; }
+.empty { color: #999; font-size: 14px; }
+footer { color: #999; font-size: 12px; text-align: center; margin-top: 24px; }
+"#;
+
+/// 生成完整的 HTML 报告
+///
+/// * `detector` - 已完成检测的硬件检测器
+/// * `asset` - 本机的资产信息，有时在最后附带“资产”章节
+pub fn generate(detector: &HardwareDetector, asset: Option<&AssetRecord>) -> String {
+    let generated_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+    let mut html = String::new();
+
+    html.push_str("<!DOCTYPE html>\n<html lang=\"zh-CN\">\n<head>\n");
+    html.push_str("<meta charset=\"utf-8\">\n");
+    html.push_str(&format!(
+        "<title>{} 硬件检测报告</title>\n",
+        escape(&detector.system_info.computer_model)
+    ));
+    html.push_str(&format!("<style>{}</style>\n", STYLE));
+    html.push_str("</head>\n<body>\n<div class=\"container\">\n");
+
+    html.push_str(&format!(
+        "<h1>{} {}</h1>\n",
+        escape(&detector.system_info.system_manufacturer),
+        escape(&detector.system_info.computer_model)
+    ));
+    html.push_str(&format!(
+        "<div class=\"meta\">生成时间：{} · 硬大师 v{}</div>\n",
+        generated_at,
+        crate::VERSION
+    ));
+
+    for section in build_sections_with_asset(detector, asset) {
+        html.push_str(&format!("<section>\n<h2>{}</h2>\n", escape(&section.title)));
+        if section.rows.is_empty() {
+            html.push_str("<div class=\"empty\">未检测到</div>\n");
+        } else {
+            html.push_str("<table>\n");
+            for (key, value) in section.rows.iter() {
+                html.push_str(&format!(
+                    "<tr><td class=\"key\">{}</td><td>{}</td></tr>\n",
+                    escape(key),
+                    escape(value)
+                ));
+            }
+            html.push_str("</table>\n");
+        }
+        html.push_str("</section>\n");
+    }
+
+    html.push_str("<footer>以上硬件信息来源于硬大师，仅供参考</footer>\n");
+    html.push_str("</div>\n</body>\n</html>\n");
+    html
+}
+
+/// 转义 HTML 特殊字符
+///
+/// 示例
+/// ```
+/// use hardware_master::report::html::escape;
+/// assert_eq!(escape("<a & b>"), "&lt;a &amp; b&gt;");
+/// ```
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
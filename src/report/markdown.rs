@@ -0,0 +1,65 @@
+//! Markdown 报告生成模块
+//!
+//! 以 Markdown 表格输出完整检测结果，便于粘贴到论坛帖子或 GitHub Issue
+
+use super::build_sections_with_asset;
+use crate::asset::AssetRecord;
+use crate::detector::HardwareDetector;
+
+/// 生成完整的 Markdown 报告
+///
+/// * `detector` - 已完成检测的硬件检测器
+/// * `asset` - 本机的资产信息，有时在最后附带“资产”章节
+pub fn generate(detector: &HardwareDetector, asset: Option<&AssetRecord>) -> String {
+    let mut markdown = String::new();
+
+    markdown.push_str(&format!(
+        "## {} {} 硬件信息\n\n",
+        escape(&detector.system_info.system_manufacturer),
+        escape(&detector.system_info.computer_model)
+    ));
+
+    for section in build_sections_with_asset(detector, asset) {
+        markdown.push_str(&format!("### {}\n\n", escape(&section.title)));
+        if section.rows.is_empty() {
+            markdown.push_str("未检测到\n\n");
+            continue;
+        }
+        markdown.push_str("| 项目 | 信息 |\n| --- | --- |\n");
+        for (key, value) in section.rows.iter() {
+            markdown.push_str(&format!("| {} | {} |\n", escape(key), escape(value)));
+        }
+        markdown.push('\n');
+    }
+
+    markdown.push_str(&format!(
+        "> 以上硬件信息来源于硬大师 v{}，仅供参考\n",
+        crate::VERSION
+    ));
+    markdown
+}
+
+/// 转义 Markdown 表格中的特殊字符，换行替换为 `<br>`
+///
+/// 示例
+/// ```
+/// use hardware_master::report::markdown::escape;
+/// assert_eq!(escape("a | b"), "a \\| b");
+/// assert_eq!(escape("*RGB*"), "\\*RGB\\*");
+/// assert_eq!(escape("第一行\n第二行"), "第一行<br>第二行");
+/// ```
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '|' | '\\' | '*' | '_' | '`' | '#' | '<' | '>' | '[' | ']' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\r' => {}
+            '\n' => escaped.push_str("<br>"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,299 @@
+//! 报告导出模块
+//!
+//! 将检测结果整理为按章节划分的结构，供各种报告格式复用
+
+pub mod csv;
+pub mod html;
+pub mod markdown;
+pub mod template;
+pub mod xml;
+
+use crate::asset::AssetRecord;
+use crate::detector::{
+    gpu::GpuType, memory::MemoryProfile, power::PowerStatus, volume::group_by_disk,
+    HardwareDetector,
+};
+use crate::utils::string::{format_cores, format_size};
+
+/// 报告章节
+#[derive(Debug, Clone)]
+pub struct ReportSection {
+    /// 章节标题
+    pub title: String,
+    /// 章节内容（字段名, 字段值）
+    pub rows: Vec<(String, String)>,
+}
+
+impl ReportSection {
+    fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            rows: Vec::new(),
+        }
+    }
+
+    fn row(&mut self, key: &str, value: impl ToString) {
+        self.rows.push((key.to_string(), value.to_string()));
+    }
+}
+
+/// 将检测结果整理为报告章节列表，有资产信息时在最后追加“资产”章节
+///
+/// * `detector` - 已完成检测的硬件检测器
+/// * `asset` - 本机的资产信息
+pub fn build_sections_with_asset(
+    detector: &HardwareDetector,
+    asset: Option<&AssetRecord>,
+) -> Vec<ReportSection> {
+    let mut sections = build_sections(detector);
+    if let Some(asset) = asset {
+        sections.push(asset_section(asset, chrono::Local::now().date_naive()));
+    }
+    sections
+}
+
+/// 资产信息章节，只包含已填写的字段
+///
+/// * `asset` - 资产信息
+/// * `today` - 当天日期，用于计算保修状态
+pub fn asset_section(asset: &AssetRecord, today: chrono::NaiveDate) -> ReportSection {
+    let mut section = ReportSection::new("资产");
+    let warranty = if asset.warranty_end.trim().is_empty() {
+        String::new()
+    } else {
+        asset.warranty_status(today).to_string()
+    };
+    let fields = [
+        ("购买日期", asset.purchase_date.trim()),
+        ("保修截止", asset.warranty_end.trim()),
+        ("保修状态", warranty.as_str()),
+        ("使用人", asset.owner.trim()),
+        ("备注", asset.notes.trim()),
+    ];
+    for (key, value) in fields {
+        if !value.is_empty() {
+            section.row(key, value);
+        }
+    }
+    for (key, value) in asset.custom_fields.iter() {
+        section.row(key, value);
+    }
+    section
+}
+
+/// 将检测结果整理为报告章节列表
+pub fn build_sections(detector: &HardwareDetector) -> Vec<ReportSection> {
+    let mut sections = Vec::new();
+
+    // 系统
+    let mut system = ReportSection::new("系统");
+    system.row("操作系统", &detector.system_info.os_name);
+    system.row("版本", &detector.system_info.display_version);
+    system.row("内部版本", &detector.system_info.os_build);
+    system.row("激活状态", detector.system_info.activation);
+    system.row(
+        "虚拟化",
+        detector
+            .system_info
+            .virtualization
+            .as_ref()
+            .map_or_else(|| "物理机".to_string(), |hypervisor| hypervisor.to_string()),
+    );
+    system.row("制造商", &detector.system_info.system_manufacturer);
+    system.row("型号", &detector.system_info.computer_model);
+    sections.push(system);
+
+    // 处理器
+    let cpu = &detector.cpu_info;
+    let mut processor = ReportSection::new("处理器");
+    processor.row("名称", &cpu.name);
+    processor.row("架构", &cpu.architecture);
+    processor.row(
+        "核心",
+        format_cores(cpu.physical_cores, cpu.logical_cores, cpu.efficiency_cores),
+    );
+    processor.row("制造商", &cpu.vendor);
+    processor.row("标识符", &cpu.cpu_id);
+    processor.row("基准频率", format!("{} MHz", cpu.base_frequency));
+    if cpu.max_frequency > cpu.base_frequency {
+        processor.row("最大频率", format!("{} MHz", cpu.max_frequency));
+    }
+    sections.push(processor);
+
+    // 显卡
+    let mut graphics = ReportSection::new("显卡");
+    for gpu in detector.gpu_info.gpus.iter() {
+        if gpu.gpu_type != GpuType::DiscreteGpu && gpu.gpu_type != GpuType::IntegratedGpu {
+            continue;
+        }
+        graphics.row(
+            &gpu.gpu_type.to_string(),
+            format!(
+                "{} ({}, {})",
+                gpu.description,
+                format_size(gpu.effective_vram()),
+                gpu.manufacturer
+            ),
+        );
+        if gpu.vbios_version != "未知" {
+            graphics.row("VBIOS", &gpu.vbios_version);
+        }
+        if let Some(stats) = &gpu.vendor_stats {
+            let summary = stats.summary();
+            if !summary.is_empty() {
+                graphics.row("运行状态", summary);
+            }
+        }
+        if let Some(link) = &gpu.pcie_link {
+            graphics.row("PCIe 链路", link.summary());
+        }
+        for output in gpu.outputs.iter() {
+            graphics.row("显示输出", output.summary());
+        }
+    }
+    if let Some(hybrid) = &detector.gpu_info.hybrid {
+        graphics.row("混合输出", hybrid.summary());
+    }
+    sections.push(graphics);
+
+    // 内存
+    let mut memory = ReportSection::new("内存");
+    memory.row("概要", &detector.memory_info.name);
+    memory.row("通道模式", detector.memory_info.channel_mode);
+    memory.row("纠错", detector.memory_info.error_correction);
+    for (index, slot) in detector.memory_info.slots.iter().enumerate() {
+        memory.row(&format!("插槽 {}", index + 1), &slot.name);
+        if slot.profile != MemoryProfile::Unknown {
+            memory.row(&format!("插槽 {} 配置", index + 1), slot.profile_summary());
+        }
+    }
+    sections.push(memory);
+
+    // 主板
+    let board = &detector.motherboard_info;
+    let mut motherboard = ReportSection::new("主板");
+    motherboard.row("制造商", &board.manufacturer);
+    motherboard.row("型号", &board.product_name);
+    motherboard.row("芯片组", &board.chipset);
+    motherboard.row(
+        "BIOS",
+        format!("{} {}", board.bios_vendor, board.bios_version),
+    );
+    motherboard.row("BIOS 日期", &board.bios_date);
+    motherboard.row("启动模式", board.firmware_type);
+    motherboard.row("SMBIOS 版本", &board.smbios_version);
+    sections.push(motherboard);
+
+    // 显示器
+    let mut monitor = ReportSection::new("显示器");
+    monitor.row("名称", &detector.monitor_info.name);
+    monitor.row("面板", &detector.monitor_info.panel_id);
+    monitor.row("背光", detector.monitor_info.backlight_desc());
+    if !detector.monitor_info.modes.is_empty() {
+        monitor.row("刷新率", detector.monitor_info.refresh_desc());
+    }
+    for display in detector.monitor_info.displays.iter() {
+        monitor.row("当前模式", display.summary());
+        monitor.row(
+            "排列",
+            format!(
+                "{}: {}",
+                display.name,
+                detector.monitor_info.layout_desc(display)
+            ),
+        );
+    }
+    sections.push(monitor);
+
+    // 硬盘
+    let disk = &detector.disk_info;
+    let mut storage = ReportSection::new("硬盘");
+    storage.row("型号", &disk.model);
+    storage.row("容量", format_size(disk.total_capacity));
+    storage.row("类型", disk.type_desc());
+    if let Some(link_speed) = &disk.link_speed {
+        storage.row("链路速率", link_speed);
+    }
+    storage.row("可用空间", format_size(disk.free_space));
+    if let Some(wear) = disk.wear_percent {
+        storage.row("磨损", format!("{:.0}%", wear));
+    }
+    if let Some(temperature) = disk.temperature {
+        storage.row("温度", format!("{:.0}°C", temperature));
+    }
+    for (number, volumes) in group_by_disk(&disk.volumes) {
+        let key = match number {
+            Some(number) => format!("磁盘 {} 分区", number),
+            None => "其他分区".to_string(),
+        };
+        for volume in volumes {
+            storage.row(
+                &key,
+                format!(
+                    "{} {} 可用 {} / {}",
+                    volume.display_name(),
+                    volume.file_system,
+                    format_size(volume.free_space),
+                    format_size(volume.total_space)
+                ),
+            );
+        }
+    }
+    for drive in disk.optical_drives.iter() {
+        storage.row("光驱", drive.summary());
+    }
+    sections.push(storage);
+
+    // 网卡
+    let mut network = ReportSection::new("网卡");
+    for adapter in detector.network_info.adapters.iter() {
+        network.row("网卡", adapter.summary());
+        network.row("MAC 地址", &adapter.mac_address);
+        network.row("驱动版本", &adapter.driver_version);
+    }
+    for wifi in detector.network_info.wifi_adapters.iter() {
+        network.row("无线网卡", wifi.summary());
+        if !wifi.standards.is_empty() {
+            network.row("支持标准", wifi.standards.join(" / "));
+        }
+    }
+    sections.push(network);
+
+    // 电池
+    let mut battery = ReportSection::new("电池");
+    for bt in detector.battery_info.batteries.iter() {
+        battery.row(
+            "电池",
+            format!("{} {} {}", bt.vendor, bt.name, bt.chemistry),
+        );
+        battery.row("健康度", format!("{:.0}%", bt.health));
+        battery.row("循环次数", bt.cycle_count);
+        if let Some(temperature) = bt.temperature {
+            battery.row("温度", format!("{:.1} °C", temperature));
+        }
+        if let Some(date) = &bt.manufacture_date {
+            battery.row("生产日期", date);
+        }
+    }
+    if detector.battery_info.power != PowerStatus::default() {
+        battery.row("电源", detector.battery_info.power.summary());
+    }
+    sections.push(battery);
+
+    // 声卡
+    let mut audio = ReportSection::new("声卡");
+    for device in detector.audio_info.devices.iter() {
+        audio.row("声卡", format!("{} ({})", device.name, device.transport));
+    }
+    sections.push(audio);
+
+    // 安全特性
+    let security_info = &detector.security_info;
+    let mut security = ReportSection::new("安全");
+    security.row("TPM", security_info.tpm_summary());
+    security.row("安全启动", security_info.secure_boot);
+    security.row("BitLocker", security_info.bitlocker_summary());
+    sections.push(security);
+
+    sections
+}
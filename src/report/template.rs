@@ -0,0 +1,220 @@
+//! 复制模板模块
+//!
+//! 将检测结果格式化为便于粘贴的文本：简洁摘要、完整列表、论坛 BBCode 与 Markdown
+
+use super::{build_sections, markdown, ReportSection};
+use crate::detector::{gpu::GpuType, HardwareDetector};
+use crate::utils::string::format_size;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 复制到剪贴板时使用的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CopyTemplate {
+    /// 每个硬件一行的摘要
+    #[default]
+    Concise,
+    /// 按章节列出全部检测结果
+    Full,
+    /// 论坛 BBCode
+    BbCode,
+    /// Markdown 表格
+    Markdown,
+}
+
+impl CopyTemplate {
+    /// 所有模板，按显示顺序排列
+    pub const ALL: [CopyTemplate; 4] = [
+        CopyTemplate::Concise,
+        CopyTemplate::Full,
+        CopyTemplate::BbCode,
+        CopyTemplate::Markdown,
+    ];
+
+    /// 获取模板名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            CopyTemplate::Concise => "简洁",
+            CopyTemplate::Full => "完整",
+            CopyTemplate::BbCode => "论坛 BBCode",
+            CopyTemplate::Markdown => "Markdown",
+        }
+    }
+
+    /// 按模板生成文本，复制的文本常用于公开分享，不附带资产信息
+    ///
+    /// * `detector` - 已完成检测的硬件检测器
+    pub fn render(&self, detector: &HardwareDetector) -> String {
+        match self {
+            CopyTemplate::Concise => concise(detector),
+            CopyTemplate::Full => full(&build_sections(detector)),
+            CopyTemplate::BbCode => bbcode(&build_sections(detector)),
+            CopyTemplate::Markdown => markdown::generate(detector, None),
+        }
+    }
+}
+
+impl fmt::Display for CopyTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// 结尾的来源说明
+fn footer() -> String {
+    format!("以上硬件信息来源于硬大师 v{}，仅供参考", crate::VERSION)
+}
+
+/// 生成每个硬件一行的摘要
+///
+/// * `detector` - 已完成检测的硬件检测器
+pub fn concise(detector: &HardwareDetector) -> String {
+    let mut text = String::from("以下硬件信息来源于硬大师，仅供参考：\n");
+
+    // 操作系统
+    text.push_str(&format!("操作系统: {}\n", detector.system_info.os_name));
+
+    // 处理器
+    text.push_str(&format!("处理器: {}\n", detector.cpu_info.name));
+
+    // 显卡
+    for gpu in detector.gpu_info.gpus.iter() {
+        if gpu.gpu_type != GpuType::DiscreteGpu && gpu.gpu_type != GpuType::IntegratedGpu {
+            continue;
+        }
+        text.push_str(&format!(
+            "{}: {} ({}, {})\n",
+            gpu.gpu_type,
+            gpu.description,
+            format_size(gpu.effective_vram()),
+            gpu.manufacturer
+        ));
+    }
+
+    // 内存
+    text.push_str(&format!("内存: {}\n", detector.memory_info.name));
+
+    // 主板
+    text.push_str(&format!(
+        "主板: {} {} ({}, {})\n",
+        detector.motherboard_info.manufacturer,
+        detector.motherboard_info.product_name,
+        detector.motherboard_info.chipset,
+        detector.motherboard_info.bios_vendor
+    ));
+
+    // 显示器
+    text.push_str(&format!("显示器: {}\n", detector.monitor_info.name));
+    if !detector.monitor_info.modes.is_empty() {
+        text.push_str(&format!(
+            "刷新率: {}\n",
+            detector.monitor_info.refresh_desc()
+        ));
+    }
+    for display in detector.monitor_info.displays.iter() {
+        text.push_str(&format!("当前模式: {}\n", display.summary()));
+    }
+
+    // 硬盘
+    text.push_str(&format!(
+        "主硬盘: {} ({}, {})\n",
+        detector.disk_info.model,
+        format_size(detector.disk_info.total_capacity),
+        detector.disk_info.type_desc()
+    ));
+
+    // 网卡
+    for adapter in detector.network_info.adapters.iter() {
+        text.push_str(&format!(
+            "网卡: {} (MAC: {}, 驱动: {})\n",
+            adapter.summary(),
+            adapter.mac_address,
+            adapter.driver_version
+        ));
+    }
+
+    // 电池
+    for battery in detector.battery_info.batteries.iter() {
+        text.push_str(&format!(
+            "电池: {} {} {} (健康度：{:.0}%)\n",
+            battery.vendor, battery.name, battery.chemistry, battery.health
+        ));
+    }
+
+    // 声卡
+    if let Some(device) = detector.audio_info.devices.first() {
+        text.push_str(&format!("声卡: {}\n", device.name));
+    } else {
+        text.push_str("声卡: 未检测到\n");
+    }
+
+    // 安全特性
+    let security = &detector.security_info;
+    text.push_str(&format!(
+        "安全: {}, 安全启动{}\n",
+        security.tpm_summary(),
+        security.secure_boot
+    ));
+
+    text
+}
+
+/// 按章节生成纯文本列表
+///
+/// * `sections` - 报告章节
+///
+/// 示例
+/// ```
+/// use hardware_master::report::{template, ReportSection};
+/// let section = ReportSection {
+///     title: "处理器".to_string(),
+///     rows: vec![("名称".to_string(), "Intel Core i5-12400".to_string())],
+/// };
+/// assert!(template::full(&[section]).starts_with("【处理器】\n名称: Intel Core i5-12400\n"));
+/// ```
+pub fn full(sections: &[ReportSection]) -> String {
+    let mut text = String::new();
+    for section in sections {
+        text.push_str(&format!("【{}】\n", section.title));
+        if section.rows.is_empty() {
+            text.push_str("未检测到\n");
+        }
+        for (key, value) in section.rows.iter() {
+            text.push_str(&format!("{}: {}\n", key, value));
+        }
+        text.push('\n');
+    }
+    text.push_str(&footer());
+    text.push('\n');
+    text
+}
+
+/// 按章节生成论坛 BBCode，章节标题加粗放大，字段名加粗
+///
+/// * `sections` - 报告章节
+///
+/// 示例
+/// ```
+/// use hardware_master::report::{template, ReportSection};
+/// let section = ReportSection {
+///     title: "内存".to_string(),
+///     rows: vec![("容量".to_string(), "32 GB".to_string())],
+/// };
+/// assert!(template::bbcode(&[section])
+///     .starts_with("[size=4][b]内存[/b][/size]\n[b]容量[/b]: 32 GB\n"));
+/// ```
+pub fn bbcode(sections: &[ReportSection]) -> String {
+    let mut text = String::new();
+    for section in sections {
+        text.push_str(&format!("[size=4][b]{}[/b][/size]\n", section.title));
+        if section.rows.is_empty() {
+            text.push_str("未检测到\n");
+        }
+        for (key, value) in section.rows.iter() {
+            text.push_str(&format!("[b]{}[/b]: {}\n", key, value));
+        }
+        text.push('\n');
+    }
+    text.push_str(&format!("[i]{}[/i]\n", footer()));
+    text
+}
@@ -0,0 +1,97 @@
+//! XML 报告生成模块
+//!
+//! 输出与 msinfo32 `/nfo` 导出文件相似的 XML 结构（`MsInfo` → `Category` → `Data` →
+//! `Item`/`Value`），便于已有的资产盘点流程少量修改即可导入。
+//! “系统”章节对应 msinfo32 的“系统摘要”，其余章节归入“组件”分类。
+
+use super::build_sections_with_asset;
+use crate::asset::AssetRecord;
+use crate::detector::HardwareDetector;
+
+/// msinfo32 XML 格式版本
+const MSINFO_VERSION: &str = "8.0";
+
+/// 生成完整的 XML 报告
+///
+/// * `detector` - 已完成检测的硬件检测器
+/// * `asset` - 本机的资产信息，有时在最后附带“资产”章节
+pub fn generate(detector: &HardwareDetector, asset: Option<&AssetRecord>) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<MsInfo>\n");
+    xml.push_str("<Metadata>\n");
+    xml.push_str(&format!("<Version>{}</Version>\n", MSINFO_VERSION));
+    xml.push_str(&format!(
+        "<CreationUTC>{}</CreationUTC>\n",
+        chrono::Utc::now().format("%m/%d/%y %H:%M:%S")
+    ));
+    xml.push_str(&format!(
+        "<Generator>{}</Generator>\n",
+        cdata(&format!("硬大师 v{}", crate::VERSION))
+    ));
+    xml.push_str("</Metadata>\n");
+
+    let mut sections = build_sections_with_asset(detector, asset).into_iter();
+    if let Some(summary) = sections.next() {
+        xml.push_str("<Category name=\"系统摘要\">\n");
+        push_rows(&mut xml, &summary.rows);
+        xml.push_str("<Category name=\"组件\">\n");
+        for section in sections {
+            xml.push_str(&format!(
+                "<Category name=\"{}\">\n",
+                escape_attribute(&section.title)
+            ));
+            push_rows(&mut xml, &section.rows);
+            xml.push_str("</Category>\n");
+        }
+        xml.push_str("</Category>\n");
+        xml.push_str("</Category>\n");
+    }
+
+    xml.push_str("</MsInfo>\n");
+    xml
+}
+
+/// 输出章节中的全部字段
+fn push_rows(xml: &mut String, rows: &[(String, String)]) {
+    for (key, value) in rows {
+        xml.push_str("<Data>\n");
+        xml.push_str(&format!("<Item>{}</Item>\n", cdata(key)));
+        xml.push_str(&format!("<Value>{}</Value>\n", cdata(value)));
+        xml.push_str("</Data>\n");
+    }
+}
+
+/// 将文本包裹在 CDATA 中，文本内的 `]]>` 会拆分到两个 CDATA 段
+///
+/// 示例
+/// ```
+/// use hardware_master::report::xml::cdata;
+/// assert_eq!(cdata("DDR4 <3200>"), "<![CDATA[DDR4 <3200>]]>");
+/// assert_eq!(cdata("a]]>b"), "<![CDATA[a]]]]><![CDATA[>b]]>");
+/// ```
+pub fn cdata(text: &str) -> String {
+    format!("<![CDATA[{}]]>", text.replace("]]>", "]]]]><![CDATA[>"))
+}
+
+/// 转义 XML 属性值
+///
+/// 示例
+/// ```
+/// use hardware_master::report::xml::escape_attribute;
+/// assert_eq!(escape_attribute("<\"A&B\">"), "&lt;&quot;A&amp;B&quot;&gt;");
+/// ```
+pub fn escape_attribute(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,534 @@
+//! 本地 HTTP 接口
+//!
+//! 以 `--serve 127.0.0.1:<端口>` 启动时，在界面运行期间于本机地址上提供只读的检测结果：
+//!
+//! - `/report.json`：完整检测结果（与检测结果缓存格式相同，附带本机的资产信息，需要启用 `serialize` 特性）
+//! - `/metrics`：Prometheus 文本格式的主要数值指标，便于资产管理脚本或监控系统采集
+//!
+//! 检测结果包含序列号等敏感信息，因此只允许监听回环地址，隐私模式下 `/report.json` 中的序列号会被遮盖。
+//! 为防止网页通过 DNS 重绑定读取接口，`Host` 不是本机地址的请求返回 403。
+//! 接口只实现了最基本的 HTTP/1.1，请求行与请求头的长度和数量有上限，
+//! 每个连接处理一个请求后即关闭。
+
+use crate::asset::AssetRecord;
+use crate::constants::{
+    SERVE_ARG, SERVE_MAX_HEADERS, SERVE_MAX_LINE_BYTES, SERVE_READ_TIMEOUT_SECS,
+};
+use crate::HardwareDetector;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// 本地 HTTP 接口参数
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServeOptions {
+    /// 监听地址
+    pub addr: SocketAddr,
+}
+
+impl ServeOptions {
+    /// 解析命令行参数，未指定 `--serve` 时返回 `Ok(None)`
+    ///
+    /// 监听地址必须是回环地址
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::server::ServeOptions;
+    /// let args = ["硬大师.exe", "--serve", "127.0.0.1:9182"].map(String::from);
+    /// let options = ServeOptions::from_args(args).unwrap().unwrap();
+    /// assert_eq!(options.addr.port(), 9182);
+    /// let args = ["硬大师.exe", "--serve", "0.0.0.0:9182"].map(String::from);
+    /// assert!(ServeOptions::from_args(args).is_err());
+    /// assert_eq!(ServeOptions::from_args(["硬大师.exe".to_string()]), Ok(None));
+    /// ```
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg != SERVE_ARG {
+                continue;
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} 需要监听地址，如 127.0.0.1:9182", SERVE_ARG))?;
+            let addr: SocketAddr = value
+                .parse()
+                .map_err(|_| format!("无效的监听地址: {}", value))?;
+            if !addr.ip().is_loopback() {
+                return Err(format!("{} 只允许监听本机回环地址: {}", SERVE_ARG, addr));
+            }
+            return Ok(Some(Self { addr }));
+        }
+        Ok(None)
+    }
+}
+
+/// HTTP 响应
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    /// 状态码
+    pub status: u16,
+    /// 内容类型
+    pub content_type: &'static str,
+    /// 响应正文
+    pub body: String,
+}
+
+impl HttpResponse {
+    fn text(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: body.into(),
+        }
+    }
+
+    /// 状态码对应的原因短语
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            414 => "URI Too Long",
+            431 => "Request Header Fields Too Large",
+            500 => "Internal Server Error",
+            501 => "Not Implemented",
+            _ => "",
+        }
+    }
+
+    /// 序列化为 HTTP/1.1 响应报文
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.status,
+            self.reason(),
+            self.content_type,
+            self.body.len()
+        )
+        .into_bytes();
+        bytes.extend_from_slice(self.body.as_bytes());
+        bytes
+    }
+}
+
+/// HTTP 请求中接口用到的部分
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    /// 请求方法
+    pub method: String,
+    /// 请求路径
+    pub path: String,
+    /// `Host` 请求头，未提供时为 None
+    pub host: Option<String>,
+}
+
+/// 读取请求行与请求头，请求正文被忽略
+///
+/// 请求行过长返回 414，单个请求头过长或请求头过多返回 431，读取失败返回 400
+///
+/// 示例
+/// ```
+/// use hardware_master::server::read_request;
+/// let mut reader = &b"GET /metrics HTTP/1.1\r\nHost: localhost:9182\r\n\r\n"[..];
+/// let request = read_request(&mut reader).unwrap();
+/// assert_eq!(request.path, "/metrics");
+/// assert_eq!(request.host.as_deref(), Some("localhost:9182"));
+/// ```
+pub fn read_request(reader: &mut impl BufRead) -> Result<HttpRequest, HttpResponse> {
+    let request_line = match read_line(reader) {
+        Ok(Some(line)) => line,
+        Ok(None) => return Err(HttpResponse::text(414, "请求行过长")),
+        Err(e) => return Err(HttpResponse::text(400, format!("读取请求失败: {}", e))),
+    };
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => return Err(HttpResponse::text(400, "请求行格式错误")),
+    };
+
+    let mut host = None;
+    let mut headers = 0;
+    loop {
+        let line = match read_line(reader) {
+            Ok(Some(line)) => line,
+            Ok(None) => return Err(HttpResponse::text(431, "请求头过长")),
+            Err(e) => return Err(HttpResponse::text(400, format!("读取请求失败: {}", e))),
+        };
+        // 空行表示请求头结束，连接提前关闭时同样结束
+        if line.is_empty() {
+            break;
+        }
+        headers += 1;
+        if headers > SERVE_MAX_HEADERS {
+            return Err(HttpResponse::text(431, "请求头过多"));
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("host") {
+                host = Some(value.trim().to_string());
+            }
+        }
+    }
+    Ok(HttpRequest { method, path, host })
+}
+
+/// 读取一行并去掉行尾换行，超过 [`SERVE_MAX_LINE_BYTES`] 时返回 None
+fn read_line(reader: &mut impl BufRead) -> std::io::Result<Option<String>> {
+    let mut line = Vec::new();
+    reader
+        .by_ref()
+        .take(SERVE_MAX_LINE_BYTES as u64 + 1)
+        .read_until(b'\n', &mut line)?;
+    if line.len() > SERVE_MAX_LINE_BYTES {
+        return Ok(None);
+    }
+    let line = String::from_utf8_lossy(&line);
+    Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+}
+
+/// `Host` 请求头是否为本机地址：127.0.0.1、localhost 或 [::1]，可以带端口
+///
+/// 示例
+/// ```
+/// use hardware_master::server::is_local_host;
+/// assert!(is_local_host("127.0.0.1:9182"));
+/// assert!(is_local_host("LocalHost"));
+/// assert!(is_local_host("[::1]:9182"));
+/// assert!(!is_local_host("attacker.example:9182"));
+/// assert!(!is_local_host("localhost.attacker.example"));
+/// assert!(!is_local_host("127.0.0.1:abc"));
+/// ```
+pub fn is_local_host(host: &str) -> bool {
+    let host = host.trim();
+    let name = match host.rsplit_once(':') {
+        // IPv6 地址不带端口时最后一个冒号在方括号内
+        Some((name, port)) if !port.contains(']') => {
+            if port.is_empty() || !port.bytes().all(|b| b.is_ascii_digit()) {
+                return false;
+            }
+            name
+        }
+        _ => host,
+    };
+    name == "127.0.0.1" || name == "[::1]" || name.eq_ignore_ascii_case("localhost")
+}
+
+/// 按请求方法与路径生成响应
+///
+/// * `method` - 请求方法，只支持 GET
+/// * `path` - 请求路径，查询参数会被忽略
+/// * `detector` - 当前检测结果
+/// * `privacy_mode` - 是否遮盖序列号等敏感信息
+/// * `asset` - 本机的资产信息，有时附带在 `/report.json` 中
+pub fn route(
+    method: &str,
+    path: &str,
+    detector: &HardwareDetector,
+    privacy_mode: bool,
+    asset: Option<&AssetRecord>,
+) -> HttpResponse {
+    if method != "GET" {
+        return HttpResponse::text(405, "只支持 GET 请求");
+    }
+    match path.split('?').next().unwrap_or_default() {
+        "/" => HttpResponse::text(200, "硬大师本地接口\n/report.json\n/metrics\n"),
+        "/report.json" => report_json(detector, privacy_mode, asset),
+        "/metrics" => HttpResponse {
+            status: 200,
+            content_type: "text/plain; version=0.0.4; charset=utf-8",
+            body: metrics(detector),
+        },
+        _ => HttpResponse::text(404, "未找到"),
+    }
+}
+
+/// `/report.json` 的内容：检测结果缓存的全部字段，以及本机的资产信息
+#[cfg(feature = "serialize")]
+#[derive(serde::Serialize)]
+struct ReportJson<'a> {
+    #[serde(flatten)]
+    detection: crate::cache::CachedDetection,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    asset: Option<&'a AssetRecord>,
+}
+
+/// 完整检测结果的 JSON
+#[cfg(feature = "serialize")]
+fn report_json(
+    detector: &HardwareDetector,
+    privacy_mode: bool,
+    asset: Option<&AssetRecord>,
+) -> HttpResponse {
+    let mut detection =
+        crate::cache::CachedDetection::from_detector(detector, chrono::Local::now().timestamp());
+    if privacy_mode {
+        detection.mask_sensitive();
+    }
+    match serde_json::to_string_pretty(&ReportJson { detection, asset }) {
+        Ok(body) => HttpResponse {
+            status: 200,
+            content_type: "application/json; charset=utf-8",
+            body,
+        },
+        Err(e) => HttpResponse::text(500, format!("序列化检测结果失败: {}", e)),
+    }
+}
+
+/// 未启用 serialize 特性时无法输出 JSON
+#[cfg(not(feature = "serialize"))]
+fn report_json(
+    _detector: &HardwareDetector,
+    _privacy_mode: bool,
+    _asset: Option<&AssetRecord>,
+) -> HttpResponse {
+    HttpResponse::text(501, "/report.json 需要启用 serialize 特性")
+}
+
+/// 转义 Prometheus 标签值中的反斜杠、双引号与换行
+///
+/// 示例
+/// ```
+/// use hardware_master::server::escape_label;
+/// assert_eq!(escape_label("Intel(R) \"Core\"\n"), "Intel(R) \\\"Core\\\"\\n");
+/// ```
+pub fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Prometheus 文本格式的指标
+struct MetricsWriter {
+    text: String,
+}
+
+impl MetricsWriter {
+    /// 写入指标的说明与类型（均为 gauge）
+    fn describe(&mut self, name: &str, help: &str) {
+        let _ = writeln!(self.text, "# HELP {} {}", name, help);
+        let _ = writeln!(self.text, "# TYPE {} gauge", name);
+    }
+
+    /// 写入一个样本
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        let labels: Vec<String> = labels
+            .iter()
+            .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+            .collect();
+        if labels.is_empty() {
+            let _ = writeln!(self.text, "{} {}", name, value);
+        } else {
+            let _ = writeln!(self.text, "{}{{{}}} {}", name, labels.join(","), value);
+        }
+    }
+
+    /// 写入只有一个样本的指标
+    fn gauge(&mut self, name: &str, help: &str, value: f64) {
+        self.describe(name, help);
+        self.sample(name, &[], value);
+    }
+}
+
+/// 生成 Prometheus 文本格式的指标，未检测到的数值不输出
+pub fn metrics(detector: &HardwareDetector) -> String {
+    let mut writer = MetricsWriter {
+        text: String::new(),
+    };
+
+    writer.describe("hardware_master_info", "硬件概要，值恒为 1");
+    writer.sample(
+        "hardware_master_info",
+        &[
+            ("version", crate::VERSION),
+            ("model", &detector.system_info.computer_model),
+            ("os", &detector.system_info.os_name),
+            ("cpu", &detector.cpu_info.name),
+        ],
+        1.0,
+    );
+
+    let cpu = &detector.cpu_info;
+    writer.describe("hardware_master_cpu_cores", "处理器核心数");
+    writer.sample(
+        "hardware_master_cpu_cores",
+        &[("kind", "physical")],
+        cpu.physical_cores as f64,
+    );
+    writer.sample(
+        "hardware_master_cpu_cores",
+        &[("kind", "logical")],
+        cpu.logical_cores as f64,
+    );
+
+    writer.gauge(
+        "hardware_master_memory_total_bytes",
+        "内存总容量（字节）",
+        detector.memory_info.total_memory.as_f64(),
+    );
+
+    let disk = &detector.disk_info;
+    writer.gauge(
+        "hardware_master_disk_total_bytes",
+        "主硬盘容量（字节）",
+        disk.total_capacity.as_f64(),
+    );
+    if let Some(wear) = disk.wear_percent {
+        writer.gauge("hardware_master_disk_wear_percent", "主硬盘磨损（%）", wear);
+    }
+    if let Some(temperature) = disk.temperature {
+        writer.gauge(
+            "hardware_master_disk_temperature_celsius",
+            "主硬盘温度（°C）",
+            temperature,
+        );
+    }
+    if let Some(hours) = disk.power_on_hours {
+        writer.gauge(
+            "hardware_master_disk_power_on_hours",
+            "主硬盘通电时间（小时）",
+            hours as f64,
+        );
+    }
+    if !disk.volumes.is_empty() {
+        writer.describe("hardware_master_volume_free_bytes", "分区可用空间（字节）");
+        for volume in disk.volumes.iter() {
+            writer.sample(
+                "hardware_master_volume_free_bytes",
+                &[("drive", &volume.drive_letter)],
+                volume.free_space.as_f64(),
+            );
+        }
+    }
+
+    let batteries = &detector.battery_info.batteries;
+    if !batteries.is_empty() {
+        writer.describe("hardware_master_battery_health_percent", "电池健康度（%）");
+        for battery in batteries.iter() {
+            writer.sample(
+                "hardware_master_battery_health_percent",
+                &[("name", &battery.name)],
+                battery.health,
+            );
+        }
+        writer.describe("hardware_master_battery_cycle_count", "电池循环次数");
+        for battery in batteries.iter() {
+            writer.sample(
+                "hardware_master_battery_cycle_count",
+                &[("name", &battery.name)],
+                battery.cycle_count as f64,
+            );
+        }
+    }
+
+    let gpus = &detector.gpu_info.gpus;
+    if !gpus.is_empty() {
+        writer.describe("hardware_master_gpu_memory_bytes", "显卡专用显存（字节）");
+        for gpu in gpus.iter() {
+            writer.sample(
+                "hardware_master_gpu_memory_bytes",
+                &[("name", &gpu.description)],
+                gpu.dedicated_vram.as_f64(),
+            );
+        }
+    }
+
+    writer.text
+}
+
+/// 运行中的本地 HTTP 接口，丢弃时停止监听
+pub struct LocalServer {
+    addr: SocketAddr,
+    stopped: Arc<AtomicBool>,
+}
+
+impl LocalServer {
+    /// 在后台线程中开始监听
+    ///
+    /// * `addr` - 监听地址，端口为 0 时由系统分配
+    /// * `detector` - 与界面共享的检测器，每个请求读取其当前结果
+    /// * `privacy_mode` - 与界面共享的隐私模式开关，每个请求读取其当前值
+    /// * `asset` - 与界面共享的本机资产信息，每个请求读取其当前值
+    pub fn start(
+        addr: SocketAddr,
+        detector: Arc<Mutex<HardwareDetector>>,
+        privacy_mode: Arc<AtomicBool>,
+        asset: Arc<Mutex<Option<AssetRecord>>>,
+    ) -> Result<Self, String> {
+        let listener = TcpListener::bind(addr).map_err(|e| format!("监听 {} 失败: {}", addr, e))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| format!("获取监听地址失败: {}", e))?;
+        let stopped = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stopped);
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop_flag.load(Ordering::Relaxed) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => {
+                        let privacy_mode = privacy_mode.load(Ordering::Relaxed);
+                        let asset = asset.lock().ok().and_then(|asset| asset.clone());
+                        if let Err(e) =
+                            handle_connection(stream, &detector, privacy_mode, asset.as_ref())
+                        {
+                            log::debug!("处理本地接口请求失败: {}", e);
+                        }
+                    }
+                    Err(e) => log::debug!("接受本地接口连接失败: {}", e),
+                }
+            }
+        });
+        log::info!("本地接口已启动: http://{}/report.json", addr);
+        Ok(Self { addr, stopped })
+    }
+
+    /// 实际监听的地址
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for LocalServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        // 连接一次以唤醒阻塞在 accept 上的线程
+        let _ = TcpStream::connect_timeout(&self.addr, Duration::from_secs(1));
+    }
+}
+
+/// 读取请求并写回响应，`Host` 不是本机地址时返回 403
+fn handle_connection(
+    mut stream: TcpStream,
+    detector: &Mutex<HardwareDetector>,
+    privacy_mode: bool,
+    asset: Option<&AssetRecord>,
+) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(SERVE_READ_TIMEOUT_SECS)))?;
+    // 读完请求头，避免客户端在写完请求前收到连接重置
+    let request = read_request(&mut BufReader::new(stream.try_clone()?));
+    let response = match request {
+        Err(response) => response,
+        Ok(request) if !request.host.as_deref().is_some_and(is_local_host) => {
+            HttpResponse::text(403, "只接受 Host 为本机地址的请求")
+        }
+        Ok(request) => match detector.lock() {
+            Ok(detector) => route(
+                &request.method,
+                &request.path,
+                &detector,
+                privacy_mode,
+                asset,
+            ),
+            Err(_) => HttpResponse::text(500, "硬件检测器互斥锁被污染"),
+        },
+    };
+    stream.write_all(&response.to_bytes())?;
+    stream.flush()
+}
@@ -0,0 +1,429 @@
+//! 硬件快照对比模块
+//!
+//! 每次检测完成后将设备清单保存到 `%APPDATA%\hardware-master\last_snapshot.json`，
+//! 下次检测完成后与之比较，列出新增、移除与属性变化的设备（如加装内存条、更换硬盘、电池健康度下降）。
+//! 快照只记录用于识别设备的稳定属性，温度、剩余电量等随时变化的数据不参与比较。
+
+use crate::config::AppConfig;
+use crate::constants::SNAPSHOT_FILE_NAME;
+use crate::detector::DetectionCategory;
+use crate::utils::string::{format_cores, format_size, mask_sensitive};
+use crate::HardwareDetector;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// 含序列号等敏感信息的属性，隐私模式下显示时遮盖
+pub const SENSITIVE_PROPERTIES: &[&str] = &["序列号"];
+
+/// 快照中的单个设备
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SnapshotDevice {
+    /// 设备标识，用于在两次快照之间匹配同一设备
+    pub id: String,
+    /// 设备名称
+    pub name: String,
+    /// 参与比较的属性 (名称, 值)
+    pub properties: Vec<(String, String)>,
+}
+
+impl SnapshotDevice {
+    fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            properties: Vec::new(),
+        }
+    }
+
+    fn with(mut self, property: &str, value: impl Into<String>) -> Self {
+        self.properties.push((property.to_string(), value.into()));
+        self
+    }
+
+    /// 获取属性值
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// 一次检测的设备清单快照
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HardwareSnapshot {
+    /// 记录时间（Unix 时间戳，秒）
+    pub timestamp: i64,
+    /// 快照包含的检测类别，未启用的类别不参与比较
+    pub categories: Vec<DetectionCategory>,
+    /// 各类别的设备列表
+    pub devices: Vec<(DetectionCategory, SnapshotDevice)>,
+}
+
+impl HardwareSnapshot {
+    /// 从检测结果生成快照
+    ///
+    /// * `detector` - 硬件检测器
+    /// * `timestamp` - 记录时间（Unix 时间戳，秒）
+    pub fn capture(detector: &HardwareDetector, timestamp: i64) -> Self {
+        let mut snapshot = Self {
+            timestamp,
+            categories: detector.enabled_categories().to_vec(),
+            devices: Vec::new(),
+        };
+
+        let system = &detector.system_info;
+        snapshot.push(
+            DetectionCategory::System,
+            SnapshotDevice::new("system", &system.computer_model)
+                .with("制造商", &system.system_manufacturer)
+                .with("操作系统", &system.os_name)
+                .with("序列号", &system.serial_number),
+        );
+
+        let cpu = &detector.cpu_info;
+        snapshot.push(
+            DetectionCategory::Cpu,
+            SnapshotDevice::new("cpu", &cpu.name)
+                .with("型号", &cpu.name)
+                .with(
+                    "核心数",
+                    format_cores(cpu.physical_cores, cpu.logical_cores, cpu.efficiency_cores),
+                ),
+        );
+
+        let slots = detector.memory_info.slots.iter().map(|slot| &slot.name);
+        for (slot, id) in detector.memory_info.slots.iter().zip(numbered_ids(slots)) {
+            snapshot.push(
+                DetectionCategory::Memory,
+                SnapshotDevice::new(id, &slot.name).with("容量", format_size(slot.capacity)),
+            );
+        }
+
+        // 存储卡与 U 盘随时插拔，不计入硬件变化
+        let disk = &detector.disk_info;
+        let fixed: Vec<_> = disk
+            .physical_disks
+            .iter()
+            .filter(|d| !d.removable)
+            .collect();
+        let disks = fixed.iter().map(|d| &d.model);
+        for (physical, id) in fixed.iter().zip(numbered_ids(disks)) {
+            snapshot.push(
+                DetectionCategory::Disk,
+                SnapshotDevice::new(id, &physical.model)
+                    .with("容量", format_size(physical.total_capacity)),
+            );
+        }
+        snapshot.push(
+            DetectionCategory::Disk,
+            SnapshotDevice::new("disk.main", format!("主硬盘 {}", disk.model))
+                .with("型号", &disk.model)
+                .with("序列号", &disk.serial_number),
+        );
+
+        let gpus = detector.gpu_info.gpus.iter().map(|gpu| &gpu.description);
+        for (gpu, id) in detector.gpu_info.gpus.iter().zip(numbered_ids(gpus)) {
+            snapshot.push(
+                DetectionCategory::Gpu,
+                SnapshotDevice::new(id, &gpu.description)
+                    .with("显存", format_size(gpu.dedicated_vram))
+                    .with("驱动版本", &gpu.driver_version),
+            );
+        }
+
+        let board = &detector.motherboard_info;
+        snapshot.push(
+            DetectionCategory::Motherboard,
+            SnapshotDevice::new("motherboard", &board.product_name)
+                .with(
+                    "型号",
+                    format!("{} {}", board.manufacturer, board.product_name),
+                )
+                .with("序列号", &board.serial_number)
+                .with("BIOS 版本", &board.bios_version),
+        );
+
+        for adapter in detector.network_info.adapters.iter() {
+            snapshot.push(
+                DetectionCategory::Network,
+                SnapshotDevice::new(&adapter.pnp_device_id, &adapter.name)
+                    .with("MAC 地址", &adapter.mac_address)
+                    .with("驱动版本", &adapter.driver_version),
+            );
+        }
+
+        for device in detector.audio_info.devices.iter() {
+            snapshot.push(
+                DetectionCategory::Audio,
+                SnapshotDevice::new(&device.device_id, &device.name)
+                    .with("连接方式", device.transport.to_string()),
+            );
+        }
+
+        let monitor = &detector.monitor_info;
+        if !monitor.panel_id.is_empty() && monitor.panel_id != "未知" {
+            snapshot.push(
+                DetectionCategory::Monitor,
+                SnapshotDevice::new(&monitor.panel_id, &monitor.name),
+            );
+        }
+
+        let batteries = detector.battery_info.batteries.iter().map(|b| &b.name);
+        for (battery, id) in detector
+            .battery_info
+            .batteries
+            .iter()
+            .zip(numbered_ids(batteries))
+        {
+            snapshot.push(
+                DetectionCategory::Battery,
+                SnapshotDevice::new(id, &battery.name)
+                    .with("健康度", format!("{:.0}%", battery.health))
+                    .with("满充容量", format!("{} mWh", battery.full_charged_capacity)),
+            );
+        }
+
+        if let Some(tpm) = &detector.security_info.tpm {
+            snapshot.push(
+                DetectionCategory::Security,
+                SnapshotDevice::new("tpm", format!("TPM {}", tpm.version))
+                    .with("固件版本", &tpm.firmware_version),
+            );
+        }
+
+        snapshot
+    }
+
+    fn push(&mut self, category: DetectionCategory, device: SnapshotDevice) {
+        self.devices.push((category, device));
+    }
+
+    /// 指定类别的设备
+    pub fn devices_of(&self, category: DetectionCategory) -> Vec<&SnapshotDevice> {
+        self.devices
+            .iter()
+            .filter(|(c, _)| *c == category)
+            .map(|(_, device)| device)
+            .collect()
+    }
+}
+
+/// 为同名设备生成带序号的标识，如两条相同的内存条为 "名称#1"、"名称#2"
+fn numbered_ids<'a>(names: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let mut counts: HashMap<&String, usize> = HashMap::new();
+    names
+        .map(|name| {
+            let count = counts.entry(name).or_insert(0);
+            *count += 1;
+            format!("{}#{}", name, count)
+        })
+        .collect()
+}
+
+/// 两次快照之间的硬件变化
+#[derive(Debug, Clone, PartialEq)]
+pub enum HardwareChange {
+    /// 新增设备
+    Added {
+        /// 检测类别
+        category: DetectionCategory,
+        /// 设备名称
+        name: String,
+    },
+    /// 移除设备
+    Removed {
+        /// 检测类别
+        category: DetectionCategory,
+        /// 设备名称
+        name: String,
+    },
+    /// 设备属性变化
+    Changed {
+        /// 检测类别
+        category: DetectionCategory,
+        /// 设备名称
+        name: String,
+        /// 属性名称
+        property: String,
+        /// 旧值
+        old: String,
+        /// 新值
+        new: String,
+    },
+}
+
+impl HardwareChange {
+    /// 检测类别
+    pub fn category(&self) -> DetectionCategory {
+        match self {
+            HardwareChange::Added { category, .. }
+            | HardwareChange::Removed { category, .. }
+            | HardwareChange::Changed { category, .. } => *category,
+        }
+    }
+
+    /// 遮盖敏感属性的新旧值，用于隐私模式下显示
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::detector::DetectionCategory;
+    /// use hardware_master::snapshot::HardwareChange;
+    /// let change = HardwareChange::Changed {
+    ///     category: DetectionCategory::Disk,
+    ///     name: "主硬盘".to_string(),
+    ///     property: "序列号".to_string(),
+    ///     old: "S4EWNX0R123456".to_string(),
+    ///     new: "S4EWNX0R654321".to_string(),
+    /// };
+    /// assert_eq!(change.masked().description(), "磁盘信息: 主硬盘 序列号 S4**********56 → S4**********21");
+    /// ```
+    pub fn masked(&self) -> Self {
+        match self {
+            HardwareChange::Changed {
+                category,
+                name,
+                property,
+                old,
+                new,
+            } if SENSITIVE_PROPERTIES.contains(&property.as_str()) => HardwareChange::Changed {
+                category: *category,
+                name: name.clone(),
+                property: property.clone(),
+                old: mask_sensitive(old),
+                new: mask_sensitive(new),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// 描述，如 "电池信息: Battery 健康度 95% → 80%"
+    pub fn description(&self) -> String {
+        match self {
+            HardwareChange::Added { category, name } => format!("{}: 新增 {}", category, name),
+            HardwareChange::Removed { category, name } => format!("{}: 移除 {}", category, name),
+            HardwareChange::Changed {
+                category,
+                name,
+                property,
+                old,
+                new,
+            } => format!("{}: {} {} {} → {}", category, name, property, old, new),
+        }
+    }
+}
+
+/// 比较两次快照，按检测类别顺序列出移除、新增与属性变化的设备
+///
+/// 只比较两次快照都包含的类别
+///
+/// 示例
+/// ```
+/// use hardware_master::detector::battery::Battery;
+/// use hardware_master::snapshot::{diff, HardwareSnapshot};
+/// use hardware_master::HardwareDetector;
+///
+/// let mut detector = HardwareDetector::new();
+/// detector.battery_info.batteries.push(Battery { health: 95.0, ..Default::default() });
+/// let old = HardwareSnapshot::capture(&detector, 0);
+/// detector.battery_info.batteries[0].health = 80.0;
+/// let new = HardwareSnapshot::capture(&detector, 1);
+///
+/// let changes = diff(&old, &new);
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].description(), "电池信息: 未知 健康度 95% → 80%");
+/// ```
+pub fn diff(old: &HardwareSnapshot, new: &HardwareSnapshot) -> Vec<HardwareChange> {
+    let mut changes = Vec::new();
+
+    for category in DetectionCategory::ALL {
+        if !old.categories.contains(&category) || !new.categories.contains(&category) {
+            continue;
+        }
+        let old_devices = old.devices_of(category);
+        let new_devices = new.devices_of(category);
+
+        for device in old_devices.iter() {
+            if !new_devices.iter().any(|d| d.id == device.id) {
+                changes.push(HardwareChange::Removed {
+                    category,
+                    name: device.name.clone(),
+                });
+            }
+        }
+
+        for device in new_devices.iter() {
+            let Some(previous) = old_devices.iter().find(|d| d.id == device.id) else {
+                changes.push(HardwareChange::Added {
+                    category,
+                    name: device.name.clone(),
+                });
+                continue;
+            };
+            for (property, value) in device.properties.iter() {
+                match previous.property(property) {
+                    Some(old_value) if old_value != value => {
+                        changes.push(HardwareChange::Changed {
+                            category,
+                            name: device.name.clone(),
+                            property: property.clone(),
+                            old: old_value.to_string(),
+                            new: value.clone(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    changes
+}
+
+/// 上一次检测快照的存储
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    path: PathBuf,
+}
+
+impl SnapshotStore {
+    /// 使用指定文件创建存储
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// 使用配置目录下的默认文件创建存储
+    pub fn open_default() -> Option<Self> {
+        AppConfig::config_dir().map(|dir| Self::new(dir.join(SNAPSHOT_FILE_NAME)))
+    }
+
+    /// 存储文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 读取上一次快照，文件不存在时返回 None
+    pub fn load(&self) -> Result<Option<HardwareSnapshot>, String> {
+        let content = match std::fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("读取硬件快照失败: {}", e)),
+        };
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| format!("解析硬件快照失败: {}", e))
+    }
+
+    /// 保存快照，覆盖上一次的快照
+    pub fn save(&self, snapshot: &HardwareSnapshot) -> Result<(), String> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("创建快照目录失败: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(snapshot)
+            .map_err(|e| format!("序列化硬件快照失败: {}", e))?;
+        std::fs::write(&self.path, content).map_err(|e| format!("写入硬件快照失败: {}", e))
+    }
+}
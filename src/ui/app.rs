@@ -1,13 +1,69 @@
+#[cfg(feature = "serialize")]
+use crate::agent;
+use crate::analysis::advice::{Advice, AdviceLevel, AdviceRuleSet};
+use crate::analysis::baseline::{Baseline, BaselineMismatch};
+#[cfg(feature = "driver-update-check")]
+use crate::analysis::driver_advisor::DriverProvider;
+use crate::analysis::driver_advisor::{self, DriverRuleSet};
+use crate::analysis::energy::{EnergyProfile, PowerEstimate};
+use crate::analysis::grade::{Grade, GradeRules, Tier};
+use crate::analysis::inspection::{self, CheckItem, CheckRuleSet, CheckStatus};
+use crate::analysis::upgrade::{Priority, Suggestion, UpgradeRuleSet};
+use crate::asset::{self, AssetRecord};
+use crate::cache::describe_age;
+#[cfg(feature = "serialize")]
+use crate::cache::{CachedDetection, DetectionCache};
+use crate::config::{AppConfig, Language, VirtualDeviceMode};
+use crate::constants::AUTO_REFRESH_CHOICES;
+use crate::constants::BYTES_PER_GB;
+use crate::constants::DEFAULT_ACCENT_COLOR;
+use crate::constants::ENERGY_HOURS_PER_DAY;
+use crate::constants::MAX_DETECTION_THREADS;
+use crate::constants::SCREENSHOT_MARGIN;
+use crate::detector::dynamic::DynamicData;
+use crate::detector::events::HotplugWatcher;
+use crate::detector::power::PowerStatus;
+use crate::detector::system::{format_uptime, hotfix_kind_name};
+use crate::detector::volume::{group_by_disk, Volume};
 use crate::detector::DEBOUNCE_DURATION_SECS;
-use crate::detector::{gpu::GpuType, HardwareDetector};
-use crate::utils::string::format_size;
+use crate::detector::{
+    gpu::GpuType, DetectionCategory, DetectionError, DetectionEvent, DetectionPriority,
+    HardwareDetector,
+};
+use crate::handover::HandoverState;
+use crate::history::{battery_degradation, HistoryStore, Snapshot};
+#[cfg(feature = "ids-updater")]
+use crate::iddb::updater::{self, IdsUpdate};
+use crate::iddb::{self, vendor_zh::display_vendor, IdsSource};
+use crate::report::csv::{self, CsvTable};
+use crate::report::template::CopyTemplate;
+use crate::server::{LocalServer, ServeOptions};
+use crate::snapshot::{self, HardwareChange, HardwareSnapshot, SnapshotStore};
+use crate::ui::asset::AssetPanel;
+use crate::ui::benchmark::BenchmarkPanel;
+use crate::ui::chart::{line_chart, line_chart_with_threshold, SeriesPoint};
+use crate::ui::copy;
+use crate::ui::details::{DetailWindows, DeviceDetails};
+use crate::ui::drivers::DriverPanel;
+use crate::ui::font::{self, FontChoice};
+use crate::ui::live::LiveMonitor;
+use crate::ui::peripherals::PeripheralPanel;
+use crate::ui::screenshot;
+use crate::ui::search::{self, SearchIndex, SearchPalette, BATTERY_DETAILS, MOTHERBOARD_DETAILS};
+use crate::ui::tab::AppTab;
+use crate::ui::theme::{Density, ThemeStyle};
+use crate::ui::timings;
+use crate::ui::tray::{self, SystemTray, TrayCommand};
+use crate::ui::wmi_console::WmiConsole;
+use crate::utils::elevation;
+use crate::utils::string::{format_cores, format_size, mask_sensitive};
 use eframe::egui;
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{mpsc, Arc, Mutex};
 use std::time::{Duration, Instant};
 
-/// 进度更新消息
-type ProgressMessage = (f32, String);
-
 /// 硬件检测工具主应用程序
 pub struct HardwareMasterApp {
     /// 硬件检测器
@@ -20,76 +76,384 @@ pub struct HardwareMasterApp {
     detection_message: String,
     /// 是否已检测
     has_detected: bool,
+    /// 正在显示的缓存检测结果的时间（Unix 时间戳，秒），后台检测完成后为 None
+    cached_at: Option<i64>,
+    /// 界面上下文，后台线程收发消息后通过它唤醒界面重绘
+    egui_ctx: egui::Context,
     /// 检测完成接收器
     detection_rx: Option<mpsc::Receiver<()>>,
     /// 进度更新接收器
-    progress_rx: Option<mpsc::Receiver<ProgressMessage>>,
+    progress_rx: Option<mpsc::Receiver<DetectionEvent>>,
     /// 上次刷新时间（用于防抖）
     last_refresh_time: Option<Instant>,
+    /// 上次更新动态数据（电池、分区可用空间、硬盘温度）的时间
+    last_dynamic_refresh: Instant,
+    /// 动态数据刷新结果接收器，刷新进行中时为 Some
+    dynamic_rx: Option<mpsc::Receiver<DynamicData>>,
     /// 当前主题
     theme: crate::ui::theme::AppTheme,
+    /// 购买建议规则
+    advice_rules: AdviceRuleSet,
+    /// 当前检测结果对应的购买建议
+    advice: Vec<Advice>,
+    /// 是否显示购买建议面板
+    show_advice: bool,
+    /// 升级建议规则
+    upgrade_rules: UpgradeRuleSet,
+    /// 当前检测结果对应的升级建议
+    upgrades: Vec<Suggestion>,
+    /// 功耗估算数据
+    energy_profile: EnergyProfile,
+    /// 当前功耗估算
+    power_estimate: Option<PowerEstimate>,
+    /// 综合评级规则
+    grade_rules: GradeRules,
+    /// 当前检测结果的综合评级
+    grade: Option<Grade>,
+    /// 验机检查项
+    inspection_rules: CheckRuleSet,
+    /// 当前检测结果对应的验机清单
+    inspection: Vec<CheckItem>,
+    /// 部署基线，程序目录下没有基线文件时为 None
+    baseline: Option<Result<Baseline, String>>,
+    /// 当前检测结果与部署基线的不符合项
+    baseline_mismatches: Vec<BaselineMismatch>,
+    /// 用户配置
+    config: AppConfig,
+    /// 点击刷新时重新检测的类别
+    refresh_categories: Vec<DetectionCategory>,
+    /// 历史记录存储
+    history: Option<HistoryStore>,
+    /// 已加载的历史快照
+    snapshots: Vec<Snapshot>,
+    /// 硬件快照存储
+    snapshot_store: Option<SnapshotStore>,
+    /// 上一次检测的硬件快照，用于与下一次检测对比
+    last_snapshot: Option<HardwareSnapshot>,
+    /// 本次检测与上一次检测相比的硬件变化
+    hardware_changes: Vec<HardwareChange>,
+    /// 参与对比的上一次检测时间（Unix 时间戳，秒），没有上一次快照时为 None
+    compared_timestamp: Option<i64>,
+    /// 当前标签页
+    current_tab: AppTab,
+    /// 尚未恢复的滚动位置，每个标签页在本次运行中首次显示时恢复一次
+    pending_scroll: BTreeMap<AppTab, f32>,
+    /// 内核驱动面板
+    driver_panel: DriverPanel,
+    /// 外设面板
+    peripheral_panel: PeripheralPanel,
+    /// WMI 查询控制台
+    wmi_console: WmiConsole,
+    /// 字段搜索面板
+    search_palette: SearchPalette,
+    /// 设备详情窗口
+    detail_windows: DetailWindows,
+    /// 实际加载的中文字体，没有可用字体时为 None
+    font_name: Option<String>,
+    /// 本帧硬件概览（标题与硬件信息表格）所在区域，用于截图裁剪
+    summary_rect: Option<egui::Rect>,
+    /// 是否在等待保存截图所需的窗口截图
+    screenshot_pending: bool,
+    /// 实时面板
+    live_monitor: LiveMonitor,
+    /// 性能测试面板
+    benchmark_panel: BenchmarkPanel,
+    /// 资产面板
+    asset_panel: AssetPanel,
+    /// 硬件变化监听器，关闭自动刷新时为 None
+    hotplug_watcher: Option<HotplugWatcher>,
+    /// 硬件变化事件接收端
+    hotplug_rx: Option<mpsc::Receiver<Vec<DetectionCategory>>>,
+    /// 等待重新检测的类别（检测进行中或防抖期间到达的硬件变化）
+    pending_hotplug: Vec<DetectionCategory>,
+    /// 主窗口句柄，托盘模式下用于显示、隐藏窗口
+    main_window: Option<isize>,
+    /// 系统托盘图标，关闭托盘模式时为 None
+    tray: Option<SystemTray>,
+    /// 托盘菜单命令接收端
+    tray_rx: Option<mpsc::Receiver<TrayCommand>>,
+    /// 本地 HTTP 接口，未以 `--serve` 启动时为 None
+    local_server: Option<LocalServer>,
+    /// 与本地 HTTP 接口共享的隐私模式开关
+    server_privacy: Arc<AtomicBool>,
+    /// 与本地 HTTP 接口共享的本机资产信息，资产面板保存后更新
+    server_asset: Arc<Mutex<Option<AssetRecord>>>,
+    /// 是否正在退出（托盘模式下关闭窗口不退出，只有托盘菜单“退出”才真正关闭）
+    quitting: bool,
+    /// 是否以管理员身份运行
+    is_elevated: bool,
+    /// 是否显示序列号等敏感信息（不保存，隐私模式下无效）
+    show_sensitive: bool,
+    /// 设备数据库更新结果接收器，更新进行中时为 Some
+    #[cfg(feature = "ids-updater")]
+    ids_update_rx: Option<mpsc::Receiver<Result<IdsUpdate, String>>>,
+    /// 上一次设备数据库更新的结果提示
+    #[cfg(feature = "ids-updater")]
+    ids_update_message: Option<String>,
+    /// 已下载的在线驱动规则，未配置或尚未下载完成时为 None
+    online_driver_rules: Option<DriverRuleSet>,
+    /// 在线驱动规则下载结果接收器，下载进行中时为 Some
+    #[cfg(feature = "driver-update-check")]
+    driver_rules_rx: Option<mpsc::Receiver<Result<DriverRuleSet, String>>>,
 }
 
 impl HardwareMasterApp {
     /// 创建新的应用程序实例
-    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        // 设置中文字体（使用跨平台字体加载）
-        crate::ui::setup_chinese_fonts(&cc.egui_ctx);
+    ///
+    /// * `config` - 启动时加载的用户配置
+    /// * `handover` - 以管理员身份重新启动时由上一个进程交接的状态
+    pub fn new(
+        cc: &eframe::CreationContext<'_>,
+        config: AppConfig,
+        handover: Option<HandoverState>,
+    ) -> Self {
+        // 按配置加载中文字体，系统字体都不可用且启用了 embedded-font 特性时使用内置字体
+        let font_name = font::setup_fonts(&cc.egui_ctx, &config.font);
 
-        // 应用默认主题
-        let theme = crate::ui::theme::AppTheme::default();
-        theme.apply(&cc.egui_ctx);
+        // 应用配置中保存的主题与外观
+        let theme = config.theme;
+        config.theme_style().apply(&cc.egui_ctx);
 
         let (tx, rx) = mpsc::channel();
         let (progress_tx, progress_rx) = mpsc::channel();
 
-        let detector = Arc::new(Mutex::new(HardwareDetector::new()));
+        crate::utils::wmi::set_default_timeout(Duration::from_secs(config.wmi_timeout_secs));
+
+        let mut hardware_detector = HardwareDetector::new();
+        hardware_detector.set_enabled_categories(&config.enabled_detectors());
+        hardware_detector.set_concurrency(config.detection_threads, config.detection_priority);
+        let cached_at = Self::restore_cache(&mut hardware_detector);
+        let detector = Arc::new(Mutex::new(hardware_detector));
 
         // 启动时自动检测
-        Self::start_auto_detection_thread(Arc::clone(&detector), tx, progress_tx);
+        Self::start_auto_detection_thread(
+            cc.egui_ctx.clone(),
+            Arc::clone(&detector),
+            config.enabled_detectors(),
+            tx,
+            progress_tx,
+        );
+
+        // 在后台提前加载设备 ID 数据库（优先使用程序目录或配置目录下的 pci.ids/usb.ids）
+        std::thread::spawn(|| {
+            let db = iddb::db();
+            log::info!(
+                "设备数据库来源: PCI {}, USB {}",
+                db.pci_source(),
+                db.usb_source()
+            );
+        });
+
+        let history = HistoryStore::open_default();
+        let snapshots = history
+            .as_ref()
+            .map(|store| {
+                store.load().unwrap_or_else(|e| {
+                    log::warn!("{}", e);
+                    Vec::new()
+                })
+            })
+            .unwrap_or_default();
+
+        let snapshot_store = SnapshotStore::open_default();
+        let last_snapshot = snapshot_store.as_ref().and_then(|store| {
+            store.load().unwrap_or_else(|e| {
+                log::warn!("{}", e);
+                None
+            })
+        });
 
-        Self {
+        // 恢复上次查看的标签页，高级模式关闭后不再恢复高级标签页
+        let current_tab = if AppTab::visible(config.advanced_mode).contains(&config.last_tab) {
+            config.last_tab
+        } else {
+            AppTab::default()
+        };
+
+        let mut app = Self {
             detector,
             is_detecting: true,
             detection_progress: 0.0,
             detection_message: "正在初始化硬件检测...".to_string(),
             has_detected: false,
+            cached_at,
+            egui_ctx: cc.egui_ctx.clone(),
             detection_rx: Some(rx),
             progress_rx: Some(progress_rx),
             last_refresh_time: None,
+            last_dynamic_refresh: Instant::now(),
+            dynamic_rx: None,
             theme,
+            advice_rules: AdviceRuleSet::load(),
+            advice: Vec::new(),
+            show_advice: config.show_advice,
+            upgrade_rules: UpgradeRuleSet::load(),
+            upgrades: Vec::new(),
+            energy_profile: EnergyProfile::load(),
+            power_estimate: None,
+            grade_rules: GradeRules::load(),
+            grade: None,
+            inspection_rules: CheckRuleSet::load(),
+            inspection: Vec::new(),
+            baseline: Baseline::load(),
+            baseline_mismatches: Vec::new(),
+            refresh_categories: config.enabled_detectors(),
+            pending_scroll: config.scroll_offsets.clone(),
+            server_privacy: Arc::new(AtomicBool::new(config.privacy_mode)),
+            server_asset: Arc::new(Mutex::new(
+                config.asset(&Self::local_asset_key(&config)).cloned(),
+            )),
+            config,
+            history,
+            snapshots,
+            snapshot_store,
+            last_snapshot,
+            hardware_changes: Vec::new(),
+            compared_timestamp: None,
+            current_tab,
+            driver_panel: DriverPanel::default(),
+            peripheral_panel: PeripheralPanel::default(),
+            wmi_console: WmiConsole::default(),
+            search_palette: SearchPalette::default(),
+            detail_windows: DetailWindows::default(),
+            font_name,
+            summary_rect: None,
+            screenshot_pending: false,
+            live_monitor: LiveMonitor::default(),
+            benchmark_panel: BenchmarkPanel::new(),
+            asset_panel: AssetPanel::default(),
+            hotplug_watcher: None,
+            hotplug_rx: None,
+            pending_hotplug: Vec::new(),
+            main_window: Self::main_window_handle(cc),
+            tray: None,
+            tray_rx: None,
+            local_server: None,
+            quitting: false,
+            is_elevated: elevation::is_elevated(),
+            show_sensitive: false,
+            #[cfg(feature = "ids-updater")]
+            ids_update_rx: None,
+            #[cfg(feature = "ids-updater")]
+            ids_update_message: None,
+            online_driver_rules: None,
+            #[cfg(feature = "driver-update-check")]
+            driver_rules_rx: None,
+        };
+
+        if app.config.hotplug_refresh {
+            app.start_hotplug_watcher();
+        }
+        if app.config.tray_enabled {
+            app.start_tray();
+        }
+
+        if let Some(state) = handover {
+            app.current_tab = state.current_tab;
+            app.refresh_categories = state.refresh_categories;
+            app.show_advice = state.show_advice;
         }
+
+        #[cfg(feature = "driver-update-check")]
+        app.start_driver_rules_download();
+
+        if app.cached_at.is_some() {
+            app.update_advice();
+            app.update_upgrades();
+            app.update_power_estimate();
+            app.update_grade();
+            app.update_inspection();
+            app.update_baseline();
+        }
+
+        app
+    }
+
+    /// 将未过期的检测结果缓存写入检测器，返回缓存的检测时间
+    #[cfg(feature = "serialize")]
+    fn restore_cache(detector: &mut HardwareDetector) -> Option<i64> {
+        let cached =
+            DetectionCache::open_default()?.load_fresh(chrono::Local::now().timestamp())?;
+        let timestamp = cached.timestamp;
+        cached.apply_to(detector);
+        Some(timestamp)
+    }
+
+    #[cfg(not(feature = "serialize"))]
+    fn restore_cache(_detector: &mut HardwareDetector) -> Option<i64> {
+        None
     }
 
+    /// 将最新检测结果保存为缓存，供下次启动时立即显示
+    #[cfg(feature = "serialize")]
+    fn save_cache(&self) {
+        let Some(cache) = DetectionCache::open_default() else {
+            return;
+        };
+        let cached = {
+            let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+            CachedDetection::from_detector(&detector, chrono::Local::now().timestamp())
+        };
+        if let Err(e) = cache.save(&cached) {
+            log::warn!("{}", e);
+        }
+    }
+
+    #[cfg(not(feature = "serialize"))]
+    fn save_cache(&self) {}
+
     /// 启动自动检测线程
+    ///
+    /// 每次发送进度或完成消息后立即唤醒界面，界面空闲时无需持续重绘轮询。
+    /// 检测在复制出的检测器上进行，完成后再写回，检测期间界面仍可读取旧结果
+    ///
+    /// * `ctx` - 界面上下文
+    /// * `categories` - 本次检测的类别
     fn start_auto_detection_thread(
+        ctx: egui::Context,
         detector: Arc<Mutex<HardwareDetector>>,
+        categories: Vec<DetectionCategory>,
         tx: mpsc::Sender<()>,
-        progress_tx: mpsc::Sender<ProgressMessage>,
+        progress_tx: mpsc::Sender<DetectionEvent>,
     ) {
         std::thread::spawn(move || {
+            let mut worker = detector.lock().expect("硬件检测器互斥锁被污染").fork();
+
             // 设置进度回调
-            {
-                let mut det = detector.lock().expect("硬件检测器互斥锁被污染");
-                let tx_clone = progress_tx.clone();
-                det.set_progress_callback(Box::new(move |progress, message| {
-                    let _ = tx_clone.send((progress, message.to_string()));
-                }));
-            }
+            let ctx_clone = ctx.clone();
+            worker.set_progress_callback(Box::new(move |event| {
+                let _ = progress_tx.send(event.clone());
+                ctx_clone.request_repaint();
+            }));
 
             // 执行检测
-            let mut det = detector.lock().expect("硬件检测器互斥锁被污染");
-            if let Err(e) = det.detect_all() {
+            if let Err(e) = worker.detect(&categories) {
                 log::warn!("{}", e);
             }
+            detector
+                .lock()
+                .expect("硬件检测器互斥锁被污染")
+                .adopt_results(worker);
 
             // 通知检测完成
             let _ = tx.send(());
+            ctx.request_repaint();
         });
     }
 
-    /// 开始刷新检测
+    /// 开始刷新检测，仅重新检测勾选的类别
     fn start_refresh(&mut self) {
+        self.start_detection(self.refresh_categories.clone());
+    }
+
+    /// 开始检测指定类别
+    ///
+    /// * `categories` - 本次检测的类别
+    fn start_detection(&mut self, categories: Vec<DetectionCategory>) {
+        if categories.is_empty() {
+            return;
+        }
+
         // 防抖：如果距离上次刷新不足指定时间，则忽略
         if let Some(last_time) = self.last_refresh_time {
             if last_time.elapsed() < Duration::from_secs(DEBOUNCE_DURATION_SECS) {
@@ -107,200 +471,1878 @@ impl HardwareMasterApp {
         let (progress_tx, progress_rx) = mpsc::channel();
         self.detection_rx = Some(rx);
         self.progress_rx = Some(progress_rx);
-        Self::start_auto_detection_thread(Arc::clone(&self.detector), tx, progress_tx);
+        Self::start_auto_detection_thread(
+            self.egui_ctx.clone(),
+            Arc::clone(&self.detector),
+            categories,
+            tx,
+            progress_tx,
+        );
+    }
+
+    /// 开始监听硬件变化，事件到达时唤醒界面
+    fn start_hotplug_watcher(&mut self) {
+        if self.hotplug_watcher.is_some() {
+            return;
+        }
+        let (tx, rx) = mpsc::channel();
+        let ctx = self.egui_ctx.clone();
+        match HotplugWatcher::start(move |categories| {
+            let _ = tx.send(categories);
+            ctx.request_repaint();
+        }) {
+            Ok(watcher) => {
+                self.hotplug_watcher = Some(watcher);
+                self.hotplug_rx = Some(rx);
+            }
+            Err(e) => log::warn!("{}", e),
+        }
+    }
+
+    /// 停止监听硬件变化
+    fn stop_hotplug_watcher(&mut self) {
+        self.hotplug_watcher = None;
+        self.hotplug_rx = None;
+        self.pending_hotplug.clear();
     }
 
-    /// 获取硬件信息文本格式
+    /// 收集硬件变化事件，空闲时重新检测受影响的已启用类别
+    fn handle_hotplug(&mut self, ctx: &egui::Context) {
+        if let Some(rx) = &self.hotplug_rx {
+            for categories in rx.try_iter() {
+                for category in categories {
+                    if !self.config.disabled_detectors.contains(&category)
+                        && !self.pending_hotplug.contains(&category)
+                    {
+                        self.pending_hotplug.push(category);
+                    }
+                }
+            }
+        }
+        if self.pending_hotplug.is_empty() || self.is_detecting {
+            return;
+        }
+
+        // 防抖期间暂缓，到期后再唤醒界面重试
+        let debounce = Duration::from_secs(DEBOUNCE_DURATION_SECS);
+        match self.last_refresh_time.map(|time| time.elapsed()) {
+            Some(elapsed) if elapsed < debounce => ctx.request_repaint_after(debounce - elapsed),
+            _ => {
+                let categories = std::mem::take(&mut self.pending_hotplug);
+                self.start_detection(categories);
+            }
+        }
+    }
+
+    /// 获取主窗口句柄
+    fn main_window_handle(cc: &eframe::CreationContext<'_>) -> Option<isize> {
+        match cc.window_handle().ok()?.as_raw() {
+            RawWindowHandle::Win32(handle) => Some(handle.hwnd.get()),
+            _ => None,
+        }
+    }
+
+    /// 显示托盘图标
+    fn start_tray(&mut self) {
+        if self.tray.is_some() {
+            return;
+        }
+        let Some(main_window) = self.main_window else {
+            log::warn!("无法获取主窗口句柄，托盘模式不可用");
+            return;
+        };
+        let (tx, rx) = mpsc::channel();
+        match SystemTray::start(self.egui_ctx.clone(), main_window, tx) {
+            Ok(tray) => {
+                self.tray = Some(tray);
+                self.tray_rx = Some(rx);
+            }
+            Err(e) => log::warn!("{}", e),
+        }
+    }
+
+    /// 启动本地 HTTP 接口，与界面共享检测结果
+    pub fn start_server(&mut self, options: &ServeOptions) {
+        match LocalServer::start(
+            options.addr,
+            Arc::clone(&self.detector),
+            Arc::clone(&self.server_privacy),
+            Arc::clone(&self.server_asset),
+        ) {
+            Ok(server) => self.local_server = Some(server),
+            Err(e) => log::warn!("{}", e),
+        }
+    }
+
+    /// 移除托盘图标
+    fn stop_tray(&mut self) {
+        self.tray = None;
+        self.tray_rx = None;
+    }
+
+    /// 处理托盘菜单命令，托盘模式下关闭窗口时改为隐藏到托盘
+    fn handle_tray(&mut self, ctx: &egui::Context) {
+        let commands: Vec<TrayCommand> = self
+            .tray_rx
+            .as_ref()
+            .map(|rx| rx.try_iter().collect())
+            .unwrap_or_default();
+        for command in commands {
+            match command {
+                TrayCommand::Redetect => {
+                    if !self.is_detecting {
+                        self.start_detection(self.config.enabled_detectors());
+                    }
+                }
+                TrayCommand::Quit => {
+                    self.quitting = true;
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                }
+            }
+        }
+
+        if self.tray.is_some() && !self.quitting && ctx.input(|i| i.viewport().close_requested()) {
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            if let Some(main_window) = self.main_window {
+                tray::hide_main_window(main_window);
+            }
+        }
+    }
+
+    /// 按自动刷新间隔在后台重新读取动态数据，不重新执行完整检测
+    fn handle_auto_refresh(&mut self, ctx: &egui::Context) {
+        let received = self.dynamic_rx.as_ref().map(|rx| rx.try_recv());
+        match received {
+            Some(Ok(data)) => {
+                // 完整检测进行中时检测器被占用，丢弃本次结果，检测完成后数据同样是最新的
+                if !self.is_detecting {
+                    self.detector
+                        .lock()
+                        .expect("硬件检测器互斥锁被污染")
+                        .apply_dynamic(data);
+                }
+                self.dynamic_rx = None;
+                self.last_dynamic_refresh = Instant::now();
+            }
+            Some(Err(mpsc::TryRecvError::Empty)) => return,
+            Some(Err(mpsc::TryRecvError::Disconnected)) => self.dynamic_rx = None,
+            None => {}
+        }
+
+        if self.config.auto_refresh_secs == 0 || !self.has_detected || self.is_detecting {
+            return;
+        }
+        let interval = Duration::from_secs(self.config.auto_refresh_secs);
+        let elapsed = self.last_dynamic_refresh.elapsed();
+        if elapsed < interval {
+            ctx.request_repaint_after(interval - elapsed);
+            return;
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let categories = self.config.enabled_detectors();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let _ = tx.send(DynamicData::detect(&categories));
+            ctx.request_repaint();
+        });
+        self.dynamic_rx = Some(rx);
+    }
+
+    /// 当前界面样式
+    fn theme_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            theme: self.theme,
+            ..self.config.theme_style()
+        }
+    }
+
+    /// 渲染外观设置（强调色、间距、区块卡片与无障碍模式），修改后立即生效
+    fn render_appearance_settings(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        ui.menu_button("外观", |ui| {
+            ui.horizontal(|ui| {
+                ui.label("强调色:");
+                changed |= ui
+                    .color_edit_button_srgb(&mut self.config.accent_color)
+                    .changed();
+                if ui.button("恢复默认").clicked() {
+                    self.config.accent_color = DEFAULT_ACCENT_COLOR;
+                    changed = true;
+                }
+            });
+            ui.label("间距:");
+            for density in Density::ALL {
+                changed |= ui
+                    .radio_value(&mut self.config.density, density, density.name())
+                    .changed();
+            }
+            changed |= ui
+                .checkbox(&mut self.config.section_cards, "区块卡片背景")
+                .changed();
+            ui.separator();
+            changed |= ui
+                .checkbox(&mut self.config.accessibility_mode, "无障碍模式")
+                .on_hover_text("放大字体并提高对比度；Tab 键在各行之间移动，Ctrl+C 复制当前行")
+                .changed();
+        });
+
+        if changed {
+            self.theme_style().apply(ui.ctx());
+        }
+    }
+
+    /// 渲染字体设置，只列出系统中存在的中文字体，修改后立即重新加载
+    fn render_font_settings(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        let current = self.font_name.as_deref().unwrap_or("无可用中文字体");
+        ui.menu_button("字体", |ui| {
+            changed |= ui
+                .radio_value(&mut self.config.font, FontChoice::Auto, "自动")
+                .changed();
+            for candidate in font::available_fonts() {
+                let choice = FontChoice::System(candidate.name.to_string());
+                changed |= ui
+                    .radio_value(&mut self.config.font, choice, candidate.name)
+                    .changed();
+            }
+            ui.add_enabled_ui(font::embedded_font().is_some(), |ui| {
+                changed |= ui
+                    .radio_value(&mut self.config.font, FontChoice::Embedded, "内置字体")
+                    .on_disabled_hover_text("构建时未启用 embedded-font 特性")
+                    .changed();
+            });
+        })
+        .response
+        .on_hover_text(format!("当前字体: {}", current));
+
+        if changed {
+            self.font_name = font::setup_fonts(ui.ctx(), &self.config.font);
+        }
+    }
+
+    /// 渲染自动刷新间隔设置
+    fn render_auto_refresh_settings(&mut self, ui: &mut egui::Ui) {
+        ui.menu_button("自动刷新", |ui| {
+            for secs in AUTO_REFRESH_CHOICES {
+                let label = if secs == 0 {
+                    "关闭".to_string()
+                } else {
+                    format!("每 {} 秒", secs)
+                };
+                ui.radio_value(&mut self.config.auto_refresh_secs, secs, label);
+            }
+        })
+        .response
+        .on_hover_text("定时更新电池状态、分区可用空间与硬盘温度，不重新执行完整检测");
+    }
+
+    /// 渲染检测并发度设置，修改后从下一次检测开始生效
+    fn render_concurrency_settings(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        ui.menu_button("检测性能", |ui| {
+            changed |= ui
+                .add(
+                    egui::Slider::new(
+                        &mut self.config.detection_threads,
+                        1..=MAX_DETECTION_THREADS,
+                    )
+                    .text("线程数"),
+                )
+                .on_hover_text("低配电脑可减少线程数，工作站可增加线程数以加快检测")
+                .changed();
+            ui.label("线程优先级:");
+            for priority in [DetectionPriority::Normal, DetectionPriority::Background] {
+                changed |= ui
+                    .radio_value(
+                        &mut self.config.detection_priority,
+                        priority,
+                        priority.name(),
+                    )
+                    .changed();
+            }
+        });
+
+        if changed {
+            self.detector
+                .lock()
+                .expect("硬件检测器互斥锁被污染")
+                .set_concurrency(
+                    self.config.detection_threads,
+                    self.config.detection_priority,
+                );
+        }
+    }
+
+    /// 渲染设置菜单
+    fn render_settings_menu(&mut self, ui: &mut egui::Ui) {
+        ui.menu_button("设置", |ui| {
+            let mut advanced_mode = self.config.advanced_mode;
+            if ui
+                .checkbox(&mut advanced_mode, "高级模式 (Ctrl+Shift+A)")
+                .changed()
+            {
+                self.toggle_advanced_mode();
+            }
+            ui.checkbox(&mut self.config.localize_vendors, "厂商显示中文名");
+            if ui
+                .checkbox(&mut self.config.hotplug_refresh, "硬件插拔时自动刷新")
+                .on_hover_text("插拔 U 盘、显示器、电池等设备后自动重新检测对应类别")
+                .changed()
+            {
+                if self.config.hotplug_refresh {
+                    self.start_hotplug_watcher();
+                } else {
+                    self.stop_hotplug_watcher();
+                }
+            }
+            if ui
+                .checkbox(&mut self.config.tray_enabled, "托盘模式")
+                .on_hover_text("关闭窗口时隐藏到系统托盘，托盘提示显示 CPU 占用与温度")
+                .changed()
+            {
+                if self.config.tray_enabled {
+                    self.start_tray();
+                } else {
+                    self.stop_tray();
+                }
+            }
+            if ui
+                .checkbox(&mut self.config.privacy_mode, "隐私模式")
+                .on_hover_text("始终遮盖序列号、UUID 等敏感信息，导出与本地接口同样遮盖")
+                .changed()
+            {
+                self.server_privacy
+                    .store(self.config.privacy_mode, Ordering::Relaxed);
+                self.update_advice();
+                self.update_inspection();
+            }
+            ui.menu_button("虚拟显示设备", |ui| {
+                for mode in [VirtualDeviceMode::Group, VirtualDeviceMode::Hide] {
+                    ui.radio_value(&mut self.config.virtual_device_mode, mode, mode.name());
+                }
+            })
+            .response
+            .on_hover_text("虚拟机显卡及 Parsec、spacedesk 等虚拟显示器驱动");
+            ui.menu_button("界面语言", |ui| {
+                for language in Language::ALL {
+                    ui.radio_value(&mut self.config.language, language, language.name());
+                }
+            })
+            .response
+            .on_hover_text("目前仅支持简体中文");
+            self.render_appearance_settings(ui);
+            self.render_font_settings(ui);
+            self.render_auto_refresh_settings(ui);
+            self.render_concurrency_settings(ui);
+            render_iddb_source(ui);
+            #[cfg(feature = "ids-updater")]
+            self.render_ids_updater(ui);
+            ui.separator();
+            let button = ui
+                .add_enabled(!self.is_elevated, egui::Button::new("以管理员身份重新检测"))
+                .on_hover_text("硬盘磨损、温度等信息需要管理员权限才能读取")
+                .on_disabled_hover_text("当前已是管理员身份");
+            if button.clicked() {
+                ui.close_menu();
+                self.relaunch_as_admin(ui.ctx());
+            }
+        });
+    }
+
+    /// 渲染设备数据库更新按钮与上次更新时间
+    #[cfg(feature = "ids-updater")]
+    fn render_ids_updater(&mut self, ui: &mut egui::Ui) {
+        let updating = self.ids_update_rx.is_some();
+        let text = if updating {
+            "正在更新设备数据库..."
+        } else {
+            "更新设备数据库"
+        };
+        let button = ui
+            .add_enabled(!updating, egui::Button::new(text))
+            .on_hover_text("从 pci-ids.ucw.cz 与 linux-usb.org 下载最新的 pci.ids/usb.ids");
+        if button.clicked() {
+            self.start_ids_update();
+        }
+        let updated = self
+            .config
+            .ids_updated_at
+            .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+            .map(|time| {
+                time.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_else(|| "从未更新".to_string());
+        ui.label(format!("上次更新: {}", updated));
+        if let Some(message) = &self.ids_update_message {
+            ui.label(message);
+        }
+    }
+
+    /// 在后台线程下载并更新设备数据库
+    #[cfg(feature = "ids-updater")]
+    fn start_ids_update(&mut self) {
+        let (tx, rx) = mpsc::channel();
+        let ctx = self.egui_ctx.clone();
+        std::thread::spawn(move || {
+            let _ = tx.send(updater::update_ids());
+            ctx.request_repaint();
+        });
+        self.ids_update_rx = Some(rx);
+        self.ids_update_message = None;
+    }
+
+    /// 接收设备数据库更新结果，成功后重新检测以使用新的设备名称
+    #[cfg(feature = "ids-updater")]
+    fn handle_ids_update(&mut self) {
+        let Some(result) = self
+            .ids_update_rx
+            .as_ref()
+            .and_then(|rx| rx.try_recv().ok())
+        else {
+            return;
+        };
+        self.ids_update_rx = None;
+        match result {
+            Ok(update) => {
+                self.config.ids_updated_at = Some(update.updated_at);
+                self.ids_update_message = Some(format!(
+                    "更新完成：PCI 厂商 {} 个，USB 厂商 {} 个",
+                    update.pci_vendors, update.usb_vendors
+                ));
+                if !self.is_detecting {
+                    self.start_detection(self.config.enabled_detectors());
+                }
+            }
+            Err(e) => {
+                log::warn!("{}", e);
+                self.ids_update_message = Some(format!("更新失败：{}", e));
+            }
+        }
+    }
+
+    /// 配置了在线驱动规则地址时，在后台线程下载规则
+    #[cfg(feature = "driver-update-check")]
+    fn start_driver_rules_download(&mut self) {
+        let url = self.config.driver_rules_url.trim().to_string();
+        if url.is_empty() {
+            return;
+        }
+        let (tx, rx) = mpsc::channel();
+        let ctx = self.egui_ctx.clone();
+        std::thread::spawn(move || {
+            let _ = tx.send(driver_advisor::UrlProvider { url }.rules());
+            ctx.request_repaint();
+        });
+        self.driver_rules_rx = Some(rx);
+    }
+
+    /// 接收在线驱动规则，下载成功后重新评估建议
+    #[cfg(feature = "driver-update-check")]
+    fn handle_driver_rules(&mut self) {
+        let Some(result) = self
+            .driver_rules_rx
+            .as_ref()
+            .and_then(|rx| rx.try_recv().ok())
+        else {
+            return;
+        };
+        self.driver_rules_rx = None;
+        match result {
+            Ok(rules) => {
+                self.online_driver_rules = Some(rules);
+                if self.has_detected || self.cached_at.is_some() {
+                    self.update_advice();
+                }
+            }
+            Err(e) => log::warn!("{}", e),
+        }
+    }
+
+    /// 以管理员身份重新启动程序，成功后关闭当前窗口
+    fn relaunch_as_admin(&mut self, ctx: &egui::Context) {
+        self.sync_config();
+        let state = HandoverState {
+            config: self.config.clone(),
+            current_tab: self.current_tab,
+            refresh_categories: self.refresh_categories.clone(),
+            show_advice: self.show_advice,
+        };
+
+        let path = match state.write_temp() {
+            Ok(path) => path,
+            Err(e) => {
+                log::warn!("{}", e);
+                return;
+            }
+        };
+
+        match elevation::relaunch_elevated(&HandoverState::to_args(&path)) {
+            Ok(()) => {
+                self.quitting = true;
+                ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+            }
+            Err(e) => {
+                log::warn!("{}", e);
+                let _ = std::fs::remove_file(&path);
+            }
+        }
+    }
+
+    /// 渲染刷新范围选择菜单
+    fn render_refresh_menu(&mut self, ui: &mut egui::Ui) {
+        ui.menu_button("刷新范围", |ui| {
+            if ui.button("全选").clicked() {
+                self.refresh_categories = DetectionCategory::ALL.to_vec();
+            }
+            ui.separator();
+            for category in DetectionCategory::ALL {
+                let mut checked = self.refresh_categories.contains(&category);
+                if ui.checkbox(&mut checked, category.name()).changed() {
+                    if checked {
+                        self.refresh_categories.push(category);
+                    } else {
+                        self.refresh_categories.retain(|c| *c != category);
+                    }
+                }
+            }
+        });
+    }
+
+    /// 按所选模板生成复制到剪贴板的文本
     fn get_hardware_info_text(&self) -> String {
         let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
-        let mut text = String::from("以下硬件信息来源于硬大师，仅供参考：\n");
+        self.config.copy_template.render(&detector)
+    }
+
+    /// 导出报告
+    ///
+    /// * `filter_name` - 文件类型名称，如 "HTML 文件"
+    /// * `extension` - 文件扩展名，如 "html"
+    /// * `generate` - 报告生成函数，报告附带本机的资产信息
+    fn export_report(
+        &self,
+        filter_name: &str,
+        extension: &str,
+        generate: fn(&HardwareDetector, Option<&AssetRecord>) -> String,
+    ) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("导出报告")
+            .set_file_name(format!("硬件检测报告.{}", extension))
+            .add_filter(filter_name, &[extension])
+            .save_file()
+        else {
+            return;
+        };
+
+        let report = {
+            let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+            generate(&detector, self.config.asset(&self.asset_key()))
+        };
+
+        match std::fs::write(&path, report) {
+            Ok(()) => log::info!("报告已导出: {}", path.display()),
+            Err(e) => log::warn!("导出报告失败: {}", e),
+        }
+    }
+
+    /// 将表格导出为 CSV 文件
+    fn export_csv(table: &CsvTable) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("导出 CSV")
+            .set_file_name(table.file_name())
+            .add_filter("CSV 文件", &["csv"])
+            .save_file()
+        else {
+            return;
+        };
 
-        // 操作系统
-        text.push_str(&format!("操作系统: {}\n", detector.system_info.os_name));
+        match std::fs::write(&path, table.to_csv()) {
+            Ok(()) => log::info!("CSV 已导出: {}", path.display()),
+            Err(e) => log::warn!("导出 CSV 失败: {}", e),
+        }
+    }
 
-        // 处理器
-        text.push_str(&format!("处理器: {}\n", detector.cpu_info.name));
+    /// 根据最新检测结果重新评估购买建议，并检查驱动是否过旧
+    fn update_advice(&mut self) {
+        let (mut facts, driver_advice) = {
+            let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+            (
+                crate::analysis::collect_facts(&detector),
+                driver_advisor::check(&detector, self.online_driver_rules.as_ref()),
+            )
+        };
+        if self.config.privacy_mode {
+            facts.mask_sensitive();
+        }
+        self.advice = self.advice_rules.evaluate(&facts);
+        self.advice.extend(driver_advice);
+    }
+
+    /// 根据最新检测结果重新生成升级建议
+    fn update_upgrades(&mut self) {
+        let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+        self.upgrades = self
+            .upgrade_rules
+            .suggest(&detector, chrono::Local::now().date_naive());
+    }
+
+    /// 根据最新检测结果与上一次快照生成验机清单
+    fn update_inspection(&mut self) {
+        let (mut facts, current) = {
+            let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+            (
+                crate::analysis::collect_facts(&detector),
+                Snapshot::from_detector(&detector, chrono::Local::now().timestamp()),
+            )
+        };
+        inspection::add_serial_facts(&mut facts, &current, self.snapshots.last());
+        if self.config.privacy_mode {
+            facts.mask_sensitive();
+        }
+        self.inspection = self.inspection_rules.evaluate(&facts);
+    }
+
+    /// 将最新检测结果与部署基线比较
+    fn update_baseline(&mut self) {
+        let Some(Ok(baseline)) = &self.baseline else {
+            return;
+        };
+        let facts = {
+            let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+            crate::analysis::collect_facts(&detector)
+        };
+        self.baseline_mismatches = baseline.check(&facts);
+        for mismatch in self.baseline_mismatches.iter() {
+            log::warn!("不符合部署基线: {}", mismatch.description());
+        }
+    }
+
+    /// 渲染部署基线核验结果，没有基线文件时不显示
+    fn render_baseline(&self, ui: &mut egui::Ui) {
+        let Some(baseline) = &self.baseline else {
+            return;
+        };
+        let error_color = ui.visuals().error_fg_color;
+        match baseline {
+            Err(e) => {
+                ui.colored_label(error_color, format!("⚠ 部署基线文件无效: {}", e));
+            }
+            Ok(_) if self.baseline_mismatches.is_empty() => {
+                ui.colored_label(egui::Color32::from_rgb(0, 160, 0), "✔ 符合部署基线");
+            }
+            Ok(_) => {
+                egui::Frame::group(ui.style())
+                    .stroke(egui::Stroke::new(1.5, error_color))
+                    .show(ui, |ui| {
+                        ui.set_width(ui.available_width());
+                        ui.colored_label(
+                            error_color,
+                            egui::RichText::new(format!(
+                                "⚠ 与部署基线不符（{} 项）",
+                                self.baseline_mismatches.len()
+                            ))
+                            .strong(),
+                        );
+                        for mismatch in self.baseline_mismatches.iter() {
+                            ui.label(mismatch.description());
+                        }
+                    });
+            }
+        }
+        ui.add_space(6.0);
+    }
 
-        // 显卡
-        for gpu in detector.gpu_info.gpus.iter() {
-            if gpu.gpu_type != GpuType::DiscreteGpu && gpu.gpu_type != GpuType::IntegratedGpu {
-                continue;
+    /// 渲染验机清单
+    fn render_inspection(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("开始验机").clicked() {
+                self.start_detection(DetectionCategory::ALL.to_vec());
             }
-            text.push_str(&format!(
-                "{}: {} ({}, {})\n",
-                gpu.gpu_type.to_string(),
-                gpu.description,
-                format_size(gpu.vram_size),
-                gpu.manufacturer
+            let warnings = self
+                .inspection
+                .iter()
+                .filter(|item| item.status == CheckStatus::Warn)
+                .count();
+            ui.label(format!(
+                "共 {} 项，{} 项需要注意",
+                self.inspection.len(),
+                warnings
             ));
+        });
+        ui.label("验机会重新检测全部硬件，并与上一次检测记录比较序列号");
+        ui.add_space(6.0);
+
+        let scroll_area = self.restore_scroll(egui::ScrollArea::vertical());
+        let output = scroll_area.show(ui, |ui| {
+            egui::Grid::new("inspection_grid")
+                .num_columns(3)
+                .spacing([20.0, 8.0])
+                .show(ui, |ui| {
+                    for item in self.inspection.iter() {
+                        match item.status {
+                            CheckStatus::Pass => ui.colored_label(
+                                egui::Color32::from_rgb(0, 160, 0),
+                                item.status.icon(),
+                            ),
+                            CheckStatus::Warn => {
+                                ui.colored_label(ui.visuals().warn_fg_color, item.status.icon())
+                            }
+                            CheckStatus::Unknown => ui.weak(item.status.icon()),
+                        }
+                        .on_hover_text(item.status.to_string());
+                        ui.label(&item.name);
+                        let label = ui.label(&item.detail);
+                        if let Some(advice) = &item.advice {
+                            label.on_hover_text(advice);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+        self.remember_scroll(output.state.offset);
+    }
+
+    /// 将最新检测结果追加到历史记录
+    fn record_snapshot(&mut self) {
+        let Some(store) = &self.history else {
+            return;
+        };
+        let snapshot = {
+            let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+            Snapshot::from_detector(&detector, chrono::Local::now().timestamp())
+        };
+        match store.append(&snapshot) {
+            Ok(()) => self.snapshots.push(snapshot),
+            Err(e) => log::warn!("{}", e),
         }
+    }
 
-        // 内存
-        text.push_str(&format!("内存: {}\n", detector.memory_info.name));
+    /// 与上一次检测快照比较，并保存本次快照供下次对比
+    fn update_hardware_changes(&mut self) {
+        let current = {
+            let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+            HardwareSnapshot::capture(&detector, chrono::Local::now().timestamp())
+        };
+        if let Some(previous) = self.last_snapshot.take() {
+            self.hardware_changes = snapshot::diff(&previous, &current);
+            self.compared_timestamp = Some(previous.timestamp);
+            for change in self.hardware_changes.iter() {
+                log::info!("硬件变化: {}", change.masked().description());
+            }
+        }
+        if let Some(store) = &self.snapshot_store {
+            if let Err(e) = store.save(&current) {
+                log::warn!("{}", e);
+            }
+        }
+        self.last_snapshot = Some(current);
+    }
 
-        // 主板
-        text.push_str(&format!(
-            "主板: {} {} ({}, {})\n",
-            detector.motherboard_info.manufacturer,
-            detector.motherboard_info.product_name,
-            detector.motherboard_info.chipset,
-            detector.motherboard_info.bios_vendor
-        ));
+    /// 本机资产信息的键，不随检测结果变化
+    fn asset_key(&self) -> String {
+        Self::local_asset_key(&self.config)
+    }
+
+    /// 按配置计算本机资产信息的键
+    fn local_asset_key(config: &AppConfig) -> String {
+        let hostname = std::env::var("COMPUTERNAME").unwrap_or_default();
+        asset::asset_key(&config.agent_machine_id, &hostname)
+    }
+
+    /// 渲染资产面板，保存后立即写入配置文件
+    fn render_asset(&mut self, ui: &mut egui::Ui) {
+        let asset_key = self.asset_key();
+        let today = chrono::Local::now().date_naive();
+        if self
+            .asset_panel
+            .show(ui, &asset_key, &mut self.config, today)
+        {
+            if let Ok(mut asset) = self.server_asset.lock() {
+                *asset = self.config.asset(&asset_key).cloned();
+            }
+            self.sync_config();
+            if let Err(e) = self.config.save() {
+                log::warn!("{}", e);
+            }
+        }
+    }
+
+    /// 导出附带本机资产信息的 JSON 资产记录，格式与远程上报内容相同
+    #[cfg(feature = "serialize")]
+    fn export_asset_json(&self) {
+        let json = {
+            let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+            let hostname = std::env::var("COMPUTERNAME").unwrap_or_default();
+            let machine_id = agent::machine_id(&self.config.agent_machine_id, &detector, &hostname);
+            let asset = self.config.asset(&self.asset_key()).cloned();
+            let mut report = agent::AgentReport::new(
+                machine_id,
+                hostname,
+                &detector,
+                chrono::Local::now().timestamp(),
+            )
+            .with_asset(asset);
+            if self.config.privacy_mode {
+                report.detection.mask_sensitive();
+            }
+            report.to_json_pretty()
+        };
+        let json = match json {
+            Ok(json) => json,
+            Err(e) => {
+                log::warn!("{}", e);
+                return;
+            }
+        };
+
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("导出资产记录")
+            .set_file_name("资产记录.json")
+            .add_filter("JSON 文件", &["json"])
+            .save_file()
+        else {
+            return;
+        };
+        match std::fs::write(&path, json) {
+            Ok(()) => log::info!("资产记录已导出: {}", path.display()),
+            Err(e) => log::warn!("导出资产记录失败: {}", e),
+        }
+    }
+
+    /// 渲染检测耗时面板，停用的类别在下次检测时跳过
+    fn render_timings(&mut self, ui: &mut egui::Ui) {
+        let mut detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+        let timings = detector.timings().to_vec();
+        if timings::show(ui, &timings, &mut self.config.disabled_detectors) {
+            detector.set_enabled_categories(&self.config.enabled_detectors());
+        }
+    }
 
-        // 显示器
-        text.push_str(&format!("显示器: {}\n", detector.monitor_info.name));
-
-        // 硬盘
-        let memory_capacity = format_size(detector.disk_info.total_capacity);
-        text.push_str(&format!(
-            "主硬盘:({}) {} ({} / {})\n",
-            detector.disk_info.disk_type.to_string(),
-            detector.disk_info.model,
-            &memory_capacity,
-            detector.disk_info.disk_type.to_string()
+    /// 渲染与上次检测的对比结果
+    fn render_hardware_changes(&mut self, ui: &mut egui::Ui) {
+        ui.heading("与上次检测对比");
+        let Some(timestamp) = self.compared_timestamp else {
+            ui.label("暂无上次检测记录，下次检测完成后显示对比结果");
+            return;
+        };
+        let time = chrono::DateTime::from_timestamp(timestamp, 0)
+            .map(|time| {
+                time.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        ui.label(format!(
+            "上次检测于 {}，共 {} 项变化",
+            time,
+            self.hardware_changes.len()
         ));
+        ui.add_space(6.0);
 
-        // 网卡
-        for adapter in detector.network_info.adapters.iter() {
-            text.push_str(&format!("网卡: {}\n", adapter.to_string()));
+        if self.hardware_changes.is_empty() {
+            ui.colored_label(egui::Color32::from_rgb(0, 160, 0), "✔ 硬件配置没有变化");
+            return;
         }
 
-        // 电池
-        for battery in detector.battery_info.batteries.iter() {
-            text.push_str(&format!(
-                "电池: {} {} {} (健康度：{:.0}%)\n",
-                battery.vendor,
-                battery.name,
-                battery.chemistry.to_string(),
-                battery.health
-            ));
+        let scroll_area = self.restore_scroll(egui::ScrollArea::vertical());
+        let output = scroll_area.show(ui, |ui| {
+            egui::Grid::new("hardware_changes_grid")
+                .num_columns(2)
+                .spacing([20.0, 8.0])
+                .show(ui, |ui| {
+                    for change in self.hardware_changes.iter() {
+                        let change = if self.config.privacy_mode {
+                            change.masked()
+                        } else {
+                            change.clone()
+                        };
+                        match change {
+                            HardwareChange::Added { .. } => {
+                                ui.colored_label(egui::Color32::from_rgb(0, 160, 0), "＋")
+                            }
+                            HardwareChange::Removed { .. } => {
+                                ui.colored_label(ui.visuals().error_fg_color, "－")
+                            }
+                            HardwareChange::Changed { .. } => {
+                                ui.colored_label(ui.visuals().warn_fg_color, "～")
+                            }
+                        };
+                        ui.label(change.description());
+                        ui.end_row();
+                    }
+                });
+        });
+        self.remember_scroll(output.state.offset);
+    }
+
+    /// 渲染历史趋势图
+    fn render_trends(&mut self, ui: &mut egui::Ui) {
+        let scroll_area = self.restore_scroll(egui::ScrollArea::vertical());
+        let series = |value: fn(&Snapshot) -> Option<f64>| -> Vec<SeriesPoint> {
+            self.snapshots
+                .iter()
+                .filter_map(|snapshot| value(snapshot).map(|v| (snapshot.timestamp, v)))
+                .collect()
+        };
+
+        let output = scroll_area.show(ui, |ui| {
+            ui.label(format!("共 {} 条检测记录", self.snapshots.len()));
+            ui.add_space(6.0);
+            line_chart(
+                ui,
+                "电池满充容量",
+                "Wh",
+                &series(|s| s.battery_full_capacity.map(|c| c as f64 / 1000.0)),
+            );
+            self.render_battery_health_trend(ui);
+            line_chart(
+                ui,
+                "硬盘可用空间",
+                "GB",
+                &series(|s| s.disk_free_space.map(|b| b / BYTES_PER_GB)),
+            );
+            line_chart(ui, "硬盘磨损", "%", &series(|s| s.disk_wear_percent));
+            line_chart(ui, "硬盘温度", "°C", &series(|s| s.disk_temperature));
+        });
+        self.remember_scroll(output.state.offset);
+    }
+
+    /// 渲染电池健康度趋势与损耗提示
+    fn render_battery_health_trend(&mut self, ui: &mut egui::Ui) {
+        let points: Vec<SeriesPoint> = self
+            .snapshots
+            .iter()
+            .filter_map(|snapshot| snapshot.battery_health().map(|h| (snapshot.timestamp, h)))
+            .collect();
+        if points.is_empty() {
+            return;
         }
 
-        // 声卡
-        if let Some(device) = detector.audio_info.devices.first() {
-            text.push_str(&format!("声卡: {}\n", device.name));
-        } else {
-            text.push_str("声卡: 未检测到\n");
+        let warning = self.config.battery_degradation_warning;
+        line_chart_with_threshold(ui, "电池健康度", "%", &points, Some(100.0 - warning));
+        ui.horizontal(|ui| {
+            ui.label("损耗提示阈值:");
+            ui.add(
+                egui::DragValue::new(&mut self.config.battery_degradation_warning)
+                    .range(1.0..=99.0)
+                    .speed(0.5)
+                    .suffix(" %"),
+            )
+            .on_hover_text("电池损耗达到该比例时提示，可在更换电池前提早留意");
+        });
+        if let Some(degradation) = battery_degradation(&self.snapshots) {
+            if degradation >= warning {
+                ui.colored_label(
+                    ui.visuals().warn_fg_color,
+                    format!(
+                        "⚠ 电池已损耗 {:.1}%，超过提示阈值 {:.0}%，续航明显缩短时建议更换电池",
+                        degradation, warning
+                    ),
+                );
+            }
         }
+        ui.add_space(8.0);
+    }
+
+    /// 根据最新检测结果重新估算功耗
+    fn update_power_estimate(&mut self) {
+        let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+        self.power_estimate = Some(self.energy_profile.estimate(&detector));
+    }
+
+    /// 根据最新检测结果重新评级
+    fn update_grade(&mut self) {
+        let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+        self.grade = Some(self.grade_rules.grade(&detector));
+    }
 
-        text
+    /// 渲染综合评级横幅
+    fn render_grade(&self, ui: &mut egui::Ui) {
+        let Some(grade) = &self.grade else {
+            return;
+        };
+        let color = match grade.tier {
+            Tier::Flagship | Tier::HighEnd => ui.visuals().hyperlink_color,
+            Tier::Mainstream => ui.visuals().text_color(),
+            Tier::Entry => ui.visuals().warn_fg_color,
+        };
+        egui::Frame::group(ui.style())
+            .fill(ui.visuals().faint_bg_color)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(grade.summary())
+                            .size(18.0)
+                            .strong()
+                            .color(color),
+                    );
+                    ui.label(grade.breakdown());
+                });
+            })
+            .response
+            .on_hover_text("按处理器、显卡、内存与硬盘粗略评级，规则见 grade_rules.toml");
+        ui.add_space(8.0);
+    }
+
+    /// 渲染购买建议面板
+    fn render_advice(&self, ui: &mut egui::Ui) {
+        self.theme_style().section(ui, |ui| {
+            egui::CollapsingHeader::new(format!("购买建议 ({})", self.advice.len()))
+                .default_open(true)
+                .show(ui, |ui| {
+                    if self.advice.is_empty() {
+                        ui.label("当前配置暂无建议");
+                        return;
+                    }
+                    for advice in self.advice.iter() {
+                        ui.horizontal_wrapped(|ui| {
+                            match advice.level {
+                                AdviceLevel::Warning => {
+                                    ui.colored_label(ui.visuals().warn_fg_color, "⚠");
+                                }
+                                AdviceLevel::Info => {
+                                    ui.label("ℹ");
+                                }
+                            }
+                            ui.label(&advice.message);
+                            if let Some(link) = &advice.link {
+                                ui.hyperlink_to("去看看", link);
+                            }
+                        });
+                    }
+                });
+        });
+    }
+
+    /// 渲染升级建议面板
+    fn render_upgrades(&self, ui: &mut egui::Ui) {
+        self.theme_style().section(ui, |ui| {
+            egui::CollapsingHeader::new(format!("升级建议 ({})", self.upgrades.len()))
+                .default_open(true)
+                .show(ui, |ui| {
+                    if self.upgrades.is_empty() {
+                        ui.label("当前配置暂无可改进的项");
+                        return;
+                    }
+                    for suggestion in self.upgrades.iter() {
+                        ui.horizontal_wrapped(|ui| {
+                            let priority = format!("[{}]", suggestion.priority);
+                            match suggestion.priority {
+                                Priority::High => {
+                                    ui.colored_label(ui.visuals().warn_fg_color, priority);
+                                }
+                                Priority::Medium | Priority::Low => {
+                                    ui.label(priority);
+                                }
+                            }
+                            ui.label(format!("{}: ", suggestion.component));
+                            ui.strong(&suggestion.title);
+                        });
+                        ui.indent(&suggestion.rule_id, |ui| {
+                            ui.weak(&suggestion.detail);
+                        });
+                    }
+                });
+        });
+    }
+
+    /// 为当前标签页的滚动区域恢复上次保存的滚动位置
+    fn restore_scroll(&mut self, scroll_area: egui::ScrollArea) -> egui::ScrollArea {
+        match self.pending_scroll.remove(&self.current_tab) {
+            Some(offset) => scroll_area.vertical_scroll_offset(offset),
+            None => scroll_area,
+        }
+    }
+
+    /// 记录当前标签页的滚动位置，退出时写入配置
+    fn remember_scroll(&mut self, offset: egui::Vec2) {
+        self.config
+            .scroll_offsets
+            .insert(self.current_tab, offset.y);
+    }
+
+    /// 将界面状态同步到配置中
+    fn sync_config(&mut self) {
+        self.config.theme = self.theme;
+        self.config.last_tab = self.current_tab;
+        self.config.show_advice = self.show_advice;
+    }
+
+    /// 打开字段搜索面板，按当前检测结果与敏感信息显示设置生成索引
+    fn open_search(&mut self) {
+        let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+        let reveal = self.show_sensitive && !self.config.privacy_mode;
+        self.search_palette
+            .open(SearchIndex::build(&detector, reveal));
+    }
+
+    /// 切换高级模式，关闭时回到概览页
+    fn toggle_advanced_mode(&mut self) {
+        self.config.advanced_mode = !self.config.advanced_mode;
+        if !self.config.advanced_mode && self.current_tab.is_advanced() {
+            self.current_tab = AppTab::Overview;
+        }
+    }
+
+    /// 渲染标签栏，仅有一个可见标签页时不显示
+    fn render_tab_bar(&mut self, ui: &mut egui::Ui) {
+        let tabs = AppTab::visible(self.config.advanced_mode);
+        if tabs.len() <= 1 {
+            return;
+        }
+        ui.horizontal(|ui| {
+            for tab in tabs {
+                ui.selectable_value(&mut self.current_tab, tab, tab.name());
+            }
+        });
+        ui.separator();
     }
 
     /// 渲染电脑标题
     fn render_computer_title(&mut self, ui: &mut egui::Ui) {
         let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
         let system_type = match detector.system_info.computer_type {
-            crate::detector::ComputerType::Laptop => "笔记本",
-            crate::detector::ComputerType::Desktop => "台式机",
-            crate::detector::ComputerType::Unknown => "主机",
-        };
-        ui.heading(format!(
-            "{} {} {}",
-            detector.system_info.system_manufacturer,
-            detector.system_info.computer_model,
-            system_type
-        ));
+            crate::detector::ComputerType::Unknown => "主机".to_string(),
+            computer_type => computer_type.to_string(),
+        };
+        let title = ui.horizontal(|ui| {
+            ui.heading(format!(
+                "{} {} {}",
+                detector.system_info.system_manufacturer,
+                detector.system_info.computer_model,
+                system_type
+            ));
+            if let Some(hypervisor) = &detector.system_info.virtualization {
+                let badge = egui::RichText::new(format!(" 虚拟机 · {} ", hypervisor))
+                    .color(ui.visuals().warn_fg_color)
+                    .background_color(ui.visuals().faint_bg_color);
+                ui.label(badge)
+                    .on_hover_text("运行在虚拟机中，显示的是虚拟硬件信息");
+            }
+        });
+        self.summary_rect = Some(title.response.rect);
+    }
+
+    /// 渲染“磁盘 → 分区”树
+    fn render_disk_tree(&self, ui: &mut egui::Ui) {
+        let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+        let disk = &detector.disk_info;
+        if disk.volumes.is_empty() {
+            return;
+        }
+
+        let volume_bar = |ui: &mut egui::Ui, volume: &Volume| {
+            ui.add(
+                egui::ProgressBar::new(volume.used_ratio() as f32)
+                    .desired_width(320.0)
+                    .text(format!(
+                        "{} {} 可用 {} / {}",
+                        volume.display_name(),
+                        volume.file_system,
+                        format_size(volume.free_space),
+                        format_size(volume.total_space)
+                    )),
+            );
+        };
+
+        let mut export = None;
+        self.theme_style().section(ui, |ui| {
+            egui::CollapsingHeader::new("磁盘与分区")
+                .default_open(true)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        if ui.button("导出磁盘 CSV").clicked() {
+                            export = Some(csv::physical_disks(&detector));
+                        }
+                        if ui.button("导出分区 CSV").clicked() {
+                            export = Some(csv::volumes(&detector));
+                        }
+                    });
+                    for (number, volumes) in group_by_disk(&disk.volumes) {
+                        let title = match number {
+                            Some(number) => disk
+                                .physical_disks
+                                .iter()
+                                .find(|d| d.number == number)
+                                .map(|d| d.summary())
+                                .unwrap_or_else(|| format!("磁盘 {}", number)),
+                            None => "其他".to_string(),
+                        };
+                        egui::CollapsingHeader::new(title)
+                            .id_source(("disk_tree", number))
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                for volume in volumes {
+                                    volume_bar(ui, volume);
+                                }
+                            });
+                    }
+                    // 没有分区的磁盘（如未插卡的读卡器）
+                    for physical in disk.physical_disks.iter().filter(|d| {
+                        !disk
+                            .volumes
+                            .iter()
+                            .any(|v| v.disk_numbers().contains(&d.number))
+                    }) {
+                        ui.label(physical.summary());
+                    }
+                    for drive in disk.optical_drives.iter() {
+                        ui.label(format!("光驱 {}", drive.summary()));
+                    }
+                });
+        });
+
+        // 释放检测器后再打开保存对话框，避免阻塞后台检测
+        drop(detector);
+        if let Some(table) = export {
+            Self::export_csv(&table);
+        }
+    }
+
+    /// 渲染内存插槽表
+    fn render_memory_slots(&self, ui: &mut egui::Ui) {
+        let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+        let table = csv::memory_slots(&detector);
+        drop(detector);
+        if table.rows.is_empty() {
+            return;
+        }
+
+        self.theme_style().section(ui, |ui| {
+            egui::CollapsingHeader::new(format!("内存插槽 ({})", table.rows.len())).show(
+                ui,
+                |ui| {
+                    if ui.button("导出 CSV").clicked() {
+                        Self::export_csv(&table);
+                    }
+                    egui::Grid::new("memory_slots_grid")
+                        .num_columns(table.headers.len())
+                        .spacing(self.config.density.grid_spacing(20.0, 6.0))
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for header in table.headers.iter() {
+                                ui.strong(header);
+                            }
+                            ui.end_row();
+                            for row in table.rows.iter() {
+                                for (header, value) in table.headers.iter().zip(row) {
+                                    copy::value_label(ui, header, value);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                },
+            );
+        });
+    }
+
+    /// 显示缓存结果时提示缓存时间与后台检测进度
+    fn render_cache_notice(&self, ui: &mut egui::Ui) {
+        let Some(timestamp) = self.cached_at else {
+            return;
+        };
+        let age = chrono::Local::now().timestamp() - timestamp;
+        ui.horizontal(|ui| {
+            ui.spinner();
+            ui.weak(format!(
+                "{}，正在后台重新检测 ({:.0}%)",
+                describe_age(age),
+                self.detection_progress * 100.0
+            ));
+        });
+    }
+
+    /// 渲染系统详情（版本、安装日期、运行时长与激活状态）
+    fn render_system_details(&self, ui: &mut egui::Ui) {
+        self.theme_style().section(ui, |ui| {
+            egui::CollapsingHeader::new("系统详情").show(ui, |ui| {
+                let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+                let system = &detector.system_info;
+                let local_time = |timestamp: Option<i64>| {
+                    timestamp
+                        .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+                        .map(|time| {
+                            time.with_timezone(&chrono::Local)
+                                .format("%Y-%m-%d %H:%M")
+                                .to_string()
+                        })
+                        .unwrap_or_else(|| "未知".to_string())
+                };
+                let uptime = system
+                    .uptime_secs(chrono::Local::now().timestamp())
+                    .map(format_uptime)
+                    .unwrap_or_else(|| "未知".to_string());
+                let rows = [
+                    ("版本", system.display_version.clone()),
+                    ("内部版本", system.os_build.clone()),
+                    ("安装日期", local_time(system.install_date)),
+                    ("上次开机", local_time(system.last_boot_time)),
+                    ("已运行", uptime),
+                    ("激活状态", system.activation.to_string()),
+                ];
+                egui::Grid::new("system_details_grid")
+                    .num_columns(2)
+                    .spacing(self.config.density.grid_spacing(20.0, 6.0))
+                    .show(ui, |ui| {
+                        for (key, value) in rows {
+                            ui.label(format!("{}:", key));
+                            copy::value_label(ui, key, &value);
+                            ui.end_row();
+                        }
+                    });
+
+                egui::CollapsingHeader::new(format!("已安装更新 ({})", system.hotfixes.len()))
+                    .id_source("system_hotfixes")
+                    .show(ui, |ui| {
+                        if system.hotfixes.is_empty() {
+                            ui.label("未检测到已安装的更新");
+                            return;
+                        }
+                        egui::Grid::new("system_hotfix_grid")
+                            .striped(true)
+                            .spacing(self.config.density.grid_spacing(20.0, 4.0))
+                            .show(ui, |ui| {
+                                for column in ["编号", "类型", "安装日期"] {
+                                    ui.strong(column);
+                                }
+                                ui.end_row();
+
+                                for hotfix in system.hotfixes.iter() {
+                                    ui.label(&hotfix.kb);
+                                    ui.label(hotfix_kind_name(&hotfix.description));
+                                    ui.label(&hotfix.installed_on);
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            });
+        });
+    }
+
+    /// 渲染主板详情，敏感字段默认遮盖
+    fn render_motherboard_details(&mut self, ui: &mut egui::Ui) {
+        self.theme_style().section(ui, |ui| {
+            egui::CollapsingHeader::new(MOTHERBOARD_DETAILS)
+                .open(search::header_open(ui.ctx(), MOTHERBOARD_DETAILS))
+                .show(ui, |ui| {
+                    let privacy_mode = self.config.privacy_mode;
+                    ui.add_enabled(
+                        !privacy_mode,
+                        egui::Checkbox::new(&mut self.show_sensitive, "显示敏感信息"),
+                    )
+                    .on_disabled_hover_text("隐私模式已开启");
+                    let reveal = self.show_sensitive && !privacy_mode;
+                    let sensitive = |value: &str| {
+                        if reveal {
+                            value.to_string()
+                        } else {
+                            mask_sensitive(value)
+                        }
+                    };
+
+                    let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+                    let board = &detector.motherboard_info;
+                    let rows = [
+                        ("BIOS 版本", board.bios_version.clone()),
+                        ("BIOS 日期", board.bios_date.clone()),
+                        ("启动模式", board.firmware_type.to_string()),
+                        ("安全启动", board.secure_boot.to_string()),
+                        ("SMBIOS 版本", board.smbios_version.clone()),
+                        ("主板序列号", sensitive(&board.serial_number)),
+                        ("BIOS 序列号", sensitive(&board.bios_serial)),
+                        ("系统 UUID", sensitive(&board.system_uuid)),
+                        ("主板资产标签", sensitive(&board.asset_tag)),
+                        ("机箱序列号", sensitive(&board.chassis_serial)),
+                    ];
+                    egui::Grid::new("motherboard_details_grid")
+                        .num_columns(2)
+                        .spacing(self.config.density.grid_spacing(20.0, 6.0))
+                        .show(ui, |ui| {
+                            for (key, value) in rows {
+                                let response = ui.label(format!("{}:", key));
+                                search::highlight_field(ui, &response, MOTHERBOARD_DETAILS, key);
+                                copy::value_label(ui, key, &value);
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+    }
+
+    /// 渲染电池详情，没有电池时不显示，序列号默认遮盖
+    fn render_battery_details(&mut self, ui: &mut egui::Ui) {
+        let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+        if detector.battery_info.batteries.is_empty() {
+            return;
+        }
+        let reveal = self.show_sensitive && !self.config.privacy_mode;
+        self.theme_style().section(ui, |ui| {
+            egui::CollapsingHeader::new(BATTERY_DETAILS)
+                .open(search::header_open(ui.ctx(), BATTERY_DETAILS))
+                .show(ui, |ui| {
+                    for (index, battery) in detector.battery_info.batteries.iter().enumerate() {
+                        let serial_number = if reveal {
+                            battery.serial_number.clone()
+                        } else {
+                            mask_sensitive(&battery.serial_number)
+                        };
+                        let rows = [
+                            ("名称", format!("{} {}", battery.vendor, battery.name)),
+                            ("化学成分", battery.chemistry.to_string()),
+                            (
+                                "设计容量",
+                                format!("{:.1} Wh", battery.designed_capacity as f64 / 1000.0),
+                            ),
+                            (
+                                "满充容量",
+                                format!("{:.1} Wh", battery.full_charged_capacity as f64 / 1000.0),
+                            ),
+                            ("健康度", format!("{:.0}%", battery.health)),
+                            ("循环次数", battery.cycle_count.to_string()),
+                            (
+                                "温度",
+                                battery
+                                    .temperature
+                                    .map_or("未知".to_string(), |t| format!("{:.1} °C", t)),
+                            ),
+                            (
+                                "生产日期",
+                                battery
+                                    .manufacture_date
+                                    .clone()
+                                    .unwrap_or_else(|| "未知".to_string()),
+                            ),
+                            ("序列号", serial_number),
+                        ];
+                        egui::Grid::new(("battery_details_grid", index))
+                            .num_columns(2)
+                            .spacing(self.config.density.grid_spacing(20.0, 6.0))
+                            .show(ui, |ui| {
+                                for (key, value) in rows {
+                                    let response = ui.label(format!("{}:", key));
+                                    search::highlight_field(ui, &response, BATTERY_DETAILS, key);
+                                    copy::value_label(ui, key, &value);
+                                    ui.end_row();
+                                }
+                            });
+                        ui.add_space(4.0);
+                    }
+                });
+        });
     }
 
     /// 渲染硬件信息
     fn render_hardware_info(&mut self, ui: &mut egui::Ui) {
         let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+        // 被点击的行，表格绘制完成后打开对应的详情窗口
+        let mut clicked: Option<DeviceDetails> = None;
 
-        egui::Grid::new("hardware_info_grid")
-            .num_columns(2)
-            .spacing([20.0, 8.0])
-            .show(ui, |ui| {
-                ui.label("操作系统:");
-                ui.label(&detector.system_info.os_name);
-                ui.end_row();
-
-                ui.label("处理器:");
-                ui.label(format!(
-                    "{}({})",
-                    &detector.cpu_info.name, &detector.cpu_info.cores
-                ));
-                ui.end_row();
+        let grid = self.theme_style().section(ui, |ui| {
+            egui::Grid::new("hardware_info_grid")
+                .num_columns(2)
+                .spacing(self.config.density.grid_spacing(20.0, 8.0))
+                .show(ui, |ui| {
+                    section_label(ui, "操作系统:", detector.error(DetectionCategory::System));
+                    if device_label(ui, "操作系统", &detector.system_info.os_name).clicked() {
+                        clicked = DeviceDetails::section(&detector, "系统");
+                    }
+                    ui.end_row();
 
-                for gpu in detector.gpu_info.gpus.iter() {
-                    if gpu.gpu_type != GpuType::DiscreteGpu
-                        && gpu.gpu_type != GpuType::IntegratedGpu
-                    {
-                        continue;
+                    section_label(ui, "处理器:", detector.error(DetectionCategory::Cpu));
+                    let cpu = &detector.cpu_info;
+                    let label = device_label(
+                        ui,
+                        "处理器",
+                        &format!(
+                            "{}({})",
+                            &cpu.name,
+                            format_cores(
+                                cpu.physical_cores,
+                                cpu.logical_cores,
+                                cpu.efficiency_cores
+                            )
+                        ),
+                    )
+                    .on_hover_text(detector.cpu_info.frequency_desc());
+                    if label.clicked() {
+                        clicked = DeviceDetails::section(&detector, "处理器");
                     }
-                    let vram_size_str = format_size(gpu.vram_size);
-                    ui.label(format!("{}:", gpu.gpu_type.to_string()));
-                    ui.label(format!(
-                        "{} ({}, {})",
-                        gpu.description, vram_size_str, gpu.manufacturer
-                    ));
                     ui.end_row();
-                }
 
-                ui.label("内存:");
-                ui.label(&detector.memory_info.name);
-                ui.end_row();
-
-                ui.label("主板:");
-                ui.label(format!(
-                    "{} {} ({}, {})",
-                    &detector.motherboard_info.manufacturer,
-                    &detector.motherboard_info.product_name,
-                    &detector.motherboard_info.chipset,
-                    &detector.motherboard_info.bios_vendor
-                ));
-                ui.end_row();
+                    for gpu in detector.gpu_info.gpus.iter() {
+                        if gpu.gpu_type != GpuType::DiscreteGpu
+                            && gpu.gpu_type != GpuType::IntegratedGpu
+                        {
+                            continue;
+                        }
+                        let vram_size_str = format_size(gpu.effective_vram());
+                        let manufacturer =
+                            display_vendor(&gpu.manufacturer, self.config.localize_vendors);
+                        section_label(
+                            ui,
+                            &format!("{}:", gpu.gpu_type),
+                            detector.error(DetectionCategory::Gpu),
+                        );
+                        let label = device_label(
+                            ui,
+                            &gpu.gpu_type.to_string(),
+                            &format!("{} ({}, {})", gpu.description, vram_size_str, manufacturer),
+                        );
+                        if label.clicked() {
+                            clicked = Some(DeviceDetails::gpu(gpu));
+                        }
+                        let mut hover = Vec::new();
+                        if manufacturer != gpu.manufacturer {
+                            hover.push(gpu.manufacturer.clone());
+                        }
+                        match (gpu.driver_version.as_str(), gpu.driver_date.as_str()) {
+                            ("未知", _) => {}
+                            (version, "未知") => hover.push(format!("驱动: {}", version)),
+                            (version, date) => hover.push(format!("驱动: {} ({})", version, date)),
+                        }
+                        if gpu.vbios_version != "未知" {
+                            hover.push(format!("VBIOS: {}", gpu.vbios_version));
+                        }
+                        if let Some(stats) = &gpu.vendor_stats {
+                            let summary = stats.summary();
+                            if !summary.is_empty() {
+                                hover.push(format!("{} ({})", summary, stats.source));
+                            }
+                        }
+                        if let Some(link) = &gpu.pcie_link {
+                            hover.push(link.summary());
+                        }
+                        for output in gpu.outputs.iter() {
+                            hover.push(format!("输出: {}", output.summary()));
+                        }
+                        if !hover.is_empty() {
+                            label.on_hover_text(hover.join("\n"));
+                        }
+                        ui.end_row();
 
-                ui.label("显示器:");
-                ui.label(&detector.monitor_info.name);
+                        if let Some(warning) = gpu.pcie_link.and_then(|link| link.warning()) {
+                            ui.label("");
+                            ui.colored_label(ui.visuals().warn_fg_color, warning);
+                            ui.end_row();
+                        }
+                    }
 
-                ui.end_row();
+                    if let Some(hybrid) = &detector.gpu_info.hybrid {
+                        ui.label("混合输出:");
+                        copy::value_label(ui, "混合输出", &hybrid.summary());
+                        ui.end_row();
+                    }
 
-                ui.label("主硬盘:");
-                ui.label(format!(
-                    "{} ({}, {})",
-                    &detector.disk_info.model,
-                    format_size(detector.disk_info.total_capacity),
-                    &detector.disk_info.disk_type.to_string(),
-                ));
-                ui.end_row();
+                    if detector.gpu_info.gpus.is_empty() {
+                        failed_section_row(ui, &detector, DetectionCategory::Gpu, "显卡:");
+                    }
+
+                    if self.config.virtual_device_mode == VirtualDeviceMode::Group {
+                        let virtual_devices: Vec<&str> = detector
+                            .gpu_info
+                            .gpus
+                            .iter()
+                            .filter(|gpu| gpu.gpu_type.is_virtual())
+                            .map(|gpu| gpu.description.as_str())
+                            .collect();
+                        if !virtual_devices.is_empty() {
+                            ui.label("虚拟设备:");
+                            copy::value_label(ui, "虚拟设备", &virtual_devices.join(", "));
+                            ui.end_row();
+                        }
+                    }
 
-                for an in detector.network_info.adapters.iter() {
-                    ui.label("网卡:");
-                    ui.label(an.to_string());
+                    section_label(ui, "内存:", detector.error(DetectionCategory::Memory));
+                    if device_label(ui, "内存", &detector.memory_info.name).clicked() {
+                        clicked = DeviceDetails::section(&detector, "内存");
+                    }
                     ui.end_row();
-                }
 
-                for bt in detector.battery_info.batteries.iter() {
-                    ui.label("电池:");
-                    let label = ui.label(format!(
-                        "{} {} {} (健康度：{:.0}%)",
-                        bt.vendor,
-                        bt.name,
-                        bt.chemistry.to_string(),
-                        bt.health
-                    ));
-                    if bt.health > 100.0 {
-                        label.on_hover_text("提示：健康度超过 100% 是正常的");
+                    section_label(ui, "主板:", detector.error(DetectionCategory::Motherboard));
+                    let label = device_label(
+                        ui,
+                        "主板",
+                        &format!(
+                            "{} {} ({}, {})",
+                            &detector.motherboard_info.manufacturer,
+                            &detector.motherboard_info.product_name,
+                            &detector.motherboard_info.chipset,
+                            &detector.motherboard_info.bios_vendor
+                        ),
+                    );
+                    if label.clicked() {
+                        clicked = DeviceDetails::section(&detector, "主板");
                     }
                     ui.end_row();
-                }
 
-                for device in detector.audio_info.devices.iter() {
-                    ui.label("声卡:");
-                    ui.label(&device.name);
+                    section_label(ui, "显示器:", detector.error(DetectionCategory::Monitor));
+                    let label = device_label(ui, "显示器", &detector.monitor_info.name)
+                        .on_hover_text(format!(
+                            "面板: {}\n背光: {}",
+                            detector.monitor_info.panel_id,
+                            detector.monitor_info.backlight_desc()
+                        ));
+                    if label.clicked() {
+                        clicked = DeviceDetails::section(&detector, "显示器");
+                    }
+
                     ui.end_row();
-                }
-            });
+
+                    if !detector.monitor_info.modes.is_empty() {
+                        let modes: Vec<String> = detector
+                            .monitor_info
+                            .modes
+                            .iter()
+                            .map(|mode| mode.to_string())
+                            .collect();
+                        ui.label("刷新率:");
+                        copy::value_label(ui, "刷新率", &detector.monitor_info.refresh_desc())
+                            .on_hover_text(format!("支持的显示模式:\n{}", modes.join("\n")));
+                        ui.end_row();
+                    }
+
+                    for display in detector.monitor_info.displays.iter() {
+                        ui.label("当前模式:");
+                        copy::value_label(ui, "当前模式", &display.summary()).on_hover_text(
+                            format!(
+                                "设备: {}\n排列: {}",
+                                display.device_name,
+                                detector.monitor_info.layout_desc(display)
+                            ),
+                        );
+                        ui.end_row();
+                    }
+
+                    section_label(ui, "主硬盘:", detector.error(DetectionCategory::Disk));
+                    let mut disk_label = device_label(
+                        ui,
+                        "主硬盘",
+                        &format!(
+                            "{} ({}, {})",
+                            &detector.disk_info.model,
+                            format_size(detector.disk_info.total_capacity),
+                            detector.disk_info.type_desc(),
+                        ),
+                    );
+                    if let Some(link_speed) = &detector.disk_info.link_speed {
+                        disk_label = disk_label.on_hover_text(format!("链路速率: {}", link_speed));
+                    }
+                    if disk_label.clicked() {
+                        clicked = DeviceDetails::section(&detector, "硬盘");
+                    }
+                    ui.end_row();
+
+                    for an in detector.network_info.adapters.iter() {
+                        section_label(ui, "网卡:", detector.error(DetectionCategory::Network));
+                        let label = device_label(ui, "网卡", &an.summary()).on_hover_text(format!(
+                            "MAC: {}\n驱动版本: {}\n设备 ID: {}",
+                            an.mac_address, an.driver_version, an.pnp_device_id
+                        ));
+                        if label.clicked() {
+                            clicked = Some(DeviceDetails::network(an));
+                        }
+                        ui.end_row();
+                    }
+
+                    if detector.network_info.adapters.is_empty() {
+                        failed_section_row(ui, &detector, DetectionCategory::Network, "网卡:");
+                    }
+
+                    for wifi in detector.network_info.wifi_adapters.iter() {
+                        ui.label("无线网卡:");
+                        let label = copy::value_label(ui, "无线网卡", &wifi.summary());
+                        if wifi.connected {
+                            label.on_hover_text(format!(
+                                "网络: {}\n标准: {}\n信道: {}\n信号强度: {}\n支持: {}",
+                                wifi.ssid.as_deref().unwrap_or("未知"),
+                                wifi.current_standard.as_deref().unwrap_or("未知"),
+                                wifi.channel.map_or("未知".to_string(), |c| c.to_string()),
+                                wifi.rssi
+                                    .map_or("未知".to_string(), |r| format!("{} dBm", r)),
+                                wifi.standards.join(" / ")
+                            ));
+                        }
+                        ui.end_row();
+                    }
+
+                    for bt in detector.battery_info.batteries.iter() {
+                        section_label(ui, "电池:", detector.error(DetectionCategory::Battery));
+                        let mut label = device_label(
+                            ui,
+                            "电池",
+                            &format!(
+                                "{} {} {} (健康度：{:.0}%)",
+                                bt.vendor,
+                                bt.name,
+                                bt.chemistry.to_string(),
+                                bt.health
+                            ),
+                        );
+                        if bt.health > 100.0 {
+                            label = label.on_hover_text("提示：健康度超过 100% 是正常的");
+                        }
+                        if label.clicked() {
+                            clicked = DeviceDetails::section(&detector, "电池");
+                        }
+                        ui.end_row();
+                    }
+
+                    if detector.battery_info.power != PowerStatus::default() {
+                        section_label(ui, "电源:", None);
+                        copy::value_label(ui, "电源", &detector.battery_info.power.summary());
+                        ui.end_row();
+                    }
+
+                    for device in detector.audio_info.devices.iter() {
+                        section_label(ui, "声卡:", detector.error(DetectionCategory::Audio));
+                        let mut hover_text = format!("制造商: {}", device.manufacturer);
+                        if device.board_vendor != "未知" {
+                            hover_text.push_str(&format!(
+                                "\n板卡厂商: {}",
+                                display_vendor(&device.board_vendor, self.config.localize_vendors)
+                            ));
+                        }
+                        let label = device_label(
+                            ui,
+                            "声卡",
+                            &format!("{} ({})", device.name, device.transport),
+                        )
+                        .on_hover_text(hover_text);
+                        if label.clicked() {
+                            clicked = Some(DeviceDetails::audio(device));
+                        }
+                        ui.end_row();
+                    }
+
+                    if detector.battery_info.batteries.is_empty() {
+                        failed_section_row(ui, &detector, DetectionCategory::Battery, "电池:");
+                    }
+                    if detector.audio_info.devices.is_empty() {
+                        failed_section_row(ui, &detector, DetectionCategory::Audio, "声卡:");
+                    }
+
+                    let security = &detector.security_info;
+                    section_label(ui, "安全:", detector.error(DetectionCategory::Security));
+                    let label = device_label(
+                        ui,
+                        "安全",
+                        &format!(
+                            "{}, 安全启动{}",
+                            security.tpm_summary(),
+                            security.secure_boot
+                        ),
+                    )
+                    .on_hover_text(format!("BitLocker: {}", security.bitlocker_summary()));
+                    if label.clicked() {
+                        clicked = DeviceDetails::section(&detector, "安全");
+                    }
+                    ui.end_row();
+
+                    if let Some(estimate) = &self.power_estimate {
+                        ui.label("功耗估算:");
+                        copy::value_label(ui, "功耗估算", &estimate.summary()).on_hover_text(
+                            format!("粗略估算，每天按 {} 小时计算", ENERGY_HOURS_PER_DAY),
+                        );
+                        ui.end_row();
+                    }
+                })
+        });
+        drop(detector);
+        if let Some(details) = clicked {
+            self.detail_windows.open(details);
+        }
+
+        // 只截取滚动区域中可见的部分
+        let grid_rect = grid.response.rect.intersect(ui.clip_rect());
+        self.summary_rect = Some(match self.summary_rect {
+            Some(rect) => rect.union(grid_rect),
+            None => grid_rect,
+        });
+    }
+
+    /// 处理窗口截图：裁剪出硬件概览区域并保存为 PNG
+    ///
+    /// * `ctx` - 界面上下文
+    fn handle_screenshot(&mut self, ctx: &egui::Context) {
+        if !self.screenshot_pending {
+            return;
+        }
+        let Some(image) = ctx.input(|i| {
+            i.raw.events.iter().find_map(|event| match event {
+                egui::Event::Screenshot { image, .. } => Some(image.clone()),
+                _ => None,
+            })
+        }) else {
+            return;
+        };
+        self.screenshot_pending = false;
+
+        let region = self
+            .summary_rect
+            .map(|rect| rect.expand(SCREENSHOT_MARGIN))
+            .unwrap_or(egui::Rect::EVERYTHING);
+        let png = match screenshot::encode_region_png(&image, region, ctx.pixels_per_point()) {
+            Ok(png) => png,
+            Err(e) => {
+                log::warn!("{}", e);
+                return;
+            }
+        };
+
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("保存截图")
+            .set_file_name(screenshot::default_file_name(chrono::Local::now()))
+            .add_filter("PNG 图片", &["png"])
+            .save_file()
+        else {
+            return;
+        };
+        match std::fs::write(&path, png) {
+            Ok(()) => log::info!("截图已保存: {}", path.display()),
+            Err(e) => log::warn!("保存截图失败: {}", e),
+        }
     }
 }
 
 impl eframe::App for HardwareMasterApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // 记录窗口尺寸，退出时写入配置
+        if let Some(rect) = ctx.input(|i| i.viewport().inner_rect) {
+            self.config.window_width = rect.width();
+            self.config.window_height = rect.height();
+        }
+
+        // Ctrl+Shift+A 切换高级模式
+        if ctx.input_mut(|i| {
+            i.consume_key(egui::Modifiers::CTRL | egui::Modifiers::SHIFT, egui::Key::A)
+        }) {
+            self.toggle_advanced_mode();
+        }
+
+        // Ctrl+F 打开字段搜索
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::CTRL, egui::Key::F)) {
+            self.open_search();
+        }
+
+        #[cfg(feature = "ids-updater")]
+        self.handle_ids_update();
+        #[cfg(feature = "driver-update-check")]
+        self.handle_driver_rules();
+
+        // 截图回传于下一帧，需在重新绘制前处理，此时记录的概览区域仍属于被截图的那一帧
+        self.handle_screenshot(ctx);
+        self.summary_rect = None;
+
         // 检查进度更新
         if let Some(rx) = &self.progress_rx {
-            while let Ok((progress, message)) = rx.try_recv() {
-                self.detection_progress = progress;
-                self.detection_message = message;
+            while let Ok(event) = rx.try_recv() {
+                if let Some(progress) = event.progress() {
+                    self.detection_progress = progress;
+                }
+                self.detection_message = event.message();
             }
         }
 
@@ -309,16 +2351,31 @@ impl eframe::App for HardwareMasterApp {
             if let Ok(()) = rx.try_recv() {
                 self.is_detecting = false;
                 self.has_detected = true;
+                self.cached_at = None;
                 self.detection_progress = 1.0;
                 self.detection_message = "硬件检测完成！".to_string();
+                self.last_dynamic_refresh = Instant::now();
                 self.detection_rx = None;
                 self.progress_rx = None;
+                self.update_advice();
+                self.update_upgrades();
+                self.update_power_estimate();
+                self.update_grade();
+                self.update_inspection();
+                self.update_baseline();
+                self.record_snapshot();
+                self.update_hardware_changes();
+                self.save_cache();
             }
         }
 
+        self.handle_hotplug(ctx);
+        self.handle_tray(ctx);
+        self.handle_auto_refresh(ctx);
+
         // 主内容区域
         egui::CentralPanel::default().show(ctx, |ui| {
-            if self.is_detecting {
+            if self.is_detecting && self.cached_at.is_none() {
                 ui.vertical_centered(|ui| {
                     ui.add_space(ui.available_height() / 2.0 - 40.0);
                     ui.heading(format!(
@@ -334,27 +2391,221 @@ impl eframe::App for HardwareMasterApp {
                     self.render_computer_title(ui);
 
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        if ui.button("刷新").clicked() {
+                        let can_refresh = !self.refresh_categories.is_empty() && !self.is_detecting;
+                        if ui
+                            .add_enabled(can_refresh, egui::Button::new("刷新"))
+                            .clicked()
+                        {
                             self.start_refresh();
                         }
-                        if ui.button("复制").clicked() {
+                        self.render_refresh_menu(ui);
+                        if ui.button("搜索").on_hover_text("Ctrl+F").clicked() {
+                            self.open_search();
+                        }
+                        if ui
+                            .button("复制")
+                            .on_hover_text(format!("格式: {}", self.config.copy_template))
+                            .clicked()
+                        {
                             let info_text = self.get_hardware_info_text();
                             ui.ctx().copy_text(info_text);
                         }
+                        egui::ComboBox::from_id_source("copy_template")
+                            .selected_text(self.config.copy_template.name())
+                            .width(90.0)
+                            .show_ui(ui, |ui| {
+                                for template in CopyTemplate::ALL {
+                                    ui.selectable_value(
+                                        &mut self.config.copy_template,
+                                        template,
+                                        template.name(),
+                                    );
+                                }
+                            });
+                        if ui
+                            .button("保存截图")
+                            .on_hover_text("将硬件概览保存为 PNG 图片")
+                            .clicked()
+                        {
+                            // 截图在本帧绘制完成后生成，先切换到概览页保证截到的是硬件概览
+                            self.current_tab = AppTab::Overview;
+                            self.screenshot_pending = true;
+                            ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot);
+                        }
+                        ui.menu_button("导出报告", |ui| {
+                            if ui.button("HTML 报告").clicked() {
+                                ui.close_menu();
+                                self.export_report(
+                                    "HTML 文件",
+                                    "html",
+                                    crate::report::html::generate,
+                                );
+                            }
+                            if ui.button("Markdown 报告").clicked() {
+                                ui.close_menu();
+                                self.export_report(
+                                    "Markdown 文件",
+                                    "md",
+                                    crate::report::markdown::generate,
+                                );
+                            }
+                            if ui
+                                .button("XML 报告")
+                                .on_hover_text("与 msinfo32 导出格式相近，便于资产盘点工具导入")
+                                .clicked()
+                            {
+                                ui.close_menu();
+                                self.export_report("XML 文件", "xml", crate::report::xml::generate);
+                            }
+                            #[cfg(feature = "serialize")]
+                            if ui
+                                .button("JSON 资产记录")
+                                .on_hover_text(
+                                    "完整检测结果附带本机的购买日期、保修与使用人等资产信息",
+                                )
+                                .clicked()
+                            {
+                                ui.close_menu();
+                                self.export_asset_json();
+                            }
+                        });
+                        ui.checkbox(&mut self.show_advice, "建议");
+                        self.render_settings_menu(ui);
                         ui.separator();
                         ui.label(format!("主题: {}", self.theme));
                         if ui.button("切换").clicked() {
                             self.theme = self.theme.next();
-                            self.theme.apply(ctx);
+                            self.theme_style().apply(ctx);
                         }
                     });
                 });
+                self.render_cache_notice(ui);
                 ui.add_space(10.0);
+                self.render_baseline(ui);
+                self.render_tab_bar(ui);
 
-                egui::ScrollArea::both().show(ui, |ui| {
-                    self.render_hardware_info(ui);
-                });
+                // 仅在实时页可见时采样
+                if self.current_tab == AppTab::Live {
+                    self.live_monitor.start(ctx);
+                } else if self.live_monitor.is_running() {
+                    self.live_monitor.stop();
+                }
+
+                match self.current_tab {
+                    AppTab::Overview => {
+                        let scroll_area = self.restore_scroll(egui::ScrollArea::both());
+                        let output = scroll_area.show(ui, |ui| {
+                            self.render_grade(ui);
+                            self.render_hardware_info(ui);
+                            ui.add_space(10.0);
+                            self.render_memory_slots(ui);
+                            self.render_disk_tree(ui);
+                            self.render_system_details(ui);
+                            self.render_motherboard_details(ui);
+                            self.render_battery_details(ui);
+                            if self.show_advice {
+                                ui.add_space(10.0);
+                                self.render_upgrades(ui);
+                                self.render_advice(ui);
+                            }
+                        });
+                        self.remember_scroll(output.state.offset);
+                    }
+                    AppTab::Live => {
+                        let cpu_info = self
+                            .detector
+                            .lock()
+                            .expect("硬件检测器互斥锁被污染")
+                            .cpu_info
+                            .clone();
+                        self.live_monitor.show(ui, &cpu_info);
+                    }
+                    AppTab::Inspection => self.render_inspection(ui),
+                    AppTab::Trends => self.render_trends(ui),
+                    AppTab::Changes => self.render_hardware_changes(ui),
+                    AppTab::Benchmark => {
+                        let detector = self.detector.lock().expect("硬件检测器互斥锁被污染");
+                        self.benchmark_panel.show(ui, &detector);
+                    }
+                    AppTab::Peripherals => self.peripheral_panel.show(ui),
+                    AppTab::Asset => self.render_asset(ui),
+                    AppTab::Drivers => self.driver_panel.show(ui),
+                    AppTab::WmiConsole => self.wmi_console.show(ui),
+                    AppTab::Timings => self.render_timings(ui),
+                }
             }
         });
+
+        // 字段搜索：选中结果后切换到概览页并高亮对应的行
+        if let Some(target) = self.search_palette.show(ctx) {
+            self.current_tab = AppTab::Overview;
+            search::jump_to(ctx, target);
+        }
+        self.detail_windows.show(ctx);
+    }
+
+    fn save(&mut self, _storage: &mut dyn eframe::Storage) {
+        self.sync_config();
+        if let Err(e) = self.config.save() {
+            log::warn!("{}", e);
+        }
+    }
+}
+
+/// 在设置菜单中显示设备数据库来源，使用用户文件时悬停显示路径
+fn render_iddb_source(ui: &mut egui::Ui) {
+    let db = iddb::db();
+    let is_user_file = |source: &IdsSource| matches!(source, IdsSource::File(_));
+    let text = if is_user_file(db.pci_source()) || is_user_file(db.usb_source()) {
+        "设备数据库: 用户文件"
+    } else {
+        "设备数据库: 内置"
+    };
+    ui.label(text).on_hover_text(format!(
+        "PCI: {}\nUSB: {}\n将 pci.ids/usb.ids 放在程序目录或配置目录下即可替换内置数据",
+        db.pci_source(),
+        db.usb_source()
+    ));
+}
+
+/// 渲染概览中可点击的硬件行，点击后打开设备详情窗口，右键可复制
+///
+/// * `title` - 所在区块标题，如 "处理器"
+/// * `text` - 显示的内容
+fn device_label(ui: &mut egui::Ui, title: &str, text: &str) -> egui::Response {
+    copy::value_label(ui, title, text).on_hover_cursor(egui::CursorIcon::PointingHand)
+}
+
+/// 渲染概览中的区块标题，该类别最近一次检测失败时在标题后显示警告图标，悬停显示失败原因
+///
+/// 字段搜索跳转到该区块时高亮标题
+///
+/// * `title` - 区块标题，如 "处理器:"
+/// * `error` - 该类别最近一次检测的失败原因
+fn section_label(ui: &mut egui::Ui, title: &str, error: Option<&DetectionError>) {
+    ui.horizontal(|ui| {
+        let response = ui.label(title);
+        search::highlight_section(ui, &response, title);
+        if let Some(error) = error {
+            ui.colored_label(ui.visuals().warn_fg_color, "⚠")
+                .on_hover_text(format!("{}\n显示的是上次成功检测的结果", error));
+        }
+    });
+}
+
+/// 列表类区块（如网卡、声卡）检测失败且没有可显示的条目时，单独渲染一行失败提示
+///
+/// * `category` - 检测类别
+/// * `title` - 区块标题
+fn failed_section_row(
+    ui: &mut egui::Ui,
+    detector: &HardwareDetector,
+    category: DetectionCategory,
+    title: &str,
+) {
+    if let Some(error) = detector.error(category) {
+        section_label(ui, title, Some(error));
+        ui.weak("检测失败");
+        ui.end_row();
     }
 }
@@ -0,0 +1,184 @@
+//! 资产面板
+//!
+//! 编辑本机的购买日期、保修截止日期、使用人、备注与自定义字段，保存到配置文件，
+//! 导出的报告与 JSON 资产记录、远程上报以及本地接口会附带这些信息
+
+use crate::asset::{AssetRecord, WarrantyStatus};
+use crate::config::AppConfig;
+use chrono::NaiveDate;
+use eframe::egui;
+
+/// 资产面板
+#[derive(Default)]
+pub struct AssetPanel {
+    /// 正在编辑的资产信息的键，切换机器时重新载入
+    asset_key: Option<String>,
+    /// 编辑中的资产信息
+    draft: AssetRecord,
+    /// 新增自定义字段的名称
+    new_field_name: String,
+    /// 新增自定义字段的值
+    new_field_value: String,
+    /// 最近一次保存的结果
+    message: Option<Result<String, String>>,
+}
+
+impl AssetPanel {
+    /// 渲染面板
+    ///
+    /// * `asset_key` - 本机资产信息的键，见 [`crate::asset::asset_key`]
+    /// * `config` - 用户配置，保存时写入本机的资产信息
+    /// * `today` - 当天日期，用于计算保修状态
+    ///
+    /// 返回是否保存了修改
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        asset_key: &str,
+        config: &mut AppConfig,
+        today: NaiveDate,
+    ) -> bool {
+        if self.asset_key.as_deref() != Some(asset_key) {
+            self.draft = config.asset(asset_key).cloned().unwrap_or_default();
+            self.asset_key = Some(asset_key.to_string());
+            self.message = None;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("机器标识:");
+            ui.strong(asset_key);
+        });
+        ui.add_space(6.0);
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("asset_fields")
+                .num_columns(2)
+                .spacing([16.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label("购买日期");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.draft.purchase_date)
+                            .hint_text("2023-06-18"),
+                    );
+                    ui.end_row();
+
+                    ui.label("保修截止");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.draft.warranty_end)
+                                .hint_text("2026-06-17"),
+                        );
+                        let status = self.draft.warranty_status(today);
+                        match status {
+                            WarrantyStatus::Unknown => {}
+                            WarrantyStatus::Active { days_left } if days_left <= 30 => {
+                                ui.colored_label(
+                                    egui::Color32::from_rgb(255, 165, 0),
+                                    status.to_string(),
+                                );
+                            }
+                            WarrantyStatus::Active { .. } => {
+                                ui.colored_label(
+                                    egui::Color32::from_rgb(0, 170, 0),
+                                    status.to_string(),
+                                );
+                            }
+                            WarrantyStatus::Expired { .. } => {
+                                ui.colored_label(
+                                    egui::Color32::from_rgb(220, 50, 50),
+                                    status.to_string(),
+                                );
+                            }
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label("使用人");
+                    ui.text_edit_singleline(&mut self.draft.owner);
+                    ui.end_row();
+
+                    ui.label("备注");
+                    ui.text_edit_multiline(&mut self.draft.notes);
+                    ui.end_row();
+
+                    let mut removed = None;
+                    for (name, value) in self.draft.custom_fields.iter_mut() {
+                        ui.label(name.as_str());
+                        ui.horizontal(|ui| {
+                            ui.text_edit_singleline(value);
+                            if ui.small_button("删除").clicked() {
+                                removed = Some(name.clone());
+                            }
+                        });
+                        ui.end_row();
+                    }
+                    if let Some(name) = removed {
+                        self.draft.custom_fields.remove(&name);
+                    }
+                });
+
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                ui.label("自定义字段:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.new_field_name)
+                        .hint_text("资产编号")
+                        .desired_width(120.0),
+                );
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.new_field_value)
+                        .hint_text("IT-0042")
+                        .desired_width(160.0),
+                );
+                let name = self.new_field_name.trim();
+                if ui
+                    .add_enabled(!name.is_empty(), egui::Button::new("添加"))
+                    .clicked()
+                {
+                    self.draft
+                        .custom_fields
+                        .insert(name.to_string(), self.new_field_value.trim().to_string());
+                    self.new_field_name.clear();
+                    self.new_field_value.clear();
+                }
+            });
+        });
+
+        ui.add_space(8.0);
+        let mut saved = false;
+        ui.horizontal(|ui| {
+            let stored = config.asset(asset_key).cloned().unwrap_or_default();
+            let modified = self.draft != stored;
+            if ui
+                .add_enabled(modified, egui::Button::new("保存"))
+                .clicked()
+            {
+                self.message = Some(match self.draft.validate() {
+                    Ok(()) => {
+                        config.set_asset(asset_key, self.draft.clone());
+                        saved = true;
+                        Ok("已保存".to_string())
+                    }
+                    Err(e) => Err(e),
+                });
+            }
+            if ui
+                .add_enabled(modified, egui::Button::new("撤销修改"))
+                .clicked()
+            {
+                self.draft = stored;
+                self.message = None;
+            }
+            match &self.message {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), e);
+                }
+                None => {}
+            }
+        });
+        saved
+    }
+}
@@ -0,0 +1,426 @@
+//! 性能测试面板
+//!
+//! 在后台线程中运行内存与硬盘测试，在检测到的硬件规格旁显示每次测试的结果，
+//! 并保存历史成绩以便观察变化趋势、发现驱动更新后的性能回退
+
+use crate::benchmark::disk::{self, DiskBenchOptions, DiskBenchResult};
+use crate::benchmark::history::{
+    find_regressions, BenchmarkRecord, BenchmarkResult, BenchmarkStore,
+};
+use crate::benchmark::memory::{self, MemoryBenchOptions, MemoryBenchResult};
+use crate::detector::memory::MemoryInfo;
+use crate::detector::volume::{DriveKind, Volume};
+use crate::ui::chart::{line_chart, SeriesPoint};
+use crate::utils::string::format_size;
+use crate::HardwareDetector;
+use eframe::egui;
+use std::sync::mpsc;
+
+/// 成绩表格中最多显示的记录数
+const MAX_TABLE_ROWS: usize = 10;
+
+/// 后台测试线程返回的结果
+enum BenchmarkOutcome {
+    /// 内存测试结果
+    Memory(MemoryBenchResult),
+    /// 硬盘测试结果
+    Disk(Result<DiskBenchResult, String>),
+}
+
+/// 性能测试面板
+#[derive(Default)]
+pub struct BenchmarkPanel {
+    /// 测试结果接收器，测试进行中时存在
+    result_rx: Option<mpsc::Receiver<BenchmarkOutcome>>,
+    /// 历史成绩存储
+    store: Option<BenchmarkStore>,
+    /// 全部历史成绩，按测试顺序排列
+    records: Vec<BenchmarkRecord>,
+    /// 历史成绩图表所选的测试对象
+    history_target: Option<String>,
+    /// 硬盘测试所选分区，如 "C:"
+    disk_target: Option<String>,
+    /// 用户是否同意在所选分区写入临时文件
+    disk_consent: bool,
+    /// 最近一次硬盘测试错误
+    disk_error: Option<String>,
+}
+
+impl BenchmarkPanel {
+    /// 创建面板并加载历史成绩
+    pub fn new() -> Self {
+        let store = BenchmarkStore::open_default();
+        let records = store
+            .as_ref()
+            .map(|store| {
+                store.load().unwrap_or_else(|e| {
+                    log::warn!("{}", e);
+                    Vec::new()
+                })
+            })
+            .unwrap_or_default();
+        Self {
+            store,
+            records,
+            ..Default::default()
+        }
+    }
+
+    /// 是否正在测试
+    pub fn is_running(&self) -> bool {
+        self.result_rx.is_some()
+    }
+
+    /// 在后台线程中启动内存测试，已在测试时不重复启动
+    ///
+    /// * `ctx` - 界面上下文，测试完成后唤醒界面重绘
+    pub fn start_memory(&mut self, ctx: &egui::Context) {
+        if self.is_running() {
+            return;
+        }
+
+        let (tx, rx) = mpsc::channel();
+        self.result_rx = Some(rx);
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let result = memory::run(&MemoryBenchOptions::default());
+            let _ = tx.send(BenchmarkOutcome::Memory(result));
+            ctx.request_repaint();
+        });
+    }
+
+    /// 在后台线程中对指定分区启动硬盘测试，已在测试时不重复启动
+    ///
+    /// * `ctx` - 界面上下文，测试完成后唤醒界面重绘
+    /// * `drive_letter` - 测试的分区，如 "C:"
+    pub fn start_disk(&mut self, ctx: &egui::Context, drive_letter: String) {
+        if self.is_running() {
+            return;
+        }
+
+        let (tx, rx) = mpsc::channel();
+        self.result_rx = Some(rx);
+        self.disk_error = None;
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let result = disk::run(&drive_letter, &DiskBenchOptions::default());
+            let _ = tx.send(BenchmarkOutcome::Disk(result));
+            ctx.request_repaint();
+        });
+    }
+
+    /// 保存一次测试结果
+    ///
+    /// * `result` - 测试结果
+    /// * `detector` - 硬件检测器，用于生成硬件与驱动指纹
+    fn record(&mut self, result: BenchmarkResult, detector: &HardwareDetector) {
+        let record = BenchmarkRecord::new(result, detector);
+        if let Some(store) = &self.store {
+            if let Err(e) = store.append(&record) {
+                log::warn!("{}", e);
+            }
+        }
+        self.history_target = Some(record.result.target());
+        self.records.push(record);
+    }
+
+    /// 渲染性能测试面板
+    ///
+    /// * `detector` - 硬件检测器，提供内存规格、可测试的分区与指纹信息
+    pub fn show(&mut self, ui: &mut egui::Ui, detector: &HardwareDetector) {
+        if let Some(rx) = &self.result_rx {
+            match rx.try_recv() {
+                Ok(outcome) => {
+                    match outcome {
+                        BenchmarkOutcome::Memory(result) => {
+                            self.record(BenchmarkResult::Memory(result), detector)
+                        }
+                        BenchmarkOutcome::Disk(Ok(result)) => {
+                            self.record(BenchmarkResult::Disk(result), detector)
+                        }
+                        BenchmarkOutcome::Disk(Err(e)) => self.disk_error = Some(e),
+                    }
+                    self.result_rx = None;
+                }
+                Err(mpsc::TryRecvError::Disconnected) => self.result_rx = None,
+                Err(mpsc::TryRecvError::Empty) => {}
+            }
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            self.show_memory(ui, &detector.memory_info);
+            ui.add_space(16.0);
+            self.show_disk(ui, &detector.disk_info.volumes);
+            ui.add_space(16.0);
+            self.show_history(ui);
+        });
+    }
+
+    /// 渲染内存测试部分
+    fn show_memory(&mut self, ui: &mut egui::Ui, memory_info: &MemoryInfo) {
+        ui.heading("内存性能");
+        ui.horizontal(|ui| {
+            ui.label("已检测内存:");
+            ui.label(memory_spec(memory_info));
+        });
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.is_running(), egui::Button::new("开始测试"))
+                .clicked()
+            {
+                self.start_memory(ui.ctx());
+            }
+            if self.is_running() {
+                ui.spinner();
+                ui.label("正在测试，请勿运行其他程序...");
+            }
+        });
+        ui.add_space(6.0);
+
+        let rows: Vec<(usize, &MemoryBenchResult)> = self
+            .records
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(index, record)| match &record.result {
+                BenchmarkResult::Memory(result) => Some((index, result)),
+                _ => None,
+            })
+            .take(MAX_TABLE_ROWS)
+            .collect();
+        if rows.is_empty() {
+            return;
+        }
+
+        egui::Grid::new("memory_bench_grid")
+            .num_columns(5)
+            .spacing([20.0, 8.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("时间");
+                ui.strong("读取");
+                ui.strong("写入");
+                ui.strong("延迟");
+                ui.strong("对比上次");
+                ui.end_row();
+
+                for (index, result) in rows {
+                    ui.label(format_time(result.timestamp));
+                    ui.label(format!("{}/s", format_size(result.read_bytes_per_sec)));
+                    ui.label(format!("{}/s", format_size(result.write_bytes_per_sec)));
+                    ui.label(format!("{:.1} ns", result.latency_ns));
+                    self.regression_label(ui, index);
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// 渲染硬盘测试部分
+    fn show_disk(&mut self, ui: &mut egui::Ui, volumes: &[Volume]) {
+        let candidates: Vec<&Volume> = volumes
+            .iter()
+            .filter(|volume| matches!(volume.kind, DriveKind::Fixed | DriveKind::Removable))
+            .collect();
+        if self.disk_target.is_none() {
+            self.disk_target = candidates.first().map(|volume| volume.drive_letter.clone());
+        }
+
+        ui.heading("硬盘性能");
+        let options = DiskBenchOptions::default();
+        ui.horizontal(|ui| {
+            ui.label("测试分区:");
+            let selected = self.disk_target.clone().unwrap_or_default();
+            egui::ComboBox::from_id_source("disk_bench_target")
+                .selected_text(&selected)
+                .show_ui(ui, |ui| {
+                    for volume in candidates.iter() {
+                        let text = format!(
+                            "{} 可用 {}",
+                            volume.display_name(),
+                            format_size(volume.free_space)
+                        );
+                        ui.selectable_value(
+                            &mut self.disk_target,
+                            Some(volume.drive_letter.clone()),
+                            text,
+                        );
+                    }
+                });
+        });
+        ui.checkbox(
+            &mut self.disk_consent,
+            format!(
+                "我已了解测试会在所选分区写入约 {} 的临时文件，测试结束后自动删除",
+                format_size(options.file_size as f64)
+            ),
+        );
+        ui.horizontal(|ui| {
+            let enabled = self.disk_consent && self.disk_target.is_some() && !self.is_running();
+            if ui
+                .add_enabled(enabled, egui::Button::new("开始测试"))
+                .clicked()
+            {
+                if let Some(target) = self.disk_target.clone() {
+                    self.start_disk(ui.ctx(), target);
+                }
+            }
+            if self.is_running() {
+                ui.spinner();
+            }
+        });
+        if let Some(e) = &self.disk_error {
+            ui.colored_label(ui.visuals().error_fg_color, e);
+        }
+        ui.add_space(6.0);
+
+        let rows: Vec<(usize, &DiskBenchResult)> = self
+            .records
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(index, record)| match &record.result {
+                BenchmarkResult::Disk(result) => Some((index, result)),
+                _ => None,
+            })
+            .take(MAX_TABLE_ROWS)
+            .collect();
+        if rows.is_empty() {
+            return;
+        }
+
+        egui::Grid::new("disk_bench_grid")
+            .num_columns(7)
+            .spacing([20.0, 8.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("时间");
+                ui.strong("分区");
+                ui.strong("顺序读取");
+                ui.strong("顺序写入");
+                ui.strong("4K 随机读取");
+                ui.strong("4K 随机写入");
+                ui.strong("对比上次");
+                ui.end_row();
+
+                for (index, result) in rows {
+                    ui.label(format_time(result.timestamp));
+                    ui.label(&result.drive_letter);
+                    ui.label(format!("{}/s", format_size(result.seq_read_bytes_per_sec)));
+                    ui.label(format!("{}/s", format_size(result.seq_write_bytes_per_sec)));
+                    ui.label(format!(
+                        "{:.0} IOPS ({}/s)",
+                        result.random_read_iops,
+                        format_size(result.random_read_bytes_per_sec())
+                    ));
+                    ui.label(format!(
+                        "{:.0} IOPS ({}/s)",
+                        result.random_write_iops,
+                        format_size(result.random_write_bytes_per_sec())
+                    ));
+                    self.regression_label(ui, index);
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// 渲染记录相对上一次成绩的回退提示，没有回退时留空
+    ///
+    /// * `index` - 记录下标
+    fn regression_label(&self, ui: &mut egui::Ui, index: usize) {
+        let regressions = find_regressions(&self.records, index);
+        if regressions.is_empty() {
+            ui.label("");
+            return;
+        }
+        let descriptions: Vec<String> = regressions
+            .iter()
+            .map(|regression| regression.description())
+            .collect();
+        let text = if regressions.iter().any(|r| r.after_driver_update) {
+            "⚠ 驱动更新后性能下降"
+        } else {
+            "⚠ 性能下降"
+        };
+        ui.colored_label(ui.visuals().warn_fg_color, text)
+            .on_hover_text(descriptions.join("\n"));
+    }
+
+    /// 渲染历史成绩图表
+    fn show_history(&mut self, ui: &mut egui::Ui) {
+        let mut targets: Vec<String> = Vec::new();
+        for record in self.records.iter() {
+            let target = record.result.target();
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+        if targets.is_empty() {
+            return;
+        }
+        if self.history_target.is_none() {
+            self.history_target = targets.first().cloned();
+        }
+
+        ui.heading("历史成绩");
+        ui.horizontal(|ui| {
+            ui.label("测试对象:");
+            let selected = self.history_target.clone().unwrap_or_default();
+            egui::ComboBox::from_id_source("bench_history_target")
+                .selected_text(&selected)
+                .show_ui(ui, |ui| {
+                    for target in targets.iter() {
+                        ui.selectable_value(&mut self.history_target, Some(target.clone()), target);
+                    }
+                });
+        });
+        ui.add_space(6.0);
+
+        let Some(target) = &self.history_target else {
+            return;
+        };
+        let records: Vec<&BenchmarkRecord> = self
+            .records
+            .iter()
+            .filter(|record| &record.result.target() == target)
+            .collect();
+        let Some(latest) = records.last() else {
+            return;
+        };
+        for (metric_index, metric) in latest.result.metrics().iter().enumerate() {
+            let points: Vec<SeriesPoint> = records
+                .iter()
+                .filter_map(|record| {
+                    let metrics = record.result.metrics();
+                    metrics
+                        .get(metric_index)
+                        .map(|m| (record.result.timestamp(), m.value))
+                })
+                .collect();
+            line_chart(ui, metric.name, metric.unit, &points);
+        }
+    }
+}
+
+/// 内存规格描述，如 "DDR4 3200 MHz 16.00 GB"
+fn memory_spec(memory_info: &MemoryInfo) -> String {
+    match memory_info.slots.first() {
+        Some(slot) => format!(
+            "{} {} MHz {}",
+            slot.memory_type,
+            slot.frequency,
+            format_size(memory_info.total_memory)
+        ),
+        None => memory_info.name.clone(),
+    }
+}
+
+/// 将 Unix 时间戳格式化为本地时间
+fn format_time(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
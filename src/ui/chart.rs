@@ -0,0 +1,165 @@
+//! 折线图模块
+//!
+//! 使用 egui 画笔绘制简单的时间序列折线图
+
+use eframe::egui;
+
+/// 图表高度
+const CHART_HEIGHT: f32 = 120.0;
+
+/// 悬停提示的命中半径
+const HOVER_RADIUS: f32 = 8.0;
+
+/// 时间序列数据点（Unix 时间戳秒, 数值）
+pub type SeriesPoint = (i64, f64);
+
+/// 绘制带标题的折线图
+///
+/// * `title` - 图表标题
+/// * `unit` - 数值单位，用于坐标轴和悬停提示
+/// * `points` - 按时间排序的数据点
+pub fn line_chart(ui: &mut egui::Ui, title: &str, unit: &str, points: &[SeriesPoint]) {
+    line_chart_with_threshold(ui, title, unit, points, None);
+}
+
+/// 绘制带标题和警戒线的折线图
+///
+/// * `title` - 图表标题
+/// * `unit` - 数值单位，用于坐标轴和悬停提示
+/// * `points` - 按时间排序的数据点
+/// * `threshold` - 警戒线数值，以虚线绘制
+pub fn line_chart_with_threshold(
+    ui: &mut egui::Ui,
+    title: &str,
+    unit: &str,
+    points: &[SeriesPoint],
+    threshold: Option<f64>,
+) {
+    ui.strong(title);
+    if points.len() < 2 {
+        ui.label("数据不足，至少需要两次检测记录");
+        ui.add_space(8.0);
+        return;
+    }
+
+    let width = ui.available_width().max(200.0);
+    let (rect, response) =
+        ui.allocate_exact_size(egui::vec2(width, CHART_HEIGHT), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+    let text_color = visuals.weak_text_color();
+    let line_color = visuals.selection.bg_fill;
+    let font = egui::FontId::proportional(11.0);
+
+    painter.rect_stroke(rect, 2.0, visuals.widgets.noninteractive.bg_stroke);
+
+    let (min_x, max_x) = (points[0].0, points[points.len() - 1].0);
+    let (mut min_y, mut max_y) = points
+        .iter()
+        .map(|(_, y)| *y)
+        .chain(threshold)
+        .fold((f64::MAX, f64::MIN), |(lo, hi), y| (lo.min(y), hi.max(y)));
+    if (max_y - min_y).abs() < f64::EPSILON {
+        min_y -= 1.0;
+        max_y += 1.0;
+    }
+
+    let plot = rect.shrink2(egui::vec2(8.0, 16.0));
+    let to_screen = |(x, y): SeriesPoint| {
+        let tx = if max_x == min_x {
+            0.5
+        } else {
+            (x - min_x) as f32 / (max_x - min_x) as f32
+        };
+        let ty = ((y - min_y) / (max_y - min_y)) as f32;
+        egui::pos2(
+            plot.left() + tx * plot.width(),
+            plot.bottom() - ty * plot.height(),
+        )
+    };
+
+    if let Some(threshold) = threshold {
+        let warn_color = visuals.warn_fg_color;
+        let y = to_screen((min_x, threshold)).y;
+        painter.add(egui::Shape::dashed_line(
+            &[egui::pos2(plot.left(), y), egui::pos2(plot.right(), y)],
+            egui::Stroke::new(1.0, warn_color),
+            6.0,
+            4.0,
+        ));
+        painter.text(
+            egui::pos2(plot.right(), y - 2.0),
+            egui::Align2::RIGHT_BOTTOM,
+            format!("{:.1} {}", threshold, unit),
+            font.clone(),
+            warn_color,
+        );
+    }
+
+    let screen_points: Vec<egui::Pos2> = points.iter().copied().map(to_screen).collect();
+    painter.add(egui::Shape::line(
+        screen_points.clone(),
+        egui::Stroke::new(1.5, line_color),
+    ));
+    for pos in screen_points.iter() {
+        painter.circle_filled(*pos, 2.5, line_color);
+    }
+
+    // 坐标轴标签
+    painter.text(
+        rect.left_top() + egui::vec2(4.0, 2.0),
+        egui::Align2::LEFT_TOP,
+        format!("{:.1} {}", max_y, unit),
+        font.clone(),
+        text_color,
+    );
+    painter.text(
+        rect.left_bottom() + egui::vec2(4.0, -2.0),
+        egui::Align2::LEFT_BOTTOM,
+        format!("{:.1} {}", min_y, unit),
+        font.clone(),
+        text_color,
+    );
+    painter.text(
+        rect.right_bottom() + egui::vec2(-4.0, -2.0),
+        egui::Align2::RIGHT_BOTTOM,
+        format!("{} ~ {}", format_date(min_x), format_date(max_x)),
+        font,
+        text_color,
+    );
+
+    // 悬停显示最近的数据点
+    if let Some(hover) = response.hover_pos() {
+        let nearest = screen_points
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (a.x - hover.x)
+                    .abs()
+                    .partial_cmp(&(b.x - hover.x).abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(index, _)| index);
+        if let Some(index) = nearest {
+            let pos = screen_points[index];
+            if (pos.x - hover.x).abs() <= HOVER_RADIUS.max(plot.width() / points.len() as f32) {
+                painter.circle_stroke(pos, 4.0, egui::Stroke::new(1.5, line_color));
+                let (x, y) = points[index];
+                response.on_hover_text_at_pointer(format!("{}\n{:.1} {}", format_date(x), y, unit));
+            }
+        }
+    }
+
+    ui.add_space(8.0);
+}
+
+/// 将 Unix 时间戳格式化为本地日期
+fn format_date(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
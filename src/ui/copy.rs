@@ -0,0 +1,73 @@
+//! 字段复制
+//!
+//! 为值标签提供右键菜单，可单独复制字段值或 "字段名: 字段值" 整行，
+//! 便于将序列号、MAC 地址等粘贴到保修单中。
+//! 值标签可以用 Tab 键逐行聚焦，按 Ctrl+C 复制聚焦的行
+
+use eframe::egui;
+
+/// 整行文本，字段名末尾的冒号会被去掉后统一补上
+///
+/// 示例
+/// ```
+/// use hardware_master::ui::copy::row_text;
+/// assert_eq!(row_text("主板序列号", "PF2ABCDE"), "主板序列号: PF2ABCDE");
+/// assert_eq!(row_text("处理器:", "Intel(R) Core(TM) i5-12400"), "处理器: Intel(R) Core(TM) i5-12400");
+/// assert_eq!(row_text("网卡：", "00:1A:2B:3C:4D:5E"), "网卡: 00:1A:2B:3C:4D:5E");
+/// ```
+pub fn row_text(key: &str, value: &str) -> String {
+    let key = key.trim_end_matches([':', '：']);
+    format!("{}: {}", key, value)
+}
+
+/// 为已绘制的值标签添加右键复制菜单
+///
+/// * `response` - 值标签的响应，需要感知点击
+/// * `key` - 字段名
+/// * `value` - 字段值
+pub fn context_menu(response: &egui::Response, key: &str, value: &str) {
+    response.context_menu(|ui| {
+        if ui.button("复制该值").clicked() {
+            ui.ctx().copy_text(value.to_string());
+            ui.close_menu();
+        }
+        if ui.button("复制本行").clicked() {
+            ui.ctx().copy_text(row_text(key, value));
+            ui.close_menu();
+        }
+    });
+}
+
+/// 键盘导航：聚焦的值标签绘制焦点框并滚动到可见区域，按 Ctrl+C 时复制整行
+///
+/// * `response` - 值标签的响应，需要感知点击才能获得焦点
+/// * `key` - 字段名
+/// * `value` - 字段值
+pub fn focus_row(ui: &egui::Ui, response: &egui::Response, key: &str, value: &str) {
+    if !response.has_focus() {
+        return;
+    }
+    if response.gained_focus() {
+        response.scroll_to_me(None);
+    }
+    ui.painter().rect_stroke(
+        response.rect.expand(2.0),
+        2.0,
+        ui.visuals().selection.stroke,
+    );
+    let copy = ui.input(|i| i.events.iter().any(|e| matches!(e, egui::Event::Copy)));
+    if copy {
+        ui.ctx().copy_text(row_text(key, value));
+    }
+}
+
+/// 绘制带右键复制菜单的值标签，支持键盘聚焦与 Ctrl+C 复制
+///
+/// * `key` - 字段名
+/// * `value` - 字段值
+pub fn value_label(ui: &mut egui::Ui, key: &str, value: &str) -> egui::Response {
+    let response = ui.add(egui::Label::new(value).sense(egui::Sense::click()));
+    context_menu(&response, key, value);
+    focus_row(ui, &response, key, value);
+    response
+}
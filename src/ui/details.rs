@@ -0,0 +1,231 @@
+//! 设备详情窗口
+//!
+//! 点击概览中的硬件行时打开，列出该设备的全部原始字段；
+//! 带有设备实例 ID 的设备（显卡、网卡、声卡）还会列出解析出的各项 ID，
+//! 并在打开时读取驱动与系统分配的资源
+
+#[cfg(windows)]
+use crate::detector::devnode;
+use crate::detector::network::{format_link_speed, NetworkAdapter};
+use crate::detector::pci;
+use crate::detector::{audio::AudioDevice, gpu::Gpu, HardwareDetector};
+use crate::iddb::{self, Bus};
+use crate::report::build_sections;
+use crate::ui::copy;
+use crate::utils::device_id::parse_device_instance_id;
+use crate::utils::string::format_size;
+use crate::utils::subsystem::SubsystemId;
+use eframe::egui;
+
+/// 设备详情
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceDetails {
+    /// 窗口标题
+    pub title: String,
+    /// 字段（字段名, 字段值）
+    pub rows: Vec<(String, String)>,
+    /// 设备实例 ID，用于按需读取驱动与资源信息
+    pub instance_id: Option<String>,
+}
+
+impl DeviceDetails {
+    fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            rows: Vec::new(),
+            instance_id: None,
+        }
+    }
+
+    fn row(&mut self, key: &str, value: impl ToString) {
+        self.rows.push((key.to_string(), value.to_string()));
+    }
+
+    /// 由报告章节生成详情，用于处理器、内存、主板等没有单独设备实例的硬件
+    ///
+    /// * `detector` - 检测结果
+    /// * `section` - 报告章节标题，如 "处理器"
+    pub fn section(detector: &HardwareDetector, section: &str) -> Option<Self> {
+        build_sections(detector)
+            .into_iter()
+            .find(|candidate| candidate.title == section)
+            .map(|section| Self {
+                title: section.title,
+                rows: section.rows,
+                instance_id: None,
+            })
+    }
+
+    /// 显卡详情
+    pub fn gpu(gpu: &Gpu) -> Self {
+        let mut details = Self::new(&gpu.description);
+        details.row("名称", &gpu.description);
+        details.row("类型", &gpu.gpu_type);
+        details.row("芯片商", &gpu.chip_vendor);
+        details.row("制造商", &gpu.manufacturer);
+        details.row("专用显存", format_size(gpu.dedicated_vram));
+        details.row("共享内存", format_size(gpu.shared_system_memory));
+        details.row("驱动版本", &gpu.driver_version);
+        details.row("驱动日期", &gpu.driver_date);
+        details.row("VBIOS", &gpu.vbios_version);
+        if let Some(link) = &gpu.pcie_link {
+            details.row("PCIe 链路", link.summary());
+        }
+        for output in gpu.outputs.iter() {
+            details.row("显示输出", output.summary());
+        }
+        details.with_instance_id(&gpu.instance_id)
+    }
+
+    /// 网卡详情
+    pub fn network(adapter: &NetworkAdapter) -> Self {
+        let mut details = Self::new(&adapter.name);
+        details.row("名称", &adapter.name);
+        details.row("类型", adapter.adapter_type);
+        details.row("状态", adapter.status);
+        details.row("MAC 地址", &adapter.mac_address);
+        if let Some(speed) = adapter.speed {
+            details.row("连接速率", format_link_speed(speed));
+        }
+        details.row("驱动版本", &adapter.driver_version);
+        details.with_instance_id(&adapter.pnp_device_id)
+    }
+
+    /// 声卡详情
+    pub fn audio(device: &AudioDevice) -> Self {
+        let mut details = Self::new(&device.name);
+        details.row("名称", &device.name);
+        details.row("制造商", &device.manufacturer);
+        details.row("连接方式", device.transport);
+        details.with_instance_id(&device.device_id)
+    }
+
+    /// 追加设备实例 ID 解析出的字段，并按设备数据库补充设备与子系统名称
+    ///
+    /// * `instance_id` - 设备实例 ID，未知时不追加
+    fn with_instance_id(mut self, instance_id: &str) -> Self {
+        if instance_id.is_empty() || instance_id == "未知" {
+            return self;
+        }
+        let info = parse_device_instance_id(instance_id);
+        for (key, value) in info.rows() {
+            self.row(key, value);
+        }
+
+        let bus = Bus::from_enumerator(&info.bus_type);
+        let vendor_id = iddb::parse_id(&info.vendor_id);
+        let device_id = iddb::parse_id(&info.device_id);
+        if let (Some(bus), Some(vendor_id), Some(device_id)) = (bus, vendor_id, device_id) {
+            let db = iddb::db();
+            let device = db.bus(bus).vendor(vendor_id).device(device_id);
+            if let Some(name) = device.name() {
+                self.row("数据库名称", name);
+            }
+            if let Some(subsystem) = SubsystemId::from_instance_id(instance_id) {
+                if let Some(name) = device.subsystem(subsystem.vendor_id, subsystem.device_id) {
+                    self.row("子系统名称", name);
+                }
+            }
+        }
+        if let Some(board_vendor) = pci::resolve_vendors(instance_id).board_vendor {
+            self.row("子系统厂商", board_vendor);
+        }
+        self.instance_id = Some(instance_id.to_string());
+        self
+    }
+
+    /// 转换为 "字段名: 字段值" 形式的多行文本，用于复制
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", self.title);
+        for (key, value) in self.rows.iter() {
+            text.push_str(&format!("{}: {}\n", key, value));
+        }
+        text
+    }
+}
+
+/// 已打开的详情窗口
+struct DetailWindow {
+    id: egui::Id,
+    details: DeviceDetails,
+    open: bool,
+}
+
+/// 设备详情窗口集合，可同时打开多个设备的详情
+#[derive(Default)]
+pub struct DetailWindows {
+    windows: Vec<DetailWindow>,
+    /// 下一个窗口的序号，用于生成不重复的窗口 ID
+    next_id: usize,
+}
+
+impl DetailWindows {
+    /// 打开设备详情，同一设备的窗口已打开时更新其内容
+    ///
+    /// 带有设备实例 ID 的设备在此时读取驱动与资源信息
+    pub fn open(&mut self, details: DeviceDetails) {
+        #[cfg(windows)]
+        let details = {
+            let mut details = details;
+            if let Some(node) = details.instance_id.as_deref().and_then(devnode::query) {
+                details.rows.extend(node.rows());
+            }
+            details
+        };
+
+        let existing = self.windows.iter_mut().find(|window| {
+            window.details.title == details.title
+                && window.details.instance_id == details.instance_id
+        });
+        if let Some(window) = existing {
+            window.details = details;
+            window.open = true;
+            return;
+        }
+        self.windows.push(DetailWindow {
+            id: egui::Id::new(("device_details", self.next_id)),
+            details,
+            open: true,
+        });
+        self.next_id += 1;
+    }
+
+    /// 绘制所有打开的详情窗口，关闭的窗口随之移除
+    pub fn show(&mut self, ctx: &egui::Context) {
+        for window in self.windows.iter_mut() {
+            let details = &window.details;
+            egui::Window::new(&details.title)
+                .id(window.id)
+                .open(&mut window.open)
+                .default_width(460.0)
+                .show(ctx, |ui| {
+                    egui::ScrollArea::vertical()
+                        .max_height(420.0)
+                        .show(ui, |ui| {
+                            egui::Grid::new(window.id.with("grid"))
+                                .num_columns(2)
+                                .striped(true)
+                                .spacing([16.0, 4.0])
+                                .show(ui, |ui| {
+                                    for (key, value) in details.rows.iter() {
+                                        ui.label(format!("{}:", key));
+                                        let response = ui.add(
+                                            egui::Label::new(value)
+                                                .wrap()
+                                                .sense(egui::Sense::click()),
+                                        );
+                                        copy::context_menu(&response, key, value);
+                                        copy::focus_row(ui, &response, key, value);
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                    ui.separator();
+                    if ui.button("复制全部").clicked() {
+                        ui.ctx().copy_text(details.to_text());
+                    }
+                });
+        }
+        self.windows.retain(|window| window.open);
+    }
+}
@@ -0,0 +1,166 @@
+//! 内核驱动面板
+//!
+//! 高级模式下列出已加载的内核驱动及其版本、厂商与签名状态，默认只显示存储、显卡、网络等硬件相关驱动
+
+use crate::detector::drivers::{self, DriverCategory, KernelDriver};
+use eframe::egui;
+use std::sync::mpsc;
+
+/// 枚举结果
+type DriverResult = Result<Vec<KernelDriver>, String>;
+
+/// 驱动筛选条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DriverFilter {
+    /// 存储、显卡、网络驱动
+    Hardware,
+    /// 全部驱动
+    All,
+    /// 指定类别
+    Category(DriverCategory),
+}
+
+impl DriverFilter {
+    fn name(&self) -> String {
+        match self {
+            DriverFilter::Hardware => "只显示硬件相关".to_string(),
+            DriverFilter::All => "全部".to_string(),
+            DriverFilter::Category(category) => category.to_string(),
+        }
+    }
+
+    fn matches(&self, driver: &KernelDriver) -> bool {
+        match self {
+            DriverFilter::Hardware => driver.category.is_hardware(),
+            DriverFilter::All => true,
+            DriverFilter::Category(category) => driver.category == *category,
+        }
+    }
+}
+
+/// 内核驱动面板
+pub struct DriverPanel {
+    /// 最近一次枚举结果
+    result: Option<DriverResult>,
+    /// 枚举结果接收器，枚举进行中时存在
+    result_rx: Option<mpsc::Receiver<DriverResult>>,
+    /// 筛选条件
+    filter: DriverFilter,
+}
+
+impl Default for DriverPanel {
+    fn default() -> Self {
+        Self {
+            result: None,
+            result_rx: None,
+            filter: DriverFilter::Hardware,
+        }
+    }
+}
+
+impl DriverPanel {
+    /// 是否正在枚举
+    pub fn is_running(&self) -> bool {
+        self.result_rx.is_some()
+    }
+
+    /// 在后台线程中枚举驱动（读取版本与校验签名较慢）
+    ///
+    /// * `ctx` - 界面上下文，枚举完成后唤醒界面重绘
+    fn refresh(&mut self, ctx: &egui::Context) {
+        let (tx, rx) = mpsc::channel();
+        self.result_rx = Some(rx);
+        let ctx = ctx.clone();
+
+        std::thread::spawn(move || {
+            let result = drivers::detect_drivers().map_err(|e| e.to_string());
+            let _ = tx.send(result);
+            ctx.request_repaint();
+        });
+    }
+
+    /// 渲染面板，首次显示时开始枚举
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        if let Some(rx) = &self.result_rx {
+            if let Ok(result) = rx.try_recv() {
+                self.result = Some(result);
+                self.result_rx = None;
+            }
+        }
+        if self.result.is_none() && !self.is_running() {
+            self.refresh(ui.ctx());
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("筛选:");
+            egui::ComboBox::from_id_source("driver_filter")
+                .selected_text(self.filter.name())
+                .show_ui(ui, |ui| {
+                    let filters = [DriverFilter::Hardware, DriverFilter::All]
+                        .into_iter()
+                        .chain(DriverCategory::ALL.map(DriverFilter::Category));
+                    for filter in filters {
+                        ui.selectable_value(&mut self.filter, filter, filter.name());
+                    }
+                });
+            if ui
+                .add_enabled(!self.is_running(), egui::Button::new("刷新"))
+                .clicked()
+            {
+                self.refresh(ui.ctx());
+            }
+            if self.is_running() {
+                ui.spinner();
+            }
+        });
+        ui.separator();
+
+        match &self.result {
+            None => {
+                ui.label("正在枚举内核驱动...");
+            }
+            Some(Err(e)) => {
+                ui.colored_label(ui.visuals().error_fg_color, e);
+            }
+            Some(Ok(drivers)) => Self::render_table(ui, drivers, self.filter),
+        }
+    }
+
+    /// 渲染驱动表格
+    fn render_table(ui: &mut egui::Ui, drivers: &[KernelDriver], filter: DriverFilter) {
+        let shown: Vec<&KernelDriver> = drivers.iter().filter(|d| filter.matches(d)).collect();
+        let unsigned = shown.iter().filter(|d| d.signed == Some(false)).count();
+        ui.label(format!(
+            "显示 {} / {} 个驱动，未签名 {} 个",
+            shown.len(),
+            drivers.len(),
+            unsigned
+        ));
+
+        egui::ScrollArea::both().show(ui, |ui| {
+            egui::Grid::new("driver_grid")
+                .striped(true)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    for column in ["名称", "类别", "版本", "厂商", "签名", "描述"] {
+                        ui.strong(column);
+                    }
+                    ui.end_row();
+
+                    for driver in shown {
+                        ui.label(&driver.name).on_hover_text(&driver.path);
+                        ui.label(driver.category.to_string());
+                        ui.label(&driver.version);
+                        ui.label(&driver.company);
+                        if driver.signed == Some(false) {
+                            ui.colored_label(ui.visuals().warn_fg_color, driver.signature_desc());
+                        } else {
+                            ui.label(driver.signature_desc());
+                        }
+                        ui.label(&driver.description);
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+}
@@ -1,44 +1,111 @@
 //! 字体加载模块
 //!
-//! 提供跨平台的中文字体加载功能
+//! 提供跨平台的中文字体加载功能。系统字体都不可用时（N 版、部分语言版本的 Windows、Wine），
+//! 启用 `embedded-font` 特性后使用程序内置的精简中文字体作为最后的后备
 
 use egui::FontDefinitions;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
-/// 获取系统可用的中文字体路径列表
+/// 内置字体在字体表中的名称
+const EMBEDDED_FONT_NAME: &str = "内置字体";
+
+/// 字体选择
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FontChoice {
+    /// 自动：依次尝试系统中文字体，都不可用时使用内置字体
+    #[default]
+    Auto,
+    /// 指定的系统字体（候选字体名称，如 "微软雅黑"），不可用时按自动顺序回退
+    System(String),
+    /// 内置字体
+    Embedded,
+}
+
+impl FontChoice {
+    /// 获取选择的名称
+    pub fn name(&self) -> &str {
+        match self {
+            FontChoice::Auto => "自动",
+            FontChoice::System(name) => name,
+            FontChoice::Embedded => EMBEDDED_FONT_NAME,
+        }
+    }
+}
+
+/// 中文字体候选
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontCandidate {
+    /// 字体名称，如 "微软雅黑"
+    pub name: &'static str,
+    /// 字体文件路径
+    pub path: PathBuf,
+}
+
+impl FontCandidate {
+    fn new(name: &'static str, path: impl Into<PathBuf>) -> Self {
+        Self {
+            name,
+            path: path.into(),
+        }
+    }
+}
+
+/// 获取系统可用的中文字体候选列表，按优先级排列
 ///
-/// 根据不同的操作系统返回相应平台的中文字体路径
-pub fn get_chinese_font_paths() -> Vec<&'static str> {
+/// Windows 下字体目录取自 `%WINDIR%`，系统不在 C 盘时同样可用
+pub fn font_candidates() -> Vec<FontCandidate> {
     #[cfg(target_os = "windows")]
     {
-        vec![
-            "C:\\Windows\\Fonts\\msyh.ttc",    // 微软雅黑
-            "C:\\Windows\\Fonts\\msyhbd.ttc",  // 微软雅黑粗体
-            "C:\\Windows\\Fonts\\simsun.ttc",  // 宋体
-            "C:\\Windows\\Fonts\\simhei.ttf",  // 黑体
-            "C:\\Windows\\Fonts\\simkai.ttf",  // 楷体
-            "C:\\Windows\\Fonts\\simfang.ttf", // 仿宋
+        let dir = std::env::var_os("WINDIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("C:\\Windows"))
+            .join("Fonts");
+        [
+            ("微软雅黑", "msyh.ttc"),
+            ("微软正黑体", "msjh.ttc"),
+            ("等线", "Deng.ttf"),
+            ("宋体", "simsun.ttc"),
+            ("黑体", "simhei.ttf"),
+            ("楷体", "simkai.ttf"),
+            ("仿宋", "simfang.ttf"),
         ]
+        .into_iter()
+        .map(|(name, file)| FontCandidate::new(name, dir.join(file)))
+        .collect()
     }
 
     #[cfg(target_os = "macos")]
     {
         vec![
-            "/System/Library/Fonts/PingFang.ttc",      // 苹方
-            "/System/Library/Fonts/STHeiti Light.ttc", // 华文黑体
-            "/System/Library/Fonts/STSong.ttc",        // 华文宋体
-            "/System/Library/Fonts/STKaiti.ttc",       // 华文楷体
-            "/Library/Fonts/Arial Unicode.ttf",        // Arial Unicode
+            FontCandidate::new("苹方", "/System/Library/Fonts/PingFang.ttc"),
+            FontCandidate::new("华文黑体", "/System/Library/Fonts/STHeiti Light.ttc"),
+            FontCandidate::new("华文宋体", "/System/Library/Fonts/STSong.ttc"),
+            FontCandidate::new("华文楷体", "/System/Library/Fonts/STKaiti.ttc"),
+            FontCandidate::new("Arial Unicode", "/Library/Fonts/Arial Unicode.ttf"),
         ]
     }
 
     #[cfg(target_os = "linux")]
     {
         vec![
-            "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc", // 文泉驿微米黑
-            "/usr/share/fonts/truetype/wqy/wqy-zenhei.ttc",   // 文泉驿正黑
-            "/usr/share/fonts/truetype/droid/DroidSansFallbackFull.ttf",
-            "/usr/share/fonts/truetype/noto/NotoSansCJK-Regular.ttc", // Noto Sans CJK
-            "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+            FontCandidate::new(
+                "文泉驿微米黑",
+                "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc",
+            ),
+            FontCandidate::new("文泉驿正黑", "/usr/share/fonts/truetype/wqy/wqy-zenhei.ttc"),
+            FontCandidate::new(
+                "Droid Sans Fallback",
+                "/usr/share/fonts/truetype/droid/DroidSansFallbackFull.ttf",
+            ),
+            FontCandidate::new(
+                "Noto Sans CJK",
+                "/usr/share/fonts/truetype/noto/NotoSansCJK-Regular.ttc",
+            ),
+            FontCandidate::new(
+                "Noto Sans CJK",
+                "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+            ),
         ]
     }
 
@@ -49,46 +116,151 @@ pub fn get_chinese_font_paths() -> Vec<&'static str> {
     }
 }
 
-/// 设置中文字体
+/// 获取系统可用的中文字体路径列表
+///
+/// 根据不同的操作系统返回相应平台的中文字体路径
+pub fn get_chinese_font_paths() -> Vec<PathBuf> {
+    font_candidates()
+        .into_iter()
+        .map(|candidate| candidate.path)
+        .collect()
+}
+
+/// 系统中实际存在的中文字体，用于设置中的字体选择
+pub fn available_fonts() -> Vec<FontCandidate> {
+    let mut fonts: Vec<FontCandidate> = font_candidates()
+        .into_iter()
+        .filter(|candidate| candidate.path.is_file())
+        .collect();
+    fonts.dedup_by(|a, b| a.name == b.name);
+    fonts
+}
+
+/// 按字体选择确定系统字体的尝试顺序
+///
+/// 指定的字体排在最前，其余候选按原顺序回退；选择内置字体时不尝试系统字体
+///
+/// 示例
+/// ```
+/// use hardware_master::ui::font::{font_order, FontCandidate, FontChoice};
+/// let candidates = vec![
+///     FontCandidate { name: "微软雅黑", path: "msyh.ttc".into() },
+///     FontCandidate { name: "宋体", path: "simsun.ttc".into() },
+/// ];
+/// let names = |choice: &FontChoice| -> Vec<&str> {
+///     font_order(choice, &candidates).iter().map(|c| c.name).collect()
+/// };
+/// assert_eq!(names(&FontChoice::Auto), ["微软雅黑", "宋体"]);
+/// assert_eq!(names(&FontChoice::System("宋体".to_string())), ["宋体", "微软雅黑"]);
+/// assert!(names(&FontChoice::Embedded).is_empty());
+/// ```
+pub fn font_order<'a>(
+    choice: &FontChoice,
+    candidates: &'a [FontCandidate],
+) -> Vec<&'a FontCandidate> {
+    match choice {
+        FontChoice::Auto => candidates.iter().collect(),
+        FontChoice::System(name) => {
+            let (mut preferred, rest): (Vec<_>, Vec<_>) = candidates
+                .iter()
+                .partition(|candidate| candidate.name == name);
+            preferred.extend(rest);
+            preferred
+        }
+        FontChoice::Embedded => Vec::new(),
+    }
+}
+
+/// 内置的精简中文字体，未启用 `embedded-font` 特性时为 None
+pub fn embedded_font() -> Option<&'static [u8]> {
+    #[cfg(feature = "embedded-font")]
+    {
+        Some(include_bytes!("../assets/fonts/fallback-cjk.otf"))
+    }
+
+    #[cfg(not(feature = "embedded-font"))]
+    {
+        None
+    }
+}
+
+/// 将字体加入字体表：比例字体中优先使用，等宽字体中作为后备
+fn install_font(fonts: &mut FontDefinitions, name: &str, data: egui::FontData) {
+    fonts.font_data.insert(name.to_string(), data);
+    fonts
+        .families
+        .entry(egui::FontFamily::Proportional)
+        .or_default()
+        .insert(0, name.to_string());
+    fonts
+        .families
+        .entry(egui::FontFamily::Monospace)
+        .or_default()
+        .push(name.to_string());
+}
+
+/// 按字体选择加载中文字体
 ///
-/// 尝试加载系统中的中文字体，如果加载失败则使用默认字体
+/// 依次尝试系统字体，都不可用时使用内置字体（需要启用 `embedded-font` 特性）
 ///
 /// # 参数
 /// * `ctx` - egui 上下文
+/// * `choice` - 字体选择
 ///
 /// # 返回值
-/// 返回是否成功加载了中文字体
-pub fn setup_chinese_fonts(ctx: &egui::Context) -> bool {
+/// 返回实际加载的字体名称，没有可用的中文字体时返回 None
+pub fn setup_fonts(ctx: &egui::Context, choice: &FontChoice) -> Option<String> {
+    // 未内置字体时，选择内置字体按自动处理
+    let choice = match choice {
+        FontChoice::Embedded if embedded_font().is_none() => &FontChoice::Auto,
+        choice => choice,
+    };
     let mut fonts = FontDefinitions::default();
-    let font_paths = get_chinese_font_paths();
-
-    let mut font_loaded = false;
-
-    for font_path in font_paths {
-        if let Ok(font_data) = std::fs::read(font_path) {
-            fonts
-                .font_data
-                .insert(font_path.to_string(), egui::FontData::from_owned(font_data));
-            fonts
-                .families
-                .entry(egui::FontFamily::Proportional)
-                .or_default()
-                .insert(0, font_path.to_string());
-            fonts
-                .families
-                .entry(egui::FontFamily::Monospace)
-                .or_default()
-                .push(font_path.to_string());
-            font_loaded = true;
-            log::info!("成功加载中文字体: {}", font_path);
+    let candidates = font_candidates();
+
+    let mut loaded = None;
+    for candidate in font_order(choice, &candidates) {
+        if let Ok(font_data) = std::fs::read(&candidate.path) {
+            install_font(
+                &mut fonts,
+                candidate.name,
+                egui::FontData::from_owned(font_data),
+            );
+            log::info!("成功加载中文字体: {}", candidate.path.display());
+            loaded = Some(candidate.name.to_string());
             break;
         }
     }
 
-    if !font_loaded {
+    if loaded.is_none() {
+        if let Some(font_data) = embedded_font() {
+            install_font(
+                &mut fonts,
+                EMBEDDED_FONT_NAME,
+                egui::FontData::from_static(font_data),
+            );
+            log::info!("使用内置中文字体");
+            loaded = Some(EMBEDDED_FONT_NAME.to_string());
+        }
+    }
+
+    if loaded.is_none() {
         log::warn!("无法加载中文字体，中文可能显示为方框");
     }
 
     ctx.set_fonts(fonts);
-    font_loaded
+    loaded
+}
+
+/// 设置中文字体
+///
+/// 尝试加载系统中的中文字体，如果加载失败则使用内置字体或默认字体
+///
+/// # 参数
+/// * `ctx` - egui 上下文
+///
+/// # 返回值
+/// 返回是否成功加载了中文字体
+pub fn setup_chinese_fonts(ctx: &egui::Context) -> bool {
+    setup_fonts(ctx, &FontChoice::Auto).is_some()
 }
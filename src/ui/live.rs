@@ -0,0 +1,362 @@
+//! 实时面板
+//!
+//! 在后台线程中周期性采样 CPU 占用与频率、硬盘 IO 活动、显存占用与进程资源占用，
+//! 帮助判断系统卡顿是否由硬盘、显存不足或某个进程造成；同时读取温度、风扇与电压传感器
+
+use crate::constants::{LIVE_SAMPLE_INTERVAL_MS, SENSOR_SAMPLE_EVERY, TOP_PROCESS_COUNT};
+use crate::detector::cpu::{format_mhz, CpuInfo};
+use crate::detector::processes::{self, ProcessSampler, ProcessUsage};
+use crate::detector::sensors::{self, SensorReading};
+use crate::realtime::cpu_load::{CpuLoad, CpuLoadSampler};
+use crate::realtime::disk_io::{DiskIoSampler, DiskIoStats};
+use crate::realtime::gpu_memory::{GpuMemorySampler, GpuMemoryUsage};
+use crate::utils::string::format_size;
+use eframe::egui;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+/// 单次采样结果
+struct LiveSample {
+    /// CPU 占用与频率，采样失败时为 None
+    cpu: Option<CpuLoad>,
+    /// 硬盘 IO 活动
+    disks: Result<Vec<DiskIoStats>, String>,
+    /// 显存占用，显卡不支持查询时为空
+    gpus: Vec<GpuMemoryUsage>,
+    /// 进程资源占用，枚举失败时为空
+    processes: Vec<ProcessUsage>,
+    /// 传感器读数，本次未读取传感器时为 None
+    sensors: Option<Vec<SensorReading>>,
+}
+
+/// 实时面板
+#[derive(Default)]
+pub struct LiveMonitor {
+    /// 采样结果接收器，采样进行中时存在
+    sample_rx: Option<mpsc::Receiver<LiveSample>>,
+    /// 通知采样线程退出
+    stop_flag: Arc<AtomicBool>,
+    /// 最近一次 CPU 占用与频率
+    cpu: Option<CpuLoad>,
+    /// 最近一次采样结果
+    disks: Vec<DiskIoStats>,
+    /// 最近一次显存占用
+    gpus: Vec<GpuMemoryUsage>,
+    /// 最近一次进程资源占用
+    processes: Vec<ProcessUsage>,
+    /// 最近一次传感器读数
+    sensors: Vec<SensorReading>,
+    /// 最近一次采样错误
+    error: Option<String>,
+}
+
+impl LiveMonitor {
+    /// 是否正在采样
+    pub fn is_running(&self) -> bool {
+        self.sample_rx.is_some()
+    }
+
+    /// 启动后台采样，已在采样时不重复启动
+    ///
+    /// * `ctx` - 界面上下文，每次采样完成后唤醒界面重绘
+    pub fn start(&mut self, ctx: &egui::Context) {
+        if self.is_running() {
+            return;
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        self.sample_rx = Some(rx);
+        self.stop_flag = Arc::clone(&stop_flag);
+
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let mut sampler = match DiskIoSampler::new() {
+                Ok(sampler) => sampler,
+                Err(e) => {
+                    let _ = tx.send(LiveSample {
+                        cpu: None,
+                        disks: Err(e),
+                        gpus: Vec::new(),
+                        processes: Vec::new(),
+                        sensors: None,
+                    });
+                    ctx.request_repaint();
+                    return;
+                }
+            };
+            let gpu_sampler = GpuMemorySampler::new()
+                .map_err(|e| log::warn!("{}", e))
+                .ok();
+            let mut cpu_sampler = CpuLoadSampler::new().map_err(|e| log::warn!("{}", e)).ok();
+            let mut process_sampler = ProcessSampler::new();
+            let mut round = 0u32;
+            while !stop_flag.load(Ordering::Relaxed) {
+                std::thread::sleep(Duration::from_millis(LIVE_SAMPLE_INTERVAL_MS));
+                // 首次采样即读取传感器，之后每隔若干次读取一次
+                let read_sensors = round % SENSOR_SAMPLE_EVERY == 0;
+                round = round.wrapping_add(1);
+                let sample = LiveSample {
+                    cpu: cpu_sampler
+                        .as_mut()
+                        .and_then(|cpu_sampler| cpu_sampler.sample().ok()),
+                    disks: sampler.sample(),
+                    gpus: gpu_sampler
+                        .as_ref()
+                        .map(|gpu_sampler| gpu_sampler.sample())
+                        .unwrap_or_default(),
+                    processes: process_sampler.sample().unwrap_or_else(|e| {
+                        log::warn!("{}", e);
+                        Vec::new()
+                    }),
+                    sensors: read_sensors.then(sensors::read_sensors),
+                };
+                if tx.send(sample).is_err() {
+                    break;
+                }
+                ctx.request_repaint();
+            }
+        });
+    }
+
+    /// 停止后台采样
+    pub fn stop(&mut self) {
+        self.stop_flag.store(true, Ordering::Relaxed);
+        self.sample_rx = None;
+    }
+
+    /// 渲染实时面板
+    ///
+    /// * `cpu_info` - 检测到的处理器信息，用于对比基准频率与最大频率
+    pub fn show(&mut self, ui: &mut egui::Ui, cpu_info: &CpuInfo) {
+        if let Some(rx) = &self.sample_rx {
+            while let Ok(sample) = rx.try_recv() {
+                self.cpu = sample.cpu;
+                self.gpus = sample.gpus;
+                self.processes = sample.processes;
+                if let Some(sensors) = sample.sensors {
+                    self.sensors = sensors;
+                }
+                match sample.disks {
+                    Ok(disks) => {
+                        self.disks = disks;
+                        self.error = None;
+                    }
+                    Err(e) => self.error = Some(e),
+                }
+            }
+        }
+
+        self.show_cpu(ui, cpu_info);
+        self.show_sensors(ui);
+
+        ui.heading("硬盘活动");
+        if let Some(e) = &self.error {
+            ui.colored_label(ui.visuals().error_fg_color, e);
+        }
+        if self.disks.is_empty() {
+            ui.label("正在采样...");
+            return;
+        }
+
+        egui::Grid::new("disk_io_grid")
+            .num_columns(5)
+            .spacing([20.0, 8.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("磁盘");
+                ui.strong("活动时间");
+                ui.strong("读取");
+                ui.strong("写入");
+                ui.strong("队列");
+                ui.end_row();
+
+                for disk in self.disks.iter() {
+                    ui.label(&disk.instance);
+                    ui.add(
+                        egui::ProgressBar::new((disk.busy_percent / 100.0) as f32)
+                            .desired_width(120.0)
+                            .text(format!("{:.0}%", disk.busy_percent)),
+                    );
+                    ui.label(format!("{}/s", format_size(disk.read_bytes_per_sec)));
+                    ui.label(format!("{}/s", format_size(disk.write_bytes_per_sec)));
+                    let queue = ui.label(format!("{:.0}", disk.queue_length));
+                    if disk.is_busy() {
+                        queue.on_hover_text("硬盘繁忙，系统卡顿可能由硬盘读写造成");
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if self.disks.iter().any(|disk| disk.is_busy()) {
+            ui.add_space(6.0);
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                "⚠ 有硬盘处于繁忙状态，系统卡顿可能由硬盘读写造成",
+            );
+        }
+
+        self.show_gpu_memory(ui);
+        self.show_processes(ui);
+    }
+
+    /// 渲染 CPU 占用与实时频率
+    fn show_cpu(&self, ui: &mut egui::Ui, cpu_info: &CpuInfo) {
+        let Some(cpu) = &self.cpu else {
+            return;
+        };
+
+        ui.heading("处理器");
+        egui::Grid::new("cpu_load_grid")
+            .num_columns(2)
+            .spacing([20.0, 8.0])
+            .show(ui, |ui| {
+                ui.label("占用率");
+                ui.add(
+                    egui::ProgressBar::new((cpu.usage_percent / 100.0) as f32)
+                        .desired_width(160.0)
+                        .text(format!("{:.0}%", cpu.usage_percent)),
+                );
+                ui.end_row();
+
+                if let Some(mhz) = cpu.frequency_mhz {
+                    ui.label("当前频率");
+                    let label = ui.label(format_mhz(mhz));
+                    if let Some(performance) = cpu.performance_percent {
+                        label.on_hover_text(format!(
+                            "基准频率的 {:.0}%，超过 100% 表示处于睿频状态",
+                            performance
+                        ));
+                    }
+                    ui.end_row();
+                }
+
+                if cpu_info.base_frequency > 0 {
+                    ui.label("基准 / 最大");
+                    ui.label(format!(
+                        "{} / {}",
+                        format_mhz(cpu_info.base_frequency as f64),
+                        format_mhz(cpu_info.max_frequency as f64)
+                    ));
+                    ui.end_row();
+                }
+
+                if let Some(temperature) = cpu.temperature {
+                    ui.label("温度");
+                    ui.label(format!("{:.0}°C", temperature));
+                    ui.end_row();
+                }
+            });
+        ui.add_space(12.0);
+    }
+
+    /// 渲染温度、风扇与电压传感器，没有可用传感器时不显示
+    fn show_sensors(&self, ui: &mut egui::Ui) {
+        if self.sensors.is_empty() {
+            return;
+        }
+
+        ui.heading("传感器");
+        egui::Grid::new("sensor_grid")
+            .num_columns(4)
+            .spacing([20.0, 8.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("类型");
+                ui.strong("名称");
+                ui.strong("读数");
+                ui.strong("来源");
+                ui.end_row();
+
+                for reading in self.sensors.iter() {
+                    ui.label(reading.kind.to_string());
+                    ui.label(&reading.name);
+                    ui.label(reading.value_desc());
+                    ui.label(&reading.source);
+                    ui.end_row();
+                }
+            });
+        ui.add_space(12.0);
+    }
+
+    /// 渲染显存占用
+    fn show_gpu_memory(&self, ui: &mut egui::Ui) {
+        if self.gpus.is_empty() {
+            return;
+        }
+
+        ui.add_space(12.0);
+        ui.heading("显存占用");
+        egui::Grid::new("gpu_memory_grid")
+            .num_columns(3)
+            .spacing([20.0, 8.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("显卡");
+                ui.strong("显存");
+                ui.strong("共享内存");
+                ui.end_row();
+
+                for gpu in self.gpus.iter() {
+                    ui.label(&gpu.description);
+                    ui.add(
+                        egui::ProgressBar::new((gpu.local_percent() / 100.0) as f32)
+                            .desired_width(160.0)
+                            .text(format!(
+                                "{} / {}",
+                                format_size(gpu.local_usage as f64),
+                                format_size(gpu.local_budget as f64)
+                            )),
+                    );
+                    ui.label(format_size(gpu.shared_usage as f64));
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// 渲染 CPU 与内存占用最高的进程
+    fn show_processes(&self, ui: &mut egui::Ui) {
+        if self.processes.is_empty() {
+            return;
+        }
+
+        ui.add_space(12.0);
+        ui.heading("资源占用最高的进程");
+        ui.columns(2, |columns| {
+            columns[0].strong("CPU");
+            egui::Grid::new("top_cpu_grid")
+                .num_columns(2)
+                .spacing([20.0, 6.0])
+                .striped(true)
+                .show(&mut columns[0], |ui| {
+                    for process in processes::top_by_cpu(&self.processes, TOP_PROCESS_COUNT) {
+                        ui.label(&process.name)
+                            .on_hover_text(format!("PID: {}", process.pid));
+                        ui.label(format!("{:.1}%", process.cpu_percent));
+                        ui.end_row();
+                    }
+                });
+
+            columns[1].strong("内存");
+            egui::Grid::new("top_memory_grid")
+                .num_columns(2)
+                .spacing([20.0, 6.0])
+                .striped(true)
+                .show(&mut columns[1], |ui| {
+                    for process in processes::top_by_memory(&self.processes, TOP_PROCESS_COUNT) {
+                        ui.label(&process.name)
+                            .on_hover_text(format!("PID: {}", process.pid));
+                        ui.label(format_size(process.working_set as f64));
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+}
+
+impl Drop for LiveMonitor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
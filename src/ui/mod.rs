@@ -2,10 +2,33 @@
 //!
 //! 使用 egui 提供用户界面
 
+#[cfg(windows)]
 pub mod app;
+pub mod asset;
+#[cfg(windows)]
+pub mod benchmark;
+pub mod chart;
+pub mod copy;
+pub mod details;
+#[cfg(windows)]
+pub mod drivers;
 pub mod font;
+#[cfg(windows)]
+pub mod live;
+#[cfg(windows)]
+pub mod peripherals;
+pub mod screenshot;
+pub mod search;
+pub mod tab;
 pub mod theme;
+pub mod timings;
+#[cfg(windows)]
+pub mod tray;
+#[cfg(windows)]
+pub mod wmi_console;
 
+#[cfg(windows)]
 pub use app::HardwareMasterApp;
 pub use font::setup_chinese_fonts;
+pub use tab::AppTab;
 pub use theme::AppTheme;
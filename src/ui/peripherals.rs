@@ -0,0 +1,162 @@
+//! 外设面板
+//!
+//! 列出键盘、鼠标等输入设备，已安装的打印机与扫描仪等图像设备，以及它们的连接方式与驱动
+
+use crate::detector::peripherals::{self, PeripheralInfo};
+use eframe::egui;
+use std::sync::mpsc;
+
+/// 检测结果
+type PeripheralResult = Result<PeripheralInfo, String>;
+
+/// 外设面板
+#[derive(Default)]
+pub struct PeripheralPanel {
+    /// 最近一次检测结果
+    result: Option<PeripheralResult>,
+    /// 检测结果接收器，检测进行中时存在
+    result_rx: Option<mpsc::Receiver<PeripheralResult>>,
+}
+
+impl PeripheralPanel {
+    /// 是否正在检测
+    pub fn is_running(&self) -> bool {
+        self.result_rx.is_some()
+    }
+
+    /// 在后台线程中检测外设（WMI 查询签名驱动较慢）
+    ///
+    /// * `ctx` - 界面上下文，检测完成后唤醒界面重绘
+    fn refresh(&mut self, ctx: &egui::Context) {
+        let (tx, rx) = mpsc::channel();
+        self.result_rx = Some(rx);
+        let ctx = ctx.clone();
+
+        std::thread::spawn(move || {
+            let result = peripherals::detect_peripherals().map_err(|e| e.to_string());
+            let _ = tx.send(result);
+            ctx.request_repaint();
+        });
+    }
+
+    /// 渲染面板，首次显示时开始检测
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        if let Some(rx) = &self.result_rx {
+            if let Ok(result) = rx.try_recv() {
+                self.result = Some(result);
+                self.result_rx = None;
+            }
+        }
+        if self.result.is_none() && !self.is_running() {
+            self.refresh(ui.ctx());
+        }
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.is_running(), egui::Button::new("刷新"))
+                .clicked()
+            {
+                self.refresh(ui.ctx());
+            }
+            if self.is_running() {
+                ui.spinner();
+            }
+        });
+        ui.separator();
+
+        match &self.result {
+            None => {
+                ui.label("正在检测外设...");
+            }
+            Some(Err(e)) => {
+                ui.colored_label(ui.visuals().error_fg_color, e);
+            }
+            Some(Ok(info)) => {
+                egui::ScrollArea::both().show(ui, |ui| Self::render_tables(ui, info));
+            }
+        }
+    }
+
+    /// 渲染输入设备、打印机与图像设备表格
+    fn render_tables(ui: &mut egui::Ui, info: &PeripheralInfo) {
+        ui.heading(format!("输入设备 ({})", info.input_devices.len()));
+        if info.input_devices.is_empty() {
+            ui.label("未检测到输入设备");
+        } else {
+            egui::Grid::new("input_device_grid")
+                .striped(true)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    for column in ["类型", "名称", "厂商", "连接", "VID:PID"] {
+                        ui.strong(column);
+                    }
+                    ui.end_row();
+
+                    for device in info.input_devices.iter() {
+                        ui.label(device.kind.to_string());
+                        ui.label(&device.name).on_hover_text(&device.device_path);
+                        ui.label(&device.vendor);
+                        ui.label(device.connection.to_string());
+                        ui.label(match device.usb_ids {
+                            Some((vid, pid)) => format!("{:04X}:{:04X}", vid, pid),
+                            None => "-".to_string(),
+                        });
+                        ui.end_row();
+                    }
+                });
+        }
+
+        ui.add_space(12.0);
+        ui.heading(format!("打印机 ({})", info.printers.len()));
+        if info.printers.is_empty() {
+            ui.label("未检测到打印机");
+        } else {
+            egui::Grid::new("printer_grid")
+                .striped(true)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    for column in ["名称", "连接", "端口", "驱动", "状态"] {
+                        ui.strong(column);
+                    }
+                    ui.end_row();
+
+                    for printer in info.printers.iter() {
+                        ui.label(&printer.name);
+                        ui.label(printer.connection.to_string());
+                        ui.label(&printer.port);
+                        ui.label(&printer.driver);
+                        if printer.offline {
+                            ui.colored_label(ui.visuals().warn_fg_color, printer.status_desc());
+                        } else {
+                            ui.label(printer.status_desc());
+                        }
+                        ui.end_row();
+                    }
+                });
+        }
+
+        ui.add_space(12.0);
+        ui.heading(format!("扫描仪与图像设备 ({})", info.imaging_devices.len()));
+        if info.imaging_devices.is_empty() {
+            ui.label("未检测到扫描仪");
+        } else {
+            egui::Grid::new("imaging_grid")
+                .striped(true)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    for column in ["名称", "连接", "制造商", "驱动版本"] {
+                        ui.strong(column);
+                    }
+                    ui.end_row();
+
+                    for device in info.imaging_devices.iter() {
+                        ui.label(&device.name).on_hover_text(&device.device_id);
+                        ui.label(device.connection.to_string());
+                        ui.label(&device.manufacturer);
+                        ui.label(&device.driver_version);
+                        ui.end_row();
+                    }
+                });
+        }
+    }
+}
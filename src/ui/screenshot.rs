@@ -0,0 +1,80 @@
+//! 截图模块
+//!
+//! 通过视口截图获取窗口画面，裁剪出硬件概览区域后编码为 PNG，便于像鲁大师截图那样分享
+
+use eframe::egui;
+use image::ImageEncoder;
+
+/// 裁剪截图中的指定区域并编码为 PNG
+///
+/// * `image` - 整个窗口的截图
+/// * `region` - 裁剪区域（逻辑坐标），超出截图的部分会被截掉
+/// * `pixels_per_point` - 界面缩放比例
+///
+/// 示例
+/// ```
+/// use eframe::egui::{pos2, Color32, ColorImage, Rect};
+/// use hardware_master::ui::screenshot::encode_region_png;
+/// let image = ColorImage::new([40, 30], Color32::WHITE);
+/// let region = Rect::from_min_max(pos2(5.0, 5.0), pos2(100.0, 20.0));
+/// let png = encode_region_png(&image, region, 1.0).unwrap();
+/// assert!(png.starts_with(b"\x89PNG"));
+/// assert!(encode_region_png(&image, Rect::NOTHING, 1.0).is_err());
+/// ```
+pub fn encode_region_png(
+    image: &egui::ColorImage,
+    region: egui::Rect,
+    pixels_per_point: f32,
+) -> Result<Vec<u8>, String> {
+    let [width, height] = image.size;
+    let to_pixel = |value: f32, max: usize| {
+        let pixel = (value * pixels_per_point).round();
+        if pixel.is_nan() || pixel <= 0.0 {
+            0
+        } else {
+            (pixel as usize).min(max)
+        }
+    };
+    let (min_x, max_x) = (to_pixel(region.min.x, width), to_pixel(region.max.x, width));
+    let (min_y, max_y) = (
+        to_pixel(region.min.y, height),
+        to_pixel(region.max.y, height),
+    );
+    if min_x >= max_x || min_y >= max_y {
+        return Err("截图区域为空".to_string());
+    }
+
+    let (crop_width, crop_height) = (max_x - min_x, max_y - min_y);
+    let mut rgba = Vec::with_capacity(crop_width * crop_height * 4);
+    for y in min_y..max_y {
+        for pixel in &image.pixels[y * width + min_x..y * width + max_x] {
+            rgba.extend_from_slice(&pixel.to_srgba_unmultiplied());
+        }
+    }
+
+    let mut png = Vec::new();
+    image::codecs::png::PngEncoder::new(&mut png)
+        .write_image(
+            &rgba,
+            crop_width as u32,
+            crop_height as u32,
+            image::ExtendedColorType::Rgba8,
+        )
+        .map_err(|e| format!("编码 PNG 失败: {}", e))?;
+    Ok(png)
+}
+
+/// 截图的默认文件名
+///
+/// * `time` - 截图时间
+///
+/// 示例
+/// ```
+/// use chrono::TimeZone;
+/// use hardware_master::ui::screenshot::default_file_name;
+/// let time = chrono::Local.with_ymd_and_hms(2024, 10, 16, 9, 5, 0).unwrap();
+/// assert_eq!(default_file_name(time), "硬件概览_20241016_0905.png");
+/// ```
+pub fn default_file_name(time: chrono::DateTime<chrono::Local>) -> String {
+    format!("硬件概览_{}.png", time.format("%Y%m%d_%H%M"))
+}
@@ -0,0 +1,440 @@
+//! 字段搜索模块
+//!
+//! 在所有检测到的字段名与字段值中模糊查找（如“序列号”、“MAC”、“BIOS”），
+//! 选中结果后跳转到概览页并高亮对应的行
+
+use crate::detector::HardwareDetector;
+use crate::report::{build_sections, ReportSection};
+use crate::utils::string::mask_sensitive;
+use eframe::egui;
+
+/// 最多显示的搜索结果数
+const MAX_RESULTS: usize = 20;
+
+/// 跳转后高亮持续的时间（秒）
+const HIGHLIGHT_SECS: f64 = 2.5;
+
+/// 字段名命中时额外加的分数，用户通常按字段名查找
+const KEY_BONUS: u32 = 20;
+
+/// 主板详情章节标题，与概览中的折叠标题一致
+pub const MOTHERBOARD_DETAILS: &str = "主板详情";
+
+/// 电池详情章节标题，与概览中的折叠标题一致
+pub const BATTERY_DETAILS: &str = "电池详情";
+
+/// 可搜索的字段
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchEntry {
+    /// 所属章节，如 "网卡"
+    pub section: String,
+    /// 字段名，如 "MAC 地址"
+    pub key: String,
+    /// 字段值
+    pub value: String,
+}
+
+impl SearchEntry {
+    /// 跳转目标
+    pub fn target(&self) -> SearchTarget {
+        SearchTarget {
+            section: self.section.clone(),
+            key: self.key.clone(),
+        }
+    }
+}
+
+/// 跳转目标
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTarget {
+    /// 所属章节
+    pub section: String,
+    /// 字段名
+    pub key: String,
+}
+
+/// 字段索引
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndex {
+    entries: Vec<SearchEntry>,
+}
+
+impl SearchIndex {
+    /// 由报告章节生成索引
+    pub fn from_sections(sections: &[ReportSection]) -> Self {
+        let entries = sections
+            .iter()
+            .flat_map(|section| {
+                section.rows.iter().map(|(key, value)| SearchEntry {
+                    section: section.title.clone(),
+                    key: key.clone(),
+                    value: value.clone(),
+                })
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// 由检测结果生成索引，包含报告中的全部字段以及主板、电池详情中的序列号
+    ///
+    /// * `detector` - 检测结果
+    /// * `reveal_sensitive` - 是否显示序列号等敏感信息，否则以遮盖后的值参与搜索
+    pub fn build(detector: &HardwareDetector, reveal_sensitive: bool) -> Self {
+        let mut index = Self::from_sections(&build_sections(detector));
+        let sensitive = |value: &str| {
+            if reveal_sensitive {
+                value.to_string()
+            } else {
+                mask_sensitive(value)
+            }
+        };
+
+        let board = &detector.motherboard_info;
+        for (key, value) in [
+            ("主板序列号", &board.serial_number),
+            ("BIOS 序列号", &board.bios_serial),
+            ("系统 UUID", &board.system_uuid),
+            ("主板资产标签", &board.asset_tag),
+            ("机箱序列号", &board.chassis_serial),
+        ] {
+            index.push(MOTHERBOARD_DETAILS, key, sensitive(value));
+        }
+        for battery in detector.battery_info.batteries.iter() {
+            index.push(BATTERY_DETAILS, "序列号", sensitive(&battery.serial_number));
+        }
+        index
+    }
+
+    fn push(&mut self, section: &str, key: &str, value: String) {
+        self.entries.push(SearchEntry {
+            section: section.to_string(),
+            key: key.to_string(),
+            value,
+        });
+    }
+
+    /// 全部字段
+    pub fn entries(&self) -> &[SearchEntry] {
+        &self.entries
+    }
+
+    /// 按匹配度从高到低返回命中的字段，匹配度相同时保持原有顺序
+    ///
+    /// 查询按空白拆分为多个词，每个词都须命中字段名、字段值或章节名之一
+    ///
+    /// * `query` - 查询文本，为空时不返回结果
+    /// * `limit` - 最多返回的结果数
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&SearchEntry> {
+        let terms: Vec<&str> = query.split_whitespace().collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        let mut hits: Vec<(u32, &SearchEntry)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                terms
+                    .iter()
+                    .map(|term| {
+                        let key = fuzzy_score(term, &entry.key).map(|score| score + KEY_BONUS);
+                        let value = fuzzy_score(term, &entry.value);
+                        let section = fuzzy_score(term, &entry.section);
+                        key.max(value).max(section)
+                    })
+                    .sum::<Option<u32>>()
+                    .map(|score| (score, entry))
+            })
+            .collect();
+        hits.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        hits.into_iter()
+            .take(limit)
+            .map(|(_, entry)| entry)
+            .collect()
+    }
+}
+
+/// 模糊匹配得分，未命中时返回 `None`，得分越高越匹配
+///
+/// 忽略大小写；完全相同最高，其次为前缀、子串，查询的字符按顺序出现在文本中也算命中，
+/// 字符间隔越大得分越低
+///
+/// 示例
+/// ```
+/// use hardware_master::ui::search::fuzzy_score;
+/// assert!(fuzzy_score("mac", "MAC 地址") > fuzzy_score("mac", "Realtek MAC"));
+/// assert!(fuzzy_score("序列号", "主板序列号").is_some());
+/// assert!(fuzzy_score("bsn", "BIOS 序列号").is_none());
+/// assert!(fuzzy_score("bios序", "BIOS 序列号").is_some());
+/// ```
+pub fn fuzzy_score(query: &str, text: &str) -> Option<u32> {
+    let query = query.to_lowercase();
+    let text = text.to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+    if text == query {
+        return Some(300);
+    }
+    if text.starts_with(&query) {
+        return Some(200);
+    }
+    if let Some(position) = text.find(&query) {
+        let position = text[..position].chars().count() as u32;
+        return Some(150 - position.min(50));
+    }
+
+    // 按顺序查找查询中的每个字符，累计跳过的字符数
+    let mut chars = text.chars();
+    let mut gaps = 0u32;
+    for wanted in query.chars().filter(|c| !c.is_whitespace()) {
+        let mut skipped = 0u32;
+        loop {
+            match chars.next() {
+                Some(c) if c == wanted => break,
+                Some(_) => skipped += 1,
+                None => return None,
+            }
+        }
+        gaps += skipped;
+    }
+    Some(50u32.saturating_sub(gaps).max(1))
+}
+
+/// 概览中的区块标题对应的报告章节
+///
+/// 示例
+/// ```
+/// use hardware_master::ui::search::overview_section;
+/// assert_eq!(overview_section("操作系统:"), "系统");
+/// assert_eq!(overview_section("独显:"), "显卡");
+/// assert_eq!(overview_section("处理器:"), "处理器");
+/// ```
+pub fn overview_section(label: &str) -> &str {
+    match label.trim_end_matches(':') {
+        "操作系统" => "系统",
+        "独显" | "核显" | "混合输出" | "虚拟设备" => "显卡",
+        "刷新率" | "当前模式" => "显示器",
+        "主硬盘" => "硬盘",
+        "无线网卡" => "网卡",
+        "电源" => "电池",
+        other => other,
+    }
+}
+
+/// 正在高亮的跳转目标
+#[derive(Debug, Clone)]
+struct ActiveTarget {
+    target: SearchTarget,
+    /// 高亮结束的时间（界面时间，秒）
+    until: f64,
+    /// 是否已滚动到目标行
+    scrolled: bool,
+    /// 是否已展开目标所在的折叠区块
+    opened: bool,
+}
+
+fn active_id() -> egui::Id {
+    egui::Id::new("search_active_target")
+}
+
+fn active_target(ctx: &egui::Context) -> Option<ActiveTarget> {
+    let active: Option<ActiveTarget> = ctx.data(|data| data.get_temp(active_id()));
+    let now = ctx.input(|i| i.time);
+    match active {
+        Some(active) if active.until > now => Some(active),
+        Some(_) => {
+            ctx.data_mut(|data| data.remove::<ActiveTarget>(active_id()));
+            None
+        }
+        None => None,
+    }
+}
+
+/// 跳转到指定字段：下一次绘制时滚动到对应的行并短暂高亮
+pub fn jump_to(ctx: &egui::Context, target: SearchTarget) {
+    let active = ActiveTarget {
+        target,
+        until: ctx.input(|i| i.time) + HIGHLIGHT_SECS,
+        scrolled: false,
+        opened: false,
+    };
+    ctx.data_mut(|data| data.insert_temp(active_id(), active));
+    ctx.request_repaint();
+}
+
+/// 折叠区块的展开状态：跳转目标位于该区块时返回 `Some(true)` 展开一次，其余情况交由用户控制
+///
+/// * `header` - 折叠区块标题，与搜索字段的章节名一致
+pub fn header_open(ctx: &egui::Context, header: &str) -> Option<bool> {
+    let mut active = active_target(ctx)?;
+    if active.opened || active.target.section != header {
+        return None;
+    }
+    active.opened = true;
+    ctx.data_mut(|data| data.insert_temp(active_id(), active));
+    Some(true)
+}
+
+/// 高亮概览中的区块标题行，跳转目标属于该区块时生效
+///
+/// * `response` - 区块标题的响应
+/// * `label` - 区块标题，如 "处理器:"
+pub fn highlight_section(ui: &egui::Ui, response: &egui::Response, label: &str) {
+    let Some(active) = active_target(ui.ctx()) else {
+        return;
+    };
+    if active.target.section == overview_section(label) {
+        highlight(ui, response, active);
+    }
+}
+
+/// 高亮详情中的字段行，跳转目标为该字段时生效
+///
+/// * `response` - 字段名的响应
+/// * `section` - 所属章节，如 "主板详情"
+/// * `key` - 字段名
+pub fn highlight_field(ui: &egui::Ui, response: &egui::Response, section: &str, key: &str) {
+    let Some(active) = active_target(ui.ctx()) else {
+        return;
+    };
+    if active.target.section == section && active.target.key == key {
+        highlight(ui, response, active);
+    }
+}
+
+fn highlight(ui: &egui::Ui, response: &egui::Response, mut active: ActiveTarget) {
+    let stroke = egui::Stroke::new(2.0, ui.visuals().selection.stroke.color);
+    ui.painter()
+        .rect_stroke(response.rect.expand(3.0), 3.0, stroke);
+    if !active.scrolled {
+        response.scroll_to_me(Some(egui::Align::Center));
+        active.scrolled = true;
+        ui.ctx()
+            .data_mut(|data| data.insert_temp(active_id(), active.clone()));
+    }
+    // 高亮到期后需要重绘一次才会消失
+    let remaining = active.until - ui.ctx().input(|i| i.time);
+    ui.ctx()
+        .request_repaint_after(std::time::Duration::from_secs_f64(remaining.max(0.0)));
+}
+
+/// 搜索面板
+#[derive(Debug, Default)]
+pub struct SearchPalette {
+    /// 是否显示
+    open: bool,
+    /// 查询文本
+    query: String,
+    /// 当前选中的结果序号
+    selected: usize,
+    /// 打开面板时生成的字段索引
+    index: SearchIndex,
+    /// 打开后是否需要聚焦输入框
+    focus: bool,
+}
+
+impl SearchPalette {
+    /// 是否显示
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// 打开面板
+    ///
+    /// * `index` - 当前检测结果的字段索引
+    pub fn open(&mut self, index: SearchIndex) {
+        self.open = true;
+        self.focus = true;
+        self.selected = 0;
+        self.index = index;
+    }
+
+    /// 关闭面板
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// 绘制面板，用户选中结果时返回跳转目标
+    ///
+    /// 上下方向键切换结果，回车跳转，Esc 关闭
+    pub fn show(&mut self, ctx: &egui::Context) -> Option<SearchTarget> {
+        if !self.open {
+            return None;
+        }
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape)) {
+            self.close();
+            return None;
+        }
+
+        let results: Vec<SearchEntry> = self
+            .index
+            .search(&self.query, MAX_RESULTS)
+            .into_iter()
+            .cloned()
+            .collect();
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown)) {
+            self.selected = (self.selected + 1).min(results.len().saturating_sub(1));
+        }
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp)) {
+            self.selected = self.selected.saturating_sub(1);
+        }
+
+        let mut chosen = None;
+        let mut open = self.open;
+        egui::Window::new("搜索字段")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .default_width(420.0)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 60.0])
+            .show(ctx, |ui| {
+                let edit = ui.add(
+                    egui::TextEdit::singleline(&mut self.query)
+                        .hint_text("如 序列号、MAC、BIOS")
+                        .desired_width(f32::INFINITY),
+                );
+                if self.focus {
+                    edit.request_focus();
+                    self.focus = false;
+                }
+                if edit.changed() {
+                    self.selected = 0;
+                }
+                let enter = edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+                if self.query.trim().is_empty() {
+                    ui.weak("输入字段名或字段值，回车跳转到对应位置");
+                    return;
+                }
+                if results.is_empty() {
+                    ui.weak("没有匹配的字段");
+                    return;
+                }
+                ui.separator();
+                egui::ScrollArea::vertical()
+                    .max_height(320.0)
+                    .show(ui, |ui| {
+                        for (index, entry) in results.iter().enumerate() {
+                            let text =
+                                format!("{} › {}: {}", entry.section, entry.key, entry.value);
+                            let selected = index == self.selected;
+                            let response = ui.selectable_label(selected, text);
+                            if selected {
+                                response.scroll_to_me(None);
+                            }
+                            if response.clicked() {
+                                chosen = Some(entry.target());
+                            }
+                        }
+                    });
+                if enter {
+                    chosen = results.get(self.selected).map(SearchEntry::target);
+                    if chosen.is_none() {
+                        edit.request_focus();
+                    }
+                }
+            });
+        self.open = open && chosen.is_none();
+        chosen
+    }
+}
@@ -0,0 +1,89 @@
+//! 标签页模块
+//!
+//! 定义主界面中可切换的标签页
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 主界面标签页
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub enum AppTab {
+    /// 硬件概览
+    #[default]
+    Overview,
+    /// 实时监控
+    Live,
+    /// 验机清单
+    Inspection,
+    /// 历史趋势
+    Trends,
+    /// 与上次检测对比
+    Changes,
+    /// 性能测试
+    Benchmark,
+    /// 输入设备、打印机与扫描仪
+    Peripherals,
+    /// 资产与保修信息
+    Asset,
+    /// 内核驱动列表（高级模式）
+    Drivers,
+    /// WMI 查询控制台（高级模式）
+    WmiConsole,
+    /// 检测耗时（高级模式）
+    Timings,
+}
+
+impl AppTab {
+    /// 所有标签页，按显示顺序排列
+    pub const ALL: [AppTab; 11] = [
+        AppTab::Overview,
+        AppTab::Live,
+        AppTab::Inspection,
+        AppTab::Trends,
+        AppTab::Changes,
+        AppTab::Benchmark,
+        AppTab::Peripherals,
+        AppTab::Asset,
+        AppTab::Drivers,
+        AppTab::WmiConsole,
+        AppTab::Timings,
+    ];
+
+    /// 获取标签页名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            AppTab::Overview => "概览",
+            AppTab::Live => "实时",
+            AppTab::Inspection => "验机",
+            AppTab::Trends => "趋势",
+            AppTab::Changes => "对比",
+            AppTab::Benchmark => "性能测试",
+            AppTab::Peripherals => "外设",
+            AppTab::Asset => "资产",
+            AppTab::Drivers => "驱动程序",
+            AppTab::WmiConsole => "WMI 控制台",
+            AppTab::Timings => "检测耗时",
+        }
+    }
+
+    /// 是否仅在高级模式下显示
+    pub fn is_advanced(&self) -> bool {
+        matches!(self, AppTab::Drivers | AppTab::WmiConsole | AppTab::Timings)
+    }
+
+    /// 当前模式下可见的标签页
+    ///
+    /// * `advanced_mode` - 是否启用高级模式
+    pub fn visible(advanced_mode: bool) -> Vec<AppTab> {
+        Self::ALL
+            .into_iter()
+            .filter(|tab| advanced_mode || !tab.is_advanced())
+            .collect()
+    }
+}
+
+impl fmt::Display for AppTab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
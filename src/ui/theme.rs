@@ -1,13 +1,17 @@
 //! 主题模块
 //!
-//! 提供主题切换功能，支持系统、亮色、暗色三种主题
+//! 提供主题切换功能，支持系统、亮色、暗色三种主题，
+//! 并在主题之上叠加强调色、间距预设、区块卡片背景与无障碍模式（大字体、高对比度）
 
-use eframe::egui;
+use crate::constants::{ACCESSIBLE_FONT_SCALE, DEFAULT_ACCENT_COLOR};
+use eframe::egui::{self, Color32};
+use serde::{Deserialize, Serialize};
 
 /// 应用主题
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum AppTheme {
     /// 系统主题（跟随系统设置）
+    #[default]
     System,
     /// 亮色主题
     Light,
@@ -52,7 +56,8 @@ impl AppTheme {
         }
     }
 
-    /// 从字符串解析主题
+    /// 从字符串解析主题，未知值返回系统主题
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Self {
         match s {
             crate::constants::THEME_SYSTEM => AppTheme::System,
@@ -63,14 +68,237 @@ impl AppTheme {
     }
 }
 
-impl Default for AppTheme {
-    fn default() -> Self {
-        AppTheme::System
+impl std::fmt::Display for AppTheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
     }
 }
 
-impl std::fmt::Display for AppTheme {
+/// 界面间距预设
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Density {
+    /// 紧凑
+    Compact,
+    /// 普通
+    #[default]
+    Normal,
+    /// 宽松
+    Relaxed,
+}
+
+impl Density {
+    /// 全部预设，按从紧到松排列
+    pub const ALL: [Density; 3] = [Density::Compact, Density::Normal, Density::Relaxed];
+
+    /// 获取预设名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Density::Compact => "紧凑",
+            Density::Normal => "普通",
+            Density::Relaxed => "宽松",
+        }
+    }
+
+    /// 相对于 egui 默认间距的缩放比例
+    pub fn scale(&self) -> f32 {
+        match self {
+            Density::Compact => 0.75,
+            Density::Normal => 1.0,
+            Density::Relaxed => 1.35,
+        }
+    }
+
+    /// 按预设缩放表格间距
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::ui::theme::Density;
+    /// assert_eq!(Density::Normal.grid_spacing(20.0, 8.0), [20.0, 8.0].into());
+    /// assert_eq!(Density::Compact.grid_spacing(20.0, 8.0), [15.0, 6.0].into());
+    /// ```
+    pub fn grid_spacing(&self, x: f32, y: f32) -> egui::Vec2 {
+        egui::vec2(x, y) * self.scale()
+    }
+}
+
+impl std::fmt::Display for Density {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.name())
     }
 }
+
+/// 按比例混合两种颜色，`t` 为 0 时返回 `a`，为 1 时返回 `b`
+///
+/// 示例
+/// ```
+/// use eframe::egui::Color32;
+/// use hardware_master::ui::theme::mix;
+/// assert_eq!(mix(Color32::BLACK, Color32::WHITE, 0.0), Color32::BLACK);
+/// assert_eq!(mix(Color32::BLACK, Color32::WHITE, 1.0), Color32::WHITE);
+/// assert_eq!(mix(Color32::from_rgb(0, 100, 200), Color32::from_rgb(100, 200, 0), 0.5), Color32::from_rgb(50, 150, 100));
+/// ```
+pub fn mix(a: Color32, b: Color32, t: f32) -> Color32 {
+    let t = t.clamp(0.0, 1.0);
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color32::from_rgb(
+        channel(a.r(), b.r()),
+        channel(a.g(), b.g()),
+        channel(a.b(), b.b()),
+    )
+}
+
+/// 高对比度配色：纯黑或纯白底色、前景色文字，控件描边加粗
+fn high_contrast(visuals: &mut egui::Visuals) {
+    let (foreground, background) = if visuals.dark_mode {
+        (Color32::WHITE, Color32::BLACK)
+    } else {
+        (Color32::BLACK, Color32::WHITE)
+    };
+    visuals.override_text_color = Some(foreground);
+    visuals.panel_fill = background;
+    visuals.window_fill = background;
+    visuals.extreme_bg_color = background;
+    visuals.faint_bg_color = mix(background, foreground, 0.1);
+    visuals.window_stroke = egui::Stroke::new(1.5, foreground);
+    visuals.selection.stroke.width = 2.0;
+
+    let widgets = &mut visuals.widgets;
+    for widget in [
+        &mut widgets.noninteractive,
+        &mut widgets.inactive,
+        &mut widgets.hovered,
+        &mut widgets.active,
+        &mut widgets.open,
+    ] {
+        widget.fg_stroke = egui::Stroke::new(widget.fg_stroke.width.max(1.5), foreground);
+        widget.bg_stroke = egui::Stroke::new(1.5, foreground);
+    }
+    widgets.inactive.weak_bg_fill = background;
+    widgets.inactive.bg_fill = mix(background, foreground, 0.15);
+}
+
+/// 界面样式：在主题之上叠加强调色、间距预设与区块卡片背景
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeStyle {
+    /// 主题
+    pub theme: AppTheme,
+    /// 强调色（RGB）
+    pub accent: [u8; 3],
+    /// 间距预设
+    pub density: Density,
+    /// 是否为概览中的每个区块绘制卡片背景
+    pub cards: bool,
+    /// 无障碍模式：放大字体并使用高对比度配色
+    pub accessible: bool,
+}
+
+impl Default for ThemeStyle {
+    fn default() -> Self {
+        Self {
+            theme: AppTheme::default(),
+            accent: DEFAULT_ACCENT_COLOR,
+            density: Density::default(),
+            cards: true,
+            accessible: false,
+        }
+    }
+}
+
+impl ThemeStyle {
+    /// 强调色
+    pub fn accent_color(&self) -> Color32 {
+        let [r, g, b] = self.accent;
+        Color32::from_rgb(r, g, b)
+    }
+
+    /// 应用主题与样式到 egui 上下文
+    pub fn apply(&self, ctx: &egui::Context) {
+        self.theme.apply(ctx);
+        ctx.style_mut(|style| self.apply_style(style));
+    }
+
+    /// 将强调色、间距预设与无障碍设置写入样式，按亮色或暗色调整强调色的深浅
+    ///
+    /// 间距与字号以 egui 默认值为基准缩放，重复应用不会累积
+    pub fn apply_style(&self, style: &mut egui::Style) {
+        if self.accessible {
+            high_contrast(&mut style.visuals);
+        }
+
+        let accent = self.accent_color();
+        let visuals = &mut style.visuals;
+        let background = visuals.panel_fill;
+        let (selection, text) = if visuals.dark_mode {
+            (
+                mix(background, accent, 0.7),
+                mix(accent, Color32::WHITE, 0.6),
+            )
+        } else {
+            (
+                mix(background, accent, 0.35),
+                mix(accent, Color32::BLACK, 0.4),
+            )
+        };
+        visuals.selection.bg_fill = selection;
+        visuals.selection.stroke.color = text;
+        visuals.hyperlink_color = text;
+        visuals.widgets.hovered.bg_stroke.color = accent;
+        visuals.widgets.active.bg_stroke.color = accent;
+        visuals.widgets.active.bg_fill = selection;
+
+        let base = egui::style::Spacing::default();
+        let scale = self.density.scale();
+        let spacing = &mut style.spacing;
+        spacing.item_spacing = base.item_spacing * scale;
+        spacing.button_padding = base.button_padding * scale;
+        spacing.indent = base.indent * scale;
+        spacing.window_margin = base.window_margin * scale;
+        spacing.menu_margin = base.menu_margin * scale;
+
+        let font_scale = if self.accessible {
+            ACCESSIBLE_FONT_SCALE
+        } else {
+            1.0
+        };
+        style.text_styles = egui::Style::default()
+            .text_styles
+            .into_iter()
+            .map(|(text_style, font)| {
+                (
+                    text_style,
+                    egui::FontId::new(font.size * font_scale, font.family),
+                )
+            })
+            .collect();
+    }
+
+    /// 区块卡片的背景，在面板底色上略微混入强调色
+    pub fn card_frame(&self, visuals: &egui::Visuals) -> egui::Frame {
+        let tint = if visuals.dark_mode { 0.06 } else { 0.04 };
+        egui::Frame::none()
+            .fill(mix(visuals.faint_bg_color, self.accent_color(), tint))
+            .stroke(visuals.widgets.noninteractive.bg_stroke)
+            .rounding(6.0)
+            .inner_margin(egui::Margin::same(10.0 * self.density.scale()))
+    }
+
+    /// 绘制概览中的一个区块，开启卡片背景时包在卡片中
+    pub fn section<R>(
+        &self,
+        ui: &mut egui::Ui,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> R {
+        if !self.cards {
+            return add_contents(ui);
+        }
+        let inner = self
+            .card_frame(ui.visuals())
+            .show(ui, |ui| {
+                ui.set_width(ui.available_width());
+                add_contents(ui)
+            })
+            .inner;
+        ui.add_space(6.0 * self.density.scale());
+        inner
+    }
+}
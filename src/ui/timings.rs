@@ -0,0 +1,96 @@
+//! 检测耗时面板
+//!
+//! 高级模式下列出各检测类别最近一次的耗时和其中最慢的 WMI 查询，
+//! 可在此停用在本机上特别慢的检测类别
+
+use crate::detector::timing::{format_millis, DetectionTiming};
+use crate::detector::DetectionCategory;
+use eframe::egui;
+
+/// 单个类别最多列出的查询数
+const MAX_QUERIES: usize = 10;
+
+/// 绘制检测耗时面板
+///
+/// * `timings` - 各类别最近一次检测的耗时
+/// * `disabled` - 停用的检测类别，可在面板中勾选修改
+///
+/// 返回停用的检测类别是否被修改
+pub fn show(
+    ui: &mut egui::Ui,
+    timings: &[DetectionTiming],
+    disabled: &mut Vec<DetectionCategory>,
+) -> bool {
+    let mut changed = false;
+    let total: u64 = timings.iter().map(|timing| timing.millis).sum();
+    ui.label(format!(
+        "已记录 {} 个类别，累计耗时 {}（各类别并行检测，实际等待时间更短）",
+        timings.len(),
+        format_millis(total)
+    ));
+    ui.add_space(6.0);
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        egui::Grid::new("detection_timings")
+            .num_columns(5)
+            .striped(true)
+            .spacing([16.0, 4.0])
+            .show(ui, |ui| {
+                ui.strong("启用");
+                ui.strong("类别");
+                ui.strong("耗时");
+                ui.strong("结果");
+                ui.strong("最慢的 WMI 查询");
+                ui.end_row();
+
+                for category in DetectionCategory::ALL {
+                    let mut checked = !disabled.contains(&category);
+                    if ui.checkbox(&mut checked, "").changed() {
+                        if checked {
+                            disabled.retain(|c| *c != category);
+                        } else {
+                            disabled.push(category);
+                        }
+                        changed = true;
+                    }
+                    ui.label(category.name());
+                    match timings.iter().find(|timing| timing.category == category) {
+                        Some(timing) => {
+                            ui.label(format_millis(timing.millis));
+                            ui.label(if timing.succeeded { "成功" } else { "失败" });
+                            render_queries(ui, timing);
+                        }
+                        None => {
+                            ui.label("-");
+                            ui.label("未检测");
+                            ui.label("-");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+    });
+    changed
+}
+
+/// 显示最慢的查询，悬停列出该类别的全部查询
+fn render_queries(ui: &mut egui::Ui, timing: &DetectionTiming) {
+    let Some(slowest) = timing.slowest_query() else {
+        ui.label("未执行 WMI 查询");
+        return;
+    };
+    ui.label(format!("{}  {}", format_millis(slowest.millis), slowest.query))
+        .on_hover_ui(|ui| {
+            ui.label(format!(
+                "WMI 查询共 {}，其余 {} 用于其他系统调用",
+                format_millis(timing.query_millis()),
+                format_millis(timing.millis.saturating_sub(timing.query_millis()))
+            ));
+            for query in timing.queries.iter().take(MAX_QUERIES) {
+                ui.label(format!("{}  {}", format_millis(query.millis), query.query));
+            }
+            if timing.queries.len() > MAX_QUERIES {
+                ui.label(format!("…… 另有 {} 条", timing.queries.len() - MAX_QUERIES));
+            }
+        });
+}
@@ -0,0 +1,355 @@
+//! 系统托盘模块
+//!
+//! 托盘图标运行在独立线程的消息循环中：主窗口隐藏或最小化后 egui 不再调用
+//! `update`，托盘仍需响应菜单并每秒刷新提示文字（CPU 占用与温度）。
+//! 菜单命令通过通道发送给界面，并唤醒界面处理。
+
+use crate::constants::TRAY_REFRESH_MS;
+use crate::realtime::cpu_load::CpuLoadSampler;
+use eframe::egui;
+use std::cell::RefCell;
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use windows::core::{w, PCWSTR};
+use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, POINT, WPARAM};
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::UI::Shell::{
+    Shell_NotifyIconW, NIF_ICON, NIF_MESSAGE, NIF_TIP, NIM_ADD, NIM_DELETE, NIM_MODIFY,
+    NOTIFYICONDATAW,
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+    AppendMenuW, CreatePopupMenu, CreateWindowExW, DefWindowProcW, DestroyMenu, DestroyWindow,
+    DispatchMessageW, GetCursorPos, GetMessageW, IsIconic, KillTimer, LoadIconW, PostMessageW,
+    PostQuitMessage, RegisterClassW, SetForegroundWindow, SetTimer, ShowWindow, ShowWindowAsync,
+    TrackPopupMenu, TranslateMessage, IDI_APPLICATION, MF_SEPARATOR, MF_STRING, MSG, SW_HIDE,
+    SW_RESTORE, SW_SHOW, TPM_NONOTIFY, TPM_RETURNCMD, TPM_RIGHTBUTTON, WINDOW_EX_STYLE, WM_APP,
+    WM_CLOSE, WM_DESTROY, WM_LBUTTONDBLCLK, WM_NULL, WM_RBUTTONUP, WM_TIMER, WNDCLASSW,
+    WS_OVERLAPPED,
+};
+
+/// 托盘图标回调消息
+const WM_TRAY_ICON: u32 = WM_APP + 1;
+
+/// 托盘图标编号
+const TRAY_ICON_ID: u32 = 1;
+
+/// 提示文字刷新定时器编号
+const REFRESH_TIMER_ID: usize = 1;
+
+/// 菜单项：打开主窗口
+const MENU_OPEN: usize = 1;
+/// 菜单项：重新检测
+const MENU_REDETECT: usize = 2;
+/// 菜单项：退出
+const MENU_QUIT: usize = 3;
+
+/// 托盘提示标题
+const TOOLTIP_TITLE: &str = "硬大师";
+
+/// 托盘菜单发给界面的命令
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayCommand {
+    /// 重新检测全部已启用类别
+    Redetect,
+    /// 退出程序
+    Quit,
+}
+
+/// 托盘线程的状态，只在托盘线程中访问
+struct TrayState {
+    /// 主窗口句柄
+    main_window: HWND,
+    /// 界面上下文
+    ctx: egui::Context,
+    /// 命令发送端
+    command_tx: mpsc::Sender<TrayCommand>,
+    /// CPU 负载采样器，创建失败时提示中不显示负载
+    sampler: Option<CpuLoadSampler>,
+}
+
+thread_local! {
+    static TRAY_STATE: RefCell<Option<TrayState>> = const { RefCell::new(None) };
+}
+
+/// 系统托盘图标，释放时移除图标并结束托盘线程
+pub struct SystemTray {
+    /// 托盘窗口句柄
+    tray_window: isize,
+    /// 托盘线程
+    thread: Option<JoinHandle<()>>,
+}
+
+impl SystemTray {
+    /// 创建托盘图标
+    ///
+    /// * `ctx` - 界面上下文，收到菜单命令后唤醒界面
+    /// * `main_window` - 主窗口句柄
+    /// * `command_tx` - 菜单命令发送端
+    pub fn start(
+        ctx: egui::Context,
+        main_window: isize,
+        command_tx: mpsc::Sender<TrayCommand>,
+    ) -> Result<Self, String> {
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let thread = std::thread::Builder::new()
+            .name("tray".to_string())
+            .spawn(move || {
+                let state = TrayState {
+                    main_window: HWND(main_window as *mut core::ffi::c_void),
+                    ctx,
+                    command_tx,
+                    sampler: CpuLoadSampler::new().map_err(|e| log::warn!("{}", e)).ok(),
+                };
+                TRAY_STATE.with(|cell| *cell.borrow_mut() = Some(state));
+                match unsafe { create_tray_window() } {
+                    Ok(hwnd) => {
+                        let _ = ready_tx.send(Ok(hwnd.0 as isize));
+                        unsafe { run_message_loop() };
+                    }
+                    Err(e) => {
+                        let _ = ready_tx.send(Err(e));
+                    }
+                }
+                TRAY_STATE.with(|cell| cell.borrow_mut().take());
+            })
+            .map_err(|e| format!("创建托盘线程失败: {}", e))?;
+
+        match ready_rx.recv() {
+            Ok(Ok(tray_window)) => Ok(Self {
+                tray_window,
+                thread: Some(thread),
+            }),
+            Ok(Err(e)) => {
+                let _ = thread.join();
+                Err(e)
+            }
+            Err(_) => Err("托盘线程意外退出".to_string()),
+        }
+    }
+}
+
+impl Drop for SystemTray {
+    fn drop(&mut self) {
+        unsafe {
+            let hwnd = HWND(self.tray_window as *mut core::ffi::c_void);
+            let _ = PostMessageW(Some(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// 显示并激活主窗口
+///
+/// 使用异步显示，托盘线程不会等待界面线程处理窗口消息
+///
+/// * `main_window` - 主窗口句柄
+pub fn show_main_window(main_window: isize) {
+    unsafe {
+        let hwnd = HWND(main_window as *mut core::ffi::c_void);
+        let command = if IsIconic(hwnd).as_bool() {
+            SW_RESTORE
+        } else {
+            SW_SHOW
+        };
+        let _ = ShowWindowAsync(hwnd, command);
+        let _ = SetForegroundWindow(hwnd);
+    }
+}
+
+/// 隐藏主窗口（只保留托盘图标）
+///
+/// * `main_window` - 主窗口句柄
+pub fn hide_main_window(main_window: isize) {
+    unsafe {
+        let _ = ShowWindow(HWND(main_window as *mut core::ffi::c_void), SW_HIDE);
+    }
+}
+
+/// 创建接收托盘消息的隐藏窗口，并添加托盘图标
+unsafe fn create_tray_window() -> Result<HWND, String> {
+    let instance = GetModuleHandleW(None).map_err(|e| format!("获取模块句柄失败: {}", e))?;
+    let class_name = w!("HardwareMasterTray");
+    let class = WNDCLASSW {
+        lpfnWndProc: Some(tray_window_proc),
+        hInstance: instance.into(),
+        lpszClassName: class_name,
+        ..Default::default()
+    };
+    // 重复开启托盘时窗口类已注册，注册失败不影响创建窗口
+    RegisterClassW(&class);
+
+    let hwnd = CreateWindowExW(
+        WINDOW_EX_STYLE::default(),
+        class_name,
+        w!("硬大师"),
+        WS_OVERLAPPED,
+        0,
+        0,
+        0,
+        0,
+        None,
+        None,
+        Some(instance.into()),
+        None,
+    )
+    .map_err(|e| format!("创建托盘窗口失败: {}", e))?;
+
+    // 优先使用程序资源中的图标（见 icon.rc）
+    let icon = LoadIconW(Some(instance.into()), w!("iconName"))
+        .or_else(|_| LoadIconW(None, IDI_APPLICATION))
+        .unwrap_or_default();
+
+    let mut data = notify_data(hwnd);
+    data.uFlags = NIF_ICON | NIF_MESSAGE | NIF_TIP;
+    data.uCallbackMessage = WM_TRAY_ICON;
+    data.hIcon = icon;
+    set_tooltip(&mut data, TOOLTIP_TITLE);
+    if !Shell_NotifyIconW(NIM_ADD, &data).as_bool() {
+        let _ = DestroyWindow(hwnd);
+        return Err("添加托盘图标失败".to_string());
+    }
+
+    SetTimer(Some(hwnd), REFRESH_TIMER_ID, TRAY_REFRESH_MS, None);
+    Ok(hwnd)
+}
+
+/// 运行托盘线程的消息循环，收到 WM_QUIT 后返回
+unsafe fn run_message_loop() {
+    let mut msg = MSG::default();
+    while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+        let _ = TranslateMessage(&msg);
+        DispatchMessageW(&msg);
+    }
+}
+
+/// 托盘窗口消息处理
+unsafe extern "system" fn tray_window_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_TRAY_ICON => {
+            match lparam.0 as u32 {
+                WM_LBUTTONDBLCLK => handle_menu_command(MENU_OPEN),
+                WM_RBUTTONUP => {
+                    if let Some(command) = show_context_menu(hwnd) {
+                        handle_menu_command(command);
+                    }
+                }
+                _ => {}
+            }
+            LRESULT(0)
+        }
+        WM_TIMER if wparam.0 == REFRESH_TIMER_ID => {
+            refresh_tooltip(hwnd);
+            LRESULT(0)
+        }
+        WM_CLOSE => {
+            let _ = KillTimer(Some(hwnd), REFRESH_TIMER_ID);
+            let _ = Shell_NotifyIconW(NIM_DELETE, &notify_data(hwnd));
+            let _ = DestroyWindow(hwnd);
+            LRESULT(0)
+        }
+        WM_DESTROY => {
+            PostQuitMessage(0);
+            LRESULT(0)
+        }
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
+
+/// 在鼠标位置弹出托盘菜单，返回选中的菜单项
+unsafe fn show_context_menu(hwnd: HWND) -> Option<usize> {
+    let menu = CreatePopupMenu().ok()?;
+    let _ = AppendMenuW(menu, MF_STRING, MENU_OPEN, w!("打开主窗口"));
+    let _ = AppendMenuW(menu, MF_STRING, MENU_REDETECT, w!("重新检测"));
+    let _ = AppendMenuW(menu, MF_SEPARATOR, 0, PCWSTR::null());
+    let _ = AppendMenuW(menu, MF_STRING, MENU_QUIT, w!("退出"));
+
+    let mut point = POINT::default();
+    let _ = GetCursorPos(&mut point);
+    // 先激活托盘窗口，否则点击菜单外部时菜单不会关闭
+    let _ = SetForegroundWindow(hwnd);
+    let selected = TrackPopupMenu(
+        menu,
+        TPM_RETURNCMD | TPM_NONOTIFY | TPM_RIGHTBUTTON,
+        point.x,
+        point.y,
+        None,
+        hwnd,
+        None,
+    );
+    let _ = PostMessageW(Some(hwnd), WM_NULL, WPARAM(0), LPARAM(0));
+    let _ = DestroyMenu(menu);
+
+    match selected.0 {
+        0 => None,
+        command => Some(command as usize),
+    }
+}
+
+/// 执行菜单命令
+///
+/// 重新检测与退出都需要界面处理，而主窗口隐藏时界面不会刷新，因此先显示主窗口
+fn handle_menu_command(command: usize) {
+    TRAY_STATE.with(|cell| {
+        let state = cell.borrow();
+        let Some(state) = state.as_ref() else {
+            return;
+        };
+        show_main_window(state.main_window.0 as isize);
+        let command = match command {
+            MENU_REDETECT => Some(TrayCommand::Redetect),
+            MENU_QUIT => Some(TrayCommand::Quit),
+            _ => None,
+        };
+        if let Some(command) = command {
+            let _ = state.command_tx.send(command);
+        }
+        state.ctx.request_repaint();
+    });
+}
+
+/// 采样 CPU 负载并更新托盘提示
+unsafe fn refresh_tooltip(hwnd: HWND) {
+    let summary = TRAY_STATE.with(|cell| {
+        let mut state = cell.borrow_mut();
+        let sampler = state.as_mut()?.sampler.as_mut()?;
+        sampler
+            .sample()
+            .map_err(|e| log::debug!("{}", e))
+            .ok()
+            .map(|load| load.summary())
+    });
+    let tooltip = match summary {
+        Some(summary) => format!("{}\n{}", TOOLTIP_TITLE, summary),
+        None => TOOLTIP_TITLE.to_string(),
+    };
+
+    let mut data = notify_data(hwnd);
+    data.uFlags = NIF_TIP;
+    set_tooltip(&mut data, &tooltip);
+    let _ = Shell_NotifyIconW(NIM_MODIFY, &data);
+}
+
+/// 创建标识托盘图标的通知数据
+fn notify_data(hwnd: HWND) -> NOTIFYICONDATAW {
+    NOTIFYICONDATAW {
+        cbSize: std::mem::size_of::<NOTIFYICONDATAW>() as u32,
+        hWnd: hwnd,
+        uID: TRAY_ICON_ID,
+        ..Default::default()
+    }
+}
+
+/// 写入提示文字，超出长度时截断
+fn set_tooltip(data: &mut NOTIFYICONDATAW, text: &str) {
+    let capacity = data.szTip.len() - 1;
+    let wide: Vec<u16> = text.encode_utf16().take(capacity).collect();
+    data.szTip = [0; 128];
+    data.szTip[..wide.len()].copy_from_slice(&wide);
+}
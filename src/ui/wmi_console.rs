@@ -0,0 +1,154 @@
+//! WMI 查询控制台
+//!
+//! 高级模式下提供的原始 WQL 查询界面，可用于排查检测结果缺失的问题
+
+use crate::utils::wmi::{self, WmiConfig, WmiTable};
+use eframe::egui;
+use std::sync::mpsc;
+
+/// 单次查询最多显示的行数
+const MAX_ROWS: usize = 500;
+
+/// 常用命名空间
+const COMMON_NAMESPACES: [&str; 4] = [
+    "ROOT\\CIMV2",
+    "ROOT\\WMI",
+    "ROOT\\Microsoft\\Windows\\Storage",
+    "ROOT\\StandardCimv2",
+];
+
+/// 查询结果
+type QueryResult = Result<WmiTable, String>;
+
+/// WMI 查询控制台
+pub struct WmiConsole {
+    /// 命名空间
+    namespace: String,
+    /// WQL 查询语句
+    query: String,
+    /// 最近一次查询结果
+    result: Option<QueryResult>,
+    /// 查询结果接收器，查询进行中时存在
+    result_rx: Option<mpsc::Receiver<QueryResult>>,
+}
+
+impl Default for WmiConsole {
+    fn default() -> Self {
+        Self {
+            namespace: "ROOT\\CIMV2".to_string(),
+            query: "SELECT * FROM Win32_BIOS".to_string(),
+            result: None,
+            result_rx: None,
+        }
+    }
+}
+
+impl WmiConsole {
+    /// 是否正在查询
+    pub fn is_running(&self) -> bool {
+        self.result_rx.is_some()
+    }
+
+    /// 在后台线程中执行查询
+    ///
+    /// * `ctx` - 界面上下文，查询完成后唤醒界面重绘
+    fn run_query(&mut self, ctx: &egui::Context) {
+        let config = WmiConfig {
+            namespace: self.namespace.trim().to_string(),
+            ..Default::default()
+        };
+        let query = self.query.trim().to_string();
+        let (tx, rx) = mpsc::channel();
+        self.result_rx = Some(rx);
+        let ctx = ctx.clone();
+
+        std::thread::spawn(move || {
+            let result = unsafe { wmi::query_table(&config, &query, MAX_ROWS) };
+            let _ = tx.send(result);
+            ctx.request_repaint();
+        });
+    }
+
+    /// 渲染控制台
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        if let Some(rx) = &self.result_rx {
+            if let Ok(result) = rx.try_recv() {
+                self.result = Some(result);
+                self.result_rx = None;
+            }
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("命名空间:");
+            ui.add(egui::TextEdit::singleline(&mut self.namespace).desired_width(260.0));
+            egui::ComboBox::from_id_source("wmi_namespace")
+                .selected_text("常用")
+                .show_ui(ui, |ui| {
+                    for namespace in COMMON_NAMESPACES {
+                        ui.selectable_value(&mut self.namespace, namespace.to_string(), namespace);
+                    }
+                });
+        });
+
+        ui.add(
+            egui::TextEdit::multiline(&mut self.query)
+                .code_editor()
+                .desired_rows(3)
+                .desired_width(f32::INFINITY),
+        );
+
+        ui.horizontal(|ui| {
+            let can_run = !self.is_running() && !self.query.trim().is_empty();
+            if ui.add_enabled(can_run, egui::Button::new("执行")).clicked() {
+                self.run_query(ui.ctx());
+            }
+            if self.is_running() {
+                ui.spinner();
+            }
+        });
+        ui.separator();
+
+        match &self.result {
+            None => {
+                ui.label("输入 WQL 查询语句后点击执行");
+            }
+            Some(Err(e)) => {
+                ui.colored_label(ui.visuals().error_fg_color, e);
+            }
+            Some(Ok(table)) => Self::render_table(ui, table),
+        }
+    }
+
+    /// 渲染查询结果表格
+    fn render_table(ui: &mut egui::Ui, table: &WmiTable) {
+        if table.rows.is_empty() {
+            ui.label("查询结果为空");
+            return;
+        }
+
+        let mut summary = format!("共 {} 行", table.rows.len());
+        if table.rows.len() >= MAX_ROWS {
+            summary.push_str(&format!("（仅显示前 {} 行）", MAX_ROWS));
+        }
+        ui.label(summary);
+
+        egui::ScrollArea::both().show(ui, |ui| {
+            egui::Grid::new("wmi_result_grid")
+                .striped(true)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    for column in table.columns.iter() {
+                        ui.strong(column);
+                    }
+                    ui.end_row();
+
+                    for row in table.rows.iter() {
+                        for value in row.iter() {
+                            ui.label(value);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+}
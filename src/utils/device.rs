@@ -12,13 +12,16 @@ use windows::Win32::Devices::DeviceAndDriverInstallation::{
     SP_DEVICE_INTERFACE_DETAIL_DATA_W,
 };
 use windows::Win32::Devices::DeviceAndDriverInstallation::{
-    SetupDiEnumDeviceInterfaces, SETUP_DI_REGISTRY_PROPERTY, SP_DEVINFO_DATA,
+    SetupDiEnumDeviceInfo, SetupDiEnumDeviceInterfaces, SetupDiGetDevicePropertyW,
+    SETUP_DI_REGISTRY_PROPERTY, SP_DEVINFO_DATA,
 };
+use windows::Win32::Devices::Properties::{DEVPROPTYPE, DEVPROP_TYPE_UINT32};
 use windows::Win32::Foundation::{
-    GetLastError, ERROR_INSUFFICIENT_BUFFER, ERROR_NO_MORE_ITEMS, HANDLE,
+    GetLastError, DEVPROPKEY, ERROR_INSUFFICIENT_BUFFER, ERROR_NO_MORE_ITEMS, HANDLE,
 };
 use windows::Win32::System::IO::DeviceIoControl;
 
+pub use super::device_id::{parse_device_instance_id, DeviceInstanceIdInfo};
 use super::string::pwstr_to_string;
 use crate::detector::DetectionError;
 
@@ -111,116 +114,40 @@ pub unsafe fn get_device_instance_id(
     )))
 }
 
-/// 设备实例 ID 信息结构体
-#[derive(Debug, Clone)]
-pub struct DeviceInstanceIdInfo {
-    /// 原始设备实例 ID (例如: `PCI\VEN_10EC&DEV_8168&SUBSYS_12341462&REV_06\4&12a3b456&0&00E5`)
-    pub id: String,
-    /// 总线类型 (例如: PCI)
-    pub bus_type: String,
-    /// 厂商标识符 (例如: 10EC)
-    pub vendor_id: String,
-    /// 设备标识符 (例如: 8168)
-    pub device_id: String,
-    /// 子系统厂商标识符 (例如: 1234)
-    pub subsystem_vendor_id: String,
-    /// 子系统设备标识符 (例如: 8168)
-    pub subsystem_device_id: String,
-    /// 修订版本号 (例如: 06)
-    pub revision_id: String,
-    /// 总线编号 (实例部分)
-    pub bus_number: String,
-    /// 实例 ID
-    pub instance_id: String,
-    /// 特征码/唯一序列号 (实例部分)
-    pub feature_code: String,
-    /// 设备编号 (实例部分)
-    pub device_number: String,
-    /// 功能编号 (实例部分)
-    pub function_number: String,
-}
-
-/// 解析设备实例 ID，返回设备实例 ID 信息结构体
+/// 在指定设备类中查找实例 ID 包含指定片段（忽略大小写）的第一个设备，返回其设备实例 ID
 ///
-/// * `dev_ins_id` - 设备实例 ID 字符串
+/// * `class_guid` - 设备类的 GUID
+/// * `pattern` - 实例 ID 片段，如 "ven_10de&dev_2684"
 ///
 /// 示例
 /// ```ignore
-/// use hardware_master::utils::device::parse_device_instance_id;
+/// use hardware_master::utils::device::find_device_instance_id;
+/// use windows::Win32::Devices::DeviceAndDriverInstallation::GUID_DEVCLASS_DISPLAY;
 ///
-/// let info = parse_device_instance_id("PCI\\VEN_10EC&DEV_8168&SUBSYS_12341462&REV_06\\4&12a3b456&0&00E5");
-/// assert_eq!(info.vendor_id, "10EC");
-/// assert_eq!(info.device_id, "8168");
+/// let instance_id = unsafe { find_device_instance_id(&GUID_DEVCLASS_DISPLAY, "ven_10de&dev_2684") };
 /// ```
-pub fn parse_device_instance_id(dev_ins_id: &str) -> DeviceInstanceIdInfo {
-    let mut info = DeviceInstanceIdInfo {
-        id: dev_ins_id.to_string(),
-        bus_type: "未知".to_string(),
-        vendor_id: "未知".to_string(),
-        device_id: "未知".to_string(),
-        subsystem_vendor_id: "未知".to_string(),
-        subsystem_device_id: "未知".to_string(),
-        revision_id: "未知".to_string(),
-        bus_number: "未知".to_string(),
-        instance_id: "未知".to_string(),
-        feature_code: "未知".to_string(),
-        device_number: "未知".to_string(),
-        function_number: "未知".to_string(),
-    };
-
-    // BUS_TYPE\HW_PARAMS\INSTANCE_PARAMS
-    let parts: Vec<&str> = dev_ins_id.split('\\').collect();
-
-    if parts.is_empty() {
-        return info;
-    }
-
-    // 总线类型
-    info.bus_type = parts[0].to_string();
-
-    // 硬件参数
-    if parts.len() > 1 {
-        let hw_params = parts[1];
-        for pair in hw_params.split('&') {
-            if let Some((key, value)) = pair.split_once('_') {
-                match key {
-                    "VEN" => info.vendor_id = value.to_string(),
-                    "DEV" => info.device_id = value.to_string(),
-                    "SUBSYS" => {
-                        if value.len() >= 4 {
-                            info.subsystem_device_id = value[..4].to_string();
-                            info.subsystem_vendor_id = value[4..].to_string();
-                        }
-                    }
-                    "REV" => info.revision_id = value.to_string(),
-                    _ => {}
-                }
+pub unsafe fn find_device_instance_id(class_guid: *const GUID, pattern: &str) -> Option<String> {
+    let pattern = pattern.to_lowercase();
+    let device_info_set = get_device_info_set(class_guid, DIGCF_PRESENT).ok()?;
+    let _guard = scopeguard::guard(device_info_set, |set| {
+        let _ = SetupDiDestroyDeviceInfoList(set);
+    });
+
+    let mut index = 0;
+    loop {
+        let mut device_info_data = SP_DEVINFO_DATA {
+            cbSize: mem::size_of::<SP_DEVINFO_DATA>() as u32,
+            ..Default::default()
+        };
+        SetupDiEnumDeviceInfo(device_info_set, index, &mut device_info_data).ok()?;
+        index += 1;
+
+        if let Ok(id) = get_device_instance_id(device_info_set, &device_info_data) {
+            if id.to_lowercase().contains(&pattern) {
+                return Some(id);
             }
         }
     }
-
-    // 实例 ID
-    if parts.len() > 2 {
-        let instance_params = parts[2];
-        info.instance_id = instance_params.to_string();
-
-        let instance_parts: Vec<&str> = instance_params.split('&').collect();
-
-        if instance_parts.len() >= 1 {
-            info.bus_number = instance_parts[0].to_string();
-        }
-        if instance_parts.len() >= 2 {
-            info.feature_code = instance_parts[1].to_string();
-        }
-        if instance_parts.len() >= 3 {
-            info.device_number = instance_parts[2].to_string();
-        }
-        if instance_parts.len() >= 4 {
-            info.function_number = instance_parts[3].to_string();
-        }
-    }
-
-    info
 }
 
 /// 获取设备注册表属性
@@ -294,6 +221,31 @@ pub unsafe fn get_device_property(
     }
 }
 
+/// 读取设备的 32 位整数属性（DEVPROP_TYPE_UINT32），属性不存在或类型不符时返回 None
+///
+/// * `device_info_set` - 设备信息集句柄
+/// * `device_info_data` - 设备信息数据
+/// * `key` - 属性键，如 DEVPKEY_PciDevice_CurrentLinkWidth
+pub unsafe fn get_device_property_u32(
+    device_info_set: HDEVINFO,
+    device_info_data: &SP_DEVINFO_DATA,
+    key: &DEVPROPKEY,
+) -> Option<u32> {
+    let mut property_type = DEVPROPTYPE::default();
+    let mut buffer = [0u8; 4];
+    SetupDiGetDevicePropertyW(
+        device_info_set,
+        device_info_data,
+        key,
+        &mut property_type,
+        Some(&mut buffer),
+        None,
+        0,
+    )
+    .ok()?;
+    (property_type == DEVPROP_TYPE_UINT32).then(|| u32::from_le_bytes(buffer))
+}
+
 /// 获取指定设备类的设备信息集
 ///
 /// 通用的设备信息集获取函数，可被多个模块复用。
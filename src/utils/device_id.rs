@@ -0,0 +1,150 @@
+//! 设备实例 ID 解析模块
+//!
+//! 解析 SetupAPI、WMI 返回的设备实例 ID（如 `PCI\VEN_10DE&DEV_2684&SUBSYS_16F310DE&REV_A1\4&...`），
+//! 不依赖 Windows 接口，检测与界面均可使用
+
+use super::subsystem::SubsystemId;
+
+/// 设备实例 ID 信息结构体
+#[derive(Debug, Clone)]
+pub struct DeviceInstanceIdInfo {
+    /// 原始设备实例 ID (例如: `PCI\VEN_10EC&DEV_8168&SUBSYS_12341462&REV_06\4&12a3b456&0&00E5`)
+    pub id: String,
+    /// 总线类型 (例如: PCI)
+    pub bus_type: String,
+    /// 厂商标识符 (例如: 10EC)
+    pub vendor_id: String,
+    /// 设备标识符 (例如: 8168)
+    pub device_id: String,
+    /// 子系统厂商标识符 (例如: 1462)
+    pub subsystem_vendor_id: String,
+    /// 子系统设备标识符 (例如: 1234)
+    pub subsystem_device_id: String,
+    /// 修订版本号 (例如: 06)
+    pub revision_id: String,
+    /// 总线编号 (实例部分)
+    pub bus_number: String,
+    /// 实例 ID
+    pub instance_id: String,
+    /// 特征码/唯一序列号 (实例部分)
+    pub feature_code: String,
+    /// 设备编号 (实例部分)
+    pub device_number: String,
+    /// 功能编号 (实例部分)
+    pub function_number: String,
+}
+
+impl DeviceInstanceIdInfo {
+    /// 可显示的字段（字段名, 字段值），跳过未解析出的字段
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::utils::device_id::parse_device_instance_id;
+    /// let info = parse_device_instance_id(r"HDAUDIO\FUNC_01&VEN_10EC&DEV_0897\4&1b5b1c0e&0&0001");
+    /// let rows = info.rows();
+    /// assert_eq!(rows[1], ("总线", "HDAUDIO".to_string()));
+    /// assert_eq!(rows[2], ("厂商 ID", "10EC".to_string()));
+    /// assert!(rows.iter().all(|(key, _)| *key != "子系统厂商 ID"));
+    /// ```
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        [
+            ("设备实例 ID", &self.id),
+            ("总线", &self.bus_type),
+            ("厂商 ID", &self.vendor_id),
+            ("设备 ID", &self.device_id),
+            ("子系统厂商 ID", &self.subsystem_vendor_id),
+            ("子系统 ID", &self.subsystem_device_id),
+            ("修订版本", &self.revision_id),
+            ("实例路径", &self.instance_id),
+        ]
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty() && value.as_str() != "未知")
+        .map(|(key, value)| (key, value.clone()))
+        .collect()
+    }
+}
+
+/// 解析设备实例 ID，返回设备实例 ID 信息结构体
+///
+/// * `dev_ins_id` - 设备实例 ID 字符串
+///
+/// 示例
+/// ```
+/// use hardware_master::utils::device_id::parse_device_instance_id;
+///
+/// let info = parse_device_instance_id(r"PCI\VEN_10EC&DEV_8168&SUBSYS_12341462&REV_06\4&12a3b456&0&00E5");
+/// assert_eq!(info.vendor_id, "10EC");
+/// assert_eq!(info.device_id, "8168");
+/// assert_eq!(info.subsystem_vendor_id, "1462");
+/// assert_eq!(info.subsystem_device_id, "1234");
+/// assert_eq!(info.instance_id, "4&12a3b456&0&00E5");
+/// ```
+pub fn parse_device_instance_id(dev_ins_id: &str) -> DeviceInstanceIdInfo {
+    let mut info = DeviceInstanceIdInfo {
+        id: dev_ins_id.to_string(),
+        bus_type: "未知".to_string(),
+        vendor_id: "未知".to_string(),
+        device_id: "未知".to_string(),
+        subsystem_vendor_id: "未知".to_string(),
+        subsystem_device_id: "未知".to_string(),
+        revision_id: "未知".to_string(),
+        bus_number: "未知".to_string(),
+        instance_id: "未知".to_string(),
+        feature_code: "未知".to_string(),
+        device_number: "未知".to_string(),
+        function_number: "未知".to_string(),
+    };
+
+    // BUS_TYPE\HW_PARAMS\INSTANCE_PARAMS
+    let parts: Vec<&str> = dev_ins_id.split('\\').collect();
+
+    if parts.is_empty() {
+        return info;
+    }
+
+    // 总线类型
+    info.bus_type = parts[0].to_string();
+
+    // 硬件参数
+    if parts.len() > 1 {
+        let hw_params = parts[1];
+        for pair in hw_params.split('&') {
+            if let Some((key, value)) = pair.split_once('_') {
+                match key {
+                    "VEN" => info.vendor_id = value.to_string(),
+                    "DEV" => info.device_id = value.to_string(),
+                    "REV" => info.revision_id = value.to_string(),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    if let Some(subsystem) = SubsystemId::from_instance_id(dev_ins_id) {
+        info.subsystem_vendor_id = format!("{:04X}", subsystem.vendor_id);
+        info.subsystem_device_id = format!("{:04X}", subsystem.device_id);
+    }
+
+    // 实例 ID
+    if parts.len() > 2 {
+        let instance_params = parts[2];
+        info.instance_id = instance_params.to_string();
+
+        let instance_parts: Vec<&str> = instance_params.split('&').collect();
+
+        if !instance_parts.is_empty() {
+            info.bus_number = instance_parts[0].to_string();
+        }
+        if instance_parts.len() >= 2 {
+            info.feature_code = instance_parts[1].to_string();
+        }
+        if instance_parts.len() >= 3 {
+            info.device_number = instance_parts[2].to_string();
+        }
+        if instance_parts.len() >= 4 {
+            info.function_number = instance_parts[3].to_string();
+        }
+    }
+
+    info
+}
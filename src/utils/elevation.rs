@@ -0,0 +1,75 @@
+//! 进程权限模块
+//!
+//! 判断当前进程是否以管理员身份运行，并支持以管理员身份重新启动程序
+
+use super::string::wide_str;
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+use windows::Win32::Security::{GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY};
+use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+use windows::Win32::UI::Shell::ShellExecuteW;
+use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+/// 当前进程是否以管理员身份运行
+pub fn is_elevated() -> bool {
+    unsafe {
+        let mut token = HANDLE::default();
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token).is_err() {
+            return false;
+        }
+        let _guard = scopeguard::guard(token, |h| {
+            let _ = CloseHandle(h);
+        });
+
+        let mut elevation = TOKEN_ELEVATION::default();
+        let mut return_length = 0u32;
+        GetTokenInformation(
+            token,
+            TokenElevation,
+            Some(&mut elevation as *mut _ as *mut _),
+            std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+            &mut return_length,
+        )
+        .is_ok()
+            && elevation.TokenIsElevated != 0
+    }
+}
+
+/// 以管理员身份重新启动当前程序
+///
+/// 会弹出 UAC 确认框，用户拒绝时返回错误
+///
+/// * `args` - 传给新进程的命令行参数
+pub fn relaunch_elevated(args: &[String]) -> Result<(), String> {
+    let exe = std::env::current_exe().map_err(|e| format!("无法获取程序路径: {}", e))?;
+    let parameters = args
+        .iter()
+        .map(|arg| format!("\"{}\"", arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let operation = wide_str("runas");
+    let file = wide_str(&exe.to_string_lossy());
+    let parameters = wide_str(&parameters);
+
+    let result = unsafe {
+        ShellExecuteW(
+            None,
+            PCWSTR::from_raw(operation.as_ptr()),
+            PCWSTR::from_raw(file.as_ptr()),
+            PCWSTR::from_raw(parameters.as_ptr()),
+            PCWSTR::null(),
+            SW_SHOWNORMAL,
+        )
+    };
+
+    // 返回值大于 32 表示成功
+    if result.0 as isize > 32 {
+        Ok(())
+    } else {
+        Err(format!(
+            "以管理员身份启动失败（错误码 {}）",
+            result.0 as isize
+        ))
+    }
+}
@@ -0,0 +1,67 @@
+//! 动态链接库加载模块
+//!
+//! 按需加载显卡厂商运行库等可选组件，库不存在时由调用方跳过对应功能
+
+use super::string::wide_str;
+use std::ffi::CString;
+use windows::core::{PCSTR, PCWSTR};
+use windows::Win32::Foundation::{FreeLibrary, HMODULE};
+use windows::Win32::System::LibraryLoader::{
+    GetProcAddress, LoadLibraryExW, LoadLibraryW, LOAD_LIBRARY_SEARCH_SYSTEM32,
+};
+
+/// 已加载的动态链接库，释放时卸载
+pub struct Library {
+    module: HMODULE,
+}
+
+impl Library {
+    /// 从系统目录加载动态链接库，不在当前目录与 PATH 中查找，避免加载同名的其他 DLL
+    ///
+    /// * `name` - 库文件名，如 "nvml.dll"
+    pub fn open_system(name: &str) -> Option<Self> {
+        let name_wide = wide_str(name);
+        unsafe {
+            LoadLibraryExW(
+                PCWSTR::from_raw(name_wide.as_ptr()),
+                None,
+                LOAD_LIBRARY_SEARCH_SYSTEM32,
+            )
+        }
+        .map(|module| Self { module })
+        .map_err(|e| log::debug!("加载 {} 失败: {:?}", name, e))
+        .ok()
+    }
+
+    /// 从完整路径加载动态链接库
+    ///
+    /// * `path` - 库文件的完整路径
+    pub fn open(path: &str) -> Option<Self> {
+        let path_wide = wide_str(path);
+        unsafe { LoadLibraryW(PCWSTR::from_raw(path_wide.as_ptr())) }
+            .map(|module| Self { module })
+            .map_err(|e| log::debug!("加载 {} 失败: {:?}", path, e))
+            .ok()
+    }
+
+    /// 获取导出函数，不存在时返回 None
+    ///
+    /// * `name` - 导出函数名
+    ///
+    /// # Safety
+    /// `T` 必须是与导出函数签名一致的函数指针类型
+    pub unsafe fn symbol<T: Copy>(&self, name: &str) -> Option<T> {
+        debug_assert_eq!(std::mem::size_of::<T>(), std::mem::size_of::<usize>());
+        let name = CString::new(name).ok()?;
+        let address = GetProcAddress(self.module, PCSTR(name.as_ptr() as *const u8))?;
+        Some(std::mem::transmute_copy(&address))
+    }
+}
+
+impl Drop for Library {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = FreeLibrary(self.module);
+        }
+    }
+}
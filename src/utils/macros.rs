@@ -1,20 +1,90 @@
-/// 宏：生成硬件检测方法
+/// 宏：调用模块中的检测函数，并将结果包装为对应类别的检测输出
 ///
-/// 这个宏用于减少重复的检测方法代码
+/// 这个宏用于减少重复的检测代码。超时与平台不支持错误保持原样，便于调用方区分
 ///
 /// # 参数
-/// * `$name` - 检测方法名称
-/// * `$field` - HardwareDetector 中的字段名
-/// * `$module` - 检测函数所在的模块
-/// * `$detect_fn` - 模块中的检测函数名
+/// * `$variant` - CategoryOutput 的变体
+/// * `$backend` - 检测后端
+/// * `$detect_fn` - 后端 trait 中的检测方法名
 /// * `$error_variant` - DetectionError 的错误变体
 #[macro_export]
-macro_rules! impl_detect_method {
-    ($name:ident, $field:ident, $module:ident, $detect_fn:ident, $error_variant:ident) => {
-        fn $name(&mut self) -> Result<(), DetectionError> {
-            self.$field =
-                $module::$detect_fn().map_err(|e| DetectionError::$error_variant(e.to_string()))?;
-            Ok(())
+macro_rules! detect_output {
+    ($variant:ident, $backend:expr, $detect_fn:ident, $error_variant:ident) => {
+        $backend
+            .$detect_fn()
+            .map(CategoryOutput::$variant)
+            .map_err(|e| match e {
+                DetectionError::Timeout(_) | DetectionError::Unsupported(_) => e,
+                e => DetectionError::$error_variant(e.to_string()),
+            })
+    };
+}
+
+/// 宏：声明 WMI 属性结构体，并实现 [`FromWmiRow`](crate::utils::wmi::FromWmiRow) 与
+/// [`FromDataRow`](crate::detector::source::FromDataRow)
+///
+/// 每个字段写作 `字段名: 类型 = "WMI 属性名"`，生成的字段类型为 `Option<类型>`，
+/// 属性不存在或类型无法转换时为 None。字段类型需要实现
+/// [`FromVariant`](crate::utils::wmi::FromVariant) 与 [`FromValue`](crate::detector::source::FromValue)。
+/// `FromWmiRow` 的实现只在 Windows 上生成，`FromDataRow` 的实现可用于模拟数据源
+///
+/// # 示例
+/// ```
+/// use hardware_master::wmi_row;
+///
+/// wmi_row! {
+///     /// Win32_BIOS 中用到的属性
+///     #[derive(PartialEq)]
+///     pub struct BiosRow {
+///         /// BIOS 版本
+///         version: String = "SMBIOSBIOSVersion",
+///         release_date: String = "ReleaseDate",
+///     }
+/// }
+///
+/// let row = BiosRow::default();
+/// assert_eq!(row.version, None);
+///
+/// use hardware_master::detector::source::{DataRow, FromDataRow};
+/// let row = BiosRow::from_data_row(&DataRow::new().with("SMBIOSBIOSVersion", "F12"));
+/// assert_eq!(row.version.as_deref(), Some("F12"));
+/// assert_eq!(row.release_date, None);
+/// ```
+#[macro_export]
+macro_rules! wmi_row {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $ty:ty = $property:literal
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default)]
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: Option<$ty>,
+            )*
+        }
+
+        #[cfg(windows)]
+        impl $crate::utils::wmi::FromWmiRow for $name {
+            fn from_row(row: &$crate::utils::wmi::WmiRow) -> Self {
+                Self {
+                    $($field: row.get::<$ty>($property),)*
+                }
+            }
+        }
+
+        impl $crate::detector::source::FromDataRow for $name {
+            fn from_data_row(row: &$crate::detector::source::DataRow) -> Self {
+                Self {
+                    $($field: row.get::<$ty>($property),)*
+                }
+            }
         }
     };
 }
@@ -33,11 +33,11 @@ pub fn diagonal_inches_from_cm(w_cm: f64, h_cm: f64) -> f64 {
 /// ```
 /// use hardware_master::utils::math::div;
 /// assert_eq!(div(1.0, 2.0), 0.5);
-/// assert_eq!(div(1.0, 0.0), std::f64::INFINITY);
+/// assert_eq!(div(1.0, 0.0), f64::INFINITY);
 /// ```
 pub fn div(a: f64, b: f64) -> f64 {
     if b == 0.0 {
-        std::f64::INFINITY
+        f64::INFINITY
     } else {
         a / b
     }
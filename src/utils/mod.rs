@@ -1,15 +1,27 @@
 //! 工具模块
 //!
-//! 提供各种辅助功能，包括字符串转换、数学计算、WMI 查询、注册表操作和设备操作等。
+//! 提供各种辅助功能，包括字符串转换、数学计算、WMI 查询、注册表操作、设备操作、动态库加载和进程权限等。
 
+#[cfg(windows)]
 pub mod device;
+pub mod device_id;
+#[cfg(windows)]
+pub mod elevation;
+#[cfg(windows)]
+pub mod library;
 pub mod macros;
 pub mod math;
+#[cfg(windows)]
 pub mod registry;
+pub mod size;
+pub mod smbios;
 pub mod string;
+pub mod subsystem;
+#[cfg(windows)]
 pub mod wmi;
 pub mod wmi_date;
 
 pub use math::{cm_to_inches, diagonal_inches_from_cm, div};
+pub use size::ByteSize;
 pub use string::{u16_bytes_to_string, u16_slice_to_string, u8_slice_to_string, wide_str};
 pub use wmi_date::parse_wmi_date;
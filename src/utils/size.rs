@@ -0,0 +1,131 @@
+//! 字节数
+//!
+//! 内存、硬盘、分区与显存的容量统一以 [`ByteSize`] 保存字节数，
+//! 显示时都经过同一个格式化实现，避免各处换算单位时结果不一致
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+/// 显示单位，按 1024 进位
+const UNITS: [&str; 6] = ["B", "KB", "MB", "GB", "TB", "PB"];
+
+/// 字节数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// 0 字节
+    pub const ZERO: ByteSize = ByteSize(0);
+
+    /// 由字节数创建
+    pub const fn from_bytes(bytes: u64) -> Self {
+        Self(bytes)
+    }
+
+    /// 由 KB 数创建
+    pub const fn from_kb(kb: u64) -> Self {
+        Self(kb * 1024)
+    }
+
+    /// 由 MB 数创建
+    pub const fn from_mb(mb: u64) -> Self {
+        Self(mb * 1024 * 1024)
+    }
+
+    /// 由 GB 数创建
+    pub const fn from_gb(gb: u64) -> Self {
+        Self(gb * 1024 * 1024 * 1024)
+    }
+
+    /// 字节数
+    pub const fn bytes(self) -> u64 {
+        self.0
+    }
+
+    /// 字节数（浮点），用于计算占比等
+    pub fn as_f64(self) -> f64 {
+        self.0 as f64
+    }
+
+    /// 以 GB 为单位的容量
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::utils::size::ByteSize;
+    /// assert_eq!(ByteSize::from_mb(1536).as_gb(), 1.5);
+    /// ```
+    pub fn as_gb(self) -> f64 {
+        self.as_f64() / (1024.0 * 1024.0 * 1024.0)
+    }
+
+    /// 是否为 0 字节（通常表示未知）
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        Self(bytes)
+    }
+}
+
+/// 由浮点字节数转换，小数部分舍去，负数与 NaN 视为 0
+impl From<f64> for ByteSize {
+    fn from(bytes: f64) -> Self {
+        Self(bytes as u64)
+    }
+}
+
+impl Add for ByteSize {
+    type Output = ByteSize;
+
+    fn add(self, other: ByteSize) -> ByteSize {
+        ByteSize(self.0.saturating_add(other.0))
+    }
+}
+
+impl AddAssign for ByteSize {
+    fn add_assign(&mut self, other: ByteSize) {
+        *self = *self + other;
+    }
+}
+
+impl Sum for ByteSize {
+    fn sum<I: Iterator<Item = ByteSize>>(iter: I) -> Self {
+        iter.fold(ByteSize::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a ByteSize> for ByteSize {
+    fn sum<I: Iterator<Item = &'a ByteSize>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// 按 1024 进位取整显示，如 "16 GB"、"512 MB"；四舍五入后满 1024 的值进到下一个单位
+///
+/// 示例
+/// ```
+/// use hardware_master::utils::size::ByteSize;
+/// assert_eq!(ByteSize::from_gb(16).to_string(), "16 GB");
+/// assert_eq!(ByteSize::from_bytes(1023).to_string(), "1023 B");
+/// assert_eq!(ByteSize::from_bytes(1024 * 1024 * 1024 - 1).to_string(), "1 GB");
+/// ```
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.as_f64();
+        let mut unit = 0;
+        while value >= 1023.5 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.0} {}", value, UNITS[unit])
+    }
+}
@@ -0,0 +1,183 @@
+//! SMBIOS 表解析模块
+//!
+//! 通过 `GetSystemFirmwareTable('RSMB')` 读取原始 SMBIOS 表，
+//! 用于获取 WMI 未提供的字段（如主板资产标签、SMBIOS 版本）。
+
+#[cfg(windows)]
+use windows::Win32::System::SystemInformation::{GetSystemFirmwareTable, RSMB};
+
+/// RawSMBIOSData 头部长度（调用方式、主次版本号、DMI 修订号与表长度）
+const RAW_HEADER_LEN: usize = 8;
+
+/// 结束标记结构类型
+const END_OF_TABLE: u8 = 127;
+
+/// 解析后的 SMBIOS 表
+#[derive(Debug, Clone)]
+pub struct SmbiosTable {
+    /// SMBIOS 版本，如 "3.4"
+    pub version: String,
+    /// 结构列表
+    pub structures: Vec<SmbiosStructure>,
+}
+
+impl Default for SmbiosTable {
+    fn default() -> Self {
+        Self {
+            version: "未知".to_string(),
+            structures: Vec::new(),
+        }
+    }
+}
+
+/// 单个 SMBIOS 结构
+#[derive(Debug, Clone)]
+pub struct SmbiosStructure {
+    /// 结构类型，如 1 系统信息、2 主板信息、3 机箱信息
+    pub kind: u8,
+    /// 结构句柄
+    pub handle: u16,
+    /// 格式化区域（包含 4 字节结构头）
+    pub formatted: Vec<u8>,
+    /// 字符串区域，按出现顺序排列
+    pub strings: Vec<String>,
+}
+
+impl SmbiosStructure {
+    /// 读取格式化区域中指定偏移的字节
+    pub fn byte(&self, offset: usize) -> Option<u8> {
+        self.formatted.get(offset).copied()
+    }
+
+    /// 读取格式化区域中指定偏移处引用的字符串
+    ///
+    /// 字符串编号从 1 开始，0 表示未设置
+    pub fn string(&self, offset: usize) -> Option<&str> {
+        let index = self.byte(offset)? as usize;
+        if index == 0 {
+            return None;
+        }
+        self.strings
+            .get(index - 1)
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+    }
+}
+
+/// 解析 SMBIOS 结构表
+///
+/// * `table` - 不含 RawSMBIOSData 头部的结构表数据
+pub fn parse_structures(table: &[u8]) -> Vec<SmbiosStructure> {
+    let mut structures = Vec::new();
+    let mut pos = 0;
+
+    while pos + 4 <= table.len() {
+        let kind = table[pos];
+        let length = table[pos + 1] as usize;
+        if length < 4 || pos + length > table.len() {
+            break;
+        }
+        let handle = u16::from_le_bytes([table[pos + 2], table[pos + 3]]);
+        let formatted = table[pos..pos + length].to_vec();
+
+        // 字符串区域紧跟格式化区域，每个字符串以 0 结尾
+        let mut strings = Vec::new();
+        let mut cursor = pos + length;
+        while cursor < table.len() && table[cursor] != 0 {
+            let Some(end) = table[cursor..].iter().position(|&b| b == 0) else {
+                return structures;
+            };
+            strings.push(String::from_utf8_lossy(&table[cursor..cursor + end]).into_owned());
+            cursor += end + 1;
+        }
+        // 字符串区域以额外的 0 结束，没有字符串时为两个 0
+        cursor += if strings.is_empty() { 2 } else { 1 };
+
+        structures.push(SmbiosStructure {
+            kind,
+            handle,
+            formatted,
+            strings,
+        });
+        if kind == END_OF_TABLE {
+            break;
+        }
+        pos = cursor;
+    }
+
+    structures
+}
+
+/// 解析 `GetSystemFirmwareTable('RSMB')` 返回的 RawSMBIOSData（含头部）
+///
+/// 示例
+/// ```
+/// use hardware_master::utils::smbios::parse_raw_data;
+/// let raw = [0, 3, 4, 0, 0, 0, 0, 0, 127, 4, 0, 0, 0, 0];
+/// let table = parse_raw_data(&raw);
+/// assert_eq!(table.version, "3.4");
+/// assert_eq!(table.structures.len(), 1);
+/// assert_eq!(parse_raw_data(&[]).version, "未知");
+/// ```
+pub fn parse_raw_data(raw: &[u8]) -> SmbiosTable {
+    if raw.len() < RAW_HEADER_LEN {
+        return SmbiosTable::default();
+    }
+    let (major, minor) = (raw[1], raw[2]);
+    SmbiosTable {
+        version: if major == 0 {
+            "未知".to_string()
+        } else {
+            format!("{}.{}", major, minor)
+        },
+        structures: parse_structures(&raw[RAW_HEADER_LEN..]),
+    }
+}
+
+/// 格式化 SMBIOS 系统 UUID
+///
+/// SMBIOS 2.6 起前三段按小端序存储；全 0 或全 FF 表示未设置
+///
+/// 示例
+/// ```
+/// use hardware_master::utils::smbios::format_uuid;
+/// let bytes = [
+///     0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66,
+///     0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+/// ];
+/// assert_eq!(
+///     format_uuid(&bytes).as_deref(),
+///     Some("00112233-4455-6677-8899-AABBCCDDEEFF")
+/// );
+/// assert_eq!(format_uuid(&[0; 16]), None);
+/// ```
+pub fn format_uuid(bytes: &[u8]) -> Option<String> {
+    if bytes.len() != 16 || bytes.iter().all(|&b| b == 0) || bytes.iter().all(|&b| b == 0xFF) {
+        return None;
+    }
+    let b = bytes;
+    Some(format!(
+        "{:02X}{:02X}{:02X}{:02X}-{:02X}{:02X}-{:02X}{:02X}-{:02X}{:02X}-{:02X}{:02X}{:02X}{:02X}{:02X}{:02X}",
+        b[3], b[2], b[1], b[0], b[5], b[4], b[7], b[6],
+        b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]
+    ))
+}
+
+/// 读取并解析本机的 SMBIOS 表，读取失败时返回空表
+#[cfg(windows)]
+pub fn read_table() -> SmbiosTable {
+    unsafe {
+        let size = GetSystemFirmwareTable(RSMB, 0, None);
+        if size == 0 {
+            log::warn!("读取 SMBIOS 表失败");
+            return SmbiosTable::default();
+        }
+        let mut buffer = vec![0u8; size as usize];
+        if GetSystemFirmwareTable(RSMB, 0, Some(&mut buffer)) == 0 || buffer.len() < RAW_HEADER_LEN
+        {
+            log::warn!("读取 SMBIOS 表失败");
+            return SmbiosTable::default();
+        }
+        parse_raw_data(&buffer)
+    }
+}
@@ -1,7 +1,11 @@
 //! 字符串转换工具模块
 
+use super::size::ByteSize;
+#[cfg(windows)]
 use std::ffi::OsString;
+#[cfg(windows)]
 use std::os::windows::ffi::OsStringExt;
+#[cfg(windows)]
 use windows::core::PWSTR;
 
 /// 移除切片末尾所有的零值元素。
@@ -100,7 +104,7 @@ pub fn u8_slice_to_string(v: &[u8]) -> String {
 /// assert_eq!(u16_bytes_to_string(&data), "Hello");
 /// ```
 pub fn u16_bytes_to_string(bytes: &[u8]) -> String {
-    if bytes.len() % 2 != 0 {
+    if !bytes.len().is_multiple_of(2) {
         return String::new();
     }
 
@@ -144,6 +148,7 @@ pub fn wide_str(s: &str) -> Vec<u16> {
 /// };
 /// assert_eq!(result, "Hello");
 /// ```
+#[cfg(windows)]
 pub unsafe fn pwstr_to_string(pwstr: PWSTR) -> String {
     if pwstr.is_null() {
         return String::new();
@@ -153,13 +158,67 @@ pub unsafe fn pwstr_to_string(pwstr: PWSTR) -> String {
     OsString::from_wide(slice).to_string_lossy().into_owned()
 }
 
-/// 格式化
-pub fn format_size(size: f64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB", "PB"];
-    if size <= 0.0 {
-        return "0 B".to_string();
+/// 格式化字节数，与 [`ByteSize`] 的显示相同
+///
+/// 示例
+/// ```
+/// use hardware_master::utils::string::format_size;
+/// assert_eq!(format_size(0.0), "0 B");
+/// assert_eq!(format_size(512.0 * 1024.0 * 1024.0), "512 MB");
+/// ```
+pub fn format_size(size: impl Into<ByteSize>) -> String {
+    size.into().to_string()
+}
+
+/// 遮盖敏感信息（如序列号），仅保留首尾各 2 个字符
+///
+/// 长度不超过 4 的值全部遮盖，"未知" 保持原样
+///
+/// 示例
+/// ```
+/// use hardware_master::utils::string::mask_sensitive;
+/// assert_eq!(mask_sensitive("PF2ABCDE"), "PF****DE");
+/// assert_eq!(mask_sensitive("1234"), "****");
+/// assert_eq!(mask_sensitive("未知"), "未知");
+/// ```
+pub fn mask_sensitive(value: &str) -> String {
+    if value == "未知" {
+        return value.to_string();
+    }
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() <= 4 {
+        return "*".repeat(chars.len());
+    }
+    let head: String = chars[..2].iter().collect();
+    let tail: String = chars[chars.len() - 2..].iter().collect();
+    format!("{}{}{}", head, "*".repeat(chars.len() - 4), tail)
+}
+
+/// 格式化处理器核心数，用于界面与报告显示
+///
+/// * `physical` - 物理核心数，0 表示未知
+/// * `logical` - 逻辑核心数
+/// * `efficiency` - 能效核心数，非混合架构处理器为 0
+///
+/// 示例
+/// ```
+/// use hardware_master::utils::string::format_cores;
+/// assert_eq!(format_cores(8, 16, 0), "物理核：8 / 逻辑核：16");
+/// assert_eq!(format_cores(14, 20, 8), "物理核：14 (6P + 8E) / 逻辑核：20");
+/// assert_eq!(format_cores(0, 0, 0), "未知");
+/// ```
+pub fn format_cores(physical: u32, logical: u32, efficiency: u32) -> String {
+    if physical == 0 {
+        return "未知".to_string();
+    }
+    if efficiency == 0 {
+        return format!("物理核：{} / 逻辑核：{}", physical, logical);
     }
-    let exp = size.log(1024.0).min(UNITS.len() as f64 - 1.0) as usize;
-    let value = size as f64 / 1024f64.powi(exp as i32);
-    format!("{:.0} {}", value, UNITS[exp])
+    format!(
+        "物理核：{} ({}P + {}E) / 逻辑核：{}",
+        physical,
+        physical.saturating_sub(efficiency),
+        efficiency,
+        logical
+    )
 }
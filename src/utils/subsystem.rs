@@ -0,0 +1,110 @@
+//! PCI 子系统 ID 解析模块
+//!
+//! 子系统 ID 由板卡厂商填写，PCI 设备实例 ID 中写作 `SUBSYS_ssssvvvv`（高 16 位为子系统设备 ID，
+//! 低 16 位为子系统厂商 ID），DXGI 的 `SubSysId` 为同样布局的 32 位整数。
+//! 显卡、声卡等由子系统厂商 ID 识别板卡厂商（如七彩虹、微星）。
+
+use std::fmt;
+
+/// PCI 子系统 ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubsystemId {
+    /// 子系统厂商 ID（板卡厂商）
+    pub vendor_id: u16,
+    /// 子系统设备 ID（板卡型号）
+    pub device_id: u16,
+}
+
+impl SubsystemId {
+    /// 由 32 位子系统 ID 转换，厂商 ID 为 0 表示驱动未提供，返回 None
+    ///
+    /// * `raw` - 子系统 ID，如 DXGI 的 `SubSysId`
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::utils::subsystem::SubsystemId;
+    /// let id = SubsystemId::from_raw(0x16F3_10DE).unwrap();
+    /// assert_eq!((id.vendor_id, id.device_id), (0x10de, 0x16f3));
+    /// assert_eq!(SubsystemId::from_raw(0), None);
+    /// ```
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        let vendor_id = (raw & 0xFFFF) as u16;
+        let device_id = (raw >> 16) as u16;
+        (vendor_id != 0).then_some(Self {
+            vendor_id,
+            device_id,
+        })
+    }
+
+    /// 解析 8 位十六进制的子系统 ID（允许 0x 前缀与首尾空白），位数不足或含非十六进制字符时返回 None
+    ///
+    /// * `text` - PCI 设备的子系统 ID，如 "7D251462"
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::utils::subsystem::SubsystemId;
+    /// let id = SubsystemId::parse("7D251462").unwrap();
+    /// assert_eq!((id.vendor_id, id.device_id), (0x1462, 0x7d25));
+    /// assert_eq!(SubsystemId::parse("1462"), None);
+    /// assert_eq!(SubsystemId::parse("00000000"), None);
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        Self::from_raw(parse_raw(text)?)
+    }
+
+    /// 从设备实例 ID 或硬件 ID 的 `SUBSYS_` 参数中解析子系统 ID
+    ///
+    /// PCI 设备的厂商 ID 在低 16 位；HD Audio 编解码器的子系统 ID 取自编解码器寄存器，厂商 ID 在高 16 位
+    ///
+    /// * `instance_id` - 设备实例 ID，如 `PCI\VEN_10DE&DEV_2684&SUBSYS_16F310DE&REV_A1\4&...`
+    ///
+    /// 示例
+    /// ```
+    /// use hardware_master::utils::subsystem::SubsystemId;
+    /// let gpu = SubsystemId::from_instance_id(r"PCI\VEN_10DE&DEV_2684&SUBSYS_16F37377&REV_A1\4&1")
+    ///     .unwrap();
+    /// assert_eq!(gpu.vendor_id, 0x7377);
+    /// let codec = SubsystemId::from_instance_id(r"HDAUDIO\FUNC_01&VEN_10EC&DEV_0897&SUBSYS_1462D001\4&1")
+    ///     .unwrap();
+    /// assert_eq!(codec.vendor_id, 0x1462);
+    /// assert_eq!(SubsystemId::from_instance_id(r"PCI\VEN_10DE&DEV_2684\4&1"), None);
+    /// ```
+    pub fn from_instance_id(instance_id: &str) -> Option<Self> {
+        let mut parts = instance_id.split('\\');
+        let enumerator = parts.next()?;
+        let value = parts.next()?.split('&').find_map(|pair| {
+            let (key, value) = pair.split_once('_')?;
+            key.eq_ignore_ascii_case("SUBSYS").then_some(value)
+        })?;
+        let raw = parse_raw(value)?;
+        if enumerator.eq_ignore_ascii_case("HDAUDIO") {
+            Self::from_raw(raw.rotate_left(16))
+        } else {
+            Self::from_raw(raw)
+        }
+    }
+
+    /// 32 位子系统 ID
+    pub fn raw(&self) -> u32 {
+        ((self.device_id as u32) << 16) | self.vendor_id as u32
+    }
+}
+
+impl fmt::Display for SubsystemId {
+    /// 按 PCI 设备实例 ID 中的格式输出，如 "16F310DE"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08X}", self.raw())
+    }
+}
+
+/// 解析 8 位十六进制数（允许 0x 前缀与首尾空白）
+fn parse_raw(text: &str) -> Option<u32> {
+    let text = text
+        .trim()
+        .trim_start_matches("0x")
+        .trim_start_matches("0X");
+    if text.len() != 8 || !text.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(text, 16).ok()
+}
@@ -1,66 +1,252 @@
 //! Windows WMI (Windows Management Instrumentation) 查询模块
 //!
 //! 提供简化的 WMI 查询接口，封装了 Windows COM 初始化、WMI 服务连接和查询操作。
-
+//! 连接与查询在工作线程中执行并带有超时，WMI 存储库损坏时不会使检测永久卡住。
+//! 同一命名空间的连接在进程内缓存复用，各检测器无需重复建立连接。
+//! 查询结果通过 [`WmiRow`] 按类型读取属性，检测器可以用 [`crate::wmi_row!`]
+//! 声明属性结构体并通过 [`WmiClient::query_as`] 直接得到结构体列表。
+
+use crate::constants::WMI_TIMEOUT_SECS;
+use crate::detector::source::{DataRow, Value};
+use crate::detector::{timing, DetectionError};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use windows::core::BSTR;
 use windows::Win32::System::Com::{
     CoCreateInstance, CoInitializeEx, CoSetProxyBlanket, CLSCTX_ALL, COINIT_MULTITHREADED,
     EOAC_NONE, RPC_C_AUTHN_LEVEL_PKT_PRIVACY, RPC_C_IMP_LEVEL_IMPERSONATE,
 };
+use windows::Win32::System::Com::SAFEARRAY;
 use windows::Win32::System::Ole::{
     SafeArrayAccessData, SafeArrayGetLBound, SafeArrayGetUBound, SafeArrayUnaccessData,
 };
 use windows::Win32::System::Rpc::{RPC_C_AUTHN_NONE, RPC_C_AUTHN_WINNT};
-use windows::Win32::System::Variant::{VariantInit, VARIANT, VT_BSTR, VT_I2, VT_I4, VT_UI1, VT_UI2, VT_UI8, VT_BOOL};
+use windows::Win32::System::Variant::{VariantClear, VariantInit, VARENUM, VARIANT, VT_ARRAY, VT_BSTR, VT_EMPTY, VT_I1, VT_I2, VT_I4, VT_I8, VT_INT, VT_NULL, VT_R4, VT_R8, VT_UI1, VT_UI2, VT_UI4, VT_UI8, VT_UINT, VT_BOOL};
 use windows::Win32::System::Wmi::{
     IEnumWbemClassObject, IWbemClassObject, IWbemLocator, IWbemServices, WbemLocator,
-    WBEM_FLAG_FORWARD_ONLY, WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE,
+    WBEM_FLAG_FORWARD_ONLY, WBEM_FLAG_NONSYSTEM_ONLY, WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE,
 };
 
+/// 默认查询超时（毫秒），可通过 [`set_default_timeout`] 修改
+static DEFAULT_TIMEOUT_MS: AtomicU64 = AtomicU64::new(WMI_TIMEOUT_SECS * 1000);
+
+/// 设置 [`WmiConfig::default`] 使用的超时时间
+///
+/// * `timeout` - 连接或单次查询的最长等待时间
+pub fn set_default_timeout(timeout: Duration) {
+    DEFAULT_TIMEOUT_MS.store(timeout.as_millis() as u64, Ordering::Relaxed);
+}
+
+/// 获取默认超时时间
+pub fn default_timeout() -> Duration {
+    Duration::from_millis(DEFAULT_TIMEOUT_MS.load(Ordering::Relaxed))
+}
+
 /// WMI 连接配置
 #[derive(Debug, Clone)]
 pub struct WmiConfig {
     /// WMI 命名空间，如 "ROOT\\CIMV2" 或 "ROOT\\wmi"
     pub namespace: String,
+    /// 连接或单次查询的超时时间，超时后返回 [`DetectionError::Timeout`]
+    pub timeout: Duration,
 }
 
 impl Default for WmiConfig {
     fn default() -> Self {
         Self {
             namespace: "ROOT\\CIMV2".to_string(),
+            timeout: default_timeout(),
+        }
+    }
+}
+
+/// 表格形式的 WMI 查询结果
+#[derive(Debug, Clone, Default)]
+pub struct WmiTable {
+    /// 列名（属性名称）
+    pub columns: Vec<String>,
+    /// 行数据，与列名一一对应
+    pub rows: Vec<Vec<String>>,
+}
+
+/// 在多线程套间（MTA）线程之间转移 COM 对象
+///
+/// WMI 对象均在 MTA 中创建，同一套间内的任意线程都可以使用
+struct MtaObject<T>(T);
+
+unsafe impl<T> Send for MtaObject<T> {}
+
+impl<T> MtaObject<T> {
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// 在工作线程中执行 WMI 调用，超时后放弃等待
+///
+/// 损坏的 WMI 存储库可能使调用永久阻塞，超时后工作线程会被遗弃，调用方继续执行
+///
+/// * `action` - 调用描述，用于错误信息
+/// * `timeout` - 最长等待时间
+/// * `call` - 在工作线程中执行的调用
+fn run_with_timeout<T, F>(action: &str, timeout: Duration, call: F) -> Result<T, DetectionError>
+where
+    T: 'static,
+    F: FnOnce() -> Result<T, DetectionError> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        }
+        let _ = tx.send(MtaObject(call()));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result.into_inner(),
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(DetectionError::Timeout(format!(
+            "{} 超过 {} 秒未响应",
+            action,
+            timeout.as_secs_f32()
+        ))),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(DetectionError::WindowsApiError(format!(
+            "{} 的工作线程异常退出",
+            action
+        ))),
+    }
+}
+
+/// 已建立的 WMI 连接，键为大写的命名空间
+type ServiceCache = Mutex<HashMap<String, MtaObject<IWbemServices>>>;
+
+/// 进程内共享的 WMI 连接缓存
+static SERVICE_CACHE: OnceLock<ServiceCache> = OnceLock::new();
+
+thread_local! {
+    /// 当前线程是否已初始化 COM
+    static COM_INITIALIZED: Cell<bool> = const { Cell::new(false) };
+}
+
+fn service_cache() -> &'static ServiceCache {
+    SERVICE_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// 命名空间不区分大小写，统一转换为大写作为缓存键
+fn cache_key(namespace: &str) -> String {
+    namespace.trim().to_ascii_uppercase()
+}
+
+/// 确保当前线程已加入 MTA，每个线程只初始化一次
+fn ensure_com_initialized() {
+    COM_INITIALIZED.with(|initialized| {
+        if !initialized.get() {
+            unsafe {
+                let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+            }
+            initialized.set(true);
         }
+    });
+}
+
+/// 清空 WMI 连接缓存，之后的查询会重新建立连接
+///
+/// 查询失败时会自动移除对应命名空间的连接，通常无需手动调用
+pub fn clear_connection_cache() {
+    if let Ok(mut cache) = service_cache().lock() {
+        cache.clear();
     }
 }
 
 /// WMI 查询结果迭代器
 pub struct WmiQueryResult {
-    enumerator: IEnumWbemClassObject,
+    objects: std::vec::IntoIter<IWbemClassObject>,
 }
 
 impl WmiQueryResult {
     /// 获取下一个 WMI 对象
-    pub unsafe fn next(&mut self) -> Option<IWbemClassObject> {
-        let mut objs = [None; 1];
-        let mut returned = 0u32;
+    pub fn next(&mut self) -> Option<IWbemClassObject> {
+        self.objects.next()
+    }
 
-        let result = self.enumerator.Next(WBEM_INFINITE, &mut objs, &mut returned);
+    /// 将剩余的对象转换为 [`WmiRow`] 列表
+    pub fn into_rows(self) -> Vec<WmiRow> {
+        self.objects.map(WmiRow::from).collect()
+    }
+}
 
-        if result.is_err() || returned == 0 {
-            return None;
+/// 一行 WMI 查询结果，按类型安全地读取属性
+///
+/// # 示例
+/// ```ignore
+/// for row in client.query_rows("SELECT * FROM Win32_PhysicalMemory")? {
+///     let capacity = row.get::<u64>("Capacity");
+///     let manufacturer = row.get::<String>("Manufacturer");
+/// }
+/// ```
+pub struct WmiRow {
+    object: IWbemClassObject,
+}
+
+impl From<IWbemClassObject> for WmiRow {
+    fn from(object: IWbemClassObject) -> Self {
+        Self { object }
+    }
+}
+
+impl WmiRow {
+    /// 读取属性，属性不存在、值为空或类型无法转换时返回 None
+    ///
+    /// # 参数
+    /// * `name` - 属性名称
+    pub fn get<T: FromVariant>(&self, name: &str) -> Option<T> {
+        unsafe {
+            let mut var = get_property(&self.object, name).ok()?;
+            let value = T::from_variant(&var);
+            let _ = VariantClear(&mut var);
+            value
         }
+    }
+
+    /// 全部非系统属性的 (名称, 显示文本) 列表
+    pub fn properties(&self) -> Vec<(String, String)> {
+        unsafe { get_properties(&self.object) }
+    }
+
+    /// 全部非系统属性，转换为与平台无关的 [`DataRow`]
+    pub fn to_data_row(&self) -> DataRow {
+        unsafe { get_values(&self.object) }
+    }
 
-        objs[0].take()
+    /// 底层 WMI 对象
+    pub fn object(&self) -> &IWbemClassObject {
+        &self.object
     }
 }
 
+/// 可以从 WMI 行构造的类型，通常由 [`crate::wmi_row!`] 宏生成实现
+pub trait FromWmiRow: Sized {
+    /// 从一行查询结果中读取属性
+    fn from_row(row: &WmiRow) -> Self;
+}
+
 /// WMI 客户端
+///
+/// 连接与查询都在工作线程中执行，超过 [`WmiConfig::timeout`] 未完成时返回
+/// [`DetectionError::Timeout`]，避免一个卡住的查询阻塞整个检测
 pub struct WmiClient {
-    _server: IWbemServices,
+    server: IWbemServices,
+    namespace: String,
+    timeout: Duration,
 }
 
 impl WmiClient {
     /// 创建新的 WMI 客户端
     ///
+    /// 优先复用缓存中同一命名空间的连接，没有缓存时建立新连接并加入缓存
+    ///
     /// # 参数
     /// * `config` - WMI 连接配置
     ///
@@ -70,40 +256,72 @@ impl WmiClient {
     ///
     /// let config = WmiConfig {
     ///     namespace: "ROOT\\CIMV2".to_string(),
+    ///     ..Default::default()
     /// };
     /// let client = WmiClient::connect(&config)?;
     /// ```
-    pub unsafe fn connect(config: &WmiConfig) -> Result<Self, String> {
-        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
-
-        let locator: IWbemLocator = CoCreateInstance(&WbemLocator, None, CLSCTX_ALL)
-            .map_err(|e| format!("创建 WMI 定位器失败: {:?}", e))?;
-
-        let server: IWbemServices = locator
-            .ConnectServer(
-                &BSTR::from(&config.namespace),
-                &BSTR::new(),
-                &BSTR::new(),
-                &BSTR::new(),
-                0,
-                &BSTR::new(),
+    pub fn connect(config: &WmiConfig) -> Result<Self, DetectionError> {
+        // 查询结果在调用线程中使用，调用线程同样需要加入 MTA
+        ensure_com_initialized();
+
+        let key = cache_key(&config.namespace);
+        let cached = service_cache()
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(&key).map(|server| server.0.clone()));
+        if let Some(server) = cached {
+            return Ok(Self {
+                server,
+                namespace: key,
+                timeout: config.timeout,
+            });
+        }
+
+        let namespace = config.namespace.clone();
+        let action = format!("连接 WMI 命名空间 {}", namespace);
+        let server = run_with_timeout(&action, config.timeout, move || unsafe {
+            let locator: IWbemLocator = CoCreateInstance(&WbemLocator, None, CLSCTX_ALL)
+                .map_err(|e| {
+                    DetectionError::WindowsApiError(format!("创建 WMI 定位器失败: {:?}", e))
+                })?;
+
+            let server: IWbemServices = locator
+                .ConnectServer(
+                    &BSTR::from(&namespace),
+                    &BSTR::new(),
+                    &BSTR::new(),
+                    &BSTR::new(),
+                    0,
+                    &BSTR::new(),
+                    None,
+                )
+                .map_err(|e| {
+                    DetectionError::WindowsApiError(format!("连接到 WMI 服务失败: {:?}", e))
+                })?;
+
+            CoSetProxyBlanket(
+                &server,
+                RPC_C_AUTHN_WINNT,
+                RPC_C_AUTHN_NONE,
                 None,
+                RPC_C_AUTHN_LEVEL_PKT_PRIVACY,
+                RPC_C_IMP_LEVEL_IMPERSONATE,
+                None,
+                EOAC_NONE,
             )
-            .map_err(|e| format!("连接到 WMI 服务失败: {:?}", e))?;
+            .ok();
 
-        CoSetProxyBlanket(
-            &server,
-            RPC_C_AUTHN_WINNT,
-            RPC_C_AUTHN_NONE,
-            None,
-            RPC_C_AUTHN_LEVEL_PKT_PRIVACY,
-            RPC_C_IMP_LEVEL_IMPERSONATE,
-            None,
-            EOAC_NONE,
-        )
-        .ok();
+            Ok(server)
+        })?;
 
-        Ok(Self { _server: server })
+        if let Ok(mut cache) = service_cache().lock() {
+            cache.insert(key.clone(), MtaObject(server.clone()));
+        }
+        Ok(Self {
+            server,
+            namespace: key,
+            timeout: config.timeout,
+        })
     }
 
     /// 执行 WQL 查询
@@ -115,17 +333,92 @@ impl WmiClient {
     /// ```ignore
     /// let result = client.query("SELECT * FROM Win32_PhysicalMemory")?;
     /// ```
-    pub unsafe fn query(&self, query: &str) -> Result<WmiQueryResult, String> {
-        let enumerator: IEnumWbemClassObject = self._server
-            .ExecQuery(
-                &BSTR::from("WQL"),
-                &BSTR::from(query),
-                WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY,
-                None,
-            )
-            .map_err(|e| format!("执行 WMI 查询失败: {:?}", e))?;
+    pub fn query(&self, query: &str) -> Result<WmiQueryResult, DetectionError> {
+        self.query_limited(query, usize::MAX)
+    }
 
-        Ok(WmiQueryResult { enumerator })
+    /// 执行 WQL 查询，返回 [`WmiRow`] 列表
+    ///
+    /// # 参数
+    /// * `query` - WQL 查询语句
+    pub fn query_rows(&self, query: &str) -> Result<Vec<WmiRow>, DetectionError> {
+        Ok(self.query(query)?.into_rows())
+    }
+
+    /// 执行 WQL 查询，并将每一行转换为 `T`
+    ///
+    /// # 参数
+    /// * `query` - WQL 查询语句
+    ///
+    /// # 示例
+    /// ```ignore
+    /// wmi_row! {
+    ///     struct BiosRow {
+    ///         version: String = "SMBIOSBIOSVersion",
+    ///     }
+    /// }
+    ///
+    /// let rows: Vec<BiosRow> = client.query_as("SELECT * FROM Win32_BIOS")?;
+    /// ```
+    pub fn query_as<T: FromWmiRow>(&self, query: &str) -> Result<Vec<T>, DetectionError> {
+        Ok(self.query_rows(query)?.iter().map(T::from_row).collect())
+    }
+
+    /// 执行 WQL 查询，最多读取 `max_rows` 个对象
+    ///
+    /// # 参数
+    /// * `query` - WQL 查询语句
+    /// * `max_rows` - 最多读取的对象数
+    pub fn query_limited(
+        &self,
+        query: &str,
+        max_rows: usize,
+    ) -> Result<WmiQueryResult, DetectionError> {
+        let server = MtaObject(self.server.clone());
+        let wql = query.to_string();
+        let action = format!("WMI 查询 \"{}\"", query);
+        let started = Instant::now();
+        let result = run_with_timeout(&action, self.timeout, move || unsafe {
+            let server = server.into_inner();
+            let enumerator: IEnumWbemClassObject = server
+                .ExecQuery(
+                    &BSTR::from("WQL"),
+                    &BSTR::from(&wql),
+                    WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY,
+                    None,
+                )
+                .map_err(|e| {
+                    DetectionError::WindowsApiError(format!("执行 WMI 查询失败: {:?}", e))
+                })?;
+
+            let mut objects = Vec::new();
+            while objects.len() < max_rows {
+                let mut objs = [None; 1];
+                let mut returned = 0u32;
+                let result = enumerator.Next(WBEM_INFINITE, &mut objs, &mut returned);
+                if result.is_err() || returned == 0 {
+                    break;
+                }
+                match objs[0].take() {
+                    Some(obj) => objects.push(obj),
+                    None => break,
+                }
+            }
+            Ok(objects)
+        });
+        timing::record_query(query, started.elapsed());
+
+        // 连接可能已失效（如 WMI 服务重启），移除缓存，下次重新连接
+        if result.is_err() {
+            if let Ok(mut cache) = service_cache().lock() {
+                cache.remove(&self.namespace);
+            }
+        }
+        let objects = result?;
+
+        Ok(WmiQueryResult {
+            objects: objects.into_iter(),
+        })
     }
 }
 
@@ -313,3 +606,308 @@ pub unsafe fn variant_to_u16_slice(var: &VARIANT) -> Option<Vec<u16>> {
         None
     }
 }
+
+/// 读取整数类型的 VARIANT，WMI 有时会把 64 位整数作为字符串返回
+unsafe fn variant_to_i64(var: &VARIANT) -> Option<i64> {
+    let value = &var.Anonymous.Anonymous.Anonymous;
+    match var.Anonymous.Anonymous.vt {
+        VT_I1 => Some(value.cVal as i64),
+        VT_UI1 => Some(value.bVal as i64),
+        VT_I2 => Some(value.iVal as i64),
+        VT_UI2 => Some(value.uiVal as i64),
+        VT_I4 | VT_INT => Some(value.lVal as i64),
+        VT_UI4 | VT_UINT => Some(value.ulVal as i64),
+        VT_I8 => Some(value.llVal),
+        VT_UI8 => i64::try_from(value.ullVal).ok(),
+        VT_BSTR => value.bstrVal.to_string().trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// 可以从 VARIANT 读取的属性类型，供 [`WmiRow::get`] 使用
+pub trait FromVariant: Sized {
+    /// 读取 VARIANT，类型无法转换时返回 None
+    ///
+    /// # Safety
+    /// `var` 必须是已初始化的 VARIANT
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self>;
+}
+
+impl FromVariant for String {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        variant_to_string(var)
+    }
+}
+
+impl FromVariant for bool {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        variant_to_bool(var)
+    }
+}
+
+impl FromVariant for u8 {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        variant_to_i64(var).and_then(|value| u8::try_from(value).ok())
+    }
+}
+
+impl FromVariant for u16 {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        variant_to_i64(var).and_then(|value| u16::try_from(value).ok())
+    }
+}
+
+impl FromVariant for u32 {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        // WMI 的 uint32 以 VT_I4 返回，超过 i32 范围的值需要按位还原
+        match var.Anonymous.Anonymous.vt {
+            VT_I4 | VT_INT => Some(var.Anonymous.Anonymous.Anonymous.lVal as u32),
+            _ => variant_to_i64(var).and_then(|value| u32::try_from(value).ok()),
+        }
+    }
+}
+
+impl FromVariant for u64 {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        match var.Anonymous.Anonymous.vt {
+            VT_UI8 => Some(var.Anonymous.Anonymous.Anonymous.ullVal),
+            VT_BSTR => variant_to_u64(var),
+            _ => variant_to_i64(var).and_then(|value| u64::try_from(value).ok()),
+        }
+    }
+}
+
+impl FromVariant for i32 {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        variant_to_i64(var).and_then(|value| i32::try_from(value).ok())
+    }
+}
+
+impl FromVariant for i64 {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        variant_to_i64(var)
+    }
+}
+
+impl FromVariant for f64 {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        let value = &var.Anonymous.Anonymous.Anonymous;
+        match var.Anonymous.Anonymous.vt {
+            VT_R4 => Some(value.fltVal as f64),
+            VT_R8 => Some(value.dblVal),
+            _ => variant_to_i64(var).map(|value| value as f64),
+        }
+    }
+}
+
+impl FromVariant for Vec<u16> {
+    unsafe fn from_variant(var: &VARIANT) -> Option<Self> {
+        variant_to_u16_slice(var)
+    }
+}
+
+/// 枚举 WMI 对象的全部非系统属性
+///
+/// # 参数
+/// * `obj` - WMI 对象
+///
+/// 返回 (属性名称, 显示文本) 列表，顺序与 WMI 返回顺序一致
+pub unsafe fn get_properties(obj: &IWbemClassObject) -> Vec<(String, String)> {
+    let mut properties = Vec::new();
+    if obj.BeginEnumeration(WBEM_FLAG_NONSYSTEM_ONLY.0).is_err() {
+        return properties;
+    }
+
+    loop {
+        let mut name = BSTR::new();
+        let mut var = VariantInit();
+        // 枚举结束时返回 WBEM_S_NO_MORE_DATA，此时名称为空
+        if obj.Next(0, &mut name, &mut var, std::ptr::null_mut(), std::ptr::null_mut()).is_err() || name.is_empty() {
+            break;
+        }
+        properties.push((name.to_string(), variant_to_display_string(&var)));
+        let _ = VariantClear(&mut var);
+    }
+
+    let _ = obj.EndEnumeration();
+    properties
+}
+
+/// 执行 WQL 查询并以表格形式返回结果
+///
+/// 不同对象的属性集合可能不同，列为所有对象属性的并集
+///
+/// # 参数
+/// * `config` - WMI 连接配置
+/// * `query` - WQL 查询语句
+/// * `max_rows` - 最多返回的行数
+///
+/// # 示例
+/// ```ignore
+/// let table = query_table(&WmiConfig::default(), "SELECT * FROM Win32_BIOS", 100)?;
+/// ```
+pub unsafe fn query_table(config: &WmiConfig, query: &str, max_rows: usize) -> Result<WmiTable, String> {
+    let client = WmiClient::connect(config).map_err(|e| e.to_string())?;
+    let mut result = client
+        .query_limited(query, max_rows)
+        .map_err(|e| e.to_string())?;
+    let mut table = WmiTable::default();
+
+    while table.rows.len() < max_rows {
+        let Some(obj) = result.next() else {
+            break;
+        };
+
+        let mut row = vec![String::new(); table.columns.len()];
+        for (name, value) in get_properties(&obj) {
+            let index = match table.columns.iter().position(|column| *column == name) {
+                Some(index) => index,
+                None => {
+                    // 新出现的列，补齐之前的行
+                    table.columns.push(name);
+                    for previous in table.rows.iter_mut() {
+                        previous.push(String::new());
+                    }
+                    row.push(String::new());
+                    table.columns.len() - 1
+                }
+            };
+            row[index] = value;
+        }
+        table.rows.push(row);
+    }
+
+    Ok(table)
+}
+
+/// 将任意 VARIANT 转换为用于显示的文本
+///
+/// 支持常见的标量类型和一维数组，无法识别的类型显示其类型编号
+pub unsafe fn variant_to_display_string(var: &VARIANT) -> String {
+    let vt = var.Anonymous.Anonymous.vt;
+    let value = &var.Anonymous.Anonymous.Anonymous;
+
+    if vt.0 & VT_ARRAY.0 != 0 {
+        return safe_array_to_display_string(value.parray, VARENUM(vt.0 & !VT_ARRAY.0));
+    }
+
+    match vt {
+        VT_EMPTY | VT_NULL => String::new(),
+        VT_BSTR => value.bstrVal.to_string(),
+        VT_BOOL => value.boolVal.as_bool().to_string(),
+        VT_I1 => value.cVal.to_string(),
+        VT_UI1 => value.bVal.to_string(),
+        VT_I2 => value.iVal.to_string(),
+        VT_UI2 => value.uiVal.to_string(),
+        VT_I4 | VT_INT => value.lVal.to_string(),
+        VT_UI4 | VT_UINT => value.ulVal.to_string(),
+        VT_I8 => value.llVal.to_string(),
+        VT_UI8 => value.ullVal.to_string(),
+        VT_R4 => value.fltVal.to_string(),
+        VT_R8 => value.dblVal.to_string(),
+        _ => format!("<VT {}>", vt.0),
+    }
+}
+
+/// 将 VARIANT 转换为与平台无关的 [`Value`]，供 [`WindowsSource`](crate::detector::source::WindowsSource) 使用
+///
+/// 整数数组转换为 [`Value::U16Array`]，其他数组以显示文本保存
+///
+/// # Safety
+/// `var` 必须是已初始化的 VARIANT
+pub unsafe fn variant_to_value(var: &VARIANT) -> Value {
+    let vt = var.Anonymous.Anonymous.vt;
+    let value = &var.Anonymous.Anonymous.Anonymous;
+
+    if vt.0 & VT_ARRAY.0 != 0 {
+        return match variant_to_u16_slice(var) {
+            Some(values) => Value::U16Array(values),
+            None => Value::Text(variant_to_display_string(var)),
+        };
+    }
+
+    match vt {
+        VT_BSTR => Value::Text(value.bstrVal.to_string()),
+        VT_BOOL => Value::Bool(value.boolVal.as_bool()),
+        VT_I1 | VT_UI1 | VT_I2 | VT_UI2 | VT_I4 | VT_INT | VT_UI4 | VT_UINT | VT_I8 => {
+            variant_to_i64(var).map_or(Value::Null, Value::Int)
+        }
+        VT_UI8 => Value::UInt(value.ullVal),
+        VT_R4 => Value::Float(value.fltVal as f64),
+        VT_R8 => Value::Float(value.dblVal),
+        _ => Value::Null,
+    }
+}
+
+/// 读取 WMI 对象的全部非系统属性，转换为 [`DataRow`]
+///
+/// # 参数
+/// * `obj` - WMI 对象
+pub unsafe fn get_values(obj: &IWbemClassObject) -> DataRow {
+    let mut values = Vec::new();
+    if obj.BeginEnumeration(WBEM_FLAG_NONSYSTEM_ONLY.0).is_err() {
+        return DataRow::new();
+    }
+
+    loop {
+        let mut name = BSTR::new();
+        let mut var = VariantInit();
+        if obj.Next(0, &mut name, &mut var, std::ptr::null_mut(), std::ptr::null_mut()).is_err() || name.is_empty() {
+            break;
+        }
+        values.push((name.to_string(), variant_to_value(&var)));
+        let _ = VariantClear(&mut var);
+    }
+
+    let _ = obj.EndEnumeration();
+    values.into_iter().collect()
+}
+
+/// 将一维 SAFEARRAY 转换为以逗号分隔的显示文本
+unsafe fn safe_array_to_display_string(p_array: *mut SAFEARRAY, element: VARENUM) -> String {
+    if p_array.is_null() {
+        return String::new();
+    }
+
+    let (Ok(l_bound), Ok(u_bound)) = (SafeArrayGetLBound(p_array, 1), SafeArrayGetUBound(p_array, 1)) else {
+        return String::new();
+    };
+    let count = (u_bound - l_bound + 1).max(0) as usize;
+
+    let mut pv_data: *mut core::ffi::c_void = std::ptr::null_mut();
+    if SafeArrayAccessData(p_array, &mut pv_data).is_err() {
+        return String::new();
+    }
+
+    let items: Vec<String> = match element {
+        VT_BSTR => {
+            let ptr = pv_data as *const BSTR;
+            (0..count).map(|i| (*ptr.add(i)).to_string()).collect()
+        }
+        VT_UI1 => {
+            let ptr = pv_data as *const u8;
+            (0..count).map(|i| (*ptr.add(i)).to_string()).collect()
+        }
+        VT_I2 => {
+            let ptr = pv_data as *const i16;
+            (0..count).map(|i| (*ptr.add(i)).to_string()).collect()
+        }
+        VT_UI2 => {
+            let ptr = pv_data as *const u16;
+            (0..count).map(|i| (*ptr.add(i)).to_string()).collect()
+        }
+        VT_I4 | VT_INT => {
+            let ptr = pv_data as *const i32;
+            (0..count).map(|i| (*ptr.add(i)).to_string()).collect()
+        }
+        VT_UI4 | VT_UINT => {
+            let ptr = pv_data as *const u32;
+            (0..count).map(|i| (*ptr.add(i)).to_string()).collect()
+        }
+        _ => vec![format!("数组({} 项)", count)],
+    };
+
+    let _ = SafeArrayUnaccessData(p_array);
+
+    items.join(", ")
+}
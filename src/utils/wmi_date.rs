@@ -20,3 +20,23 @@ pub fn parse_wmi_date(date: &str) -> String {
         date.to_string()
     }
 }
+
+/// 解析 WMI 日期时间为 Unix 时间戳（秒）
+///
+/// WMI 日期时间格式: yyyymmddHHMMSS.mmmmmm+UUU，UUU 为相对 UTC 的分钟偏移
+///
+/// 示例
+/// ```
+/// use hardware_master::utils::wmi_date::parse_wmi_datetime;
+/// assert_eq!(parse_wmi_datetime("20230101080000.000000+480"), Some(1672531200));
+/// assert_eq!(parse_wmi_datetime("20230101000000.000000-060"), Some(1672534800));
+/// assert_eq!(parse_wmi_datetime("2023"), None);
+/// ```
+pub fn parse_wmi_datetime(date: &str) -> Option<i64> {
+    let local = chrono::NaiveDateTime::parse_from_str(date.get(..14)?, "%Y%m%d%H%M%S").ok()?;
+    let offset_minutes: i64 = match date.get(21..) {
+        Some(offset) if !offset.is_empty() => offset.trim().parse().ok()?,
+        _ => 0,
+    };
+    Some(local.and_utc().timestamp() - offset_minutes * 60)
+}
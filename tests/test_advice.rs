@@ -0,0 +1,76 @@
+use hardware_master::analysis::advice::{AdviceLevel, AdviceRuleSet};
+use hardware_master::analysis::Facts;
+
+const RULES: &str = r#"
+[[rules]]
+id = "memory-single-channel"
+level = "warning"
+message = "内存为单通道，建议加装一条 {memory.type}"
+link = "https://example.com/?q={memory.type}"
+when = [{ fact = "memory.module_count", op = "eq", value = 1 }]
+
+[[rules]]
+id = "battery-health-low"
+message = "电池健康度 {battery.health}%"
+when = [
+    { fact = "battery.health", op = "lt", value = 70 },
+    { fact = "battery.count", op = "ge", value = 1 },
+]
+"#;
+
+#[test]
+fn test_builtin_rules_parse() {
+    let rule_set = AdviceRuleSet::builtin();
+    assert!(!rule_set.rules.is_empty(), "内置规则不应为空");
+}
+
+#[test]
+fn test_rule_evaluation() {
+    let rule_set = AdviceRuleSet::from_toml(RULES).expect("规则解析失败");
+
+    let mut facts = Facts::default();
+    facts.set_number("memory.module_count", 1.0);
+    facts.set_text("memory.type", "DDR4");
+    facts.set_number("battery.count", 1.0);
+    facts.set_number("battery.health", 85.0);
+
+    let advice = rule_set.evaluate(&facts);
+    assert_eq!(advice.len(), 1);
+    assert_eq!(advice[0].rule_id, "memory-single-channel");
+    assert_eq!(advice[0].level, AdviceLevel::Warning);
+    assert_eq!(advice[0].message, "内存为单通道，建议加装一条 DDR4");
+    assert_eq!(
+        advice[0].link.as_deref(),
+        Some("https://example.com/?q=DDR4")
+    );
+}
+
+#[test]
+fn test_missing_fact_does_not_match() {
+    let rule_set = AdviceRuleSet::from_toml(RULES).expect("规则解析失败");
+    let advice = rule_set.evaluate(&Facts::default());
+    assert!(advice.is_empty());
+}
+
+#[test]
+fn test_merge_overrides_rule_by_id() {
+    let mut rule_set = AdviceRuleSet::from_toml(RULES).expect("规则解析失败");
+    let user_rules = AdviceRuleSet::from_toml(
+        r#"
+[[rules]]
+id = "battery-health-low"
+level = "warning"
+message = "自定义"
+when = [{ fact = "battery.health", op = "lt", value = 90 }]
+"#,
+    )
+    .expect("规则解析失败");
+    rule_set.merge(user_rules);
+
+    let mut facts = Facts::default();
+    facts.set_number("battery.health", 85.0);
+    let advice = rule_set.evaluate(&facts);
+    assert_eq!(rule_set.rules.len(), 2);
+    assert_eq!(advice.len(), 1);
+    assert_eq!(advice[0].message, "自定义");
+}
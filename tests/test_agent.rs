@@ -0,0 +1,118 @@
+#![cfg(feature = "serialize")]
+
+use hardware_master::agent::{
+    machine_id, resolve_token, validate_token, AgentOptions, AgentReport, Endpoint,
+};
+use hardware_master::HardwareDetector;
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_agent_options_from_args() {
+    let options = AgentOptions::from_args(args(&[
+        "app",
+        "--agent",
+        "--endpoint",
+        "https://it.example.com/inventory",
+    ]))
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        options.endpoint.as_deref(),
+        Some("https://it.example.com/inventory")
+    );
+
+    assert_eq!(
+        AgentOptions::from_args(args(&["app", "--agent"])),
+        Ok(Some(AgentOptions::default()))
+    );
+    assert_eq!(
+        AgentOptions::from_args(args(&["app", "--daemon"])),
+        Ok(None)
+    );
+    assert!(AgentOptions::from_args(args(&["app", "--agent", "--endpoint"])).is_err());
+    // 令牌不接受命令行参数
+    let err = AgentOptions::from_args(args(&["app", "--agent", "--token", "secret"])).unwrap_err();
+    assert!(err.contains("HARDWARE_MASTER_AGENT_TOKEN"), "{}", err);
+}
+
+#[test]
+fn test_resolve_token() {
+    assert_eq!(
+        resolve_token(Some(" env-token ".to_string()), "config-token").unwrap(),
+        "env-token"
+    );
+    assert_eq!(
+        resolve_token(Some("  ".to_string()), "config-token").unwrap(),
+        "config-token"
+    );
+    assert_eq!(resolve_token(None, "config-token").unwrap(), "config-token");
+    assert_eq!(resolve_token(None, "").unwrap(), "");
+
+    // 含回车换行的令牌会注入请求头
+    assert!(resolve_token(Some("a\r\nX-Forwarded-For: 1.2.3.4".to_string()), "").is_err());
+    assert!(resolve_token(None, "a\nb").is_err());
+    assert!(validate_token("a\rb").is_err());
+    assert!(validate_token("a\u{0}b").is_err());
+    assert!(validate_token("abc.DEF-123_~+/=").is_ok());
+}
+
+#[test]
+fn test_endpoint_parse() {
+    let endpoint = Endpoint::parse("https://it.example.com").unwrap();
+    assert_eq!(endpoint.port, 443);
+    assert_eq!(endpoint.path, "/");
+
+    let endpoint = Endpoint::parse("https://it.example.com?site=sh").unwrap();
+    assert_eq!(endpoint.host, "it.example.com");
+    assert_eq!(endpoint.path, "/?site=sh");
+
+    let endpoint = Endpoint::parse("http://[::1]:8080/collect").unwrap();
+    assert_eq!(endpoint.host, "[::1]");
+    assert_eq!(endpoint.port, 8080);
+    assert!(Endpoint::parse("http://localhost:9000").is_ok());
+
+    assert!(Endpoint::parse("http://192.168.1.10/collect").is_err());
+    assert!(Endpoint::parse("https://it.example.com:99999").is_err());
+    assert!(Endpoint::parse("https:///collect").is_err());
+}
+
+#[test]
+fn test_machine_id_ignores_placeholder_serials() {
+    let mut detector = HardwareDetector::new();
+    detector.system_info.system_manufacturer = "ASUS".to_string();
+    for serial in [
+        "To be filled by O.E.M.",
+        "Default string",
+        "System Serial Number",
+        "",
+    ] {
+        detector.system_info.serial_number = serial.to_string();
+        assert_eq!(machine_id("", &detector, "PC-07"), "PC-07");
+    }
+    detector.system_info.serial_number = " M1N0CX123 ".to_string();
+    assert_eq!(machine_id("  ", &detector, "PC-07"), "ASUS-M1N0CX123");
+}
+
+#[test]
+fn test_agent_report_json() {
+    let mut detector = HardwareDetector::new();
+    detector.cpu_info.name = "Intel(R) Core(TM) i5-12400".to_string();
+    let report = AgentReport::new(
+        "PC-07".to_string(),
+        "PC-07".to_string(),
+        &detector,
+        1729080000,
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+    assert_eq!(json["machine_id"], "PC-07");
+    assert_eq!(json["hostname"], "PC-07");
+    assert_eq!(json["detection"]["timestamp"], 1729080000);
+    assert_eq!(
+        json["detection"]["cpu_info"]["name"],
+        "Intel(R) Core(TM) i5-12400"
+    );
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "serialize")]
+
+use chrono::NaiveDate;
+use hardware_master::agent::AgentReport;
+use hardware_master::asset::{asset_key, AssetRecord, WarrantyStatus};
+use hardware_master::config::AppConfig;
+use hardware_master::HardwareDetector;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn record() -> AssetRecord {
+    let mut record = AssetRecord {
+        purchase_date: "2023-06-18".to_string(),
+        warranty_end: "2026-06-17".to_string(),
+        owner: "张三".to_string(),
+        notes: "财务部".to_string(),
+        ..Default::default()
+    };
+    record
+        .custom_fields
+        .insert("资产编号".to_string(), "IT-0042".to_string());
+    record
+}
+
+#[test]
+fn test_asset_validate() {
+    assert!(AssetRecord::default().validate().is_ok());
+    assert!(record().validate().is_ok());
+
+    let mut invalid = record();
+    invalid.purchase_date = "2023/06/18".to_string();
+    let err = invalid.validate().unwrap_err();
+    assert!(err.starts_with("购买日期格式"), "{}", err);
+
+    let mut invalid = record();
+    invalid.warranty_end = "2023-06-17".to_string();
+    assert_eq!(invalid.validate().unwrap_err(), "保修截止日期早于购买日期");
+
+    let mut invalid = record();
+    invalid
+        .custom_fields
+        .insert(" ".to_string(), "x".to_string());
+    assert!(invalid.validate().is_err());
+}
+
+#[test]
+fn test_warranty_status() {
+    let record = record();
+    assert_eq!(
+        record.warranty_status(date(2026, 6, 7)),
+        WarrantyStatus::Active { days_left: 10 }
+    );
+    // 保修截止当天仍在保修期内
+    assert_eq!(
+        record.warranty_status(date(2026, 6, 17)),
+        WarrantyStatus::Active { days_left: 0 }
+    );
+    assert_eq!(
+        record.warranty_status(date(2026, 7, 17)),
+        WarrantyStatus::Expired { days_ago: 30 }
+    );
+    assert_eq!(
+        AssetRecord::default().warranty_status(date(2026, 6, 7)),
+        WarrantyStatus::Unknown
+    );
+    assert_eq!(
+        WarrantyStatus::Active { days_left: 10 }.to_string(),
+        "保修中（剩余 10 天）"
+    );
+    assert_eq!(
+        WarrantyStatus::Expired { days_ago: 30 }.to_string(),
+        "已过保 30 天"
+    );
+}
+
+#[test]
+fn test_config_assets_roundtrip() {
+    let mut config = AppConfig::default();
+    config.set_asset("LENOVO-PF2ABCDE", record());
+    // 未填写任何字段的记录不保存
+    config.set_asset("PC-07", AssetRecord::default());
+    assert_eq!(config.assets.len(), 1);
+
+    let parsed = AppConfig::from_toml(&config.to_toml().unwrap()).unwrap();
+    assert_eq!(parsed, config);
+    assert_eq!(parsed.asset("LENOVO-PF2ABCDE"), Some(&record()));
+    assert_eq!(parsed.asset("PC-07"), None);
+
+    // 清空所有字段后删除记录
+    config.set_asset("LENOVO-PF2ABCDE", AssetRecord::default());
+    assert!(config.assets.is_empty());
+
+    // 旧版本配置文件没有资产信息
+    let config = AppConfig::from_toml("theme = \"Dark\"").unwrap();
+    assert!(config.assets.is_empty());
+}
+
+#[test]
+fn test_asset_key_ignores_detection() {
+    // 检测完成前后机器标识会变化，资产信息的键不随之变化
+    let mut detector = HardwareDetector::new();
+    let before = asset_key("", "PC-07");
+    detector.system_info.system_manufacturer = "LENOVO".to_string();
+    detector.system_info.serial_number = "PF2ABCDE".to_string();
+    assert_eq!(
+        hardware_master::agent::machine_id("", &detector, "PC-07"),
+        "LENOVO-PF2ABCDE"
+    );
+    assert_eq!(asset_key("", "PC-07"), before);
+    assert_eq!(asset_key("财务-03", "PC-07"), "财务-03");
+    assert_eq!(asset_key("  ", ""), "未知");
+}
+
+#[test]
+fn test_agent_report_with_asset() {
+    let detector = HardwareDetector::new();
+    let report = AgentReport::new(
+        "PC-07".to_string(),
+        "PC-07".to_string(),
+        &detector,
+        1729080000,
+    );
+    let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+    // 未记录资产信息时不输出该字段
+    assert!(json.get("asset").is_none());
+
+    let report = report.with_asset(Some(record()));
+    let json: serde_json::Value = serde_json::from_str(&report.to_json_pretty().unwrap()).unwrap();
+    assert_eq!(json["machine_id"], "PC-07");
+    assert_eq!(json["asset"]["warranty_end"], "2026-06-17");
+    assert_eq!(json["asset"]["owner"], "张三");
+    assert_eq!(json["asset"]["custom_fields"]["资产编号"], "IT-0042");
+}
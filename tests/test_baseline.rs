@@ -0,0 +1,52 @@
+use hardware_master::analysis::baseline::{Baseline, ExpectedDiskType};
+use hardware_master::analysis::Facts;
+
+const BASELINE: &str = r#"
+cpu_model = "i5-1[23]\\d{2}"
+min_memory_gb = 16
+min_tpm_version = 2
+disk_type = "ssd"
+"#;
+
+#[test]
+fn test_baseline_parse() {
+    let baseline = Baseline::from_toml(BASELINE).expect("解析失败");
+    assert_eq!(baseline.min_memory_gb, Some(16.0));
+    assert_eq!(baseline.min_tpm_version, Some(2));
+    assert_eq!(baseline.disk_type, Some(ExpectedDiskType::Ssd));
+
+    assert!(Baseline::from_toml("cpu_model = \"i5-(\"").is_err());
+    assert!(Baseline::from_toml("disk_type = \"tape\"").is_err());
+}
+
+#[test]
+fn test_baseline_all_match() {
+    let mut facts = Facts::default();
+    facts.set_text("cpu.name", "Intel(R) Core(TM) i5-1240P");
+    facts.set_number("memory.total_gb", 16.0);
+    facts.set_number("security.tpm_version", 2.0);
+    facts.set_bool("disk.is_hdd", false);
+
+    let baseline = Baseline::from_toml(BASELINE).unwrap();
+    assert!(baseline.check(&facts).is_empty());
+}
+
+#[test]
+fn test_baseline_mismatches() {
+    let mut facts = Facts::default();
+    facts.set_text("cpu.name", "Intel(R) Core(TM) i3-10110U");
+    facts.set_number("memory.total_gb", 8.0);
+    facts.set_bool("disk.is_hdd", true);
+
+    let mismatches = Baseline::from_toml(BASELINE).unwrap().check(&facts);
+    let items: Vec<&str> = mismatches.iter().map(|m| m.item.as_str()).collect();
+    assert_eq!(items, vec!["处理器", "内存", "TPM", "硬盘"]);
+    assert_eq!(mismatches[2].actual, "未检测到 TPM");
+    assert_eq!(mismatches[3].description(), "硬盘: 期望固态硬盘，实际 机械硬盘");
+}
+
+#[test]
+fn test_empty_baseline_checks_nothing() {
+    let baseline = Baseline::from_toml("").unwrap();
+    assert!(baseline.check(&Facts::default()).is_empty());
+}
@@ -0,0 +1,142 @@
+#[cfg(windows)]
+use hardware_master::benchmark::disk::{self, bench_dir, DiskBenchOptions, BENCH_FILE_NAME};
+use hardware_master::benchmark::history::{
+    find_regressions, fingerprint, BenchmarkRecord, BenchmarkResult, BenchmarkStore,
+};
+use hardware_master::benchmark::memory::{
+    self, random_cycle, MemoryBenchOptions, MemoryBenchResult,
+};
+#[cfg(windows)]
+use std::time::Duration;
+
+#[test]
+fn test_random_cycle_visits_all_nodes() {
+    let len = 1000;
+    let chain = random_cycle(len, 42);
+    let mut visited = vec![false; len];
+    let mut index = 0;
+    for _ in 0..len {
+        assert!(!visited[index], "节点 {} 被重复访问", index);
+        visited[index] = true;
+        index = chain[index];
+    }
+    assert_eq!(index, 0);
+    assert!(visited.iter().all(|&v| v));
+}
+
+#[test]
+fn test_memory_benchmark_small_buffer() {
+    let options = MemoryBenchOptions {
+        buffer_size: 4 * 1024 * 1024,
+        passes: 2,
+        latency_buffer_size: 1024 * 1024,
+        latency_steps: 10_000,
+    };
+    let result = memory::run(&options);
+    println!("{:?}", result);
+    assert!(result.read_bytes_per_sec > 0.0);
+    assert!(result.write_bytes_per_sec > 0.0);
+    assert!(result.latency_ns > 0.0);
+}
+
+#[test]
+#[cfg(windows)]
+fn test_disk_bench_dir() {
+    let temp_dir = std::env::temp_dir();
+    let system_drive = &temp_dir.to_string_lossy()[..2];
+    assert_eq!(bench_dir(system_drive), temp_dir);
+    assert_eq!(bench_dir("Z:"), std::path::PathBuf::from("Z:\\"));
+}
+
+#[test]
+#[cfg(windows)]
+fn test_disk_benchmark_small_file() {
+    let options = DiskBenchOptions {
+        file_size: 8 * 1024 * 1024,
+        random_duration: Duration::from_millis(200),
+        ..Default::default()
+    };
+    let drive_letter = &std::env::temp_dir().to_string_lossy()[..2];
+    let result = disk::run(drive_letter, &options).expect("硬盘测试失败");
+    println!("{:?}", result);
+    assert!(result.seq_read_bytes_per_sec > 0.0);
+    assert!(result.random_write_iops > 0.0);
+    assert!(!bench_dir(drive_letter).join(BENCH_FILE_NAME).exists());
+}
+
+fn memory_record(hardware: &str, driver: &str, read: f64, latency_ns: f64) -> BenchmarkRecord {
+    BenchmarkRecord {
+        hardware_hash: hardware.to_string(),
+        driver_hash: driver.to_string(),
+        result: BenchmarkResult::Memory(MemoryBenchResult {
+            timestamp: 1_700_000_000,
+            read_bytes_per_sec: read,
+            write_bytes_per_sec: 1.0e9,
+            latency_ns,
+        }),
+    }
+}
+
+#[test]
+fn test_find_regressions() {
+    // 驱动更新后读取带宽下降
+    let records = vec![
+        memory_record("hw", "a", 20.0e9, 80.0),
+        memory_record("hw", "b", 10.0e9, 80.0),
+    ];
+    let regressions = find_regressions(&records, 1);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].metric, "读取带宽");
+    assert!(regressions[0].after_driver_update);
+    assert!(regressions[0].change_percent < -40.0);
+    assert!(find_regressions(&records, 0).is_empty());
+
+    // 延迟越低越好，增加视为回退
+    let records = vec![
+        memory_record("hw", "a", 20.0e9, 80.0),
+        memory_record("hw", "a", 20.0e9, 100.0),
+    ];
+    let regressions = find_regressions(&records, 1);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].metric, "延迟");
+    assert!(!regressions[0].after_driver_update);
+
+    // 硬件变化后的记录不与之前比较
+    let records = vec![
+        memory_record("hw1", "a", 20.0e9, 80.0),
+        memory_record("hw2", "a", 10.0e9, 80.0),
+    ];
+    assert!(find_regressions(&records, 1).is_empty());
+}
+
+#[test]
+fn test_fingerprint_is_stable() {
+    let parts = vec!["Intel".to_string(), "16 GB".to_string()];
+    assert_eq!(fingerprint(&parts), fingerprint(&parts));
+    assert_eq!(fingerprint(&parts).len(), 16);
+    assert_ne!(
+        fingerprint(&["ab".to_string(), "c".to_string()]),
+        fingerprint(&["a".to_string(), "bc".to_string()])
+    );
+}
+
+#[test]
+fn test_benchmark_store_roundtrip() {
+    let path = std::env::temp_dir().join(format!("hm_bench_{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let store = BenchmarkStore::new(&path);
+    assert!(store.load().expect("读取历史失败").is_empty());
+
+    store
+        .append(&memory_record("hw", "a", 20.0e9, 80.0))
+        .expect("写入历史失败");
+    store
+        .append(&memory_record("hw", "b", 10.0e9, 80.0))
+        .expect("写入历史失败");
+    let records = store.load().expect("读取历史失败");
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[1].driver_hash, "b");
+    assert_eq!(records[0].result.target(), "内存");
+}
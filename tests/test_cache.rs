@@ -0,0 +1,56 @@
+#![cfg(feature = "serialize")]
+
+use hardware_master::cache::{CachedDetection, DetectionCache};
+use hardware_master::constants::DETECTION_CACHE_MAX_AGE_SECS;
+use hardware_master::HardwareDetector;
+
+#[test]
+fn test_cache_roundtrip() {
+    let path =
+        std::env::temp_dir().join(format!("hardware-master-cache-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let cache = DetectionCache::new(&path);
+    assert!(cache.load().unwrap().is_none());
+
+    let mut detector = HardwareDetector::new();
+    detector.cpu_info.name = "Intel Core i5-12400F".to_string();
+    detector.motherboard_info.chipset = "Intel B660".to_string();
+    cache
+        .save(&CachedDetection::from_detector(&detector, 1_700_000_000))
+        .unwrap();
+
+    let cached = cache.load_fresh(1_700_000_600).expect("应读取到缓存");
+    assert_eq!(cached.timestamp, 1_700_000_000);
+    let mut restored = HardwareDetector::new();
+    cached.apply_to(&mut restored);
+    assert_eq!(restored.cpu_info.name, "Intel Core i5-12400F");
+    assert_eq!(restored.motherboard_info.chipset, "Intel B660");
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_cache_staleness() {
+    let cached = CachedDetection::from_detector(&HardwareDetector::new(), 1_700_000_000);
+    assert!(!cached.is_stale(1_700_000_000));
+    assert!(!cached.is_stale(1_700_000_000 + DETECTION_CACHE_MAX_AGE_SECS));
+    assert!(cached.is_stale(1_700_000_001 + DETECTION_CACHE_MAX_AGE_SECS));
+    // 缓存时间晚于当前时间（系统时间被调回）
+    assert!(cached.is_stale(1_699_999_000));
+
+    let mut other_version = cached.clone();
+    other_version.version = "0.0.0".to_string();
+    assert!(other_version.is_stale(1_700_000_000));
+}
+
+#[test]
+fn test_cache_timings() {
+    let cached = CachedDetection::from_detector(&HardwareDetector::new(), 1_700_000_000);
+    let mut json = serde_json::to_value(&cached).unwrap();
+    assert!(json["timings"].is_array());
+
+    // 旧版本写入的缓存没有耗时记录
+    json.as_object_mut().unwrap().remove("timings");
+    let cached: CachedDetection = serde_json::from_value(json).unwrap();
+    assert!(cached.timings.is_empty());
+}
@@ -0,0 +1,90 @@
+use hardware_master::detector::motherboard::{identify_chipset, SystemDevice};
+use hardware_master::iddb::chipset::{ChipsetDatabase, CHIPSET_DB};
+
+const CHIPSETS: &str = r#"
+[[chipsets]]
+id = "8086:7a84"
+name = "Intel Z690"
+
+[[chipsets]]
+id = "1022:43E9"
+name = "AMD 500 系列"
+"#;
+
+#[test]
+fn test_chipset_lookup() {
+    let db = ChipsetDatabase::from_toml(CHIPSETS).expect("芯片组数据解析失败");
+
+    // 大小写不敏感
+    assert_eq!(
+        db.lookup("8086", "7A84").expect("应找到芯片组").name,
+        "Intel Z690"
+    );
+    assert_eq!(
+        db.lookup("1022", "43e9").expect("应找到芯片组").name,
+        "AMD 500 系列"
+    );
+    assert!(db.lookup("8086", "7A04").is_none());
+}
+
+#[test]
+fn test_chipset_identify_skips_non_pci() {
+    let db = ChipsetDatabase::from_toml(CHIPSETS).expect("芯片组数据解析失败");
+    let ids = [
+        "ACPI\\PNP0C02\\1",
+        "USB\\VID_8086&PID_7A84\\5&1A2B3C4D&0&1",
+        "PCI\\VEN_1022&DEV_43E9&SUBSYS_11421B21&REV_00\\4&2B2A6E49&0&0009",
+    ];
+    assert_eq!(
+        db.identify(ids).expect("应识别出芯片组").name,
+        "AMD 500 系列"
+    );
+    assert!(db.identify(["PCI\\VEN_10DE&DEV_2504\\4&1"]).is_none());
+}
+
+#[test]
+fn test_builtin_chipsets() {
+    assert_eq!(
+        CHIPSET_DB
+            .lookup("8086", "7A04")
+            .expect("应找到芯片组")
+            .name,
+        "Intel Z790"
+    );
+    assert_eq!(
+        CHIPSET_DB
+            .lookup("8086", "A145")
+            .expect("应找到芯片组")
+            .name,
+        "Intel Z170"
+    );
+}
+
+#[test]
+fn test_identify_chipset_from_system_devices() {
+    let device = |name: &str, instance_id: &str| SystemDevice {
+        name: name.to_string(),
+        instance_id: instance_id.to_string(),
+    };
+    let devices = [
+        device(
+            "Intel(R) SMBus - 7AA3",
+            "PCI\\VEN_8086&DEV_7AA3&SUBSYS_7D251462&REV_11\\3&11583659&0&FC",
+        ),
+        device(
+            "Intel(R) Z690 LPC/eSPI Controller - 7A84",
+            "PCI\\VEN_8086&DEV_7A84&SUBSYS_7D251462&REV_11\\3&11583659&0&F8",
+        ),
+    ];
+    // 芯片组数据库优先于按名称查找
+    assert_eq!(identify_chipset(&devices).as_deref(), Some("Intel Z690"));
+
+    let unknown = [device(
+        "Vendor ISA Bridge",
+        "PCI\\VEN_FFFF&DEV_0001&SUBSYS_00000000&REV_00\\3&11583659&0&F8",
+    )];
+    assert_eq!(
+        identify_chipset(&unknown).as_deref(),
+        Some("Vendor ISA Bridge")
+    );
+}
@@ -0,0 +1,119 @@
+use hardware_master::config::{AppConfig, Language, VirtualDeviceMode};
+use hardware_master::constants::WMI_TIMEOUT_SECS;
+use hardware_master::detector::DetectionCategory;
+use hardware_master::ui::font::FontChoice;
+use hardware_master::ui::tab::AppTab;
+use hardware_master::ui::theme::{AppTheme, Density};
+
+#[test]
+fn test_config_round_trip() {
+    let config = AppConfig {
+        theme: AppTheme::Dark,
+        window_width: 800.0,
+        window_height: 600.0,
+        auto_refresh_secs: 30,
+        disabled_detectors: vec![DetectionCategory::Battery, DetectionCategory::Disk],
+        ..Default::default()
+    };
+
+    let text = config.to_toml().expect("序列化失败");
+    let parsed = AppConfig::from_toml(&text).expect("解析失败");
+    assert_eq!(parsed, config);
+}
+
+#[test]
+fn test_config_missing_fields_use_default() {
+    let parsed = AppConfig::from_toml("theme = \"Light\"\n").expect("解析失败");
+    assert_eq!(parsed.theme, AppTheme::Light);
+    assert!(parsed.disabled_detectors.is_empty());
+    assert_eq!(parsed.enabled_detectors(), DetectionCategory::ALL.to_vec());
+    assert_eq!(parsed.auto_refresh_secs, 0);
+    assert!(!parsed.advanced_mode);
+    assert!(!parsed.privacy_mode);
+    assert!(!parsed.tray_enabled);
+    assert_eq!(parsed.wmi_timeout_secs, WMI_TIMEOUT_SECS);
+    assert_eq!(parsed.virtual_device_mode, VirtualDeviceMode::Group);
+    assert_eq!(parsed.last_tab, AppTab::Overview);
+    assert!(parsed.scroll_offsets.is_empty());
+    assert_eq!(parsed.density, Density::Normal);
+    assert!(parsed.section_cards);
+    assert_eq!(parsed.font, FontChoice::Auto);
+    assert_eq!(parsed.language, Language::ZhCn);
+}
+
+#[test]
+fn test_config_language() {
+    let text = AppConfig::default().to_toml().expect("序列化失败");
+    assert!(text.contains("language = \"zh-CN\""), "{}", text);
+
+    // 尚不支持的语言按简体中文处理，不影响其余配置
+    let parsed =
+        AppConfig::from_toml("language = \"en-US\"\ntheme = \"Dark\"\n").expect("解析失败");
+    assert_eq!(parsed.language, Language::ZhCn);
+    assert_eq!(parsed.theme, AppTheme::Dark);
+}
+
+#[test]
+fn test_config_legacy_enabled_detectors() {
+    // 旧版本保存启用的类别列表，之后新增的类别（安全）不应因此被停用
+    let parsed = AppConfig::from_toml(
+        r#"
+language = "zh-CN"
+enabled_detectors = ["System", "Cpu", "Gpu", "Memory", "Disk", "Motherboard", "Network", "Monitor"]
+"#,
+    )
+    .expect("解析失败");
+    assert_eq!(
+        parsed.disabled_detectors,
+        vec![DetectionCategory::Audio, DetectionCategory::Battery]
+    );
+    let enabled = parsed.enabled_detectors();
+    assert!(enabled.contains(&DetectionCategory::Security));
+    assert!(!enabled.contains(&DetectionCategory::Audio));
+
+    // 保存后不再写出旧字段
+    let text = parsed.to_toml().expect("序列化失败");
+    assert!(!text.contains("enabled_detectors"));
+    assert_eq!(AppConfig::from_toml(&text).expect("解析失败"), parsed);
+}
+
+#[test]
+fn test_config_appearance_round_trip() {
+    let config = AppConfig {
+        theme: AppTheme::Light,
+        accent_color: [16, 124, 16],
+        density: Density::Compact,
+        section_cards: false,
+        font: FontChoice::System("宋体".to_string()),
+        ..Default::default()
+    };
+    let parsed = AppConfig::from_toml(&config.to_toml().expect("序列化失败")).expect("解析失败");
+    assert_eq!(parsed, config);
+
+    let style = parsed.theme_style();
+    assert_eq!(style.theme, AppTheme::Light);
+    assert_eq!(style.accent, [16, 124, 16]);
+    assert_eq!(style.density, Density::Compact);
+    assert!(!style.cards);
+}
+
+#[test]
+fn test_config_invalid_content() {
+    assert!(AppConfig::from_toml("theme = 1").is_err());
+}
+
+#[test]
+fn test_config_layout_round_trip() {
+    let mut config = AppConfig {
+        last_tab: AppTab::Trends,
+        show_advice: true,
+        ..Default::default()
+    };
+    config.scroll_offsets.insert(AppTab::Overview, 320.0);
+    config.scroll_offsets.insert(AppTab::Trends, 48.5);
+
+    let text = config.to_toml().expect("序列化失败");
+    let parsed = AppConfig::from_toml(&text).expect("解析失败");
+    assert_eq!(parsed, config);
+    assert_eq!(parsed.scroll_offsets.get(&AppTab::Overview), Some(&320.0));
+}
@@ -1,3 +1,5 @@
+#![cfg(windows)]
+
 use hardware_master::detector::cpu::detect_cpu;
 
 #[test]
@@ -7,10 +9,18 @@ fn test_cpu_detection() {
             println!("CPU Info:");
             println!("  Name: {}", info.name);
             println!("  Architecture: {}", info.architecture);
-            println!("  Cores: {}", info.cores);
+            println!(
+                "  Cores: {} physical / {} logical / {} efficiency",
+                info.physical_cores, info.logical_cores, info.efficiency_cores
+            );
             println!("  Vendor: {}", info.vendor);
             println!("  CPU ID: {}", info.cpu_id);
+            println!("  Base Frequency: {} MHz", info.base_frequency);
             println!("  Max Frequency: {} MHz", info.max_frequency);
+            println!("  Current Frequency: {} MHz", info.current_frequency);
+
+            // 最大频率不低于基准频率
+            assert!(info.max_frequency >= info.base_frequency);
 
             // 验证至少有 CPU 名称
             assert!(!info.name.is_empty() || info.name != "未知",
@@ -0,0 +1,69 @@
+use hardware_master::daemon::DaemonOptions;
+use std::time::Duration;
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_daemon_options_from_args() {
+    // 只指定 --daemon 时使用默认参数
+    let options = DaemonOptions::from_args(args(&["app", "--daemon"]))
+        .unwrap()
+        .unwrap();
+    assert_eq!(options, DaemonOptions::default());
+    assert_eq!(options.runs, 1);
+
+    // 未指定 --daemon 时忽略其他参数
+    assert_eq!(
+        DaemonOptions::from_args(args(&["app", "--runs", "3"])),
+        Ok(None)
+    );
+
+    // 参数顺序无关
+    let options = DaemonOptions::from_args(args(&["app", "--interval", "5", "--daemon"]))
+        .unwrap()
+        .unwrap();
+    assert_eq!(options.interval, Duration::from_secs(300));
+
+    // 缺少参数值或不是正整数
+    assert!(DaemonOptions::from_args(args(&["app", "--daemon", "--runs"])).is_err());
+    assert!(DaemonOptions::from_args(args(&["app", "--daemon", "--runs", "0"])).is_err());
+    assert!(DaemonOptions::from_args(args(&["app", "--daemon", "--interval", "abc"])).is_err());
+}
+
+#[test]
+#[cfg(feature = "serialize")]
+fn test_detection_log_append_and_rotate() {
+    use hardware_master::cache::CachedDetection;
+    use hardware_master::daemon::DetectionLog;
+    use hardware_master::HardwareDetector;
+
+    let dir = std::env::temp_dir().join(format!("hardware-master-daemon-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let log = DetectionLog::new(&dir, 1);
+
+    // 目录不存在时自动创建，同一个月的记录写入同一个文件
+    let record = CachedDetection::from_detector(&HardwareDetector::new(), 1729080000);
+    let path = log.append(&record).unwrap();
+    assert_eq!(log.append(&record).unwrap(), path);
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(content.lines().count(), 2);
+
+    // 超出保留数量时删除最早的月份，非日志文件不受影响
+    for name in [
+        "detections-2020-01.jsonl",
+        "detections-2020-02.jsonl",
+        "notes.txt",
+    ] {
+        std::fs::write(dir.join(name), "").unwrap();
+    }
+    assert_eq!(log.rotate().unwrap(), 2);
+    assert!(!dir.join("detections-2020-01.jsonl").exists());
+    assert!(!dir.join("detections-2020-02.jsonl").exists());
+    assert!(dir.join("notes.txt").exists());
+    assert!(path.exists());
+    assert_eq!(log.rotate().unwrap(), 0);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,73 @@
+use hardware_master::detector::gpu::Gpu;
+use hardware_master::detector::network::NetworkAdapter;
+use hardware_master::ui::details::DeviceDetails;
+use hardware_master::HardwareDetector;
+
+fn value<'a>(details: &'a DeviceDetails, key: &str) -> Option<&'a str> {
+    details
+        .rows
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+#[test]
+fn test_network_details_with_instance_id() {
+    let adapter = NetworkAdapter {
+        name: "Intel(R) Ethernet Connection (2) I219-V".to_string(),
+        pnp_device_id: r"PCI\VEN_8086&DEV_15B8&SUBSYS_79941462&REV_31\3&11583659&0&FE".to_string(),
+        ..Default::default()
+    };
+    let details = DeviceDetails::network(&adapter);
+
+    assert_eq!(details.title, adapter.name);
+    assert_eq!(
+        details.instance_id.as_deref(),
+        Some(adapter.pnp_device_id.as_str())
+    );
+    assert_eq!(value(&details, "总线"), Some("PCI"));
+    assert_eq!(value(&details, "厂商 ID"), Some("8086"));
+    assert_eq!(value(&details, "子系统厂商 ID"), Some("1462"));
+    assert_eq!(value(&details, "子系统 ID"), Some("7994"));
+    assert_eq!(value(&details, "修订版本"), Some("31"));
+    assert_eq!(
+        value(&details, "数据库名称"),
+        Some("Ethernet Connection (2) I219-V")
+    );
+    assert_eq!(value(&details, "子系统名称"), Some("H110M ECO/GAMING"));
+    assert_eq!(
+        value(&details, "子系统厂商"),
+        Some("Micro-Star International Co., Ltd. [MSI]")
+    );
+    assert!(details.to_text().contains("子系统 ID: 7994\n"));
+}
+
+#[test]
+fn test_gpu_details_without_instance_id() {
+    let gpu = Gpu {
+        description: "Microsoft Basic Render Driver".to_string(),
+        ..Default::default()
+    };
+    let details = DeviceDetails::gpu(&gpu);
+
+    assert_eq!(details.instance_id, None);
+    assert_eq!(
+        value(&details, "名称"),
+        Some("Microsoft Basic Render Driver")
+    );
+    assert_eq!(value(&details, "设备实例 ID"), None);
+}
+
+#[test]
+fn test_section_details() {
+    let mut detector = HardwareDetector::new();
+    detector.cpu_info.name = "Intel(R) Core(TM) i5-12400".to_string();
+
+    let details = DeviceDetails::section(&detector, "处理器").unwrap();
+    assert_eq!(details.title, "处理器");
+    assert!(details
+        .rows
+        .iter()
+        .any(|(_, v)| v == "Intel(R) Core(TM) i5-12400"));
+    assert!(DeviceDetails::section(&detector, "不存在").is_none());
+}
@@ -0,0 +1,249 @@
+use hardware_master::detector::backend::{
+    AudioBackend, BatteryBackend, CpuBackend, DiskBackend, GpuBackend, MemoryBackend,
+    MonitorBackend, MotherboardBackend, NetworkBackend, SecurityBackend, SystemBackend,
+};
+use hardware_master::detector::dynamic::DynamicData;
+use hardware_master::detector::memory::{MemoryInfo, MemorySlot};
+use hardware_master::detector::timing;
+#[cfg(windows)]
+use hardware_master::detector::DetectionPriority;
+use hardware_master::detector::{
+    DetectionCategory, DetectionError, DetectionEvent, PROGRESS_COMPLETE,
+};
+use hardware_master::utils::size::ByteSize;
+use hardware_master::HardwareDetector;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// 只支持内存检测的后端
+struct MemoryOnlyBackend;
+
+impl SystemBackend for MemoryOnlyBackend {}
+impl CpuBackend for MemoryOnlyBackend {}
+impl GpuBackend for MemoryOnlyBackend {}
+impl DiskBackend for MemoryOnlyBackend {}
+impl MotherboardBackend for MemoryOnlyBackend {}
+impl NetworkBackend for MemoryOnlyBackend {}
+impl AudioBackend for MemoryOnlyBackend {}
+impl MonitorBackend for MemoryOnlyBackend {}
+impl BatteryBackend for MemoryOnlyBackend {}
+impl SecurityBackend for MemoryOnlyBackend {}
+
+impl MemoryBackend for MemoryOnlyBackend {
+    fn detect_memory(&self) -> Result<MemoryInfo, DetectionError> {
+        // 模拟一条 WMI 查询，用于耗时统计
+        timing::record_query(
+            "SELECT * FROM Win32_PhysicalMemory",
+            Duration::from_millis(30),
+        );
+        Ok(MemoryInfo {
+            slots: vec![MemorySlot {
+                capacity: ByteSize::from_gb(8),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+    }
+}
+
+#[test]
+#[cfg(windows)]
+fn test_detect_selected_categories() {
+    let mut detector = HardwareDetector::new();
+    detector
+        .detect(&[DetectionCategory::Cpu])
+        .expect("CPU 检测失败");
+
+    // 只检测了处理器，其余类别保持默认值
+    assert_ne!(detector.cpu_info.name, "未知");
+    assert_eq!(detector.disk_info.model, "未知");
+}
+
+#[test]
+fn test_detect_empty_categories() {
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let mut detector = HardwareDetector::new();
+    let progress_clone = Arc::clone(&progress);
+    detector.set_progress_callback(Box::new(move |event| {
+        progress_clone.lock().unwrap().extend(event.progress());
+    }));
+
+    detector.detect(&[]).expect("空检测不应失败");
+    assert_eq!(*progress.lock().unwrap(), vec![PROGRESS_COMPLETE]);
+}
+
+#[test]
+#[cfg(windows)]
+fn test_detect_with_single_background_worker() {
+    let mut detector = HardwareDetector::new();
+    detector.set_concurrency(1, DetectionPriority::Background);
+    detector
+        .detect(&[DetectionCategory::Cpu, DetectionCategory::Memory])
+        .expect("检测失败");
+
+    assert_ne!(detector.cpu_info.name, "未知");
+    assert!(!detector.memory_info.slots.is_empty());
+}
+
+#[test]
+#[cfg(windows)]
+fn test_detect_progress_reaches_complete() {
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let mut detector = HardwareDetector::new();
+    detector.set_concurrency(4, DetectionPriority::Normal);
+    let progress_clone = Arc::clone(&progress);
+    detector.set_progress_callback(Box::new(move |event| {
+        progress_clone.lock().unwrap().extend(event.progress());
+    }));
+
+    detector
+        .detect(&[DetectionCategory::Cpu, DetectionCategory::Memory])
+        .expect("检测失败");
+
+    // 两个类别各完成一次、结束
+    let progress = progress.lock().unwrap();
+    assert_eq!(progress.len(), 3);
+    assert_eq!(*progress.last().unwrap(), PROGRESS_COMPLETE);
+    assert!(progress.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_apply_dynamic_keeps_missing_values() {
+    let mut detector = HardwareDetector::new();
+    detector.disk_info.free_space = ByteSize::from_bytes(100);
+    detector.disk_info.temperature = Some(40.0);
+
+    detector.apply_dynamic(DynamicData {
+        free_space: Some(ByteSize::from_bytes(50)),
+        ..Default::default()
+    });
+
+    // 只更新读取到的项，读取失败的温度保留上次的值
+    assert_eq!(detector.disk_info.free_space, ByteSize::from_bytes(50));
+    assert_eq!(detector.disk_info.temperature, Some(40.0));
+}
+
+#[test]
+fn test_adopt_results_only_detected_categories() {
+    let mut detector = HardwareDetector::new();
+    detector.set_backend(Arc::new(MemoryOnlyBackend));
+    let _ = detector.detect(&[DetectionCategory::Cpu]);
+
+    let mut worker = detector.fork();
+    let _ = worker.detect(&[DetectionCategory::Memory]);
+
+    // 检测期间原检测器的磁盘数据被动态刷新
+    detector.disk_info.free_space = ByteSize::from_bytes(50);
+    detector.adopt_results(worker);
+
+    assert_eq!(detector.memory_info.slots.len(), 1);
+    assert_eq!(detector.disk_info.free_space, ByteSize::from_bytes(50));
+    // 未重新检测的类别保留失败记录与耗时
+    assert!(detector.error(DetectionCategory::Cpu).is_some());
+    assert!(detector.timing(DetectionCategory::Cpu).is_some());
+    assert!(
+        detector
+            .timing(DetectionCategory::Memory)
+            .unwrap()
+            .succeeded
+    );
+    assert_eq!(detector.errors().len(), 1);
+}
+
+#[test]
+fn test_unsupported_categories_keep_defaults() {
+    let mut detector = HardwareDetector::new();
+    detector.set_backend(Arc::new(MemoryOnlyBackend));
+
+    let result = detector.detect(&[DetectionCategory::Cpu, DetectionCategory::Memory]);
+
+    // 不支持的类别返回平台不支持错误，已支持的类别照常写回
+    assert!(matches!(result, Err(DetectionError::Unsupported(_))));
+    assert_eq!(detector.cpu_info.name, "未知");
+    assert_eq!(detector.memory_info.slots.len(), 1);
+}
+
+#[test]
+fn test_detect_records_failed_categories() {
+    let mut detector = HardwareDetector::new();
+    detector.set_backend(Arc::new(MemoryOnlyBackend));
+
+    let _ = detector.detect(&[DetectionCategory::Cpu, DetectionCategory::Memory]);
+    assert!(matches!(
+        detector.error(DetectionCategory::Cpu),
+        Some(DetectionError::Unsupported(_))
+    ));
+    assert!(detector.error(DetectionCategory::Memory).is_none());
+
+    // 只重新检测内存时，处理器的失败记录保留，且不作为本次检测的错误返回
+    assert!(detector.detect(&[DetectionCategory::Memory]).is_ok());
+    assert_eq!(detector.errors().len(), 1);
+    assert_eq!(detector.errors()[0].0, DetectionCategory::Cpu);
+}
+
+#[test]
+fn test_detect_records_timings() {
+    let mut detector = HardwareDetector::new();
+    detector.set_backend(Arc::new(MemoryOnlyBackend));
+    assert!(detector.timings().is_empty());
+
+    let _ = detector.detect(&[DetectionCategory::Cpu, DetectionCategory::Memory]);
+    assert_eq!(detector.timings().len(), 2);
+    let cpu = detector.timing(DetectionCategory::Cpu).unwrap();
+    assert!(!cpu.succeeded);
+    assert!(cpu.queries.is_empty());
+    let memory = detector.timing(DetectionCategory::Memory).unwrap();
+    assert!(memory.succeeded);
+    assert_eq!(
+        memory.slowest_query().unwrap().query,
+        "SELECT * FROM Win32_PhysicalMemory"
+    );
+    assert_eq!(memory.query_millis(), 30);
+
+    // 重新检测时替换该类别上次的记录
+    let _ = detector.detect(&[DetectionCategory::Memory]);
+    assert_eq!(detector.timings().len(), 2);
+    assert_eq!(
+        detector
+            .timing(DetectionCategory::Memory)
+            .unwrap()
+            .queries
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_detect_emits_events() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut detector = HardwareDetector::new();
+    detector.set_backend(Arc::new(MemoryOnlyBackend));
+    let events_clone = Arc::clone(&events);
+    detector.set_progress_callback(Box::new(move |event| {
+        events_clone.lock().unwrap().push(event.clone());
+    }));
+
+    let _ = detector.detect(&[DetectionCategory::Cpu, DetectionCategory::Memory]);
+
+    let events = events.lock().unwrap();
+    let started = events
+        .iter()
+        .filter(|event| matches!(event, DetectionEvent::TaskStarted { .. }))
+        .count();
+    assert_eq!(started, 2);
+    assert!(events.iter().any(|event| matches!(
+        event,
+        DetectionEvent::TaskFailed {
+            category: DetectionCategory::Cpu,
+            error: DetectionError::Unsupported(_),
+            ..
+        }
+    )));
+    assert!(matches!(
+        events.last(),
+        Some(DetectionEvent::AllDone {
+            total: 2,
+            failed: 1
+        })
+    ));
+}
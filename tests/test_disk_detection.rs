@@ -1,3 +1,5 @@
+#![cfg(windows)]
+
 use hardware_master::detector::disk::detect_disk;
 
 #[test]
@@ -6,11 +8,12 @@ fn test_disk_detection() {
         Ok(info) => {
             println!("Disk Info:");
             println!("  Model: {}", info.model);
-            println!("  Total Capacity: {} MB", info.total_capacity);
+            println!("  Total Capacity: {}", info.total_capacity);
             println!("  Type: {:?}", info.disk_type);
+            println!("  Bus: {} ({:?})", info.bus, info.link_speed);
 
             // 验证至少有磁盘数据
-            assert!(info.total_capacity > 0.0,
+            assert!(!info.total_capacity.is_zero(),
                     "Expected disk capacity > 0, but got: {}", info.total_capacity);
             assert!(!info.model.is_empty() || info.model != "未知",
                     "Expected disk model, but got: {}", info.model);
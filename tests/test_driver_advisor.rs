@@ -0,0 +1,171 @@
+use hardware_master::analysis::advice::AdviceLevel;
+use hardware_master::analysis::driver_advisor::{
+    check_with, collect_devices, DeviceDriver, DriverProvider, DriverRuleSet, OfflineProvider,
+};
+use hardware_master::detector::gpu::Gpu;
+use hardware_master::detector::network::NetworkAdapter;
+use hardware_master::HardwareDetector;
+
+const RULES: &str = r#"
+[[rules]]
+id = "realtek-8168"
+vendor = "10EC"
+device = "8168"
+min_version = "10.70"
+link = "https://example.com/realtek"
+
+[[rules]]
+id = "realtek-8168-msi"
+vendor = "10ec"
+device = "8168"
+subsys = "7B861462"
+min_version = "10.68.815.2023"
+
+[[rules]]
+id = "nvidia"
+vendor = "10de"
+min_version = "32.0.15.6094"
+message = "{device}: {current} -> {min_version}"
+"#;
+
+fn device(pnp_id: &str, version: &str) -> DeviceDriver {
+    DeviceDriver::from_pnp_id("网卡", pnp_id, version).expect("硬件 ID 解析失败")
+}
+
+#[test]
+fn test_most_specific_rule_wins() {
+    let rule_set = DriverRuleSet::from_toml(RULES).expect("规则解析失败");
+
+    // 子系统规则要求的版本已满足，不应再按设备级规则提示
+    let msi = device(
+        "PCI\\VEN_10EC&DEV_8168&SUBSYS_7B861462&REV_15\\0100",
+        "10.68.815.2023",
+    );
+    assert!(rule_set.evaluate(&[msi]).is_empty());
+
+    let other = device(
+        "PCI\\VEN_10EC&DEV_8168&SUBSYS_86771043&REV_15\\0100",
+        "10.68.815.2023",
+    );
+    let advice = rule_set.evaluate(&[other]);
+    assert_eq!(advice.len(), 1);
+    assert_eq!(advice[0].rule_id, "realtek-8168");
+    assert_eq!(advice[0].level, AdviceLevel::Warning);
+    assert_eq!(
+        advice[0].link.as_deref(),
+        Some("https://example.com/realtek")
+    );
+    assert!(advice[0].message.contains("10.68.815.2023"));
+}
+
+#[test]
+fn test_unparsable_version_is_skipped() {
+    let rule_set = DriverRuleSet::from_toml(RULES).expect("规则解析失败");
+    let beta = device("PCI\\VEN_10EC&DEV_8168&REV_15\\0100", "10.70-beta");
+    assert!(rule_set.evaluate(&[beta]).is_empty());
+}
+
+struct StaticProvider;
+
+impl DriverProvider for StaticProvider {
+    fn rules(&self) -> Result<DriverRuleSet, String> {
+        DriverRuleSet::from_toml(RULES)
+    }
+}
+
+#[test]
+fn test_check_with_provider() {
+    let mut detector = HardwareDetector::new();
+    detector.gpu_info.gpus.push(Gpu {
+        description: "NVIDIA GeForce RTX 4060".to_string(),
+        vendor_id: "10de".to_string(),
+        device_id: "2882".to_string(),
+        driver_version: "31.0.15.5222".to_string(),
+        ..Default::default()
+    });
+    detector.network_info.adapters.push(NetworkAdapter {
+        name: "Realtek PCIe GbE Family Controller".to_string(),
+        pnp_device_id: "PCI\\VEN_10EC&DEV_8168&SUBSYS_7B861462&REV_15\\0100".to_string(),
+        driver_version: "10.50.1".to_string(),
+        ..Default::default()
+    });
+
+    let advice = check_with(&detector, &StaticProvider).expect("检查失败");
+    let ids: Vec<_> = advice.iter().map(|a| a.rule_id.as_str()).collect();
+    assert_eq!(ids, ["nvidia", "realtek-8168-msi"]);
+    assert_eq!(
+        advice[0].message,
+        "NVIDIA GeForce RTX 4060: 31.0.15.5222 -> 32.0.15.6094"
+    );
+}
+
+#[test]
+fn test_collect_gpu_subsys() {
+    let mut detector = HardwareDetector::new();
+    detector.gpu_info.gpus.push(Gpu {
+        description: "NVIDIA GeForce RTX 4060".to_string(),
+        vendor_id: "10de".to_string(),
+        device_id: "2882".to_string(),
+        instance_id: "PCI\\VEN_10DE&DEV_2882&SUBSYS_51FA1462&REV_A1\\4&2F2C1A5B&0&0008".to_string(),
+        driver_version: "31.0.15.5222".to_string(),
+        ..Default::default()
+    });
+    // 未找到设备实例时只有厂商与设备 ID
+    detector.gpu_info.gpus.push(Gpu {
+        description: "Intel(R) UHD Graphics 770".to_string(),
+        vendor_id: "8086".to_string(),
+        device_id: "A780".to_string(),
+        driver_version: "31.0.101.4502".to_string(),
+        ..Default::default()
+    });
+    // 驱动版本未知的显卡不检查
+    detector.gpu_info.gpus.push(Gpu {
+        description: "Microsoft Basic Render Driver".to_string(),
+        vendor_id: "1414".to_string(),
+        device_id: "008c".to_string(),
+        ..Default::default()
+    });
+
+    let devices = collect_devices(&detector);
+    assert_eq!(devices.len(), 2);
+    assert_eq!(devices[0].subsys.as_deref(), Some("51fa1462"));
+    assert_eq!(devices[0].driver_version, "31.0.15.5222");
+    assert_eq!(devices[1].device_id, "a780");
+    assert_eq!(devices[1].subsys, None);
+}
+
+#[test]
+fn test_missing_offline_file_yields_no_rules() {
+    let provider = OfflineProvider {
+        path: std::env::temp_dir().join("hardware-master-no-such-driver-rules.toml"),
+    };
+    assert!(provider.rules().expect("读取失败").rules.is_empty());
+}
+
+#[test]
+fn test_rule_set_merge_keeps_existing_ids() {
+    let mut rule_set = DriverRuleSet::from_toml(RULES).expect("规则解析失败");
+    let online = DriverRuleSet::from_toml(
+        r#"
+[[rules]]
+id = "nvidia"
+vendor = "10de"
+min_version = "99.0"
+
+[[rules]]
+id = "intel-uhd"
+vendor = "8086"
+min_version = "31.0.101.5000"
+"#,
+    )
+    .expect("规则解析失败");
+    rule_set.merge(online);
+
+    let ids: Vec<&str> = rule_set.rules.iter().map(|rule| rule.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        ["realtek-8168", "realtek-8168-msi", "nvidia", "intel-uhd"]
+    );
+    // id 相同的规则以原规则集为准
+    assert_eq!(rule_set.rules[2].min_version, "32.0.15.6094");
+}
@@ -0,0 +1,61 @@
+use hardware_master::detector::drivers::{resolve_driver_path, DriverCategory, KernelDriver};
+
+#[test]
+fn test_driver_category_from_file_name() {
+    for name in ["storahci.sys", "iaStorAC.sys", "disk.sys", "USBSTOR.SYS"] {
+        assert_eq!(
+            DriverCategory::from_file_name(name),
+            DriverCategory::Storage,
+            "{}",
+            name
+        );
+    }
+    for name in ["amdkmdag.sys", "igdkmdn64.sys", "BasicDisplay.sys"] {
+        assert_eq!(
+            DriverCategory::from_file_name(name),
+            DriverCategory::Gpu,
+            "{}",
+            name
+        );
+    }
+    for name in ["rt640x64.sys", "tcpip.sys", "e1d68x64.sys"] {
+        assert_eq!(
+            DriverCategory::from_file_name(name),
+            DriverCategory::Network,
+            "{}",
+            name
+        );
+    }
+    for name in ["ntoskrnl.exe", "hal.dll", "WdFilter.sys"] {
+        assert_eq!(
+            DriverCategory::from_file_name(name),
+            DriverCategory::Other,
+            "{}",
+            name
+        );
+        assert!(!DriverCategory::from_file_name(name).is_hardware());
+    }
+}
+
+#[test]
+fn test_resolve_driver_path_keeps_full_path() {
+    assert_eq!(
+        resolve_driver_path("C:\\Windows\\System32\\drivers\\disk.sys", "C:\\Windows"),
+        "C:\\Windows\\System32\\drivers\\disk.sys"
+    );
+    // 前缀忽略大小写，Windows 目录末尾的分隔符不重复
+    assert_eq!(
+        resolve_driver_path("\\systemroot\\System32\\ntoskrnl.exe", "D:\\Windows\\"),
+        "D:\\Windows\\System32\\ntoskrnl.exe"
+    );
+}
+
+#[test]
+fn test_kernel_driver_signature_desc() {
+    let mut driver = KernelDriver::default();
+    assert_eq!(driver.signature_desc(), "未知");
+    driver.signed = Some(true);
+    assert_eq!(driver.signature_desc(), "已签名");
+    driver.signed = Some(false);
+    assert_eq!(driver.signature_desc(), "未签名");
+}
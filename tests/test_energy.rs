@@ -0,0 +1,70 @@
+use hardware_master::analysis::energy::{battery_discharge_watts, EnergyProfile, PowerSource};
+use hardware_master::detector::battery::Battery;
+use hardware_master::detector::gpu::{Gpu, GpuType};
+use hardware_master::detector::ComputerType;
+use hardware_master::HardwareDetector;
+
+#[test]
+fn test_builtin_profile_tdp() {
+    let profile = EnergyProfile::builtin();
+    assert_eq!(
+        profile.cpu_tdp("Intel(R) Core(TM) i7-1165G7 @ 2.80GHz", true),
+        15.0
+    );
+    assert_eq!(
+        profile.cpu_tdp("AMD Ryzen 7 5800H with Radeon Graphics", true),
+        45.0
+    );
+    assert_eq!(
+        profile.cpu_tdp("13th Gen Intel(R) Core(TM) i9-13980HX", true),
+        55.0
+    );
+    assert_eq!(profile.cpu_tdp("Intel(R) Core(TM) i5-12600K", false), 125.0);
+    // 未匹配时使用默认值
+    assert_eq!(profile.cpu_tdp("Unknown CPU", false), 65.0);
+
+    assert_eq!(profile.gpu_tdp("NVIDIA GeForce RTX 4070", false), 220.0);
+    assert_eq!(profile.gpu_tdp("NVIDIA GeForce RTX 4070", true), 110.0);
+}
+
+#[test]
+fn test_battery_discharge_watts() {
+    let discharging = Battery {
+        rate: Some(-12_500),
+        ..Default::default()
+    };
+    assert_eq!(battery_discharge_watts(&[discharging]), Some(12.5));
+
+    let charging = Battery {
+        power_online: true,
+        rate: Some(30_000),
+        ..Default::default()
+    };
+    assert_eq!(battery_discharge_watts(&[charging]), None);
+    assert_eq!(battery_discharge_watts(&[]), None);
+}
+
+#[test]
+fn test_estimate_prefers_battery() {
+    let profile = EnergyProfile::builtin();
+    let mut detector = HardwareDetector::new();
+    detector.system_info.computer_type = ComputerType::Desktop;
+    detector.gpu_info.gpus.push(Gpu {
+        description: "NVIDIA GeForce RTX 4060".to_string(),
+        gpu_type: GpuType::DiscreteGpu,
+        ..Default::default()
+    });
+
+    let estimate = profile.estimate(&detector);
+    assert_eq!(estimate.source, PowerSource::ComponentEstimate);
+    assert!(estimate.watts > profile.platform.desktop_w);
+
+    detector.battery_info.batteries.push(Battery {
+        rate: Some(-20_000),
+        ..Default::default()
+    });
+    let estimate = profile.estimate(&detector);
+    assert_eq!(estimate.source, PowerSource::BatteryDischarge);
+    assert_eq!(estimate.watts, 20.0);
+    assert!((estimate.daily_kwh(8.0) - 0.16).abs() < 1e-9);
+}
@@ -0,0 +1,28 @@
+#![cfg(windows)]
+
+use hardware_master::detector::events::{
+    category_for_interface, HotplugWatcher, WATCHED_INTERFACES,
+};
+use hardware_master::detector::DetectionCategory;
+use windows::core::GUID;
+
+#[test]
+fn test_category_for_interface() {
+    for (guid, category) in WATCHED_INTERFACES.iter() {
+        assert_eq!(category_for_interface(guid), Some(*category));
+    }
+    // 显示器接口 GUID_DEVINTERFACE_MONITOR
+    let monitor = GUID::from_u128(0xe6f07b5f_ee97_4a90_b076_33f57bf4eaa7);
+    assert_eq!(
+        category_for_interface(&monitor),
+        Some(DetectionCategory::Monitor)
+    );
+    assert_eq!(category_for_interface(&GUID::zeroed()), None);
+}
+
+#[test]
+fn test_hotplug_watcher_start_and_drop() {
+    let watcher = HotplugWatcher::start(|categories| println!("{:?}", categories))
+        .expect("订阅设备变化通知失败");
+    drop(watcher);
+}
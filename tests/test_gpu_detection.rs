@@ -1,6 +1,9 @@
+#[cfg(windows)]
 use hardware_master::detector::gpu::detect_gpu;
+use hardware_master::detector::gpu::{get_gpu_type, normalize_vbios_version, GpuType};
 
 #[test]
+#[cfg(windows)]
 fn test_gpu_detection() {
     match detect_gpu() {
         Ok(info) => {
@@ -13,9 +16,15 @@ fn test_gpu_detection() {
                 println!("    Manufacturer: {}", gpu.manufacturer);
                 println!("    Chip Vendor: {}", gpu.chip_vendor);
                 println!("    Type: {:?}", gpu.gpu_type);
-                println!("    VRAM: {:.0} MB", gpu.vram_size);
+                println!("    VRAM: {}", gpu.dedicated_vram);
+                println!("    Shared Memory: {:.0} MB", gpu.shared_system_memory);
                 println!("    Device ID: {}", gpu.device_id);
                 println!("    Vendor ID: {}", gpu.vendor_id);
+                println!("    VBIOS: {}", gpu.vbios_version);
+                println!("    Driver: {}", gpu.driver_version);
+                for output in gpu.outputs.iter() {
+                    println!("    Output: {}", output.summary());
+                }
             }
 
             // 验证至少有一个 GPU
@@ -27,3 +36,36 @@ fn test_gpu_detection() {
         }
     }
 }
+
+#[test]
+fn test_virtual_display_classification() {
+    for description in [
+        "Parsec Virtual Display Adapter",
+        "spacedesk Graphics Adapter",
+        "IddCx Indirect Display Adapter",
+        "Meta Virtual Monitor",
+    ] {
+        let gpu_type = get_gpu_type(description, "0000", "0000");
+        assert_eq!(gpu_type, GpuType::VirtualDisplay, "{}", description);
+        assert!(gpu_type.is_virtual());
+    }
+
+    assert_eq!(
+        get_gpu_type("VMware SVGA 3D", "15ad", "0405"),
+        GpuType::VirtualGpu
+    );
+    assert!(!get_gpu_type("Intel(R) UHD Graphics 620", "8086", "5917").is_virtual());
+}
+
+#[test]
+fn test_normalize_vbios_version() {
+    assert_eq!(
+        normalize_vbios_version("Version94.6.3e.0.1\0"),
+        Some("94.6.3e.0.1".to_string())
+    );
+    assert_eq!(
+        normalize_vbios_version(" 113-D4120100-100 "),
+        Some("113-D4120100-100".to_string())
+    );
+    assert_eq!(normalize_vbios_version("Version"), None);
+}
@@ -0,0 +1,192 @@
+use hardware_master::detector::gpu::{get_gpu_type, GpuType};
+use hardware_master::iddb::gpu::{GpuRuleDatabase, GPU_RULE_DB};
+
+#[test]
+fn test_amd_integrated_gpus() {
+    for (description, device_id) in [
+        ("AMD Radeon(TM) 680M", "1681"),
+        ("AMD Radeon 780M Graphics", "15bf"),
+        ("AMD Radeon(TM) Graphics", "164e"),
+        ("AMD Radeon(TM) Vega 8 Graphics", "15d8"),
+        ("AMD Radeon R7 Graphics", "1313"),
+        // 设备 ID 未收录时按名称判断
+        ("AMD Radeon 890M Graphics", "ffff"),
+    ] {
+        assert_eq!(
+            get_gpu_type(description, "1002", device_id),
+            GpuType::IntegratedGpu,
+            "{}",
+            description
+        );
+    }
+}
+
+#[test]
+fn test_amd_discrete_gpus() {
+    for (description, device_id) in [
+        ("AMD Radeon RX 7900 XTX", "744c"),
+        ("AMD Radeon RX 6600", "73ff"),
+        ("Radeon RX 580 Series", "67df"),
+    ] {
+        assert_eq!(
+            get_gpu_type(description, "1002", device_id),
+            GpuType::DiscreteGpu,
+            "{}",
+            description
+        );
+    }
+}
+
+#[test]
+fn test_intel_arc_and_integrated_gpus() {
+    // Arc 独立显卡
+    assert_eq!(
+        get_gpu_type("Intel(R) Arc(TM) A770 Graphics", "8086", "56a0"),
+        GpuType::DiscreteGpu
+    );
+    assert_eq!(
+        get_gpu_type("Intel(R) Arc(TM) B580 Graphics", "8086", "e20b"),
+        GpuType::DiscreteGpu
+    );
+    assert_eq!(
+        get_gpu_type("Intel(R) Iris(R) Xe MAX Graphics", "8086", "4905"),
+        GpuType::DiscreteGpu
+    );
+
+    // Meteor Lake 之后的核显同样叫 Arc
+    assert_eq!(
+        get_gpu_type("Intel(R) Arc(TM) Graphics", "8086", "7d55"),
+        GpuType::IntegratedGpu
+    );
+    assert_eq!(
+        get_gpu_type("Intel(R) UHD Graphics 770", "8086", "4680"),
+        GpuType::IntegratedGpu
+    );
+    assert_eq!(
+        get_gpu_type("Intel(R) Iris(R) Xe Graphics", "8086", "9a49"),
+        GpuType::IntegratedGpu
+    );
+}
+
+#[test]
+fn test_nvidia_gpus() {
+    assert_eq!(
+        get_gpu_type("NVIDIA GeForce RTX 4090", "10de", "2684"),
+        GpuType::DiscreteGpu
+    );
+    // 型号与 AMD 核显重名
+    assert_eq!(
+        get_gpu_type("NVIDIA GeForce GTX 680M", "10de", "11a0"),
+        GpuType::DiscreteGpu
+    );
+}
+
+#[test]
+fn test_virtual_and_software_adapters() {
+    assert_eq!(
+        get_gpu_type("Parsec Virtual Display Adapter", "0000", "0000"),
+        GpuType::VirtualDisplay
+    );
+    assert_eq!(
+        get_gpu_type("Microsoft Basic Render Driver", "1414", "008c"),
+        GpuType::Cpu
+    );
+    assert_eq!(
+        get_gpu_type("VirtualBox Graphics Adapter (WDDM)", "80ee", "beef"),
+        GpuType::VirtualGpu
+    );
+    assert_eq!(
+        get_gpu_type("Red Hat QXL controller", "1b36", "0100"),
+        GpuType::VirtualGpu
+    );
+    assert_eq!(
+        get_gpu_type("Microsoft Hyper-V Video", "1414", "5353"),
+        GpuType::VirtualGpu
+    );
+}
+
+#[test]
+fn test_unknown_vendor_falls_back_to_keywords() {
+    assert_eq!(
+        get_gpu_type("Intel(R) Arc(TM) A380 Graphics", "未知", "未知"),
+        GpuType::DiscreteGpu
+    );
+    assert_eq!(
+        get_gpu_type("Intel(R) HD Graphics 530", "未知", "未知"),
+        GpuType::IntegratedGpu
+    );
+    assert_eq!(
+        get_gpu_type("AMD Radeon(TM) Graphics", "未知", "未知"),
+        GpuType::IntegratedGpu
+    );
+    assert_eq!(
+        get_gpu_type("NVIDIA GeForce RTX 3060", "未知", "未知"),
+        GpuType::DiscreteGpu
+    );
+    assert_eq!(
+        get_gpu_type("Unknown Adapter", "未知", "未知"),
+        GpuType::Other
+    );
+}
+
+#[test]
+fn test_user_rules_take_precedence() {
+    let mut db = GpuRuleDatabase::from_toml(
+        r#"
+[[rules]]
+name = "Intel 核显"
+type = "integrated"
+vendor = "8086"
+"#,
+    )
+    .expect("规则解析失败");
+    db.merge_toml(
+        r#"
+[[rules]]
+name = "新独立显卡"
+type = "discrete"
+vendor = "8086"
+devices = ["E2A0-E2AF"]
+"#,
+    )
+    .expect("规则解析失败");
+
+    assert_eq!(db.rules().len(), 2);
+    let rule = db
+        .classify("Intel(R) Graphics", Some(0x8086), Some(0xe2a5))
+        .expect("应命中规则");
+    assert_eq!(rule.name, "新独立显卡");
+    assert_eq!(rule.gpu_type, GpuType::DiscreteGpu);
+
+    // 不在范围内的设备、设备 ID 未知时由后面的规则判断
+    for device_id in [Some(0xe2b0), None] {
+        let rule = db
+            .classify("Intel(R) Graphics", Some(0x8086), device_id)
+            .expect("应命中规则");
+        assert_eq!(rule.gpu_type, GpuType::IntegratedGpu);
+    }
+}
+
+#[test]
+fn test_invalid_rules() {
+    for content in [
+        // 无效的厂商 ID
+        "[[rules]]\nname = \"a\"\ntype = \"discrete\"\nvendor = \"XYZ\"\n",
+        // 无效的设备 ID 范围
+        "[[rules]]\nname = \"a\"\ntype = \"discrete\"\nvendor = \"10de\"\ndevices = [\"2000-1000\"]\n",
+        // 指定设备 ID 但没有厂商 ID
+        "[[rules]]\nname = \"a\"\ntype = \"discrete\"\ndevices = [\"2000\"]\nkeywords = [\"x\"]\n",
+        // 没有任何条件
+        "[[rules]]\nname = \"a\"\ntype = \"discrete\"\n",
+        // 未知的类型
+        "[[rules]]\nname = \"a\"\ntype = \"gpu\"\nvendor = \"10de\"\n",
+    ] {
+        assert!(GpuRuleDatabase::from_toml(content).is_err(), "{}", content);
+    }
+}
+
+#[test]
+fn test_builtin_rules_loaded() {
+    assert!(!GPU_RULE_DB.rules().is_empty());
+    assert!(GPU_RULE_DB.rules().iter().all(|rule| !rule.name.is_empty()));
+}
@@ -0,0 +1,123 @@
+use hardware_master::detector::gpu::vendor::{
+    adl_pnp_matches, adl_sensor_value, c_buffer_to_string, nvml_device_matches, query_with,
+    RawReadings, VendorLibrary,
+};
+
+fn readings() -> RawReadings {
+    RawReadings {
+        core_clock_mhz: Some(1830),
+        memory_clock_mhz: Some(8001),
+        power: Some(115),
+        fan_percent: Some(42),
+        vbios_version: Some("94.04.3A.00.2B".to_string()),
+    }
+}
+
+#[test]
+fn test_vendor_library_for_vendor() {
+    assert_eq!(VendorLibrary::for_vendor("10de"), Some(VendorLibrary::Nvml));
+    assert_eq!(VendorLibrary::for_vendor("10DE"), Some(VendorLibrary::Nvml));
+    assert_eq!(VendorLibrary::for_vendor("1002"), Some(VendorLibrary::Adl));
+    assert_eq!(VendorLibrary::for_vendor("8086"), None);
+    assert_eq!(VendorLibrary::for_vendor("未知"), None);
+}
+
+#[test]
+fn test_vendor_stats_power_units() {
+    // NVML 的功耗以毫瓦为单位
+    let stats = VendorLibrary::Nvml.stats(RawReadings {
+        power: Some(115_240),
+        ..readings()
+    });
+    assert_eq!(stats.source, "NVML");
+    assert_eq!(stats.power_watts, Some(115.24));
+    assert_eq!(stats.core_clock_mhz, Some(1830));
+    assert_eq!(stats.memory_clock_mhz, Some(8001));
+    assert_eq!(stats.fan_percent, Some(42));
+    assert_eq!(stats.vbios_version.as_deref(), Some("94.04.3A.00.2B"));
+
+    // ADL 的功耗以瓦为单位
+    let stats = VendorLibrary::Adl.stats(readings());
+    assert_eq!(stats.source, "ADL");
+    assert_eq!(stats.power_watts, Some(115.0));
+
+    // 读取失败的项保持为空
+    let stats = VendorLibrary::Nvml.stats(RawReadings::default());
+    assert_eq!(stats.power_watts, None);
+    assert_eq!(stats.summary(), "");
+}
+
+#[test]
+fn test_query_with_library_missing() {
+    assert_eq!(query_with("10de", "2504", |_, _| None), None);
+    assert_eq!(query_with("1002", "73bf", |_, _| None), None);
+}
+
+#[test]
+fn test_query_with_dispatch() {
+    let mut called = None;
+    let stats = query_with("1002", "73BF", |library, device_id| {
+        called = Some((library, device_id));
+        Some(readings())
+    })
+    .expect("应返回运行数据");
+    assert_eq!(called, Some((VendorLibrary::Adl, 0x73bf)));
+    assert_eq!(stats.source, "ADL");
+    assert_eq!(
+        stats.summary(),
+        "核心 1830 MHz · 显存 8001 MHz · 功耗 115.0 W · 风扇 42%"
+    );
+
+    // 不支持的厂商与无效的设备 ID 不调用运行库
+    let mut calls = 0;
+    assert_eq!(
+        query_with("8086", "a780", |_, _| {
+            calls += 1;
+            Some(readings())
+        }),
+        None
+    );
+    assert_eq!(
+        query_with("10de", "未知", |_, _| {
+            calls += 1;
+            Some(readings())
+        }),
+        None
+    );
+    assert_eq!(calls, 0);
+}
+
+#[test]
+fn test_device_matching() {
+    assert!(nvml_device_matches(0x2504_10de, 0x2504));
+    assert!(!nvml_device_matches(0x2504_10de, 0x2503));
+
+    assert!(adl_pnp_matches(
+        "PCI_VEN_1002&DEV_73BF&SUBSYS_23181458&REV_C1",
+        0x73bf
+    ));
+    assert!(!adl_pnp_matches("PCI_VEN_1002&DEV_73BF", 0x73b));
+    assert!(!adl_pnp_matches("", 0x73bf));
+}
+
+#[test]
+fn test_adl_sensor_value() {
+    assert_eq!(adl_sensor_value(1, 2400), Some(2400));
+    assert_eq!(adl_sensor_value(1, 0), Some(0));
+    assert_eq!(adl_sensor_value(0, 2400), None);
+    assert_eq!(adl_sensor_value(1, -1), None);
+}
+
+#[test]
+fn test_c_buffer_to_string() {
+    let buffer: Vec<std::ffi::c_char> = b"  94.04.3A.00.2B \0garbage"
+        .iter()
+        .map(|b| *b as std::ffi::c_char)
+        .collect();
+    assert_eq!(
+        c_buffer_to_string(&buffer).as_deref(),
+        Some("94.04.3A.00.2B")
+    );
+    assert_eq!(c_buffer_to_string(&[0; 8]), None);
+    assert_eq!(c_buffer_to_string(&[]), None);
+}
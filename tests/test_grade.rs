@@ -0,0 +1,112 @@
+use hardware_master::analysis::grade::{GradeRules, Tier};
+use hardware_master::constants::BYTES_PER_GB;
+use hardware_master::detector::disk::DiskType;
+use hardware_master::detector::gpu::{Gpu, GpuType};
+use hardware_master::detector::memory::MemorySlot;
+use hardware_master::utils::size::ByteSize;
+use hardware_master::HardwareDetector;
+
+/// 构造一台指定配置的电脑
+fn machine(cpu: &str, cores: u32, gpu: &str, memory_gb: f64, disk: DiskType) -> HardwareDetector {
+    let mut detector = HardwareDetector::new();
+    detector.cpu_info.name = cpu.to_string();
+    detector.cpu_info.physical_cores = cores;
+    detector.gpu_info.gpus.push(Gpu {
+        description: gpu.to_string(),
+        gpu_type: GpuType::DiscreteGpu,
+        ..Default::default()
+    });
+    detector.memory_info.total_memory = ByteSize::from(memory_gb * BYTES_PER_GB);
+    detector.memory_info.slots.push(MemorySlot {
+        capacity: detector.memory_info.total_memory,
+        ..Default::default()
+    });
+    detector.disk_info.disk_type = disk;
+    detector
+}
+
+#[test]
+fn test_builtin_cpu_years_and_gpu_classes() {
+    let rules = GradeRules::builtin();
+    assert_eq!(
+        rules.cpu_release_year("Intel(R) Core(TM) i7-1165G7 @ 2.80GHz"),
+        Some(2020)
+    );
+    assert_eq!(
+        rules.cpu_release_year("13th Gen Intel(R) Core(TM) i9-13900K"),
+        Some(2022)
+    );
+    assert_eq!(
+        rules.cpu_release_year("AMD Ryzen 7 5800H with Radeon Graphics"),
+        Some(2021)
+    );
+    assert_eq!(rules.cpu_release_year("Intel(R) Celeron(R) N4020"), None);
+
+    assert_eq!(rules.gpu_class("NVIDIA GeForce RTX 4090"), 4);
+    assert_eq!(rules.gpu_class("NVIDIA GeForce RTX 4060 Ti"), 3);
+    assert_eq!(rules.gpu_class("NVIDIA GeForce RTX 4060 Laptop GPU"), 2);
+    assert_eq!(rules.gpu_class("Intel(R) Iris(R) Xe Graphics"), 1);
+    assert_eq!(rules.gpu_class("Microsoft Basic Display Adapter"), 0);
+}
+
+#[test]
+fn test_grade_tiers() {
+    let rules = GradeRules::builtin();
+
+    let flagship = machine(
+        "Intel(R) Core(TM) i9-14900K",
+        24,
+        "NVIDIA GeForce RTX 4090",
+        64.0,
+        DiskType::SSD,
+    );
+    let grade = rules.grade(&flagship);
+    assert_eq!(grade.tier, Tier::Flagship);
+    assert_eq!(grade.score, 100.0);
+    assert_eq!(grade.summary(), "综合评级: 旗舰 (100 分)");
+    assert_eq!(grade.breakdown(), "处理器 35 / 显卡 35 / 内存 15 / 硬盘 15");
+
+    let mainstream = machine(
+        "12th Gen Intel(R) Core(TM) i5-12400F",
+        6,
+        "NVIDIA GeForce RTX 3060",
+        16.0,
+        DiskType::SSD,
+    );
+    assert_eq!(rules.grade(&mainstream).tier, Tier::Mainstream);
+
+    let entry = machine(
+        "Intel(R) Core(TM) i5-4590",
+        4,
+        "Microsoft Basic Display Adapter",
+        8.0,
+        DiskType::HDD,
+    );
+    let grade = rules.grade(&entry);
+    assert_eq!(grade.tier, Tier::Entry);
+    assert_eq!(grade.breakdown(), "处理器 5 / 显卡 0 / 内存 5 / 硬盘 3");
+}
+
+#[test]
+fn test_custom_rules() {
+    let rules = GradeRules::from_toml(
+        r#"
+        [tiers]
+        mainstream = 10
+        high_end = 20
+        flagship = 30
+
+        [[score]]
+        component = "内存"
+        points = 20
+        when = [{ fact = "memory.total_gb", op = "ge", value = 16 }]
+        "#,
+    )
+    .unwrap();
+    let detector = machine("Unknown", 4, "Unknown", 16.0, DiskType::SSD);
+    let grade = rules.grade(&detector);
+    assert_eq!(grade.tier, Tier::HighEnd);
+    assert_eq!(grade.breakdown(), "内存 20");
+
+    assert!(GradeRules::from_toml("[tiers]\nmainstream = 1").is_err());
+}
@@ -0,0 +1,51 @@
+use hardware_master::detector::DetectionCategory;
+use hardware_master::handover::HandoverState;
+use hardware_master::ui::tab::AppTab;
+
+#[test]
+fn test_handover_roundtrip() {
+    let mut state = HandoverState::default();
+    state.config.advanced_mode = true;
+    state.current_tab = AppTab::Trends;
+    state.refresh_categories = vec![DetectionCategory::Disk];
+    state.show_advice = true;
+
+    let path = state.write_temp().expect("写入交接文件失败");
+    let restored = HandoverState::take(&path).expect("读取交接文件失败");
+    assert_eq!(restored, state);
+
+    // 读取后删除交接文件
+    assert!(!path.exists());
+    assert!(HandoverState::take(&path).is_err());
+}
+
+#[test]
+fn test_handover_temp_name_unpredictable() {
+    let state = HandoverState::default();
+    let first = state.write_temp().expect("写入交接文件失败");
+    let second = state.write_temp().expect("写入交接文件失败");
+    // 文件名为 128 位随机数，每次写入都不同
+    assert_ne!(first, second);
+    let name = first.file_name().unwrap().to_string_lossy().into_owned();
+    let random = name
+        .strip_prefix("hardware-master-handover-")
+        .and_then(|rest| rest.strip_suffix(".toml"))
+        .expect("文件名格式错误");
+    assert_eq!(random.len(), 32, "{}", name);
+    assert!(random.chars().all(|c| c.is_ascii_hexdigit()), "{}", name);
+    HandoverState::take(&first).expect("读取交接文件失败");
+    HandoverState::take(&second).expect("读取交接文件失败");
+}
+
+#[test]
+fn test_handover_args() {
+    let path = std::path::Path::new("state.toml");
+    let args = HandoverState::to_args(path);
+    let parsed = HandoverState::path_from_args(
+        std::iter::once("hardware-master.exe".to_string()).chain(args),
+    );
+    assert_eq!(parsed.as_deref(), Some(path));
+
+    let none = HandoverState::path_from_args(vec!["hardware-master.exe".to_string()]);
+    assert_eq!(none, None);
+}
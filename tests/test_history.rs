@@ -0,0 +1,103 @@
+use hardware_master::detector::battery::Battery;
+use hardware_master::history::{battery_degradation, HistoryStore, Snapshot};
+use hardware_master::utils::size::ByteSize;
+use hardware_master::HardwareDetector;
+
+fn temp_store(name: &str) -> HistoryStore {
+    let path = std::env::temp_dir().join(format!(
+        "hardware-master-{}-{}.jsonl",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    HistoryStore::new(path)
+}
+
+#[test]
+fn test_snapshot_from_detector() {
+    let mut detector = HardwareDetector::new();
+    detector.battery_info.batteries.push(Battery {
+        full_charged_capacity: 45_000,
+        designed_capacity: 50_000,
+        ..Default::default()
+    });
+    detector.disk_info.free_space = ByteSize::from_kb(1);
+    detector.disk_info.temperature = Some(40.0);
+
+    let snapshot = Snapshot::from_detector(&detector, 1_700_000_000);
+    assert_eq!(snapshot.timestamp, 1_700_000_000);
+    assert_eq!(snapshot.battery_full_capacity, Some(45_000));
+    assert_eq!(snapshot.battery_designed_capacity, Some(50_000));
+    assert_eq!(snapshot.battery_health(), Some(90.0));
+    assert_eq!(snapshot.disk_free_space, Some(1024.0));
+    assert_eq!(snapshot.disk_wear_percent, None);
+    assert_eq!(snapshot.disk_temperature, Some(40.0));
+}
+
+#[test]
+fn test_snapshot_reads_legacy_temperature() {
+    let snapshot: Snapshot =
+        serde_json::from_str(r#"{"timestamp":1,"average_temperature":38.0}"#).expect("解析失败");
+    assert_eq!(snapshot.disk_temperature, Some(38.0));
+}
+
+#[test]
+fn test_history_append_and_load() {
+    let store = temp_store("append");
+    assert!(store.load().expect("读取失败").is_empty());
+
+    let first = Snapshot {
+        timestamp: 1,
+        battery_full_capacity: Some(50_000),
+        ..Default::default()
+    };
+    let second = Snapshot {
+        timestamp: 2,
+        disk_wear_percent: Some(3.0),
+        ..Default::default()
+    };
+    store.append(&first).expect("写入失败");
+    store.append(&second).expect("写入失败");
+
+    assert_eq!(store.load().expect("读取失败"), vec![first, second]);
+    let _ = std::fs::remove_file(store.path());
+}
+
+#[test]
+fn test_history_skips_invalid_lines() {
+    let store = temp_store("invalid");
+    std::fs::write(store.path(), "{\"timestamp\":5}\nnot json\n\n").expect("写入失败");
+
+    let snapshots = store.load().expect("读取失败");
+    assert_eq!(snapshots.len(), 1);
+    assert_eq!(snapshots[0].timestamp, 5);
+    let _ = std::fs::remove_file(store.path());
+}
+
+#[test]
+fn test_battery_degradation_uses_latest_record() {
+    // 旧版本记录没有设计容量，无法计算健康度
+    let legacy = Snapshot {
+        timestamp: 1,
+        battery_full_capacity: Some(50_000),
+        ..Default::default()
+    };
+    assert_eq!(legacy.battery_health(), None);
+
+    let record = |timestamp, full| Snapshot {
+        timestamp,
+        battery_full_capacity: Some(full),
+        battery_designed_capacity: Some(60_000),
+        ..Default::default()
+    };
+    let snapshots = vec![legacy, record(2, 57_000), record(3, 45_000)];
+    assert_eq!(battery_degradation(&snapshots), Some(25.0));
+
+    // 电池被移除后仍以最后一次有电池的记录为准
+    let mut snapshots = snapshots;
+    snapshots.push(Snapshot {
+        timestamp: 4,
+        ..Default::default()
+    });
+    assert_eq!(battery_degradation(&snapshots), Some(25.0));
+}
@@ -0,0 +1,29 @@
+use hardware_master::detector::hypervisor::{cpuid_vendor, Hypervisor};
+use hardware_master::detector::SystemInfo;
+
+#[test]
+fn test_cpuid_vendor_strings() {
+    // "KVMKVMKVM" 不足 12 字节，末尾以 0 填充
+    let vendor = cpuid_vendor(0x4B4D564B, 0x564B4D56, 0x0000004D);
+    assert_eq!(vendor, "KVMKVMKVM");
+    assert_eq!(Hypervisor::from_cpuid_vendor(&vendor), Hypervisor::Kvm);
+    assert_eq!(
+        Hypervisor::from_cpuid_vendor("VBoxVBoxVBox"),
+        Hypervisor::VirtualBox
+    );
+    assert_eq!(Hypervisor::Other("ACRN".to_string()).to_string(), "ACRN");
+}
+
+#[test]
+fn test_system_info_virtualization() {
+    let mut info = SystemInfo {
+        system_manufacturer: "LENOVO".to_string(),
+        computer_model: "ThinkPad X1 Carbon".to_string(),
+        ..Default::default()
+    };
+    assert!(!info.is_virtual_machine());
+
+    // 检测到虚拟机监视器时，即使制造商信息正常也视为虚拟机
+    info.virtualization = Some(Hypervisor::HyperV);
+    assert!(info.is_virtual_machine());
+}
@@ -0,0 +1,342 @@
+use hardware_master::iddb::{format_id, parse_id, Bus, DeviceIdDatabase, IdsSource};
+use std::collections::HashMap;
+
+#[test]
+fn test_load_from_paths_prefers_user_file() {
+    let dir = std::env::temp_dir().join(format!("hardware-master-iddb-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("pci.ids"),
+        "# 测试数据\n10ec  Realtek Test\n\t8168  RTL8168 Test Controller\n",
+    )
+    .unwrap();
+
+    let db = DeviceIdDatabase::load_from_paths(std::slice::from_ref(&dir));
+    assert_eq!(db.pci_source(), &IdsSource::File(dir.join("pci.ids")));
+    let vendor = db.pci().vendor(0x10ec);
+    assert_eq!(vendor.name().as_deref(), Some("Realtek Test"));
+    assert_eq!(
+        vendor.device(0x8168).name().as_deref(),
+        Some("RTL8168 Test Controller")
+    );
+
+    // 没有 usb.ids 时使用内置数据
+    assert_eq!(db.usb_source(), &IdsSource::Embedded);
+    assert!(db.usb().vendor(0x046d).name().is_some());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_load_from_paths_ignores_invalid_file() {
+    let dir = std::env::temp_dir().join(format!(
+        "hardware-master-iddb-invalid-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("pci.ids"), "<html>404 Not Found</html>\n").unwrap();
+
+    let db = DeviceIdDatabase::load_from_paths(std::slice::from_ref(&dir));
+    assert_eq!(db.pci_source(), &IdsSource::Embedded);
+    assert!(db.pci().vendor(0x8086).name().is_some());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_lookup_embedded_database() {
+    let db = DeviceIdDatabase::load_from_paths(&[]);
+    let vendor = db.pci().vendor(0x10ec);
+    assert_eq!(
+        vendor.name().as_deref(),
+        Some("Realtek Semiconductor Co., Ltd.")
+    );
+    let device = vendor.device(0x8168);
+    assert_eq!(
+        device.name().as_deref(),
+        Some("RTL8111/8168/8211/8411 PCI Express Gigabit Ethernet Controller")
+    );
+    assert_eq!(
+        device.subsystem(0x1028, 0x0283).as_deref(),
+        Some("Vostro 220")
+    );
+    assert_eq!(device.subsystem(0x1028, 0xffff), None);
+
+    assert_eq!(
+        db.bus(Bus::Usb).vendor(0x046d).name().as_deref(),
+        Some("Logitech, Inc.")
+    );
+    assert!(db.pci().vendor(0x0002).name().is_none());
+    assert!(db.pci().vendor(0x10ec).device(0xfffe).name().is_none());
+}
+
+/// 包含各种异常行的 pci.ids 片段
+const PCI_FIXTURE: &str = "\
+# 注释行
+#\t10ec  注释中的设备行不计入
+
+10ec  Realtek Semiconductor
+\t8168  RTL8168 Gigabit Ethernet
+\t\t1028 0283  Vostro 220
+\t\t1043 8432  P5Q Deluxe
+\t\t1043  缺少子系统设备 ID
+\t\tzzzz 0283  非十六进制的子系统行
+\t8139  RTL-8139
+10e  三位厂商 ID
+\t1234  属于无效厂商的设备
+1af4 单空格分隔
+\t1000  属于无法解析厂商的设备
+8086  Intel Corporation
+\t1533  I210 Gigabit Network Connection
+\t8169
+10ec  Realtek Duplicate
+\t8168  重复的设备
+\t\t1028 0283  重复的子系统
+\t8125  RTL8125 2.5GbE Controller
+\t812  三位设备 ID
+C 02  Network controller
+\t00  Ethernet controller
+\t\t00  Fast Ethernet
+";
+
+/// 设备下带接口行的 usb.ids 片段
+const USB_FIXTURE: &str = "\
+046d  Logitech, Inc.
+\tc52b  Unifying Receiver
+\t\t00  Keyboard Interface
+\t\t01  Mouse Interface
+\t\t02  HID++ Interface
+\tc077  M105 Optical Mouse
+C 03  Human Interface Device
+\t01  Boot Interface Subclass
+\t\t01  Keyboard
+";
+
+#[test]
+fn test_fixture_malformed_lines() {
+    let db = DeviceIdDatabase::from_strings(PCI_FIXTURE, "");
+    let pci = db.pci();
+
+    assert_eq!(
+        pci.vendor(0x8086).device(0x1533).name().as_deref(),
+        Some("I210 Gigabit Network Connection")
+    );
+    assert_eq!(
+        pci.vendor(0x10ec).device(0x8139).name().as_deref(),
+        Some("RTL-8139")
+    );
+
+    // ID 不是 4 位十六进制或缺少两个空格分隔的厂商行被忽略，其下属设备也不计入
+    assert!(pci.vendor(0x010e).name().is_none());
+    assert!(pci.vendor(0x010e).device(0x1234).name().is_none());
+    assert!(pci.vendor(0x1af4).name().is_none());
+    assert!(pci.vendor(0x1af4).device(0x1000).name().is_none());
+
+    // 没有名称或 ID 位数不对的设备行被忽略
+    assert!(pci.vendor(0x8086).device(0x8169).name().is_none());
+    assert!(pci.vendor(0x10ec).device(0x0812).name().is_none());
+
+    // 设备类别段落不会被当作厂商
+    assert!(pci.vendor(0x0c02).name().is_none());
+    assert!(pci.vendor(0x0002).name().is_none());
+    assert!(pci.vendor(0x0002).device(0x0000).name().is_none());
+}
+
+#[test]
+fn test_fixture_duplicate_vendor() {
+    let db = DeviceIdDatabase::from_strings(PCI_FIXTURE, "");
+    let realtek = db.pci().vendor(0x10ec);
+
+    // 重复出现的厂商与设备以第一次为准
+    assert_eq!(realtek.name().as_deref(), Some("Realtek Semiconductor"));
+    let nic = realtek.device(0x8168);
+    assert_eq!(nic.name().as_deref(), Some("RTL8168 Gigabit Ethernet"));
+    assert_eq!(nic.subsystem(0x1028, 0x0283).as_deref(), Some("Vostro 220"));
+
+    // 只在第二次出现时列出的设备仍可查到
+    assert_eq!(
+        realtek.device(0x8125).name().as_deref(),
+        Some("RTL8125 2.5GbE Controller")
+    );
+}
+
+#[test]
+fn test_fixture_subsystems() {
+    let db = DeviceIdDatabase::from_strings(PCI_FIXTURE, "");
+    let nic = db.pci().vendor(0x10ec).device(0x8168);
+
+    assert_eq!(nic.subsystem(0x1043, 0x8432).as_deref(), Some("P5Q Deluxe"));
+    assert_eq!(nic.subsystem(0x1043, 0x0000), None);
+    // 子系统只在所属设备的下属行中查找
+    assert_eq!(
+        db.pci()
+            .vendor(0x10ec)
+            .device(0x8139)
+            .subsystem(0x1028, 0x0283),
+        None
+    );
+    // 非十六进制的子系统行不会匹配
+    assert_eq!(nic.subsystem(0x0000, 0x0283), None);
+    // ID 个数不同的下属行不会被当作子系统
+    assert_eq!(nic.interface(0x1043).as_deref(), Some("缺少子系统设备 ID"));
+    assert_eq!(nic.interface(0x1028), None);
+}
+
+#[test]
+fn test_fixture_usb_interfaces() {
+    let db = DeviceIdDatabase::from_strings("", USB_FIXTURE);
+    let receiver = db.usb().vendor(0x046d).device(0xc52b);
+
+    assert_eq!(receiver.name().as_deref(), Some("Unifying Receiver"));
+    assert_eq!(
+        receiver.interface(0x00).as_deref(),
+        Some("Keyboard Interface")
+    );
+    assert_eq!(receiver.interface(0x02).as_deref(), Some("HID++ Interface"));
+    assert_eq!(receiver.interface(0x03), None);
+    assert_eq!(receiver.subsystem(0x00, 0x01), None);
+
+    // 接口行不会泄漏到同一厂商的其他设备
+    let mouse = db.usb().vendor(0x046d).device(0xc077);
+    assert_eq!(mouse.name().as_deref(), Some("M105 Optical Mouse"));
+    assert_eq!(mouse.interface(0x00), None);
+
+    // 两条总线的数据相互独立，类别段落不计入
+    assert!(db.pci().vendor(0x046d).name().is_none());
+    assert!(db.usb().vendor(0x0c03).name().is_none());
+}
+
+#[test]
+fn test_fixture_line_endings_and_empty_input() {
+    let db = DeviceIdDatabase::from_strings(
+        "10de  NVIDIA Corporation\r\n\t2504  GA106 [GeForce RTX 3060]\r\n\t\t1462 397d  RTX 3060 VENTUS\r\n",
+        "1d6b  Linux Foundation\n\t0003  3.0 root hub",
+    );
+    let gpu = db.pci().vendor(0x10de).device(0x2504);
+    assert_eq!(
+        db.pci().vendor(0x10de).name().as_deref(),
+        Some("NVIDIA Corporation")
+    );
+    assert_eq!(gpu.name().as_deref(), Some("GA106 [GeForce RTX 3060]"));
+    assert_eq!(
+        gpu.subsystem(0x1462, 0x397d).as_deref(),
+        Some("RTX 3060 VENTUS")
+    );
+    // 末尾没有换行的最后一行
+    assert_eq!(
+        db.usb().vendor(0x1d6b).device(0x0003).name().as_deref(),
+        Some("3.0 root hub")
+    );
+
+    let empty = DeviceIdDatabase::from_strings("", "<html>404 Not Found</html>");
+    assert!(empty.pci().vendor(0x10de).name().is_none());
+    assert!(empty.usb().vendor(0x0000).device(0x0000).name().is_none());
+}
+
+/// 测试用的确定性伪随机数生成器（xorshift64）
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[(self.next() % items.len() as u64) as usize]
+    }
+}
+
+#[test]
+fn test_format_id_properties() {
+    const ALPHABET: &[char] = &[
+        '0', '7', '9', 'a', 'f', 'A', 'F', 'z', 'Z', ':', '-', '_', ' ', '\t', '\n', '京', '东',
+    ];
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..2000 {
+        let len = (rng.next() % 12) as usize;
+        let id: String = (0..len).map(|_| *rng.pick(ALPHABET)).collect();
+        let formatted = format_id(&id);
+
+        // 幂等
+        assert_eq!(format_id(&formatted), formatted, "{:?}", id);
+        // 结果没有首尾空白，也没有小写字母
+        assert_eq!(formatted.trim(), formatted, "{:?}", id);
+        assert!(!formatted.chars().any(char::is_lowercase), "{:?}", id);
+        // 与大小写和首尾空白无关
+        assert_eq!(format_id(&id.to_lowercase()), formatted, "{:?}", id);
+        assert_eq!(format_id(&format!(" \t{}\r\n", id)), formatted, "{:?}", id);
+    }
+}
+
+#[test]
+fn test_parse_id_round_trip() {
+    for id in 0..=u16::MAX {
+        for text in [
+            format!("{:04x}", id),
+            format!("{:04X}", id),
+            format!("0x{:x}", id),
+            format!(" 0X{:X}\t", id),
+        ] {
+            assert_eq!(parse_id(&text), Some(id), "{:?}", text);
+        }
+    }
+    assert_eq!(parse_id("10000"), None);
+    assert_eq!(parse_id(""), None);
+}
+
+#[test]
+fn test_generated_ids_round_trip() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    let mut content = String::new();
+    let mut vendors = HashMap::new();
+    let mut devices = HashMap::new();
+
+    for v in 0..300 {
+        let vendor_id = (rng.next() % 0x400) as u16;
+        let vendor_name = format!("Vendor {}", v);
+        // 大小写混用的十六进制 ID 都应能解析
+        if rng.next().is_multiple_of(2) {
+            content.push_str(&format!("{:04x}  {}\n", vendor_id, vendor_name));
+        } else {
+            content.push_str(&format!("{:04X}  {}\n", vendor_id, vendor_name));
+        }
+        vendors.entry(vendor_id).or_insert(vendor_name);
+
+        for d in 0..(rng.next() % 4) {
+            let device_id = (rng.next() % 0x10) as u16;
+            let device_name = format!("Device {}-{}", v, d);
+            content.push_str(&format!("\t{:04x}  {}\n", device_id, device_name));
+            devices.entry((vendor_id, device_id)).or_insert(device_name);
+        }
+    }
+
+    let db = DeviceIdDatabase::from_strings(&content, "");
+    for (vendor_id, name) in &vendors {
+        assert_eq!(
+            db.pci().vendor(*vendor_id).name().as_ref(),
+            Some(name),
+            "{:04x}",
+            vendor_id
+        );
+    }
+    for ((vendor_id, device_id), name) in &devices {
+        assert_eq!(
+            db.pci()
+                .vendor(*vendor_id)
+                .device(*device_id)
+                .name()
+                .as_ref(),
+            Some(name),
+            "{:04x}:{:04x}",
+            vendor_id,
+            device_id
+        );
+    }
+    for vendor_id in 0x400..0x500 {
+        assert!(db.pci().vendor(vendor_id).name().is_none());
+    }
+}
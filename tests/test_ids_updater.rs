@@ -0,0 +1,110 @@
+#![cfg(feature = "ids-updater")]
+
+use hardware_master::constants::IDS_MIN_VENDORS;
+use hardware_master::iddb::updater::{replace_files, validate_ids};
+use std::path::PathBuf;
+
+/// 生成包含指定厂商数的 .ids 数据
+fn ids_content(vendors: usize) -> String {
+    let mut content = String::from("# 测试数据\n#\tvendor  vendor_name\n\n");
+    for id in 0..vendors {
+        content.push_str(&format!("{:04x}  Vendor {}\n\t0001  Device 1\n", id, id));
+    }
+    // 设备类别段落不计入厂商
+    content.push_str("\nC 00  Unclassified device\n\t00  Non-VGA unclassified device\n");
+    content
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "hardware-master-updater-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_validate_ids() {
+    assert_eq!(
+        validate_ids(ids_content(IDS_MIN_VENDORS).as_bytes()),
+        Ok(IDS_MIN_VENDORS)
+    );
+    assert_eq!(validate_ids(ids_content(2000).as_bytes()), Ok(2000));
+
+    // 下载中断时厂商数不足
+    let content = ids_content(IDS_MIN_VENDORS);
+    let truncated = &content.as_bytes()[..content.len() / 2];
+    let err = validate_ids(truncated).unwrap_err();
+    assert!(err.starts_with("数据不完整"), "{}", err);
+
+    assert!(validate_ids(b"").is_err());
+    assert!(validate_ids(b"<html>404 Not Found</html>").is_err());
+}
+
+#[test]
+fn test_replace_files() {
+    let dir = temp_dir("replace");
+    let pci = dir.join("pci.ids");
+    let usb = dir.join("usb.ids");
+    std::fs::write(&pci, "old pci").unwrap();
+    std::fs::write(dir.join("pci.ids.download"), "new pci").unwrap();
+    std::fs::write(dir.join("usb.ids.download"), "new usb").unwrap();
+
+    // usb.ids 原本不存在
+    replace_files(&[
+        (dir.join("pci.ids.download"), pci.clone()),
+        (dir.join("usb.ids.download"), usb.clone()),
+    ])
+    .unwrap();
+    assert_eq!(std::fs::read_to_string(&pci).unwrap(), "new pci");
+    assert_eq!(std::fs::read_to_string(&usb).unwrap(), "new usb");
+    // 备份与临时文件均已删除
+    let mut names: Vec<String> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["pci.ids", "usb.ids"]);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_replace_files_rolls_back() {
+    let dir = temp_dir("rollback");
+    let pci = dir.join("pci.ids");
+    let usb = dir.join("usb.ids");
+    std::fs::write(&pci, "old pci").unwrap();
+    std::fs::write(&usb, "old usb").unwrap();
+    std::fs::write(dir.join("pci.ids.download"), "new pci").unwrap();
+
+    // 第二个临时文件不存在，替换失败
+    let err = replace_files(&[
+        (dir.join("pci.ids.download"), pci.clone()),
+        (dir.join("usb.ids.download"), usb.clone()),
+    ])
+    .unwrap_err();
+    assert!(err.contains("usb.ids"), "{}", err);
+
+    // 已替换的 pci.ids 恢复为旧文件
+    assert_eq!(std::fs::read_to_string(&pci).unwrap(), "old pci");
+    assert_eq!(std::fs::read_to_string(&usb).unwrap(), "old usb");
+    assert!(!dir.join("pci.ids.backup").exists());
+    assert!(!dir.join("usb.ids.backup").exists());
+    assert!(!dir.join("pci.ids.download").exists());
+
+    // 原本不存在的目标文件在失败后仍不存在
+    let new = dir.join("new.ids");
+    std::fs::write(dir.join("new.ids.download"), "new").unwrap();
+    assert!(replace_files(&[
+        (dir.join("new.ids.download"), new.clone()),
+        (dir.join("missing.download"), dir.join("missing.ids")),
+    ])
+    .is_err());
+    assert!(!new.exists());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
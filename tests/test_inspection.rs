@@ -0,0 +1,93 @@
+use hardware_master::analysis::inspection::{add_serial_facts, CheckRuleSet, CheckStatus};
+use hardware_master::analysis::Facts;
+use hardware_master::history::Snapshot;
+
+fn status_of(items: &[hardware_master::analysis::inspection::CheckItem], id: &str) -> CheckStatus {
+    items
+        .iter()
+        .find(|item| item.id == id)
+        .map(|item| item.status)
+        .expect("缺少检查项")
+}
+
+#[test]
+fn test_builtin_checks_parse() {
+    let checks = CheckRuleSet::builtin();
+    assert!(!checks.checks.is_empty());
+    assert!(checks.checks.iter().all(|c| !c.warn_when.is_empty()));
+}
+
+#[test]
+fn test_inspection_pass_warn_unknown() {
+    let mut facts = Facts::default();
+    facts.set_number("disk.power_on_hours", 12000.0);
+    facts.set_number("disk.uncorrected_errors", 0.0);
+    facts.set_number("battery.cycle_count", 120.0);
+    facts.set_bool("system.is_vm", false);
+
+    let items = CheckRuleSet::builtin().evaluate(&facts);
+    assert_eq!(status_of(&items, "disk-power-on-hours"), CheckStatus::Warn);
+    assert_eq!(
+        status_of(&items, "disk-uncorrected-errors"),
+        CheckStatus::Pass
+    );
+    assert_eq!(status_of(&items, "battery-cycles"), CheckStatus::Pass);
+    assert_eq!(status_of(&items, "virtual-machine"), CheckStatus::Pass);
+    assert_eq!(status_of(&items, "battery-health"), CheckStatus::Unknown);
+
+    let warn = items
+        .iter()
+        .find(|item| item.id == "disk-power-on-hours")
+        .unwrap();
+    assert_eq!(warn.detail, "累计通电 12000 小时");
+    assert!(warn.advice.is_some());
+}
+
+#[test]
+fn test_serial_facts() {
+    let previous = Snapshot {
+        system_serial: Some("ABC123".to_string()),
+        disk_serial: Some("DISK1".to_string()),
+        ..Default::default()
+    };
+    let current = Snapshot {
+        system_serial: Some("ABC123".to_string()),
+        disk_serial: Some("DISK2".to_string()),
+        ..Default::default()
+    };
+
+    let mut facts = Facts::default();
+    add_serial_facts(&mut facts, &current, Some(&previous));
+    assert_eq!(facts.number("serial.system_changed"), Some(0.0));
+    assert_eq!(facts.number("serial.disk_changed"), Some(1.0));
+
+    // 没有历史记录时不做比较
+    let mut facts = Facts::default();
+    add_serial_facts(&mut facts, &current, None);
+    assert_eq!(facts.number("serial.system_changed"), None);
+}
+
+#[test]
+fn test_serial_checks_masked_in_privacy_mode() {
+    let previous = Snapshot {
+        disk_serial: Some("S4EWNX0R123456".to_string()),
+        ..Default::default()
+    };
+    let current = Snapshot {
+        disk_serial: Some("S4EWNX0R654321".to_string()),
+        ..Default::default()
+    };
+    let mut facts = Facts::default();
+    facts.set_text("disk.serial_number", "S4EWNX0R654321");
+    add_serial_facts(&mut facts, &current, Some(&previous));
+    facts.mask_sensitive();
+
+    // 遮盖序列号不影响判断结果
+    let items = CheckRuleSet::builtin().evaluate(&facts);
+    assert_eq!(status_of(&items, "disk-serial-changed"), CheckStatus::Warn);
+    let item = items
+        .iter()
+        .find(|item| item.id == "disk-serial-changed")
+        .unwrap();
+    assert_eq!(item.detail, "序列号 S4**********21");
+}
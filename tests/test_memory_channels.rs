@@ -0,0 +1,232 @@
+use hardware_master::analysis::advice::AdviceRuleSet;
+use hardware_master::analysis::collect_facts;
+use hardware_master::detector::memory::{
+    detect_memory_with, parse_channel, ChannelMode, ErrorCorrection, MemorySlot,
+};
+use hardware_master::detector::source::{DataRow, MockSource};
+use hardware_master::detector::DetectionError;
+use hardware_master::HardwareDetector;
+
+const MEMORY_QUERY: &str = "SELECT * FROM Win32_PhysicalMemory";
+const ARRAY_QUERY: &str = "SELECT * FROM Win32_PhysicalMemoryArray";
+
+fn memory_row(bank_label: &str, device_locator: &str) -> DataRow {
+    DataRow::new()
+        .with("Capacity", "17179869184")
+        .with("SMBIOSMemoryType", 26)
+        .with("ConfiguredClockSpeed", 3200)
+        .with("BankLabel", bank_label)
+        .with("DeviceLocator", device_locator)
+}
+
+fn slots(locators: &[(&str, &str)]) -> Vec<MemorySlot> {
+    locators
+        .iter()
+        .map(|(bank_label, device_locator)| MemorySlot {
+            bank_label: bank_label.to_string(),
+            device_locator: device_locator.to_string(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_channel() {
+    for (locator, expected) in [
+        // Intel 平台
+        ("ChannelA-DIMM0", (0, 'A')),
+        ("ChannelB-DIMM1", (0, 'B')),
+        ("Controller0-ChannelA-DIMM0", (0, 'A')),
+        ("Controller1-ChannelA-DIMM0", (1, 'A')),
+        // AMD 平台的 BankLabel
+        ("P0 CHANNEL A", (0, 'A')),
+        ("P0 CHANNEL B", (0, 'B')),
+        // 主板丝印
+        ("DIMM_A1", (0, 'A')),
+        ("DIMM_B2", (0, 'B')),
+        ("DIMMA2", (0, 'A')),
+        ("DDR4_A1", (0, 'A')),
+        ("DDR5_B1", (0, 'B')),
+        ("A1_DIMM0", (0, 'A')),
+        ("  dimm_c1 ", (0, 'C')),
+    ] {
+        assert_eq!(parse_channel(locator), Some(expected), "{}", locator);
+    }
+
+    for locator in [
+        "",
+        "未知",
+        "BANK 0",
+        "DIMM 1",
+        "DIMM A",
+        "XMM1",
+        "Bottom-Slot 1(left)",
+        "ChannelZ-DIMM0",
+    ] {
+        assert_eq!(parse_channel(locator), None, "{}", locator);
+    }
+}
+
+#[test]
+fn test_infer_channel_mode() {
+    assert_eq!(ChannelMode::infer(&[]), ChannelMode::Unknown);
+    // 只有一根内存时必然是单通道
+    assert_eq!(
+        ChannelMode::infer(&slots(&[("BANK 0", "未知")])),
+        ChannelMode::Single
+    );
+    assert_eq!(
+        ChannelMode::infer(&slots(&[
+            ("BANK 0", "ChannelA-DIMM0"),
+            ("BANK 2", "ChannelB-DIMM0"),
+        ])),
+        ChannelMode::Dual
+    );
+    // 插槽位置无法识别时使用 BankLabel
+    assert_eq!(
+        ChannelMode::infer(&slots(&[
+            ("P0 CHANNEL A", "DIMM 1"),
+            ("P0 CHANNEL B", "DIMM 1"),
+        ])),
+        ChannelMode::Dual
+    );
+    // 两根内存插在同一通道
+    assert_eq!(
+        ChannelMode::infer(&slots(&[("BANK 0", "DIMM_A1"), ("BANK 1", "DIMM_A2")])),
+        ChannelMode::Single
+    );
+    // DDR5 平台按内存控制器区分通道
+    assert_eq!(
+        ChannelMode::infer(&slots(&[
+            ("BANK 0", "Controller0-ChannelA-DIMM0"),
+            ("BANK 0", "Controller1-ChannelA-DIMM0"),
+        ])),
+        ChannelMode::Dual
+    );
+    assert_eq!(
+        ChannelMode::infer(&slots(&[
+            ("", "DIMM_A1"),
+            ("", "DIMM_B1"),
+            ("", "DIMM_C1"),
+            ("", "DIMM_D1"),
+        ])),
+        ChannelMode::Quad
+    );
+    // 任意一根无法识别时不做推断
+    assert_eq!(
+        ChannelMode::infer(&slots(&[("BANK 0", "DIMM_A1"), ("BANK 1", "DIMM 2")])),
+        ChannelMode::Unknown
+    );
+
+    assert_eq!(ChannelMode::from_count(8), ChannelMode::Multi(8));
+    assert_eq!(ChannelMode::Multi(8).count(), Some(8));
+    assert_eq!(ChannelMode::Multi(8).to_string(), "8 通道");
+    assert_eq!(ChannelMode::Dual.to_string(), "双通道");
+}
+
+#[test]
+fn test_memory_channels_and_ecc_from_source() {
+    let source = MockSource::new()
+        .with_wmi(
+            MEMORY_QUERY,
+            vec![
+                memory_row("P0 CHANNEL A", "DIMM 1"),
+                memory_row("P0 CHANNEL B", "DIMM 1"),
+            ],
+        )
+        .with_wmi(
+            ARRAY_QUERY,
+            vec![
+                // 非系统内存的阵列（如闪存）被忽略
+                DataRow::new()
+                    .with("Use", 7)
+                    .with("MemoryErrorCorrection", 3),
+                DataRow::new()
+                    .with("Use", 3)
+                    .with("MemoryErrorCorrection", 6),
+            ],
+        );
+    let memory = detect_memory_with(&source).expect("内存检测失败");
+
+    assert_eq!(memory.slots[0].bank_label, "P0 CHANNEL A");
+    assert_eq!(memory.slots[0].device_locator, "DIMM 1");
+    assert_eq!(memory.channel_mode, ChannelMode::Dual);
+    assert_eq!(memory.error_correction, ErrorCorrection::MultiBitEcc);
+    assert!(memory.error_correction.is_ecc());
+}
+
+#[test]
+fn test_memory_slot_count_from_source() {
+    // 双路服务器每个处理器各有一个系统内存阵列
+    let source = MockSource::new()
+        .with_wmi(MEMORY_QUERY, vec![memory_row("BANK 0", "DIMM_A1")])
+        .with_wmi(
+            ARRAY_QUERY,
+            vec![
+                DataRow::new().with("Use", 3).with("MemoryDevices", 8),
+                DataRow::new().with("Use", 7).with("MemoryDevices", 1),
+                DataRow::new().with("Use", 3).with("MemoryDevices", 8),
+            ],
+        );
+    let memory = detect_memory_with(&source).expect("内存检测失败");
+    assert_eq!(memory.slot_count, Some(16));
+    assert_eq!(memory.empty_slots(), Some(15));
+
+    // 插槽数量为 0 表示 BIOS 未提供
+    let source = MockSource::new()
+        .with_wmi(MEMORY_QUERY, vec![memory_row("BANK 0", "DIMM_A1")])
+        .with_wmi(ARRAY_QUERY, vec![DataRow::new().with("MemoryDevices", 0)]);
+    let memory = detect_memory_with(&source).expect("内存检测失败");
+    assert_eq!(memory.slot_count, None);
+    assert_eq!(memory.empty_slots(), None);
+}
+
+#[test]
+fn test_memory_array_query_error() {
+    // 纠错方式查询失败时内存检测仍然成功
+    let source = MockSource::new()
+        .with_wmi(MEMORY_QUERY, vec![memory_row("BANK 0", "ChannelA-DIMM0")])
+        .with_wmi_error(
+            ARRAY_QUERY,
+            DetectionError::WindowsApiError("WMI 查询失败".to_string()),
+        );
+    let memory = detect_memory_with(&source).expect("内存检测失败");
+
+    assert_eq!(memory.slots.len(), 1);
+    assert_eq!(memory.channel_mode, ChannelMode::Single);
+    assert_eq!(memory.error_correction, ErrorCorrection::Unknown);
+    assert!(!ErrorCorrection::None.is_ecc());
+}
+
+#[test]
+fn test_channel_advice() {
+    let advice_ids = |detector: &HardwareDetector| -> Vec<String> {
+        AdviceRuleSet::builtin()
+            .evaluate(&collect_facts(detector))
+            .into_iter()
+            .map(|advice| advice.rule_id)
+            .collect()
+    };
+
+    let mut detector = HardwareDetector::new();
+    detector.memory_info.slots = slots(&[("BANK 0", "DIMM_A2")]);
+    detector.memory_info.channel_mode = ChannelMode::Single;
+    let advice = AdviceRuleSet::builtin().evaluate(&collect_facts(&detector));
+    let single = advice
+        .iter()
+        .find(|advice| advice.rule_id == "memory-single-channel")
+        .expect("应提示加装内存");
+    assert!(single.message.starts_with("单通道运行，建议加装第二根"));
+
+    detector.memory_info.slots = slots(&[("BANK 0", "DIMM_A1"), ("BANK 1", "DIMM_A2")]);
+    let ids = advice_ids(&detector);
+    assert!(ids.contains(&"memory-same-channel".to_string()));
+    assert!(!ids.contains(&"memory-single-channel".to_string()));
+
+    // 双通道或通道未知时不提示
+    for mode in [ChannelMode::Dual, ChannelMode::Unknown] {
+        detector.memory_info.channel_mode = mode;
+        let ids = advice_ids(&detector);
+        assert!(ids.iter().all(|id| !id.contains("channel")), "{:?}", ids);
+    }
+}
@@ -1,18 +1,17 @@
 use hardware_master::detector::memory::{generate_total_name, MemorySlot};
-
-const BYTES_B: f64 = 1024.0 * 1024.0;
+use hardware_master::utils::size::ByteSize;
 
 #[test]
 fn test_same_memory_slots() {
     let slots = vec![
         MemorySlot {
             name: "英睿达 DDR4 3200MHz 8GB".to_string(),
-            capacity: 8192.0 * BYTES_B,
+            capacity: ByteSize::from_mb(8192),
             ..Default::default()
         },
         MemorySlot {
             name: "英睿达 DDR4 3200MHz 8GB".to_string(),
-            capacity: 8192.0 * BYTES_B,
+            capacity: ByteSize::from_mb(8192),
             ..Default::default()
         },
     ];
@@ -24,17 +23,17 @@ fn test_mixed_memory_slots() {
     let slots = vec![
         MemorySlot {
             name: "金士顿 DDR4 2666MHz 4GB".to_string(),
-            capacity: 4096.0 * BYTES_B,
+            capacity: ByteSize::from_mb(4096),
             ..Default::default()
         },
         MemorySlot {
             name: "三星 DDR4 3200MHz 16GB".to_string(),
-            capacity: 16384.0 * BYTES_B,
+            capacity: ByteSize::from_mb(16384),
             ..Default::default()
         },
         MemorySlot {
             name: "金士顿 DDR4 2666MHz 4GB".to_string(),
-            capacity: 4096.0 * BYTES_B,
+            capacity: ByteSize::from_mb(4096),
             ..Default::default()
         },
     ];
@@ -45,7 +44,7 @@ fn test_mixed_memory_slots() {
 fn test_single_memory_slot() {
     let slots = vec![MemorySlot {
         name: "海盗船 DDR5 4800MHz 32GB".to_string(),
-        capacity: 32768.0 * BYTES_B,
+        capacity: ByteSize::from_mb(32768),
         ..Default::default()
     }];
     assert_eq!("32 GB (海盗船 DDR5 4800MHz 32GB x 1)", generate_total_name(&slots));
@@ -56,3 +55,48 @@ fn test_empty_slots() {
     let slots: Vec<MemorySlot> = vec![];
     assert_eq!("0 B ()", generate_total_name(&slots));
 }
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_memory_info_serde_roundtrip() {
+    use hardware_master::detector::memory::{
+        ChannelMode, ErrorCorrection, MemoryInfo, MemoryProfile,
+    };
+
+    let slot = MemorySlot {
+        name: "海盗船 DDR5 4800MHz 32GB".to_string(),
+        capacity: ByteSize::from_mb(32768),
+        manufacturer: "海盗船".to_string(),
+        memory_type: "DDR5".to_string(),
+        frequency: 4800,
+        rated_frequency: 6000,
+        configured_voltage: Some(1100),
+        min_voltage: Some(1100),
+        max_voltage: Some(1350),
+        profile: MemoryProfile::Available,
+        bank_label: "P0 CHANNEL A".to_string(),
+        device_locator: "DIMM 1".to_string(),
+    };
+    let info = MemoryInfo {
+        name: generate_total_name(std::slice::from_ref(&slot)),
+        total_memory: slot.capacity,
+        slots: vec![slot],
+        error_correction: ErrorCorrection::None,
+        channel_mode: ChannelMode::Single,
+        slot_count: Some(2),
+    };
+
+    let json = serde_json::to_string(&info).unwrap();
+    let restored: MemoryInfo = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.name, info.name);
+    assert_eq!(restored.total_memory, info.total_memory);
+    assert_eq!(restored.slots.len(), 1);
+    assert_eq!(restored.slots[0].frequency, 4800);
+    assert_eq!(restored.slots[0].manufacturer, "海盗船");
+    assert_eq!(restored.slots[0].max_voltage, Some(1350));
+    assert_eq!(restored.slots[0].profile, MemoryProfile::Available);
+    assert_eq!(restored.slots[0].bank_label, "P0 CHANNEL A");
+    assert_eq!(restored.channel_mode, ChannelMode::Single);
+    assert_eq!(restored.error_correction, ErrorCorrection::None);
+    assert_eq!(restored.slot_count, Some(2));
+}
@@ -0,0 +1,159 @@
+use hardware_master::analysis::advice::AdviceRuleSet;
+use hardware_master::analysis::collect_facts;
+use hardware_master::detector::memory::{
+    detect_memory_with, format_voltage, MemoryProfile, MemorySlot,
+};
+use hardware_master::detector::source::{DataRow, MockSource};
+use hardware_master::HardwareDetector;
+
+const MEMORY_QUERY: &str = "SELECT * FROM Win32_PhysicalMemory";
+
+fn slot(
+    memory_type: &str,
+    frequency: u64,
+    rated: u64,
+    configured_mv: u32,
+    max_mv: u32,
+) -> MemorySlot {
+    MemorySlot {
+        memory_type: memory_type.to_string(),
+        frequency,
+        rated_frequency: rated,
+        configured_voltage: (configured_mv > 0).then_some(configured_mv),
+        max_voltage: (max_mv > 0).then_some(max_mv),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_detect_memory_profile() {
+    // 以 XMP/EXPO 电压运行
+    assert_eq!(
+        MemoryProfile::detect(&slot("DDR4", 3600, 3600, 1350, 1350)),
+        MemoryProfile::Enabled
+    );
+    assert_eq!(
+        MemoryProfile::detect(&slot("DDR5", 6000, 6000, 1350, 1350)),
+        MemoryProfile::Enabled
+    );
+    // 电压未知但频率超出 JEDEC 标准
+    assert_eq!(
+        MemoryProfile::detect(&slot("DDR4", 3600, 3600, 0, 0)),
+        MemoryProfile::Enabled
+    );
+
+    // 支持但未开启
+    assert_eq!(
+        MemoryProfile::detect(&slot("DDR4", 2133, 3600, 1200, 1350)),
+        MemoryProfile::Available
+    );
+    assert_eq!(
+        MemoryProfile::detect(&slot("DDR5", 4800, 4800, 1100, 1350)),
+        MemoryProfile::Available
+    );
+
+    // 标准配置：受处理器限制降频运行不算超频配置
+    assert_eq!(
+        MemoryProfile::detect(&slot("DDR4", 2666, 3200, 1200, 1200)),
+        MemoryProfile::Jedec
+    );
+    assert_eq!(
+        MemoryProfile::detect(&slot("DDR3", 1600, 1600, 1350, 1500)),
+        MemoryProfile::Jedec
+    );
+
+    // 电压未知，或内存类型不支持超频配置
+    assert_eq!(
+        MemoryProfile::detect(&slot("DDR4", 3200, 3200, 0, 0)),
+        MemoryProfile::Unknown
+    );
+    assert_eq!(
+        MemoryProfile::detect(&slot("LPDDR5", 6400, 6400, 1050, 1050)),
+        MemoryProfile::Unknown
+    );
+}
+
+#[test]
+fn test_memory_voltage_from_source() {
+    let source = MockSource::new().with_wmi(
+        MEMORY_QUERY,
+        vec![DataRow::new()
+            .with("Capacity", "17179869184")
+            .with("Manufacturer", "G.Skill")
+            .with("SMBIOSMemoryType", 26)
+            .with("ConfiguredClockSpeed", 2133)
+            .with("Speed", 3600)
+            .with("ConfiguredVoltage", 1200)
+            .with("MinVoltage", 1200)
+            .with("MaxVoltage", 1350)],
+    );
+    let memory = detect_memory_with(&source).expect("内存检测失败");
+    let slot = &memory.slots[0];
+
+    assert_eq!(slot.frequency, 2133);
+    assert_eq!(slot.rated_frequency, 3600);
+    assert_eq!(slot.configured_voltage, Some(1200));
+    assert_eq!(slot.min_voltage, Some(1200));
+    assert_eq!(slot.max_voltage, Some(1350));
+    assert_eq!(slot.profile, MemoryProfile::Available);
+    assert_eq!(slot.profile_summary(), "XMP/EXPO 未开启 (1.20 V)");
+}
+
+#[test]
+fn test_memory_voltage_missing() {
+    // 电压为 0 表示 BIOS 未提供，Speed 缺失时标称频率取当前频率
+    let source = MockSource::new().with_wmi(
+        MEMORY_QUERY,
+        vec![DataRow::new()
+            .with("Capacity", "8589934592")
+            .with("SMBIOSMemoryType", 26)
+            .with("ConfiguredClockSpeed", 2666)
+            .with("ConfiguredVoltage", 0)],
+    );
+    let memory = detect_memory_with(&source).expect("内存检测失败");
+    let slot = &memory.slots[0];
+
+    assert_eq!(slot.rated_frequency, 2666);
+    assert_eq!(slot.configured_voltage, None);
+    assert_eq!(slot.max_voltage, None);
+    assert_eq!(slot.profile, MemoryProfile::Unknown);
+    assert_eq!(slot.profile_summary(), "未知");
+}
+
+#[test]
+fn test_format_voltage() {
+    assert_eq!(format_voltage(1200), "1.20 V");
+    assert_eq!(format_voltage(1350), "1.35 V");
+    assert_eq!(format_voltage(1450), "1.45 V");
+}
+
+#[test]
+fn test_profile_disabled_advice() {
+    let mut detector = HardwareDetector::new();
+    detector.memory_info.slots = vec![
+        MemorySlot {
+            profile: MemoryProfile::Available,
+            ..slot("DDR4", 2133, 3600, 1200, 1350)
+        },
+        MemorySlot {
+            profile: MemoryProfile::Available,
+            ..slot("DDR4", 2133, 3600, 1200, 1350)
+        },
+    ];
+    let advice = AdviceRuleSet::builtin().evaluate(&collect_facts(&detector));
+    let advice = advice
+        .iter()
+        .find(|advice| advice.rule_id == "memory-profile-disabled")
+        .expect("应提示开启 XMP/EXPO");
+    assert!(advice.message.contains("2133MHz"), "{}", advice.message);
+
+    for profile in [MemoryProfile::Enabled, MemoryProfile::Unknown] {
+        for slot in detector.memory_info.slots.iter_mut() {
+            slot.profile = profile;
+        }
+        let advice = AdviceRuleSet::builtin().evaluate(&collect_facts(&detector));
+        assert!(advice
+            .iter()
+            .all(|advice| advice.rule_id != "memory-profile-disabled"));
+    }
+}
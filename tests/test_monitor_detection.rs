@@ -1,6 +1,9 @@
+#[cfg(windows)]
 use hardware_master::detector::monitor::detect_display;
+use hardware_master::detector::monitor::{DisplayMode, MonitorInfo};
 
 #[test]
+#[cfg(windows)]
 fn test_monitor_detection() {
     match detect_display() {
         Ok(info) => {
@@ -9,6 +12,7 @@ fn test_monitor_detection() {
             println!("  Manufacturer: {}", info.manufacturer);
             println!("  Size: {:.1} inches", info.size_desc);
             println!("  Manufacture Date: {}", info.manufacture_date);
+            println!("  Refresh Rate: {}", info.refresh_desc());
 
             // 验证至少有显示器数据
             assert!(
@@ -29,3 +33,28 @@ fn test_monitor_detection() {
         }
     }
 }
+
+#[test]
+fn test_refresh_rates_use_native_resolution() {
+    let mode = |width, height, refresh_rate| DisplayMode {
+        width,
+        height,
+        refresh_rate,
+    };
+    let info = MonitorInfo {
+        modes: vec![
+            mode(2560, 1600, 60),
+            mode(2560, 1600, 165),
+            mode(2560, 1600, 120),
+            mode(1920, 1080, 240),
+            mode(2560, 1600, 1),
+        ],
+        current_mode: Some(mode(2560, 1600, 165)),
+        current_refresh_rate: Some(165.004),
+        ..Default::default()
+    };
+
+    assert_eq!(info.refresh_rates(), vec![60, 120, 165]);
+    assert_eq!(info.refresh_desc(), "60/120/165Hz（当前 165.00Hz）");
+    assert_eq!(MonitorInfo::default().refresh_desc(), "未知");
+}
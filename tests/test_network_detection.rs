@@ -0,0 +1,40 @@
+#[cfg(windows)]
+use hardware_master::detector::network::detect_network;
+use hardware_master::detector::network::ConnectionStatus;
+
+#[test]
+#[cfg(windows)]
+fn test_network_detection() {
+    match detect_network() {
+        Ok(info) => {
+            println!("Network Info:");
+            for adapter in info.adapters.iter() {
+                println!("  Adapter: {}", adapter.summary());
+                println!("    MAC: {}", adapter.mac_address);
+                println!("    Driver: {}", adapter.driver_version);
+                println!("    PnP ID: {}", adapter.pnp_device_id);
+            }
+            for wifi in info.wifi_adapters.iter() {
+                println!("  Wi-Fi: {}", wifi.summary());
+            }
+
+            // 已连接的网卡应有 MAC 地址
+            for adapter in info.adapters.iter() {
+                if adapter.status == ConnectionStatus::Connected {
+                    assert_ne!(adapter.mac_address, "未知",
+                               "Connected adapter without MAC: {}", adapter.name);
+                }
+            }
+        }
+        Err(e) => {
+            panic!("Network detection failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_connection_status_from_code() {
+    assert_eq!(ConnectionStatus::from(2), ConnectionStatus::Connected);
+    assert_eq!(ConnectionStatus::from(7), ConnectionStatus::MediaDisconnected);
+    assert_eq!(ConnectionStatus::from(99), ConnectionStatus::Unknown);
+}
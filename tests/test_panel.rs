@@ -0,0 +1,42 @@
+use hardware_master::iddb::panel::{BacklightType, PanelDatabase};
+
+const PANELS: &str = r#"
+[[panels]]
+id = "boe0900"
+backlight = "wled"
+
+[[panels]]
+id = "SDC4161"
+backlight = "oled"
+"#;
+
+#[test]
+fn test_panel_lookup() {
+    let db = PanelDatabase::from_toml(PANELS).expect("面板数据解析失败");
+
+    let panel = db.lookup("BOE", "0900").expect("应找到面板");
+    assert_eq!(panel.backlight, BacklightType::Wled);
+
+    // 产品代码不足 4 位时补 0，大小写不敏感
+    let panel = db.lookup("sdc", "4161").expect("应找到面板");
+    assert_eq!(panel.backlight, BacklightType::Oled);
+
+    assert!(db.lookup("AUO", "1234").is_none());
+}
+
+#[test]
+fn test_panel_merge_overrides() {
+    let mut db = PanelDatabase::from_toml(PANELS).expect("面板数据解析失败");
+    db.merge_toml("[[panels]]\nid = \"BOE0900\"\nbacklight = \"miniled\"\n")
+        .expect("面板数据解析失败");
+    let panel = db.lookup("BOE", "900").expect("应找到面板");
+    assert_eq!(panel.backlight, BacklightType::MiniLed);
+}
+
+#[test]
+fn test_backlight_guess_from_year() {
+    assert_eq!(BacklightType::guess_from_year(0), BacklightType::Unknown);
+    assert_eq!(BacklightType::guess_from_year(2008), BacklightType::Ccfl);
+    // 2010 年以后的面板无法仅凭年份区分 WLED、Mini LED 与 OLED
+    assert_eq!(BacklightType::guess_from_year(2021), BacklightType::Unknown);
+}
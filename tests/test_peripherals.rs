@@ -0,0 +1,122 @@
+#[cfg(windows)]
+use hardware_master::detector::peripherals::detect_peripherals;
+use hardware_master::detector::peripherals::{
+    input_connection, parse_usb_ids, InputDeviceKind, PeripheralConnection, Printer,
+};
+
+#[test]
+fn test_connection_from_port_name() {
+    for (port, expected) in [
+        ("USB002", PeripheralConnection::Usb),
+        ("DOT4_001", PeripheralConnection::Usb),
+        ("10.0.0.31", PeripheralConnection::Network),
+        ("IP_192.168.1.20", PeripheralConnection::Network),
+        (r"\\print-server\hp-3f", PeripheralConnection::Network),
+        ("COM3:", PeripheralConnection::Serial),
+        ("FILE:", PeripheralConnection::Virtual),
+        ("SHRFAX:", PeripheralConnection::Virtual),
+        ("1.2.3", PeripheralConnection::Other),
+        ("TS001", PeripheralConnection::Other),
+    ] {
+        assert_eq!(
+            PeripheralConnection::from_port_name(port),
+            expected,
+            "{}",
+            port
+        );
+    }
+}
+
+#[test]
+fn test_connection_from_device_id() {
+    for (device_id, expected) in [
+        (
+            r"BTHENUM\{00001101-0000-1000-8000-00805F9B34FB}",
+            PeripheralConnection::Bluetooth,
+        ),
+        (
+            r"UMB\UMB\1&841921D&0&WSDPRINT",
+            PeripheralConnection::Network,
+        ),
+        (r"SWD\PRINTENUM\{A1B2}", PeripheralConnection::Virtual),
+        (r"PCI\VEN_8086&DEV_1234", PeripheralConnection::Other),
+    ] {
+        assert_eq!(
+            PeripheralConnection::from_device_id(device_id),
+            expected,
+            "{}",
+            device_id
+        );
+    }
+}
+
+#[test]
+fn test_printer_status_desc() {
+    let printer = Printer {
+        name: "Microsoft Print to PDF".to_string(),
+        driver: "Microsoft Print To PDF".to_string(),
+        port: "PORTPROMPT:".to_string(),
+        connection: PeripheralConnection::Virtual,
+        is_default: false,
+        shared: false,
+        offline: false,
+    };
+    assert_eq!(printer.status_desc(), "");
+    let shared = Printer {
+        is_default: true,
+        shared: true,
+        ..printer
+    };
+    assert_eq!(shared.status_desc(), "默认, 已共享");
+}
+
+#[test]
+fn test_input_device_kind() {
+    assert_eq!(
+        InputDeviceKind::from_raw_input(0, 0, 0),
+        Some(InputDeviceKind::Mouse)
+    );
+    // 摇杆与多轴控制器
+    assert_eq!(
+        InputDeviceKind::from_raw_input(2, 0x01, 0x04),
+        Some(InputDeviceKind::GameController)
+    );
+    assert_eq!(
+        InputDeviceKind::from_raw_input(2, 0x01, 0x08),
+        Some(InputDeviceKind::GameController)
+    );
+    // 触摸屏不是触控板
+    assert_eq!(InputDeviceKind::from_raw_input(2, 0x0D, 0x04), None);
+}
+
+#[test]
+fn test_input_device_ids_and_connection() {
+    // 低功耗蓝牙，ID 来源为 USB-IF
+    let ble = r"\\?\HID#{00001812-0000-1000-8000-00805f9b34fb}_Dev_VID&02046d_PID&b023_REV&0007#a";
+    assert_eq!(parse_usb_ids(ble), Some((0x046D, 0xB023)));
+    assert_eq!(input_connection(ble), PeripheralConnection::Bluetooth);
+    let ps2 = r"\\?\ACPI#PNP0303#4&1d401fb5&0#{884b96c3-56ef-11d1-bc8c-00a0c91405dd}";
+    assert_eq!(input_connection(ps2), PeripheralConnection::Internal);
+    assert_eq!(parse_usb_ids(r"\\?\HID#VID_046D&PID_ZZZZ#1"), None);
+}
+
+#[test]
+#[cfg(windows)]
+fn test_peripheral_detection() {
+    let info = detect_peripherals().expect("外设检测失败");
+    for printer in info.printers.iter() {
+        println!(
+            "打印机: {} ({}, {})",
+            printer.name, printer.connection, printer.port
+        );
+    }
+    for device in info.input_devices.iter() {
+        println!(
+            "输入设备: {} {} ({})",
+            device.kind, device.name, device.connection
+        );
+    }
+    for device in info.imaging_devices.iter() {
+        println!("图像设备: {} ({})", device.name, device.connection);
+    }
+}
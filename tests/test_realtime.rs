@@ -0,0 +1,74 @@
+use hardware_master::detector::processes::{top_by_cpu, top_by_memory, ProcessUsage};
+use hardware_master::realtime::cpu_load::{effective_frequency, max_zone_celsius, CpuLoad};
+use hardware_master::realtime::disk_io::{parse_disk_number, DiskIoStats};
+
+#[test]
+fn test_disk_io_busy() {
+    let idle = DiskIoStats {
+        instance: "0 C:".to_string(),
+        disk_number: parse_disk_number("0 C:"),
+        busy_percent: 5.0,
+        ..Default::default()
+    };
+    assert_eq!(idle.disk_number, Some(0));
+    assert!(!idle.is_busy());
+
+    let saturated = DiskIoStats {
+        busy_percent: 100.0,
+        ..idle.clone()
+    };
+    assert!(saturated.is_busy());
+
+    let queued = DiskIoStats {
+        queue_length: 4.0,
+        ..idle
+    };
+    assert!(queued.is_busy());
+}
+
+#[test]
+fn test_cpu_load_summary() {
+    let temperature = max_zone_celsius(&[0.0, 318.15, 341.15]);
+    assert_eq!(temperature, Some(68.0));
+
+    let load = CpuLoad {
+        usage_percent: 99.6,
+        temperature,
+        ..Default::default()
+    };
+    assert_eq!(load.summary(), "CPU 100% · 68°C");
+
+    // 性能百分比超过 100% 时实时频率高于标称频率（睿频）
+    let frequency_mhz = effective_frequency(2900.0, 151.7);
+    assert_eq!(frequency_mhz, Some(4399.0));
+    let load = CpuLoad {
+        frequency_mhz,
+        ..load
+    };
+    assert_eq!(load.summary(), "CPU 100% · 4.40 GHz · 68°C");
+}
+
+#[test]
+fn test_top_processes() {
+    let process = |pid: u32, name: &str, cpu_percent: f64, working_set: u64| ProcessUsage {
+        pid,
+        name: name.to_string(),
+        cpu_percent,
+        working_set,
+    };
+    let processes = vec![
+        process(4, "System", 0.5, 1 << 20),
+        process(1200, "chrome.exe", 12.0, 800 << 20),
+        process(2300, "game.exe", 45.0, 3 << 30),
+        process(3400, "explorer.exe", 1.0, 120 << 20),
+    ];
+
+    let top_cpu = top_by_cpu(&processes, 2);
+    assert_eq!(top_cpu.len(), 2);
+    assert_eq!(top_cpu[0].name, "game.exe");
+    assert_eq!(top_cpu[1].name, "chrome.exe");
+
+    let top_memory = top_by_memory(&processes, 3);
+    let names: Vec<&str> = top_memory.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["game.exe", "chrome.exe", "explorer.exe"]);
+}
@@ -0,0 +1,306 @@
+use chrono::NaiveDate;
+use hardware_master::asset::AssetRecord;
+use hardware_master::detector::battery::Battery;
+use hardware_master::detector::disk::DiskBus;
+use hardware_master::detector::gpu::{Gpu, GpuOutput, GpuType, HybridGraphics};
+use hardware_master::detector::memory::{MemoryProfile, MemorySlot};
+use hardware_master::detector::monitor::{ActiveDisplay, DisplayMode, HdrStatus};
+use hardware_master::detector::optical::OpticalDrive;
+use hardware_master::detector::power::{PowerLine, PowerStatus};
+use hardware_master::report::template::CopyTemplate;
+use hardware_master::report::{asset_section, build_sections, csv, html, markdown, xml};
+use hardware_master::utils::size::ByteSize;
+use hardware_master::HardwareDetector;
+
+#[test]
+fn test_html_report_contains_all_sections() {
+    let detector = HardwareDetector::new();
+    let report = html::generate(&detector, None);
+
+    assert!(report.starts_with("<!DOCTYPE html>"));
+    for section in build_sections(&detector) {
+        assert!(
+            report.contains(&format!("<h2>{}</h2>", section.title)),
+            "报告缺少章节: {}",
+            section.title
+        );
+    }
+}
+
+#[test]
+fn test_html_report_escapes_values() {
+    let mut detector = HardwareDetector::new();
+    detector.cpu_info.name = "<script>alert('x')</script>".to_string();
+    let report = html::generate(&detector, None);
+
+    assert!(!report.contains("<script>"));
+    assert!(report.contains("&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;"));
+}
+
+#[test]
+fn test_html_escape() {
+    assert_eq!(html::escape("a & b"), "a &amp; b");
+    assert_eq!(html::escape("\"quoted\""), "&quot;quoted&quot;");
+    assert_eq!(html::escape("普通文本"), "普通文本");
+}
+
+#[test]
+fn test_csv_memory_slots() {
+    let mut detector = HardwareDetector::new();
+    detector.memory_info.slots = vec![MemorySlot {
+        name: "Kingston, 16GB".to_string(),
+        capacity: ByteSize::from_gb(16),
+        manufacturer: "Kingston".to_string(),
+        memory_type: "DDR4".to_string(),
+        frequency: 3200,
+        configured_voltage: Some(1200),
+        profile: MemoryProfile::Jedec,
+        device_locator: "DIMM_A2".to_string(),
+        ..Default::default()
+    }];
+
+    let table = csv::memory_slots(&detector);
+    assert_eq!(table.file_name(), "内存插槽.csv");
+    let text = table.to_csv();
+    assert!(text.starts_with('\u{feff}'));
+    let lines: Vec<&str> = text.trim_start_matches('\u{feff}').split("\r\n").collect();
+    assert_eq!(
+        lines[0],
+        "插槽,位置,名称,容量 (GB),类型,频率 (MHz),制造商,电压 (V),超频配置"
+    );
+    assert_eq!(
+        lines[1],
+        "1,DIMM_A2,\"Kingston, 16GB\",16.0,DDR4,3200,Kingston,1.20,JEDEC 标准"
+    );
+    assert_eq!(lines[2], "");
+}
+
+#[test]
+fn test_csv_empty_tables_have_headers() {
+    let detector = HardwareDetector::new();
+    for table in [csv::physical_disks(&detector), csv::volumes(&detector)] {
+        assert!(table.rows.is_empty());
+        assert_eq!(table.to_csv().lines().count(), 1);
+    }
+}
+
+#[test]
+fn test_markdown_report_contains_all_sections() {
+    let mut detector = HardwareDetector::new();
+    detector.cpu_info.name = "Intel | Core".to_string();
+    let report = markdown::generate(&detector, None);
+
+    for section in build_sections(&detector) {
+        assert!(
+            report.contains(&format!("### {}\n", section.title)),
+            "报告缺少章节: {}",
+            section.title
+        );
+    }
+    assert!(report.contains("| 名称 | Intel \\| Core |"));
+}
+
+#[test]
+fn test_xml_report_layout() {
+    let mut detector = HardwareDetector::new();
+    detector.cpu_info.name = "Intel]]>Core".to_string();
+    let report = xml::generate(&detector, None);
+
+    assert!(report.starts_with("<?xml"));
+    assert!(report.contains("<MsInfo>") && report.trim_end().ends_with("</MsInfo>"));
+    assert!(report.contains("<Category name=\"系统摘要\">"));
+    assert!(report.contains("<Category name=\"处理器\">"));
+    assert!(report.contains("<Item><![CDATA[名称]]></Item>"));
+    assert!(report.contains("<![CDATA[Intel]]]]><![CDATA[>Core]]>"));
+    assert_eq!(
+        report.matches("<Category").count(),
+        report.matches("</Category>").count()
+    );
+}
+
+fn asset() -> AssetRecord {
+    let mut asset = AssetRecord {
+        warranty_end: "2026-06-17".to_string(),
+        owner: "张三".to_string(),
+        ..Default::default()
+    };
+    asset
+        .custom_fields
+        .insert("资产编号".to_string(), "IT-0042".to_string());
+    asset
+}
+
+#[test]
+fn test_asset_section_rows() {
+    let today = NaiveDate::from_ymd_opt(2026, 6, 7).unwrap();
+    let section = asset_section(&asset(), today);
+    assert_eq!(section.title, "资产");
+    // 未填写的字段不输出
+    assert_eq!(
+        section.rows,
+        vec![
+            ("保修截止".to_string(), "2026-06-17".to_string()),
+            ("保修状态".to_string(), "保修中（剩余 10 天）".to_string()),
+            ("使用人".to_string(), "张三".to_string()),
+            ("资产编号".to_string(), "IT-0042".to_string()),
+        ]
+    );
+    assert!(asset_section(&AssetRecord::default(), today)
+        .rows
+        .is_empty());
+}
+
+#[test]
+fn test_reports_include_asset() {
+    let detector = HardwareDetector::new();
+    let asset = asset();
+
+    let report = html::generate(&detector, Some(&asset));
+    assert!(report.contains("<h2>资产</h2>") && report.contains("IT-0042"));
+    assert!(!html::generate(&detector, None).contains("<h2>资产</h2>"));
+
+    let report = markdown::generate(&detector, Some(&asset));
+    assert!(report.contains("### 资产\n") && report.contains("| 使用人 | 张三 |"));
+
+    let report = xml::generate(&detector, Some(&asset));
+    assert!(report.contains("<Category name=\"资产\">"));
+    assert!(report.contains("<![CDATA[IT-0042]]>"));
+}
+
+#[test]
+fn test_copy_templates() {
+    let mut detector = HardwareDetector::new();
+    detector.cpu_info.name = "AMD Ryzen 5 7600".to_string();
+
+    for template in CopyTemplate::ALL {
+        let text = template.render(&detector);
+        assert!(
+            text.contains("AMD Ryzen 5 7600"),
+            "{} 模板缺少处理器",
+            template
+        );
+    }
+    assert!(CopyTemplate::Concise
+        .render(&detector)
+        .contains("处理器: AMD Ryzen 5 7600\n"));
+    assert!(CopyTemplate::BbCode
+        .render(&detector)
+        .contains("[size=4][b]处理器[/b][/size]"));
+    assert_eq!(
+        CopyTemplate::Markdown.render(&detector),
+        markdown::generate(&detector, None)
+    );
+}
+
+#[test]
+fn test_battery_section_includes_power_and_details() {
+    let mut detector = HardwareDetector::new();
+    let rows = |detector: &HardwareDetector| {
+        build_sections(detector)
+            .into_iter()
+            .find(|section| section.title == "电池")
+            .expect("缺少电池章节")
+            .rows
+    };
+
+    // 未检测到电源信息时不输出电源行
+    assert!(rows(&detector).is_empty());
+
+    detector.battery_info.batteries.push(Battery {
+        temperature: Some(31.5),
+        manufacture_date: Some("2023-05-12".to_string()),
+        ..Default::default()
+    });
+    detector.battery_info.power = PowerStatus {
+        line: PowerLine::Battery,
+        plan: "节能".to_string(),
+        charger_watts: None,
+    };
+    let rows = rows(&detector);
+    let value = |key: &str| rows.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+    assert_eq!(value("温度"), Some("31.5 °C"));
+    assert_eq!(value("生产日期"), Some("2023-05-12"));
+    assert_eq!(value("电源"), Some("电池供电, 电源计划: 节能"));
+}
+
+#[test]
+fn test_graphics_section_lists_outputs_and_hybrid() {
+    let mut detector = HardwareDetector::new();
+    detector.gpu_info.gpus.push(Gpu {
+        description: "NVIDIA GeForce RTX 4060 Laptop GPU".to_string(),
+        gpu_type: GpuType::DiscreteGpu,
+        outputs: vec![GpuOutput {
+            device_name: r"\\.\DISPLAY2".to_string(),
+            monitor: "DELL U2720Q".to_string(),
+            connection: "HDMI".to_string(),
+            width: 3840,
+            height: 2160,
+        }],
+        ..Default::default()
+    });
+    detector.gpu_info.hybrid = Some(HybridGraphics {
+        display_gpu: GpuType::IntegratedGpu,
+        render_gpu: GpuType::DiscreteGpu,
+    });
+    let graphics = build_sections(&detector)
+        .into_iter()
+        .find(|section| section.title == "显卡")
+        .expect("缺少显卡章节");
+    assert!(graphics.rows.contains(&(
+        "显示输出".to_string(),
+        "DELL U2720Q (HDMI, 3840x2160)".to_string()
+    )));
+}
+
+#[test]
+fn test_storage_section_lists_optical_drives() {
+    let mut detector = HardwareDetector::new();
+    detector.disk_info.optical_drives.push(OpticalDrive {
+        model: "ASUS SDRW-08D2S-U".to_string(),
+        drive_letter: Some("F:".to_string()),
+        media_type: "DVD Writer".to_string(),
+        bus: DiskBus::Usb,
+        media_loaded: true,
+    });
+    let storage = build_sections(&detector)
+        .into_iter()
+        .find(|section| section.title == "硬盘")
+        .expect("缺少硬盘章节");
+    assert!(storage.rows.contains(&(
+        "光驱".to_string(),
+        "F: ASUS SDRW-08D2S-U (USB DVD 刻录机, 已放入光盘)".to_string()
+    )));
+}
+
+#[test]
+fn test_monitor_section_lists_active_displays() {
+    let mut detector = HardwareDetector::new();
+    detector.monitor_info.displays.push(ActiveDisplay {
+        name: "DELL U2720Q".to_string(),
+        connection: "DisplayPort".to_string(),
+        device_name: r"\\.\DISPLAY2".to_string(),
+        mode: Some(DisplayMode {
+            width: 3840,
+            height: 2160,
+            refresh_rate: 59,
+        }),
+        refresh_rate: Some(59.951),
+        bits_per_channel: Some(10),
+        hdr: Some(HdrStatus::On),
+        position: Some((0, 0)),
+        primary: true,
+        scale_percent: Some(150),
+    });
+    let monitor = build_sections(&detector)
+        .into_iter()
+        .find(|section| section.title == "显示器")
+        .expect("缺少显示器章节");
+    assert!(monitor.rows.contains(&(
+        "当前模式".to_string(),
+        "DELL U2720Q (DisplayPort): 3840x2160 @ 59.95Hz, 10 位色深, HDR 已开启".to_string()
+    )));
+    assert!(monitor.rows.contains(&(
+        "排列".to_string(),
+        "DELL U2720Q: 主显示器, 缩放 150%".to_string()
+    )));
+}
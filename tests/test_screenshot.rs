@@ -0,0 +1,26 @@
+use eframe::egui::{pos2, Color32, ColorImage, Rect};
+use hardware_master::ui::screenshot::encode_region_png;
+
+#[test]
+fn test_encode_region_png_crops_and_scales() {
+    // 左半部分红色、右半部分蓝色的 20x10 截图
+    let mut image = ColorImage::new([20, 10], Color32::BLUE);
+    for y in 0..10 {
+        for x in 0..10 {
+            image.pixels[y * 20 + x] = Color32::RED;
+        }
+    }
+
+    // 缩放比例为 2 时，逻辑区域 (0,0)-(5,5) 对应左半部分的 10x10 像素
+    let region = Rect::from_min_max(pos2(0.0, 0.0), pos2(5.0, 5.0));
+    let png = encode_region_png(&image, region, 2.0).unwrap();
+    let decoded = image::load_from_memory(&png).unwrap().to_rgba8();
+    assert_eq!(decoded.dimensions(), (10, 10));
+    assert!(decoded.pixels().all(|pixel| pixel.0 == [255, 0, 0, 255]));
+
+    // 超出截图的区域被截掉
+    let region = Rect::from_min_max(pos2(-10.0, 2.0), pos2(100.0, 100.0));
+    let png = encode_region_png(&image, region, 1.0).unwrap();
+    let decoded = image::load_from_memory(&png).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (20, 8));
+}
@@ -0,0 +1,75 @@
+use hardware_master::detector::battery::Battery;
+use hardware_master::detector::network::NetworkAdapter;
+use hardware_master::ui::search::{fuzzy_score, SearchIndex, BATTERY_DETAILS, MOTHERBOARD_DETAILS};
+use hardware_master::HardwareDetector;
+
+fn detector() -> HardwareDetector {
+    let mut detector = HardwareDetector::new();
+    detector.motherboard_info.serial_number = "PF2ABCDE".to_string();
+    detector.motherboard_info.bios_vendor = "American Megatrends".to_string();
+    detector.network_info.adapters.push(NetworkAdapter {
+        name: "Intel(R) Ethernet Connection I219-V".to_string(),
+        mac_address: "00:1A:2B:3C:4D:5E".to_string(),
+        ..Default::default()
+    });
+    detector.battery_info.batteries.push(Battery {
+        name: "BAT0".to_string(),
+        serial_number: "BAT123456".to_string(),
+        ..Default::default()
+    });
+    detector
+}
+
+#[test]
+fn test_fuzzy_score_ranking() {
+    assert_eq!(fuzzy_score("BIOS", "bios"), Some(300));
+    assert!(fuzzy_score("bios", "BIOS 日期") > fuzzy_score("bios", "主板 BIOS"));
+    assert!(fuzzy_score("bios", "主板 BIOS") > fuzzy_score("bs", "BIOS"));
+    assert_eq!(fuzzy_score("", "BIOS"), None);
+    assert_eq!(fuzzy_score("xyz", "BIOS"), None);
+}
+
+#[test]
+fn test_search_serial_numbers() {
+    let index = SearchIndex::build(&detector(), false);
+    // 越靠前命中得分越高
+    let hits = index.search("序列号", 10);
+    let keys: Vec<(&str, &str)> = hits
+        .iter()
+        .map(|hit| (hit.section.as_str(), hit.key.as_str()))
+        .collect();
+    assert_eq!(
+        keys,
+        [
+            (BATTERY_DETAILS, "序列号"),
+            (MOTHERBOARD_DETAILS, "主板序列号"),
+            (MOTHERBOARD_DETAILS, "机箱序列号"),
+            (MOTHERBOARD_DETAILS, "BIOS 序列号"),
+        ]
+    );
+    // 未显示敏感信息时以遮盖后的值参与搜索
+    let board = hits.iter().find(|hit| hit.key == "主板序列号").unwrap();
+    assert_eq!(board.value, "PF****DE");
+    assert!(index.search("PF2ABCDE", 10).is_empty());
+
+    let index = SearchIndex::build(&detector(), true);
+    let hits = index.search("PF2ABCDE", 10);
+    assert_eq!(hits[0].key, "主板序列号");
+}
+
+#[test]
+fn test_search_ranks_key_matches_first() {
+    let index = SearchIndex::build(&detector(), false);
+    let hits = index.search("mac", 5);
+    assert_eq!(hits[0].section, "网卡");
+    assert_eq!(hits[0].key, "MAC 地址");
+    assert_eq!(hits[0].value, "00:1A:2B:3C:4D:5E");
+
+    // 多个词须同时命中
+    let hits = index.search("网卡 mac", 5);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].target().key, "MAC 地址");
+
+    assert!(index.search("   ", 5).is_empty());
+    assert_eq!(index.search("bios", 1).len(), 1);
+}
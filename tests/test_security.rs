@@ -0,0 +1,59 @@
+use hardware_master::detector::security::{
+    BitLockerStatus, SecureBootState, SecurityInfo, TpmInfo, VolumeEncryption,
+};
+
+#[test]
+fn test_bitlocker_status_from_wmi() {
+    assert_eq!(
+        BitLockerStatus::from_wmi(Some(0), Some(0)),
+        BitLockerStatus::Off
+    );
+    assert_eq!(
+        BitLockerStatus::from_wmi(Some(1), Some(1)),
+        BitLockerStatus::On
+    );
+    assert_eq!(
+        BitLockerStatus::from_wmi(Some(1), Some(0)),
+        BitLockerStatus::Suspended
+    );
+    assert_eq!(
+        BitLockerStatus::from_wmi(Some(4), None),
+        BitLockerStatus::Encrypting
+    );
+    assert_eq!(
+        BitLockerStatus::from_wmi(Some(3), Some(0)),
+        BitLockerStatus::Decrypting
+    );
+    assert_eq!(
+        BitLockerStatus::from_wmi(None, None),
+        BitLockerStatus::Unknown
+    );
+}
+
+#[test]
+fn test_security_summary() {
+    let mut info = SecurityInfo::default();
+    assert_eq!(info.tpm_summary(), "未检测到");
+    assert_eq!(info.bitlocker_summary(), "需要管理员权限");
+    assert_eq!(info.secure_boot.to_string(), "不支持");
+
+    info.tpm = Some(TpmInfo {
+        version: "2.0".to_string(),
+        manufacturer: "INTC".to_string(),
+        ..Default::default()
+    });
+    info.secure_boot = SecureBootState::Enabled;
+    info.volumes = Some(vec![
+        VolumeEncryption {
+            drive_letter: "C:".to_string(),
+            status: BitLockerStatus::On,
+        },
+        VolumeEncryption {
+            drive_letter: "D:".to_string(),
+            status: BitLockerStatus::Off,
+        },
+    ]);
+    assert_eq!(info.tpm_summary(), "TPM 2.0 (INTC)");
+    assert_eq!(info.tpm.as_ref().unwrap().major_version(), Some(2));
+    assert_eq!(info.bitlocker_summary(), "C: 已加密, D: 未加密");
+}
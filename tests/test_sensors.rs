@@ -0,0 +1,36 @@
+#[cfg(windows)]
+use hardware_master::detector::sensors::read_sensors;
+use hardware_master::detector::sensors::{processor_voltage, scale_reading, SensorKind};
+
+#[test]
+fn test_processor_voltage() {
+    // 位 7 置位：1.1 V
+    assert_eq!(processor_voltage(0x8B), Some(1.1));
+    // 位 7 未置位时为支持的电压档位（位 0 为 5 V，位 1 为 3.3 V）
+    assert_eq!(processor_voltage(0x03), None);
+    assert_eq!(processor_voltage(0), None);
+}
+
+#[test]
+fn test_numeric_sensor_units() {
+    assert_eq!(SensorKind::from_base_units(0), None);
+    assert_eq!(SensorKind::Fan.unit(), "RPM");
+    assert!((scale_reading(125, -2) - 1.25).abs() < 1e-9);
+    assert_eq!(scale_reading(3, 3), 3000.0);
+}
+
+#[test]
+#[cfg(windows)]
+fn test_read_sensors() {
+    // 多数电脑只能读取到部分传感器，这里只检查读数合理
+    for reading in read_sensors() {
+        println!(
+            "{} {}: {} ({})",
+            reading.kind,
+            reading.name,
+            reading.value_desc(),
+            reading.source
+        );
+        assert!(reading.value.is_finite());
+    }
+}
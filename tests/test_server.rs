@@ -0,0 +1,241 @@
+#[cfg(feature = "serialize")]
+use hardware_master::asset::AssetRecord;
+use hardware_master::constants::{SERVE_MAX_HEADERS, SERVE_MAX_LINE_BYTES};
+use hardware_master::detector::battery::Battery;
+use hardware_master::server::{
+    is_local_host, metrics, read_request, route, LocalServer, ServeOptions,
+};
+use hardware_master::HardwareDetector;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_serve_options_from_args() {
+    let options = ServeOptions::from_args(args(&["app", "--serve", "[::1]:8080"]))
+        .unwrap()
+        .unwrap();
+    assert_eq!(options.addr.port(), 8080);
+
+    assert_eq!(
+        ServeOptions::from_args(args(&["app", "--daemon"])),
+        Ok(None)
+    );
+    assert!(ServeOptions::from_args(args(&["app", "--serve"])).is_err());
+    assert!(ServeOptions::from_args(args(&["app", "--serve", "localhost"])).is_err());
+    assert!(ServeOptions::from_args(args(&["app", "--serve", "192.168.1.2:80"])).is_err());
+}
+
+#[test]
+fn test_metrics_text() {
+    let mut detector = HardwareDetector::new();
+    detector.cpu_info.name = "AMD Ryzen 5 7600".to_string();
+    detector.cpu_info.physical_cores = 6;
+    detector.disk_info.temperature = Some(41.0);
+    detector.battery_info.batteries.push(Battery {
+        name: "BAT0".to_string(),
+        health: 92.5,
+        ..Default::default()
+    });
+
+    let text = metrics(&detector);
+    assert!(text.contains("# TYPE hardware_master_cpu_cores gauge\n"));
+    assert!(text.contains("cpu=\"AMD Ryzen 5 7600\""));
+    assert!(text.contains("hardware_master_cpu_cores{kind=\"physical\"} 6\n"));
+    assert!(text.contains("hardware_master_disk_temperature_celsius 41\n"));
+    assert!(text.contains("hardware_master_battery_health_percent{name=\"BAT0\"} 92.5\n"));
+    // 未检测到的数值不输出
+    assert!(!text.contains("hardware_master_disk_wear_percent"));
+}
+
+#[test]
+fn test_route() {
+    let detector = HardwareDetector::new();
+    assert_eq!(
+        route("GET", "/metrics?x=1", &detector, false, None).status,
+        200
+    );
+    assert_eq!(route("GET", "/missing", &detector, false, None).status, 404);
+    assert_eq!(
+        route("POST", "/metrics", &detector, false, None).status,
+        405
+    );
+}
+
+#[test]
+#[cfg(feature = "serialize")]
+fn test_route_report() {
+    let detector = HardwareDetector::new();
+    let response = route("GET", "/report.json", &detector, false, None);
+    assert_eq!(response.status, 200);
+    let json: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+    assert!(json.get("cpu_info").is_some());
+}
+
+#[test]
+#[cfg(feature = "serialize")]
+fn test_route_report_with_asset() {
+    let detector = HardwareDetector::new();
+    let asset = AssetRecord {
+        owner: "张三".to_string(),
+        ..Default::default()
+    };
+    let response = route("GET", "/report.json", &detector, false, Some(&asset));
+    let json: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(json["asset"]["owner"], "张三");
+    assert!(json.get("cpu_info").is_some());
+
+    // 未记录资产信息时不输出该字段
+    let response = route("GET", "/report.json", &detector, false, None);
+    let json: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+    assert!(json.get("asset").is_none());
+}
+
+#[test]
+#[cfg(feature = "serialize")]
+fn test_route_report_privacy_mode() {
+    let mut detector = HardwareDetector::new();
+    detector.system_info.serial_number = "PF2ABCDE".to_string();
+    detector.motherboard_info.serial_number = "MB1234567".to_string();
+
+    let json = |privacy_mode| -> serde_json::Value {
+        let response = route("GET", "/report.json", &detector, privacy_mode, None);
+        serde_json::from_str(&response.body).unwrap()
+    };
+    assert_eq!(json(false)["system_info"]["serial_number"], "PF2ABCDE");
+    let masked = json(true);
+    assert_eq!(masked["system_info"]["serial_number"], "PF****DE");
+    assert_eq!(masked["motherboard_info"]["serial_number"], "MB*****67");
+    // 未知的值保持原样
+    assert_eq!(masked["disk_info"]["serial_number"], "未知");
+}
+
+#[test]
+fn test_local_server_serves_requests() {
+    let detector = Arc::new(Mutex::new(HardwareDetector::new()));
+    detector.lock().unwrap().cpu_info.name = "Test CPU".to_string();
+    let server = LocalServer::start(
+        "127.0.0.1:0".parse().unwrap(),
+        detector,
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(Mutex::new(None)),
+    )
+    .unwrap();
+
+    let mut stream = TcpStream::connect(server.addr()).unwrap();
+    stream
+        .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("Connection: close\r\n"));
+    assert!(response.contains("cpu=\"Test CPU\""));
+}
+
+#[test]
+fn test_local_server_rejects_foreign_host() {
+    let server = LocalServer::start(
+        "127.0.0.1:0".parse().unwrap(),
+        Arc::new(Mutex::new(HardwareDetector::new())),
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(Mutex::new(None)),
+    )
+    .unwrap();
+
+    let request = |raw: &[u8]| {
+        let mut stream = TcpStream::connect(server.addr()).unwrap();
+        stream.write_all(raw).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+    // DNS 重绑定后浏览器发送的是攻击者的域名
+    let response = request(b"GET /report.json HTTP/1.1\r\nHost: attacker.example:9182\r\n\r\n");
+    assert!(
+        response.starts_with("HTTP/1.1 403 Forbidden\r\n"),
+        "{}",
+        response
+    );
+    assert!(!response.contains("cpu_info"));
+    let response = request(b"GET /metrics HTTP/1.0\r\n\r\n");
+    assert!(
+        response.starts_with("HTTP/1.1 403 Forbidden\r\n"),
+        "{}",
+        response
+    );
+
+    let response = request(b"GET /metrics HTTP/1.1\r\nHost: [::1]:9182\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+}
+
+#[test]
+fn test_is_local_host() {
+    for host in [
+        "127.0.0.1",
+        "127.0.0.1:9182",
+        "localhost",
+        "LOCALHOST:80",
+        "[::1]",
+        "[::1]:9182",
+    ] {
+        assert!(is_local_host(host), "{}", host);
+    }
+    for host in [
+        "",
+        "127.0.0.2",
+        "0.0.0.0:9182",
+        "192.168.1.10:9182",
+        "localhost.attacker.example",
+        "attacker.example:9182",
+        "localhost:",
+        "127.0.0.1:9182:80",
+        "[::2]:9182",
+    ] {
+        assert!(!is_local_host(host), "{}", host);
+    }
+}
+
+#[test]
+fn test_read_request_limits() {
+    let request = read_request(
+        &mut &b"GET /metrics?x=1 HTTP/1.1\r\nUser-Agent: curl\r\nhost:  localhost \r\n\r\nbody"[..],
+    )
+    .unwrap();
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/metrics?x=1");
+    assert_eq!(request.host.as_deref(), Some("localhost"));
+
+    // 请求行过长
+    let raw = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(SERVE_MAX_LINE_BYTES));
+    assert_eq!(read_request(&mut raw.as_bytes()).unwrap_err().status, 414);
+
+    // 单个请求头过长
+    let raw = format!(
+        "GET / HTTP/1.1\r\nCookie: {}\r\n\r\n",
+        "a".repeat(SERVE_MAX_LINE_BYTES)
+    );
+    assert_eq!(read_request(&mut raw.as_bytes()).unwrap_err().status, 431);
+
+    // 请求头过多
+    let raw = format!(
+        "GET / HTTP/1.1\r\n{}\r\n",
+        "X-Test: 1\r\n".repeat(SERVE_MAX_HEADERS + 1)
+    );
+    assert_eq!(read_request(&mut raw.as_bytes()).unwrap_err().status, 431);
+    let raw = format!(
+        "GET / HTTP/1.1\r\n{}\r\n",
+        "X-Test: 1\r\n".repeat(SERVE_MAX_HEADERS)
+    );
+    assert!(read_request(&mut raw.as_bytes()).is_ok());
+
+    // 请求行格式错误
+    assert_eq!(read_request(&mut &b"\r\n\r\n"[..]).unwrap_err().status, 400);
+    assert_eq!(read_request(&mut &b""[..]).unwrap_err().status, 400);
+}
@@ -0,0 +1,67 @@
+use hardware_master::utils::size::ByteSize;
+use hardware_master::utils::string::format_size;
+
+#[test]
+fn test_byte_size_unit_boundaries() {
+    let display = |bytes: u64| ByteSize::from_bytes(bytes).to_string();
+
+    assert_eq!(display(0), "0 B");
+    assert_eq!(display(1023), "1023 B");
+    assert_eq!(display(1024), "1 KB");
+
+    // MB
+    assert_eq!(display(1024 * 1024 - 1), "1 MB");
+    assert_eq!(display(1024 * 1024), "1 MB");
+    assert_eq!(display(1023 * 1024 * 1024), "1023 MB");
+
+    // GB：四舍五入后满 1024 MB 时显示为 1 GB，而不是 "1024 MB"
+    assert_eq!(display(1024 * 1024 * 1024 - 1), "1 GB");
+    assert_eq!(ByteSize::from_gb(1).to_string(), "1 GB");
+    assert_eq!(ByteSize::from_gb(16).to_string(), "16 GB");
+
+    // TB
+    assert_eq!(ByteSize::from_gb(1023).to_string(), "1023 GB");
+    assert_eq!(ByteSize::from_gb(1024).to_string(), "1 TB");
+    assert_eq!(ByteSize::from_gb(4 * 1024).to_string(), "4 TB");
+
+    // 超过最大单位时不再进位
+    assert_eq!(display(u64::MAX), "16384 PB");
+}
+
+#[test]
+fn test_byte_size_arithmetic() {
+    let slots = [
+        ByteSize::from_gb(8),
+        ByteSize::from_gb(8),
+        ByteSize::from_mb(4096),
+    ];
+    let total: ByteSize = slots.iter().sum();
+    assert_eq!(total, ByteSize::from_gb(20));
+    assert_eq!(total.as_gb(), 20.0);
+
+    let mut free = ByteSize::from_kb(512);
+    free += ByteSize::from_kb(512);
+    assert_eq!(free, ByteSize::from_mb(1));
+    assert!(ByteSize::ZERO.is_zero());
+    assert_eq!(
+        ByteSize::from_bytes(u64::MAX) + free,
+        ByteSize::from_bytes(u64::MAX)
+    );
+}
+
+#[test]
+fn test_format_size_matches_byte_size() {
+    assert_eq!(format_size(ByteSize::from_gb(512)), "512 GB");
+    assert_eq!(format_size(512.0 * 1024.0 * 1024.0 * 1024.0), "512 GB");
+    assert_eq!(format_size(-1.0), "0 B");
+    assert_eq!(format_size(f64::NAN), "0 B");
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_byte_size_serializes_as_bytes() {
+    let size = ByteSize::from_gb(16);
+    let json = serde_json::to_string(&size).unwrap();
+    assert_eq!(json, "17179869184");
+    assert_eq!(serde_json::from_str::<ByteSize>(&json).unwrap(), size);
+}
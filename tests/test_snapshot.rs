@@ -0,0 +1,110 @@
+use hardware_master::detector::disk::{DiskBus, DiskType, PhysicalDisk};
+use hardware_master::detector::memory::MemorySlot;
+use hardware_master::detector::DetectionCategory;
+use hardware_master::snapshot::{diff, HardwareChange, HardwareSnapshot, SnapshotStore};
+use hardware_master::utils::size::ByteSize;
+use hardware_master::HardwareDetector;
+
+fn slot(name: &str) -> MemorySlot {
+    MemorySlot {
+        name: name.to_string(),
+        capacity: ByteSize::from_gb(8),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_diff_added_memory_slot() {
+    let mut detector = HardwareDetector::new();
+    detector.memory_info.slots = vec![slot("金士顿 DDR4 3200 8 GB")];
+    let old = HardwareSnapshot::capture(&detector, 0);
+
+    // 加装一条相同的内存条
+    detector
+        .memory_info
+        .slots
+        .push(slot("金士顿 DDR4 3200 8 GB"));
+    let new = HardwareSnapshot::capture(&detector, 1);
+
+    assert_eq!(
+        diff(&old, &new),
+        vec![HardwareChange::Added {
+            category: DetectionCategory::Memory,
+            name: "金士顿 DDR4 3200 8 GB".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_diff_swapped_disk() {
+    let mut detector = HardwareDetector::new();
+    detector.disk_info.model = "Samsung SSD 970".to_string();
+    detector.disk_info.serial_number = "S1".to_string();
+    let old = HardwareSnapshot::capture(&detector, 0);
+
+    detector.disk_info.model = "WD Blue SN570".to_string();
+    detector.disk_info.serial_number = "W2".to_string();
+    let new = HardwareSnapshot::capture(&detector, 1);
+
+    let descriptions: Vec<String> = diff(&old, &new).iter().map(|c| c.description()).collect();
+    assert_eq!(
+        descriptions,
+        vec![
+            "磁盘信息: 主硬盘 WD Blue SN570 型号 Samsung SSD 970 → WD Blue SN570",
+            "磁盘信息: 主硬盘 WD Blue SN570 序列号 S1 → W2",
+        ]
+    );
+}
+
+#[test]
+fn test_diff_ignores_removable_media() {
+    let mut detector = HardwareDetector::new();
+    let old = HardwareSnapshot::capture(&detector, 0);
+
+    // 插入一张 SD 卡不算硬件变化
+    detector.disk_info.physical_disks.push(PhysicalDisk {
+        number: 2,
+        model: "SD/MMC".to_string(),
+        total_capacity: ByteSize::from_gb(8),
+        disk_type: DiskType::Unknown,
+        bus: DiskBus::Sd,
+        removable: true,
+        media_present: true,
+    });
+    let new = HardwareSnapshot::capture(&detector, 1);
+
+    assert!(diff(&old, &new).is_empty());
+}
+
+#[test]
+fn test_diff_skips_disabled_categories() {
+    let mut detector = HardwareDetector::new();
+    detector.memory_info.slots = vec![slot("金士顿 DDR4 3200 8 GB")];
+    let old = HardwareSnapshot::capture(&detector, 0);
+
+    // 未检测内存时不应报告内存条被移除
+    detector.set_enabled_categories(&[DetectionCategory::System]);
+    detector.memory_info.slots.clear();
+    let new = HardwareSnapshot::capture(&detector, 1);
+
+    assert!(diff(&old, &new).is_empty());
+}
+
+#[test]
+fn test_snapshot_store_roundtrip() {
+    let path = std::env::temp_dir().join(format!(
+        "hardware-master-snapshot-{}.json",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    let store = SnapshotStore::new(&path);
+    assert_eq!(store.load().unwrap(), None);
+
+    let mut detector = HardwareDetector::new();
+    detector.memory_info.slots = vec![slot("金士顿 DDR4 3200 8 GB")];
+    let snapshot = HardwareSnapshot::capture(&detector, 1_700_000_000);
+    store.save(&snapshot).unwrap();
+    assert_eq!(store.load().unwrap(), Some(snapshot));
+
+    let _ = std::fs::remove_file(&path);
+}
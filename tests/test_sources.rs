@@ -0,0 +1,287 @@
+use hardware_master::detector::cpu::detect_cpu_with;
+use hardware_master::detector::memory::detect_memory_with;
+use hardware_master::detector::motherboard::detect_motherboard_with;
+use hardware_master::detector::source::{
+    DataRow, DeviceClass, FromDataRow, MockSource, RegistryRoot, SourceBackend, Value,
+};
+use hardware_master::detector::{DetectionCategory, DetectionError};
+use hardware_master::utils::size::ByteSize;
+use hardware_master::wmi_row;
+use hardware_master::HardwareDetector;
+use std::sync::Arc;
+
+const MEMORY_QUERY: &str = "SELECT * FROM Win32_PhysicalMemory";
+const CPU_KEY: &str = r"HARDWARE\DESCRIPTION\System\CentralProcessor\0";
+
+fn memory_row(capacity: u64, memory_type: u32, configured: u32, speed: u32) -> DataRow {
+    DataRow::new()
+        // WMI 的 Capacity 为 uint64，以字符串返回
+        .with("Capacity", capacity.to_string())
+        .with("Manufacturer", "Kingston")
+        .with("SMBIOSMemoryType", memory_type as i32)
+        .with("ConfiguredClockSpeed", configured as i32)
+        .with("Speed", speed as i32)
+}
+
+fn desktop_source() -> MockSource {
+    MockSource::new()
+        .with_wmi(
+            MEMORY_QUERY,
+            vec![
+                memory_row(8 * 1024 * 1024 * 1024, 26, 3200, 3200),
+                memory_row(8 * 1024 * 1024 * 1024, 26, 3200, 3200),
+            ],
+        )
+        .with_wmi(
+            "SELECT * FROM Win32_BaseBoard",
+            vec![DataRow::new()
+                .with("Manufacturer", "Micro-Star International Co., Ltd.")
+                .with("Product", "PRO Z790-A WIFI (MS-7E07)")
+                .with("SerialNumber", "07E0711_M81E123456")],
+        )
+        .with_wmi(
+            "SELECT * FROM Win32_BIOS",
+            vec![DataRow::new()
+                .with("Manufacturer", "American Megatrends International, LLC.")
+                .with("SMBIOSBIOSVersion", "1.A0")
+                .with("ReleaseDate", "20230512000000.000000+000")],
+        )
+        .with_device(
+            DeviceClass::System,
+            "ACPI\\PNP0C02\\1",
+            "Motherboard resources",
+        )
+        .with_device(
+            DeviceClass::System,
+            "PCI\\VEN_8086&DEV_7A04&SUBSYS_7E071462&REV_11\\3&11583659&0&F8",
+            "Intel(R) LPC Controller/eSPI Controller - 7A04",
+        )
+        .with_registry_string(
+            RegistryRoot::LocalMachine,
+            CPU_KEY,
+            "ProcessorNameString",
+            "13th Gen Intel(R) Core(TM) i5-13600K",
+        )
+        .with_registry_string(
+            RegistryRoot::LocalMachine,
+            CPU_KEY,
+            "VendorIdentifier",
+            "GenuineIntel",
+        )
+        .with_registry_string(
+            RegistryRoot::LocalMachine,
+            CPU_KEY,
+            "Identifier",
+            "Intel64 Family 6 Model 183 Stepping 1",
+        )
+        .with_registry_dword(RegistryRoot::LocalMachine, CPU_KEY, "~MHz", 3500)
+}
+
+wmi_row! {
+    /// 覆盖各类转换的属性
+    struct ConversionRow {
+        status: u32 = "Status",
+        capacity: u64 = "Capacity",
+        size: u64 = "Size",
+        speed: u16 = "Speed",
+        name: String = "Name",
+    }
+}
+
+#[test]
+fn test_u32_from_wmi_int() {
+    // WMI 的 uint32 以 VT_I4 返回，高位为 1 时读到负数，按位还原为 u32
+    let row = DataRow::new().with("Status", -1i32);
+    assert_eq!(row.get::<u32>("Status"), Some(u32::MAX));
+    let row = DataRow::new().with("Status", i32::MIN);
+    assert_eq!(row.get::<u32>("Status"), Some(0x8000_0000));
+    let row = DataRow::new().with("Status", 26i32);
+    assert_eq!(row.get::<u32>("Status"), Some(26));
+    // 超出 i32 范围的负数不是 VT_I4 的值，不做还原
+    let row = DataRow::new().with("Status", i64::from(i32::MIN) - 1);
+    assert_eq!(row.get::<u32>("Status"), None);
+    // 其他整数类型只按范围转换
+    let row = DataRow::new().with("Status", -1i32);
+    assert_eq!(row.get::<u64>("Status"), None);
+    assert_eq!(row.get::<i32>("Status"), Some(-1));
+}
+
+#[test]
+fn test_u64_from_wmi_text() {
+    // WMI 的 uint64 以 VT_BSTR 返回
+    let row = DataRow::new()
+        .with("Capacity", "17179869184")
+        .with("Size", " 512110190592 ")
+        .with("Speed", "3200");
+    assert_eq!(row.get::<u64>("Capacity"), Some(17179869184));
+    assert_eq!(row.get::<u64>("Size"), Some(512110190592));
+    assert_eq!(row.get::<u16>("Speed"), Some(3200));
+    assert_eq!(row.get::<f64>("Capacity"), Some(17179869184.0));
+
+    let row = DataRow::new()
+        .with("Capacity", "")
+        .with("Size", "8 GB")
+        .with("Speed", "70000");
+    assert_eq!(row.get::<u64>("Capacity"), None);
+    assert_eq!(row.get::<u64>("Size"), None);
+    // 超出范围时不截断
+    assert_eq!(row.get::<u16>("Speed"), None);
+    // 文本属性不会从整数转换
+    assert_eq!(
+        DataRow::new().with("Name", 7u32).get::<String>("Name"),
+        None
+    );
+}
+
+#[test]
+fn test_wmi_row_missing_and_null() {
+    let row = ConversionRow::from_data_row(
+        &DataRow::new()
+            .with("status", -2i32)
+            .with("Capacity", "8589934592")
+            .with("Speed", "fast"),
+    );
+    assert_eq!(row.status, Some(u32::MAX - 1));
+    assert_eq!(row.capacity, Some(8589934592));
+    // 类型无法转换
+    assert_eq!(row.speed, None);
+    // 属性不存在
+    assert_eq!(row.size, None);
+    assert_eq!(row.name, None);
+
+    // 值为 NULL 的属性与不存在的属性一样读为 None
+    let row: DataRow = [
+        ("Status".to_string(), Value::Null),
+        ("Capacity".to_string(), Value::Null),
+        ("Name".to_string(), Value::Null),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(row.get::<bool>("Status"), None);
+    let row = ConversionRow::from_data_row(&row);
+    assert_eq!(row.status, None);
+    assert_eq!(row.capacity, None);
+    assert_eq!(row.name, None);
+}
+
+#[test]
+fn test_memory_from_source() {
+    let memory = detect_memory_with(&desktop_source()).expect("内存检测失败");
+
+    assert_eq!(memory.slots.len(), 2);
+    assert_eq!(memory.total_memory, ByteSize::from_gb(16));
+    let slot = &memory.slots[0];
+    assert_eq!(slot.memory_type, "DDR4");
+    assert_eq!(slot.frequency, 3200);
+    assert_eq!(slot.name, "Kingston DDR4 3200 8 GB");
+    assert_eq!(memory.name, "16 GB (Kingston DDR4 3200 8 GB x 2)");
+}
+
+#[test]
+fn test_memory_row_fallbacks() {
+    let source = MockSource::new().with_wmi(
+        MEMORY_QUERY,
+        vec![
+            // ConfiguredClockSpeed 为 0 时使用 Speed，空白制造商视为未知
+            DataRow::new()
+                .with("Capacity", "4294967296")
+                .with("Manufacturer", "  ")
+                .with("ConfiguredClockSpeed", 0)
+                .with("Speed", 2400),
+            // 容量为 0 的插槽被忽略
+            memory_row(0, 26, 3200, 3200),
+        ],
+    );
+    let memory = detect_memory_with(&source).expect("内存检测失败");
+
+    assert_eq!(memory.slots.len(), 1);
+    let slot = &memory.slots[0];
+    assert_eq!(slot.frequency, 2400);
+    assert_eq!(slot.memory_type, "未知");
+    assert_eq!(slot.manufacturer, "未知");
+}
+
+#[test]
+fn test_memory_query_error() {
+    let source = MockSource::new().with_wmi_error(
+        MEMORY_QUERY,
+        DetectionError::WindowsApiError("WMI 查询失败".to_string()),
+    );
+    assert!(matches!(
+        detect_memory_with(&source),
+        Err(DetectionError::MemoryError(_))
+    ));
+
+    // 超时错误保持原样
+    let source =
+        MockSource::new().with_wmi_error(MEMORY_QUERY, DetectionError::Timeout("超时".to_string()));
+    assert!(matches!(
+        detect_memory_with(&source),
+        Err(DetectionError::Timeout(_))
+    ));
+}
+
+#[test]
+fn test_motherboard_from_source() {
+    let board = detect_motherboard_with(&desktop_source()).expect("主板检测失败");
+
+    assert_eq!(board.manufacturer, "Micro-Star International Co., Ltd.");
+    assert_eq!(board.product_name, "PRO Z790-A WIFI (MS-7E07)");
+    assert_eq!(board.bios_version, "1.A0");
+    assert_eq!(board.bios_date, "2023-05-12");
+    assert_eq!(board.bios_serial, "未知");
+
+    // 只保留 PCI 设备，芯片组按 PCI ID 查表
+    assert_eq!(board.system_devices.len(), 1);
+    assert_eq!(board.chipset, "Intel Z790");
+}
+
+#[test]
+fn test_motherboard_without_data() {
+    let board = detect_motherboard_with(&MockSource::new()).expect("主板检测失败");
+    assert_eq!(board.manufacturer, "未知");
+    assert_eq!(board.chipset, "未知");
+    assert!(board.system_devices.is_empty());
+}
+
+#[test]
+fn test_cpu_from_source() {
+    let cpu = detect_cpu_with(&desktop_source());
+    assert_eq!(cpu.name, "13th Gen Intel(R) Core(TM) i5-13600K");
+    assert_eq!(cpu.vendor, "GenuineIntel");
+    assert_eq!(cpu.cpu_id, "Intel64 Family 6 Model 183 Stepping 1");
+    assert_eq!(cpu.base_frequency, 3500);
+    assert_eq!(cpu.max_frequency, 3500);
+
+    // 没有 VendorIdentifier 时退回 Identifier
+    let source = MockSource::new().with_registry_string(
+        RegistryRoot::LocalMachine,
+        CPU_KEY,
+        "Identifier",
+        "ARMv8 (64-bit) Family 8",
+    );
+    assert_eq!(detect_cpu_with(&source).vendor, "ARMv8 (64-bit) Family 8");
+}
+
+#[test]
+fn test_detector_with_source_backend() {
+    let mut detector = HardwareDetector::new();
+    detector.set_backend(Arc::new(SourceBackend::new(desktop_source())));
+
+    detector
+        .detect(&[
+            DetectionCategory::Cpu,
+            DetectionCategory::Memory,
+            DetectionCategory::Motherboard,
+        ])
+        .expect("检测失败");
+    assert_eq!(detector.cpu_info.vendor, "GenuineIntel");
+    assert_eq!(detector.memory_info.total_memory, ByteSize::from_gb(16));
+    assert_eq!(detector.motherboard_info.chipset, "Intel Z790");
+
+    // 数据源未覆盖的类别返回不支持
+    assert!(matches!(
+        detector.detect(&[DetectionCategory::Gpu]),
+        Err(DetectionError::Unsupported(_))
+    ));
+}
@@ -0,0 +1,147 @@
+use hardware_master::detector::pci::{board_vendor_name, resolve_vendors};
+use hardware_master::iddb::vendor_zh::localize_vendor;
+use hardware_master::utils::device_id::parse_device_instance_id;
+use hardware_master::utils::subsystem::SubsystemId;
+
+#[test]
+fn test_subsystem_id_parse() {
+    // SUBSYS_ 后高 16 位为子系统设备 ID，低 16 位为子系统厂商 ID
+    let id = SubsystemId::parse("16F310DE").expect("应解析出子系统 ID");
+    assert_eq!(id.vendor_id, 0x10de);
+    assert_eq!(id.device_id, 0x16f3);
+    assert_eq!(id.to_string(), "16F310DE");
+    assert_eq!(
+        SubsystemId::parse(" 0x7d251462 "),
+        SubsystemId::from_raw(0x7d25_1462)
+    );
+
+    for text in [
+        "",
+        "1462",
+        "251462",
+        "7D25146",
+        "7D2514620",
+        "7D25146G",
+        "+D251462",
+        "00000000",
+    ] {
+        assert_eq!(SubsystemId::parse(text), None, "{:?}", text);
+    }
+}
+
+#[test]
+fn test_subsystem_id_from_raw() {
+    let id = SubsystemId::from_raw(0x5010_1043).expect("应解析出子系统 ID");
+    assert_eq!((id.vendor_id, id.device_id), (0x1043, 0x5010));
+    assert_eq!(id.raw(), 0x5010_1043);
+    // 只有设备 ID 时同样视为未提供
+    assert_eq!(SubsystemId::from_raw(0x5010_0000), None);
+
+    // 任意 32 位值与 8 位十六进制文本的解析结果一致
+    let mut state = 0x2545_f491_u32;
+    for _ in 0..1000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        assert_eq!(
+            SubsystemId::parse(&format!("{:08x}", state)),
+            SubsystemId::from_raw(state)
+        );
+        if let Some(id) = SubsystemId::from_raw(state) {
+            assert_eq!(SubsystemId::parse(&id.to_string()), Some(id));
+        }
+    }
+}
+
+#[test]
+fn test_subsystem_id_from_instance_id() {
+    let id = SubsystemId::from_instance_id(
+        r"PCI\VEN_10DE&DEV_2684&SUBSYS_16F37377&REV_A1\4&2283F625&0&0019",
+    )
+    .expect("应解析出子系统 ID");
+    assert_eq!((id.vendor_id, id.device_id), (0x7377, 0x16f3));
+
+    // HD Audio 设备与小写的硬件 ID
+    let id = SubsystemId::from_instance_id(
+        r"hdaudio\func_01&ven_10ec&dev_0897&subsys_1462d001&rev_1001",
+    )
+    .expect("应解析出子系统 ID");
+    assert_eq!((id.vendor_id, id.device_id), (0x1462, 0xd001));
+
+    for instance_id in [
+        r"PCI\VEN_8086&DEV_7A84\3&11583659&0&F8",
+        r"PCI\VEN_8086&DEV_7A84&SUBSYS_1462&REV_11\3&11583659&0&F8",
+        r"USB\VID_046D&PID_0A44&MI_00\7&1A2B3C4D&0&0000",
+        "未知",
+    ] {
+        assert_eq!(
+            SubsystemId::from_instance_id(instance_id),
+            None,
+            "{}",
+            instance_id
+        );
+    }
+}
+
+#[test]
+fn test_device_instance_id_short_subsystem() {
+    // 位数不足的子系统 ID 不再被截断为错误的厂商 ID
+    let info = parse_device_instance_id(r"PCI\VEN_8086&DEV_7A84&SUBSYS_146&REV_11\3&11583659&0&F8");
+    assert_eq!(info.vendor_id, "8086");
+    assert_eq!(info.subsystem_vendor_id, "未知");
+    assert_eq!(info.subsystem_device_id, "未知");
+    assert_eq!(info.revision_id, "11");
+
+    let info = parse_device_instance_id(r"PCI\VEN_10DE&DEV_2684&SUBSYS_16F37377&REV_A1\4&1");
+    assert_eq!(info.subsystem_vendor_id, "7377");
+    assert_eq!(info.subsystem_device_id, "16F3");
+
+    let info =
+        parse_device_instance_id(r"HDAUDIO\FUNC_01&VEN_10EC&DEV_0897&SUBSYS_1462D001&REV_1001\4&1");
+    assert_eq!(info.subsystem_vendor_id, "1462");
+    assert_eq!(info.subsystem_device_id, "D001");
+}
+
+#[test]
+fn test_board_partner_vendors() {
+    let colorful = resolve_vendors(r"PCI\VEN_10DE&DEV_2684&SUBSYS_16F37377&REV_A1\4&1");
+    assert_eq!(colorful.chip_vendor.as_deref(), Some("NVIDIA Corporation"));
+    assert_eq!(
+        colorful.board_vendor.as_deref().and_then(localize_vendor),
+        Some("七彩虹")
+    );
+    assert_eq!(colorful.subsystem, SubsystemId::parse("16F37377"));
+
+    let msi = resolve_vendors(r"PCI\VEN_1002&DEV_73BF&SUBSYS_39821462&REV_C1\6&1");
+    assert_eq!(
+        msi.board_vendor.as_deref().and_then(localize_vendor),
+        Some("微星")
+    );
+
+    // 显卡 HDMI 音频同样带有板卡厂商的子系统 ID，HD Audio 设备的厂商 ID 在高 16 位
+    let audio = resolve_vendors(r"HDAUDIO\FUNC_01&VEN_10DE&DEV_00A3&SUBSYS_73771C00&REV_1001\5&1");
+    assert_eq!(audio.subsystem, SubsystemId::from_raw(0x1c00_7377));
+    assert_eq!(
+        audio.board_vendor.as_deref().and_then(localize_vendor),
+        Some("七彩虹")
+    );
+
+    for (raw, zh) in [
+        (0x1c00_1b4c, "影驰"),
+        (0x1c00_19da, "索泰"),
+        (0x1c00_1da2, "蓝宝石"),
+        (0x1c00_148c, "撼讯"),
+        (0x1c00_1043, "华硕"),
+        (0x1c00_1458, "技嘉"),
+    ] {
+        let subsystem = SubsystemId::from_raw(raw).expect("应解析出子系统 ID");
+        let name = board_vendor_name(subsystem).expect("pci.ids 中应有该厂商");
+        assert_eq!(localize_vendor(&name), Some(zh), "{}", name);
+    }
+
+    // 非 PCI 设备不查询
+    assert_eq!(
+        resolve_vendors(r"USB\VID_046D&PID_0A44&MI_00\7&1A2B3C4D&0&0000"),
+        Default::default()
+    );
+}
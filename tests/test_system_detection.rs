@@ -1,3 +1,5 @@
+#![cfg(windows)]
+
 use hardware_master::detector::system::detect_system;
 
 #[test]
@@ -9,6 +11,8 @@ fn test_system_detection() {
             println!("  Manufacturer: {}", info.system_manufacturer);
             println!("  Model: {}", info.computer_model);
             println!("  Type: {:?}", info.computer_type);
+            println!("  Version: {} ({})", info.display_version, info.os_build);
+            println!("  Activation: {}", info.activation);
 
             // 验证至少有系统信息
             assert!(!info.os_name.is_empty() || info.os_name != "未知",
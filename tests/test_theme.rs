@@ -1,4 +1,5 @@
-use hardware_master::ui::theme::AppTheme;
+use eframe::egui;
+use hardware_master::ui::theme::{AppTheme, Density, ThemeStyle};
 
 #[test]
 fn test_theme_next() {
@@ -33,3 +34,78 @@ fn test_theme_display() {
     assert_eq!(format!("{}", AppTheme::Light), "亮色");
     assert_eq!(format!("{}", AppTheme::Dark), "暗色");
 }
+
+#[test]
+fn test_tab_visible() {
+    use hardware_master::ui::tab::AppTab;
+    // 普通模式下隐藏高级标签页
+    assert_eq!(
+        AppTab::visible(false),
+        vec![
+            AppTab::Overview,
+            AppTab::Live,
+            AppTab::Inspection,
+            AppTab::Trends,
+            AppTab::Changes,
+            AppTab::Benchmark,
+            AppTab::Peripherals,
+            AppTab::Asset
+        ]
+    );
+    assert_eq!(AppTab::visible(true), AppTab::ALL.to_vec());
+}
+
+#[test]
+fn test_density_name() {
+    let names: Vec<&str> = Density::ALL.iter().map(|d| d.name()).collect();
+    assert_eq!(names, ["紧凑", "普通", "宽松"]);
+    assert_eq!(Density::default(), Density::Normal);
+}
+
+#[test]
+fn test_theme_style_apply_is_idempotent() {
+    let style = ThemeStyle {
+        accent: [200, 40, 40],
+        density: Density::Relaxed,
+        ..Default::default()
+    };
+    let mut once = egui::Style::default();
+    style.apply_style(&mut once);
+    let mut twice = once.clone();
+    style.apply_style(&mut twice);
+
+    assert_eq!(once.spacing.item_spacing, twice.spacing.item_spacing);
+    assert_eq!(
+        once.visuals.selection.bg_fill,
+        twice.visuals.selection.bg_fill
+    );
+    assert!(once.spacing.item_spacing.y > egui::Style::default().spacing.item_spacing.y);
+    assert_eq!(
+        once.visuals.widgets.hovered.bg_stroke.color,
+        egui::Color32::from_rgb(200, 40, 40)
+    );
+}
+
+#[test]
+fn test_accessible_style() {
+    let style = ThemeStyle {
+        accessible: true,
+        ..Default::default()
+    };
+    let mut accessible = egui::Style::default();
+    style.apply_style(&mut accessible);
+    style.apply_style(&mut accessible);
+
+    let default = egui::Style::default();
+    let body = |style: &egui::Style| style.text_styles[&egui::TextStyle::Body].size;
+    assert_eq!(body(&accessible), body(&default) * 1.25);
+    assert_eq!(
+        accessible.visuals.override_text_color,
+        Some(egui::Color32::WHITE)
+    );
+    assert_eq!(accessible.visuals.panel_fill, egui::Color32::BLACK);
+
+    let mut normal = accessible.clone();
+    ThemeStyle::default().apply_style(&mut normal);
+    assert_eq!(body(&normal), body(&default));
+}
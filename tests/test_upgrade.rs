@@ -0,0 +1,211 @@
+use chrono::NaiveDate;
+use hardware_master::analysis::upgrade::{Priority, Suggestion, UpgradeRuleSet};
+use hardware_master::detector::disk::{DiskBus, DiskExtent, DiskType, PhysicalDisk};
+use hardware_master::detector::gpu::{Gpu, GpuType};
+use hardware_master::detector::memory::{ChannelMode, MemorySlot};
+use hardware_master::detector::volume::{DriveKind, Volume};
+use hardware_master::utils::size::ByteSize;
+use hardware_master::HardwareDetector;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+}
+
+fn rule_ids(suggestions: &[Suggestion]) -> Vec<&str> {
+    suggestions
+        .iter()
+        .map(|suggestion| suggestion.rule_id.as_str())
+        .collect()
+}
+
+fn physical_disk(number: u32, disk_type: DiskType) -> PhysicalDisk {
+    PhysicalDisk {
+        number,
+        model: format!("Disk {}", number),
+        total_capacity: ByteSize::from_gb(1000),
+        disk_type,
+        bus: DiskBus::Sata,
+        removable: false,
+        media_present: true,
+    }
+}
+
+fn volume(letter: &str, disk_number: u32) -> Volume {
+    Volume {
+        drive_letter: letter.to_string(),
+        kind: DriveKind::Fixed,
+        extents: vec![DiskExtent {
+            disk_number,
+            starting_offset: 0,
+            length: 1024,
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_builtin_upgrade_rules_parse() {
+    let rule_set = UpgradeRuleSet::builtin();
+    assert!(!rule_set.rules.is_empty());
+    assert!(rule_set.rules.iter().all(|rule| !rule.when.is_empty()));
+    for rule in rule_set.rules.iter() {
+        let same_id = rule_set.rules.iter().filter(|r| r.id == rule.id).count();
+        assert_eq!(same_id, 1, "{}", rule.id);
+    }
+}
+
+#[test]
+fn test_memory_upgrade_suggestions() {
+    let mut detector = HardwareDetector::new();
+    detector.memory_info.slots = vec![MemorySlot {
+        memory_type: "DDR4".to_string(),
+        frequency: 3200,
+        capacity: ByteSize::from_gb(8),
+        ..Default::default()
+    }];
+    detector.memory_info.channel_mode = ChannelMode::Single;
+    detector.memory_info.slot_count = Some(4);
+
+    let suggestions = UpgradeRuleSet::builtin().suggest(&detector, today());
+    // 高优先级的建议排在前面
+    assert_eq!(
+        rule_ids(&suggestions),
+        ["memory-single-channel", "memory-empty-slots"]
+    );
+    assert_eq!(suggestions[0].priority, Priority::High);
+    assert_eq!(suggestions[0].component, "内存");
+    assert!(
+        suggestions[0].detail.contains("DDR4 3200MHz 8GB"),
+        "{}",
+        suggestions[0].detail
+    );
+    assert_eq!(suggestions[1].title, "空余内存插槽 3 个");
+
+    // 插满后不再提示空余插槽
+    detector.memory_info.slot_count = Some(1);
+    let suggestions = UpgradeRuleSet::builtin().suggest(&detector, today());
+    assert_eq!(rule_ids(&suggestions), ["memory-single-channel"]);
+
+    // 双通道且插槽数量未知时没有内存相关建议
+    detector.memory_info.channel_mode = ChannelMode::Dual;
+    detector.memory_info.slot_count = None;
+    assert!(UpgradeRuleSet::builtin()
+        .suggest(&detector, today())
+        .is_empty());
+}
+
+#[test]
+fn test_system_disk_hdd_suggestion() {
+    let mut detector = HardwareDetector::new();
+    // 主硬盘为固态硬盘，但系统装在机械硬盘上
+    detector.disk_info.disk_type = DiskType::SSD;
+    detector.disk_info.physical_disks = vec![
+        physical_disk(0, DiskType::SSD),
+        physical_disk(1, DiskType::HDD),
+    ];
+    detector.disk_info.volumes = vec![volume("D:", 0), volume("c:", 1)];
+
+    let system_disk = detector.disk_info.system_disk("C:").expect("应找到系统盘");
+    assert_eq!(system_disk.number, 1);
+    assert_eq!(detector.disk_info.system_disk("D:").unwrap().number, 0);
+    assert!(detector.disk_info.system_disk("E:").is_none());
+
+    let suggestions = UpgradeRuleSet::builtin().suggest(&detector, today());
+    assert_eq!(rule_ids(&suggestions), ["disk-system-hdd"]);
+    assert_eq!(suggestions[0].component, "硬盘");
+
+    detector.disk_info.volumes = vec![volume("C:", 0)];
+    assert!(UpgradeRuleSet::builtin()
+        .suggest(&detector, today())
+        .is_empty());
+}
+
+#[test]
+fn test_gpu_driver_outdated_suggestion() {
+    let mut detector = HardwareDetector::new();
+    detector.gpu_info.gpus = vec![
+        Gpu {
+            description: "Intel(R) UHD Graphics 770".to_string(),
+            gpu_type: GpuType::IntegratedGpu,
+            driver_date: "2024-03-20".to_string(),
+            ..Default::default()
+        },
+        Gpu {
+            description: "NVIDIA GeForce RTX 3060".to_string(),
+            gpu_type: GpuType::DiscreteGpu,
+            driver_date: "2023-01-10".to_string(),
+            ..Default::default()
+        },
+        // 虚拟显示适配器不参与判断
+        Gpu {
+            description: "Parsec Virtual Display Adapter".to_string(),
+            gpu_type: GpuType::VirtualDisplay,
+            driver_date: "2019-01-01".to_string(),
+            ..Default::default()
+        },
+    ];
+
+    let suggestions = UpgradeRuleSet::builtin().suggest(&detector, today());
+    assert_eq!(rule_ids(&suggestions), ["gpu-driver-outdated"]);
+    assert_eq!(suggestions[0].priority, Priority::Medium);
+    assert!(suggestions[0]
+        .detail
+        .starts_with("NVIDIA GeForce RTX 3060 的驱动发布于 2023-01-10"));
+
+    // 驱动在一年以内或日期未知时不提示
+    detector.gpu_info.gpus[1].driver_date = "2023-08-01".to_string();
+    assert!(UpgradeRuleSet::builtin()
+        .suggest(&detector, today())
+        .is_empty());
+    detector.gpu_info.gpus[1].driver_date = "未知".to_string();
+    assert!(UpgradeRuleSet::builtin()
+        .suggest(&detector, today())
+        .is_empty());
+}
+
+#[test]
+fn test_user_upgrade_rules_merge() {
+    let mut rule_set = UpgradeRuleSet::builtin();
+    let count = rule_set.rules.len();
+    let user_rules = UpgradeRuleSet::from_toml(
+        r#"
+[[rules]]
+id = "memory-empty-slots"
+component = "内存"
+priority = "high"
+title = "还能加 {memory.empty_slots} 根内存"
+detail = "联系 IT 申请内存"
+when = [{ fact = "memory.empty_slots", op = "ge", value = 1 }]
+
+[[rules]]
+id = "memory-capacity-low"
+component = "内存"
+title = "内存容量不足"
+detail = "当前 {memory.total_gb}GB"
+when = [{ fact = "memory.total_gb", op = "lt", value = 16 }]
+"#,
+    )
+    .expect("规则解析失败");
+    rule_set.merge(user_rules);
+    assert_eq!(rule_set.rules.len(), count + 1);
+
+    let mut detector = HardwareDetector::new();
+    detector.memory_info.slots = vec![MemorySlot {
+        capacity: ByteSize::from_gb(8),
+        ..Default::default()
+    }];
+    detector.memory_info.total_memory = ByteSize::from_gb(8);
+    detector.memory_info.slot_count = Some(2);
+
+    let suggestions = rule_set.suggest(&detector, today());
+    assert_eq!(
+        rule_ids(&suggestions),
+        ["memory-empty-slots", "memory-capacity-low"]
+    );
+    assert_eq!(suggestions[0].title, "还能加 1 根内存");
+    // 未填写优先级时为中
+    assert_eq!(suggestions[1].priority, Priority::Medium);
+    assert_eq!(suggestions[1].detail, "当前 8GB");
+
+    assert!(UpgradeRuleSet::from_toml("[[rules]]\nid = \"x\"").is_err());
+}
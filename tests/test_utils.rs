@@ -1,7 +1,7 @@
 use hardware_master::utils::{
     math::{cm_to_inches, div},
     string::{u16_bytes_to_string, u16_slice_to_string, u8_slice_to_string},
-    wmi_date::parse_wmi_date,
+    wmi_date::{parse_wmi_date, parse_wmi_datetime},
 };
 
 #[test]
@@ -39,4 +39,103 @@ fn test_wmi_date_parsing() {
     let date_str = "20240101120000.000000+000";
     let result = parse_wmi_date(date_str);
     assert!(!result.is_empty());
+
+    // 同一时刻在不同时区的表示
+    assert_eq!(
+        parse_wmi_datetime("20240101120000.000000+000"),
+        parse_wmi_datetime("20240101200000.000000+480")
+    );
+    // 没有时区偏移时按 UTC 处理
+    assert_eq!(parse_wmi_datetime("20240101120000"), Some(1704110400));
+    assert_eq!(parse_wmi_datetime("not a date"), None);
+}
+
+#[test]
+fn test_wifi_helpers() {
+    use hardware_master::detector::network::{phy_type_name, WifiBand};
+
+    assert_eq!(phy_type_name(8), Some("802.11ac (Wi-Fi 5)"));
+    assert_eq!(phy_type_name(0), None);
+
+    assert_eq!(WifiBand::from_frequency_khz(2_437_000), Some(WifiBand::Band2_4G));
+    assert_eq!(WifiBand::from_frequency_khz(5_180_000), Some(WifiBand::Band5G));
+    assert_eq!(WifiBand::from_frequency_khz(5_955_000), Some(WifiBand::Band6G));
+    assert_eq!(WifiBand::from_channel(6), Some(WifiBand::Band2_4G));
+    assert_eq!(WifiBand::from_channel(149), Some(WifiBand::Band5G));
+}
+
+#[test]
+fn test_smbios_structures() {
+    use hardware_master::detector::motherboard::{fill_from_smbios, MotherboardInfo};
+    use hardware_master::utils::smbios::parse_structures;
+
+    // 类型 1：系统信息，UUID 位于偏移 0x08
+    let mut table = vec![1u8, 0x19, 0x01, 0x00, 1, 2, 0, 0];
+    table.extend([
+        0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ]);
+    table.push(0);
+    table.extend(b"Vendor\0Model\0\0");
+    // 类型 2：主板信息，资产标签为第 1 个字符串
+    table.extend([2u8, 0x09, 0x02, 0x00, 0, 0, 0, 0, 1]);
+    table.extend(b"ASSET-01\0\0");
+    // 类型 3：机箱信息，序列号为第 1 个字符串，无其他字符串
+    table.extend([3u8, 0x09, 0x03, 0x00, 0, 0, 0, 1, 0]);
+    table.extend(b"CHS123\0\0");
+    // 结束标记
+    table.extend([127u8, 4, 0x04, 0x00, 0, 0]);
+
+    let structures = parse_structures(&table);
+    assert_eq!(structures.len(), 4);
+    assert_eq!(structures[0].string(4), Some("Vendor"));
+    assert_eq!(structures[0].string(6), None);
+
+    let mut info = MotherboardInfo::default();
+    fill_from_smbios(&mut info, &structures);
+    assert_eq!(info.system_uuid, "00112233-4455-6677-8899-AABBCCDDEEFF");
+    assert_eq!(info.asset_tag, "ASSET-01");
+    assert_eq!(info.chassis_serial, "CHS123");
+}
+
+#[test]
+fn test_smbios_chassis_type() {
+    use hardware_master::detector::system::chassis_type;
+    use hardware_master::detector::ComputerType;
+    use hardware_master::utils::smbios::parse_structures;
+
+    // 类型 3：机箱类型位于偏移 0x05，最高位为机箱锁标志
+    let mut table = vec![3u8, 0x09, 0x03, 0x00, 0, 0x8A, 0, 0, 0, 0, 0];
+    table.extend([127u8, 4, 0x04, 0x00, 0, 0]);
+    let structures = parse_structures(&table);
+    assert_eq!(chassis_type(&structures), Some(10));
+    assert_eq!(
+        chassis_type(&structures).map(ComputerType::from_chassis_type),
+        Some(ComputerType::Laptop)
+    );
+    assert_eq!(chassis_type(&structures[1..]), None);
+
+    assert!(ComputerType::Convertible.is_portable());
+    assert!(!ComputerType::MiniPc.is_portable());
+}
+
+#[test]
+fn test_mask_sensitive() {
+    use hardware_master::utils::string::mask_sensitive;
+
+    assert_eq!(mask_sensitive("5CD1234XYZ"), "5C******YZ");
+    assert_eq!(mask_sensitive("ab"), "**");
+    assert_eq!(mask_sensitive("未知"), "未知");
+}
+
+#[test]
+#[cfg(windows)]
+fn test_wmi_default_timeout() {
+    use hardware_master::utils::wmi::{set_default_timeout, WmiConfig};
+    use std::time::Duration;
+
+    set_default_timeout(Duration::from_secs(5));
+    let config = WmiConfig::default();
+    assert_eq!(config.namespace, "ROOT\\CIMV2");
+    assert_eq!(config.timeout, Duration::from_secs(5));
 }
@@ -0,0 +1,33 @@
+use hardware_master::iddb::vendor_zh::{display_vendor, localize_vendor};
+
+#[test]
+fn test_localize_vendor() {
+    assert_eq!(localize_vendor("NVIDIA Corporation"), Some("英伟达"));
+    assert_eq!(
+        localize_vendor("Advanced Micro Devices, Inc. [AMD/ATI]"),
+        Some("超威")
+    );
+    assert_eq!(localize_vendor("SK hynix"), Some("海力士"));
+    assert_eq!(
+        localize_vendor("Shenzhen Colorful Yugong Technology and Development Co."),
+        Some("七彩虹")
+    );
+    assert_eq!(
+        localize_vendor("Tul Corporation / PowerColor"),
+        Some("撼讯")
+    );
+    assert_eq!(localize_vendor("micron technology inc"), Some("美光"));
+    // 前缀后必须是单词边界
+    assert_eq!(localize_vendor("Intellon Corporation"), None);
+    assert_eq!(localize_vendor("Unknown Vendor"), None);
+}
+
+#[test]
+fn test_display_vendor() {
+    assert_eq!(display_vendor("Intel Corporation", true), "英特尔");
+    assert_eq!(
+        display_vendor("Intel Corporation", false),
+        "Intel Corporation"
+    );
+    assert_eq!(display_vendor("Unknown Vendor", true), "Unknown Vendor");
+}
@@ -0,0 +1,74 @@
+use hardware_master::detector::disk::DiskExtent;
+use hardware_master::detector::volume::{group_by_disk, DriveKind, Volume};
+use hardware_master::utils::size::ByteSize;
+
+fn volume(letter: &str, disks: &[u32]) -> Volume {
+    Volume {
+        drive_letter: letter.to_string(),
+        kind: DriveKind::Fixed,
+        extents: disks
+            .iter()
+            .map(|&disk_number| DiskExtent {
+                disk_number,
+                starting_offset: 0,
+                length: 1024,
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_drive_kind_from_drive_type() {
+    assert_eq!(DriveKind::from(2), DriveKind::Removable);
+    assert_eq!(DriveKind::from(3), DriveKind::Fixed);
+    assert_eq!(DriveKind::from(5), DriveKind::CdRom);
+    assert_eq!(DriveKind::from(0), DriveKind::Unknown);
+}
+
+#[test]
+fn test_volume_display() {
+    let mut volume = Volume {
+        drive_letter: "C:".to_string(),
+        file_system: "NTFS".to_string(),
+        kind: DriveKind::Fixed,
+        total_space: ByteSize::from_bytes(200),
+        free_space: ByteSize::from_bytes(50),
+        ..Default::default()
+    };
+    assert_eq!(volume.display_name(), "本地磁盘 (C:)");
+    assert!((volume.used_ratio() - 0.75).abs() < 1e-9);
+
+    volume.label = "系统".to_string();
+    assert_eq!(volume.display_name(), "系统 (C:)");
+
+    volume.total_space = ByteSize::ZERO;
+    assert_eq!(volume.used_ratio(), 0.0);
+}
+
+#[test]
+fn test_group_volumes_by_disk() {
+    let volumes = vec![
+        volume("C:", &[0]),
+        volume("D:", &[1]),
+        volume("E:", &[0]),
+        // 跨区卷
+        volume("F:", &[1, 2, 1]),
+        // 网络驱动器
+        volume("Z:", &[]),
+    ];
+    assert_eq!(volumes[3].disk_numbers(), vec![1, 2]);
+
+    let groups = group_by_disk(&volumes);
+    let letters = |key: Option<u32>| -> Vec<&str> {
+        groups[&key]
+            .iter()
+            .map(|v| v.drive_letter.as_str())
+            .collect()
+    };
+    assert_eq!(groups.len(), 4);
+    assert_eq!(letters(Some(0)), vec!["C:", "E:"]);
+    assert_eq!(letters(Some(1)), vec!["D:", "F:"]);
+    assert_eq!(letters(Some(2)), vec!["F:"]);
+    assert_eq!(letters(None), vec!["Z:"]);
+}